@@ -0,0 +1,310 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, Bytes, BytesN, Env, Symbol, Vec,
+};
+
+pub use pg_contracts_types::airdrop::AirdropRound;
+
+// --- Proof Constraints ---
+const MAX_PROOF_LENGTH: u32 = 32; // Maximum Merkle proof depth accepted per claim
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const ROUNDS_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+const CLAIM_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 0] = [];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum AirdropContractDataKey {
+    Admin,                 // Contract administrator address
+    Token,                 // Token distributed by the airdrop
+    Round(u32),            // Merkle root, funding, and deadline per round
+    Claimed(u32, Address), // Tracks whether an address has claimed in a round
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1100-1199 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AirdropContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidRound = 1103,            // Funding must be positive and the deadline in the future
+    RoundAlreadyExists = 1104,      // A round with this number already exists
+    RoundNotFound = 1105,           // The round does not exist
+    AlreadyClaimed = 1106,          // The address has already claimed in this round
+    InvalidProof = 16,              // The proof does not match the round's Merkle root
+    ClaimPeriodEnded = 1108,        // The round's claim deadline has passed
+    ClaimPeriodActive = 1109,       // Unclaimed tokens can only be swept after the deadline
+    RoundAlreadySwept = 1110,       // Unclaimed tokens were already swept
+    InsufficientRoundFunds = 1111,  // The claim exceeds the round's remaining funds
+}
+
+#[contract]
+pub struct AirdropContract;
+
+#[contractimpl]
+impl AirdropContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, AirdropContractErrors> {
+        env.storage()
+            .instance()
+            .get(&AirdropContractDataKey::Admin)
+            .ok_or(AirdropContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the airdrop token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, AirdropContractErrors> {
+        env.storage()
+            .instance()
+            .get(&AirdropContractDataKey::Token)
+            .ok_or(AirdropContractErrors::ContractNotInitialized)
+    }
+
+    // Saves a round and extends its TTL
+    fn write_round(env: &Env, round: u32, data: &AirdropRound) {
+        let round_key = AirdropContractDataKey::Round(round);
+        env.storage().persistent().set(&round_key, data);
+        env.storage().persistent().extend_ttl(
+            &round_key,
+            ROUNDS_TTL_EXTENSION,
+            ROUNDS_TTL_EXTENSION,
+        );
+    }
+
+    // Hashes an (address, amount) entry into a Merkle leaf
+    fn hash_leaf(env: &Env, address: &Address, amount: i128) -> BytesN<32> {
+        let entry = (address.clone(), amount).to_xdr(env);
+        env.crypto().sha256(&entry).to_bytes()
+    }
+
+    // Hashes two nodes in sorted order so proofs need no position flags
+    fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(env, &first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and airdrop token
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), AirdropContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env.storage().instance().has(&AirdropContractDataKey::Admin) {
+            return Err(AirdropContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&AirdropContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&AirdropContractDataKey::Token, &token);
+        Ok(())
+    }
+
+    // Opens a claim round with a Merkle root, funded by the admin (admin only)
+    pub fn create_round(
+        env: Env,
+        round: u32,
+        root: BytesN<32>,
+        total: i128,
+        deadline: u64,
+    ) -> Result<(), AirdropContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if total <= 0 || deadline <= env.ledger().timestamp() {
+            return Err(AirdropContractErrors::InvalidRound);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&AirdropContractDataKey::Round(round))
+        {
+            return Err(AirdropContractErrors::RoundAlreadyExists);
+        }
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &admin,
+            &env.current_contract_address(),
+            &total,
+        );
+
+        let data = AirdropRound {
+            root: root.clone(),
+            total,
+            claimed: 0,
+            deadline,
+            swept: false,
+        };
+        Self::write_round(&env, round, &data);
+
+        env.events()
+            .publish(("ROUND", "CREATED", round), (root, total, deadline));
+        Ok(())
+    }
+
+    // Claims an airdrop entry by proving it is included in the round's Merkle root
+    pub fn claim(
+        env: Env,
+        claimant: Address,
+        round: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), AirdropContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        claimant.require_auth();
+
+        let mut data = Self::get_round(env.clone(), round)?;
+        if env.ledger().timestamp() > data.deadline {
+            return Err(AirdropContractErrors::ClaimPeriodEnded);
+        }
+        let claimed_key = AirdropContractDataKey::Claimed(round, claimant.clone());
+        if env.storage().persistent().has(&claimed_key) {
+            return Err(AirdropContractErrors::AlreadyClaimed);
+        }
+        if amount <= 0 || proof.len() > MAX_PROOF_LENGTH {
+            return Err(AirdropContractErrors::InvalidProof);
+        }
+
+        let mut node = Self::hash_leaf(&env, &claimant, amount);
+        for sibling in proof.iter() {
+            node = Self::hash_pair(&env, &node, &sibling);
+        }
+        if node != data.root {
+            return Err(AirdropContractErrors::InvalidProof);
+        }
+        if data.claimed.saturating_add(amount) > data.total {
+            return Err(AirdropContractErrors::InsufficientRoundFunds);
+        }
+
+        data.claimed += amount;
+        Self::write_round(&env, round, &data);
+        env.storage().persistent().set(&claimed_key, &true);
+        env.storage().persistent().extend_ttl(
+            &claimed_key,
+            CLAIM_TTL_EXTENSION,
+            CLAIM_TTL_EXTENSION,
+        );
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &claimant,
+            &amount,
+        );
+
+        env.events()
+            .publish(("AIRDROP", "CLAIMED", round), (claimant, amount));
+        Ok(())
+    }
+
+    // Returns a round's unclaimed tokens to the admin after its deadline (admin only)
+    pub fn sweep(env: Env, round: u32) -> Result<i128, AirdropContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut data = Self::get_round(env.clone(), round)?;
+        if env.ledger().timestamp() <= data.deadline {
+            return Err(AirdropContractErrors::ClaimPeriodActive);
+        }
+        if data.swept {
+            return Err(AirdropContractErrors::RoundAlreadySwept);
+        }
+
+        let unclaimed = data.total - data.claimed;
+        data.swept = true;
+        Self::write_round(&env, round, &data);
+
+        if unclaimed > 0 {
+            let token_address = Self::read_token(&env)?;
+            TokenClient::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &admin,
+                &unclaimed,
+            );
+        }
+
+        env.events()
+            .publish(("ROUND", "SWEPT", round), (admin, unclaimed));
+        Ok(unclaimed)
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), AirdropContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&AirdropContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the Merkle root, funding, and deadline of a round
+    pub fn get_round(env: Env, round: u32) -> Result<AirdropRound, AirdropContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&AirdropContractDataKey::Round(round))
+            .ok_or(AirdropContractErrors::RoundNotFound)
+    }
+
+    // Returns whether an address has claimed in a round
+    pub fn has_claimed(env: Env, round: u32, claimant: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&AirdropContractDataKey::Claimed(round, claimant))
+    }
+
+    // Returns the Merkle leaf for an (address, amount) entry, for building trees off-chain
+    pub fn leaf(env: Env, claimant: Address, amount: i128) -> BytesN<32> {
+        Self::hash_leaf(&env, &claimant, amount)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
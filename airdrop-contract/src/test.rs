@@ -0,0 +1,311 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, AIRDROP_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Bytes, BytesN, Env, Vec,
+};
+
+fn create_airdrop_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> AirdropContractClient<'a> {
+    let contract_address = e.register(
+        AirdropContract,
+        AirdropContractArgs::__constructor(admin, token_address),
+    );
+    AirdropContractClient::new(e, &contract_address)
+}
+
+// Hashes two nodes in sorted order, matching the contract's tree construction
+fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(e, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+struct AirdropTree {
+    root: BytesN<32>,
+    entries: std::vec::Vec<(Address, i128)>,
+    proofs: std::vec::Vec<Vec<BytesN<32>>>,
+}
+
+// Builds a four-entry Merkle tree with amounts 100, 200, 300, and 400
+fn build_tree(e: &Env, client: &AirdropContractClient) -> AirdropTree {
+    let entries: std::vec::Vec<(Address, i128)> =
+        (1..=4).map(|i| (Address::generate(e), i * 100)).collect();
+    let leaves: std::vec::Vec<BytesN<32>> = entries
+        .iter()
+        .map(|(address, amount)| client.leaf(address, amount))
+        .collect();
+    let left = hash_pair(e, &leaves[0], &leaves[1]);
+    let right = hash_pair(e, &leaves[2], &leaves[3]);
+    let proofs = std::vec![
+        vec![e, leaves[1].clone(), right.clone()],
+        vec![e, leaves[0].clone(), right.clone()],
+        vec![e, leaves[3].clone(), left.clone()],
+        vec![e, leaves[2].clone(), left.clone()],
+    ];
+    AirdropTree {
+        root: hash_pair(e, &left, &right),
+        entries,
+        proofs,
+    }
+}
+
+// Deploys the token and airdrop, mints 10,000 tokens to the admin, and builds a tree
+fn setup_airdrop<'a>(
+    e: &Env,
+) -> (
+    AirdropContractClient<'a>,
+    TokenClient<'a>,
+    Address,
+    AirdropTree,
+) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    StellarAssetClient::new(e, &token.address).mint(&admin, &10000);
+    let client = create_airdrop_contract(e, &admin, &token.address);
+    let tree = build_tree(e, &client);
+    (client, token, admin, tree)
+}
+
+// Tests successful initialization with admin and airdrop token.
+// Expects: No rounds exist yet.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_airdrop(&e);
+
+    assert_eq!(
+        client.try_get_round(&1),
+        Err(Ok(AirdropContractErrors::RoundNotFound))
+    );
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, admin, _) = setup_airdrop(&e);
+
+    e.register_at(
+        &client.address,
+        AirdropContract,
+        AirdropContractArgs::__constructor(&admin, &token.address),
+    );
+}
+
+// Tests creating a funded round.
+// Expects: The funding moves from the admin into the contract and the round is stored.
+#[test]
+fn test_create_round() {
+    let e = setup_test_env();
+    let (client, token, admin, tree) = setup_airdrop(&e);
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+
+    let round = client.get_round(&1);
+    assert_eq!(round.root, tree.root);
+    assert_eq!(round.total, 1000);
+    assert_eq!(round.claimed, 0);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(token.balance(&admin), 9000);
+}
+
+// Tests creating a round whose deadline has already passed.
+// Expects: InvalidRound error (Error #1103).
+#[test]
+#[should_panic(expected = "Error(Contract, #1103)")]
+fn test_create_round_invalid_deadline() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+
+    client.create_round(&1, &tree.root, &1000, &1000000);
+}
+
+// Tests creating a round with a number already in use.
+// Expects: RoundAlreadyExists error (Error #1104).
+#[test]
+#[should_panic(expected = "Error(Contract, #1104)")]
+fn test_create_round_duplicate() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.create_round(&1, &tree.root, &1000, &1086400);
+}
+
+// Tests claiming every entry of a round with valid proofs.
+// Expects: Each claimant receives their amount and is marked as claimed.
+#[test]
+fn test_claim() {
+    let e = setup_test_env();
+    let (client, token, _, tree) = setup_airdrop(&e);
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    for (i, (address, amount)) in tree.entries.iter().enumerate() {
+        client.claim(address, &1, amount, &tree.proofs[i]);
+        assert_eq!(token.balance(address), *amount);
+        assert!(client.has_claimed(&1, address));
+    }
+
+    assert_eq!(client.get_round(&1).claimed, 1000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// Tests claiming the same entry twice.
+// Expects: AlreadyClaimed error (Error #1106).
+#[test]
+#[should_panic(expected = "Error(Contract, #1106)")]
+fn test_double_claim() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+    let (alice, amount) = &tree.entries[0];
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.claim(alice, &1, amount, &tree.proofs[0]);
+    client.claim(alice, &1, amount, &tree.proofs[0]);
+}
+
+// Tests claiming a larger amount than the entry holds.
+// Expects: InvalidProof error (Error #16).
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_claim_invalid_proof() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+    let (alice, _) = &tree.entries[0];
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.claim(alice, &1, &400, &tree.proofs[0]);
+}
+
+// Tests claiming after the round deadline.
+// Expects: ClaimPeriodEnded error (Error #1108).
+#[test]
+#[should_panic(expected = "Error(Contract, #1108)")]
+fn test_claim_after_deadline() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+    let (alice, amount) = &tree.entries[0];
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1086401);
+    client.claim(alice, &1, amount, &tree.proofs[0]);
+}
+
+// Tests claiming the same entry in separate rounds sharing a root.
+// Expects: Claims are tracked per round, so each round pays out once.
+#[test]
+fn test_multi_round_claims() {
+    let e = setup_test_env();
+    let (client, token, _, tree) = setup_airdrop(&e);
+    let (alice, amount) = &tree.entries[0];
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.create_round(&2, &tree.root, &1000, &1172800);
+    client.claim(alice, &1, amount, &tree.proofs[0]);
+    client.claim(alice, &2, amount, &tree.proofs[0]);
+
+    assert_eq!(token.balance(alice), 200);
+    assert!(client.has_claimed(&1, alice));
+    assert!(client.has_claimed(&2, alice));
+}
+
+// Tests sweeping unclaimed tokens after the deadline.
+// Expects: The unclaimed remainder returns to the admin and the round is marked swept.
+#[test]
+fn test_sweep() {
+    let e = setup_test_env();
+    let (client, token, admin, tree) = setup_airdrop(&e);
+    let (alice, amount) = &tree.entries[0];
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.claim(alice, &1, amount, &tree.proofs[0]);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1086401);
+
+    assert_eq!(client.sweep(&1), 900);
+    assert_eq!(token.balance(&admin), 9900);
+    assert!(client.get_round(&1).swept);
+    assert_eq!(
+        client.try_sweep(&1),
+        Err(Ok(AirdropContractErrors::RoundAlreadySwept))
+    );
+}
+
+// Tests sweeping before the claim deadline.
+// Expects: ClaimPeriodActive error (Error #1109).
+#[test]
+#[should_panic(expected = "Error(Contract, #1109)")]
+fn test_sweep_before_deadline() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+
+    client.create_round(&1, &tree.root, &1000, &1086400);
+    client.sweep(&1);
+}
+
+// Tests claiming from a round funded below its entries.
+// Expects: InsufficientRoundFunds error (Error #1111).
+#[test]
+#[should_panic(expected = "Error(Contract, #1111)")]
+fn test_claim_underfunded_round() {
+    let e = setup_test_env();
+    let (client, _, _, tree) = setup_airdrop(&e);
+    let (dave, amount) = &tree.entries[3];
+
+    client.create_round(&1, &tree.root, &300, &1086400);
+    client.claim(dave, &1, amount, &tree.proofs[3]);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version and no support for any governance feature.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_airdrop(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1100 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        AirdropContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        AirdropContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        AirdropContractErrors::InvalidProof as u32,
+        GovernanceError::InvalidProof as u32
+    );
+    for error in [
+        AirdropContractErrors::InvalidRound,
+        AirdropContractErrors::RoundAlreadyExists,
+        AirdropContractErrors::RoundNotFound,
+        AirdropContractErrors::AlreadyClaimed,
+        AirdropContractErrors::ClaimPeriodEnded,
+        AirdropContractErrors::ClaimPeriodActive,
+        AirdropContractErrors::RoundAlreadySwept,
+        AirdropContractErrors::InsufficientRoundFunds,
+    ] {
+        assert!(in_range(error as u32, AIRDROP_CODES));
+    }
+}
@@ -0,0 +1,274 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, Env, Symbol,
+};
+
+pub use pg_contracts_types::dual_token_weight::{
+    DualTokenWeightCoefficients, DualTokenWeightVesting,
+};
+
+// --- Coefficient Scale ---
+const COEFFICIENT_SCALE: i128 = 10_000; // Basis points, 10_000 = 1.0x
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_WEIGHT];
+
+// Interface of the vesting contract holding team and investor allocations
+#[contractclient(name = "VestingClient")]
+pub trait VestingInterface {
+    fn vested_balance(env: Env, user: Address) -> i128;
+    fn unvested_balance(env: Env, user: Address) -> i128;
+}
+
+// Defines the structure for instance storage
+#[contracttype]
+pub enum DualTokenWeightContractDataKey {
+    Admin,        // Contract administrator address
+    GovToken,     // Governance (stake) token address
+    RepToken,     // Reputation (earned) token address
+    Coefficients, // Weighting coefficients applied to both balances
+    Vesting,      // Optional vesting contract counted toward the governance balance
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 300-399 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DualTokenWeightContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidCoefficient = 303,       // A coefficient is negative or both are zero
+}
+
+#[contract]
+pub struct DualTokenWeightContract;
+
+#[contractimpl]
+impl DualTokenWeightContract {
+    // --- Helper Functions ---
+
+    // Rejects negative coefficients and configurations that would always yield zero
+    fn validate_coefficients(
+        coefficients: &DualTokenWeightCoefficients,
+    ) -> Result<(), DualTokenWeightContractErrors> {
+        if coefficients.gov_coefficient < 0 || coefficients.rep_coefficient < 0 {
+            return Err(DualTokenWeightContractErrors::InvalidCoefficient);
+        }
+        if coefficients.gov_coefficient == 0 && coefficients.rep_coefficient == 0 {
+            return Err(DualTokenWeightContractErrors::InvalidCoefficient);
+        }
+        Ok(())
+    }
+
+    // Loads an address entry from instance storage
+    fn read_address(
+        env: &Env,
+        key: &DualTokenWeightContractDataKey,
+    ) -> Result<Address, DualTokenWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(key)
+            .ok_or(DualTokenWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the weighting coefficients from instance storage
+    fn read_coefficients(
+        env: &Env,
+    ) -> Result<DualTokenWeightCoefficients, DualTokenWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&DualTokenWeightContractDataKey::Coefficients)
+            .ok_or(DualTokenWeightContractErrors::ContractNotInitialized)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, both token addresses, and coefficients
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        gov_token: Address,
+        rep_token: Address,
+        gov_coefficient: i128,
+        rep_coefficient: i128,
+    ) -> Result<(), DualTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&DualTokenWeightContractDataKey::Admin)
+        {
+            return Err(DualTokenWeightContractErrors::ContractAlreadyInitialized);
+        }
+        let coefficients = DualTokenWeightCoefficients {
+            gov_coefficient,
+            rep_coefficient,
+        };
+        Self::validate_coefficients(&coefficients)?;
+
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::GovToken, &gov_token);
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::RepToken, &rep_token);
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::Coefficients, &coefficients);
+        Ok(())
+    }
+
+    // Updates the weighting coefficients (admin only)
+    pub fn set_coefficients(
+        env: Env,
+        gov_coefficient: i128,
+        rep_coefficient: i128,
+    ) -> Result<(), DualTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_address(&env, &DualTokenWeightContractDataKey::Admin)?;
+        admin.require_auth();
+
+        let coefficients = DualTokenWeightCoefficients {
+            gov_coefficient,
+            rep_coefficient,
+        };
+        Self::validate_coefficients(&coefficients)?;
+
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::Coefficients, &coefficients);
+
+        env.events().publish(
+            ("COEFFICIENTS", "UPDATED"),
+            (gov_coefficient, rep_coefficient),
+        );
+        Ok(())
+    }
+
+    // Sets or clears the vesting contract counted toward the governance balance (admin only)
+    pub fn set_vesting(
+        env: Env,
+        vesting: Option<Address>,
+        include_unvested: bool,
+    ) -> Result<(), DualTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_address(&env, &DualTokenWeightContractDataKey::Admin)?;
+        admin.require_auth();
+
+        match vesting.clone() {
+            Some(contract) => env.storage().instance().set(
+                &DualTokenWeightContractDataKey::Vesting,
+                &DualTokenWeightVesting {
+                    contract,
+                    include_unvested,
+                },
+            ),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DualTokenWeightContractDataKey::Vesting),
+        }
+
+        env.events()
+            .publish(("VESTING", "UPDATED"), (vesting, include_unvested));
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), DualTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_address(&env, &DualTokenWeightContractDataKey::Admin)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DualTokenWeightContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns w = (a * gov + b * rep) / 10_000 using current balances, where gov includes
+    // vested (and optionally unvested) tokens held by the configured vesting contract
+    pub fn calculate_weight(
+        env: Env,
+        user: Address,
+        _snapshot_time: u64,
+    ) -> Result<i128, DualTokenWeightContractErrors> {
+        let gov_token = Self::read_address(&env, &DualTokenWeightContractDataKey::GovToken)?;
+        let rep_token = Self::read_address(&env, &DualTokenWeightContractDataKey::RepToken)?;
+        let coefficients = Self::read_coefficients(&env)?;
+
+        let mut gov_balance = TokenClient::new(&env, &gov_token).balance(&user).max(0);
+        if let Some(vesting) = Self::get_vesting(env.clone()) {
+            let vesting_client = VestingClient::new(&env, &vesting.contract);
+            gov_balance = gov_balance.saturating_add(vesting_client.vested_balance(&user).max(0));
+            if vesting.include_unvested {
+                gov_balance =
+                    gov_balance.saturating_add(vesting_client.unvested_balance(&user).max(0));
+            }
+        }
+        let rep_balance = TokenClient::new(&env, &rep_token).balance(&user).max(0);
+
+        let weighted_sum = gov_balance
+            .saturating_mul(coefficients.gov_coefficient)
+            .saturating_add(rep_balance.saturating_mul(coefficients.rep_coefficient));
+        Ok(weighted_sum / COEFFICIENT_SCALE)
+    }
+
+    // Returns the configured coefficients
+    pub fn get_coefficients(
+        env: Env,
+    ) -> Result<DualTokenWeightCoefficients, DualTokenWeightContractErrors> {
+        Self::read_coefficients(&env)
+    }
+
+    // Returns the vesting contract configuration, if any
+    pub fn get_vesting(env: Env) -> Option<DualTokenWeightVesting> {
+        env.storage()
+            .instance()
+            .get(&DualTokenWeightContractDataKey::Vesting)
+    }
+
+    // Returns the governance and reputation token addresses
+    pub fn get_tokens(env: Env) -> Result<(Address, Address), DualTokenWeightContractErrors> {
+        let gov_token = Self::read_address(&env, &DualTokenWeightContractDataKey::GovToken)?;
+        let rep_token = Self::read_address(&env, &DualTokenWeightContractDataKey::RepToken)?;
+        Ok((gov_token, rep_token))
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
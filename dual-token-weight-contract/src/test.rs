@@ -0,0 +1,303 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, DUAL_TOKEN_WEIGHT_CODES};
+use governance_testutils::create_token_contract;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token::StellarAssetClient,
+    Address, Env,
+};
+
+// Vesting mock: reports fixed vested and unvested balances for every user
+#[contract]
+pub struct MockVestingContract;
+
+#[contractimpl]
+impl MockVestingContract {
+    pub fn set_balances(env: Env, vested: i128, unvested: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VESTED"), &vested);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("UNVESTED"), &unvested);
+    }
+
+    pub fn vested_balance(env: Env, _user: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("VESTED"))
+            .unwrap_or(0)
+    }
+
+    pub fn unvested_balance(env: Env, _user: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("UNVESTED"))
+            .unwrap_or(0)
+    }
+}
+
+fn create_weight_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    gov_token: &Address,
+    rep_token: &Address,
+    gov_coefficient: i128,
+    rep_coefficient: i128,
+) -> DualTokenWeightContractClient<'a> {
+    let contract_address = e.register(
+        DualTokenWeightContract,
+        DualTokenWeightContractArgs::__constructor(
+            admin,
+            gov_token,
+            rep_token,
+            &gov_coefficient,
+            &rep_coefficient,
+        ),
+    );
+    DualTokenWeightContractClient::new(e, &contract_address)
+}
+
+fn setup_test_env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e
+}
+
+// Tests successful initialization with both tokens and coefficients.
+// Expects: Stored coefficients and token addresses match the constructor arguments.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let gov_token = Address::generate(&e);
+    let rep_token = Address::generate(&e);
+    let client = create_weight_contract(&e, &admin, &gov_token, &rep_token, 10_000, 5_000);
+
+    let coefficients = client.get_coefficients();
+    assert_eq!(coefficients.gov_coefficient, 10_000);
+    assert_eq!(coefficients.rep_coefficient, 5_000);
+    assert_eq!(client.get_tokens(), (gov_token, rep_token));
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let gov_token = Address::generate(&e);
+    let rep_token = Address::generate(&e);
+    let client = create_weight_contract(&e, &admin, &gov_token, &rep_token, 10_000, 5_000);
+
+    e.register_at(
+        &client.address,
+        DualTokenWeightContract,
+        DualTokenWeightContractArgs::__constructor(&admin, &gov_token, &rep_token, &10_000, &5_000),
+    );
+}
+
+// Tests weight combination across both balances.
+// Expects: w = (1.0 * 1000 + 0.5 * 400) = 1200 for the configured coefficients.
+#[test]
+fn test_calculate_weight() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let gov_token = create_token_contract(&e, &admin);
+    let rep_token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &gov_token.address).mint(&user, &1000);
+    StellarAssetClient::new(&e, &rep_token.address).mint(&user, &400);
+
+    let client = create_weight_contract(
+        &e,
+        &admin,
+        &gov_token.address,
+        &rep_token.address,
+        10_000,
+        5_000,
+    );
+
+    assert_eq!(client.calculate_weight(&user, &0), 1200);
+}
+
+// Tests that a holder of only the reputation token still receives weight.
+// Expects: Weight derived solely from the reputation balance and its coefficient.
+#[test]
+fn test_calculate_weight_reputation_only() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let gov_token = create_token_contract(&e, &admin);
+    let rep_token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &rep_token.address).mint(&user, &300);
+
+    let client = create_weight_contract(
+        &e,
+        &admin,
+        &gov_token.address,
+        &rep_token.address,
+        10_000,
+        20_000,
+    );
+
+    assert_eq!(client.calculate_weight(&user, &0), 600);
+}
+
+// Tests admin coefficient updates take effect immediately.
+// Expects: Weight recalculated using the new coefficients.
+#[test]
+fn test_set_coefficients() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let gov_token = create_token_contract(&e, &admin);
+    let rep_token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &gov_token.address).mint(&user, &1000);
+    StellarAssetClient::new(&e, &rep_token.address).mint(&user, &1000);
+
+    let client = create_weight_contract(
+        &e,
+        &admin,
+        &gov_token.address,
+        &rep_token.address,
+        10_000,
+        0,
+    );
+    assert_eq!(client.calculate_weight(&user, &0), 1000);
+
+    client.set_coefficients(&2_500, &7_500);
+    assert_eq!(client.calculate_weight(&user, &0), 1000);
+    assert_eq!(client.get_coefficients().rep_coefficient, 7_500);
+}
+
+// Tests rejection of negative coefficients.
+// Expects: InvalidCoefficient error (Error #303) when a coefficient is below zero.
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn test_negative_coefficient() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let gov_token = Address::generate(&e);
+    let rep_token = Address::generate(&e);
+    let client = create_weight_contract(&e, &admin, &gov_token, &rep_token, 10_000, 5_000);
+
+    client.set_coefficients(&-1, &5_000);
+}
+
+// Tests rejection of an all-zero coefficient configuration.
+// Expects: InvalidCoefficient error (Error #303) since every weight would be zero.
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn test_zero_coefficients() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let gov_token = Address::generate(&e);
+    let rep_token = Address::generate(&e);
+
+    create_weight_contract(&e, &admin, &gov_token, &rep_token, 0, 0);
+}
+
+// Tests counting only vested tokens from a vesting contract.
+// Expects: Vested tokens add to the governance balance while unvested tokens are ignored.
+#[test]
+fn test_calculate_weight_vested_only() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let gov_token = create_token_contract(&e, &admin);
+    let rep_token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &gov_token.address).mint(&user, &1000);
+
+    let vesting_address = e.register(MockVestingContract, ());
+    MockVestingContractClient::new(&e, &vesting_address).set_balances(&400, &600);
+
+    let client = create_weight_contract(
+        &e,
+        &admin,
+        &gov_token.address,
+        &rep_token.address,
+        10_000,
+        0,
+    );
+    client.set_vesting(&Some(vesting_address.clone()), &false);
+
+    assert_eq!(client.calculate_weight(&user, &0), 1400);
+    assert_eq!(
+        client.get_vesting(),
+        Some(DualTokenWeightVesting {
+            contract: vesting_address,
+            include_unvested: false,
+        })
+    );
+}
+
+// Tests counting both vested and unvested tokens, then clearing the vesting contract.
+// Expects: All allocated tokens count until the vesting contract is removed.
+#[test]
+fn test_calculate_weight_including_unvested() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let gov_token = create_token_contract(&e, &admin);
+    let rep_token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &gov_token.address).mint(&user, &1000);
+
+    let vesting_address = e.register(MockVestingContract, ());
+    MockVestingContractClient::new(&e, &vesting_address).set_balances(&400, &600);
+
+    let client =
+        create_weight_contract(&e, &admin, &gov_token.address, &rep_token.address, 5_000, 0);
+    client.set_vesting(&Some(vesting_address), &true);
+    assert_eq!(client.calculate_weight(&user, &0), 1000);
+
+    client.set_vesting(&None, &false);
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+    assert_eq!(client.get_vesting(), None);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_weight_contract(
+        &e,
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &Address::generate(&e),
+        10_000,
+        5_000,
+    );
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 300 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        DualTokenWeightContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        DualTokenWeightContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert!(in_range(
+        DualTokenWeightContractErrors::InvalidCoefficient as u32,
+        DUAL_TOKEN_WEIGHT_CODES
+    ));
+}
@@ -0,0 +1,573 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol,
+    Vec,
+};
+
+pub use pg_contracts_types::election::{ElectionData, ElectionMode};
+
+// --- Election Constraints ---
+const MAX_CANDIDATES: u32 = 50; // Maximum candidates registered per election
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const ELECTIONS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
+const VOTE_TTL_EXTENSION: u32 = 1_600_000; // ~18.5 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 0] = [];
+
+// Interface of the council contract whose membership is set by finalized elections
+#[contractclient(name = "CouncilClient")]
+pub trait CouncilInterface {
+    fn set_members(env: Env, caller: Address, members: Vec<Address>);
+}
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum ElectionContractDataKey {
+    Admin,                  // Contract administrator address
+    Token,                  // Governance token used for stakes and voting weight
+    Council,                // Council contract receiving elected members
+    CandidateStake,         // Stake locked by each candidate on registration
+    Election(Symbol),       // Election configuration and candidate list
+    Tally(Symbol),          // Votes received per candidate
+    Voted(Symbol, Address), // Tracks whether a voter has voted in an election
+    Stake(Symbol, Address), // Stake locked by a candidate in an election
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 500-599 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElectionContractErrors {
+    ContractNotInitialized = 1,       // The contract has not been initialized
+    ContractAlreadyInitialized = 2,   // The contract has already been initialized
+    InvalidStake = 503,               // Candidate stake must not be negative
+    ElectionAlreadyExists = 504,      // An election with this id already exists
+    ElectionNotFound = 505,           // The election does not exist
+    InvalidElectionTimes = 506,       // Registration must end before voting ends
+    InvalidSeats = 507,               // Seats must be greater than zero
+    RegistrationClosed = 508,         // The candidate registration window is closed
+    CandidateAlreadyRegistered = 509, // The candidate is already registered
+    TooManyCandidates = 510,          // The election has reached its candidate limit
+    VotingNotActive = 7,              // The voting window is not open
+    CandidateNotFound = 512,          // The candidate is not registered
+    UserAlreadyVoted = 5,             // The voter has already voted in this election
+    UserCannotVote = 6,               // The voter holds no governance tokens
+    ElectionNotEnded = 515,           // The voting window has not ended yet
+    ElectionAlreadyFinalized = 516,   // The election has already been finalized
+    ElectionNotFinalized = 517,       // The election has not been finalized yet
+    StakeNotFound = 518,              // No stake is locked for this candidate
+    WrongElectionMode = 519,          // The vote does not match the election mode
+    InvalidAllocation = 520,          // Allocations must be non-empty and positive
+    AllocationExceedsBudget = 521,    // Allocations exceed seats x voter weight
+}
+
+#[contract]
+pub struct ElectionContract;
+
+#[contractimpl]
+impl ElectionContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, ElectionContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ElectionContractDataKey::Admin)
+            .ok_or(ElectionContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, ElectionContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ElectionContractDataKey::Token)
+            .ok_or(ElectionContractErrors::ContractNotInitialized)
+    }
+
+    // Loads an election from persistent storage
+    fn read_election(env: &Env, id: &Symbol) -> Result<ElectionData, ElectionContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&ElectionContractDataKey::Election(id.clone()))
+            .ok_or(ElectionContractErrors::ElectionNotFound)
+    }
+
+    // Saves an election and extends its TTL
+    fn write_election(env: &Env, id: &Symbol, election: &ElectionData) {
+        let election_key = ElectionContractDataKey::Election(id.clone());
+        env.storage().persistent().set(&election_key, election);
+        env.storage().persistent().extend_ttl(
+            &election_key,
+            ELECTIONS_TTL_EXTENSION,
+            ELECTIONS_TTL_EXTENSION,
+        );
+    }
+
+    // Loads the votes received per candidate
+    fn read_tally(env: &Env, id: &Symbol) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&ElectionContractDataKey::Tally(id.clone()))
+            .unwrap_or(Map::new(env))
+    }
+
+    // Saves the votes received per candidate and extends its TTL
+    fn write_tally(env: &Env, id: &Symbol, tally: &Map<Address, i128>) {
+        let tally_key = ElectionContractDataKey::Tally(id.clone());
+        env.storage().persistent().set(&tally_key, tally);
+        env.storage().persistent().extend_ttl(
+            &tally_key,
+            ELECTIONS_TTL_EXTENSION,
+            ELECTIONS_TTL_EXTENSION,
+        );
+    }
+
+    // Validates that the voting window of an election is open
+    fn require_voting_active(
+        env: &Env,
+        election: &ElectionData,
+    ) -> Result<(), ElectionContractErrors> {
+        let ledger_time = env.ledger().timestamp();
+        if ledger_time <= election.registration_end || ledger_time > election.voting_end {
+            return Err(ElectionContractErrors::VotingNotActive);
+        }
+        Ok(())
+    }
+
+    // Validates the voting window and eligibility of a voter, returning the election and weight
+    fn prepare_vote(
+        env: &Env,
+        voter: &Address,
+        id: &Symbol,
+        mode: ElectionMode,
+    ) -> Result<(ElectionData, i128), ElectionContractErrors> {
+        let election = Self::read_election(env, id)?;
+        if election.mode != mode {
+            return Err(ElectionContractErrors::WrongElectionMode);
+        }
+        Self::require_voting_active(env, &election)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&ElectionContractDataKey::Voted(id.clone(), voter.clone()))
+        {
+            return Err(ElectionContractErrors::UserAlreadyVoted);
+        }
+
+        let token_address = Self::read_token(env)?;
+        let weight = TokenClient::new(env, &token_address).balance(voter);
+        if weight <= 0 {
+            return Err(ElectionContractErrors::UserCannotVote);
+        }
+        Ok((election, weight))
+    }
+
+    // Marks a voter as having voted in an election
+    fn record_voted(env: &Env, voter: &Address, id: &Symbol) {
+        let voted_key = ElectionContractDataKey::Voted(id.clone(), voter.clone());
+        env.storage().persistent().set(&voted_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&voted_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+    }
+
+    // Selects the top-N candidates by votes through the shared plurality count, breaking ties
+    // by registration order
+    fn select_winners(
+        env: &Env,
+        election: &ElectionData,
+        tally: &Map<Address, i128>,
+    ) -> Vec<Address> {
+        let mut totals = Vec::new(env);
+        for candidate in election.candidates.iter() {
+            totals.push_back(tally.get(candidate).unwrap_or(0));
+        }
+        let mut elected = Vec::new(env);
+        for index in governance_core::plurality(env, &totals, election.seats).iter() {
+            elected.push_back(election.candidates.get_unchecked(index));
+        }
+        elected
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, governance token, council contract, and candidate stake
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+        council: Address,
+        candidate_stake: i128,
+    ) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&ElectionContractDataKey::Admin)
+        {
+            return Err(ElectionContractErrors::ContractAlreadyInitialized);
+        }
+        if candidate_stake < 0 {
+            return Err(ElectionContractErrors::InvalidStake);
+        }
+
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::Council, &council);
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::CandidateStake, &candidate_stake);
+        Ok(())
+    }
+
+    // Creates an election with a registration window followed by a voting window (admin only)
+    pub fn create_election(
+        env: Env,
+        id: Symbol,
+        seats: u32,
+        registration_end: u64,
+        voting_end: u64,
+        mode: ElectionMode,
+    ) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if env
+            .storage()
+            .persistent()
+            .has(&ElectionContractDataKey::Election(id.clone()))
+        {
+            return Err(ElectionContractErrors::ElectionAlreadyExists);
+        }
+        if seats == 0 || seats > MAX_CANDIDATES {
+            return Err(ElectionContractErrors::InvalidSeats);
+        }
+        let ledger_time = env.ledger().timestamp();
+        if registration_end <= ledger_time || voting_end <= registration_end {
+            return Err(ElectionContractErrors::InvalidElectionTimes);
+        }
+
+        let stake: i128 = env
+            .storage()
+            .instance()
+            .get(&ElectionContractDataKey::CandidateStake)
+            .unwrap_or(0);
+        let election = ElectionData {
+            seats,
+            mode,
+            registration_end,
+            voting_end,
+            stake,
+            candidates: Vec::new(&env),
+            elected: Vec::new(&env),
+            finalized: false,
+        };
+        Self::write_election(&env, &id, &election);
+
+        env.events().publish(
+            ("ELECTION", "CREATED", id),
+            (seats, mode, registration_end, voting_end),
+        );
+        Ok(())
+    }
+
+    // Registers a candidate during the registration window, locking the candidate stake
+    pub fn register_candidate(
+        env: Env,
+        candidate: Address,
+        id: Symbol,
+    ) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        candidate.require_auth();
+
+        let mut election = Self::read_election(&env, &id)?;
+        if env.ledger().timestamp() > election.registration_end {
+            return Err(ElectionContractErrors::RegistrationClosed);
+        }
+        if election.candidates.contains(&candidate) {
+            return Err(ElectionContractErrors::CandidateAlreadyRegistered);
+        }
+        if election.candidates.len() >= MAX_CANDIDATES {
+            return Err(ElectionContractErrors::TooManyCandidates);
+        }
+
+        if election.stake > 0 {
+            let token_address = Self::read_token(&env)?;
+            TokenClient::new(&env, &token_address).transfer(
+                &candidate,
+                &env.current_contract_address(),
+                &election.stake,
+            );
+            let stake_key = ElectionContractDataKey::Stake(id.clone(), candidate.clone());
+            env.storage().persistent().set(&stake_key, &election.stake);
+            env.storage().persistent().extend_ttl(
+                &stake_key,
+                ELECTIONS_TTL_EXTENSION,
+                ELECTIONS_TTL_EXTENSION,
+            );
+        }
+
+        election.candidates.push_back(candidate.clone());
+        Self::write_election(&env, &id, &election);
+
+        env.events()
+            .publish(("CANDIDATE", "REGISTERED", id), (candidate, election.stake));
+        Ok(())
+    }
+
+    // Casts a single-mode vote for a candidate weighted by the voter's token balance
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        id: Symbol,
+        candidate: Address,
+    ) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        voter.require_auth();
+
+        let (election, weight) = Self::prepare_vote(&env, &voter, &id, ElectionMode::Single)?;
+        if !election.candidates.contains(&candidate) {
+            return Err(ElectionContractErrors::CandidateNotFound);
+        }
+
+        let mut tally = Self::read_tally(&env, &id);
+        let votes = tally.get(candidate.clone()).unwrap_or(0);
+        tally.set(candidate.clone(), votes.saturating_add(weight));
+        Self::write_tally(&env, &id, &tally);
+        Self::record_voted(&env, &voter, &id);
+
+        env.events()
+            .publish(("ELECTION", "VOTE", id), (voter, candidate, weight));
+        Ok(())
+    }
+
+    // Casts a cumulative-mode vote splitting seats x weight points across candidates
+    pub fn vote_cumulative(
+        env: Env,
+        voter: Address,
+        id: Symbol,
+        allocations: Map<Address, i128>,
+    ) -> Result<i128, ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        voter.require_auth();
+
+        let (election, weight) = Self::prepare_vote(&env, &voter, &id, ElectionMode::Cumulative)?;
+        if allocations.is_empty() {
+            return Err(ElectionContractErrors::InvalidAllocation);
+        }
+
+        let budget = weight.saturating_mul(election.seats as i128);
+        let mut allocated: i128 = 0;
+        let mut tally = Self::read_tally(&env, &id);
+        for (candidate, points) in allocations.iter() {
+            if points <= 0 {
+                return Err(ElectionContractErrors::InvalidAllocation);
+            }
+            if !election.candidates.contains(&candidate) {
+                return Err(ElectionContractErrors::CandidateNotFound);
+            }
+            allocated = allocated
+                .checked_add(points)
+                .filter(|total| *total <= budget)
+                .ok_or(ElectionContractErrors::AllocationExceedsBudget)?;
+            let votes = tally.get(candidate.clone()).unwrap_or(0);
+            tally.set(candidate, votes.saturating_add(points));
+        }
+        Self::write_tally(&env, &id, &tally);
+        Self::record_voted(&env, &voter, &id);
+
+        env.events()
+            .publish(("ELECTION", "ALLOCATED", id), (voter, allocations, budget));
+        Ok(allocated)
+    }
+
+    // Elects the top-N candidates after voting ends and seats them on the council
+    pub fn finalize_election(env: Env, id: Symbol) -> Result<Vec<Address>, ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut election = Self::read_election(&env, &id)?;
+        if election.finalized {
+            return Err(ElectionContractErrors::ElectionAlreadyFinalized);
+        }
+        if env.ledger().timestamp() <= election.voting_end {
+            return Err(ElectionContractErrors::ElectionNotEnded);
+        }
+
+        let tally = Self::read_tally(&env, &id);
+        let elected = Self::select_winners(&env, &election, &tally);
+        election.elected = elected.clone();
+        election.finalized = true;
+        Self::write_election(&env, &id, &election);
+
+        let council: Address = env
+            .storage()
+            .instance()
+            .get(&ElectionContractDataKey::Council)
+            .ok_or(ElectionContractErrors::ContractNotInitialized)?;
+        CouncilClient::new(&env, &council).set_members(&env.current_contract_address(), &elected);
+
+        env.events()
+            .publish(("ELECTION", "FINALIZED", id), elected.clone());
+        Ok(elected)
+    }
+
+    // Returns a candidate's locked stake once the election has been finalized
+    pub fn withdraw_stake(
+        env: Env,
+        candidate: Address,
+        id: Symbol,
+    ) -> Result<i128, ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        candidate.require_auth();
+
+        let election = Self::read_election(&env, &id)?;
+        if !election.finalized {
+            return Err(ElectionContractErrors::ElectionNotFinalized);
+        }
+        let stake_key = ElectionContractDataKey::Stake(id.clone(), candidate.clone());
+        let stake: i128 = env
+            .storage()
+            .persistent()
+            .get(&stake_key)
+            .ok_or(ElectionContractErrors::StakeNotFound)?;
+        env.storage().persistent().remove(&stake_key);
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &candidate,
+            &stake,
+        );
+
+        env.events()
+            .publish(("STAKE", "WITHDRAWN", id), (candidate, stake));
+        Ok(stake)
+    }
+
+    // Updates the stake required from candidates of future elections (admin only)
+    pub fn set_candidate_stake(
+        env: Env,
+        candidate_stake: i128,
+    ) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if candidate_stake < 0 {
+            return Err(ElectionContractErrors::InvalidStake);
+        }
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::CandidateStake, &candidate_stake);
+
+        env.events().publish(("STAKE", "UPDATED"), candidate_stake);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), ElectionContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&ElectionContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the configuration and state of an election
+    pub fn get_election(env: Env, id: Symbol) -> Result<ElectionData, ElectionContractErrors> {
+        Self::read_election(&env, &id)
+    }
+
+    // Returns each registered candidate with the votes received so far
+    pub fn get_results(
+        env: Env,
+        id: Symbol,
+    ) -> Result<Vec<(Address, i128)>, ElectionContractErrors> {
+        let election = Self::read_election(&env, &id)?;
+        let tally = Self::read_tally(&env, &id);
+        let mut results = Vec::new(&env);
+        for candidate in election.candidates.iter() {
+            let votes = tally.get(candidate.clone()).unwrap_or(0);
+            results.push_back((candidate, votes));
+        }
+        Ok(results)
+    }
+
+    // Returns whether a voter has voted in an election
+    pub fn has_voted(env: Env, voter: Address, id: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ElectionContractDataKey::Voted(id, voter))
+    }
+
+    // Returns the stake still locked by a candidate in an election
+    pub fn get_stake(env: Env, candidate: Address, id: Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ElectionContractDataKey::Stake(id, candidate))
+            .unwrap_or(0)
+    }
+
+    // Returns the stake required from candidates of new elections
+    pub fn get_candidate_stake(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&ElectionContractDataKey::CandidateStake)
+            .unwrap_or(0)
+    }
+
+    // Returns the council contract address
+    pub fn get_council(env: Env) -> Result<Address, ElectionContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ElectionContractDataKey::Council)
+            .ok_or(ElectionContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
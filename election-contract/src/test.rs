@@ -0,0 +1,522 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, ELECTION_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+// Council mock: records the members seated by the last election
+#[contract]
+pub struct MockCouncilContract;
+
+#[contractimpl]
+impl MockCouncilContract {
+    pub fn set_members(env: Env, caller: Address, members: Vec<Address>) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("CALLER"), &caller);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("MEMBERS"), &members);
+    }
+
+    pub fn members(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("MEMBERS"))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn caller(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("CALLER"))
+    }
+}
+
+fn create_council_contract<'a>(e: &Env) -> MockCouncilContractClient<'a> {
+    let council_address = e.register(MockCouncilContract, ());
+    MockCouncilContractClient::new(e, &council_address)
+}
+
+fn create_election_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+    council_address: &Address,
+    candidate_stake: i128,
+) -> ElectionContractClient<'a> {
+    let contract_address = e.register(
+        ElectionContract,
+        ElectionContractArgs::__constructor(
+            admin,
+            token_address,
+            council_address,
+            &candidate_stake,
+        ),
+    );
+    ElectionContractClient::new(e, &contract_address)
+}
+
+// Deploys token, council, and election contracts with a 100-token candidate stake
+fn setup_election<'a>(
+    e: &Env,
+) -> (
+    ElectionContractClient<'a>,
+    TokenClient<'a>,
+    MockCouncilContractClient<'a>,
+) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let council = create_council_contract(e);
+    let client = create_election_contract(e, &admin, &token.address, &council.address, 100);
+    (client, token, council)
+}
+
+// Tests successful initialization with admin, token, council, and candidate stake.
+// Expects: Stored council and stake match constructor arguments.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _, council) = setup_election(&e);
+
+    assert_eq!(client.get_council(), council.address);
+    assert_eq!(client.get_candidate_stake(), 100);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let (client, token, council) = setup_election(&e);
+
+    e.register_at(
+        &client.address,
+        ElectionContract,
+        ElectionContractArgs::__constructor(&admin, &token.address, &council.address, &100),
+    );
+}
+
+// Tests election creation with a registration window followed by a voting window.
+// Expects: Stored election captures seats, windows, and the current candidate stake.
+#[test]
+fn test_create_election() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_election(&e);
+    let id = symbol_short!("ELECT1");
+
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+
+    let election = client.get_election(&id);
+    assert_eq!(election.seats, 3);
+    assert_eq!(election.mode, ElectionMode::Single);
+    assert_eq!(election.registration_end, 1100000);
+    assert_eq!(election.voting_end, 1200000);
+    assert_eq!(election.stake, 100);
+    assert!(election.candidates.is_empty());
+    assert!(!election.finalized);
+}
+
+// Tests election creation with a voting window ending before registration closes.
+// Expects: InvalidElectionTimes error (Error #506).
+#[test]
+#[should_panic(expected = "Error(Contract, #506)")]
+fn test_invalid_election_times() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_election(&e);
+
+    client.create_election(
+        &symbol_short!("ELECT1"),
+        &3,
+        &1100000,
+        &1100000,
+        &ElectionMode::Single,
+    );
+}
+
+// Tests candidate registration locking the candidate stake in the contract.
+// Expects: Stake moves from the candidate to the contract and is tracked per election.
+#[test]
+fn test_register_candidate_locks_stake() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &250);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+
+    assert_eq!(token.balance(&candidate), 150);
+    assert_eq!(token.balance(&client.address), 100);
+    assert_eq!(client.get_stake(&candidate, &id), 100);
+    assert_eq!(client.get_election(&id).candidates, vec![&e, candidate]);
+}
+
+// Tests candidate registration after the registration window has closed.
+// Expects: RegistrationClosed error (Error #508).
+#[test]
+#[should_panic(expected = "Error(Contract, #508)")]
+fn test_register_after_registration_closed() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1100001);
+    client.register_candidate(&candidate, &id);
+}
+
+// Tests registering the same candidate twice in one election.
+// Expects: CandidateAlreadyRegistered error (Error #509).
+#[test]
+#[should_panic(expected = "Error(Contract, #509)")]
+fn test_candidate_already_registered() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &200);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    client.register_candidate(&candidate, &id);
+}
+
+// Tests voting while candidate registration is still open.
+// Expects: VotingNotActive error (Error #7).
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_vote_during_registration() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    stellar_asset.mint(&voter, &50);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    client.vote(&voter, &id, &candidate);
+}
+
+// Tests a voter casting a second vote in the same election.
+// Expects: UserAlreadyVoted error (Error #5).
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_user_already_voted() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    stellar_asset.mint(&voter, &50);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1150000);
+    client.vote(&voter, &id, &candidate);
+    client.vote(&voter, &id, &candidate);
+}
+
+// Tests voting without holding governance tokens.
+// Expects: UserCannotVote error (Error #6).
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_user_cannot_vote() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1150000);
+    client.vote(&voter, &id, &candidate);
+}
+
+// Tests finalization seating the top-N candidates on the council contract.
+// Expects: Highest-voted candidates are elected in order and pushed to the council.
+#[test]
+fn test_finalize_elects_top_candidates() {
+    let e = setup_test_env();
+    let (client, token, council) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let voter1 = Address::generate(&e);
+    let voter2 = Address::generate(&e);
+    let voter3 = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    for candidate in [&alice, &bob, &carol] {
+        stellar_asset.mint(candidate, &100);
+    }
+    stellar_asset.mint(&voter1, &300);
+    stellar_asset.mint(&voter2, &500);
+    stellar_asset.mint(&voter3, &200);
+
+    client.create_election(&id, &2, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&alice, &id);
+    client.register_candidate(&bob, &id);
+    client.register_candidate(&carol, &id);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1150000);
+    client.vote(&voter1, &id, &alice);
+    client.vote(&voter2, &id, &carol);
+    client.vote(&voter3, &id, &bob);
+
+    let results = client.get_results(&id);
+    assert_eq!(results.get(0).unwrap(), (alice.clone(), 300));
+    assert_eq!(results.get(2).unwrap(), (carol.clone(), 500));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1200001);
+    let elected = client.finalize_election(&id);
+
+    assert_eq!(elected, vec![&e, carol.clone(), alice.clone()]);
+    assert_eq!(council.members(), elected);
+    assert_eq!(council.caller(), Some(client.address.clone()));
+    assert!(client.get_election(&id).finalized);
+}
+
+// Tests finalization before the voting window has ended.
+// Expects: ElectionNotEnded error (Error #515).
+#[test]
+#[should_panic(expected = "Error(Contract, #515)")]
+fn test_finalize_before_voting_ends() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_election(&e);
+    let id = symbol_short!("ELECT1");
+
+    client.create_election(&id, &3, &1100000, &1200000, &ElectionMode::Single);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1200000);
+    client.finalize_election(&id);
+}
+
+// Tests candidates reclaiming their stake once the election is finalized.
+// Expects: Stake returns to the candidate and is no longer tracked.
+#[test]
+fn test_withdraw_stake() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    client.create_election(&id, &1, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1200001);
+    client.finalize_election(&id);
+
+    assert_eq!(client.withdraw_stake(&candidate, &id), 100);
+    assert_eq!(token.balance(&candidate), 100);
+    assert_eq!(client.get_stake(&candidate, &id), 0);
+}
+
+// Tests stake withdrawal before the election has been finalized.
+// Expects: ElectionNotFinalized error (Error #517).
+#[test]
+#[should_panic(expected = "Error(Contract, #517)")]
+fn test_withdraw_stake_before_finalize() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_election(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let candidate = Address::generate(&e);
+    let id = symbol_short!("ELECT1");
+
+    stellar_asset.mint(&candidate, &100);
+    client.create_election(&id, &1, &1100000, &1200000, &ElectionMode::Single);
+    client.register_candidate(&candidate, &id);
+    client.withdraw_stake(&candidate, &id);
+}
+
+// Registers three candidates in a cumulative election and opens its voting window
+fn setup_cumulative_election<'a>(
+    e: &Env,
+    id: &Symbol,
+) -> (
+    ElectionContractClient<'a>,
+    StellarAssetClient<'a>,
+    [Address; 3],
+) {
+    let (client, token, _) = setup_election(e);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    let candidates = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+
+    client.create_election(id, &2, &1100000, &1200000, &ElectionMode::Cumulative);
+    for candidate in candidates.iter() {
+        stellar_asset.mint(candidate, &100);
+        client.register_candidate(candidate, id);
+    }
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1150000);
+    (client, stellar_asset, candidates)
+}
+
+// Tests cumulative voting splitting seats x weight points across candidates.
+// Expects: Split and partial allocations are tallied and decide the elected seats.
+#[test]
+fn test_cumulative_vote_split() {
+    let e = setup_test_env();
+    let id = symbol_short!("ELECT1");
+    let (client, stellar_asset, [alice, bob, carol]) = setup_cumulative_election(&e, &id);
+    let voter1 = Address::generate(&e);
+    let voter2 = Address::generate(&e);
+
+    stellar_asset.mint(&voter1, &100);
+    stellar_asset.mint(&voter2, &50);
+
+    let split = Map::from_array(&e, [(alice.clone(), 120), (bob.clone(), 80)]);
+    assert_eq!(client.vote_cumulative(&voter1, &id, &split), 200);
+    let partial = Map::from_array(&e, [(carol.clone(), 90)]);
+    assert_eq!(client.vote_cumulative(&voter2, &id, &partial), 90);
+
+    let results = client.get_results(&id);
+    assert_eq!(results.get(0).unwrap(), (alice.clone(), 120));
+    assert_eq!(results.get(1).unwrap(), (bob.clone(), 80));
+    assert_eq!(results.get(2).unwrap(), (carol.clone(), 90));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1200001);
+    assert_eq!(client.finalize_election(&id), vec![&e, alice, carol]);
+}
+
+// Tests cumulative allocations exceeding the voter's seats x weight budget.
+// Expects: AllocationExceedsBudget error (Error #521).
+#[test]
+#[should_panic(expected = "Error(Contract, #521)")]
+fn test_cumulative_vote_exceeds_budget() {
+    let e = setup_test_env();
+    let id = symbol_short!("ELECT1");
+    let (client, stellar_asset, [alice, bob, _]) = setup_cumulative_election(&e, &id);
+    let voter = Address::generate(&e);
+
+    stellar_asset.mint(&voter, &100);
+    let allocations = Map::from_array(&e, [(alice, 150), (bob, 51)]);
+    client.vote_cumulative(&voter, &id, &allocations);
+}
+
+// Tests cumulative allocations containing a non-positive amount.
+// Expects: InvalidAllocation error (Error #520).
+#[test]
+#[should_panic(expected = "Error(Contract, #520)")]
+fn test_cumulative_vote_invalid_allocation() {
+    let e = setup_test_env();
+    let id = symbol_short!("ELECT1");
+    let (client, stellar_asset, [alice, bob, _]) = setup_cumulative_election(&e, &id);
+    let voter = Address::generate(&e);
+
+    stellar_asset.mint(&voter, &100);
+    let allocations = Map::from_array(&e, [(alice, 100), (bob, 0)]);
+    client.vote_cumulative(&voter, &id, &allocations);
+}
+
+// Tests an empty cumulative allocation.
+// Expects: InvalidAllocation error (Error #520).
+#[test]
+#[should_panic(expected = "Error(Contract, #520)")]
+fn test_cumulative_vote_empty_allocation() {
+    let e = setup_test_env();
+    let id = symbol_short!("ELECT1");
+    let (client, stellar_asset, _) = setup_cumulative_election(&e, &id);
+    let voter = Address::generate(&e);
+
+    stellar_asset.mint(&voter, &100);
+    client.vote_cumulative(&voter, &id, &Map::new(&e));
+}
+
+// Tests a single-candidate vote in a cumulative election.
+// Expects: WrongElectionMode error (Error #519).
+#[test]
+#[should_panic(expected = "Error(Contract, #519)")]
+fn test_wrong_election_mode() {
+    let e = setup_test_env();
+    let id = symbol_short!("ELECT1");
+    let (client, stellar_asset, [alice, _, _]) = setup_cumulative_election(&e, &id);
+    let voter = Address::generate(&e);
+
+    stellar_asset.mint(&voter, &100);
+    client.vote(&voter, &id, &alice);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version and no support for any governance feature.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_election(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 500 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        ElectionContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        ElectionContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        ElectionContractErrors::VotingNotActive as u32,
+        GovernanceError::VotingNotActive as u32
+    );
+    assert_eq!(
+        ElectionContractErrors::UserAlreadyVoted as u32,
+        GovernanceError::UserAlreadyVoted as u32
+    );
+    assert_eq!(
+        ElectionContractErrors::UserCannotVote as u32,
+        GovernanceError::UserCannotVote as u32
+    );
+    for error in [
+        ElectionContractErrors::InvalidStake,
+        ElectionContractErrors::ElectionAlreadyExists,
+        ElectionContractErrors::ElectionNotFound,
+        ElectionContractErrors::InvalidElectionTimes,
+        ElectionContractErrors::InvalidSeats,
+        ElectionContractErrors::RegistrationClosed,
+        ElectionContractErrors::CandidateAlreadyRegistered,
+        ElectionContractErrors::TooManyCandidates,
+        ElectionContractErrors::CandidateNotFound,
+        ElectionContractErrors::ElectionNotEnded,
+        ElectionContractErrors::ElectionAlreadyFinalized,
+        ElectionContractErrors::ElectionNotFinalized,
+        ElectionContractErrors::StakeNotFound,
+        ElectionContractErrors::WrongElectionMode,
+        ElectionContractErrors::InvalidAllocation,
+        ElectionContractErrors::AllocationExceedsBudget,
+    ] {
+        assert!(in_range(error as u32, ELECTION_CODES));
+    }
+}
@@ -0,0 +1,315 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+pub use pg_contracts_types::faucet::{FaucetConfig, FaucetWindow};
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const CLAIM_TTL_EXTENSION: u32 = 1_600_000; // ~18.5 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 0] = [];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum FaucetContractDataKey {
+    Admin,              // Contract administrator address
+    Token,              // Governance token dispensed by the faucet
+    Config,             // Claim amount, cooldown, and drain-protection cap
+    Window,             // Tokens dispensed in the current drain-protection window
+    LastClaim(Address), // Timestamp of the last claim per address
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1000-1099 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FaucetContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidConfig = 15,             // Amount, periods, and cap must be positive, cap >= amount
+    CooldownActive = 1004,          // The address must wait for its cooldown to end
+    WindowCapReached = 1005,        // The faucet dispensed its cap for the current window
+    FaucetEmpty = 1006,             // The faucet holds fewer tokens than a claim
+    InvalidAmount = 13,             // Amount must be greater than zero
+}
+
+#[contract]
+pub struct FaucetContract;
+
+#[contractimpl]
+impl FaucetContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, FaucetContractErrors> {
+        env.storage()
+            .instance()
+            .get(&FaucetContractDataKey::Admin)
+            .ok_or(FaucetContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, FaucetContractErrors> {
+        env.storage()
+            .instance()
+            .get(&FaucetContractDataKey::Token)
+            .ok_or(FaucetContractErrors::ContractNotInitialized)
+    }
+
+    // Validates that amount, periods, and cap are positive and the cap covers one claim
+    fn validate_config(config: &FaucetConfig) -> Result<(), FaucetContractErrors> {
+        if config.amount <= 0
+            || config.cooldown == 0
+            || config.window == 0
+            || config.window_cap < config.amount
+        {
+            return Err(FaucetContractErrors::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    // Loads the current window, starting a new one once the previous has elapsed
+    fn current_window(env: &Env, config: &FaucetConfig) -> FaucetWindow {
+        let ledger_time = env.ledger().timestamp();
+        let window: FaucetWindow = env
+            .storage()
+            .instance()
+            .get(&FaucetContractDataKey::Window)
+            .unwrap_or_default();
+        if ledger_time >= window.start.saturating_add(config.window) {
+            return FaucetWindow {
+                start: ledger_time,
+                dispensed: 0,
+            };
+        }
+        window
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, governance token, and rate limits
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        cooldown: u64,
+        window: u64,
+        window_cap: i128,
+    ) -> Result<(), FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env.storage().instance().has(&FaucetContractDataKey::Admin) {
+            return Err(FaucetContractErrors::ContractAlreadyInitialized);
+        }
+        let config = FaucetConfig {
+            amount,
+            cooldown,
+            window,
+            window_cap,
+        };
+        Self::validate_config(&config)?;
+
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Config, &config);
+        Ok(())
+    }
+
+    // Dispenses the claim amount to an address once per cooldown, within the window cap
+    pub fn claim(env: Env, user: Address) -> Result<i128, FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let config = Self::get_config(env.clone())?;
+        let ledger_time = env.ledger().timestamp();
+        let last_claim_key = FaucetContractDataKey::LastClaim(user.clone());
+        if let Some(last_claim) = env.storage().persistent().get::<_, u64>(&last_claim_key) {
+            if ledger_time < last_claim.saturating_add(config.cooldown) {
+                return Err(FaucetContractErrors::CooldownActive);
+            }
+        }
+
+        let mut window = Self::current_window(&env, &config);
+        if window.dispensed.saturating_add(config.amount) > config.window_cap {
+            return Err(FaucetContractErrors::WindowCapReached);
+        }
+        let token_client = TokenClient::new(&env, &Self::read_token(&env)?);
+        if token_client.balance(&env.current_contract_address()) < config.amount {
+            return Err(FaucetContractErrors::FaucetEmpty);
+        }
+
+        window.dispensed += config.amount;
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Window, &window);
+        env.storage()
+            .persistent()
+            .set(&last_claim_key, &ledger_time);
+        env.storage().persistent().extend_ttl(
+            &last_claim_key,
+            CLAIM_TTL_EXTENSION,
+            CLAIM_TTL_EXTENSION,
+        );
+
+        token_client.transfer(&env.current_contract_address(), &user, &config.amount);
+
+        env.events()
+            .publish(("FAUCET", "CLAIMED", user), config.amount);
+        Ok(config.amount)
+    }
+
+    // Transfers governance tokens into the faucet
+    pub fn fund(env: Env, funder: Address, amount: i128) -> Result<(), FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(FaucetContractErrors::InvalidAmount);
+        }
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        env.events().publish(("FAUCET", "FUNDED", funder), amount);
+        Ok(())
+    }
+
+    // Withdraws governance tokens from the faucet to an address (admin only)
+    pub fn withdraw(env: Env, to: Address, amount: i128) -> Result<(), FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(FaucetContractErrors::InvalidAmount);
+        }
+        let token_client = TokenClient::new(&env, &Self::read_token(&env)?);
+        if token_client.balance(&env.current_contract_address()) < amount {
+            return Err(FaucetContractErrors::FaucetEmpty);
+        }
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        env.events().publish(("FAUCET", "WITHDRAWN", to), amount);
+        Ok(())
+    }
+
+    // Updates the claim amount, cooldown, and drain-protection cap (admin only)
+    pub fn set_config(
+        env: Env,
+        amount: i128,
+        cooldown: u64,
+        window: u64,
+        window_cap: i128,
+    ) -> Result<(), FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let config = FaucetConfig {
+            amount,
+            cooldown,
+            window,
+            window_cap,
+        };
+        Self::validate_config(&config)?;
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Config, &config);
+
+        env.events().publish(
+            ("CONFIG", "UPDATED"),
+            (amount, cooldown, window, window_cap),
+        );
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), FaucetContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&FaucetContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the claim amount, cooldown, and drain-protection cap
+    pub fn get_config(env: Env) -> Result<FaucetConfig, FaucetContractErrors> {
+        env.storage()
+            .instance()
+            .get(&FaucetContractDataKey::Config)
+            .ok_or(FaucetContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the tokens dispensed in the current drain-protection window
+    pub fn get_window(env: Env) -> Result<FaucetWindow, FaucetContractErrors> {
+        let config = Self::get_config(env.clone())?;
+        Ok(Self::current_window(&env, &config))
+    }
+
+    // Returns the timestamp of an address's last claim, if any
+    pub fn get_last_claim(env: Env, user: Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&FaucetContractDataKey::LastClaim(user))
+    }
+
+    // Returns the earliest timestamp at which an address can claim again
+    pub fn next_claim_at(env: Env, user: Address) -> Result<u64, FaucetContractErrors> {
+        let config = Self::get_config(env.clone())?;
+        match Self::get_last_claim(env, user) {
+            Some(last_claim) => Ok(last_claim.saturating_add(config.cooldown)),
+            None => Ok(0),
+        }
+    }
+
+    // Returns the governance tokens held by the faucet
+    pub fn get_balance(env: Env) -> Result<i128, FaucetContractErrors> {
+        let token_address = Self::read_token(&env)?;
+        Ok(TokenClient::new(&env, &token_address).balance(&env.current_contract_address()))
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,267 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, FAUCET_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    testutils::{storage::Instance as _, Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+fn create_faucet_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+    config: &FaucetConfig,
+) -> FaucetContractClient<'a> {
+    let contract_address = e.register(
+        FaucetContract,
+        FaucetContractArgs::__constructor(
+            admin,
+            token_address,
+            &config.amount,
+            &config.cooldown,
+            &config.window,
+            &config.window_cap,
+        ),
+    );
+    FaucetContractClient::new(e, &contract_address)
+}
+
+fn default_config() -> FaucetConfig {
+    FaucetConfig {
+        amount: 100,
+        cooldown: 86400,
+        window: 3600,
+        window_cap: 250,
+    }
+}
+
+// Deploys the governance token and a faucet funded with 10,000 tokens, returning its admin
+fn setup_faucet<'a>(e: &Env) -> (FaucetContractClient<'a>, TokenClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let client = create_faucet_contract(e, &admin, &token.address, &default_config());
+    StellarAssetClient::new(e, &token.address).mint(&client.address, &10000);
+    (client, token, admin)
+}
+
+// Tests successful initialization with admin, governance token, and rate limits.
+// Expects: Configuration matches the constructor arguments.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+
+    assert_eq!(client.get_config(), default_config());
+    assert_eq!(client.get_balance(), 10000);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, admin) = setup_faucet(&e);
+
+    e.register_at(
+        &client.address,
+        FaucetContract,
+        FaucetContractArgs::__constructor(&admin, &token.address, &100, &86400, &3600, &250),
+    );
+}
+
+// Tests updating the configuration with a cap below one claim.
+// Expects: InvalidConfig error (Error #15).
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_invalid_config() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+
+    client.set_config(&100, &86400, &3600, &99);
+}
+
+// Tests claiming from a funded faucet.
+// Expects: The claim amount is transferred and the claim time is recorded.
+#[test]
+fn test_claim() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_faucet(&e);
+    let user = Address::generate(&e);
+
+    assert_eq!(client.claim(&user), 100);
+
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(client.get_balance(), 9900);
+    assert_eq!(client.get_last_claim(&user), Some(1000000));
+    assert_eq!(client.next_claim_at(&user), 1086400);
+    assert_eq!(client.get_window().dispensed, 100);
+}
+
+// Tests claiming twice within the cooldown period.
+// Expects: CooldownActive error (Error #1004).
+#[test]
+#[should_panic(expected = "Error(Contract, #1004)")]
+fn test_claim_during_cooldown() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+    let user = Address::generate(&e);
+
+    client.claim(&user);
+    e.ledger().with_mut(|ledger| ledger.timestamp += 86399);
+    client.claim(&user);
+}
+
+// Tests claiming again once the cooldown has elapsed.
+// Expects: A second claim succeeds and the balance doubles.
+#[test]
+fn test_claim_after_cooldown() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_faucet(&e);
+    let user = Address::generate(&e);
+
+    client.claim(&user);
+    e.ledger().with_mut(|ledger| ledger.timestamp += 86400);
+    client.claim(&user);
+
+    assert_eq!(token.balance(&user), 200);
+}
+
+// Tests the drain-protection cap across many addresses in one window.
+// Expects: WindowCapReached error (Error #1005) once the cap would be exceeded.
+#[test]
+fn test_window_cap() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+
+    client.claim(&Address::generate(&e));
+    client.claim(&Address::generate(&e));
+    assert_eq!(
+        client.try_claim(&Address::generate(&e)),
+        Err(Ok(FaucetContractErrors::WindowCapReached))
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 3600);
+    client.claim(&Address::generate(&e));
+    assert_eq!(client.get_window().start, 1003600);
+    assert_eq!(client.get_window().dispensed, 100);
+}
+
+// Tests claiming from a faucet holding less than one claim.
+// Expects: FaucetEmpty error (Error #1006).
+#[test]
+#[should_panic(expected = "Error(Contract, #1006)")]
+fn test_claim_empty_faucet() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_faucet_contract(&e, &admin, &token.address, &default_config());
+
+    client.claim(&Address::generate(&e));
+}
+
+// Tests funding the faucet and withdrawing by the admin.
+// Expects: Balances move in and out of the faucet.
+#[test]
+fn test_fund_and_withdraw() {
+    let e = setup_test_env();
+    let (client, token, admin) = setup_faucet(&e);
+    let funder = Address::generate(&e);
+
+    StellarAssetClient::new(&e, &token.address).mint(&funder, &500);
+    client.fund(&funder, &500);
+    assert_eq!(client.get_balance(), 10500);
+
+    client.withdraw(&admin, &10500);
+    assert_eq!(client.get_balance(), 0);
+    assert_eq!(token.balance(&admin), 10500);
+}
+
+// Tests funding the faucet with a non-positive amount.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_fund_invalid_amount() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+
+    client.fund(&Address::generate(&e), &0);
+}
+
+// Tests updating the rate limits.
+// Expects: New claims use the updated amount.
+#[test]
+fn test_set_config() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_faucet(&e);
+    let user = Address::generate(&e);
+
+    client.set_config(&40, &60, &600, &400);
+    client.claim(&user);
+
+    assert_eq!(token.balance(&user), 40);
+    assert_eq!(client.next_claim_at(&user), 1000060);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version and no support for any governance feature.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1000 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        FaucetContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        FaucetContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        FaucetContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    assert_eq!(
+        FaucetContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    for error in [
+        FaucetContractErrors::CooldownActive,
+        FaucetContractErrors::WindowCapReached,
+        FaucetContractErrors::FaucetEmpty,
+    ] {
+        assert!(in_range(error as u32, FAUCET_CODES));
+    }
+}
+
+// Tests instance storage TTL maintenance across a long idle period.
+// Expects: Writes extend the instance TTL, and bump_instance restores it once it runs low.
+#[test]
+fn test_bump_instance() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_faucet(&e);
+    let instance_ttl = || e.as_contract(&client.address, || e.storage().instance().get_ttl());
+    assert_eq!(instance_ttl(), governance_core::INSTANCE_TTL_EXTENSION);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 1_000_000);
+    assert!(instance_ttl() < governance_core::INSTANCE_TTL_THRESHOLD);
+
+    client.bump_instance();
+    assert_eq!(instance_ttl(), governance_core::INSTANCE_TTL_EXTENSION);
+}
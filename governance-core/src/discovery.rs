@@ -0,0 +1,59 @@
+use soroban_sdk::{contractclient, contractspecfn, symbol_short, Env, Symbol};
+
+// --- Contract Version ---
+// Workspace version shared by every contract in the suite, as (major, minor, patch)
+pub const CONTRACT_VERSION: (u32, u32, u32) = (
+    parse_version(env!("CARGO_PKG_VERSION_MAJOR")),
+    parse_version(env!("CARGO_PKG_VERSION_MINOR")),
+    parse_version(env!("CARGO_PKG_VERSION_PATCH")),
+);
+
+// --- Feature Identifiers ---
+pub const FEATURE_GOVERNANCE: Symbol = symbol_short!("GOVERN"); // GovernanceInterface proposals
+pub const FEATURE_DELEGATION: Symbol = symbol_short!("DELEGATE"); // Voting power delegation
+pub const FEATURE_EXECUTION: Symbol = symbol_short!("EXECUTE"); // Queued proposal execution
+pub const FEATURE_SNAPSHOTS: Symbol = symbol_short!("SNAPSHOT"); // Historical or snapshot weights
+pub const FEATURE_SIGNED_VOTES: Symbol = symbol_short!("SIGNED"); // Relayed signature voting
+pub const FEATURE_TREASURY: Symbol = symbol_short!("TREASURY"); // Shared treasury payouts
+pub const FEATURE_HIERARCHY: Symbol = symbol_short!("HIERARCHY"); // Parent ratification of children
+pub const FEATURE_WEIGHT: Symbol = symbol_short!("WEIGHT"); // WeightCalculator strategy
+pub const FEATURE_ORACLE: Symbol = symbol_short!("ORACLE"); // OracleAdapterInterface adapter
+pub const FEATURE_REPUTATION: Symbol = symbol_short!("REPUTE"); // Participation reputation hooks
+pub const FEATURE_TOKEN: Symbol = symbol_short!("TOKEN"); // SEP-41 token interface
+pub const FEATURE_MEMBERSHIP: Symbol = symbol_short!("MEMBERS"); // Token-compatible membership
+pub const FEATURE_BADGES: Symbol = symbol_short!("BADGES"); // Participation badges
+pub const FEATURE_VESTING: Symbol = symbol_short!("VESTING"); // Vested and unvested balances
+pub const FEATURE_DEPLOYMENT: Symbol = symbol_short!("DEPLOY"); // Factory deployments
+pub const FEATURE_REGISTRY: Symbol = symbol_short!("REGISTRY"); // Deployment directory
+
+// Holds the XDR spec entries of the discovery interface functions
+pub struct DiscoverySpec;
+
+// Cross-contract interface implemented by every contract in the suite, letting clients and
+// coordinator contracts check versions and capabilities before calling a deployment
+#[contractspecfn(name = "DiscoverySpec", export = false)]
+#[contractclient(name = "DiscoveryClient")]
+pub trait DiscoveryInterface {
+    // Returns the contract version as (major, minor, patch)
+    fn get_version(env: Env) -> (u32, u32, u32);
+
+    // Returns whether the contract offers a feature, such as DELEGATE, EXECUTE, or SNAPSHOT
+    fn supports(env: Env, feature: Symbol) -> bool;
+}
+
+// Parses one numeric component of a package version at compile time
+const fn parse_version(component: &str) -> u32 {
+    let digits = component.as_bytes();
+    let mut value = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        value = value * 10 + (digits[index] - b'0') as u32;
+        index += 1;
+    }
+    value
+}
+
+// Returns whether a feature is in a contract's feature list
+pub fn supports(features: &[Symbol], feature: &Symbol) -> bool {
+    features.iter().any(|supported| supported == feature)
+}
@@ -0,0 +1,223 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, Val};
+
+use crate::{GovernanceStatus, Tally};
+
+// --- Event Schema Version ---
+pub const EVENT_VERSION: u32 = 2; // Third topic of every event, bumped when a payload changes
+
+// --- Event Categories (first topic) ---
+pub const PROPOSAL: &str = "PROPOSAL"; // Proposal lifecycle, subject is the proposal id
+pub const VOTE: &str = "VOTE"; // Recorded votes, subject is the proposal id
+pub const CONFIG: &str = "CONFIG"; // Governance settings, subject is the setting name
+pub const ADMIN: &str = "ADMIN"; // Admin role changes, subject is the new admin
+
+// --- Event Actions (second topic) ---
+pub const CREATED: &str = "CREATED";
+pub const AMENDED: &str = "AMENDED";
+pub const CANCELLED: &str = "CANCELLED";
+pub const FINALIZED: &str = "FINALIZED";
+pub const CAST: &str = "CAST";
+pub const CHANGED: &str = "CHANGED";
+pub const UPDATED: &str = "UPDATED";
+pub const TRANSFERRED: &str = "TRANSFERRED";
+
+// Payload of PROPOSAL/CREATED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    pub id: Symbol,        // Proposal identifier
+    pub proposer: Address, // Account that created the proposal
+    pub start_time: u64,   // UNIX timestamp when voting begins
+    pub end_time: u64,     // UNIX timestamp when voting ends
+}
+
+// Payload of PROPOSAL/AMENDED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAmendedEvent {
+    pub id: Symbol,          // Proposal identifier
+    pub description: String, // Description after the amendment
+    pub start_time: u64,     // Voting start after the amendment
+    pub end_time: u64,       // Voting end after the amendment
+}
+
+// Payload of every PROPOSAL status transition other than FINALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalStatusEvent {
+    pub id: Symbol,               // Proposal identifier
+    pub status: GovernanceStatus, // Status entered by the transition
+}
+
+// Payload of PROPOSAL/FINALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalFinalizedEvent {
+    pub id: Symbol,               // Proposal identifier
+    pub status: GovernanceStatus, // Resolved outcome
+    pub total_for: i128,          // Final voting power cast FOR
+    pub total_against: i128,      // Final voting power cast AGAINST
+    pub total_abstain: i128,      // Final voting power cast ABSTAIN
+    pub turnout: i128,            // Combined voting power across all choices
+    pub quorum: i128,             // Minimum turnout the proposal required
+    pub quorum_met: bool,         // Whether turnout reached the quorum
+    pub threshold: u32,           // FOR share of FOR + AGAINST to exceed, in basis points
+}
+
+// Payload of VOTE/CAST
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCastEvent {
+    pub id: Symbol,     // Proposal identifier
+    pub voter: Address, // Account the vote is recorded for
+    pub choice: Symbol, // FOR, AGAINST, or ABSTAIN
+    pub weight: i128,   // Voting power applied
+}
+
+// Payload of VOTE/CHANGED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteChangedEvent {
+    pub id: Symbol,       // Proposal identifier
+    pub voter: Address,   // Account the vote is recorded for
+    pub previous: Symbol, // Choice withdrawn by the change
+    pub choice: Symbol,   // Choice now recorded
+    pub weight: i128,     // Voting power applied
+}
+
+// Payload of ADMIN/TRANSFERRED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferredEvent {
+    pub previous: Address, // Admin before the transfer
+    pub admin: Address,    // Admin after the transfer
+}
+
+// Publishes a payload under the versioned (category, action, version, subject) topic layout
+pub fn publish<S, D>(env: &Env, category: &'static str, action: &'static str, subject: S, data: D)
+where
+    S: IntoVal<Env, Val>,
+    D: IntoVal<Env, Val>,
+{
+    env.events()
+        .publish((category, action, EVENT_VERSION, subject), data);
+}
+
+// Publishes PROPOSAL/CREATED
+pub fn proposal_created(
+    env: &Env,
+    id: &Symbol,
+    proposer: &Address,
+    start_time: u64,
+    end_time: u64,
+) {
+    let event = ProposalCreatedEvent {
+        id: id.clone(),
+        proposer: proposer.clone(),
+        start_time,
+        end_time,
+    };
+    publish(env, PROPOSAL, CREATED, id.clone(), event);
+}
+
+// Publishes PROPOSAL/AMENDED
+pub fn proposal_amended(
+    env: &Env,
+    id: &Symbol,
+    description: &String,
+    start_time: u64,
+    end_time: u64,
+) {
+    let event = ProposalAmendedEvent {
+        id: id.clone(),
+        description: description.clone(),
+        start_time,
+        end_time,
+    };
+    publish(env, PROPOSAL, AMENDED, id.clone(), event);
+}
+
+// Publishes a PROPOSAL status transition under the given action (e.g. CANCELLED)
+pub fn proposal_transitioned(
+    env: &Env,
+    action: &'static str,
+    id: &Symbol,
+    status: GovernanceStatus,
+) {
+    let event = ProposalStatusEvent {
+        id: id.clone(),
+        status,
+    };
+    publish(env, PROPOSAL, action, id.clone(), event);
+}
+
+// Publishes PROPOSAL/FINALIZED with a snapshot of the complete results, so the outcome can be
+// reconstructed from the event stream alone
+pub fn proposal_finalized(
+    env: &Env,
+    id: &Symbol,
+    status: GovernanceStatus,
+    tally: &Tally,
+    quorum: i128,
+    threshold: u32,
+) {
+    let event = ProposalFinalizedEvent {
+        id: id.clone(),
+        status,
+        total_for: tally.total_for,
+        total_against: tally.total_against,
+        total_abstain: tally.total_abstain,
+        turnout: tally.turnout(),
+        quorum,
+        quorum_met: tally.turnout() >= quorum,
+        threshold,
+    };
+    publish(env, PROPOSAL, FINALIZED, id.clone(), event);
+}
+
+// Publishes VOTE/CAST
+pub fn vote_cast(env: &Env, id: &Symbol, voter: &Address, choice: &Symbol, weight: i128) {
+    let event = VoteCastEvent {
+        id: id.clone(),
+        voter: voter.clone(),
+        choice: choice.clone(),
+        weight,
+    };
+    publish(env, VOTE, CAST, id.clone(), event);
+}
+
+// Publishes VOTE/CHANGED
+pub fn vote_changed(
+    env: &Env,
+    id: &Symbol,
+    voter: &Address,
+    previous: &Symbol,
+    choice: &Symbol,
+    weight: i128,
+) {
+    let event = VoteChangedEvent {
+        id: id.clone(),
+        voter: voter.clone(),
+        previous: previous.clone(),
+        choice: choice.clone(),
+        weight,
+    };
+    publish(env, VOTE, CHANGED, id.clone(), event);
+}
+
+// Publishes CONFIG/UPDATED for a named setting with its new value
+pub fn config_updated<D>(env: &Env, setting: &'static str, value: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    publish(env, CONFIG, UPDATED, setting, value);
+}
+
+// Publishes ADMIN/TRANSFERRED
+pub fn admin_transferred(env: &Env, previous: &Address, admin: &Address) {
+    let event = AdminTransferredEvent {
+        previous: previous.clone(),
+        admin: admin.clone(),
+    };
+    publish(env, ADMIN, TRANSFERRED, admin.clone(), event);
+}
@@ -0,0 +1,128 @@
+use soroban_sdk::{contractclient, contractspecfn, contracttype, Address, Env, String, Symbol};
+
+use crate::TallyOutcome;
+
+// Lifecycle status reported through the shared governance interface
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GovernanceStatus {
+    Pending,   // Created, voting has not started
+    Active,    // Voting window is open
+    Succeeded, // Voting closed and the proposal passed
+    Defeated,  // Voting closed without passing (including failed quorum or veto)
+    Queued,    // Passed and queued for execution
+    Executed,  // Executed by the voting model
+    Cancelled, // Cancelled before finalization
+    Expired,   // Not executed before its execution window closed
+}
+
+// Proposal view returned by every voting model implementing the interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceProposal {
+    pub id: Symbol,               // Proposal identifier
+    pub description: String,      // Proposal description
+    pub start_time: u64,          // UNIX timestamp when voting begins
+    pub end_time: u64,            // UNIX timestamp when voting ends
+    pub total_for: i128,          // Total voting power cast FOR
+    pub total_against: i128,      // Total voting power cast AGAINST
+    pub total_abstain: i128,      // Total voting power cast ABSTAIN
+    pub status: GovernanceStatus, // Status at the current ledger timestamp
+}
+
+// Holds the XDR spec entries of the governance interface functions
+pub struct GovernanceSpec;
+
+// Cross-contract interface implemented by voting models, letting coordinator, treasury, and
+// timelock contracts drive proposals without knowing which model runs the vote
+#[contractspecfn(name = "GovernanceSpec", export = false)]
+#[contractclient(name = "GovernanceClient")]
+pub trait GovernanceInterface {
+    // Creates a proposal on behalf of the proposer
+    fn create_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    );
+
+    // Records a vote of FOR, AGAINST, or ABSTAIN
+    fn vote(env: Env, user: Address, id: Symbol, choice: Symbol);
+
+    // Resolves the outcome once voting has closed
+    fn finalize(env: Env, id: Symbol) -> GovernanceStatus;
+
+    // Returns the proposal in the shared view
+    fn get_proposal(env: Env, id: Symbol) -> GovernanceProposal;
+
+    // Returns the proposal status at the current ledger timestamp
+    fn get_status(env: Env, id: Symbol) -> GovernanceStatus;
+}
+
+// Voting power of a user attested off-chain and published by an oracle adapter
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleWeight {
+    pub weight: i128,   // Attested voting power
+    pub issued_at: u64, // UNIX timestamp the oracle signed the attestation at
+}
+
+// Holds the XDR spec entries of the oracle adapter interface functions
+pub struct OracleAdapterSpec;
+
+// Cross-contract interface implemented by adapters that turn off-chain attestations (e.g.
+// contribution scores) into voting power; calculate_weight matches WeightCalculator, so an
+// adapter can be plugged into the Token-Weighted Vote Contract as its weighting strategy
+#[contractspecfn(name = "OracleAdapterSpec", export = false)]
+#[contractclient(name = "OracleAdapterClient")]
+pub trait OracleAdapterInterface {
+    // Returns the weight attested for a user as of a snapshot timestamp, 0 when it is missing,
+    // stale, or the adapter is frozen
+    fn calculate_weight(env: Env, user: Address, snapshot_time: u64) -> i128;
+
+    // Returns the latest attestation recorded for a user
+    fn get_attestation(env: Env, user: Address) -> Option<OracleWeight>;
+
+    // Returns whether oracle updates and weights are suspended
+    fn is_frozen(env: Env) -> bool;
+}
+
+// Holds the XDR spec entries of the membership interface functions
+pub struct MembershipSpec;
+
+// Cross-contract interface implemented by membership contracts, letting vote contracts gate
+// their electorate on membership instead of fungible balances
+#[contractspecfn(name = "MembershipSpec", export = false)]
+#[contractclient(name = "MembershipClient")]
+pub trait MembershipInterface {
+    // Returns whether an address is a current member
+    fn is_member(env: Env, addr: Address) -> bool;
+}
+
+// Holds the XDR spec entries of the badge interface functions
+pub struct BadgeSpec;
+
+// Cross-contract interface implemented by badge contracts, letting vote contracts mint
+// non-transferable participation badges and other contracts check proof of participation
+#[contractspecfn(name = "BadgeSpec", export = false)]
+#[contractclient(name = "BadgeClient")]
+pub trait BadgeInterface {
+    // Mints the badge of a proposal on the calling vote contract to a voter (registered minters
+    // only)
+    fn mint_badge(env: Env, minter: Address, voter: Address, proposal_id: Symbol);
+
+    // Returns whether an address holds the badge of a proposal on a vote contract
+    fn has_badge(env: Env, owner: Address, governance: Address, proposal_id: Symbol) -> bool;
+}
+
+// Maps a resolved tally onto the status reported through the interface
+impl From<TallyOutcome> for GovernanceStatus {
+    fn from(outcome: TallyOutcome) -> Self {
+        match outcome {
+            TallyOutcome::Succeeded => GovernanceStatus::Succeeded,
+            TallyOutcome::QuorumFailed | TallyOutcome::Defeated => GovernanceStatus::Defeated,
+        }
+    }
+}
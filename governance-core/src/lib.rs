@@ -0,0 +1,113 @@
+#![no_std]
+
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+mod discovery;
+pub mod events;
+mod interface;
+mod tally;
+mod token;
+pub use discovery::{
+    supports, DiscoveryClient, DiscoveryInterface, DiscoverySpec, CONTRACT_VERSION, FEATURE_BADGES,
+    FEATURE_DELEGATION, FEATURE_DEPLOYMENT, FEATURE_EXECUTION, FEATURE_GOVERNANCE,
+    FEATURE_HIERARCHY, FEATURE_MEMBERSHIP, FEATURE_ORACLE, FEATURE_REGISTRY, FEATURE_REPUTATION,
+    FEATURE_SIGNED_VOTES, FEATURE_SNAPSHOTS, FEATURE_TOKEN, FEATURE_TREASURY, FEATURE_VESTING,
+    FEATURE_WEIGHT,
+};
+pub use interface::{
+    BadgeClient, BadgeInterface, BadgeSpec, GovernanceClient, GovernanceInterface,
+    GovernanceProposal, GovernanceSpec, GovernanceStatus, MembershipClient, MembershipInterface,
+    MembershipSpec, OracleAdapterClient, OracleAdapterInterface, OracleAdapterSpec, OracleWeight,
+};
+pub use tally::{
+    count, plurality, Ballot, InvalidChoice, Tally, TallyError, TallyOutcome, TallyResult,
+    TallyStrategy, BINARY_OPTIONS, OPTION_ABSTAIN, OPTION_AGAINST, OPTION_FOR,
+};
+pub use token::{
+    log2_weight, normalize, supply_quorum, SupplyClient, SupplyInterface, TokenSource,
+    WeightScaling, CANONICAL_DECIMALS, MAX_DECIMALS,
+};
+
+// --- Vote Choice Constants ---
+pub const VOTE_FOR: Symbol = symbol_short!("FOR");
+pub const VOTE_AGAINST: Symbol = symbol_short!("AGAINST");
+pub const VOTE_ABSTAIN: Symbol = symbol_short!("ABSTAIN");
+
+// --- Threshold Scale ---
+pub const THRESHOLD_SCALE: u32 = 10_000; // Basis points, 10_000 = 100%
+pub const SIMPLE_MAJORITY: u32 = 5_000; // FOR must exceed AGAINST
+
+// --- Instance Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+pub const INSTANCE_TTL_THRESHOLD: u32 = 2_592_000; // ~30 days, extend once TTL falls below
+pub const INSTANCE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Enumerates proposal time validation failures, mapped onto each contract's error codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalTimeError {
+    StartTimeAfterEnd, // Proposal start time is not before its end time
+    StartTimeInPast,   // Proposal start time is before current timestamp
+    DurationTooLong,   // Proposal duration exceeds maximum allowed period
+    DurationTooShort,  // Proposal duration is below minimum required period
+}
+
+// Position of a ledger timestamp relative to a proposal's voting window
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VotingWindow {
+    Pending, // Voting has not started yet
+    Active,  // Voting is open, both bounds inclusive
+    Ended,   // Voting has closed
+}
+
+// Validates proposal start/end times against ledger time and duration bounds
+pub fn validate_proposal_times(
+    ledger_time: u64,
+    start_time: u64,
+    end_time: u64,
+    min_duration: u64,
+    max_duration: u64,
+) -> Result<(), ProposalTimeError> {
+    if start_time >= end_time {
+        return Err(ProposalTimeError::StartTimeAfterEnd);
+    }
+    if start_time < ledger_time {
+        return Err(ProposalTimeError::StartTimeInPast);
+    }
+    let duration = end_time - start_time;
+    if duration > max_duration {
+        return Err(ProposalTimeError::DurationTooLong);
+    }
+    if duration < min_duration {
+        return Err(ProposalTimeError::DurationTooShort);
+    }
+    Ok(())
+}
+
+// Computes where a ledger timestamp falls relative to a voting window
+pub fn voting_window(ledger_time: u64, start_time: u64, end_time: u64) -> VotingWindow {
+    if ledger_time < start_time {
+        VotingWindow::Pending
+    } else if ledger_time <= end_time {
+        VotingWindow::Active
+    } else {
+        VotingWindow::Ended
+    }
+}
+
+// Derives the TTL extension keeping a proposal alive until end_time plus a buffer, never
+// below the minimum extension
+pub fn proposal_ttl(ledger_time: u64, end_time: u64, buffer: u32, minimum: u32) -> u32 {
+    let remaining = end_time.saturating_sub(ledger_time);
+    let remaining = u32::try_from(remaining).unwrap_or(u32::MAX);
+    remaining.saturating_add(buffer).max(minimum)
+}
+
+// Extends the TTL of a contract's instance storage, which holds its admin, token, and
+// configuration keys, so a long-idle deployment never loses them
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTENSION);
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,300 @@
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+use crate::{SIMPLE_MAJORITY, THRESHOLD_SCALE, VOTE_ABSTAIN, VOTE_AGAINST, VOTE_FOR};
+
+// --- Binary Option Indexes ---
+pub const OPTION_FOR: u32 = 0; // Ballot option for FOR under binary strategies
+pub const OPTION_AGAINST: u32 = 1; // Ballot option for AGAINST under binary strategies
+pub const OPTION_ABSTAIN: u32 = 2; // Ballot option for ABSTAIN under binary strategies
+pub const BINARY_OPTIONS: u32 = 3; // Option count required by binary strategies
+
+// Signals a vote choice other than FOR, AGAINST, or ABSTAIN
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidChoice;
+
+// Enumerates the reasons a set of ballots cannot be counted
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TallyError {
+    InvalidOption,   // A ballot ranks an unknown option or the same option twice
+    InvalidWeight,   // A ballot carries a negative weight
+    InvalidStrategy, // The strategy does not fit the option count or its bounds
+}
+
+// Result of a tally measured against quorum and passing threshold
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TallyOutcome {
+    QuorumFailed, // Turnout stayed below the quorum
+    Succeeded,    // FOR share of FOR + AGAINST exceeded the threshold
+    Defeated,     // Quorum met but the threshold was not exceeded
+}
+
+// Counting rule applied to a set of ballots
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TallyStrategy {
+    SimpleMajority,     // FOR must exceed AGAINST, abstentions only add turnout
+    Supermajority(u32), // FOR share of FOR + AGAINST must exceed a basis-point threshold
+    Plurality(u32),     // The given number of options with the most weight win
+    InstantRunoff,      // Weakest option eliminated until one holds a majority
+}
+
+// Weighted ballot ranking options by preference
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ballot {
+    pub ranking: Vec<u32>, // Options in order of preference, only the first counts outside IRV
+    pub weight: i128,      // Voting power behind the ballot
+}
+
+// Counted result of a set of ballots
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TallyResult {
+    pub totals: Vec<i128>, // First-preference weight per option
+    pub winners: Vec<u32>, // Winning options in order of election, empty if none won
+    pub rounds: u32,       // Counting rounds, above 1 only for instant runoff
+}
+
+// Accumulated voting power per vote choice
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub total_for: i128,     // Total voting power cast FOR
+    pub total_against: i128, // Total voting power cast AGAINST
+    pub total_abstain: i128, // Total voting power cast ABSTAIN
+}
+
+impl Tally {
+    // Adds (or with a negative weight, removes) weight on the tally of a vote choice
+    pub fn record(&mut self, choice: &Symbol, weight: i128) -> Result<(), InvalidChoice> {
+        if *choice == VOTE_FOR {
+            self.total_for = self.total_for.saturating_add(weight);
+        } else if *choice == VOTE_AGAINST {
+            self.total_against = self.total_against.saturating_add(weight);
+        } else if *choice == VOTE_ABSTAIN {
+            self.total_abstain = self.total_abstain.saturating_add(weight);
+        } else {
+            return Err(InvalidChoice);
+        }
+        Ok(())
+    }
+
+    // Returns the combined voting power across all choices
+    pub fn turnout(&self) -> i128 {
+        self.total_for
+            .saturating_add(self.total_against)
+            .saturating_add(self.total_abstain)
+    }
+
+    // Resolves the tally against a quorum and a threshold in basis points
+    pub fn outcome(&self, quorum: i128, threshold: u32) -> TallyOutcome {
+        if self.turnout() < quorum {
+            TallyOutcome::QuorumFailed
+        } else if self.total_for.saturating_mul(THRESHOLD_SCALE as i128)
+            > self
+                .total_for
+                .saturating_add(self.total_against)
+                .saturating_mul(threshold as i128)
+        {
+            TallyOutcome::Succeeded
+        } else {
+            TallyOutcome::Defeated
+        }
+    }
+
+    // Returns one ballot per binary option carrying that option's total
+    pub fn ballots(&self, env: &Env) -> Vec<Ballot> {
+        let mut ballots = Vec::new(env);
+        for (option, weight) in [
+            (OPTION_FOR, self.total_for),
+            (OPTION_AGAINST, self.total_against),
+            (OPTION_ABSTAIN, self.total_abstain),
+        ] {
+            ballots.push_back(Ballot {
+                ranking: Vec::from_array(env, [option]),
+                weight,
+            });
+        }
+        ballots
+    }
+
+    // Resolves the tally against a quorum by counting its ballots under a binary strategy; a
+    // strategy or tally that cannot be counted resolves as Defeated
+    pub fn resolve(&self, env: &Env, quorum: i128, strategy: TallyStrategy) -> TallyOutcome {
+        if self.turnout() < quorum {
+            return TallyOutcome::QuorumFailed;
+        }
+        match count(env, strategy, BINARY_OPTIONS, &self.ballots(env)) {
+            Ok(result) if result.winners.contains(OPTION_FOR) => TallyOutcome::Succeeded,
+            _ => TallyOutcome::Defeated,
+        }
+    }
+}
+
+impl TallyStrategy {
+    // Returns the passing threshold of a binary strategy, None for multi-option strategies
+    pub fn threshold(&self) -> Option<u32> {
+        match self {
+            TallyStrategy::SimpleMajority => Some(SIMPLE_MAJORITY),
+            TallyStrategy::Supermajority(threshold) => Some(*threshold),
+            TallyStrategy::Plurality(_) | TallyStrategy::InstantRunoff => None,
+        }
+    }
+}
+
+// Validates ballots against the option count, rejecting unknown, repeated, or negative entries
+fn validate_ballots(env: &Env, options: u32, ballots: &Vec<Ballot>) -> Result<(), TallyError> {
+    for ballot in ballots.iter() {
+        if ballot.weight < 0 {
+            return Err(TallyError::InvalidWeight);
+        }
+        let mut seen = Vec::new(env);
+        for option in ballot.ranking.iter() {
+            if option >= options || seen.contains(option) {
+                return Err(TallyError::InvalidOption);
+            }
+            seen.push_back(option);
+        }
+    }
+    Ok(())
+}
+
+// Returns an elimination mask with every option still standing
+fn standing_options(env: &Env, options: u32) -> Vec<bool> {
+    let mut eliminated = Vec::new(env);
+    for _ in 0..options {
+        eliminated.push_back(false);
+    }
+    eliminated
+}
+
+// Sums ballot weight on the highest-ranked option of each ballot that is still standing
+fn count_preferences(
+    env: &Env,
+    options: u32,
+    ballots: &Vec<Ballot>,
+    eliminated: &Vec<bool>,
+) -> Vec<i128> {
+    let mut totals = Vec::new(env);
+    for _ in 0..options {
+        totals.push_back(0i128);
+    }
+    for ballot in ballots.iter() {
+        let preference = ballot
+            .ranking
+            .iter()
+            .find(|option| !eliminated.get_unchecked(*option));
+        if let Some(option) = preference {
+            let total = totals.get_unchecked(option);
+            totals.set(option, total.saturating_add(ballot.weight));
+        }
+    }
+    totals
+}
+
+// Selects up to `seats` options with positive weight by descending weight, breaking ties in
+// favour of the lower option index
+pub fn plurality(env: &Env, totals: &Vec<i128>, seats: u32) -> Vec<u32> {
+    let mut winners = Vec::new(env);
+    while winners.len() < seats {
+        let mut best: Option<(u32, i128)> = None;
+        for (option, weight) in totals.iter().enumerate() {
+            let option = option as u32;
+            let leads = match best {
+                Some((_, top)) => weight > top,
+                None => true,
+            };
+            if weight > 0 && leads && !winners.contains(option) {
+                best = Some((option, weight));
+            }
+        }
+        match best {
+            Some((option, _)) => winners.push_back(option),
+            None => break,
+        }
+    }
+    winners
+}
+
+// Runs instant runoff rounds, eliminating the weakest standing option (the higher index on a
+// tie) until one holds a strict majority of the ballots still counting
+fn instant_runoff(env: &Env, options: u32, ballots: &Vec<Ballot>) -> (Vec<u32>, u32) {
+    let mut eliminated = standing_options(env, options);
+    let mut rounds = 0;
+    loop {
+        rounds += 1;
+        let totals = count_preferences(env, options, ballots, &eliminated);
+        let mut active: i128 = 0;
+        let mut strongest: Option<(u32, i128)> = None;
+        let mut weakest: Option<(u32, i128)> = None;
+        for (option, weight) in totals.iter().enumerate() {
+            let option = option as u32;
+            if eliminated.get_unchecked(option) {
+                continue;
+            }
+            active = active.saturating_add(weight);
+            if strongest.is_none_or(|(_, top)| weight > top) {
+                strongest = Some((option, weight));
+            }
+            if weakest.is_none_or(|(_, bottom)| weight <= bottom) {
+                weakest = Some((option, weight));
+            }
+        }
+
+        match strongest {
+            Some((option, weight)) if weight > 0 && weight.saturating_mul(2) > active => {
+                return (Vec::from_array(env, [option]), rounds);
+            }
+            Some(_) if active > 0 => {}
+            _ => return (Vec::new(env), rounds),
+        }
+        if let Some((option, _)) = weakest {
+            eliminated.set(option, true);
+        }
+    }
+}
+
+// Counts ballots over `options` options under a strategy. Binary strategies read the first
+// preference of each ballot as OPTION_FOR, OPTION_AGAINST, or OPTION_ABSTAIN and report
+// OPTION_FOR as the winner when it passes; quorum is left to the caller through the totals.
+pub fn count(
+    env: &Env,
+    strategy: TallyStrategy,
+    options: u32,
+    ballots: &Vec<Ballot>,
+) -> Result<TallyResult, TallyError> {
+    validate_ballots(env, options, ballots)?;
+    let totals = count_preferences(env, options, ballots, &standing_options(env, options));
+
+    let (winners, rounds) = match strategy {
+        TallyStrategy::SimpleMajority | TallyStrategy::Supermajority(_) => {
+            let threshold = strategy.threshold().unwrap_or(SIMPLE_MAJORITY);
+            if options != BINARY_OPTIONS || !(SIMPLE_MAJORITY..THRESHOLD_SCALE).contains(&threshold)
+            {
+                return Err(TallyError::InvalidStrategy);
+            }
+            let tally = Tally {
+                total_for: totals.get_unchecked(OPTION_FOR),
+                total_against: totals.get_unchecked(OPTION_AGAINST),
+                total_abstain: totals.get_unchecked(OPTION_ABSTAIN),
+            };
+            let mut winners = Vec::new(env);
+            if tally.outcome(0, threshold) == TallyOutcome::Succeeded {
+                winners.push_back(OPTION_FOR);
+            }
+            (winners, 1)
+        }
+        TallyStrategy::Plurality(seats) => {
+            if seats == 0 {
+                return Err(TallyError::InvalidStrategy);
+            }
+            (plurality(env, &totals, seats), 1)
+        }
+        TallyStrategy::InstantRunoff => instant_runoff(env, options, ballots),
+    };
+
+    Ok(TallyResult {
+        totals,
+        winners,
+        rounds,
+    })
+}
@@ -0,0 +1,626 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use proptest::prelude::*;
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, Symbol, Vec};
+
+const MIN_DURATION: u64 = 432000; // ~5 days
+const MAX_DURATION: u64 = 1292000; // ~15 days
+const OPTIONS: u32 = 4; // Options offered in multi-option property tests
+
+fn build_ballots(env: &Env, raw: &[(std::vec::Vec<u32>, i128)]) -> Vec<Ballot> {
+    let mut ballots = Vec::new(env);
+    for (ranking, weight) in raw {
+        ballots.push_back(Ballot {
+            ranking: Vec::from_slice(env, ranking),
+            weight: *weight,
+        });
+    }
+    ballots
+}
+
+fn ballot_strategy() -> impl Strategy<Value = std::vec::Vec<(std::vec::Vec<u32>, i128)>> {
+    let ranking = (
+        Just((0..OPTIONS).collect::<std::vec::Vec<u32>>()).prop_shuffle(),
+        0..=OPTIONS,
+    )
+        .prop_map(|(mut ranking, length)| {
+            ranking.truncate(length as usize);
+            ranking
+        });
+    prop::collection::vec((ranking, 0i128..1_000), 0..16)
+}
+
+// Tests a proposal window within the duration bounds.
+// Expects: Validation succeeds at both the minimum and maximum duration.
+#[test]
+fn test_validate_proposal_times() {
+    assert_eq!(
+        validate_proposal_times(1000, 1000, 1000 + MIN_DURATION, MIN_DURATION, MAX_DURATION),
+        Ok(())
+    );
+    assert_eq!(
+        validate_proposal_times(1000, 2000, 2000 + MAX_DURATION, MIN_DURATION, MAX_DURATION),
+        Ok(())
+    );
+}
+
+// Tests a proposal window whose start is not before its end.
+// Expects: StartTimeAfterEnd error.
+#[test]
+fn test_validate_start_after_end() {
+    assert_eq!(
+        validate_proposal_times(1000, 5000, 5000, MIN_DURATION, MAX_DURATION),
+        Err(ProposalTimeError::StartTimeAfterEnd)
+    );
+}
+
+// Tests a proposal window starting before the ledger time.
+// Expects: StartTimeInPast error.
+#[test]
+fn test_validate_start_in_past() {
+    assert_eq!(
+        validate_proposal_times(1000, 999, 999 + MIN_DURATION, MIN_DURATION, MAX_DURATION),
+        Err(ProposalTimeError::StartTimeInPast)
+    );
+}
+
+// Tests proposal windows one second outside the duration bounds.
+// Expects: DurationTooLong and DurationTooShort errors.
+#[test]
+fn test_validate_duration_bounds() {
+    assert_eq!(
+        validate_proposal_times(1000, 1000, 1001 + MAX_DURATION, MIN_DURATION, MAX_DURATION),
+        Err(ProposalTimeError::DurationTooLong)
+    );
+    assert_eq!(
+        validate_proposal_times(1000, 1000, 999 + MIN_DURATION, MIN_DURATION, MAX_DURATION),
+        Err(ProposalTimeError::DurationTooShort)
+    );
+}
+
+// Tests the voting window around its start and end timestamps.
+// Expects: Pending before start, Active on both inclusive bounds, Ended after end.
+#[test]
+fn test_voting_window() {
+    assert_eq!(voting_window(99, 100, 200), VotingWindow::Pending);
+    assert_eq!(voting_window(100, 100, 200), VotingWindow::Active);
+    assert_eq!(voting_window(200, 100, 200), VotingWindow::Active);
+    assert_eq!(voting_window(201, 100, 200), VotingWindow::Ended);
+}
+
+// Tests the TTL for proposals ending soon and far in the future.
+// Expects: Short proposals get the minimum, long ones their remaining time plus the buffer.
+#[test]
+fn test_proposal_ttl() {
+    assert_eq!(proposal_ttl(1000, 2000, 604_800, 2_100_000), 2_100_000);
+    assert_eq!(
+        proposal_ttl(1000, 1000 + 1_600_000, 604_800, 2_100_000),
+        2_204_800
+    );
+}
+
+// Tests the TTL for ended proposals and end times beyond the u32 range.
+// Expects: Ended proposals get the minimum and huge end times saturate instead of wrapping.
+#[test]
+fn test_proposal_ttl_edges() {
+    assert_eq!(proposal_ttl(5000, 1000, 604_800, 2_100_000), 2_100_000);
+    assert_eq!(proposal_ttl(0, u64::MAX, 604_800, 2_100_000), u32::MAX);
+}
+
+// Tests recording and retracting weight on each vote choice.
+// Expects: Totals and turnout follow the recorded weights.
+#[test]
+fn test_tally_record() {
+    let mut tally = Tally::default();
+    tally.record(&VOTE_FOR, 300).unwrap();
+    tally.record(&VOTE_AGAINST, 200).unwrap();
+    tally.record(&VOTE_ABSTAIN, 100).unwrap();
+    tally.record(&VOTE_FOR, -50).unwrap();
+
+    assert_eq!(tally.total_for, 250);
+    assert_eq!(tally.total_against, 200);
+    assert_eq!(tally.total_abstain, 100);
+    assert_eq!(tally.turnout(), 550);
+}
+
+// Tests recording weight on an unknown choice, including a non-short symbol.
+// Expects: InvalidChoice error and an unchanged tally.
+#[test]
+fn test_tally_invalid_choice() {
+    let env = Env::default();
+    let mut tally = Tally::default();
+
+    assert_eq!(tally.record(&symbol_short!("MAYBE"), 1), Err(InvalidChoice));
+    assert_eq!(
+        tally.record(&Symbol::new(&env, "SUPERMAJORITY"), 1),
+        Err(InvalidChoice)
+    );
+    assert_eq!(tally, Tally::default());
+}
+
+// Tests outcomes at a simple-majority threshold.
+// Expects: QuorumFailed below quorum, Succeeded on a FOR majority, Defeated on a tie.
+#[test]
+fn test_tally_outcome() {
+    let tally = Tally {
+        total_for: 3,
+        total_against: 2,
+        total_abstain: 0,
+    };
+    assert_eq!(tally.outcome(6, 5_000), TallyOutcome::QuorumFailed);
+    assert_eq!(tally.outcome(5, 5_000), TallyOutcome::Succeeded);
+
+    let tie = Tally {
+        total_for: 2,
+        total_against: 2,
+        total_abstain: 1,
+    };
+    assert_eq!(tie.outcome(0, 5_000), TallyOutcome::Defeated);
+}
+
+// Tests outcomes at a two-thirds threshold with abstentions counting toward quorum.
+// Expects: Abstentions meet quorum but do not count toward the FOR share.
+#[test]
+fn test_tally_outcome_supermajority() {
+    let tally = Tally {
+        total_for: 2,
+        total_against: 1,
+        total_abstain: 7,
+    };
+    assert_eq!(tally.outcome(10, 6_667), TallyOutcome::Defeated);
+    assert_eq!(tally.outcome(10, 6_666), TallyOutcome::Succeeded);
+}
+
+// Tests resolving a tally through the tally engine under binary strategies.
+// Expects: QuorumFailed below quorum, the strategy threshold decides between Succeeded and
+// Defeated, and a plurality won by ABSTAIN or a strategy that cannot be counted resolves as
+// Defeated.
+#[test]
+fn test_tally_resolve() {
+    let env = Env::default();
+    let tally = Tally {
+        total_for: 2,
+        total_against: 1,
+        total_abstain: 7,
+    };
+    assert_eq!(
+        tally.resolve(&env, 11, TallyStrategy::SimpleMajority),
+        TallyOutcome::QuorumFailed
+    );
+    assert_eq!(
+        tally.resolve(&env, 10, TallyStrategy::SimpleMajority),
+        TallyOutcome::Succeeded
+    );
+    assert_eq!(
+        tally.resolve(&env, 10, TallyStrategy::Supermajority(6_667)),
+        TallyOutcome::Defeated
+    );
+    assert_eq!(
+        tally.resolve(&env, 0, TallyStrategy::Plurality(1)),
+        TallyOutcome::Defeated
+    );
+    assert_eq!(
+        tally.resolve(&env, 0, TallyStrategy::Supermajority(THRESHOLD_SCALE)),
+        TallyOutcome::Defeated
+    );
+    assert_eq!(tally.ballots(&env).len(), BINARY_OPTIONS);
+}
+
+// Tests mapping tally outcomes onto interface statuses.
+// Expects: Only a succeeded tally reads as Succeeded, failed quorum reads as Defeated.
+#[test]
+fn test_status_from_outcome() {
+    assert_eq!(
+        GovernanceStatus::from(TallyOutcome::Succeeded),
+        GovernanceStatus::Succeeded
+    );
+    assert_eq!(
+        GovernanceStatus::from(TallyOutcome::Defeated),
+        GovernanceStatus::Defeated
+    );
+    assert_eq!(
+        GovernanceStatus::from(TallyOutcome::QuorumFailed),
+        GovernanceStatus::Defeated
+    );
+}
+
+// Tests binary counting of FOR, AGAINST, and ABSTAIN ballots at a simple majority.
+// Expects: FOR wins with its first-preference totals reported per option.
+#[test]
+fn test_count_simple_majority() {
+    let env = Env::default();
+    let ballots = build_ballots(
+        &env,
+        &[
+            (std::vec![OPTION_FOR], 300),
+            (std::vec![OPTION_AGAINST], 200),
+            (std::vec![OPTION_ABSTAIN], 100),
+        ],
+    );
+
+    let result = count(
+        &env,
+        TallyStrategy::SimpleMajority,
+        BINARY_OPTIONS,
+        &ballots,
+    )
+    .unwrap();
+    assert_eq!(result.totals, vec![&env, 300, 200, 100]);
+    assert_eq!(result.winners, vec![&env, OPTION_FOR]);
+    assert_eq!(result.rounds, 1);
+}
+
+// Tests binary counting at a two-thirds threshold and with out-of-range configurations.
+// Expects: A 60% FOR share passes just below 60% but not at two-thirds, bad thresholds and
+// option counts are InvalidStrategy.
+#[test]
+fn test_count_supermajority() {
+    let env = Env::default();
+    let ballots = build_ballots(
+        &env,
+        &[
+            (std::vec![OPTION_FOR], 600),
+            (std::vec![OPTION_AGAINST], 400),
+        ],
+    );
+
+    let result = count(
+        &env,
+        TallyStrategy::Supermajority(5_999),
+        BINARY_OPTIONS,
+        &ballots,
+    );
+    assert_eq!(result.unwrap().winners, vec![&env, OPTION_FOR]);
+    let result = count(
+        &env,
+        TallyStrategy::Supermajority(6_667),
+        BINARY_OPTIONS,
+        &ballots,
+    );
+    assert!(result.unwrap().winners.is_empty());
+
+    assert_eq!(
+        count(
+            &env,
+            TallyStrategy::Supermajority(THRESHOLD_SCALE),
+            BINARY_OPTIONS,
+            &ballots
+        ),
+        Err(TallyError::InvalidStrategy)
+    );
+    assert_eq!(
+        count(
+            &env,
+            TallyStrategy::Supermajority(4_999),
+            BINARY_OPTIONS,
+            &ballots
+        ),
+        Err(TallyError::InvalidStrategy)
+    );
+    assert_eq!(
+        count(&env, TallyStrategy::SimpleMajority, OPTIONS, &ballots),
+        Err(TallyError::InvalidStrategy)
+    );
+}
+
+// Tests multi-option plurality with tied and empty options.
+// Expects: Top options win by weight, ties go to the lower index, empty options never win.
+#[test]
+fn test_count_plurality() {
+    let env = Env::default();
+    let ballots = build_ballots(
+        &env,
+        &[
+            (std::vec![2], 500),
+            (std::vec![1], 300),
+            (std::vec![3, 1], 300),
+        ],
+    );
+
+    let result = count(&env, TallyStrategy::Plurality(2), OPTIONS, &ballots).unwrap();
+    assert_eq!(result.winners, vec![&env, 2, 1]);
+    let result = count(&env, TallyStrategy::Plurality(4), OPTIONS, &ballots).unwrap();
+    assert_eq!(result.winners, vec![&env, 2, 1, 3]);
+    assert_eq!(
+        count(&env, TallyStrategy::Plurality(0), OPTIONS, &ballots),
+        Err(TallyError::InvalidStrategy)
+    );
+}
+
+// Tests instant runoff where the first-preference leader lacks a majority.
+// Expects: The weakest option is eliminated and its transfers elect the runner-up in round 2.
+#[test]
+fn test_count_instant_runoff() {
+    let env = Env::default();
+    let ballots = build_ballots(
+        &env,
+        &[
+            (std::vec![0], 40),
+            (std::vec![1, 0], 35),
+            (std::vec![2, 1], 25),
+        ],
+    );
+
+    let result = count(&env, TallyStrategy::InstantRunoff, 3, &ballots).unwrap();
+    assert_eq!(result.totals, vec![&env, 40, 35, 25]);
+    assert_eq!(result.winners, vec![&env, 1]);
+    assert_eq!(result.rounds, 2);
+}
+
+// Tests instant runoff with only weightless or empty ballots.
+// Expects: No winner is elected.
+#[test]
+fn test_count_instant_runoff_no_winner() {
+    let env = Env::default();
+    let ballots = build_ballots(&env, &[(std::vec![0, 1], 0), (std::vec![], 100)]);
+
+    let result = count(&env, TallyStrategy::InstantRunoff, 2, &ballots).unwrap();
+    assert!(result.winners.is_empty());
+}
+
+// Tests ballots ranking unknown or repeated options or carrying negative weight.
+// Expects: InvalidOption and InvalidWeight errors.
+#[test]
+fn test_count_invalid_ballots() {
+    let env = Env::default();
+    let unknown = build_ballots(&env, &[(std::vec![OPTIONS], 1)]);
+    let repeated = build_ballots(&env, &[(std::vec![1, 0, 1], 1)]);
+    let negative = build_ballots(&env, &[(std::vec![0], -1)]);
+
+    assert_eq!(
+        count(&env, TallyStrategy::InstantRunoff, OPTIONS, &unknown),
+        Err(TallyError::InvalidOption)
+    );
+    assert_eq!(
+        count(&env, TallyStrategy::InstantRunoff, OPTIONS, &repeated),
+        Err(TallyError::InvalidOption)
+    );
+    assert_eq!(
+        count(&env, TallyStrategy::Plurality(1), OPTIONS, &negative),
+        Err(TallyError::InvalidWeight)
+    );
+}
+
+// Tests rescaling amounts from lower, equal, and higher precisions.
+// Expects: Amounts scale to 7 decimals, truncating extra precision and saturating on overflow.
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize(5, 0), 50_000_000);
+    assert_eq!(normalize(1_234, CANONICAL_DECIMALS), 1_234);
+    assert_eq!(
+        normalize(1_000_000_000_000_000_000, MAX_DECIMALS),
+        10_000_000
+    );
+    assert_eq!(normalize(999, 10), 0);
+    assert_eq!(normalize(i128::MAX, 0), i128::MAX);
+    assert_eq!(normalize(i128::MAX, 64), 0);
+}
+
+// Tests balance reads through Stellar and custom token sources backed by the same token.
+// Expects: Both report 7 decimals and the same raw and normalized balance.
+#[test]
+fn test_token_source() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(admin).address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&holder, &250);
+
+    for source in [
+        TokenSource::Stellar(token.clone()),
+        TokenSource::Custom(token.clone()),
+    ] {
+        assert_eq!(source.address(), token);
+        assert_eq!(source.decimals(&env), CANONICAL_DECIMALS);
+        assert_eq!(source.balance(&env, &holder), 250);
+        assert_eq!(source.normalized_balance(&env, &holder), 250);
+    }
+}
+
+// Tests supply-based quorums for exact, fractional, zero, and oversized supplies.
+// Expects: Fractional shares round up and an empty supply yields no quorum.
+#[test]
+fn test_supply_quorum() {
+    assert_eq!(supply_quorum(1_000, 2_500), 250);
+    assert_eq!(supply_quorum(1_001, 2_500), 251);
+    assert_eq!(supply_quorum(3, 1), 1);
+    assert_eq!(supply_quorum(1_000, 0), 0);
+    assert_eq!(supply_quorum(0, 2_500), 0);
+    assert_eq!(supply_quorum(-100, 2_500), 0);
+    assert_eq!(supply_quorum(i128::MAX, THRESHOLD_SCALE), i128::MAX);
+}
+
+// Tests logarithmic weights against a table precomputed at 80-digit precision.
+// Expects: Each weight is floor(10^7 * log2(1 + tokens)), exact at powers of two.
+#[test]
+fn test_log2_weight() {
+    let table: [(i128, i128); 14] = [
+        (-5, 0),
+        (0, 0),
+        (1, 1),
+        (5_000_000, 5_849_625),
+        (10_000_000, 10_000_000),
+        (20_000_000, 15_849_625),
+        (30_000_000, 20_000_000),
+        (70_000_000, 30_000_000),
+        (100_000_000, 34_594_316),
+        (1_000_000_000, 66_582_114),
+        (10_230_000_000, 100_000_000),
+        (10_000_000_000_000, 199_315_700),
+        (10_000_000_000_000_000, 298_973_528),
+        (i128::MAX, 1_037_465_033),
+    ];
+    for (weight, expected) in table {
+        assert_eq!(log2_weight(weight), expected);
+    }
+}
+
+// Tests applying each weight scaling to the same normalized weight.
+// Expects: Linear keeps the weight, Log2 compresses 1,023 tokens to 10.
+#[test]
+fn test_weight_scaling() {
+    assert_eq!(WeightScaling::Linear.apply(10_230_000_000), 10_230_000_000);
+    assert_eq!(WeightScaling::Log2.apply(10_230_000_000), 100_000_000);
+    assert_eq!(WeightScaling::Log2.apply(-1), 0);
+}
+
+// Tests the contract version against the workspace package version.
+// Expects: Each component matches the version Cargo reports.
+#[test]
+fn test_contract_version() {
+    let (major, minor, patch) = CONTRACT_VERSION;
+    assert_eq!(
+        std::format!("{}.{}.{}", major, minor, patch),
+        env!("CARGO_PKG_VERSION")
+    );
+}
+
+// Tests feature lookups against a contract's feature list.
+// Expects: Listed features are supported, unlisted and unknown ones are not.
+#[test]
+fn test_supports() {
+    let env = Env::default();
+    let features = [FEATURE_GOVERNANCE, FEATURE_DELEGATION];
+
+    assert!(supports(&features, &FEATURE_DELEGATION));
+    assert!(!supports(&features, &FEATURE_EXECUTION));
+    assert!(!supports(&features, &Symbol::new(&env, "UNKNOWN_FEATURE")));
+    assert!(!supports(&[], &FEATURE_GOVERNANCE));
+}
+
+proptest! {
+    // Tests first-preference totals for any set of ballots.
+    // Expects: Totals sum to the weight of every ballot ranking at least one option.
+    #[test]
+    fn prop_totals_match_weights(raw in ballot_strategy()) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &raw);
+        let result = count(&env, TallyStrategy::Plurality(1), OPTIONS, &ballots).unwrap();
+
+        let expected: i128 = raw
+            .iter()
+            .filter(|(ranking, _)| !ranking.is_empty())
+            .map(|(_, weight)| weight)
+            .sum();
+        prop_assert_eq!(result.totals.iter().sum::<i128>(), expected);
+    }
+
+    // Tests plurality winners for any set of ballots and seat count.
+    // Expects: Winners fill every seat an option with weight can take and outweigh the rest.
+    #[test]
+    fn prop_plurality_winners_lead(raw in ballot_strategy(), seats in 1..=OPTIONS + 1) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &raw);
+        let result = count(&env, TallyStrategy::Plurality(seats), OPTIONS, &ballots).unwrap();
+
+        let backed = result.totals.iter().filter(|total| *total > 0).count() as u32;
+        prop_assert_eq!(result.winners.len(), seats.min(backed));
+        for (option, total) in result.totals.iter().enumerate() {
+            if result.winners.contains(option as u32) {
+                prop_assert!(total > 0);
+                continue;
+            }
+            for winner in result.winners.iter() {
+                prop_assert!(result.totals.get_unchecked(winner) >= total);
+            }
+        }
+    }
+
+    // Tests binary strategies against the tally outcome for any totals and threshold.
+    // Expects: FOR wins exactly when the tally succeeds at the strategy threshold, and resolving
+    // the tally yields the same outcome.
+    #[test]
+    fn prop_binary_matches_tally(
+        total_for in 0i128..1_000_000,
+        total_against in 0i128..1_000_000,
+        total_abstain in 0i128..1_000_000,
+        threshold in SIMPLE_MAJORITY..THRESHOLD_SCALE,
+    ) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &[
+            (std::vec![OPTION_FOR], total_for),
+            (std::vec![OPTION_AGAINST], total_against),
+            (std::vec![OPTION_ABSTAIN], total_abstain),
+        ]);
+        let tally = Tally { total_for, total_against, total_abstain };
+
+        for strategy in [TallyStrategy::SimpleMajority, TallyStrategy::Supermajority(threshold)] {
+            let result = count(&env, strategy, BINARY_OPTIONS, &ballots).unwrap();
+            let outcome = tally.outcome(0, strategy.threshold().unwrap());
+            let succeeded = outcome == TallyOutcome::Succeeded;
+            prop_assert_eq!(result.winners.contains(OPTION_FOR), succeeded);
+            prop_assert_eq!(tally.resolve(&env, 0, strategy), outcome);
+        }
+        let simple = count(&env, TallyStrategy::SimpleMajority, BINARY_OPTIONS, &ballots).unwrap();
+        prop_assert_eq!(simple.winners.contains(OPTION_FOR), total_for > total_against);
+    }
+
+    // Tests instant runoff when one option holds a first-preference majority.
+    // Expects: That option wins in the first round.
+    #[test]
+    fn prop_runoff_majority_wins(raw in ballot_strategy()) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &raw);
+        let result = count(&env, TallyStrategy::InstantRunoff, OPTIONS, &ballots).unwrap();
+
+        let active: i128 = result.totals.iter().sum();
+        for (option, total) in result.totals.iter().enumerate() {
+            if total > 0 && total * 2 > active {
+                prop_assert_eq!(result.winners.clone(), vec![&env, option as u32]);
+                prop_assert_eq!(result.rounds, 1);
+            }
+        }
+    }
+
+    // Tests instant runoff termination for any set of ballots.
+    // Expects: At most one winner within OPTIONS rounds, elected whenever any ballot has weight.
+    #[test]
+    fn prop_runoff_elects_one(raw in ballot_strategy()) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &raw);
+        let result = count(&env, TallyStrategy::InstantRunoff, OPTIONS, &ballots).unwrap();
+
+        let backed = raw.iter().any(|(ranking, weight)| !ranking.is_empty() && *weight > 0);
+        prop_assert!(result.rounds <= OPTIONS);
+        prop_assert_eq!(result.winners.len(), backed as u32);
+    }
+
+    // Tests every strategy against the same ballots in reverse order.
+    // Expects: Ballot order never changes the result.
+    #[test]
+    fn prop_count_order_independent(raw in ballot_strategy(), seats in 1..=OPTIONS) {
+        let env = Env::default();
+        let ballots = build_ballots(&env, &raw);
+        let mut reversed = raw.clone();
+        reversed.reverse();
+        let reversed = build_ballots(&env, &reversed);
+
+        for strategy in [TallyStrategy::Plurality(seats), TallyStrategy::InstantRunoff] {
+            prop_assert_eq!(
+                count(&env, strategy, OPTIONS, &ballots),
+                count(&env, strategy, OPTIONS, &reversed)
+            );
+        }
+    }
+
+    // Tests logarithmic weights for any two positive weights.
+    // Expects: More stake never counts less, and doubling the stake adds at most one token.
+    #[test]
+    fn prop_log2_weight_monotonic(a in 1..=i128::MAX / 2, b in 1..=i128::MAX / 2) {
+        let (smaller, larger) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(log2_weight(smaller) <= log2_weight(larger));
+        prop_assert!(log2_weight(2 * smaller) <= log2_weight(smaller) + 10_000_000);
+    }
+
+    // Tests logarithmic weights for stakes of at least one whole token.
+    // Expects: The compressed weight is positive and never exceeds the linear weight.
+    #[test]
+    fn prop_log2_weight_compresses(weight in 10_000_000..=i128::MAX) {
+        let scaled = log2_weight(weight);
+        prop_assert!(scaled > 0);
+        prop_assert!(scaled <= weight);
+    }
+}
@@ -0,0 +1,133 @@
+use soroban_sdk::{contractclient, contracttype, token::TokenClient, Address, Env};
+
+use crate::THRESHOLD_SCALE;
+
+// --- Token Precision ---
+pub const CANONICAL_DECIMALS: u32 = 7; // Stellar asset precision amounts are normalized to
+pub const MAX_DECIMALS: u32 = 18; // Highest token precision accepted for normalization
+
+// --- Logarithmic Weighting ---
+const MANTISSA_BITS: u32 = 60; // Fixed-point precision of the mantissa while squaring
+const LOG2_FRACTION_BITS: u32 = 40; // Binary digits computed for the fractional part
+
+// Cross-contract interface of tokens reporting their circulating supply, which neither the
+// standard token interface nor Stellar Asset Contracts expose
+#[contractclient(name = "SupplyClient")]
+pub trait SupplyInterface {
+    fn total_supply(env: Env) -> i128;
+}
+
+// Token a contract reads balances from and moves funds through
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenSource {
+    Stellar(Address), // Stellar Asset Contract, always CANONICAL_DECIMALS
+    Custom(Address),  // Contract exposing the standard token interface, precision from decimals()
+}
+
+impl TokenSource {
+    // Returns the token contract address
+    pub fn address(&self) -> Address {
+        match self {
+            TokenSource::Stellar(address) | TokenSource::Custom(address) => address.clone(),
+        }
+    }
+
+    // Returns a client for the standard token interface
+    pub fn client<'a>(&self, env: &Env) -> TokenClient<'a> {
+        TokenClient::new(env, &self.address())
+    }
+
+    // Returns the number of decimal places of the token
+    pub fn decimals(&self, env: &Env) -> u32 {
+        match self {
+            TokenSource::Stellar(_) => CANONICAL_DECIMALS,
+            TokenSource::Custom(address) => TokenClient::new(env, address).decimals(),
+        }
+    }
+
+    // Returns the raw balance of an account in the token's own precision
+    pub fn balance(&self, env: &Env, id: &Address) -> i128 {
+        self.client(env).balance(id)
+    }
+
+    // Returns the balance of an account rescaled to CANONICAL_DECIMALS
+    pub fn normalized_balance(&self, env: &Env, id: &Address) -> i128 {
+        normalize(self.balance(env, id), self.decimals(env))
+    }
+}
+
+// Rescales an amount with `decimals` places to CANONICAL_DECIMALS, truncating precision beyond
+// it and saturating instead of overflowing
+pub fn normalize(amount: i128, decimals: u32) -> i128 {
+    if decimals > CANONICAL_DECIMALS {
+        match 10i128.checked_pow(decimals - CANONICAL_DECIMALS) {
+            Some(scale) => amount / scale,
+            None => 0,
+        }
+    } else {
+        amount.saturating_mul(10i128.pow(CANONICAL_DECIMALS - decimals))
+    }
+}
+
+// Scaling applied to normalized weights before they are counted
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightScaling {
+    Linear, // Weights count as normalized
+    Log2,   // Weights count as log2(1 + whole tokens), compressing large holders
+}
+
+impl WeightScaling {
+    // Returns the weight a normalized weight counts as under this scaling
+    pub fn apply(&self, weight: i128) -> i128 {
+        match self {
+            WeightScaling::Linear => weight,
+            WeightScaling::Log2 => log2_weight(weight),
+        }
+    }
+}
+
+// Compresses a normalized weight to log2(1 + weight in whole tokens), kept at
+// CANONICAL_DECIMALS and rounded down, so 1 token counts 1, 3 count 2, and 1023 count 10;
+// non-positive weights count 0. Integer-only: the integer part is the bit length of the whole
+// tokens, the fractional bits come from repeatedly squaring the fixed-point mantissa
+pub fn log2_weight(weight: i128) -> i128 {
+    if weight <= 0 {
+        return 0;
+    }
+    let one = 10i128.pow(CANONICAL_DECIMALS);
+    let x = weight.saturating_add(one);
+
+    // Integer part: the largest n with one * 2^n <= x
+    let integer = 127 - (x / one).leading_zeros();
+    let base = one << integer;
+
+    // Mantissa x / base in [1, 2), both terms shifted down far enough for the remainder to fit
+    let shift = (128 - base.leading_zeros()).saturating_sub(66);
+    let remainder = (((x - base) >> shift) << MANTISSA_BITS) / (base >> shift);
+    let mut mantissa = (1i128 << MANTISSA_BITS) + remainder;
+
+    let mut fraction: i128 = 0;
+    for _ in 0..LOG2_FRACTION_BITS {
+        mantissa = (mantissa * mantissa) >> MANTISSA_BITS;
+        fraction <<= 1;
+        if mantissa >= 2i128 << MANTISSA_BITS {
+            mantissa >>= 1;
+            fraction |= 1;
+        }
+    }
+    one * integer as i128 + ((one * fraction) >> LOG2_FRACTION_BITS)
+}
+
+// Derives the quorum for a basis-point share of the total supply, rounding up so it never falls
+// below the exact share; an empty or negative supply yields 0
+pub fn supply_quorum(total_supply: i128, bps: u32) -> i128 {
+    if total_supply <= 0 {
+        return 0;
+    }
+    let scale = THRESHOLD_SCALE as i128;
+    let whole = (total_supply / scale).saturating_mul(bps as i128);
+    let remainder = (total_supply % scale) * bps as i128;
+    whole.saturating_add((remainder + scale - 1) / scale)
+}
@@ -0,0 +1,66 @@
+#![no_std]
+
+use soroban_sdk::contracterror;
+
+// --- Error Code Registry ---
+// Codes 1-99 are shared conditions defined by GovernanceError. Each contract owns a block of
+// CODE_RANGE_SIZE codes for its own conditions, so an error number maps to one meaning across
+// every contract in the suite.
+pub const CODE_RANGE_SIZE: u32 = 100; // Codes reserved per contract
+pub const TOKEN_GATED_VOTE_CODES: u32 = 100; // Token-Gated Vote Contract
+pub const TOKEN_WEIGHTED_VOTE_CODES: u32 = 200; // Token-Weighted Vote Contract
+pub const DUAL_TOKEN_WEIGHT_CODES: u32 = 300; // Dual-Token Weight Contract
+pub const REPUTATION_CODES: u32 = 400; // Reputation Contract
+pub const ELECTION_CODES: u32 = 500; // Election Contract
+pub const OPTIMISTIC_GOVERNANCE_CODES: u32 = 600; // Optimistic Governance Contract
+pub const MEMBERSHIP_DAO_CODES: u32 = 700; // Membership DAO Contract
+pub const GOVERNANCE_TOKEN_CODES: u32 = 800; // Governance Token Contract
+pub const TOKEN_FACTORY_CODES: u32 = 900; // Token Factory Contract
+pub const FAUCET_CODES: u32 = 1000; // Faucet Contract
+pub const AIRDROP_CODES: u32 = 1100; // Airdrop Contract
+pub const VESTING_CODES: u32 = 1200; // Vesting Contract
+pub const LOCK_BOOST_CODES: u32 = 1300; // Lock-Boost Contract
+pub const WRAPPED_GOV_TOKEN_CODES: u32 = 1400; // Wrapped Governance Token Contract
+pub const ORACLE_WEIGHT_CODES: u32 = 1500; // Oracle Weight Contract
+pub const GOVERNANCE_FACTORY_CODES: u32 = 1600; // Governance Factory Contract
+pub const REGISTRY_CODES: u32 = 1700; // Registry Contract
+pub const TENURE_BOOST_CODES: u32 = 1800; // Tenure-Boost Contract
+pub const QUADRATIC_VOTE_CODES: u32 = 1900; // Quadratic Vote Contract
+pub const MULTI_TOKEN_WEIGHT_CODES: u32 = 2000; // Multi-Token Weight Contract
+pub const LP_WEIGHT_CODES: u32 = 2100; // LP Weight Contract
+pub const MEMBERSHIP_TOKEN_CODES: u32 = 2200; // Membership Token Contract
+pub const PARTICIPATION_BADGE_CODES: u32 = 2300; // Participation Badge Contract
+pub const STAKED_VOTE_CODES: u32 = 2400; // Staked Vote Contract
+pub const TREASURY_CODES: u32 = 2500; // Treasury Contract
+
+// Enumerates the error conditions shared by contract models, with stable codes that every
+// contract error enum reuses for the same condition
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GovernanceError {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    ProposalAlreadyExists = 3,      // Proposal with this ID already exists
+    ProposalNotFound = 4,           // Proposal with this ID does not exist
+    UserAlreadyVoted = 5,           // User has already voted on this proposal
+    UserCannotVote = 6,             // User is not eligible to vote
+    VotingNotActive = 7,            // Voting is not open at the current timestamp
+    InvalidChoice = 8,              // Vote choice must be FOR, AGAINST, or ABSTAIN
+    StartTimeAfterEnd = 9,          // Proposal start time is not before its end time
+    StartTimeInPast = 10,           // Proposal start time is before current timestamp
+    DurationTooLong = 11,           // Proposal duration exceeds maximum allowed period
+    DurationTooShort = 12,          // Proposal duration is below minimum required period
+    InvalidAmount = 13,             // Amount must be greater than zero
+    VotingNotEnded = 14,            // Voting period has not ended yet
+    InvalidConfig = 15,             // Configuration values are out of bounds
+    InvalidProof = 16,              // Merkle proof does not match the stored root
+    ProposalsRestricted = 17,       // The caller is not allowed to create proposals
+}
+
+// Returns whether a code belongs to the block reserved for a contract
+pub fn in_range(code: u32, base: u32) -> bool {
+    (base..base + CODE_RANGE_SIZE).contains(&code)
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,87 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::Error;
+
+// Tests the shared error codes against their published values.
+// Expects: Every shared condition keeps its stable number.
+#[test]
+fn test_shared_codes() {
+    assert_eq!(GovernanceError::ContractNotInitialized as u32, 1);
+    assert_eq!(GovernanceError::ContractAlreadyInitialized as u32, 2);
+    assert_eq!(GovernanceError::ProposalAlreadyExists as u32, 3);
+    assert_eq!(GovernanceError::ProposalNotFound as u32, 4);
+    assert_eq!(GovernanceError::UserAlreadyVoted as u32, 5);
+    assert_eq!(GovernanceError::UserCannotVote as u32, 6);
+    assert_eq!(GovernanceError::VotingNotActive as u32, 7);
+    assert_eq!(GovernanceError::InvalidChoice as u32, 8);
+    assert_eq!(GovernanceError::StartTimeAfterEnd as u32, 9);
+    assert_eq!(GovernanceError::StartTimeInPast as u32, 10);
+    assert_eq!(GovernanceError::DurationTooLong as u32, 11);
+    assert_eq!(GovernanceError::DurationTooShort as u32, 12);
+    assert_eq!(GovernanceError::InvalidAmount as u32, 13);
+    assert_eq!(GovernanceError::VotingNotEnded as u32, 14);
+    assert_eq!(GovernanceError::InvalidConfig as u32, 15);
+    assert_eq!(GovernanceError::InvalidProof as u32, 16);
+    assert_eq!(GovernanceError::ProposalsRestricted as u32, 17);
+}
+
+// Tests converting shared errors into host errors.
+// Expects: The contract error carries the shared code.
+#[test]
+fn test_into_contract_error() {
+    assert_eq!(
+        Error::from(GovernanceError::InvalidProof),
+        Error::from_contract_error(16)
+    );
+}
+
+// Tests the contract code blocks against each other and the shared block.
+// Expects: Blocks are disjoint and none overlaps the shared codes.
+#[test]
+fn test_ranges_disjoint() {
+    let bases = [
+        TOKEN_GATED_VOTE_CODES,
+        TOKEN_WEIGHTED_VOTE_CODES,
+        DUAL_TOKEN_WEIGHT_CODES,
+        REPUTATION_CODES,
+        ELECTION_CODES,
+        OPTIMISTIC_GOVERNANCE_CODES,
+        MEMBERSHIP_DAO_CODES,
+        GOVERNANCE_TOKEN_CODES,
+        TOKEN_FACTORY_CODES,
+        FAUCET_CODES,
+        AIRDROP_CODES,
+        VESTING_CODES,
+        LOCK_BOOST_CODES,
+        WRAPPED_GOV_TOKEN_CODES,
+        ORACLE_WEIGHT_CODES,
+        GOVERNANCE_FACTORY_CODES,
+        REGISTRY_CODES,
+        TENURE_BOOST_CODES,
+        QUADRATIC_VOTE_CODES,
+        MULTI_TOKEN_WEIGHT_CODES,
+        LP_WEIGHT_CODES,
+        MEMBERSHIP_TOKEN_CODES,
+        PARTICIPATION_BADGE_CODES,
+        STAKED_VOTE_CODES,
+        TREASURY_CODES,
+    ];
+    for (i, base) in bases.iter().enumerate() {
+        assert!(!in_range(GovernanceError::InvalidProof as u32, *base));
+        for other in bases.iter().skip(i + 1) {
+            assert!(base.abs_diff(*other) >= CODE_RANGE_SIZE);
+        }
+    }
+}
+
+// Tests range membership at the block boundaries.
+// Expects: The base is inside the block and the next block's base is outside.
+#[test]
+fn test_in_range() {
+    assert!(in_range(1300, LOCK_BOOST_CODES));
+    assert!(in_range(1399, LOCK_BOOST_CODES));
+    assert!(!in_range(1299, LOCK_BOOST_CODES));
+    assert!(!in_range(1400, LOCK_BOOST_CODES));
+}
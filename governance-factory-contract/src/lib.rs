@@ -0,0 +1,276 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String, Symbol, Vec,
+};
+
+pub use pg_contracts_types::governance_factory::{
+    GovernanceFactoryInstance, GovernanceFactoryInstanceConfig, GovernanceFactoryModel,
+};
+
+// --- Registry Constraints ---
+const MAX_INSTANCES: u32 = 500; // Maximum governance instances recorded in the registry
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REGISTRY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_DEPLOYMENT];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum GovernanceFactoryContractDataKey {
+    Admin,                             // Contract administrator address
+    ModelWasm(GovernanceFactoryModel), // WASM hash of the vote contract per model
+    Instances,                         // Addresses of all deployed instances in deployment order
+    Instance(Address),                 // Registry record per deployed instance
+    Community(String),                 // Addresses of the instances deployed per community
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1600-1699 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GovernanceFactoryContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidCommunity = 1601,        // Community name must not be empty
+    InstanceNotFound = 1602,        // The instance was not deployed by this factory
+    RegistryFull = 1603,            // The registry has reached its instance limit
+}
+
+#[contract]
+pub struct GovernanceFactoryContract;
+
+#[contractimpl]
+impl GovernanceFactoryContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, GovernanceFactoryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceFactoryContractDataKey::Admin)
+            .ok_or(GovernanceFactoryContractErrors::ContractNotInitialized)
+    }
+
+    // Loads a list of instance addresses from persistent storage
+    fn read_list(env: &Env, key: &GovernanceFactoryContractDataKey) -> Vec<Address> {
+        env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+    }
+
+    // Saves a list of instance addresses and extends its TTL
+    fn write_list(env: &Env, key: &GovernanceFactoryContractDataKey, instances: &Vec<Address>) {
+        env.storage().persistent().set(key, instances);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, REGISTRY_TTL_EXTENSION, REGISTRY_TTL_EXTENSION);
+    }
+
+    // Derives a unique deployment salt from the registry position
+    fn deployment_salt(env: &Env, index: u32) -> BytesN<32> {
+        let mut salt = [0u8; 32];
+        salt[28..].copy_from_slice(&index.to_be_bytes());
+        BytesN::from_array(env, &salt)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and the gated and weighted vote contract WASM hashes
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        gated_wasm: BytesN<32>,
+        weighted_wasm: BytesN<32>,
+    ) -> Result<(), GovernanceFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&GovernanceFactoryContractDataKey::Admin)
+        {
+            return Err(GovernanceFactoryContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&GovernanceFactoryContractDataKey::Admin, &admin);
+        env.storage().instance().set(
+            &GovernanceFactoryContractDataKey::ModelWasm(GovernanceFactoryModel::Gated),
+            &gated_wasm,
+        );
+        env.storage().instance().set(
+            &GovernanceFactoryContractDataKey::ModelWasm(GovernanceFactoryModel::Weighted),
+            &weighted_wasm,
+        );
+        Ok(())
+    }
+
+    // Deploys a governance instance for a community, running its constructor in the same call,
+    // and records it (admin only)
+    pub fn deploy_governance(
+        env: Env,
+        community: String,
+        instance_admin: Address,
+        config: GovernanceFactoryInstanceConfig,
+    ) -> Result<Address, GovernanceFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if community.is_empty() {
+            return Err(GovernanceFactoryContractErrors::InvalidCommunity);
+        }
+        let instances_key = GovernanceFactoryContractDataKey::Instances;
+        let mut instances = Self::read_list(&env, &instances_key);
+        if instances.len() >= MAX_INSTANCES {
+            return Err(GovernanceFactoryContractErrors::RegistryFull);
+        }
+
+        // Constructor arguments are passed to the deployment, so an instance never exists
+        // uninitialized and cannot be claimed by anyone else
+        let wasm = Self::get_model_wasm(env.clone(), config.model())?;
+        let deployer = env
+            .deployer()
+            .with_current_contract(Self::deployment_salt(&env, instances.len()));
+        let instance_address = match &config {
+            GovernanceFactoryInstanceConfig::Gated(token) => {
+                deployer.deploy_v2(wasm.clone(), (instance_admin.clone(), token.clone()))
+            }
+            GovernanceFactoryInstanceConfig::Weighted(strategy, scaling) => deployer.deploy_v2(
+                wasm.clone(),
+                (instance_admin.clone(), strategy.clone(), *scaling),
+            ),
+        };
+
+        let record = GovernanceFactoryInstance {
+            community: community.clone(),
+            config,
+            admin: instance_admin,
+            wasm,
+            deployed_at: env.ledger().timestamp(),
+        };
+        let instance_key = GovernanceFactoryContractDataKey::Instance(instance_address.clone());
+        env.storage().persistent().set(&instance_key, &record);
+        env.storage().persistent().extend_ttl(
+            &instance_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+        instances.push_back(instance_address.clone());
+        Self::write_list(&env, &instances_key, &instances);
+        let community_key = GovernanceFactoryContractDataKey::Community(community.clone());
+        let mut community_instances = Self::read_list(&env, &community_key);
+        community_instances.push_back(instance_address.clone());
+        Self::write_list(&env, &community_key, &community_instances);
+
+        env.events().publish(
+            ("GOVERNANCE", "DEPLOYED", instance_address.clone()),
+            (record.admin, community, record.config.model()),
+        );
+        Ok(instance_address)
+    }
+
+    // Updates the vote contract WASM hash used for future deployments of a model (admin only)
+    pub fn set_model_wasm(
+        env: Env,
+        model: GovernanceFactoryModel,
+        wasm: BytesN<32>,
+    ) -> Result<(), GovernanceFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&GovernanceFactoryContractDataKey::ModelWasm(model), &wasm);
+
+        env.events()
+            .publish(("MODEL_WASM", "UPDATED"), (model, wasm));
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), GovernanceFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&GovernanceFactoryContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the addresses of all deployed instances in deployment order
+    pub fn get_instances(env: Env) -> Vec<Address> {
+        Self::read_list(&env, &GovernanceFactoryContractDataKey::Instances)
+    }
+
+    // Returns the addresses of the instances deployed for a community in deployment order
+    pub fn get_community_instances(env: Env, community: String) -> Vec<Address> {
+        Self::read_list(
+            &env,
+            &GovernanceFactoryContractDataKey::Community(community),
+        )
+    }
+
+    // Returns the registry record of a deployed instance
+    pub fn get_instance(
+        env: Env,
+        instance: Address,
+    ) -> Result<GovernanceFactoryInstance, GovernanceFactoryContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&GovernanceFactoryContractDataKey::Instance(instance))
+            .ok_or(GovernanceFactoryContractErrors::InstanceNotFound)
+    }
+
+    // Returns the number of deployed instances
+    pub fn instance_count(env: Env) -> u32 {
+        Self::get_instances(env).len()
+    }
+
+    // Returns the vote contract WASM hash used for deployments of a model
+    pub fn get_model_wasm(
+        env: Env,
+        model: GovernanceFactoryModel,
+    ) -> Result<BytesN<32>, GovernanceFactoryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceFactoryContractDataKey::ModelWasm(model))
+            .ok_or(GovernanceFactoryContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,324 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::{GovernanceClient, GovernanceStatus, TokenSource, WeightScaling};
+use governance_errors::{in_range, GovernanceError, GOVERNANCE_FACTORY_CODES};
+use governance_testutils::{register_deployable, setup_test_env};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, vec, Address, Bytes, BytesN, Env, String,
+};
+use token_gated_vote_contract::TokenGatedVoteContract;
+use token_weighted_vote_contract::{TokenWeightedVoteContract, TokenWeightedVoteContractClient};
+
+fn create_factory_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    gated_wasm: &BytesN<32>,
+    weighted_wasm: &BytesN<32>,
+) -> GovernanceFactoryContractClient<'a> {
+    let contract_address = e.register(
+        GovernanceFactoryContract,
+        GovernanceFactoryContractArgs::__constructor(admin, gated_wasm, weighted_wasm),
+    );
+    GovernanceFactoryContractClient::new(e, &contract_address)
+}
+
+// Deploys a factory and registers the native vote contract of each model at its next deployment
+// addresses, in deployment order, returning the factory admin
+fn setup_factory<'a>(
+    e: &Env,
+    models: &[GovernanceFactoryModel],
+) -> (GovernanceFactoryContractClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let wasm = e.deployer().upload_contract_wasm(Bytes::new(e));
+    let client = create_factory_contract(e, &admin, &wasm, &wasm);
+    for (index, model) in (0..).zip(models) {
+        let salt = GovernanceFactoryContract::deployment_salt(e, index);
+        match model {
+            GovernanceFactoryModel::Gated => register_deployable(
+                e,
+                &client.address,
+                &salt,
+                TokenGatedVoteContract,
+                (
+                    Address::generate(e),
+                    TokenSource::Stellar(Address::generate(e)),
+                ),
+            ),
+            GovernanceFactoryModel::Weighted => register_deployable(
+                e,
+                &client.address,
+                &salt,
+                TokenWeightedVoteContract,
+                (
+                    Address::generate(e),
+                    Address::generate(e),
+                    WeightScaling::Linear,
+                ),
+            ),
+        };
+    }
+    (client, admin)
+}
+
+// Builds a gated configuration over a fresh Stellar Asset Contract
+fn gated_config(e: &Env) -> GovernanceFactoryInstanceConfig {
+    let token = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+    GovernanceFactoryInstanceConfig::Gated(TokenSource::Stellar(token))
+}
+
+// Creates a proposal on a deployed instance through the shared governance interface
+fn create_instance_proposal<'a>(
+    e: &Env,
+    instance: &Address,
+    proposer: &Address,
+) -> GovernanceClient<'a> {
+    let governance = GovernanceClient::new(e, instance);
+    let start_time = e.ledger().timestamp() + 100;
+    governance.create_proposal(
+        proposer,
+        &symbol_short!("PROP001"),
+        &String::from_str(e, "First experiment"),
+        &start_time,
+        &(start_time + 432_000),
+    );
+    governance
+}
+
+// Tests successful initialization with admin and both vote contract WASM hashes.
+// Expects: Each model maps to its uploaded WASM and the registry is empty.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+
+    assert_eq!(
+        client.get_model_wasm(&GovernanceFactoryModel::Gated),
+        e.deployer().upload_contract_wasm(Bytes::new(&e))
+    );
+    assert_eq!(
+        client.get_model_wasm(&GovernanceFactoryModel::Weighted),
+        e.deployer().upload_contract_wasm(Bytes::new(&e))
+    );
+    assert_eq!(client.instance_count(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, admin) = setup_factory(&e, &[]);
+    let wasm = client.get_model_wasm(&GovernanceFactoryModel::Gated);
+
+    e.register_at(
+        &client.address,
+        GovernanceFactoryContract,
+        GovernanceFactoryContractArgs::__constructor(&admin, &wasm, &wasm),
+    );
+}
+
+// Tests deploying a gated vote instance through the factory.
+// Expects: The instance is initialized with the requested admin, who can create proposals,
+// and is registered under its community.
+#[test]
+fn test_deploy_gated_instance() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[GovernanceFactoryModel::Gated]);
+    let instance_admin = Address::generate(&e);
+    let community = String::from_str(&e, "Stronghold");
+    let config = gated_config(&e);
+
+    let instance = client.deploy_governance(&community, &instance_admin, &config);
+
+    let governance = create_instance_proposal(&e, &instance, &instance_admin);
+    assert_eq!(
+        governance.get_status(&symbol_short!("PROP001")),
+        GovernanceStatus::Pending
+    );
+    assert_eq!(
+        client.get_instance(&instance),
+        GovernanceFactoryInstance {
+            community: community.clone(),
+            config,
+            admin: instance_admin,
+            wasm: client.get_model_wasm(&GovernanceFactoryModel::Gated),
+            deployed_at: 1000000,
+        }
+    );
+    assert_eq!(client.get_instances(), vec![&e, instance.clone()]);
+    assert_eq!(
+        client.get_community_instances(&community),
+        vec![&e, instance]
+    );
+}
+
+// Tests deploying a weighted vote instance through the factory.
+// Expects: The instance is initialized with the requested admin, strategy, and scaling, and its
+// admin can create proposals.
+#[test]
+fn test_deploy_weighted_instance() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[GovernanceFactoryModel::Weighted]);
+    let instance_admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+
+    let instance = client.deploy_governance(
+        &String::from_str(&e, "Stronghold"),
+        &instance_admin,
+        &GovernanceFactoryInstanceConfig::Weighted(strategy.clone(), WeightScaling::Log2),
+    );
+
+    let weighted = TokenWeightedVoteContractClient::new(&e, &instance);
+    assert_eq!(weighted.get_strategy(), strategy);
+    assert_eq!(weighted.get_weight_scaling(), WeightScaling::Log2);
+    create_instance_proposal(&e, &instance, &instance_admin);
+    assert_eq!(
+        client.get_instance(&instance).config.model(),
+        GovernanceFactoryModel::Weighted
+    );
+}
+
+// Tests deploying several instances across communities from the same factory.
+// Expects: Each deployment gets a distinct address, recorded in deployment order overall and
+// per community.
+#[test]
+fn test_deploy_multiple_instances() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(
+        &e,
+        &[
+            GovernanceFactoryModel::Gated,
+            GovernanceFactoryModel::Weighted,
+            GovernanceFactoryModel::Gated,
+        ],
+    );
+    let instance_admin = Address::generate(&e);
+    let first_community = String::from_str(&e, "Stronghold");
+    let second_community = String::from_str(&e, "Dashxboard");
+
+    let first = client.deploy_governance(&first_community, &instance_admin, &gated_config(&e));
+    let second = client.deploy_governance(
+        &second_community,
+        &instance_admin,
+        &GovernanceFactoryInstanceConfig::Weighted(Address::generate(&e), WeightScaling::Linear),
+    );
+    let third = client.deploy_governance(&first_community, &instance_admin, &gated_config(&e));
+
+    assert_ne!(first, third);
+    assert_eq!(client.instance_count(), 3);
+    assert_eq!(
+        client.get_instances(),
+        vec![&e, first.clone(), second.clone(), third.clone()]
+    );
+    assert_eq!(
+        client.get_community_instances(&first_community),
+        vec![&e, first, third]
+    );
+    assert_eq!(
+        client.get_community_instances(&second_community),
+        vec![&e, second]
+    );
+}
+
+// Tests deploying an instance without a community name.
+// Expects: InvalidCommunity error (Error #1601).
+#[test]
+#[should_panic(expected = "Error(Contract, #1601)")]
+fn test_deploy_invalid_community() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+
+    client.deploy_governance(
+        &String::from_str(&e, ""),
+        &Address::generate(&e),
+        &gated_config(&e),
+    );
+}
+
+// Tests querying an address the factory did not deploy.
+// Expects: InstanceNotFound error (Error #1602).
+#[test]
+#[should_panic(expected = "Error(Contract, #1602)")]
+fn test_get_unknown_instance() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+
+    client.get_instance(&Address::generate(&e));
+}
+
+// Tests replacing the WASM hash of one model.
+// Expects: Only that model's hash changes.
+#[test]
+fn test_set_model_wasm() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+    let new_wasm = BytesN::from_array(&e, &[7; 32]);
+
+    client.set_model_wasm(&GovernanceFactoryModel::Weighted, &new_wasm);
+
+    assert_eq!(
+        client.get_model_wasm(&GovernanceFactoryModel::Weighted),
+        new_wasm
+    );
+    assert_eq!(
+        client.get_model_wasm(&GovernanceFactoryModel::Gated),
+        e.deployer().upload_contract_wasm(Bytes::new(&e))
+    );
+}
+
+// Tests transferring the admin role.
+// Expects: The new admin is stored and can update a model WASM hash.
+#[test]
+fn test_transfer_admin() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+    let new_admin = Address::generate(&e);
+    let new_wasm = BytesN::from_array(&e, &[7; 32]);
+
+    client.transfer_admin(&new_admin);
+    client.set_model_wasm(&GovernanceFactoryModel::Gated, &new_wasm);
+
+    assert_eq!(
+        client.get_model_wasm(&GovernanceFactoryModel::Gated),
+        new_wasm
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with deployment supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e, &[]);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_DEPLOYMENT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1600 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        GovernanceFactoryContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        GovernanceFactoryContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        GovernanceFactoryContractErrors::InvalidCommunity,
+        GovernanceFactoryContractErrors::InstanceNotFound,
+        GovernanceFactoryContractErrors::RegistryFull,
+    ] {
+        assert!(in_range(error as u32, GOVERNANCE_FACTORY_CODES));
+    }
+}
@@ -0,0 +1,235 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::{
+    GovernanceProposal, GovernanceStatus, WeightScaling, VOTE_ABSTAIN, VOTE_AGAINST, VOTE_FOR,
+};
+use proptest::prelude::*;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+use token_gated_vote_contract::TokenGatedVoteContract;
+use token_weighted_vote_contract::TokenWeightedVoteContract;
+
+const DURATION: u64 = 432_000; // ~5 days, the shortest window both vote contracts accept
+const CHOICES: [Symbol; 4] = [VOTE_FOR, VOTE_AGAINST, VOTE_ABSTAIN, symbol_short!("MAYBE")];
+
+// Linear strategy: voting weight equals the holder's token balance
+#[contract]
+pub struct LinearWeightCalculator;
+
+#[contractimpl]
+impl LinearWeightCalculator {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TOKEN"), &token);
+    }
+
+    pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TOKEN"))
+            .unwrap();
+        TokenClient::new(&env, &token).balance(&user)
+    }
+}
+
+// Replays holder balances and (holder, choice) votes, choices indexing CHOICES
+struct Scenario<'s> {
+    balances: &'s [i128],
+    votes: &'s [(usize, usize)],
+}
+
+// Records how a vote contract received a replayed scenario
+struct Replay {
+    accepted: std::vec::Vec<bool>, // Whether each vote of the scenario was counted
+    proposal: GovernanceProposal,  // Proposal after voting closed
+    status: GovernanceStatus,      // Outcome returned by finalize
+}
+
+// Replays a scenario against a vote contract deployed through the harness
+fn replay<F>(scenario: &Scenario, deploy: F) -> Replay
+where
+    F: FnOnce(&Env, &Address, &Address) -> Address,
+{
+    let harness = GovernanceHarness::new(deploy);
+    let holders: std::vec::Vec<Address> = scenario
+        .balances
+        .iter()
+        .map(|balance| harness.holder(*balance))
+        .collect();
+    let proposal_id = symbol_short!("PROP001");
+    harness.propose(&proposal_id, DURATION);
+    harness.open_voting(&proposal_id);
+
+    let accepted = scenario
+        .votes
+        .iter()
+        .map(|(holder, choice)| {
+            harness
+                .governance
+                .try_vote(&holders[*holder], &proposal_id, &CHOICES[*choice])
+                .is_ok()
+        })
+        .collect();
+    let status = harness.finalize(&proposal_id);
+    Replay {
+        accepted,
+        proposal: harness.governance.get_proposal(&proposal_id),
+        status,
+    }
+}
+
+fn replay_gated(scenario: &Scenario) -> Replay {
+    replay(scenario, |e, admin, token| {
+        e.register(
+            TokenGatedVoteContract,
+            (
+                admin.clone(),
+                governance_core::TokenSource::Stellar(token.clone()),
+            ),
+        )
+    })
+}
+
+fn replay_weighted(scenario: &Scenario) -> Replay {
+    replay(scenario, |e, admin, token| {
+        let strategy = e.register(LinearWeightCalculator, (token,));
+        e.register(
+            TokenWeightedVoteContract,
+            (admin.clone(), strategy, WeightScaling::Linear),
+        )
+    })
+}
+
+// Derives the expected tallies of a scenario: the unique eligible voters per choice and the
+// balances they hold, along with which votes count
+fn expected_tallies(scenario: &Scenario) -> ([i128; 3], [i128; 3], std::vec::Vec<bool>) {
+    let mut voted = std::vec![false; scenario.balances.len()];
+    let mut voters = [0i128; 3];
+    let mut balances = [0i128; 3];
+    let mut accepted = std::vec::Vec::new();
+    for (holder, choice) in scenario.votes {
+        let counts = !voted[*holder] && scenario.balances[*holder] > 0 && *choice < 3;
+        if counts {
+            voted[*holder] = true;
+            voters[*choice] += 1;
+            balances[*choice] += scenario.balances[*holder];
+        }
+        accepted.push(counts);
+    }
+    (voters, balances, accepted)
+}
+
+fn totals(proposal: &GovernanceProposal) -> [i128; 3] {
+    [
+        proposal.total_for,
+        proposal.total_against,
+        proposal.total_abstain,
+    ]
+}
+
+// Replays a scenario against both models and checks their relationship
+fn check_scenario(scenario: &Scenario) -> (Replay, Replay) {
+    let (voters, balances, accepted) = expected_tallies(scenario);
+    let gated = replay_gated(scenario);
+    let weighted = replay_weighted(scenario);
+
+    assert_eq!(gated.accepted, accepted);
+    assert_eq!(weighted.accepted, accepted);
+    assert_eq!(totals(&gated.proposal), voters);
+    assert_eq!(totals(&weighted.proposal), balances);
+    (gated, weighted)
+}
+
+// Tests fixed scenarios replayed against the gated and weighted vote contracts.
+// Expects: Both accept the same votes, gated tallies equal unique voter counts, weighted tallies
+// equal balance sums, and outcomes differ only where a large holder outweighs more voters.
+#[test]
+fn test_differential_scenarios() {
+    let cases = [
+        (
+            Scenario {
+                balances: &[100, 100, 100],
+                votes: &[(0, 0), (1, 0), (2, 1)],
+            },
+            GovernanceStatus::Succeeded,
+            GovernanceStatus::Succeeded,
+        ),
+        (
+            Scenario {
+                balances: &[1_000, 10, 10],
+                votes: &[(0, 1), (1, 0), (2, 0)],
+            },
+            GovernanceStatus::Succeeded,
+            GovernanceStatus::Defeated,
+        ),
+        (
+            Scenario {
+                balances: &[50, 0, 50, 50],
+                votes: &[(0, 0), (0, 1), (1, 0), (2, 3), (2, 2), (3, 1)],
+            },
+            GovernanceStatus::Defeated,
+            GovernanceStatus::Defeated,
+        ),
+        (
+            Scenario {
+                balances: &[10, 20],
+                votes: &[],
+            },
+            GovernanceStatus::Defeated,
+            GovernanceStatus::Defeated,
+        ),
+    ];
+
+    for (scenario, gated_status, weighted_status) in cases {
+        let (gated, weighted) = check_scenario(&scenario);
+        assert_eq!(gated.status, gated_status);
+        assert_eq!(weighted.status, weighted_status);
+        assert_eq!(gated.proposal.start_time, weighted.proposal.start_time);
+        assert_eq!(gated.proposal.end_time, weighted.proposal.end_time);
+    }
+}
+
+proptest! {
+    // Every case deploys both vote contracts and replays up to 24 votes on each
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    // Tests randomized scenarios replayed against the gated and weighted vote contracts.
+    // Expects: Both accept the same votes, gated tallies equal unique voter counts, and weighted
+    // tallies equal balance sums.
+    #[test]
+    fn prop_differential_tallies(
+        balances in prop::collection::vec(0..=1_000_000i128, 1..=6),
+        votes in prop::collection::vec((0..6usize, 0..CHOICES.len()), 0..24),
+    ) {
+        let votes: std::vec::Vec<(usize, usize)> = votes
+            .into_iter()
+            .map(|(holder, choice)| (holder % balances.len(), choice))
+            .collect();
+        check_scenario(&Scenario { balances: &balances, votes: &votes });
+    }
+
+    // Tests randomized scenarios where every holder has the same balance.
+    // Expects: Weighted tallies are the gated tallies scaled by the balance and both models
+    // reach the same outcome.
+    #[test]
+    fn prop_differential_equal_balances(
+        balance in 1..=1_000_000i128,
+        holders in 1..=6usize,
+        votes in prop::collection::vec((0..6usize, 0..CHOICES.len()), 0..24),
+    ) {
+        let balances = std::vec![balance; holders];
+        let votes: std::vec::Vec<(usize, usize)> = votes
+            .into_iter()
+            .map(|(holder, choice)| (holder % holders, choice))
+            .collect();
+        let (gated, weighted) = check_scenario(&Scenario { balances: &balances, votes: &votes });
+
+        let scaled: std::vec::Vec<i128> =
+            totals(&gated.proposal).iter().map(|total| total * balance).collect();
+        prop_assert_eq!(scaled, totals(&weighted.proposal).to_vec());
+        prop_assert_eq!(gated.status, weighted.status);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{assert_tally, create_token_contract, mint, set_time, setup_test_env};
+use governance_core::{GovernanceClient, GovernanceProposal, GovernanceStatus};
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, String, Symbol};
+
+// --- Proposal Defaults ---
+pub const PROPOSAL_START_DELAY: u64 = 100; // Seconds between creating a proposal and its start
+
+// Bundles a test environment, an admin, a governance token, and a vote contract reached through
+// the shared governance interface, so a test drives a proposal lifecycle in a few calls
+pub struct GovernanceHarness<'a> {
+    pub env: Env,                         // Environment with every authorization mocked
+    pub admin: Address,                   // Administrator passed to the vote contract
+    pub token: TokenClient<'a>,           // Stellar Asset Contract used as the governance token
+    pub governance: GovernanceClient<'a>, // Vote contract under test
+}
+
+impl<'a> GovernanceHarness<'a> {
+    // Sets up the environment and token, then registers the vote contract through deploy, which
+    // receives the environment, admin, and token address and returns the contract address
+    pub fn new<F>(deploy: F) -> Self
+    where
+        F: FnOnce(&Env, &Address, &Address) -> Address,
+    {
+        let env = setup_test_env();
+        let admin = Address::generate(&env);
+        let token = create_token_contract(&env, &admin);
+        let contract = deploy(&env, &admin, &token.address);
+        let governance = GovernanceClient::new(&env, &contract);
+        GovernanceHarness {
+            env,
+            admin,
+            token,
+            governance,
+        }
+    }
+
+    // Returns the address of the vote contract
+    pub fn contract(&self) -> Address {
+        self.governance.address.clone()
+    }
+
+    // Mints governance tokens to an address, such as a treasury or a contract under test
+    pub fn fund(&self, to: &Address, amount: i128) {
+        mint(&self.env, &self.token.address, to, amount);
+    }
+
+    // Generates a holder with a governance token balance
+    pub fn holder(&self, balance: i128) -> Address {
+        let holder = Address::generate(&self.env);
+        self.fund(&holder, balance);
+        holder
+    }
+
+    // Creates a proposal by the admin opening PROPOSAL_START_DELAY seconds from now and lasting
+    // the duration
+    pub fn propose(&self, id: &Symbol, duration: u64) -> GovernanceProposal {
+        let start_time = self.env.ledger().timestamp() + PROPOSAL_START_DELAY;
+        self.governance.create_proposal(
+            &self.admin,
+            id,
+            &String::from_str(&self.env, "Test proposal"),
+            &start_time,
+            &(start_time + duration),
+        );
+        self.governance.get_proposal(id)
+    }
+
+    // Moves the ledger to the start of a proposal's voting window
+    pub fn open_voting(&self, id: &Symbol) {
+        set_time(&self.env, self.governance.get_proposal(id).start_time);
+    }
+
+    // Moves the ledger past the end of a proposal's voting window
+    pub fn close_voting(&self, id: &Symbol) {
+        set_time(&self.env, self.governance.get_proposal(id).end_time + 1);
+    }
+
+    // Casts a vote of FOR, AGAINST, or ABSTAIN
+    pub fn vote(&self, voter: &Address, id: &Symbol, choice: &Symbol) {
+        self.governance.vote(voter, id, choice);
+    }
+
+    // Closes voting on a proposal and resolves its outcome
+    pub fn finalize(&self, id: &Symbol) -> GovernanceStatus {
+        self.close_voting(id);
+        self.governance.finalize(id)
+    }
+
+    // Asserts the FOR, AGAINST, and ABSTAIN totals of a proposal
+    pub fn assert_tally(
+        &self,
+        id: &Symbol,
+        total_for: i128,
+        total_against: i128,
+        total_abstain: i128,
+    ) {
+        assert_tally(
+            &self.governance,
+            id,
+            total_for,
+            total_against,
+            total_abstain,
+        );
+    }
+}
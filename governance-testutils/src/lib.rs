@@ -0,0 +1,197 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::rc::Rc;
+use core::fmt::Debug;
+use governance_core::GovernanceClient;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, Register},
+    token::{StellarAssetClient, TokenClient},
+    xdr, Address, Bytes, BytesN, ConstructorArgs, Env, FromVal, IntoVal, Symbol, Val, Vec,
+};
+
+mod harness;
+pub use harness::{GovernanceHarness, PROPOSAL_START_DELAY};
+
+// --- Ledger Defaults ---
+pub const TEST_START_TIME: u64 = 1_000_000; // Ledger timestamp every test environment starts at
+
+// --- Resource Limits ---
+pub const TX_CPU_INSTRUCTION_LIMIT: u64 = 100_000_000; // Soroban CPU instructions per transaction
+pub const TX_MEMORY_LIMIT: u64 = 41_943_040; // Soroban memory bytes per transaction (40 MiB)
+pub const BUDGET_PROPOSAL_COUNTS: [u32; 3] = [1, 100, 1_000]; // Stored proposals to benchmark at
+pub const BUDGET_FULL_LIST_COUNT: u32 = 100; // Most stored proposals whole-list reads must handle
+
+// Creates an environment with every authorization mocked and the ledger at TEST_START_TIME
+pub fn setup_test_env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = TEST_START_TIME;
+    });
+    e
+}
+
+// Moves the ledger timestamp forward by a number of seconds
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+}
+
+// Moves the ledger to an absolute UNIX timestamp
+pub fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|ledger| ledger.timestamp = timestamp);
+}
+
+// Registers a Stellar Asset Contract administered by the admin
+pub fn create_token_contract<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
+    let token_address = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    TokenClient::new(e, &token_address)
+}
+
+// Registers a native contract at the address a deployer derives from the salt and returns the
+// executable hash to deploy it with, so factory tests deploy the native contract instead of a
+// built WASM; the instance is removed again so the factory's deployment runs the constructor
+pub fn register_deployable<C, A>(
+    env: &Env,
+    deployer: &Address,
+    salt: &BytesN<32>,
+    contract: C,
+    constructor_args: A,
+) -> BytesN<32>
+where
+    C: Register,
+    A: ConstructorArgs,
+{
+    let address = env.as_contract(deployer, || {
+        env.deployer()
+            .with_current_contract(salt.clone())
+            .deployed_address()
+    });
+    env.register_at(&address, contract, constructor_args);
+    let instance_key = xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+        contract: xdr::ScAddress::from(&address),
+        key: xdr::ScVal::LedgerKeyContractInstance,
+        durability: xdr::ContractDataDurability::Persistent,
+    });
+    env.host()
+        .with_mut_storage(|storage| {
+            storage.del(&Rc::new(instance_key), &env.host().budget_cloned())
+        })
+        .unwrap();
+    env.deployer().upload_contract_wasm(Bytes::new(env))
+}
+
+// Mints Stellar Asset Contract tokens to an address
+pub fn mint(env: &Env, token: &Address, to: &Address, amount: i128) {
+    StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
+// Generates one holder per balance and mints that balance to it, in order
+pub fn create_holders(env: &Env, token: &Address, balances: &[i128]) -> Vec<Address> {
+    let mut holders = Vec::new(env);
+    for balance in balances {
+        let holder = Address::generate(env);
+        mint(env, token, &holder, *balance);
+        holders.push_back(holder);
+    }
+    holders
+}
+
+// Asserts the FOR, AGAINST, and ABSTAIN totals of a proposal through the shared governance
+// interface
+pub fn assert_tally(
+    governance: &GovernanceClient,
+    id: &Symbol,
+    total_for: i128,
+    total_against: i128,
+    total_abstain: i128,
+) {
+    let proposal = governance.get_proposal(id);
+    assert_eq!(
+        (
+            proposal.total_for,
+            proposal.total_against,
+            proposal.total_abstain
+        ),
+        (total_for, total_against, total_abstain)
+    );
+}
+
+// Asserts the most recent event was published by the contract with the topics and typed payload
+pub fn assert_last_event<T, D>(env: &Env, contract: &Address, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: FromVal<Env, Val> + Debug + PartialEq,
+{
+    let (event_contract, event_topics, event_data) = env.events().all().last().unwrap();
+    assert_eq!(&event_contract, contract);
+    assert_eq!(event_topics, topics.into_val(env));
+    assert_eq!(D::from_val(env, &event_data), data);
+}
+
+// Builds the (topics, data) pair of an expected event for assert_events
+pub fn event<T, D>(env: &Env, topics: T, data: D) -> (Vec<Val>, Val)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    (topics.into_val(env), data.into_val(env))
+}
+
+// Collects the (topics, data) pairs the contract published during the last invocation, in
+// publication order, leaving out events of the contracts it called
+pub fn contract_events(env: &Env, contract: &Address) -> Vec<(Vec<Val>, Val)> {
+    let mut published = Vec::new(env);
+    for (event_contract, topics, data) in env.events().all().iter() {
+        if &event_contract == contract {
+            published.push_back((topics, data));
+        }
+    }
+    published
+}
+
+// Asserts the contract published exactly the expected events during the last invocation, in
+// order; topics and payloads are compared by value, so any schema change fails the assertion
+pub fn assert_events(env: &Env, contract: &Address, expected: &[(Vec<Val>, Val)]) {
+    let mut expected_events = Vec::new(env);
+    for event in expected {
+        expected_events.push_back(event.clone());
+    }
+    assert_eq!(contract_events(env, contract), expected_events);
+}
+
+// Runs one contract invocation and asserts the CPU instructions and memory it metered stay under
+// the Soroban transaction limits, returning the invocation's result; the label names the call in
+// the failure message
+pub fn assert_within_budget<R, F>(env: &Env, label: &str, invoke: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    env.cost_estimate().budget().reset_unlimited();
+    let result = invoke();
+    let budget = env.cost_estimate().budget();
+    let cpu_instructions = budget.cpu_instruction_cost();
+    let memory_bytes = budget.memory_bytes_cost();
+    assert!(
+        cpu_instructions <= TX_CPU_INSTRUCTION_LIMIT,
+        "{} used {} CPU instructions, above the limit of {}",
+        label,
+        cpu_instructions,
+        TX_CPU_INSTRUCTION_LIMIT
+    );
+    assert!(
+        memory_bytes <= TX_MEMORY_LIMIT,
+        "{} used {} memory bytes, above the limit of {}",
+        label,
+        memory_bytes,
+        TX_MEMORY_LIMIT
+    );
+    result
+}
+
+// --- Test Module ---
+mod differential;
+mod test;
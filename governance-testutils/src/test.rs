@@ -0,0 +1,266 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::{GovernanceProposal, GovernanceStatus, VOTE_AGAINST, VOTE_FOR};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Symbol};
+
+// Governance mock: one vote per voter, FOR passes when it exceeds AGAINST
+#[contract]
+pub struct MockGovernance;
+
+#[contractimpl]
+impl MockGovernance {
+    pub fn __constructor(env: Env, admin: Address, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ADMIN"), &admin);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TOKEN"), &token);
+    }
+
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) {
+        proposer.require_auth();
+        let proposal = GovernanceProposal {
+            id: id.clone(),
+            description,
+            start_time,
+            end_time,
+            total_for: 0,
+            total_against: 0,
+            total_abstain: 0,
+            status: GovernanceStatus::Pending,
+        };
+        env.storage().instance().set(&id, &proposal);
+    }
+
+    pub fn vote(env: Env, user: Address, id: Symbol, choice: Symbol) {
+        user.require_auth();
+        let mut proposal = Self::get_proposal(env.clone(), id.clone());
+        if choice == VOTE_FOR {
+            proposal.total_for += 1;
+        } else if choice == VOTE_AGAINST {
+            proposal.total_against += 1;
+        } else {
+            proposal.total_abstain += 1;
+        }
+        env.storage().instance().set(&id, &proposal);
+        env.events().publish(("VOTE", "CAST", id), (user, choice));
+    }
+
+    pub fn finalize(env: Env, id: Symbol) -> GovernanceStatus {
+        let mut proposal = Self::get_proposal(env.clone(), id.clone());
+        proposal.status = if proposal.total_for > proposal.total_against {
+            GovernanceStatus::Succeeded
+        } else {
+            GovernanceStatus::Defeated
+        };
+        env.storage().instance().set(&id, &proposal);
+        proposal.status
+    }
+
+    pub fn get_proposal(env: Env, id: Symbol) -> GovernanceProposal {
+        env.storage().instance().get(&id).unwrap()
+    }
+
+    pub fn get_status(env: Env, id: Symbol) -> GovernanceStatus {
+        Self::get_proposal(env, id).status
+    }
+}
+
+fn setup_harness<'a>() -> GovernanceHarness<'a> {
+    GovernanceHarness::new(|env, admin, token| env.register(MockGovernance, (admin, token)))
+}
+
+// Tests the default test environment and ledger time helpers.
+// Expects: The ledger starts at TEST_START_TIME and moves by relative and absolute amounts.
+#[test]
+fn test_ledger_time() {
+    let e = setup_test_env();
+    assert_eq!(e.ledger().timestamp(), TEST_START_TIME);
+
+    advance_time(&e, 500);
+    assert_eq!(e.ledger().timestamp(), TEST_START_TIME + 500);
+
+    set_time(&e, 2_000_000);
+    assert_eq!(e.ledger().timestamp(), 2_000_000);
+}
+
+// Tests creating a token and minting balances to generated holders.
+// Expects: One distinct holder per balance, each holding its balance.
+#[test]
+fn test_create_holders() {
+    let e = setup_test_env();
+    let token = create_token_contract(&e, &Address::generate(&e));
+
+    let holders = create_holders(&e, &token.address, &[100, 250, 0]);
+
+    assert_eq!(holders.len(), 3);
+    assert_ne!(holders.get_unchecked(0), holders.get_unchecked(1));
+    assert_eq!(token.balance(&holders.get_unchecked(0)), 100);
+    assert_eq!(token.balance(&holders.get_unchecked(1)), 250);
+    assert_eq!(token.balance(&holders.get_unchecked(2)), 0);
+}
+
+// Tests a proposal lifecycle driven through the harness.
+// Expects: The proposal opens after PROPOSAL_START_DELAY, counts each vote, and succeeds once
+// voting closes.
+#[test]
+fn test_harness_lifecycle() {
+    let harness = setup_harness();
+    let voter1 = harness.holder(100);
+    let voter2 = harness.holder(100);
+    let voter3 = harness.holder(100);
+    let id = symbol_short!("PROP001");
+
+    let proposal = harness.propose(&id, 86_400);
+    assert_eq!(proposal.start_time, TEST_START_TIME + PROPOSAL_START_DELAY);
+    assert_eq!(proposal.end_time, proposal.start_time + 86_400);
+    assert_eq!(harness.token.balance(&voter1), 100);
+
+    harness.open_voting(&id);
+    assert_eq!(harness.env.ledger().timestamp(), proposal.start_time);
+    harness.vote(&voter1, &id, &VOTE_FOR);
+    harness.vote(&voter2, &id, &VOTE_FOR);
+    harness.vote(&voter3, &id, &VOTE_AGAINST);
+    harness.assert_tally(&id, 2, 1, 0);
+
+    assert_eq!(harness.finalize(&id), GovernanceStatus::Succeeded);
+    assert_eq!(harness.env.ledger().timestamp(), proposal.end_time + 1);
+}
+
+// Tests funding an arbitrary address from the harness token.
+// Expects: The address holds the minted amount.
+#[test]
+fn test_harness_fund() {
+    let harness = setup_harness();
+
+    harness.fund(&harness.contract(), 5_000);
+
+    assert_eq!(harness.token.balance(&harness.contract()), 5_000);
+}
+
+// Tests asserting the most recent event of a contract.
+// Expects: The vote event matches its contract, topics, and payload.
+#[test]
+fn test_assert_last_event() {
+    let harness = setup_harness();
+    let voter = harness.holder(100);
+    let id = symbol_short!("PROP001");
+    harness.propose(&id, 86_400);
+    harness.open_voting(&id);
+
+    harness.vote(&voter, &id, &VOTE_FOR);
+
+    assert_last_event(
+        &harness.env,
+        &harness.contract(),
+        ("VOTE", "CAST", id),
+        (voter, VOTE_FOR),
+    );
+}
+
+// Tests asserting the complete event list of the last invocation.
+// Expects: A vote publishes only its own event, finalizing publishes none, and a payload that
+// differs by a single value fails the assertion.
+#[test]
+fn test_assert_events() {
+    let harness = setup_harness();
+    let env = &harness.env;
+    let voter = harness.holder(100);
+    let id = symbol_short!("PROP001");
+    harness.propose(&id, 86_400);
+    harness.open_voting(&id);
+
+    harness.vote(&voter, &id, &VOTE_FOR);
+    let cast = event(env, ("VOTE", "CAST", id.clone()), (voter.clone(), VOTE_FOR));
+    assert_events(env, &harness.contract(), &[cast]);
+    assert_eq!(contract_events(env, &harness.contract()).len(), 1);
+
+    let against = event(env, ("VOTE", "CAST", id.clone()), (voter, VOTE_AGAINST));
+    assert_ne!(
+        contract_events(env, &harness.contract()),
+        Vec::from_array(env, [against])
+    );
+
+    harness.finalize(&id);
+    assert_events(env, &harness.contract(), &[]);
+}
+
+// Tests asserting totals that do not match the proposal.
+// Expects: The assertion panics.
+#[test]
+#[should_panic(expected = "assertion `left == right` failed")]
+fn test_assert_tally_mismatch() {
+    let harness = setup_harness();
+    let voter = harness.holder(100);
+    let id = symbol_short!("PROP001");
+    harness.propose(&id, 86_400);
+    harness.open_voting(&id);
+    harness.vote(&voter, &id, &VOTE_AGAINST);
+
+    harness.assert_tally(&id, 1, 0, 0);
+}
+
+// Tests measuring the resources of a single invocation.
+// Expects: A vote stays under the transaction limits and meters some CPU and memory, and the
+// result of a read passes through.
+#[test]
+fn test_assert_within_budget() {
+    let harness = setup_harness();
+    let voter = harness.holder(100);
+    let id = symbol_short!("PROP001");
+    harness.propose(&id, 86_400);
+    harness.open_voting(&id);
+
+    assert_within_budget(&harness.env, "vote", || {
+        harness.vote(&voter, &id, &VOTE_FOR)
+    });
+    assert!(harness.env.cost_estimate().budget().cpu_instruction_cost() > 0);
+    assert!(harness.env.cost_estimate().budget().memory_bytes_cost() > 0);
+
+    let proposal = assert_within_budget(&harness.env, "get_proposal", || {
+        harness.governance.get_proposal(&id)
+    });
+    assert_eq!(proposal.total_for, 1);
+}
+
+// Tests deploying a native contract registered ahead of a factory deployment.
+// Expects: The deployment lands at the derived address and runs the constructor with the
+// deployer's arguments.
+#[test]
+fn test_register_deployable() {
+    let e = setup_test_env();
+    let deployer = e.register(
+        MockGovernance,
+        (Address::generate(&e), Address::generate(&e)),
+    );
+    let salt = BytesN::from_array(&e, &[1; 32]);
+    let admin = Address::generate(&e);
+
+    let executable = register_deployable(
+        &e,
+        &deployer,
+        &salt,
+        MockGovernance,
+        (Address::generate(&e), Address::generate(&e)),
+    );
+    let deployed = e.as_contract(&deployer, || {
+        e.deployer()
+            .with_current_contract(salt.clone())
+            .deploy_v2(executable, (admin.clone(), deployer.clone()))
+    });
+
+    let stored_admin: Address = e.as_contract(&deployed, || {
+        e.storage().instance().get(&symbol_short!("ADMIN")).unwrap()
+    });
+    assert_eq!(stored_admin, admin);
+}
@@ -0,0 +1,419 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+
+pub use pg_contracts_types::governance_token::GovernanceTokenAllowance;
+
+// --- Token Metadata Constraints ---
+const MAX_DECIMALS: u32 = 18; // Maximum decimal places supported by the token
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const BALANCE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_TOKEN];
+
+// Defines the structure for persistent, temporary, and instance storage
+#[contracttype]
+pub enum GovernanceTokenContractDataKey {
+    Admin,                       // Token administrator allowed to mint
+    Decimals,                    // Number of decimal places
+    Name,                        // Human-readable token name
+    Symbol,                      // Token ticker symbol
+    TotalSupply,                 // Total tokens in circulation
+    Balance(Address),            // Token balance per holder
+    Allowance(Address, Address), // Spending allowance per (owner, spender) pair
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 800-899 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GovernanceTokenContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidAmount = 13,             // Amounts must not be negative
+    InvalidDecimals = 804,          // Decimals exceed the supported maximum
+    InsufficientBalance = 805,      // The holder does not have enough tokens
+    InsufficientAllowance = 806,    // The spender's allowance is too low or expired
+    InvalidExpiration = 807,        // A non-zero allowance cannot expire in the past
+}
+
+#[contract]
+pub struct GovernanceTokenContract;
+
+#[contractimpl]
+impl GovernanceTokenContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, GovernanceTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceTokenContractDataKey::Admin)
+            .ok_or(GovernanceTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Rejects negative amounts
+    fn check_amount(amount: i128) -> Result<(), GovernanceTokenContractErrors> {
+        if amount < 0 {
+            return Err(GovernanceTokenContractErrors::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    // Saves the balance of a holder and extends its TTL
+    fn write_balance(env: &Env, id: &Address, amount: i128) {
+        let balance_key = GovernanceTokenContractDataKey::Balance(id.clone());
+        env.storage().persistent().set(&balance_key, &amount);
+        env.storage().persistent().extend_ttl(
+            &balance_key,
+            BALANCE_TTL_EXTENSION,
+            BALANCE_TTL_EXTENSION,
+        );
+    }
+
+    // Credits tokens to a holder
+    fn receive_balance(env: &Env, id: &Address, amount: i128) {
+        let balance = Self::balance(env.clone(), id.clone());
+        Self::write_balance(env, id, balance.saturating_add(amount));
+    }
+
+    // Debits tokens from a holder, failing if the balance is too low
+    fn spend_balance(
+        env: &Env,
+        id: &Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        let balance = Self::balance(env.clone(), id.clone());
+        if balance < amount {
+            return Err(GovernanceTokenContractErrors::InsufficientBalance);
+        }
+        Self::write_balance(env, id, balance - amount);
+        Ok(())
+    }
+
+    // Loads an allowance, treating expired allowances as zero
+    fn read_allowance(env: &Env, from: &Address, spender: &Address) -> GovernanceTokenAllowance {
+        let allowance_key =
+            GovernanceTokenContractDataKey::Allowance(from.clone(), spender.clone());
+        let allowance: Option<GovernanceTokenAllowance> =
+            env.storage().temporary().get(&allowance_key);
+        match allowance {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            Some(allowance) => GovernanceTokenAllowance {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            },
+            None => GovernanceTokenAllowance {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+
+    // Saves an allowance in temporary storage until its expiration ledger
+    fn write_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            return Err(GovernanceTokenContractErrors::InvalidExpiration);
+        }
+
+        let allowance_key =
+            GovernanceTokenContractDataKey::Allowance(from.clone(), spender.clone());
+        let allowance = GovernanceTokenAllowance {
+            amount,
+            expiration_ledger,
+        };
+        env.storage().temporary().set(&allowance_key, &allowance);
+        if amount > 0 {
+            let live_for = expiration_ledger - env.ledger().sequence();
+            env.storage()
+                .temporary()
+                .extend_ttl(&allowance_key, live_for, live_for);
+        }
+        Ok(())
+    }
+
+    // Consumes part of an allowance, failing if it is too low or expired
+    fn spend_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        let allowance = Self::read_allowance(env, from, spender);
+        if allowance.amount < amount {
+            return Err(GovernanceTokenContractErrors::InsufficientAllowance);
+        }
+        if amount > 0 {
+            Self::write_allowance(
+                env,
+                from,
+                spender,
+                allowance.amount - amount,
+                allowance.expiration_ledger,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Adjusts the total supply by a signed delta
+    fn adjust_supply(env: &Env, delta: i128) {
+        let total_supply = Self::total_supply(env.clone()).saturating_add(delta);
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::TotalSupply, &total_supply);
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and token metadata
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&GovernanceTokenContractDataKey::Admin)
+        {
+            return Err(GovernanceTokenContractErrors::ContractAlreadyInitialized);
+        }
+        if decimal > MAX_DECIMALS {
+            return Err(GovernanceTokenContractErrors::InvalidDecimals);
+        }
+
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::Decimals, &decimal);
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::Name, &name);
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::Symbol, &symbol);
+        Ok(())
+    }
+
+    // Mints new tokens to an address (admin only)
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::check_amount(amount)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Self::receive_balance(&env, &to, amount);
+        Self::adjust_supply(&env, amount);
+
+        env.events()
+            .publish((symbol_short!("mint"), admin, to), amount);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&GovernanceTokenContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish((symbol_short!("set_admin"), current_admin), new_admin);
+        Ok(())
+    }
+
+    // Sets the allowance of a spender over the caller's tokens
+    pub fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+        Ok(())
+    }
+
+    // Transfers tokens from the caller to another address
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::spend_balance(&env, &from, amount)?;
+        Self::receive_balance(&env, &to, amount);
+
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
+    }
+
+    // Transfers tokens on behalf of an owner using the spender's allowance
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        spender.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::spend_allowance(&env, &from, &spender, amount)?;
+        Self::spend_balance(&env, &from, amount)?;
+        Self::receive_balance(&env, &to, amount);
+
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
+    }
+
+    // Burns tokens held by the caller
+    pub fn burn(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::spend_balance(&env, &from, amount)?;
+        Self::adjust_supply(&env, -amount);
+
+        env.events().publish((symbol_short!("burn"), from), amount);
+        Ok(())
+    }
+
+    // Burns tokens on behalf of an owner using the spender's allowance
+    pub fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), GovernanceTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        spender.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::spend_allowance(&env, &from, &spender, amount)?;
+        Self::spend_balance(&env, &from, amount)?;
+        Self::adjust_supply(&env, -amount);
+
+        env.events().publish((symbol_short!("burn"), from), amount);
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the remaining allowance of a spender over an owner's tokens
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Self::read_allowance(&env, &from, &spender).amount
+    }
+
+    // Returns the token balance of an address
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&GovernanceTokenContractDataKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    // Returns the total tokens in circulation
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&GovernanceTokenContractDataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    // Returns the number of decimal places
+    pub fn decimals(env: Env) -> Result<u32, GovernanceTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceTokenContractDataKey::Decimals)
+            .ok_or(GovernanceTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token name
+    pub fn name(env: Env) -> Result<String, GovernanceTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceTokenContractDataKey::Name)
+            .ok_or(GovernanceTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token ticker symbol
+    pub fn symbol(env: Env) -> Result<String, GovernanceTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&GovernanceTokenContractDataKey::Symbol)
+            .ok_or(GovernanceTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token administrator address
+    pub fn admin(env: Env) -> Result<Address, GovernanceTokenContractErrors> {
+        Self::read_admin(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
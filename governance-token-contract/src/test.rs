@@ -0,0 +1,268 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, GOVERNANCE_TOKEN_CODES};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn create_governance_token<'a>(
+    e: &Env,
+    admin: &Address,
+    decimal: u32,
+) -> GovernanceTokenContractClient<'a> {
+    let contract_address = e.register(
+        GovernanceTokenContract,
+        (
+            admin.clone(),
+            decimal,
+            String::from_str(e, "Play Governance"),
+            String::from_str(e, "PLAY"),
+        ),
+    );
+    GovernanceTokenContractClient::new(e, &contract_address)
+}
+
+fn setup_test_env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 1000000;
+        ledger.sequence_number = 100;
+    });
+    e
+}
+
+// Tests successful initialization with admin and token metadata.
+// Expects: Metadata match the constructor arguments and no supply exists yet.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.name(), String::from_str(&e, "Play Governance"));
+    assert_eq!(client.symbol(), String::from_str(&e, "PLAY"));
+    assert_eq!(client.total_supply(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    e.register_at(
+        &client.address,
+        GovernanceTokenContract,
+        (
+            admin,
+            7_u32,
+            String::from_str(&e, "Play Governance"),
+            String::from_str(&e, "PLAY"),
+        ),
+    );
+}
+
+// Tests initialization with more decimals than supported.
+// Expects: InvalidDecimals error (Error #804).
+#[test]
+#[should_panic(expected = "Error(Contract, #804)")]
+fn test_invalid_decimals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+
+    create_governance_token(&e, &admin, 19);
+}
+
+// Tests minting by the admin.
+// Expects: The recipient balance and total supply increase by the minted amount.
+#[test]
+fn test_mint() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&user, &1000);
+
+    assert_eq!(client.balance(&user), 1000);
+    assert_eq!(client.total_supply(), 1000);
+}
+
+// Tests minting a negative amount.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_mint_negative_amount() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&Address::generate(&e), &-1);
+}
+
+// Tests transferring tokens between holders.
+// Expects: Balances move from sender to recipient without changing supply.
+#[test]
+fn test_transfer() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&alice, &1000);
+    client.transfer(&alice, &bob, &400);
+
+    assert_eq!(client.balance(&alice), 600);
+    assert_eq!(client.balance(&bob), 400);
+    assert_eq!(client.total_supply(), 1000);
+}
+
+// Tests transferring more tokens than held.
+// Expects: InsufficientBalance error (Error #805).
+#[test]
+#[should_panic(expected = "Error(Contract, #805)")]
+fn test_transfer_insufficient_balance() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&alice, &100);
+    client.transfer(&alice, &Address::generate(&e), &101);
+}
+
+// Tests spending an approved allowance with transfer_from.
+// Expects: Tokens move and the allowance decreases by the spent amount.
+#[test]
+fn test_approve_and_transfer_from() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&alice, &1000);
+    client.approve(&alice, &spender, &500, &200);
+    client.transfer_from(&spender, &alice, &bob, &300);
+
+    assert_eq!(client.allowance(&alice, &spender), 200);
+    assert_eq!(client.balance(&alice), 700);
+    assert_eq!(client.balance(&bob), 300);
+}
+
+// Tests spending an allowance after its expiration ledger.
+// Expects: InsufficientAllowance error (Error #806).
+#[test]
+#[should_panic(expected = "Error(Contract, #806)")]
+fn test_transfer_from_expired_allowance() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&alice, &1000);
+    client.approve(&alice, &spender, &500, &200);
+    e.ledger().with_mut(|ledger| ledger.sequence_number = 201);
+
+    assert_eq!(client.allowance(&alice, &spender), 0);
+    client.transfer_from(&spender, &alice, &Address::generate(&e), &100);
+}
+
+// Tests approving a non-zero allowance that has already expired.
+// Expects: InvalidExpiration error (Error #807).
+#[test]
+#[should_panic(expected = "Error(Contract, #807)")]
+fn test_approve_past_expiration() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.approve(&Address::generate(&e), &Address::generate(&e), &500, &99);
+}
+
+// Tests burning held tokens and burning through an allowance.
+// Expects: Balances and total supply decrease by the burned amounts.
+#[test]
+fn test_burn_and_burn_from() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.mint(&alice, &1000);
+    client.burn(&alice, &100);
+    client.approve(&alice, &spender, &300, &200);
+    client.burn_from(&spender, &alice, &300);
+
+    assert_eq!(client.balance(&alice), 600);
+    assert_eq!(client.allowance(&alice, &spender), 0);
+    assert_eq!(client.total_supply(), 600);
+}
+
+// Tests transferring the admin role.
+// Expects: The new admin is stored and can mint.
+#[test]
+fn test_set_admin() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let client = create_governance_token(&e, &admin, 7);
+
+    client.set_admin(&new_admin);
+    assert_eq!(client.admin(), new_admin);
+
+    client.mint(&new_admin, &50);
+    assert_eq!(client.balance(&new_admin), 50);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with token supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_governance_token(&e, &Address::generate(&e), 7);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_TOKEN));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 800 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        GovernanceTokenContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        GovernanceTokenContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        GovernanceTokenContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    for error in [
+        GovernanceTokenContractErrors::InvalidDecimals,
+        GovernanceTokenContractErrors::InsufficientBalance,
+        GovernanceTokenContractErrors::InsufficientAllowance,
+        GovernanceTokenContractErrors::InvalidExpiration,
+    ] {
+        assert!(in_range(error as u32, GOVERNANCE_TOKEN_CODES));
+    }
+}
@@ -0,0 +1,291 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+pub use pg_contracts_types::lock_boost::LockBoostLock;
+
+// --- Lock Constraints ---
+const MONTH: u64 = 2_592_000; // 30 days in seconds
+const MIN_LOCK_MONTHS: u32 = 1; // Shortest lock accepted
+const MAX_LOCK_MONTHS: u32 = 12; // Longest lock accepted
+const MAX_LOCK_DURATION: u64 = MAX_LOCK_MONTHS as u64 * MONTH; // Longest lock in seconds
+
+// --- Multiplier Scale ---
+const MULTIPLIER_SCALE: i128 = 10_000; // Basis points, 10_000 = 1.0x
+const MAX_BOOST: i128 = 10_000; // Extra weight at the maximum remaining lock time (2.0x total)
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const LOCK_TTL_EXTENSION: u32 = 6_220_800; // ~72 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_WEIGHT];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum LockBoostContractDataKey {
+    Admin,         // Contract administrator address
+    Token,         // Governance token accepted for locks
+    Lock(Address), // Locked amount and unlock time per user
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1300-1399 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockBoostContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidAmount = 13,             // Amount must be greater than zero
+    InvalidLockDuration = 1304,     // Lock must be 1-12 months and extensions must lengthen it
+    LockAlreadyExists = 1305,       // The user already has an active lock
+    LockNotFound = 1306,            // The user has no lock
+    LockActive = 1307,              // Tokens cannot be unlocked before the unlock time
+}
+
+// Returns the multiplier in basis points for a remaining lock time, rising linearly from
+// 1.0x with no time left to 2.0x at the maximum lock duration
+pub fn boost_multiplier(remaining: u64) -> i128 {
+    let remaining = remaining.min(MAX_LOCK_DURATION) as i128;
+    MULTIPLIER_SCALE + MAX_BOOST * remaining / MAX_LOCK_DURATION as i128
+}
+
+// Returns the boosted weight of a locked amount for a remaining lock time
+pub fn boosted_weight(amount: i128, remaining: u64) -> i128 {
+    amount.max(0).saturating_mul(boost_multiplier(remaining)) / MULTIPLIER_SCALE
+}
+
+#[contract]
+pub struct LockBoostContract;
+
+#[contractimpl]
+impl LockBoostContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, LockBoostContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LockBoostContractDataKey::Admin)
+            .ok_or(LockBoostContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, LockBoostContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LockBoostContractDataKey::Token)
+            .ok_or(LockBoostContractErrors::ContractNotInitialized)
+    }
+
+    // Saves a user's lock and extends its TTL
+    fn write_lock(env: &Env, user: &Address, lock: &LockBoostLock) {
+        let lock_key = LockBoostContractDataKey::Lock(user.clone());
+        env.storage().persistent().set(&lock_key, lock);
+        env.storage()
+            .persistent()
+            .extend_ttl(&lock_key, LOCK_TTL_EXTENSION, LOCK_TTL_EXTENSION);
+    }
+
+    // Converts a lock length in months into an unlock time from now
+    fn unlock_time_for(env: &Env, months: u32) -> Result<u64, LockBoostContractErrors> {
+        if !(MIN_LOCK_MONTHS..=MAX_LOCK_MONTHS).contains(&months) {
+            return Err(LockBoostContractErrors::InvalidLockDuration);
+        }
+        Ok(env.ledger().timestamp() + months as u64 * MONTH)
+    }
+
+    // Returns the seconds left until a lock can be unlocked
+    fn remaining(env: &Env, lock: &LockBoostLock) -> u64 {
+        lock.unlock_time.saturating_sub(env.ledger().timestamp())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and governance token
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), LockBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&LockBoostContractDataKey::Admin)
+        {
+            return Err(LockBoostContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&LockBoostContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&LockBoostContractDataKey::Token, &token);
+        Ok(())
+    }
+
+    // Locks tokens for 1-12 months in exchange for a voting weight multiplier
+    pub fn lock(
+        env: Env,
+        user: Address,
+        amount: i128,
+        months: u32,
+    ) -> Result<(), LockBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(LockBoostContractErrors::InvalidAmount);
+        }
+        let unlock_time = Self::unlock_time_for(&env, months)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&LockBoostContractDataKey::Lock(user.clone()))
+        {
+            return Err(LockBoostContractErrors::LockAlreadyExists);
+        }
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &user,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        Self::write_lock(
+            &env,
+            &user,
+            &LockBoostLock {
+                amount,
+                unlock_time,
+            },
+        );
+
+        env.events()
+            .publish(("LOCK", "CREATED", user), (amount, unlock_time));
+        Ok(())
+    }
+
+    // Extends an existing lock to end 1-12 months from now, later than its current end
+    pub fn extend_lock(
+        env: Env,
+        user: Address,
+        months: u32,
+    ) -> Result<(), LockBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let mut lock = Self::get_lock(env.clone(), user.clone())?;
+        let unlock_time = Self::unlock_time_for(&env, months)?;
+        if unlock_time <= lock.unlock_time {
+            return Err(LockBoostContractErrors::InvalidLockDuration);
+        }
+
+        lock.unlock_time = unlock_time;
+        Self::write_lock(&env, &user, &lock);
+
+        env.events()
+            .publish(("LOCK", "EXTENDED", user), unlock_time);
+        Ok(())
+    }
+
+    // Returns locked tokens to the user once the unlock time has passed
+    pub fn unlock(env: Env, user: Address) -> Result<i128, LockBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let lock = Self::get_lock(env.clone(), user.clone())?;
+        if env.ledger().timestamp() < lock.unlock_time {
+            return Err(LockBoostContractErrors::LockActive);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&LockBoostContractDataKey::Lock(user.clone()));
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &user,
+            &lock.amount,
+        );
+
+        env.events()
+            .publish(("LOCK", "UNLOCKED", user), lock.amount);
+        Ok(lock.amount)
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), LockBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&LockBoostContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns w = amount * multiplier / 10_000 for the user's lock, zero without a lock
+    pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+        match Self::get_lock(env.clone(), user) {
+            Ok(lock) => boosted_weight(lock.amount, Self::remaining(&env, &lock)),
+            Err(_) => 0,
+        }
+    }
+
+    // Returns the user's current multiplier in basis points, 1.0x without a lock
+    pub fn get_multiplier(env: Env, user: Address) -> i128 {
+        match Self::get_lock(env.clone(), user) {
+            Ok(lock) => boost_multiplier(Self::remaining(&env, &lock)),
+            Err(_) => MULTIPLIER_SCALE,
+        }
+    }
+
+    // Returns the user's lock
+    pub fn get_lock(env: Env, user: Address) -> Result<LockBoostLock, LockBoostContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&LockBoostContractDataKey::Lock(user))
+            .ok_or(LockBoostContractErrors::LockNotFound)
+    }
+
+    // Returns the governance token accepted for locks
+    pub fn get_token(env: Env) -> Result<Address, LockBoostContractErrors> {
+        Self::read_token(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
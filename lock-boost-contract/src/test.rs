@@ -0,0 +1,275 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, LOCK_BOOST_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use proptest::prelude::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+fn create_lock_boost_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> LockBoostContractClient<'a> {
+    let contract_address = e.register(
+        LockBoostContract,
+        LockBoostContractArgs::__constructor(admin, token_address),
+    );
+    LockBoostContractClient::new(e, &contract_address)
+}
+
+// Deploys the token and lock-boost contract and mints 10,000 tokens to a user
+fn setup_lock_boost<'a>(e: &Env) -> (LockBoostContractClient<'a>, TokenClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let user = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    StellarAssetClient::new(e, &token.address).mint(&user, &10000);
+    let client = create_lock_boost_contract(e, &admin, &token.address);
+    (client, token, user)
+}
+
+// Tests successful initialization with admin and governance token.
+// Expects: Users without a lock have zero weight and a 1.0x multiplier.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_lock_boost(&e);
+
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+    assert_eq!(client.get_multiplier(&user), 10_000);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_lock_boost(&e);
+
+    e.register_at(
+        &client.address,
+        LockBoostContract,
+        LockBoostContractArgs::__constructor(&user, &token.address),
+    );
+}
+
+// Tests locking tokens for the maximum duration.
+// Expects: Tokens move into the contract and the weight is doubled.
+#[test]
+fn test_lock() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &12);
+
+    let lock = client.get_lock(&user);
+    assert_eq!(lock.amount, 1000);
+    assert_eq!(lock.unlock_time, 1000000 + 12 * MONTH);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(client.get_multiplier(&user), 20_000);
+    assert_eq!(client.calculate_weight(&user, &0), 2000);
+}
+
+// Tests the multiplier decaying as the lock approaches its end.
+// Expects: A 6-month lock starts at 1.5x and falls to 1.25x after three months.
+#[test]
+fn test_multiplier_decay() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &6);
+    assert_eq!(client.calculate_weight(&user, &0), 1500);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 3 * MONTH);
+    assert_eq!(client.calculate_weight(&user, &0), 1250);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 3 * MONTH);
+    assert_eq!(client.calculate_weight(&user, &0), 1000);
+}
+
+// Tests locking with zero or thirteen months.
+// Expects: InvalidLockDuration error (Error #1304) outside the 1-12 month range.
+#[test]
+fn test_lock_invalid_duration() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    assert_eq!(
+        client.try_lock(&user, &1000, &0),
+        Err(Ok(LockBoostContractErrors::InvalidLockDuration))
+    );
+    assert_eq!(
+        client.try_lock(&user, &1000, &13),
+        Err(Ok(LockBoostContractErrors::InvalidLockDuration))
+    );
+}
+
+// Tests locking a non-positive amount.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_lock_invalid_amount() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &0, &6);
+}
+
+// Tests locking twice without unlocking.
+// Expects: LockAlreadyExists error (Error #1305).
+#[test]
+#[should_panic(expected = "Error(Contract, #1305)")]
+fn test_lock_twice() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &6);
+    client.lock(&user, &1000, &6);
+}
+
+// Tests extending a lock to a later end.
+// Expects: The unlock time moves out and the multiplier rises.
+#[test]
+fn test_extend_lock() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &3);
+    client.extend_lock(&user, &12);
+
+    assert_eq!(client.get_lock(&user).unlock_time, 1000000 + 12 * MONTH);
+    assert_eq!(client.calculate_weight(&user, &0), 2000);
+}
+
+// Tests extending a lock to an end no later than the current one.
+// Expects: InvalidLockDuration error (Error #1304).
+#[test]
+#[should_panic(expected = "Error(Contract, #1304)")]
+fn test_extend_lock_shorter() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &6);
+    client.extend_lock(&user, &6);
+}
+
+// Tests unlocking after the lock has ended.
+// Expects: Tokens return to the user and the lock is removed.
+#[test]
+fn test_unlock() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &1);
+    e.ledger().with_mut(|ledger| ledger.timestamp += MONTH);
+
+    assert_eq!(client.unlock(&user), 1000);
+    assert_eq!(token.balance(&user), 10000);
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+    assert_eq!(
+        client.try_get_lock(&user),
+        Err(Ok(LockBoostContractErrors::LockNotFound))
+    );
+}
+
+// Tests unlocking before the lock has ended.
+// Expects: LockActive error (Error #1307).
+#[test]
+#[should_panic(expected = "Error(Contract, #1307)")]
+fn test_unlock_before_end() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_lock_boost(&e);
+
+    client.lock(&user, &1000, &1);
+    e.ledger().with_mut(|ledger| ledger.timestamp += MONTH - 1);
+    client.unlock(&user);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_lock_boost(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1300 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        LockBoostContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        LockBoostContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        LockBoostContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    for error in [
+        LockBoostContractErrors::InvalidLockDuration,
+        LockBoostContractErrors::LockAlreadyExists,
+        LockBoostContractErrors::LockNotFound,
+        LockBoostContractErrors::LockActive,
+    ] {
+        assert!(in_range(error as u32, LOCK_BOOST_CODES));
+    }
+}
+
+proptest! {
+    // Tests the multiplier bounds for any remaining lock time.
+    // Expects: The multiplier stays between 1.0x and 2.0x.
+    #[test]
+    fn prop_multiplier_bounded(remaining in any::<u64>()) {
+        let multiplier = boost_multiplier(remaining);
+        prop_assert!(multiplier >= MULTIPLIER_SCALE);
+        prop_assert!(multiplier <= MULTIPLIER_SCALE + MAX_BOOST);
+    }
+
+    // Tests the multiplier ordering for any two remaining lock times.
+    // Expects: More remaining lock time never yields a smaller multiplier.
+    #[test]
+    fn prop_multiplier_monotonic(a in 0..=MAX_LOCK_DURATION * 2, b in 0..=MAX_LOCK_DURATION * 2) {
+        let (shorter, longer) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(boost_multiplier(shorter) <= boost_multiplier(longer));
+    }
+
+    // Tests the multiplier against the linear formula for every whole-month lock.
+    // Expects: A lock of m months remaining yields 1.0x + m / 12.
+    #[test]
+    fn prop_multiplier_proportional(months in 0..=MAX_LOCK_MONTHS) {
+        let expected = MULTIPLIER_SCALE + MAX_BOOST * months as i128 / MAX_LOCK_MONTHS as i128;
+        prop_assert_eq!(boost_multiplier(months as u64 * MONTH), expected);
+    }
+
+    // Tests the boosted weight for any amount and remaining lock time.
+    // Expects: Weight is between the locked amount and twice the locked amount.
+    #[test]
+    fn prop_weight_bounded(amount in 0..=i64::MAX as i128, remaining in any::<u64>()) {
+        let weight = boosted_weight(amount, remaining);
+        prop_assert!(weight >= amount);
+        prop_assert!(weight <= amount * 2);
+    }
+
+    // Tests negative amounts passed to the weight math.
+    // Expects: Weight is clamped to zero.
+    #[test]
+    fn prop_weight_non_negative(amount in i128::MIN..0, remaining in any::<u64>()) {
+        prop_assert_eq!(boosted_weight(amount, remaining), 0);
+    }
+}
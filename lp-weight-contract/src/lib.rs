@@ -0,0 +1,352 @@
+#![no_std]
+
+use governance_core::{normalize, SupplyClient, TokenSource};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, token::TokenClient,
+    Address, Env, Symbol,
+};
+
+pub use pg_contracts_types::lp_weight::LpWeightReserves;
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_WEIGHT];
+
+// Cross-contract interface of two-asset liquidity pools exposing their reserves, such as
+// Soroswap pairs
+#[contractclient(name = "LiquidityPoolClient")]
+pub trait LiquidityPoolInterface {
+    fn token_0(env: Env) -> Address;
+    fn token_1(env: Env) -> Address;
+    fn get_reserves(env: Env) -> (i128, i128);
+}
+
+// Defines the structure for instance storage
+#[contracttype]
+pub enum LpWeightContractDataKey {
+    Admin,           // Contract administrator address
+    Pool,            // Liquidity pool whose shares are valued
+    ShareToken,      // Token representing pool shares, which may be the pool itself
+    GovernanceToken, // Governance token paired in the pool
+    ReserveIndex,    // Position of the governance token in the pool's reserves (0 or 1)
+    MaxAge,          // Seconds synced reserves stay usable when the pool cannot be read
+    Paused,          // Emergency switch valuing every share at zero
+    Reserves,        // Reserves read at the latest sync
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2100-2199 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LpWeightContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidConfig = 15,             // Maximum reserve age must be positive
+    InvalidPool = 2101,             // The pool does not pair the governance token
+    PoolUnavailable = 2102,         // The pool reserves or share supply could not be read
+}
+
+// Returns the governance tokens a share balance redeems for, shares × reserve / total shares,
+// rounded down and never above the reserve; zero without outstanding shares. The reserve is split
+// into whole tokens per share and a remainder so only the remainder product can saturate
+pub fn share_value(shares: i128, reserves: &LpWeightReserves) -> i128 {
+    if reserves.total_shares <= 0 {
+        return 0;
+    }
+    let shares = shares.clamp(0, reserves.total_shares);
+    let reserve = reserves.governance_reserve.max(0);
+    let whole = reserve / reserves.total_shares * shares;
+    let remainder =
+        (reserve % reserves.total_shares).saturating_mul(shares) / reserves.total_shares;
+    (whole + remainder).min(reserve)
+}
+
+#[contract]
+pub struct LpWeightContract;
+
+#[contractimpl]
+impl LpWeightContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, LpWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::Admin)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Rejects a zero maximum reserve age
+    fn check_max_age(max_age: u64) -> Result<(), LpWeightContractErrors> {
+        if max_age == 0 {
+            return Err(LpWeightContractErrors::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    // Reads the governance reserve from the pool and the share supply from the share token, or
+    // None when either call fails, e.g. because the pool is paused or upgraded to an
+    // incompatible interface
+    fn read_pool(env: &Env) -> Result<Option<LpWeightReserves>, LpWeightContractErrors> {
+        let pool = LiquidityPoolClient::new(env, &Self::get_pool(env.clone())?);
+        let share_token = SupplyClient::new(env, &Self::get_share_token(env.clone())?);
+        let reserve_index: u32 = env
+            .storage()
+            .instance()
+            .get(&LpWeightContractDataKey::ReserveIndex)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)?;
+        let (Some((reserve_0, reserve_1)), Some(total_shares)) = (
+            pool.try_get_reserves().ok().and_then(Result::ok),
+            share_token.try_total_supply().ok().and_then(Result::ok),
+        ) else {
+            return Ok(None);
+        };
+        Ok(Some(LpWeightReserves {
+            governance_reserve: if reserve_index == 0 {
+                reserve_0
+            } else {
+                reserve_1
+            },
+            total_shares,
+            synced_at: env.ledger().timestamp(),
+        }))
+    }
+
+    // Returns the live pool reserves, falling back to the synced reserves while they are no
+    // older than the maximum age; None when neither is usable
+    fn current_reserves(env: &Env) -> Result<Option<LpWeightReserves>, LpWeightContractErrors> {
+        if let Some(reserves) = Self::read_pool(env)? {
+            return Ok(Some(reserves));
+        }
+        let max_age = Self::get_max_age(env.clone())?;
+        Ok(Self::get_reserves(env.clone()).filter(|reserves| {
+            env.ledger().timestamp().saturating_sub(reserves.synced_at) <= max_age
+        }))
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, the pool and its share token, the governance token it
+    // pairs, and how long synced reserves stay usable, syncing the reserves once when the pool
+    // can be read
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        pool: Address,
+        share_token: Address,
+        governance_token: TokenSource,
+        max_age: u64,
+    ) -> Result<(), LpWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&LpWeightContractDataKey::Admin)
+        {
+            return Err(LpWeightContractErrors::ContractAlreadyInitialized);
+        }
+        Self::check_max_age(max_age)?;
+
+        let pool_client = LiquidityPoolClient::new(&env, &pool);
+        let governance_address = governance_token.address();
+        let reserve_index: u32 = if pool_client.token_0() == governance_address {
+            0
+        } else if pool_client.token_1() == governance_address {
+            1
+        } else {
+            return Err(LpWeightContractErrors::InvalidPool);
+        };
+
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::Pool, &pool);
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::ShareToken, &share_token);
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::GovernanceToken, &governance_token);
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::ReserveIndex, &reserve_index);
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::MaxAge, &max_age);
+        if let Some(reserves) = Self::read_pool(&env)? {
+            env.storage()
+                .instance()
+                .set(&LpWeightContractDataKey::Reserves, &reserves);
+        }
+        Ok(())
+    }
+
+    // Reads and stores the pool's current reserves as the fallback used while the pool cannot
+    // be read (anyone can call)
+    pub fn sync(env: Env) -> Result<LpWeightReserves, LpWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let reserves = Self::read_pool(&env)?.ok_or(LpWeightContractErrors::PoolUnavailable)?;
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::Reserves, &reserves);
+
+        env.events()
+            .publish(("RESERVES", "SYNCED"), reserves.clone());
+        Ok(reserves)
+    }
+
+    // Sets how long synced reserves stay usable when the pool cannot be read (admin only)
+    pub fn set_max_age(env: Env, max_age: u64) -> Result<(), LpWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+        Self::check_max_age(max_age)?;
+
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::MaxAge, &max_age);
+
+        env.events().publish(("MAX_AGE", "UPDATED"), max_age);
+        Ok(())
+    }
+
+    // Pauses or resumes share valuation in an emergency, e.g. a drained or manipulated pool;
+    // while paused every share is valued at zero (admin only)
+    pub fn set_paused(env: Env, paused: bool) -> Result<(), LpWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::Paused, &paused);
+
+        let action = if paused { "PAUSED" } else { "RESUMED" };
+        env.events().publish(("POOL", action), admin);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), LpWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&LpWeightContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the governance tokens a user's LP shares redeem for, in the governance token's
+    // precision; zero while paused, or when the share balance or any usable reserves cannot be
+    // read. Together with `decimals` this lets the adapter act as a custom token source
+    pub fn balance(env: Env, id: Address) -> Result<i128, LpWeightContractErrors> {
+        if Self::is_paused(env.clone()) {
+            return Ok(0);
+        }
+        let Some(reserves) = Self::current_reserves(&env)? else {
+            return Ok(0);
+        };
+        let shares = TokenClient::new(&env, &Self::get_share_token(env.clone())?)
+            .try_balance(&id)
+            .ok()
+            .and_then(Result::ok)
+            .unwrap_or(0);
+        Ok(share_value(shares, &reserves))
+    }
+
+    // Returns the number of decimal places of the governance token
+    pub fn decimals(env: Env) -> Result<u32, LpWeightContractErrors> {
+        Ok(Self::get_governance_token(env.clone())?.decimals(&env))
+    }
+
+    // Returns the value of a user's LP shares in governance tokens, normalized to 7 decimals,
+    // using current pool state
+    pub fn calculate_weight(
+        env: Env,
+        user: Address,
+        _snapshot_time: u64,
+    ) -> Result<i128, LpWeightContractErrors> {
+        let value = Self::balance(env.clone(), user)?;
+        Ok(normalize(value, Self::decimals(env)?))
+    }
+
+    // Returns the liquidity pool whose shares are valued
+    pub fn get_pool(env: Env) -> Result<Address, LpWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::Pool)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token representing pool shares
+    pub fn get_share_token(env: Env) -> Result<Address, LpWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::ShareToken)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the governance token paired in the pool
+    pub fn get_governance_token(env: Env) -> Result<TokenSource, LpWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::GovernanceToken)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the reserves read at the latest sync
+    pub fn get_reserves(env: Env) -> Option<LpWeightReserves> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::Reserves)
+    }
+
+    // Returns how long synced reserves stay usable when the pool cannot be read
+    pub fn get_max_age(env: Env) -> Result<u64, LpWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::MaxAge)
+            .ok_or(LpWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns whether share valuation is paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&LpWeightContractDataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
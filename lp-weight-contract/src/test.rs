@@ -0,0 +1,429 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, LP_WEIGHT_CODES};
+use governance_testutils::{advance_time, assert_last_event, setup_test_env, TEST_START_TIME};
+use soroban_sdk::{contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+// Pool mock: two-asset pool with adjustable reserves and a switch making reserve reads fail
+#[contract]
+pub struct MockLiquidityPool;
+
+#[contractimpl]
+impl MockLiquidityPool {
+    pub fn __constructor(env: Env, token_0: Address, token_1: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TOKENS"), &(token_0, token_1));
+    }
+
+    pub fn set_reserves(env: Env, reserve_0: i128, reserve_1: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("RESERVES"), &(reserve_0, reserve_1));
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("FAILING"), &failing);
+    }
+
+    pub fn token_0(env: Env) -> Address {
+        let (token_0, _): (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TOKENS"))
+            .unwrap();
+        token_0
+    }
+
+    pub fn token_1(env: Env) -> Address {
+        let (_, token_1): (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TOKENS"))
+            .unwrap();
+        token_1
+    }
+
+    pub fn get_reserves(env: Env) -> (i128, i128) {
+        let failing: bool = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("FAILING"))
+            .unwrap_or(false);
+        assert!(!failing, "pool paused");
+        env.storage()
+            .instance()
+            .get(&symbol_short!("RESERVES"))
+            .unwrap_or((0, 0))
+    }
+}
+
+// Share token mock: adjustable share balances with a matching total supply
+#[contract]
+pub struct MockShareToken;
+
+#[contractimpl]
+impl MockShareToken {
+    pub fn set_shares(env: Env, id: Address, shares: i128) {
+        let total = Self::total_supply(env.clone());
+        let previous = Self::balance(env.clone(), id.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SUPPLY"), &(total - previous + shares));
+        env.storage().instance().set(&id, &shares);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("SUPPLY"))
+            .unwrap_or(0)
+    }
+}
+
+// Custom token mock: governance token reporting 9 decimal places
+#[contract]
+pub struct MockNineDecimalToken;
+
+#[contractimpl]
+impl MockNineDecimalToken {
+    pub fn decimals(_env: Env) -> u32 {
+        9
+    }
+}
+
+fn create_lp_weight_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    pool: &Address,
+    share_token: &Address,
+    governance_token: &TokenSource,
+) -> LpWeightContractClient<'a> {
+    let contract_address = e.register(
+        LpWeightContract,
+        (
+            admin.clone(),
+            pool.clone(),
+            share_token.clone(),
+            governance_token.clone(),
+            DAY,
+        ),
+    );
+    LpWeightContractClient::new(e, &contract_address)
+}
+
+// Deploys a pool pairing another asset (token 0) with the governance token (token 1) that
+// holds 10_000 of the asset and 5_000 governance tokens, its share token with a user holding 100
+// of 1_000 shares, and the adapter valuing them
+fn setup_lp_weight<'a>(
+    e: &Env,
+) -> (
+    LpWeightContractClient<'a>,
+    MockLiquidityPoolClient<'a>,
+    MockShareTokenClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let user = Address::generate(e);
+    let governance_token = Address::generate(e);
+    let pool = MockLiquidityPoolClient::new(
+        e,
+        &e.register(
+            MockLiquidityPool,
+            (Address::generate(e), governance_token.clone()),
+        ),
+    );
+    let shares = MockShareTokenClient::new(e, &e.register(MockShareToken, ()));
+    pool.set_reserves(&10_000, &5_000);
+    shares.set_shares(&user, &100);
+    shares.set_shares(&Address::generate(e), &900);
+    let client = create_lp_weight_contract(
+        e,
+        &admin,
+        &pool.address,
+        &shares.address,
+        &TokenSource::Stellar(governance_token),
+    );
+    (client, pool, shares, user)
+}
+
+// Tests successful initialization with admin, pool, share token, governance token, and maximum
+// age.
+// Expects: The configuration is stored and the reserves are synced once.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, pool, shares, _) = setup_lp_weight(&e);
+
+    assert_eq!(client.get_pool(), pool.address);
+    assert_eq!(client.get_share_token(), shares.address);
+    assert_eq!(
+        client.get_governance_token(),
+        TokenSource::Stellar(pool.token_1())
+    );
+    assert_eq!(client.get_max_age(), DAY);
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.get_reserves(),
+        Some(LpWeightReserves {
+            governance_reserve: 5_000,
+            total_shares: 1_000,
+            synced_at: TEST_START_TIME,
+        })
+    );
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, pool, shares, user) = setup_lp_weight(&e);
+
+    e.register_at(
+        &client.address,
+        LpWeightContract,
+        (
+            user,
+            pool.address.clone(),
+            shares.address.clone(),
+            TokenSource::Stellar(pool.token_1()),
+            DAY,
+        ),
+    );
+}
+
+// Tests initialization with a pool that does not pair the governance token.
+// Expects: InvalidPool error (Error #2101).
+#[test]
+#[should_panic(expected = "Error(Contract, #2101)")]
+fn test_invalid_pool() {
+    let e = setup_test_env();
+    let (_, pool, shares, user) = setup_lp_weight(&e);
+
+    create_lp_weight_contract(
+        &e,
+        &user,
+        &pool.address,
+        &shares.address,
+        &TokenSource::Stellar(Address::generate(&e)),
+    );
+}
+
+// Tests valuing shares against the governance side of the pool, at either reserve position.
+// Expects: 100 of 1_000 shares redeem for 500 of the 5_000 governance tokens, ignoring the
+// paired asset.
+#[test]
+fn test_share_value() {
+    let e = setup_test_env();
+    let (client, pool, shares, user) = setup_lp_weight(&e);
+
+    assert_eq!(client.balance(&user), 500);
+    assert_eq!(client.calculate_weight(&user, &TEST_START_TIME), 500);
+    assert_eq!(client.calculate_weight(&Address::generate(&e), &0), 0);
+
+    let flipped = create_lp_weight_contract(
+        &e,
+        &user,
+        &pool.address,
+        &shares.address,
+        &TokenSource::Stellar(pool.token_0()),
+    );
+    assert_eq!(flipped.calculate_weight(&user, &0), 1_000);
+}
+
+// Tests pool state changing after the last sync.
+// Expects: Weights follow the live reserves and share supply without a sync.
+#[test]
+fn test_live_reserves() {
+    let e = setup_test_env();
+    let (client, pool, shares, user) = setup_lp_weight(&e);
+
+    pool.set_reserves(&20_000, &8_000);
+    assert_eq!(client.calculate_weight(&user, &0), 800);
+
+    shares.set_shares(&user, &1_100);
+    assert_eq!(client.calculate_weight(&user, &0), 4_400);
+}
+
+// Tests a governance token with 9 decimal places.
+// Expects: `balance` reports governance tokens in their own precision and the weight is
+// normalized to 7 decimals.
+#[test]
+fn test_decimals_normalization() {
+    let e = setup_test_env();
+    let (_, _, shares, user) = setup_lp_weight(&e);
+    let governance_token = e.register(MockNineDecimalToken, ());
+    let pool = MockLiquidityPoolClient::new(
+        &e,
+        &e.register(
+            MockLiquidityPool,
+            (governance_token.clone(), Address::generate(&e)),
+        ),
+    );
+    pool.set_reserves(&500_000, &1);
+
+    let client = create_lp_weight_contract(
+        &e,
+        &user,
+        &pool.address,
+        &shares.address,
+        &TokenSource::Custom(governance_token),
+    );
+    assert_eq!(client.decimals(), 9);
+    assert_eq!(client.balance(&user), 50_000);
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+}
+
+// Tests syncing the reserves by anyone.
+// Expects: The stored reserves and their timestamp update and RESERVES/SYNCED carries them.
+#[test]
+fn test_sync() {
+    let e = setup_test_env();
+    let (client, pool, _, _) = setup_lp_weight(&e);
+
+    advance_time(&e, DAY);
+    pool.set_reserves(&10_000, &6_000);
+    let reserves = client.sync();
+
+    assert_last_event(
+        &e,
+        &client.address,
+        ("RESERVES", "SYNCED"),
+        reserves.clone(),
+    );
+    assert_eq!(
+        reserves,
+        LpWeightReserves {
+            governance_reserve: 6_000,
+            total_shares: 1_000,
+            synced_at: TEST_START_TIME + DAY,
+        }
+    );
+    assert_eq!(client.get_reserves(), Some(reserves));
+}
+
+// Tests the pool failing its reserve reads.
+// Expects: Sync fails with PoolUnavailable (Error #2102), the synced reserves value shares up to
+// the maximum age, weights read zero afterwards, and live reads resume once the pool recovers.
+#[test]
+fn test_pool_failure() {
+    let e = setup_test_env();
+    let (client, pool, _, user) = setup_lp_weight(&e);
+
+    pool.set_reserves(&10_000, &9_000);
+    pool.set_failing(&true);
+    assert_eq!(
+        client.try_sync(),
+        Err(Ok(LpWeightContractErrors::PoolUnavailable))
+    );
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+
+    advance_time(&e, DAY);
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+
+    advance_time(&e, 1);
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+
+    pool.set_failing(&false);
+    assert_eq!(client.calculate_weight(&user, &0), 900);
+}
+
+// Tests pausing and resuming share valuation.
+// Expects: Every share is valued at zero while paused and at its full value once resumed.
+#[test]
+fn test_pause() {
+    let e = setup_test_env();
+    let (client, _, _, user) = setup_lp_weight(&e);
+
+    client.set_paused(&true);
+    assert!(client.is_paused());
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+
+    client.set_paused(&false);
+    assert!(!client.is_paused());
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+}
+
+// Tests updating the maximum reserve age.
+// Expects: A positive age is stored and zero is rejected with InvalidConfig (Error #15).
+#[test]
+fn test_set_max_age() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_lp_weight(&e);
+
+    client.set_max_age(&(7 * DAY));
+    assert_eq!(client.get_max_age(), 7 * DAY);
+    assert_eq!(
+        client.try_set_max_age(&0),
+        Err(Ok(LpWeightContractErrors::InvalidConfig))
+    );
+}
+
+// Tests the share valuation formula on edge cases.
+// Expects: No outstanding shares value at zero, negative balances count as zero, and values
+// never exceed the reserve or overflow.
+#[test]
+fn test_share_value_bounds() {
+    let reserves = |governance_reserve: i128, total_shares: i128| LpWeightReserves {
+        governance_reserve,
+        total_shares,
+        synced_at: 0,
+    };
+
+    assert_eq!(share_value(100, &reserves(5_000, 0)), 0);
+    assert_eq!(share_value(-100, &reserves(5_000, 1_000)), 0);
+    assert_eq!(share_value(2_000, &reserves(5_000, 1_000)), 5_000);
+    assert_eq!(
+        share_value(i128::MAX, &reserves(i128::MAX, i128::MAX)),
+        i128::MAX
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight supported and others not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_lp_weight(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2100 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        LpWeightContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        LpWeightContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        LpWeightContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    for error in [
+        LpWeightContractErrors::InvalidPool,
+        LpWeightContractErrors::PoolUnavailable,
+    ] {
+        assert!(in_range(error as u32, LP_WEIGHT_CODES));
+    }
+}
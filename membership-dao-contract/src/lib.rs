@@ -0,0 +1,307 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+pub use pg_contracts_types::membership_dao::MembershipDaoMember;
+
+// --- Membership Constraints ---
+const MAX_MEMBERS: u32 = 500; // Maximum concurrent members tracked in the member list
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const MEMBERS_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_MEMBERSHIP];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum MembershipDaoContractDataKey {
+    Admin,           // Contract administrator address
+    Token,           // Token used to pay dues
+    Dues,            // Dues charged to new members
+    Pool,            // Dues pooled across all current members
+    Members,         // List of current member addresses
+    Member(Address), // Membership record per address
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 700-799 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MembershipDaoContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidDues = 703,              // Dues must be greater than zero
+    AlreadyMember = 704,            // The address is already a member
+    NotMember = 705,                // The address is not a member
+    MembershipFull = 706,           // The member limit has been reached
+}
+
+#[contract]
+pub struct MembershipDaoContract;
+
+#[contractimpl]
+impl MembershipDaoContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, MembershipDaoContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipDaoContractDataKey::Admin)
+            .ok_or(MembershipDaoContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the dues token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, MembershipDaoContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipDaoContractDataKey::Token)
+            .ok_or(MembershipDaoContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the list of current members
+    fn read_members(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&MembershipDaoContractDataKey::Members)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // Saves the list of current members and extends its TTL
+    fn write_members(env: &Env, members: &Vec<Address>) {
+        let members_key = MembershipDaoContractDataKey::Members;
+        env.storage().persistent().set(&members_key, members);
+        env.storage().persistent().extend_ttl(
+            &members_key,
+            MEMBERS_TTL_EXTENSION,
+            MEMBERS_TTL_EXTENSION,
+        );
+    }
+
+    // Loads the dues pooled across all current members
+    fn read_pool(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MembershipDaoContractDataKey::Pool)
+            .unwrap_or(0)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, dues token, and dues amount
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+        dues: i128,
+    ) -> Result<(), MembershipDaoContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&MembershipDaoContractDataKey::Admin)
+        {
+            return Err(MembershipDaoContractErrors::ContractAlreadyInitialized);
+        }
+        if dues <= 0 {
+            return Err(MembershipDaoContractErrors::InvalidDues);
+        }
+
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Dues, &dues);
+        Ok(())
+    }
+
+    // Pays the current dues into the pool and grants one membership share
+    pub fn join(env: Env, member: Address) -> Result<(), MembershipDaoContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        member.require_auth();
+
+        let member_key = MembershipDaoContractDataKey::Member(member.clone());
+        if env.storage().persistent().has(&member_key) {
+            return Err(MembershipDaoContractErrors::AlreadyMember);
+        }
+        let mut members = Self::read_members(&env);
+        if members.len() >= MAX_MEMBERS {
+            return Err(MembershipDaoContractErrors::MembershipFull);
+        }
+
+        let dues = Self::get_dues(env.clone())?;
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &member,
+            &env.current_contract_address(),
+            &dues,
+        );
+
+        let record = MembershipDaoMember {
+            joined_at: env.ledger().timestamp(),
+            dues_paid: dues,
+        };
+        env.storage().persistent().set(&member_key, &record);
+        env.storage().persistent().extend_ttl(
+            &member_key,
+            MEMBERS_TTL_EXTENSION,
+            MEMBERS_TTL_EXTENSION,
+        );
+        members.push_back(member.clone());
+        Self::write_members(&env, &members);
+        env.storage().instance().set(
+            &MembershipDaoContractDataKey::Pool,
+            &Self::read_pool(&env).saturating_add(dues),
+        );
+
+        env.events().publish(("MEMBER", "JOINED", member), dues);
+        Ok(())
+    }
+
+    // Gives up membership and reclaims an equal share of the pooled dues
+    pub fn leave(env: Env, member: Address) -> Result<i128, MembershipDaoContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        member.require_auth();
+
+        let member_key = MembershipDaoContractDataKey::Member(member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            return Err(MembershipDaoContractErrors::NotMember);
+        }
+        let mut members = Self::read_members(&env);
+        let pool = Self::read_pool(&env);
+        let share = pool / (members.len().max(1) as i128);
+
+        env.storage().persistent().remove(&member_key);
+        if let Some(index) = members.first_index_of(&member) {
+            members.remove(index);
+        }
+        Self::write_members(&env, &members);
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Pool, &(pool - share));
+
+        if share > 0 {
+            let token_address = Self::read_token(&env)?;
+            TokenClient::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &member,
+                &share,
+            );
+        }
+
+        env.events().publish(("MEMBER", "LEFT", member), share);
+        Ok(share)
+    }
+
+    // Updates the dues charged to new members (admin only)
+    pub fn set_dues(env: Env, dues: i128) -> Result<(), MembershipDaoContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if dues <= 0 {
+            return Err(MembershipDaoContractErrors::InvalidDues);
+        }
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Dues, &dues);
+
+        env.events().publish(("DUES", "UPDATED"), dues);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), MembershipDaoContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&MembershipDaoContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns whether an address is a current member
+    pub fn is_member(env: Env, addr: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&MembershipDaoContractDataKey::Member(addr))
+    }
+
+    // Returns the membership record of an address, if it is a member
+    pub fn get_member(env: Env, addr: Address) -> Option<MembershipDaoMember> {
+        env.storage()
+            .persistent()
+            .get(&MembershipDaoContractDataKey::Member(addr))
+    }
+
+    // Returns all current members
+    pub fn get_members(env: Env) -> Vec<Address> {
+        Self::read_members(&env)
+    }
+
+    // Returns the number of current members
+    pub fn member_count(env: Env) -> u32 {
+        Self::read_members(&env).len()
+    }
+
+    // Token-compatible membership share (1 for members, 0 otherwise) so vote contracts
+    // can use the member set as their electorate
+    pub fn balance(env: Env, id: Address) -> i128 {
+        if Self::is_member(env, id) {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Returns the dues pooled across all current members
+    pub fn get_pool(env: Env) -> i128 {
+        Self::read_pool(&env)
+    }
+
+    // Returns the dues charged to new members
+    pub fn get_dues(env: Env) -> Result<i128, MembershipDaoContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipDaoContractDataKey::Dues)
+            .ok_or(MembershipDaoContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,210 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, MEMBERSHIP_DAO_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+fn create_membership_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+    dues: i128,
+) -> MembershipDaoContractClient<'a> {
+    let contract_address = e.register(
+        MembershipDaoContract,
+        MembershipDaoContractArgs::__constructor(admin, token_address, &dues),
+    );
+    MembershipDaoContractClient::new(e, &contract_address)
+}
+
+// Deploys the dues token and a DAO charging 100 tokens, returning its admin
+fn setup_dao<'a>(
+    e: &Env,
+) -> (
+    MembershipDaoContractClient<'a>,
+    TokenClient<'a>,
+    StellarAssetClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    let client = create_membership_contract(e, &admin, &token.address, 100);
+    (client, token, stellar_asset, admin)
+}
+
+// Tests successful initialization with admin, dues token, and dues amount.
+// Expects: Dues match the constructor argument and no members exist yet.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_dao(&e);
+
+    assert_eq!(client.get_dues(), 100);
+    assert_eq!(client.member_count(), 0);
+    assert_eq!(client.get_pool(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, _, admin) = setup_dao(&e);
+
+    e.register_at(
+        &client.address,
+        MembershipDaoContract,
+        MembershipDaoContractArgs::__constructor(&admin, &token.address, &100),
+    );
+}
+
+// Tests initialization with zero dues.
+// Expects: InvalidDues error (Error #703).
+#[test]
+#[should_panic(expected = "Error(Contract, #703)")]
+fn test_invalid_dues() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+
+    create_membership_contract(&e, &admin, &token.address, 0);
+}
+
+// Tests joining by paying dues.
+// Expects: Dues move into the pool and the address gains a membership share.
+#[test]
+fn test_join() {
+    let e = setup_test_env();
+    let (client, token, stellar_asset, _) = setup_dao(&e);
+    let member = Address::generate(&e);
+
+    stellar_asset.mint(&member, &250);
+    client.join(&member);
+
+    assert!(client.is_member(&member));
+    assert_eq!(client.balance(&member), 1);
+    assert_eq!(client.get_members(), vec![&e, member.clone()]);
+    assert_eq!(client.get_pool(), 100);
+    assert_eq!(token.balance(&member), 150);
+
+    let record = client.get_member(&member).unwrap();
+    assert_eq!(record.joined_at, 1000000);
+    assert_eq!(record.dues_paid, 100);
+}
+
+// Tests joining twice with the same address.
+// Expects: AlreadyMember error (Error #704).
+#[test]
+#[should_panic(expected = "Error(Contract, #704)")]
+fn test_join_already_member() {
+    let e = setup_test_env();
+    let (client, _, stellar_asset, _) = setup_dao(&e);
+    let member = Address::generate(&e);
+
+    stellar_asset.mint(&member, &200);
+    client.join(&member);
+    client.join(&member);
+}
+
+// Tests leaving after dues changed between joins.
+// Expects: The leaver reclaims an equal share of the pool and loses the membership share.
+#[test]
+fn test_leave_reclaims_pool_share() {
+    let e = setup_test_env();
+    let (client, token, stellar_asset, _) = setup_dao(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    for member in [&alice, &bob, &carol] {
+        stellar_asset.mint(member, &300);
+    }
+    client.join(&alice);
+    client.join(&bob);
+    client.set_dues(&300);
+    client.join(&carol);
+    assert_eq!(client.get_pool(), 500);
+
+    assert_eq!(client.leave(&alice), 166);
+    assert_eq!(token.balance(&alice), 366);
+    assert!(!client.is_member(&alice));
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.get_pool(), 334);
+    assert_eq!(client.get_members(), vec![&e, bob, carol]);
+}
+
+// Tests leaving without being a member.
+// Expects: NotMember error (Error #705).
+#[test]
+#[should_panic(expected = "Error(Contract, #705)")]
+fn test_leave_not_member() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_dao(&e);
+
+    client.leave(&Address::generate(&e));
+}
+
+// Tests the member set as a one-member-one-vote electorate.
+// Expects: balance reports exactly one share per member regardless of dues paid.
+#[test]
+fn test_balance_as_electorate() {
+    let e = setup_test_env();
+    let (client, _, stellar_asset, _) = setup_dao(&e);
+    let early = Address::generate(&e);
+    let late = Address::generate(&e);
+    let outsider = Address::generate(&e);
+
+    stellar_asset.mint(&early, &100);
+    stellar_asset.mint(&late, &500);
+    client.join(&early);
+    client.set_dues(&500);
+    client.join(&late);
+
+    assert_eq!(client.balance(&early), 1);
+    assert_eq!(client.balance(&late), 1);
+    assert_eq!(client.balance(&outsider), 0);
+    assert_eq!(client.member_count(), 2);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with membership supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_dao(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_MEMBERSHIP));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 700 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        MembershipDaoContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        MembershipDaoContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        MembershipDaoContractErrors::InvalidDues,
+        MembershipDaoContractErrors::AlreadyMember,
+        MembershipDaoContractErrors::NotMember,
+        MembershipDaoContractErrors::MembershipFull,
+    ] {
+        assert!(in_range(error as u32, MEMBERSHIP_DAO_CODES));
+    }
+}
@@ -0,0 +1,450 @@
+#![no_std]
+
+use governance_core::{GovernanceClient, GovernanceStatus};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, Env, String, Symbol,
+};
+
+pub use pg_contracts_types::membership_token::{MembershipTokenAction, MembershipTokenMember};
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const MEMBER_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+const ACTION_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_MEMBERSHIP];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum MembershipTokenContractDataKey {
+    Admin,           // Contract administrator address
+    Name,            // Token name shown by wallets
+    Symbol,          // Token symbol shown by wallets
+    Governance,      // Governance contract whose passed proposals mint and revoke
+    MemberCount,     // Number of current members
+    Member(Address), // Membership record per address
+    Action(Symbol),  // Membership change bound to a governance proposal
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2200-2299 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MembershipTokenContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    ProposalAlreadyExists = 3,      // A membership change is already bound to this proposal
+    ProposalNotFound = 4,           // No membership change is bound to this proposal
+    AlreadyMember = 2201,           // The address is already a member
+    NotMember = 2202,               // The address is not a member
+    GovernanceNotSet = 2203,        // No governance contract has been registered
+    ProposalNotPassed = 2204,       // The bound proposal has not passed
+    ActionAlreadyExecuted = 2205,   // The membership change has already been applied
+}
+
+#[contract]
+pub struct MembershipTokenContract;
+
+#[contractimpl]
+impl MembershipTokenContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, MembershipTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipTokenContractDataKey::Admin)
+            .ok_or(MembershipTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the number of current members
+    fn read_member_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MembershipTokenContractDataKey::MemberCount)
+            .unwrap_or(0)
+    }
+
+    // Saves a membership change bound to a proposal and extends its TTL
+    fn write_action(env: &Env, id: &Symbol, action: &MembershipTokenAction) {
+        let action_key = MembershipTokenContractDataKey::Action(id.clone());
+        env.storage().persistent().set(&action_key, action);
+        env.storage().persistent().extend_ttl(
+            &action_key,
+            ACTION_TTL_EXTENSION,
+            ACTION_TTL_EXTENSION,
+        );
+    }
+
+    // Mints a membership to an address that does not hold one
+    fn mint_membership(env: &Env, member: &Address) -> Result<(), MembershipTokenContractErrors> {
+        let member_key = MembershipTokenContractDataKey::Member(member.clone());
+        if env.storage().persistent().has(&member_key) {
+            return Err(MembershipTokenContractErrors::AlreadyMember);
+        }
+        let record = MembershipTokenMember {
+            joined_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&member_key, &record);
+        env.storage().persistent().extend_ttl(
+            &member_key,
+            MEMBER_TTL_EXTENSION,
+            MEMBER_TTL_EXTENSION,
+        );
+        env.storage().instance().set(
+            &MembershipTokenContractDataKey::MemberCount,
+            &(Self::read_member_count(env) + 1),
+        );
+
+        env.events()
+            .publish(("MEMBER", "MINTED", member.clone()), record.joined_at);
+        Ok(())
+    }
+
+    // Burns the membership of a current member
+    fn revoke_membership(env: &Env, member: &Address) -> Result<(), MembershipTokenContractErrors> {
+        let member_key = MembershipTokenContractDataKey::Member(member.clone());
+        if !env.storage().persistent().has(&member_key) {
+            return Err(MembershipTokenContractErrors::NotMember);
+        }
+        env.storage().persistent().remove(&member_key);
+        env.storage().instance().set(
+            &MembershipTokenContractDataKey::MemberCount,
+            &(Self::read_member_count(env) - 1),
+        );
+
+        env.events()
+            .publish(("MEMBER", "REVOKED", member.clone()), ());
+        Ok(())
+    }
+
+    // Creates a proposal on the registered governance contract and binds a membership change
+    // to it, so voters decide on exactly that change
+    fn propose(
+        env: &Env,
+        proposer: &Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        action: MembershipTokenAction,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        proposer.require_auth();
+
+        let governance = Self::get_governance(env.clone())
+            .ok_or(MembershipTokenContractErrors::GovernanceNotSet)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&MembershipTokenContractDataKey::Action(id.clone()))
+        {
+            return Err(MembershipTokenContractErrors::ProposalAlreadyExists);
+        }
+        let is_member = Self::is_member(env.clone(), action.member.clone());
+        if action.grant && is_member {
+            return Err(MembershipTokenContractErrors::AlreadyMember);
+        }
+        if !action.grant && !is_member {
+            return Err(MembershipTokenContractErrors::NotMember);
+        }
+
+        GovernanceClient::new(env, &governance).create_proposal(
+            proposer,
+            &id,
+            &description,
+            &start_time,
+            &end_time,
+        );
+        Self::write_action(env, &id, &action);
+
+        env.events().publish(
+            ("MEMBERSHIP", "PROPOSED", id),
+            (action.member, action.grant),
+        );
+        Ok(())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and the token name and symbol shown by wallets
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&MembershipTokenContractDataKey::Admin)
+        {
+            return Err(MembershipTokenContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&MembershipTokenContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&MembershipTokenContractDataKey::Name, &name);
+        env.storage()
+            .instance()
+            .set(&MembershipTokenContractDataKey::Symbol, &symbol);
+        Ok(())
+    }
+
+    // Mints a non-transferable membership to an address (admin only)
+    pub fn mint(env: Env, member: Address) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Self::mint_membership(&env, &member)
+    }
+
+    // Revokes the membership of a member (admin only)
+    pub fn revoke(env: Env, member: Address) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Self::revoke_membership(&env, &member)
+    }
+
+    // Registers the governance contract whose passed proposals can mint and revoke memberships
+    // (admin only)
+    pub fn set_governance(
+        env: Env,
+        governance: Address,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&MembershipTokenContractDataKey::Governance, &governance);
+
+        env.events().publish(("GOVERNANCE", "UPDATED"), governance);
+        Ok(())
+    }
+
+    // Proposes minting a membership to an address through the registered governance contract
+    pub fn propose_mint(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        member: Address,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let action = MembershipTokenAction {
+            member,
+            grant: true,
+            executed: false,
+        };
+        Self::propose(
+            &env,
+            &proposer,
+            id,
+            description,
+            start_time,
+            end_time,
+            action,
+        )
+    }
+
+    // Proposes revoking the membership of a member through the registered governance contract
+    pub fn propose_revoke(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        member: Address,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let action = MembershipTokenAction {
+            member,
+            grant: false,
+            executed: false,
+        };
+        Self::propose(
+            &env,
+            &proposer,
+            id,
+            description,
+            start_time,
+            end_time,
+            action,
+        )
+    }
+
+    // Applies the membership change bound to a proposal once it has passed (anyone can call);
+    // a change the admin already made in the meantime is marked executed without effect
+    pub fn execute_membership(env: Env, id: Symbol) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut action = Self::get_membership_action(env.clone(), id.clone())
+            .ok_or(MembershipTokenContractErrors::ProposalNotFound)?;
+        if action.executed {
+            return Err(MembershipTokenContractErrors::ActionAlreadyExecuted);
+        }
+        let governance = Self::get_governance(env.clone())
+            .ok_or(MembershipTokenContractErrors::GovernanceNotSet)?;
+        match GovernanceClient::new(&env, &governance).get_status(&id) {
+            GovernanceStatus::Succeeded | GovernanceStatus::Executed => {}
+            _ => return Err(MembershipTokenContractErrors::ProposalNotPassed),
+        }
+
+        let is_member = Self::is_member(env.clone(), action.member.clone());
+        if action.grant && !is_member {
+            Self::mint_membership(&env, &action.member)?;
+        } else if !action.grant && is_member {
+            Self::revoke_membership(&env, &action.member)?;
+        }
+        action.executed = true;
+        Self::write_action(&env, &id, &action);
+
+        env.events().publish(
+            ("MEMBERSHIP", "EXECUTED", id),
+            (action.member, action.grant),
+        );
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), MembershipTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&MembershipTokenContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // Extends the TTL of a membership record so a long-standing member keeps it (anyone can
+    // call)
+    pub fn bump_member(env: Env, member: Address) -> Result<(), MembershipTokenContractErrors> {
+        let member_key = MembershipTokenContractDataKey::Member(member);
+        if !env.storage().persistent().has(&member_key) {
+            return Err(MembershipTokenContractErrors::NotMember);
+        }
+        env.storage().persistent().extend_ttl(
+            &member_key,
+            MEMBER_TTL_EXTENSION,
+            MEMBER_TTL_EXTENSION,
+        );
+        Ok(())
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns whether an address is a current member
+    pub fn is_member(env: Env, addr: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&MembershipTokenContractDataKey::Member(addr))
+    }
+
+    // Returns the membership record of an address, if it is a member
+    pub fn get_member(env: Env, addr: Address) -> Option<MembershipTokenMember> {
+        env.storage()
+            .persistent()
+            .get(&MembershipTokenContractDataKey::Member(addr))
+    }
+
+    // Returns the number of current members
+    pub fn member_count(env: Env) -> u32 {
+        Self::read_member_count(&env)
+    }
+
+    // Token-compatible membership share (1 for members, 0 otherwise); there are no transfer
+    // functions, so a membership stays with the address it was minted to
+    pub fn balance(env: Env, id: Address) -> i128 {
+        if Self::is_member(env, id) {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Returns the number of memberships outstanding
+    pub fn total_supply(env: Env) -> i128 {
+        Self::read_member_count(&env) as i128
+    }
+
+    // Returns the number of decimal places, 0 since a membership is indivisible
+    pub fn decimals(_env: Env) -> u32 {
+        0
+    }
+
+    // Returns the token name
+    pub fn name(env: Env) -> Result<String, MembershipTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipTokenContractDataKey::Name)
+            .ok_or(MembershipTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token symbol
+    pub fn symbol(env: Env) -> Result<String, MembershipTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MembershipTokenContractDataKey::Symbol)
+            .ok_or(MembershipTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the governance contract whose passed proposals mint and revoke memberships
+    pub fn get_governance(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&MembershipTokenContractDataKey::Governance)
+    }
+
+    // Returns the membership change bound to a proposal
+    pub fn get_membership_action(env: Env, id: Symbol) -> Option<MembershipTokenAction> {
+        env.storage()
+            .persistent()
+            .get(&MembershipTokenContractDataKey::Action(id))
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
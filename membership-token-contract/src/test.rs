@@ -0,0 +1,340 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, MEMBERSHIP_TOKEN_CODES};
+use governance_testutils::{assert_last_event, setup_test_env, TEST_START_TIME};
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+// Governance mock: records created proposal IDs and reports a status set by the test
+#[contract]
+pub struct MockGovernance;
+
+#[contractimpl]
+impl MockGovernance {
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        _description: String,
+        _start_time: u64,
+        _end_time: u64,
+    ) {
+        proposer.require_auth();
+        env.storage()
+            .instance()
+            .set(&id, &GovernanceStatus::Pending);
+    }
+
+    pub fn set_status(env: Env, id: Symbol, status: GovernanceStatus) {
+        env.storage().instance().set(&id, &status);
+    }
+
+    pub fn get_status(env: Env, id: Symbol) -> GovernanceStatus {
+        env.storage().instance().get(&id).unwrap()
+    }
+}
+
+fn create_membership_token_contract<'a>(
+    e: &Env,
+    admin: &Address,
+) -> MembershipTokenContractClient<'a> {
+    let contract_address = e.register(
+        MembershipTokenContract,
+        (
+            admin.clone(),
+            String::from_str(e, "Guild Membership"),
+            String::from_str(e, "GUILD"),
+        ),
+    );
+    MembershipTokenContractClient::new(e, &contract_address)
+}
+
+// Deploys the membership token with a registered governance mock, returning both and a member
+// candidate without a membership
+fn setup_membership_token<'a>(
+    e: &Env,
+) -> (
+    MembershipTokenContractClient<'a>,
+    MockGovernanceClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let client = create_membership_token_contract(e, &admin);
+    let governance = MockGovernanceClient::new(e, &e.register(MockGovernance, ()));
+    client.set_governance(&governance.address);
+    (client, governance, Address::generate(e))
+}
+
+// Proposes minting a membership to the candidate under the given proposal ID
+fn propose_mint(client: &MembershipTokenContractClient, e: &Env, id: &Symbol, member: &Address) {
+    client.propose_mint(
+        &Address::generate(e),
+        id,
+        &String::from_str(e, "Admit a contributor"),
+        &(TEST_START_TIME + DAY),
+        &(TEST_START_TIME + 2 * DAY),
+        member,
+    );
+}
+
+// Tests successful initialization with admin, name, and symbol.
+// Expects: Token metadata is stored, with no members and no governance contract.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let client = create_membership_token_contract(&e, &Address::generate(&e));
+
+    assert_eq!(client.name(), String::from_str(&e, "Guild Membership"));
+    assert_eq!(client.symbol(), String::from_str(&e, "GUILD"));
+    assert_eq!(client.decimals(), 0);
+    assert_eq!(client.member_count(), 0);
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(client.get_governance(), None);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, _, member) = setup_membership_token(&e);
+
+    e.register_at(
+        &client.address,
+        MembershipTokenContract,
+        (
+            member,
+            String::from_str(&e, "Other"),
+            String::from_str(&e, "OTHER"),
+        ),
+    );
+}
+
+// Tests minting and revoking memberships by the admin.
+// Expects: Members hold a balance of 1 and count toward the supply until revoked.
+#[test]
+fn test_mint_and_revoke() {
+    let e = setup_test_env();
+    let (client, _, member) = setup_membership_token(&e);
+
+    client.mint(&member);
+    assert_last_event(
+        &e,
+        &client.address,
+        ("MEMBER", "MINTED", member.clone()),
+        TEST_START_TIME,
+    );
+    assert!(client.is_member(&member));
+    assert_eq!(client.balance(&member), 1);
+    assert_eq!(
+        client.get_member(&member),
+        Some(MembershipTokenMember {
+            joined_at: TEST_START_TIME,
+        })
+    );
+    assert_eq!(client.member_count(), 1);
+    assert_eq!(client.total_supply(), 1);
+
+    client.revoke(&member);
+    assert!(!client.is_member(&member));
+    assert_eq!(client.balance(&member), 0);
+    assert_eq!(client.get_member(&member), None);
+    assert_eq!(client.member_count(), 0);
+}
+
+// Tests minting to a member and revoking a non-member.
+// Expects: AlreadyMember (Error #2201) and NotMember (Error #2202) errors.
+#[test]
+fn test_mint_and_revoke_invalid() {
+    let e = setup_test_env();
+    let (client, _, member) = setup_membership_token(&e);
+
+    assert_eq!(
+        client.try_revoke(&member),
+        Err(Ok(MembershipTokenContractErrors::NotMember))
+    );
+    client.mint(&member);
+    assert_eq!(
+        client.try_mint(&member),
+        Err(Ok(MembershipTokenContractErrors::AlreadyMember))
+    );
+    assert_eq!(client.member_count(), 1);
+}
+
+// Tests minting a membership through a passed governance proposal.
+// Expects: The change is bound at creation, rejected until the proposal passes (Error #2204),
+// applied once by anyone afterwards, and not applied twice (Error #2205).
+#[test]
+fn test_proposal_mint() {
+    let e = setup_test_env();
+    let (client, governance, member) = setup_membership_token(&e);
+    let id = symbol_short!("ADMIT");
+
+    propose_mint(&client, &e, &id, &member);
+    assert_eq!(
+        client.get_membership_action(&id),
+        Some(MembershipTokenAction {
+            member: member.clone(),
+            grant: true,
+            executed: false,
+        })
+    );
+    assert_eq!(governance.get_status(&id), GovernanceStatus::Pending);
+
+    governance.set_status(&id, &GovernanceStatus::Defeated);
+    assert_eq!(
+        client.try_execute_membership(&id),
+        Err(Ok(MembershipTokenContractErrors::ProposalNotPassed))
+    );
+
+    governance.set_status(&id, &GovernanceStatus::Succeeded);
+    client.execute_membership(&id);
+    assert_last_event(
+        &e,
+        &client.address,
+        ("MEMBERSHIP", "EXECUTED", id.clone()),
+        (member.clone(), true),
+    );
+    assert!(client.is_member(&member));
+    assert!(client.get_membership_action(&id).unwrap().executed);
+    assert_eq!(
+        client.try_execute_membership(&id),
+        Err(Ok(MembershipTokenContractErrors::ActionAlreadyExecuted))
+    );
+}
+
+// Tests revoking a membership through a passed governance proposal.
+// Expects: Revocations require a current member and apply once the proposal passes.
+#[test]
+fn test_proposal_revoke() {
+    let e = setup_test_env();
+    let (client, governance, member) = setup_membership_token(&e);
+    let id = symbol_short!("EXPEL");
+    let description = String::from_str(&e, "Revoke an inactive membership");
+    let proposer = Address::generate(&e);
+
+    assert_eq!(
+        client.try_propose_revoke(&proposer, &id, &description, &0, &DAY, &member),
+        Err(Ok(MembershipTokenContractErrors::NotMember))
+    );
+    client.mint(&member);
+    client.propose_revoke(&proposer, &id, &description, &0, &DAY, &member);
+
+    governance.set_status(&id, &GovernanceStatus::Executed);
+    client.execute_membership(&id);
+    assert!(!client.is_member(&member));
+    assert_eq!(client.member_count(), 0);
+}
+
+// Tests a proposal whose change the admin made while the vote ran.
+// Expects: Execution marks the change applied without minting a second time.
+#[test]
+fn test_proposal_superseded_by_admin() {
+    let e = setup_test_env();
+    let (client, governance, member) = setup_membership_token(&e);
+    let id = symbol_short!("ADMIT");
+
+    propose_mint(&client, &e, &id, &member);
+    client.mint(&member);
+
+    governance.set_status(&id, &GovernanceStatus::Succeeded);
+    client.execute_membership(&id);
+    assert!(client.get_membership_action(&id).unwrap().executed);
+    assert_eq!(client.member_count(), 1);
+}
+
+// Tests proposals without a registered governance contract, for a member, under a bound ID,
+// and executing an unknown proposal.
+// Expects: GovernanceNotSet (Error #2203), AlreadyMember (Error #2201), ProposalAlreadyExists
+// (Error #3), and ProposalNotFound (Error #4) errors.
+#[test]
+fn test_proposal_invalid() {
+    let e = setup_test_env();
+    let (client, _, member) = setup_membership_token(&e);
+    let unbound = create_membership_token_contract(&e, &Address::generate(&e));
+    let id = symbol_short!("ADMIT");
+    let description = String::from_str(&e, "Admit a contributor");
+    let proposer = Address::generate(&e);
+
+    assert_eq!(
+        unbound.try_propose_mint(&proposer, &id, &description, &0, &DAY, &member),
+        Err(Ok(MembershipTokenContractErrors::GovernanceNotSet))
+    );
+
+    propose_mint(&client, &e, &id, &member);
+    assert_eq!(
+        client.try_propose_mint(
+            &proposer,
+            &id,
+            &description,
+            &0,
+            &DAY,
+            &Address::generate(&e)
+        ),
+        Err(Ok(MembershipTokenContractErrors::ProposalAlreadyExists))
+    );
+    client.mint(&member);
+    assert_eq!(
+        client.try_propose_mint(
+            &proposer,
+            &symbol_short!("AGAIN"),
+            &description,
+            &0,
+            &DAY,
+            &member
+        ),
+        Err(Ok(MembershipTokenContractErrors::AlreadyMember))
+    );
+    assert_eq!(
+        client.try_execute_membership(&symbol_short!("UNKNOWN")),
+        Err(Ok(MembershipTokenContractErrors::ProposalNotFound))
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with membership supported and others not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_membership_token(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_MEMBERSHIP));
+    assert!(!client.supports(&governance_core::FEATURE_TOKEN));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2200 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        MembershipTokenContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        MembershipTokenContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        MembershipTokenContractErrors::ProposalAlreadyExists as u32,
+        GovernanceError::ProposalAlreadyExists as u32
+    );
+    assert_eq!(
+        MembershipTokenContractErrors::ProposalNotFound as u32,
+        GovernanceError::ProposalNotFound as u32
+    );
+    for error in [
+        MembershipTokenContractErrors::AlreadyMember,
+        MembershipTokenContractErrors::NotMember,
+        MembershipTokenContractErrors::GovernanceNotSet,
+        MembershipTokenContractErrors::ProposalNotPassed,
+        MembershipTokenContractErrors::ActionAlreadyExecuted,
+    ] {
+        assert!(in_range(error as u32, MEMBERSHIP_TOKEN_CODES));
+    }
+}
@@ -0,0 +1,202 @@
+#![no_std]
+
+use governance_core::MAX_DECIMALS;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+pub use pg_contracts_types::multi_token_weight::MultiTokenWeightEntry;
+
+// --- Multiplier Scale ---
+const MULTIPLIER_SCALE: i128 = 10_000; // Basis points, 10_000 = 1.0x
+const MAX_MULTIPLIER: i128 = 100_000; // Highest multiplier a token may carry (10.0x)
+
+// --- Token List Constraints ---
+const MAX_TOKENS: u32 = 8; // Most tokens whose balances are combined
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_WEIGHT];
+
+// Defines the structure for instance storage
+#[contracttype]
+pub enum MultiTokenWeightContractDataKey {
+    Admin,  // Contract administrator address
+    Tokens, // Tokens whose balances are combined, with their multipliers
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2000-2099 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MultiTokenWeightContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidTokens = 2001,           // Empty, over 8, repeated, or over 18 decimals
+    InvalidMultiplier = 2002,       // A multiplier is not between 0.0001x and 10.0x
+}
+
+// Returns the weight of a set of normalized balances, each multiplied by its token's multiplier,
+// as Σ balance × multiplier / 10_000; negative balances count as 0
+pub fn combined_weight(balances: &Vec<(i128, i128)>) -> i128 {
+    let weighted_sum = balances.iter().fold(0i128, |total, (balance, multiplier)| {
+        total.saturating_add(balance.max(0).saturating_mul(multiplier))
+    });
+    weighted_sum / MULTIPLIER_SCALE
+}
+
+#[contract]
+pub struct MultiTokenWeightContract;
+
+#[contractimpl]
+impl MultiTokenWeightContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, MultiTokenWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MultiTokenWeightContractDataKey::Admin)
+            .ok_or(MultiTokenWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Rejects empty, oversized, or repeating token lists, tokens whose precision cannot be
+    // normalized, and multipliers outside 1 bps to 10.0x
+    fn validate_tokens(
+        env: &Env,
+        tokens: &Vec<MultiTokenWeightEntry>,
+    ) -> Result<(), MultiTokenWeightContractErrors> {
+        if tokens.is_empty() || tokens.len() > MAX_TOKENS {
+            return Err(MultiTokenWeightContractErrors::InvalidTokens);
+        }
+        for (i, entry) in tokens.iter().enumerate() {
+            if !(1..=MAX_MULTIPLIER).contains(&entry.multiplier) {
+                return Err(MultiTokenWeightContractErrors::InvalidMultiplier);
+            }
+            let address = entry.token.address();
+            if tokens
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.token.address() == address)
+                || entry.token.decimals(env) > MAX_DECIMALS
+            {
+                return Err(MultiTokenWeightContractErrors::InvalidTokens);
+            }
+        }
+        Ok(())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and the tokens whose balances are combined
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        tokens: Vec<MultiTokenWeightEntry>,
+    ) -> Result<(), MultiTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&MultiTokenWeightContractDataKey::Admin)
+        {
+            return Err(MultiTokenWeightContractErrors::ContractAlreadyInitialized);
+        }
+        Self::validate_tokens(&env, &tokens)?;
+
+        env.storage()
+            .instance()
+            .set(&MultiTokenWeightContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&MultiTokenWeightContractDataKey::Tokens, &tokens);
+        Ok(())
+    }
+
+    // Replaces the combined tokens and their multipliers, e.g. to phase out an old token
+    // (admin only)
+    pub fn set_tokens(
+        env: Env,
+        tokens: Vec<MultiTokenWeightEntry>,
+    ) -> Result<(), MultiTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Self::validate_tokens(&env, &tokens)?;
+        env.storage()
+            .instance()
+            .set(&MultiTokenWeightContractDataKey::Tokens, &tokens);
+
+        env.events().publish(("TOKENS", "UPDATED"), tokens);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), MultiTokenWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&MultiTokenWeightContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns w = Σ balance × multiplier / 10_000 over the configured tokens using current
+    // balances, each normalized to 7 decimals so tokens of different precision combine
+    pub fn calculate_weight(
+        env: Env,
+        user: Address,
+        _snapshot_time: u64,
+    ) -> Result<i128, MultiTokenWeightContractErrors> {
+        let tokens = Self::get_tokens(env.clone())?;
+        let mut balances = Vec::new(&env);
+        for entry in tokens.iter() {
+            balances.push_back((
+                entry.token.normalized_balance(&env, &user),
+                entry.multiplier,
+            ));
+        }
+        Ok(combined_weight(&balances))
+    }
+
+    // Returns the combined tokens with their multipliers
+    pub fn get_tokens(
+        env: Env,
+    ) -> Result<Vec<MultiTokenWeightEntry>, MultiTokenWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&MultiTokenWeightContractDataKey::Tokens)
+            .ok_or(MultiTokenWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,231 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::TokenSource;
+use governance_errors::{in_range, GovernanceError, MULTI_TOKEN_WEIGHT_CODES};
+use governance_testutils::{assert_last_event, create_token_contract, mint, setup_test_env};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env};
+
+// Custom token mock: standard balance and decimals reads with 9 decimal places
+#[contract]
+pub struct MockNineDecimalToken;
+
+#[contractimpl]
+impl MockNineDecimalToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        env.storage().instance().set(&id, &amount);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        9
+    }
+}
+
+fn entry(token: &Address, multiplier: i128) -> MultiTokenWeightEntry {
+    MultiTokenWeightEntry {
+        token: TokenSource::Stellar(token.clone()),
+        multiplier,
+    }
+}
+
+fn create_multi_token_weight_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    tokens: &Vec<MultiTokenWeightEntry>,
+) -> MultiTokenWeightContractClient<'a> {
+    let contract_address = e.register(MultiTokenWeightContract, (admin.clone(), tokens.clone()));
+    MultiTokenWeightContractClient::new(e, &contract_address)
+}
+
+// Deploys an old token counted at 0.5x, a new token counted at 1.0x, and the contract combining
+// them, returning the contract, both token addresses, and a user without balances
+fn setup_multi_token_weight<'a>(
+    e: &Env,
+) -> (
+    MultiTokenWeightContractClient<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let user = Address::generate(e);
+    let old_token = create_token_contract(e, &admin).address;
+    let new_token = create_token_contract(e, &admin).address;
+    let tokens = vec![e, entry(&old_token, 5_000), entry(&new_token, 10_000)];
+    let client = create_multi_token_weight_contract(e, &admin, &tokens);
+    (client, old_token, new_token, user)
+}
+
+// Tests successful initialization with admin and two tokens.
+// Expects: The tokens are stored in order and a user without balances has no weight.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, old_token, new_token, user) = setup_multi_token_weight(&e);
+
+    assert_eq!(
+        client.get_tokens(),
+        vec![&e, entry(&old_token, 5_000), entry(&new_token, 10_000)]
+    );
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, old_token, _, user) = setup_multi_token_weight(&e);
+
+    e.register_at(
+        &client.address,
+        MultiTokenWeightContract,
+        (user, vec![&e, entry(&old_token, 10_000)]),
+    );
+}
+
+// Tests a holder of both the old and the new token.
+// Expects: The weight sums 0.5x of the old balance and 1.0x of the new balance.
+#[test]
+fn test_combined_weight() {
+    let e = setup_test_env();
+    let (client, old_token, new_token, user) = setup_multi_token_weight(&e);
+
+    mint(&e, &old_token, &user, 1000);
+    assert_eq!(client.calculate_weight(&user, &0), 500);
+
+    mint(&e, &new_token, &user, 300);
+    assert_eq!(client.calculate_weight(&user, &0), 800);
+}
+
+// Tests combining a 7-decimal token with a 9-decimal custom token such as an LP token.
+// Expects: The custom balance is normalized to 7 decimals before its multiplier applies.
+#[test]
+fn test_decimals_normalization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token_contract(&e, &admin).address;
+    let lp_token = MockNineDecimalTokenClient::new(&e, &e.register(MockNineDecimalToken, ()));
+    let tokens = vec![
+        &e,
+        entry(&token, 10_000),
+        MultiTokenWeightEntry {
+            token: TokenSource::Custom(lp_token.address.clone()),
+            multiplier: 20_000,
+        },
+    ];
+    let client = create_multi_token_weight_contract(&e, &admin, &tokens);
+
+    mint(&e, &token, &user, 1000);
+    lp_token.set_balance(&user, &50_000);
+    assert_eq!(client.calculate_weight(&user, &0), 1000 + 500 * 2);
+}
+
+// Tests replacing the tokens to phase out the old token.
+// Expects: Weights follow the new list and the TOKENS/UPDATED event carries it.
+#[test]
+fn test_set_tokens() {
+    let e = setup_test_env();
+    let (client, old_token, new_token, user) = setup_multi_token_weight(&e);
+
+    mint(&e, &old_token, &user, 1000);
+    mint(&e, &new_token, &user, 300);
+
+    let tokens = vec![&e, entry(&new_token, 15_000)];
+    client.set_tokens(&tokens);
+
+    assert_last_event(&e, &client.address, ("TOKENS", "UPDATED"), tokens.clone());
+    assert_eq!(client.get_tokens(), tokens);
+    assert_eq!(client.calculate_weight(&user, &0), 450);
+}
+
+// Tests token lists that are empty, longer than 8, repeat a token, or leave 0.0001x-10.0x.
+// Expects: InvalidTokens (Error #2001) or InvalidMultiplier (Error #2002) and the previous list
+// stays in place.
+#[test]
+fn test_set_tokens_invalid() {
+    let e = setup_test_env();
+    let (client, old_token, new_token, _) = setup_multi_token_weight(&e);
+
+    let mut too_many = Vec::new(&e);
+    for _ in 0..=MAX_TOKENS {
+        too_many.push_back(entry(&Address::generate(&e), 10_000));
+    }
+    for tokens in [
+        Vec::new(&e),
+        too_many,
+        vec![&e, entry(&old_token, 10_000), entry(&old_token, 5_000)],
+    ] {
+        assert_eq!(
+            client.try_set_tokens(&tokens),
+            Err(Ok(MultiTokenWeightContractErrors::InvalidTokens))
+        );
+    }
+    for multiplier in [0, -10_000, MAX_MULTIPLIER + 1] {
+        assert_eq!(
+            client.try_set_tokens(&vec![&e, entry(&new_token, multiplier)]),
+            Err(Ok(MultiTokenWeightContractErrors::InvalidMultiplier))
+        );
+    }
+    assert_eq!(
+        client.get_tokens(),
+        vec![&e, entry(&old_token, 5_000), entry(&new_token, 10_000)]
+    );
+}
+
+// Tests the weight formula on balances near the i128 limit and on negative balances.
+// Expects: The sum saturates instead of overflowing and negative balances count as 0.
+#[test]
+fn test_combined_weight_bounds() {
+    let e = Env::default();
+
+    assert_eq!(
+        combined_weight(&vec![&e, (i128::MAX, MAX_MULTIPLIER), (i128::MAX, 10_000)]),
+        i128::MAX / MULTIPLIER_SCALE
+    );
+    assert_eq!(
+        combined_weight(&vec![&e, (-1000, 10_000), (1000, 10_000)]),
+        1000
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight supported and others not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_multi_token_weight(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2000 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        MultiTokenWeightContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        MultiTokenWeightContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        MultiTokenWeightContractErrors::InvalidTokens,
+        MultiTokenWeightContractErrors::InvalidMultiplier,
+    ] {
+        assert!(in_range(error as u32, MULTI_TOKEN_WEIGHT_CODES));
+    }
+}
@@ -0,0 +1,437 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+
+pub use pg_contracts_types::optimistic_governance::{
+    OptimisticGovernanceConfig, OptimisticGovernanceProposal, OptimisticGovernanceProposalStatus,
+};
+
+// --- Vote Choice Constants ---
+const VOTE_FOR: Symbol = symbol_short!("FOR");
+const VOTE_AGAINST: Symbol = symbol_short!("AGAINST");
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const PROPOSALS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
+const VOTE_TTL_EXTENSION: u32 = 1_600_000; // ~18.5 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 0] = [];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum OptimisticGovernanceContractDataKey {
+    Admin,                  // Contract administrator address
+    Token,                  // Governance token used for bonds and voting weight
+    Config,                 // Challenge period, vote duration, and bond amount
+    Proposal(Symbol),       // Optimistic proposal data
+    Voted(Symbol, Address), // Tracks whether a voter has voted on an escalated proposal
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 600-699 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OptimisticGovernanceContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidConfig = 15,             // Periods and bond must be greater than zero
+    ProposalAlreadyExists = 3,      // A proposal with this ID already exists
+    ProposalNotFound = 4,           // The specified proposal does not exist
+    ChallengeWindowClosed = 606,    // The proposal can no longer be challenged
+    ProposalNotPending = 607,       // The proposal has already been challenged or resolved
+    VotingNotActive = 7,            // The proposal has no open escalated vote
+    InvalidChoice = 8,              // The provided vote choice is invalid
+    UserAlreadyVoted = 5,           // User has already voted on this proposal
+    UserCannotVote = 6,             // User holds no governance tokens
+    ChallengeWindowOpen = 612,      // Unchallenged proposals resolve after the window
+    VotingNotEnded = 14,            // The escalated vote has not ended yet
+    ProposalAlreadyResolved = 614,  // The proposal has already been approved or rejected
+}
+
+#[contract]
+pub struct OptimisticGovernanceContract;
+
+#[contractimpl]
+impl OptimisticGovernanceContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, OptimisticGovernanceContractErrors> {
+        env.storage()
+            .instance()
+            .get(&OptimisticGovernanceContractDataKey::Admin)
+            .ok_or(OptimisticGovernanceContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, OptimisticGovernanceContractErrors> {
+        env.storage()
+            .instance()
+            .get(&OptimisticGovernanceContractDataKey::Token)
+            .ok_or(OptimisticGovernanceContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the proposal configuration from instance storage
+    fn read_config(
+        env: &Env,
+    ) -> Result<OptimisticGovernanceConfig, OptimisticGovernanceContractErrors> {
+        env.storage()
+            .instance()
+            .get(&OptimisticGovernanceContractDataKey::Config)
+            .ok_or(OptimisticGovernanceContractErrors::ContractNotInitialized)
+    }
+
+    // Validates that periods and bond are greater than zero
+    fn validate_config(
+        config: &OptimisticGovernanceConfig,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        if config.challenge_period == 0 || config.vote_duration == 0 || config.bond <= 0 {
+            return Err(OptimisticGovernanceContractErrors::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    // Loads a proposal from persistent storage
+    fn read_proposal(
+        env: &Env,
+        id: &Symbol,
+    ) -> Result<OptimisticGovernanceProposal, OptimisticGovernanceContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&OptimisticGovernanceContractDataKey::Proposal(id.clone()))
+            .ok_or(OptimisticGovernanceContractErrors::ProposalNotFound)
+    }
+
+    // Saves a proposal and extends its TTL
+    fn write_proposal(env: &Env, id: &Symbol, proposal: &OptimisticGovernanceProposal) {
+        let proposal_key = OptimisticGovernanceContractDataKey::Proposal(id.clone());
+        env.storage().persistent().set(&proposal_key, proposal);
+        env.storage().persistent().extend_ttl(
+            &proposal_key,
+            PROPOSALS_TTL_EXTENSION,
+            PROPOSALS_TTL_EXTENSION,
+        );
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, governance token, and proposal configuration
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+        challenge_period: u64,
+        vote_duration: u64,
+        bond: i128,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&OptimisticGovernanceContractDataKey::Admin)
+        {
+            return Err(OptimisticGovernanceContractErrors::ContractAlreadyInitialized);
+        }
+        let config = OptimisticGovernanceConfig {
+            challenge_period,
+            vote_duration,
+            bond,
+        };
+        Self::validate_config(&config)?;
+
+        env.storage()
+            .instance()
+            .set(&OptimisticGovernanceContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&OptimisticGovernanceContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&OptimisticGovernanceContractDataKey::Config, &config);
+        Ok(())
+    }
+
+    // Submits a proposal that passes after the challenge window unless challenged
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        proposer.require_auth();
+
+        if env
+            .storage()
+            .persistent()
+            .has(&OptimisticGovernanceContractDataKey::Proposal(id.clone()))
+        {
+            return Err(OptimisticGovernanceContractErrors::ProposalAlreadyExists);
+        }
+        let config = Self::read_config(&env)?;
+        let challenge_end = env.ledger().timestamp() + config.challenge_period;
+
+        let proposal = OptimisticGovernanceProposal {
+            status: OptimisticGovernanceProposalStatus::Pending,
+            proposer: proposer.clone(),
+            description,
+            challenge_end,
+            challenger: None,
+            bond: 0,
+            vote_end: 0,
+            votes_for: 0,
+            votes_against: 0,
+        };
+        Self::write_proposal(&env, &id, &proposal);
+
+        env.events()
+            .publish(("PROPOSAL", "CREATED", id), (proposer, challenge_end));
+        Ok(())
+    }
+
+    // Disputes a pending proposal by locking the bond, escalating it to a token vote
+    pub fn challenge(
+        env: Env,
+        challenger: Address,
+        id: Symbol,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        challenger.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if proposal.status != OptimisticGovernanceProposalStatus::Pending {
+            return Err(OptimisticGovernanceContractErrors::ProposalNotPending);
+        }
+        let ledger_time = env.ledger().timestamp();
+        if ledger_time > proposal.challenge_end {
+            return Err(OptimisticGovernanceContractErrors::ChallengeWindowClosed);
+        }
+
+        let config = Self::read_config(&env)?;
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &challenger,
+            &env.current_contract_address(),
+            &config.bond,
+        );
+
+        proposal.challenger = Some(challenger.clone());
+        proposal.bond = config.bond;
+        proposal.vote_end = ledger_time + config.vote_duration;
+        proposal.status = OptimisticGovernanceProposalStatus::Challenged;
+        Self::write_proposal(&env, &id, &proposal);
+
+        env.events().publish(
+            ("PROPOSAL", "CHALLENGED", id),
+            (challenger, config.bond, proposal.vote_end),
+        );
+        Ok(())
+    }
+
+    // Casts a token-weighted vote on a challenged proposal
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        id: Symbol,
+        choice: Symbol,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        voter.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if proposal.status != OptimisticGovernanceProposalStatus::Challenged
+            || env.ledger().timestamp() > proposal.vote_end
+        {
+            return Err(OptimisticGovernanceContractErrors::VotingNotActive);
+        }
+        if choice != VOTE_FOR && choice != VOTE_AGAINST {
+            return Err(OptimisticGovernanceContractErrors::InvalidChoice);
+        }
+        let voted_key = OptimisticGovernanceContractDataKey::Voted(id.clone(), voter.clone());
+        if env.storage().persistent().has(&voted_key) {
+            return Err(OptimisticGovernanceContractErrors::UserAlreadyVoted);
+        }
+
+        let token_address = Self::read_token(&env)?;
+        let weight = TokenClient::new(&env, &token_address).balance(&voter);
+        if weight <= 0 {
+            return Err(OptimisticGovernanceContractErrors::UserCannotVote);
+        }
+
+        if choice == VOTE_FOR {
+            proposal.votes_for = proposal.votes_for.saturating_add(weight);
+        } else {
+            proposal.votes_against = proposal.votes_against.saturating_add(weight);
+        }
+        Self::write_proposal(&env, &id, &proposal);
+
+        env.storage().persistent().set(&voted_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&voted_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+
+        env.events()
+            .publish(("PROPOSAL", "VOTE", id), (voter, choice, weight));
+        Ok(())
+    }
+
+    // Resolves a proposal: unchallenged ones pass, challenged ones follow the token vote
+    pub fn resolve(
+        env: Env,
+        id: Symbol,
+    ) -> Result<OptimisticGovernanceProposalStatus, OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        let ledger_time = env.ledger().timestamp();
+
+        match proposal.status {
+            OptimisticGovernanceProposalStatus::Pending => {
+                if ledger_time <= proposal.challenge_end {
+                    return Err(OptimisticGovernanceContractErrors::ChallengeWindowOpen);
+                }
+                proposal.status = OptimisticGovernanceProposalStatus::Approved;
+            }
+            OptimisticGovernanceProposalStatus::Challenged => {
+                if ledger_time <= proposal.vote_end {
+                    return Err(OptimisticGovernanceContractErrors::VotingNotEnded);
+                }
+                let challenger = proposal
+                    .challenger
+                    .clone()
+                    .ok_or(OptimisticGovernanceContractErrors::ProposalNotFound)?;
+                let token_client = TokenClient::new(&env, &Self::read_token(&env)?);
+
+                // An upheld proposal slashes the bond to the proposer, otherwise it is refunded
+                if proposal.votes_for > proposal.votes_against {
+                    proposal.status = OptimisticGovernanceProposalStatus::Approved;
+                    token_client.transfer(
+                        &env.current_contract_address(),
+                        &proposal.proposer,
+                        &proposal.bond,
+                    );
+                    env.events().publish(
+                        ("BOND", "SLASHED", id.clone()),
+                        (challenger, proposal.proposer.clone(), proposal.bond),
+                    );
+                } else {
+                    proposal.status = OptimisticGovernanceProposalStatus::Rejected;
+                    token_client.transfer(
+                        &env.current_contract_address(),
+                        &challenger,
+                        &proposal.bond,
+                    );
+                    env.events().publish(
+                        ("BOND", "REFUNDED", id.clone()),
+                        (challenger, proposal.bond),
+                    );
+                }
+            }
+            _ => return Err(OptimisticGovernanceContractErrors::ProposalAlreadyResolved),
+        }
+        Self::write_proposal(&env, &id, &proposal);
+
+        env.events()
+            .publish(("PROPOSAL", "RESOLVED", id), proposal.status);
+        Ok(proposal.status)
+    }
+
+    // Updates the configuration applied to new proposals and challenges (admin only)
+    pub fn set_config(
+        env: Env,
+        challenge_period: u64,
+        vote_duration: u64,
+        bond: i128,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let config = OptimisticGovernanceConfig {
+            challenge_period,
+            vote_duration,
+            bond,
+        };
+        Self::validate_config(&config)?;
+        env.storage()
+            .instance()
+            .set(&OptimisticGovernanceContractDataKey::Config, &config);
+
+        env.events().publish(
+            ("CONFIG", "UPDATED"),
+            (challenge_period, vote_duration, bond),
+        );
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), OptimisticGovernanceContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&OptimisticGovernanceContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the configuration applied to new proposals and challenges
+    pub fn get_config(
+        env: Env,
+    ) -> Result<OptimisticGovernanceConfig, OptimisticGovernanceContractErrors> {
+        Self::read_config(&env)
+    }
+
+    // Returns the full state of a proposal
+    pub fn get_proposal(
+        env: Env,
+        id: Symbol,
+    ) -> Result<OptimisticGovernanceProposal, OptimisticGovernanceContractErrors> {
+        Self::read_proposal(&env, &id)
+    }
+
+    // Returns whether a voter has voted on an escalated proposal
+    pub fn has_voted(env: Env, voter: Address, id: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&OptimisticGovernanceContractDataKey::Voted(id, voter))
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,377 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, OPTIMISTIC_GOVERNANCE_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+fn create_optimistic_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> OptimisticGovernanceContractClient<'a> {
+    let contract_address = e.register(
+        OptimisticGovernanceContract,
+        OptimisticGovernanceContractArgs::__constructor(
+            admin,
+            token_address,
+            &86400,
+            &172800,
+            &500,
+        ),
+    );
+    OptimisticGovernanceContractClient::new(e, &contract_address)
+}
+
+// Deploys the token and contract, then submits proposal "PROP1" from a new proposer
+fn setup_proposal<'a>(
+    e: &Env,
+) -> (
+    OptimisticGovernanceContractClient<'a>,
+    TokenClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let proposer = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let client = create_optimistic_contract(e, &admin, &token.address);
+
+    client.propose(
+        &proposer,
+        &symbol_short!("PROP1"),
+        &String::from_str(e, "Raise the grants budget"),
+    );
+    (client, token, proposer)
+}
+
+// Tests successful initialization with admin, token, and proposal configuration.
+// Expects: Stored configuration matches constructor arguments.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_optimistic_contract(&e, &admin, &token.address);
+
+    let config = client.get_config();
+    assert_eq!(config.challenge_period, 86400);
+    assert_eq!(config.vote_duration, 172800);
+    assert_eq!(config.bond, 500);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_optimistic_contract(&e, &admin, &token.address);
+
+    e.register_at(
+        &client.address,
+        OptimisticGovernanceContract,
+        OptimisticGovernanceContractArgs::__constructor(
+            &admin,
+            &token.address,
+            &86400,
+            &172800,
+            &500,
+        ),
+    );
+}
+
+// Tests configuration update with a zero bond.
+// Expects: InvalidConfig error (Error #15).
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_invalid_config() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_proposal(&e);
+
+    client.set_config(&86400, &172800, &0);
+}
+
+// Tests an unchallenged proposal passing once the challenge window closes.
+// Expects: Proposal resolves to Approved without any vote.
+#[test]
+fn test_unchallenged_proposal_passes() {
+    let e = setup_test_env();
+    let (client, _, proposer) = setup_proposal(&e);
+    let id = symbol_short!("PROP1");
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.proposer, proposer);
+    assert_eq!(proposal.challenge_end, 1086400);
+    assert_eq!(proposal.status, OptimisticGovernanceProposalStatus::Pending);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1086401);
+    assert_eq!(
+        client.resolve(&id),
+        OptimisticGovernanceProposalStatus::Approved
+    );
+    assert_eq!(
+        client.get_proposal(&id).status,
+        OptimisticGovernanceProposalStatus::Approved
+    );
+}
+
+// Tests resolving an unchallenged proposal while it can still be challenged.
+// Expects: ChallengeWindowOpen error (Error #612).
+#[test]
+#[should_panic(expected = "Error(Contract, #612)")]
+fn test_resolve_during_challenge_window() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_proposal(&e);
+
+    client.resolve(&symbol_short!("PROP1"));
+}
+
+// Tests a challenge locking the bond and escalating the proposal to a token vote.
+// Expects: Bond moves to the contract and the vote window opens.
+#[test]
+fn test_challenge_locks_bond() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &800);
+    client.challenge(&challenger, &id);
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(
+        proposal.status,
+        OptimisticGovernanceProposalStatus::Challenged
+    );
+    assert_eq!(proposal.challenger, Some(challenger.clone()));
+    assert_eq!(proposal.bond, 500);
+    assert_eq!(proposal.vote_end, 1172800);
+    assert_eq!(token.balance(&challenger), 300);
+    assert_eq!(token.balance(&client.address), 500);
+}
+
+// Tests challenging after the challenge window has closed.
+// Expects: ChallengeWindowClosed error (Error #606).
+#[test]
+#[should_panic(expected = "Error(Contract, #606)")]
+fn test_challenge_after_window() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+
+    stellar_asset.mint(&challenger, &500);
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1086401);
+    client.challenge(&challenger, &symbol_short!("PROP1"));
+}
+
+// Tests challenging a proposal that is already challenged.
+// Expects: ProposalNotPending error (Error #607).
+#[test]
+#[should_panic(expected = "Error(Contract, #607)")]
+fn test_challenge_twice() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &1000);
+    client.challenge(&challenger, &id);
+    client.challenge(&challenger, &id);
+}
+
+// Tests voting on a proposal that has not been challenged.
+// Expects: VotingNotActive error (Error #7).
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_vote_without_challenge() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let voter = Address::generate(&e);
+
+    stellar_asset.mint(&voter, &100);
+    client.vote(&voter, &symbol_short!("PROP1"), &symbol_short!("FOR"));
+}
+
+// Tests a voter casting a second vote on an escalated proposal.
+// Expects: UserAlreadyVoted error (Error #5).
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_user_already_voted() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &500);
+    stellar_asset.mint(&voter, &100);
+    client.challenge(&challenger, &id);
+    client.vote(&voter, &id, &symbol_short!("FOR"));
+    client.vote(&voter, &id, &symbol_short!("AGAINST"));
+}
+
+// Tests a failed challenge where the token vote upholds the proposal.
+// Expects: Proposal is Approved and the challenger's bond is slashed to the proposer.
+#[test]
+fn test_failed_challenge_slashes_bond() {
+    let e = setup_test_env();
+    let (client, token, proposer) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let supporter = Address::generate(&e);
+    let opponent = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &500);
+    stellar_asset.mint(&supporter, &300);
+    stellar_asset.mint(&opponent, &200);
+    client.challenge(&challenger, &id);
+    client.vote(&supporter, &id, &symbol_short!("FOR"));
+    client.vote(&opponent, &id, &symbol_short!("AGAINST"));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1172801);
+    assert_eq!(
+        client.resolve(&id),
+        OptimisticGovernanceProposalStatus::Approved
+    );
+    assert_eq!(token.balance(&proposer), 500);
+    assert_eq!(token.balance(&challenger), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// Tests a successful challenge where the token vote overturns the proposal.
+// Expects: Proposal is Rejected and the bond is refunded to the challenger.
+#[test]
+fn test_successful_challenge_refunds_bond() {
+    let e = setup_test_env();
+    let (client, token, proposer) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let opponent = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &500);
+    stellar_asset.mint(&opponent, &200);
+    client.challenge(&challenger, &id);
+    client.vote(&opponent, &id, &symbol_short!("AGAINST"));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1172801);
+    assert_eq!(
+        client.resolve(&id),
+        OptimisticGovernanceProposalStatus::Rejected
+    );
+    assert_eq!(token.balance(&challenger), 500);
+    assert_eq!(token.balance(&proposer), 0);
+}
+
+// Tests resolving a challenged proposal before the token vote ends.
+// Expects: VotingNotEnded error (Error #14).
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_resolve_before_vote_ends() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_proposal(&e);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let challenger = Address::generate(&e);
+    let id = symbol_short!("PROP1");
+
+    stellar_asset.mint(&challenger, &500);
+    client.challenge(&challenger, &id);
+    client.resolve(&id);
+}
+
+// Tests resolving a proposal that has already been resolved.
+// Expects: ProposalAlreadyResolved error (Error #614).
+#[test]
+#[should_panic(expected = "Error(Contract, #614)")]
+fn test_resolve_twice() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_proposal(&e);
+    let id = symbol_short!("PROP1");
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1086401);
+    client.resolve(&id);
+    client.resolve(&id);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version and no support for any governance feature.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_optimistic_contract(&e, &admin, &token.address);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 600 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        OptimisticGovernanceContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::ProposalAlreadyExists as u32,
+        GovernanceError::ProposalAlreadyExists as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::ProposalNotFound as u32,
+        GovernanceError::ProposalNotFound as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::VotingNotActive as u32,
+        GovernanceError::VotingNotActive as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::InvalidChoice as u32,
+        GovernanceError::InvalidChoice as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::UserAlreadyVoted as u32,
+        GovernanceError::UserAlreadyVoted as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::UserCannotVote as u32,
+        GovernanceError::UserCannotVote as u32
+    );
+    assert_eq!(
+        OptimisticGovernanceContractErrors::VotingNotEnded as u32,
+        GovernanceError::VotingNotEnded as u32
+    );
+    for error in [
+        OptimisticGovernanceContractErrors::ChallengeWindowClosed,
+        OptimisticGovernanceContractErrors::ProposalNotPending,
+        OptimisticGovernanceContractErrors::ChallengeWindowOpen,
+        OptimisticGovernanceContractErrors::ProposalAlreadyResolved,
+    ] {
+        assert!(in_range(error as u32, OPTIMISTIC_GOVERNANCE_CODES));
+    }
+}
@@ -0,0 +1,379 @@
+#![no_std]
+
+use governance_core::OracleWeight;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec,
+};
+
+pub use pg_contracts_types::oracle_weight::{OracleWeightAttestation, OracleWeightUpdate};
+
+// --- Attestation Bounds ---
+const MAX_ATTESTATION_BATCH: u32 = 50; // Weight updates accepted per signed attestation
+const MAX_WEIGHT_HISTORY: u32 = 8; // Attestations kept per user for snapshot lookups
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const ORACLE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+const WEIGHT_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 3] = [
+    governance_core::FEATURE_WEIGHT,
+    governance_core::FEATURE_ORACLE,
+    governance_core::FEATURE_SNAPSHOTS,
+];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum OracleWeightContractDataKey {
+    Admin,            // Contract administrator address
+    MaxAge,           // Seconds an attestation stays usable after it was issued
+    Frozen,           // Emergency switch suspending updates and weights
+    Oracle(Address),  // ed25519 public key per whitelisted oracle
+    Nonce(Address),   // Next nonce an oracle's attestation must commit to
+    Weights(Address), // Most recent attestations per user, oldest first
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1500-1599 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleWeightContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidAmount = 13,             // Attested weights must not be negative
+    InvalidConfig = 15,             // Maximum attestation age must be positive
+    OracleNotFound = 1501,          // The address is not a whitelisted oracle
+    OracleFrozen = 1502,            // Oracle updates are frozen
+    StaleAttestation = 1503,        // Older than the maximum age or a user's latest attestation
+    FutureAttestation = 1504,       // The attestation is dated after the current ledger time
+    BatchTooLarge = 1505,           // The attestation carries more than 50 updates
+}
+
+#[contract]
+pub struct OracleWeightContract;
+
+#[contractimpl]
+impl OracleWeightContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, OracleWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&OracleWeightContractDataKey::Admin)
+            .ok_or(OracleWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Rejects a zero maximum attestation age
+    fn check_max_age(max_age: u64) -> Result<(), OracleWeightContractErrors> {
+        if max_age == 0 {
+            return Err(OracleWeightContractErrors::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    // Saves a user's attestation history and extends its TTL
+    fn write_history(env: &Env, user: &Address, history: &Vec<OracleWeight>) {
+        let weights_key = OracleWeightContractDataKey::Weights(user.clone());
+        env.storage().persistent().set(&weights_key, history);
+        env.storage().persistent().extend_ttl(
+            &weights_key,
+            WEIGHT_TTL_EXTENSION,
+            WEIGHT_TTL_EXTENSION,
+        );
+    }
+
+    // Appends an attested weight to a user's history, replacing an entry issued at the same
+    // time and dropping the oldest beyond MAX_WEIGHT_HISTORY
+    fn record_weight(
+        env: &Env,
+        update: &OracleWeightUpdate,
+        issued_at: u64,
+    ) -> Result<(), OracleWeightContractErrors> {
+        if update.weight < 0 {
+            return Err(OracleWeightContractErrors::InvalidAmount);
+        }
+        let mut history = Self::get_weight_history(env.clone(), update.user.clone());
+        if let Some(latest) = history.last() {
+            if latest.issued_at > issued_at {
+                return Err(OracleWeightContractErrors::StaleAttestation);
+            }
+            if latest.issued_at == issued_at {
+                history.pop_back();
+            }
+        }
+        history.push_back(OracleWeight {
+            weight: update.weight,
+            issued_at,
+        });
+        while history.len() > MAX_WEIGHT_HISTORY {
+            history.pop_front();
+        }
+        Self::write_history(env, &update.user, &history);
+        Ok(())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and the maximum age of usable attestations
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        max_age: u64,
+    ) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&OracleWeightContractDataKey::Admin)
+        {
+            return Err(OracleWeightContractErrors::ContractAlreadyInitialized);
+        }
+        Self::check_max_age(max_age)?;
+
+        env.storage()
+            .instance()
+            .set(&OracleWeightContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&OracleWeightContractDataKey::MaxAge, &max_age);
+        Ok(())
+    }
+
+    // Whitelists an oracle with the ed25519 key its attestations are signed with, or rotates
+    // the key of an existing oracle (admin only)
+    pub fn set_oracle(
+        env: Env,
+        oracle: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let oracle_key = OracleWeightContractDataKey::Oracle(oracle.clone());
+        env.storage().persistent().set(&oracle_key, &public_key);
+        env.storage().persistent().extend_ttl(
+            &oracle_key,
+            ORACLE_TTL_EXTENSION,
+            ORACLE_TTL_EXTENSION,
+        );
+
+        env.events()
+            .publish(("ORACLE", "UPDATED", oracle), public_key);
+        Ok(())
+    }
+
+    // Removes an oracle from the whitelist; its nonce is kept so old attestations stay
+    // unusable if it is whitelisted again (admin only)
+    pub fn remove_oracle(env: Env, oracle: Address) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let oracle_key = OracleWeightContractDataKey::Oracle(oracle.clone());
+        if !env.storage().persistent().has(&oracle_key) {
+            return Err(OracleWeightContractErrors::OracleNotFound);
+        }
+        env.storage().persistent().remove(&oracle_key);
+
+        env.events().publish(("ORACLE", "REMOVED", oracle), ());
+        Ok(())
+    }
+
+    // Sets how long an attestation stays usable after it was issued (admin only)
+    pub fn set_max_age(env: Env, max_age: u64) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+        Self::check_max_age(max_age)?;
+
+        env.storage()
+            .instance()
+            .set(&OracleWeightContractDataKey::MaxAge, &max_age);
+
+        env.events().publish(("MAX_AGE", "UPDATED"), max_age);
+        Ok(())
+    }
+
+    // Freezes or resumes the oracle in an emergency; while frozen, attestations are rejected
+    // and every weight reads as zero (admin only)
+    pub fn set_frozen(env: Env, frozen: bool) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&OracleWeightContractDataKey::Frozen, &frozen);
+
+        let action = if frozen { "FROZEN" } else { "UNFROZEN" };
+        env.events().publish(("ORACLE", action), admin);
+        Ok(())
+    }
+
+    // Records a batch of weights signed off-chain by a whitelisted oracle, submitted by any
+    // relayer; an invalid signature traps the invocation. Returns the number of updates
+    pub fn submit_attestation(
+        env: Env,
+        oracle: Address,
+        updates: Vec<OracleWeightUpdate>,
+        issued_at: u64,
+        signature: BytesN<64>,
+    ) -> Result<u32, OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if Self::is_frozen(env.clone()) {
+            return Err(OracleWeightContractErrors::OracleFrozen);
+        }
+        let public_key = Self::get_oracle(env.clone(), oracle.clone())
+            .ok_or(OracleWeightContractErrors::OracleNotFound)?;
+        if updates.len() > MAX_ATTESTATION_BATCH {
+            return Err(OracleWeightContractErrors::BatchTooLarge);
+        }
+        let ledger_time = env.ledger().timestamp();
+        if issued_at > ledger_time {
+            return Err(OracleWeightContractErrors::FutureAttestation);
+        }
+        if ledger_time - issued_at > Self::get_max_age(env.clone())? {
+            return Err(OracleWeightContractErrors::StaleAttestation);
+        }
+
+        let nonce = Self::get_nonce(env.clone(), oracle.clone());
+        let attestation = OracleWeightAttestation {
+            contract: env.current_contract_address(),
+            oracle: oracle.clone(),
+            updates: updates.clone(),
+            nonce,
+            issued_at,
+        };
+        env.crypto()
+            .ed25519_verify(&public_key, &attestation.to_xdr(&env), &signature);
+
+        for update in updates.iter() {
+            Self::record_weight(&env, &update, issued_at)?;
+        }
+
+        let nonce_key = OracleWeightContractDataKey::Nonce(oracle.clone());
+        env.storage().persistent().set(&nonce_key, &(nonce + 1));
+        env.storage().persistent().extend_ttl(
+            &nonce_key,
+            ORACLE_TTL_EXTENSION,
+            ORACLE_TTL_EXTENSION,
+        );
+
+        env.events().publish(
+            ("ORACLE", "ATTESTED", oracle),
+            (nonce, updates.len(), issued_at),
+        );
+        Ok(updates.len())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), OracleWeightContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&OracleWeightContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the weight of the latest attestation issued at or before the snapshot, zero when
+    // none exists, it is older than the maximum age at the snapshot, or the oracle is frozen
+    pub fn calculate_weight(env: Env, user: Address, snapshot_time: u64) -> i128 {
+        if Self::is_frozen(env.clone()) {
+            return 0;
+        }
+        let max_age = Self::get_max_age(env.clone()).unwrap_or(0);
+        let history = Self::get_weight_history(env, user);
+        match history
+            .iter()
+            .rev()
+            .find(|attestation| attestation.issued_at <= snapshot_time)
+        {
+            Some(attestation) if snapshot_time - attestation.issued_at <= max_age => {
+                attestation.weight
+            }
+            _ => 0,
+        }
+    }
+
+    // Returns the latest attestation recorded for a user
+    pub fn get_attestation(env: Env, user: Address) -> Option<OracleWeight> {
+        Self::get_weight_history(env, user).last()
+    }
+
+    // Returns the attestations kept for a user, oldest first
+    pub fn get_weight_history(env: Env, user: Address) -> Vec<OracleWeight> {
+        env.storage()
+            .persistent()
+            .get(&OracleWeightContractDataKey::Weights(user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Returns whether oracle updates and weights are suspended
+    pub fn is_frozen(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&OracleWeightContractDataKey::Frozen)
+            .unwrap_or(false)
+    }
+
+    // Returns the ed25519 public key of a whitelisted oracle
+    pub fn get_oracle(env: Env, oracle: Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&OracleWeightContractDataKey::Oracle(oracle))
+    }
+
+    // Returns the next nonce an oracle's attestation must commit to
+    pub fn get_nonce(env: Env, oracle: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&OracleWeightContractDataKey::Nonce(oracle))
+            .unwrap_or(0)
+    }
+
+    // Returns how long an attestation stays usable after it was issued
+    pub fn get_max_age(env: Env) -> Result<u64, OracleWeightContractErrors> {
+        env.storage()
+            .instance()
+            .get(&OracleWeightContractDataKey::MaxAge)
+            .ok_or(OracleWeightContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
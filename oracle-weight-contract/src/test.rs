@@ -0,0 +1,434 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use governance_core::{OracleAdapterClient, OracleWeight};
+use governance_errors::{in_range, GovernanceError, ORACLE_WEIGHT_CODES};
+use governance_testutils::setup_test_env;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, Vec,
+};
+
+const MAX_AGE: u64 = 86_400; // One day
+
+fn create_oracle_weight_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    max_age: u64,
+) -> OracleWeightContractClient<'a> {
+    let contract_address = e.register(
+        OracleWeightContract,
+        OracleWeightContractArgs::__constructor(admin, &max_age),
+    );
+    OracleWeightContractClient::new(e, &contract_address)
+}
+
+// Creates the adapter with one whitelisted oracle signing with a fixed key
+fn setup_oracle<'a>(e: &Env) -> (OracleWeightContractClient<'a>, Address, SigningKey) {
+    let admin = Address::generate(e);
+    let oracle = Address::generate(e);
+    let signer = SigningKey::from_bytes(&[9; 32]);
+    let client = create_oracle_weight_contract(e, &admin, MAX_AGE);
+    client.set_oracle(
+        &oracle,
+        &BytesN::from_array(e, &signer.verifying_key().to_bytes()),
+    );
+    (client, oracle, signer)
+}
+
+// Signs a batch of updates for the oracle's current nonce
+fn sign_attestation(
+    e: &Env,
+    client: &OracleWeightContractClient,
+    oracle: &Address,
+    signer: &SigningKey,
+    updates: &Vec<OracleWeightUpdate>,
+    issued_at: u64,
+) -> BytesN<64> {
+    let attestation = OracleWeightAttestation {
+        contract: client.address.clone(),
+        oracle: oracle.clone(),
+        updates: updates.clone(),
+        nonce: client.get_nonce(oracle),
+        issued_at,
+    };
+    let message: std::vec::Vec<u8> = attestation.to_xdr(e).iter().collect();
+    BytesN::from_array(e, &signer.sign(&message).to_bytes())
+}
+
+// Signs and submits a batch of updates issued at the given time
+fn attest(
+    e: &Env,
+    client: &OracleWeightContractClient,
+    oracle: &Address,
+    signer: &SigningKey,
+    updates: &Vec<OracleWeightUpdate>,
+    issued_at: u64,
+) -> Result<u32, OracleWeightContractErrors> {
+    let signature = sign_attestation(e, client, oracle, signer, updates, issued_at);
+    match client.try_submit_attestation(oracle, updates, &issued_at, &signature) {
+        Ok(Ok(count)) => Ok(count),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+// Tests successful initialization with admin and maximum attestation age.
+// Expects: The age is stored, the adapter is not frozen, and unknown users weigh nothing.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_oracle_weight_contract(&e, &admin, MAX_AGE);
+
+    assert_eq!(client.get_max_age(), MAX_AGE);
+    assert!(!client.is_frozen());
+    assert_eq!(
+        client.calculate_weight(&Address::generate(&e), &e.ledger().timestamp()),
+        0
+    );
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_oracle_weight_contract(&e, &admin, MAX_AGE);
+
+    e.register_at(
+        &client.address,
+        OracleWeightContract,
+        OracleWeightContractArgs::__constructor(&admin, &MAX_AGE),
+    );
+}
+
+// Tests initialization and updates with a zero maximum attestation age.
+// Expects: InvalidConfig error (Error #15).
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_invalid_max_age() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_oracle_weight_contract(&e, &admin, MAX_AGE);
+
+    assert_eq!(
+        client.try_set_max_age(&0),
+        Err(Ok(OracleWeightContractErrors::InvalidConfig))
+    );
+    create_oracle_weight_contract(&e, &admin, 0);
+}
+
+// Tests a signed attestation relayed for two users.
+// Expects: Both weights are recorded, readable through the adapter interface, and the nonce
+// advances.
+#[test]
+fn test_submit_attestation() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let now = e.ledger().timestamp();
+
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: alice.clone(),
+            weight: 120,
+        },
+        OracleWeightUpdate {
+            user: bob.clone(),
+            weight: 45,
+        },
+    ];
+    e.set_auths(&[]);
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now - 60),
+        Ok(2)
+    );
+
+    let adapter = OracleAdapterClient::new(&e, &client.address);
+    assert_eq!(adapter.calculate_weight(&alice, &now), 120);
+    assert_eq!(adapter.calculate_weight(&bob, &now), 45);
+    assert_eq!(
+        adapter.get_attestation(&alice),
+        Some(OracleWeight {
+            weight: 120,
+            issued_at: now - 60,
+        })
+    );
+    assert!(!adapter.is_frozen());
+    assert_eq!(client.get_nonce(&oracle), 1);
+}
+
+// Tests replaying an accepted attestation.
+// Expects: Signature verification failure once the nonce has advanced.
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_submit_attestation_replay() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let now = e.ledger().timestamp();
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: Address::generate(&e),
+            weight: 10,
+        },
+    ];
+
+    let signature = sign_attestation(&e, &client, &oracle, &signer, &updates, now);
+    client.submit_attestation(&oracle, &updates, &now, &signature);
+    client.submit_attestation(&oracle, &updates, &now, &signature);
+}
+
+// Tests attestations from an address that is not whitelisted, before and after removal.
+// Expects: OracleNotFound error (Error #1501).
+#[test]
+fn test_remove_oracle() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: Address::generate(&e),
+            weight: 10,
+        },
+    ];
+    let now = e.ledger().timestamp();
+
+    assert_eq!(
+        attest(&e, &client, &Address::generate(&e), &signer, &updates, now),
+        Err(OracleWeightContractErrors::OracleNotFound)
+    );
+
+    client.remove_oracle(&oracle);
+    assert_eq!(client.get_oracle(&oracle), None);
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now),
+        Err(OracleWeightContractErrors::OracleNotFound)
+    );
+    assert_eq!(
+        client.try_remove_oracle(&oracle),
+        Err(Ok(OracleWeightContractErrors::OracleNotFound))
+    );
+}
+
+// Tests attestations older than the maximum age, dated in the future, and older than a user's
+// latest attestation.
+// Expects: StaleAttestation error (Error #1503) and FutureAttestation error (Error #1504).
+#[test]
+fn test_submit_attestation_stale() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let user = Address::generate(&e);
+    let now = e.ledger().timestamp();
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: user.clone(),
+            weight: 10,
+        },
+    ];
+
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now - MAX_AGE - 1),
+        Err(OracleWeightContractErrors::StaleAttestation)
+    );
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now + 1),
+        Err(OracleWeightContractErrors::FutureAttestation)
+    );
+
+    attest(&e, &client, &oracle, &signer, &updates, now).unwrap();
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now - 1),
+        Err(OracleWeightContractErrors::StaleAttestation)
+    );
+    assert_eq!(client.get_nonce(&oracle), 1);
+}
+
+// Tests attesting a negative weight.
+// Expects: InvalidAmount error (Error #13) and nothing recorded.
+#[test]
+fn test_submit_attestation_negative_weight() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let user = Address::generate(&e);
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: user.clone(),
+            weight: -1,
+        },
+    ];
+
+    assert_eq!(
+        attest(
+            &e,
+            &client,
+            &oracle,
+            &signer,
+            &updates,
+            e.ledger().timestamp()
+        ),
+        Err(OracleWeightContractErrors::InvalidAmount)
+    );
+    assert_eq!(client.get_attestation(&user), None);
+}
+
+// Tests an attestation carrying more updates than a batch allows.
+// Expects: BatchTooLarge error (Error #1505) before the signature is checked.
+#[test]
+fn test_submit_attestation_batch_too_large() {
+    let e = setup_test_env();
+    let (client, oracle, _) = setup_oracle(&e);
+    let mut updates = Vec::new(&e);
+    for _ in 0..=MAX_ATTESTATION_BATCH {
+        updates.push_back(OracleWeightUpdate {
+            user: Address::generate(&e),
+            weight: 1,
+        });
+    }
+
+    assert_eq!(
+        client.try_submit_attestation(
+            &oracle,
+            &updates,
+            &e.ledger().timestamp(),
+            &BytesN::from_array(&e, &[0; 64]),
+        ),
+        Err(Ok(OracleWeightContractErrors::BatchTooLarge))
+    );
+}
+
+// Tests snapshot lookups across a user's attestation history.
+// Expects: Each snapshot reads the latest attestation issued at or before it, snapshots before
+// the first attestation or past the maximum age read zero, and history is capped.
+#[test]
+fn test_snapshot_history() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let user = Address::generate(&e);
+    let first_at = e.ledger().timestamp();
+    let second_at = first_at + 1000;
+
+    let first = vec![
+        &e,
+        OracleWeightUpdate {
+            user: user.clone(),
+            weight: 100,
+        },
+    ];
+    attest(&e, &client, &oracle, &signer, &first, first_at).unwrap();
+    e.ledger().with_mut(|ledger| ledger.timestamp = second_at);
+    let second = vec![
+        &e,
+        OracleWeightUpdate {
+            user: user.clone(),
+            weight: 300,
+        },
+    ];
+    attest(&e, &client, &oracle, &signer, &second, second_at).unwrap();
+
+    assert_eq!(client.calculate_weight(&user, &(first_at - 1)), 0);
+    assert_eq!(client.calculate_weight(&user, &(first_at + 5)), 100);
+    assert_eq!(client.calculate_weight(&user, &second_at), 300);
+    assert_eq!(client.calculate_weight(&user, &(second_at + MAX_AGE)), 300);
+    assert_eq!(
+        client.calculate_weight(&user, &(second_at + MAX_AGE + 1)),
+        0
+    );
+
+    for offset in 1..=MAX_WEIGHT_HISTORY as u64 {
+        let issued_at = second_at + offset;
+        e.ledger().with_mut(|ledger| ledger.timestamp = issued_at);
+        attest(&e, &client, &oracle, &signer, &second, issued_at).unwrap();
+    }
+    let history = client.get_weight_history(&user);
+    assert_eq!(history.len(), MAX_WEIGHT_HISTORY);
+    assert_eq!(history.first().unwrap().issued_at, second_at + 1);
+}
+
+// Tests the emergency freeze switch.
+// Expects: While frozen, weights read as zero and attestations fail (Error #1502); unfreezing
+// restores recorded weights.
+#[test]
+fn test_freeze() {
+    let e = setup_test_env();
+    let (client, oracle, signer) = setup_oracle(&e);
+    let user = Address::generate(&e);
+    let now = e.ledger().timestamp();
+    let updates = vec![
+        &e,
+        OracleWeightUpdate {
+            user: user.clone(),
+            weight: 75,
+        },
+    ];
+    attest(&e, &client, &oracle, &signer, &updates, now).unwrap();
+
+    client.set_frozen(&true);
+    assert!(client.is_frozen());
+    assert_eq!(client.calculate_weight(&user, &now), 0);
+    assert_eq!(
+        attest(&e, &client, &oracle, &signer, &updates, now),
+        Err(OracleWeightContractErrors::OracleFrozen)
+    );
+
+    client.set_frozen(&false);
+    assert_eq!(client.calculate_weight(&user, &now), 75);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight, oracle and snapshots supported and unrelated
+// features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_oracle_weight_contract(&e, &Address::generate(&e), MAX_AGE);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(client.supports(&governance_core::FEATURE_ORACLE));
+    assert!(client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1500 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        OracleWeightContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        OracleWeightContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        OracleWeightContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    assert_eq!(
+        OracleWeightContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    for error in [
+        OracleWeightContractErrors::OracleNotFound,
+        OracleWeightContractErrors::OracleFrozen,
+        OracleWeightContractErrors::StaleAttestation,
+        OracleWeightContractErrors::FutureAttestation,
+        OracleWeightContractErrors::BatchTooLarge,
+    ] {
+        assert!(in_range(error as u32, ORACLE_WEIGHT_CODES));
+    }
+}
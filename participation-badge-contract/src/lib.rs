@@ -0,0 +1,268 @@
+#![no_std]
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+pub use pg_contracts_types::participation_badge::ParticipationBadge;
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const BADGE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_BADGES];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum ParticipationBadgeContractDataKey {
+    Admin,                           // Contract administrator address
+    Minter(Address),                 // Vote contracts allowed to mint badges
+    Badge(Address, Address, Symbol), // Badge per owner, vote contract, and proposal
+    BadgeCount(Address),             // Number of badges held per owner
+    TotalBadges,                     // Number of badges ever minted
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2300-2399 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParticipationBadgeContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    MinterNotAuthorized = 2301,     // The caller is not a registered minter
+    BadgeNotFound = 2302,           // The owner does not hold this badge
+}
+
+#[contract]
+pub struct ParticipationBadgeContract;
+
+#[contractimpl]
+impl ParticipationBadgeContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, ParticipationBadgeContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ParticipationBadgeContractDataKey::Admin)
+            .ok_or(ParticipationBadgeContractErrors::ContractNotInitialized)
+    }
+
+    // Authenticates a minter and checks it has been registered by the admin
+    fn require_minter(env: &Env, minter: &Address) -> Result<(), ParticipationBadgeContractErrors> {
+        minter.require_auth();
+        if !Self::is_minter(env.clone(), minter.clone()) {
+            return Err(ParticipationBadgeContractErrors::MinterNotAuthorized);
+        }
+        Ok(())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin
+    pub fn __constructor(env: Env, admin: Address) -> Result<(), ParticipationBadgeContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&ParticipationBadgeContractDataKey::Admin)
+        {
+            return Err(ParticipationBadgeContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&ParticipationBadgeContractDataKey::Admin, &admin);
+        Ok(())
+    }
+
+    // Registers or unregisters a vote contract allowed to mint badges (admin only)
+    pub fn set_minter(
+        env: Env,
+        minter: Address,
+        enabled: bool,
+    ) -> Result<(), ParticipationBadgeContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let minter_key = ParticipationBadgeContractDataKey::Minter(minter.clone());
+        if enabled {
+            env.storage().instance().set(&minter_key, &true);
+        } else {
+            env.storage().instance().remove(&minter_key);
+        }
+
+        env.events()
+            .publish(("MINTER", "UPDATED"), (minter, enabled));
+        Ok(())
+    }
+
+    // Mints the badge of a proposal on the calling vote contract to a voter (registered minters
+    // only); a badge the voter already holds is left as is
+    pub fn mint_badge(
+        env: Env,
+        minter: Address,
+        voter: Address,
+        proposal_id: Symbol,
+    ) -> Result<(), ParticipationBadgeContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_minter(&env, &minter)?;
+
+        let badge_key = ParticipationBadgeContractDataKey::Badge(
+            voter.clone(),
+            minter.clone(),
+            proposal_id.clone(),
+        );
+        if env.storage().persistent().has(&badge_key) {
+            return Ok(());
+        }
+        let badge = ParticipationBadge {
+            governance: minter,
+            proposal_id,
+            minted_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&badge_key, &badge);
+        env.storage()
+            .persistent()
+            .extend_ttl(&badge_key, BADGE_TTL_EXTENSION, BADGE_TTL_EXTENSION);
+
+        let count_key = ParticipationBadgeContractDataKey::BadgeCount(voter.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, BADGE_TTL_EXTENSION, BADGE_TTL_EXTENSION);
+        env.storage().instance().set(
+            &ParticipationBadgeContractDataKey::TotalBadges,
+            &(Self::total_badges(env.clone()) + 1),
+        );
+
+        env.events().publish(
+            ("BADGE", "MINTED", voter),
+            (badge.governance, badge.proposal_id),
+        );
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), ParticipationBadgeContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&ParticipationBadgeContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // Extends the TTL of a badge and its owner's badge count so old proof of participation
+    // stays queryable (anyone can call)
+    pub fn bump_badge(
+        env: Env,
+        owner: Address,
+        governance: Address,
+        proposal_id: Symbol,
+    ) -> Result<(), ParticipationBadgeContractErrors> {
+        let badge_key =
+            ParticipationBadgeContractDataKey::Badge(owner.clone(), governance, proposal_id);
+        if !env.storage().persistent().has(&badge_key) {
+            return Err(ParticipationBadgeContractErrors::BadgeNotFound);
+        }
+        let count_key = ParticipationBadgeContractDataKey::BadgeCount(owner);
+        for key in [badge_key, count_key] {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, BADGE_TTL_EXTENSION, BADGE_TTL_EXTENSION);
+        }
+        Ok(())
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns whether an address holds the badge of a proposal on a vote contract
+    pub fn has_badge(env: Env, owner: Address, governance: Address, proposal_id: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ParticipationBadgeContractDataKey::Badge(
+                owner,
+                governance,
+                proposal_id,
+            ))
+    }
+
+    // Returns the badge of a proposal on a vote contract held by an address, if any
+    pub fn get_badge(
+        env: Env,
+        owner: Address,
+        governance: Address,
+        proposal_id: Symbol,
+    ) -> Option<ParticipationBadge> {
+        env.storage()
+            .persistent()
+            .get(&ParticipationBadgeContractDataKey::Badge(
+                owner,
+                governance,
+                proposal_id,
+            ))
+    }
+
+    // Returns the number of badges held by an address
+    pub fn badge_count(env: Env, owner: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ParticipationBadgeContractDataKey::BadgeCount(owner))
+            .unwrap_or(0)
+    }
+
+    // Token-compatible alias of badge_count so balance-based contracts can read participation;
+    // there are no transfer functions, so badges stay with the voter they were minted to
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Self::badge_count(env, id) as i128
+    }
+
+    // Returns the number of badges ever minted
+    pub fn total_badges(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ParticipationBadgeContractDataKey::TotalBadges)
+            .unwrap_or(0)
+    }
+
+    // Returns whether an address is a registered minter
+    pub fn is_minter(env: Env, minter: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&ParticipationBadgeContractDataKey::Minter(minter))
+            .unwrap_or(false)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
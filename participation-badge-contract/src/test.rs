@@ -0,0 +1,180 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, PARTICIPATION_BADGE_CODES};
+use governance_testutils::{assert_last_event, setup_test_env, TEST_START_TIME};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn create_badge_contract<'a>(e: &Env, admin: &Address) -> ParticipationBadgeContractClient<'a> {
+    let contract_address = e.register(
+        ParticipationBadgeContract,
+        ParticipationBadgeContractArgs::__constructor(admin),
+    );
+    ParticipationBadgeContractClient::new(e, &contract_address)
+}
+
+// Deploys the badge contract with a registered minter, returning both
+fn setup_badges<'a>(e: &Env) -> (ParticipationBadgeContractClient<'a>, Address) {
+    let client = create_badge_contract(e, &Address::generate(e));
+    let minter = Address::generate(e);
+    client.set_minter(&minter, &true);
+    (client, minter)
+}
+
+// Tests successful initialization with admin.
+// Expects: No badges exist and no minter is registered.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let client = create_badge_contract(&e, &Address::generate(&e));
+
+    assert_eq!(client.total_badges(), 0);
+    assert!(!client.is_minter(&Address::generate(&e)));
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_badge_contract(&e, &admin);
+
+    e.register_at(
+        &client.address,
+        ParticipationBadgeContract,
+        ParticipationBadgeContractArgs::__constructor(&admin),
+    );
+}
+
+// Tests minting proposal badges by a registered minter.
+// Expects: Each badge is tied to the minter and proposal, and counts toward the owner's balance.
+#[test]
+fn test_mint_badge() {
+    let e = setup_test_env();
+    let (client, minter) = setup_badges(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP001");
+
+    client.mint_badge(&minter, &voter, &id);
+    assert_last_event(
+        &e,
+        &client.address,
+        ("BADGE", "MINTED", voter.clone()),
+        (minter.clone(), id.clone()),
+    );
+    assert!(client.has_badge(&voter, &minter, &id));
+    assert_eq!(
+        client.get_badge(&voter, &minter, &id),
+        Some(ParticipationBadge {
+            governance: minter.clone(),
+            proposal_id: id.clone(),
+            minted_at: TEST_START_TIME,
+        })
+    );
+    assert!(!client.has_badge(&voter, &minter, &symbol_short!("PROP002")));
+    assert!(!client.has_badge(&voter, &Address::generate(&e), &id));
+
+    client.mint_badge(&minter, &voter, &symbol_short!("PROP002"));
+    assert_eq!(client.badge_count(&voter), 2);
+    assert_eq!(client.balance(&voter), 2);
+    assert_eq!(client.total_badges(), 2);
+}
+
+// Tests minting a badge the voter already holds.
+// Expects: The original badge is kept and counted once.
+#[test]
+fn test_mint_badge_twice() {
+    let e = setup_test_env();
+    let (client, minter) = setup_badges(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP001");
+
+    client.mint_badge(&minter, &voter, &id);
+    e.ledger().with_mut(|ledger| ledger.timestamp += 100);
+    client.mint_badge(&minter, &voter, &id);
+
+    assert_eq!(
+        client.get_badge(&voter, &minter, &id).unwrap().minted_at,
+        TEST_START_TIME
+    );
+    assert_eq!(client.badge_count(&voter), 1);
+    assert_eq!(client.total_badges(), 1);
+}
+
+// Tests minting from an unregistered or removed minter.
+// Expects: MinterNotAuthorized error (Error #2301) in both cases.
+#[test]
+fn test_minter_not_authorized() {
+    let e = setup_test_env();
+    let (client, minter) = setup_badges(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP001");
+
+    assert_eq!(
+        client.try_mint_badge(&Address::generate(&e), &voter, &id),
+        Err(Ok(ParticipationBadgeContractErrors::MinterNotAuthorized))
+    );
+    client.set_minter(&minter, &false);
+    assert!(!client.is_minter(&minter));
+    assert_eq!(
+        client.try_mint_badge(&minter, &voter, &id),
+        Err(Ok(ParticipationBadgeContractErrors::MinterNotAuthorized))
+    );
+}
+
+// Tests extending the TTL of held and missing badges.
+// Expects: Held badges are bumped and missing ones fail with BadgeNotFound (Error #2302).
+#[test]
+fn test_bump_badge() {
+    let e = setup_test_env();
+    let (client, minter) = setup_badges(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP001");
+
+    assert_eq!(
+        client.try_bump_badge(&voter, &minter, &id),
+        Err(Ok(ParticipationBadgeContractErrors::BadgeNotFound))
+    );
+    client.mint_badge(&minter, &voter, &id);
+    client.bump_badge(&voter, &minter, &id);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with badges supported and others not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_badge_contract(&e, &Address::generate(&e));
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_BADGES));
+    assert!(!client.supports(&governance_core::FEATURE_TOKEN));
+    assert!(!client.supports(&governance_core::FEATURE_REPUTATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2300 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        ParticipationBadgeContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        ParticipationBadgeContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        ParticipationBadgeContractErrors::MinterNotAuthorized,
+        ParticipationBadgeContractErrors::BadgeNotFound,
+    ] {
+        assert!(in_range(error as u32, PARTICIPATION_BADGE_CODES));
+    }
+}
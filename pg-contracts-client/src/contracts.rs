@@ -0,0 +1,104 @@
+// Re-exports each contract crate under a module named after it. Every module holds the contract's
+// generated `<Contract>Client`, the `<Contract>Args` builders taking the typed arguments of each
+// entrypoint, and the contract's storage, data, and error types, so values cross the XDR boundary
+// with the exact definitions the contracts use.
+
+pub mod airdrop {
+    pub use airdrop_contract::*;
+}
+
+pub mod dual_token_weight {
+    pub use dual_token_weight_contract::*;
+}
+
+pub mod election {
+    pub use election_contract::*;
+}
+
+pub mod faucet {
+    pub use faucet_contract::*;
+}
+
+pub mod governance_factory {
+    pub use governance_factory_contract::*;
+}
+
+pub mod governance_token {
+    pub use governance_token_contract::*;
+}
+
+pub mod lock_boost {
+    pub use lock_boost_contract::*;
+}
+
+pub mod lp_weight {
+    pub use lp_weight_contract::*;
+}
+
+pub mod membership_dao {
+    pub use membership_dao_contract::*;
+}
+
+pub mod membership_token {
+    pub use membership_token_contract::*;
+}
+
+pub mod multi_token_weight {
+    pub use multi_token_weight_contract::*;
+}
+
+pub mod optimistic_governance {
+    pub use optimistic_governance_contract::*;
+}
+
+pub mod oracle_weight {
+    pub use oracle_weight_contract::*;
+}
+
+pub mod participation_badge {
+    pub use participation_badge_contract::*;
+}
+
+pub mod quadratic_vote {
+    pub use quadratic_vote_contract::*;
+}
+
+pub mod registry {
+    pub use registry_contract::*;
+}
+
+pub mod reputation {
+    pub use reputation_contract::*;
+}
+
+pub mod staked_vote {
+    pub use staked_vote_contract::*;
+}
+
+pub mod tenure_boost {
+    pub use tenure_boost_contract::*;
+}
+
+pub mod token_factory {
+    pub use token_factory_contract::*;
+}
+
+pub mod token_gated_vote {
+    pub use token_gated_vote_contract::*;
+}
+
+pub mod token_weighted_vote {
+    pub use token_weighted_vote_contract::*;
+}
+
+pub mod treasury {
+    pub use treasury_contract::*;
+}
+
+pub mod vesting {
+    pub use vesting_contract::*;
+}
+
+pub mod wrapped_gov_token {
+    pub use wrapped_gov_token_contract::*;
+}
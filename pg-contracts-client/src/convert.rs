@@ -0,0 +1,43 @@
+use core::str::FromStr;
+use soroban_sdk::{
+    xdr::{Limits, ReadXdr, ScAddress, ScVal, WriteXdr},
+    Address, Env, IntoVal, TryFromVal, Val,
+};
+
+use crate::ClientError;
+
+// Converts a typed value, such as a contracttype struct or an Address, into the ScVal the
+// contracts receive as arguments and store in ledger entries
+pub fn to_sc_val<T>(env: &Env, value: &T) -> Result<ScVal, ClientError>
+where
+    T: IntoVal<Env, Val>,
+{
+    let val: Val = value.into_val(env);
+    ScVal::try_from_val(env, &val).map_err(|_| ClientError::Conversion)
+}
+
+// Converts an ScVal read from a ledger entry, event, or simulation result into a typed value
+pub fn from_sc_val<T>(env: &Env, value: &ScVal) -> Result<T, ClientError>
+where
+    T: TryFromVal<Env, Val>,
+{
+    let val = Val::try_from_val(env, value).map_err(|_| ClientError::Conversion)?;
+    T::try_from_val(env, &val).map_err(|_| ClientError::Conversion)
+}
+
+// Encodes any XDR value, such as invocation arguments or an ScVal, as the base64 string RPC
+// endpoints accept
+pub fn to_xdr_base64<T: WriteXdr>(value: &T) -> Result<String, ClientError> {
+    Ok(value.to_xdr_base64(Limits::none())?)
+}
+
+// Decodes an XDR value from the base64 string RPC endpoints return
+pub fn from_xdr_base64<T: ReadXdr>(encoded: &str) -> Result<T, ClientError> {
+    Ok(T::from_xdr_base64(encoded, Limits::none())?)
+}
+
+// Parses an account (G...) or contract (C...) strkey into an Address
+pub fn address_from_strkey(env: &Env, strkey: &str) -> Result<Address, ClientError> {
+    let address = ScAddress::from_str(strkey).map_err(|_| ClientError::InvalidAddress)?;
+    Address::try_from_val(env, &address).map_err(|_| ClientError::InvalidAddress)
+}
@@ -0,0 +1,121 @@
+use governance_core::events::{
+    AdminTransferredEvent, ProposalAmendedEvent, ProposalCreatedEvent, ProposalFinalizedEvent,
+    ProposalStatusEvent, VoteCastEvent, VoteChangedEvent, ADMIN, AMENDED, CAST, CHANGED, CONFIG,
+    CREATED, EVENT_VERSION, FINALIZED, PROPOSAL, TRANSFERRED, UPDATED, VOTE,
+};
+use soroban_sdk::{
+    xdr::{ContractEvent, ContractEventBody, ScVal},
+    Env,
+};
+
+use crate::{from_sc_val, ClientError};
+
+// Event in the shared schema, with typed payloads for the shared actions and the raw topics and
+// data of contract-specific ones
+#[derive(Clone, Debug, PartialEq)]
+pub enum GovernanceEvent {
+    ProposalCreated(ProposalCreatedEvent),     // PROPOSAL/CREATED
+    ProposalAmended(ProposalAmendedEvent),     // PROPOSAL/AMENDED
+    ProposalFinalized(ProposalFinalizedEvent), // PROPOSAL/FINALIZED
+    ProposalStatus {
+        action: String,             // Transition, such as ACTIVATED, CANCELLED, or EXECUTED
+        event: ProposalStatusEvent, // Proposal and the status it entered
+    },
+    VoteCast(VoteCastEvent),       // VOTE/CAST
+    VoteChanged(VoteChangedEvent), // VOTE/CHANGED
+    ConfigUpdated {
+        setting: String, // Setting name, the event subject
+        value: ScVal,    // New value, typed per setting
+    },
+    AdminTransferred(AdminTransferredEvent), // ADMIN/TRANSFERRED
+    Other(RawEvent),                         // Contract-specific or unversioned event
+}
+
+// Event outside the shared payloads, split into its topics
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawEvent {
+    pub category: String,       // First topic, such as TREASURY or BADGE
+    pub action: String,         // Second topic, such as PAID or MINTED
+    pub version: Option<u32>,   // Schema version, absent for events predating the versioned layout
+    pub subject: Option<ScVal>, // Topic after the version (or the action when unversioned)
+    pub data: ScVal,            // Event payload
+}
+
+// Reads a symbol or string topic as text
+fn topic_text(topic: &ScVal) -> Result<String, ClientError> {
+    match topic {
+        ScVal::Symbol(symbol) => Ok(symbol.to_utf8_string_lossy()),
+        ScVal::String(string) => Ok(string.to_utf8_string_lossy()),
+        _ => Err(ClientError::MalformedEvent),
+    }
+}
+
+// Decodes an event from its topics and data, as RPC getEvents returns them once read from base64.
+// Versioned events of another schema version fail with UnsupportedEventVersion, and a shared
+// action whose payload does not match its type fails with Conversion
+pub fn decode_event(
+    env: &Env,
+    topics: &[ScVal],
+    data: &ScVal,
+) -> Result<GovernanceEvent, ClientError> {
+    if topics.len() < 2 {
+        return Err(ClientError::MalformedEvent);
+    }
+    let category = topic_text(&topics[0])?;
+    let action = topic_text(&topics[1])?;
+    let (version, subject) = match topics.get(2) {
+        Some(ScVal::U32(version)) => (Some(*version), topics.get(3).cloned()),
+        subject => (None, subject.cloned()),
+    };
+    let raw = RawEvent {
+        category,
+        action,
+        version,
+        subject,
+        data: data.clone(),
+    };
+    match raw.version {
+        None => return Ok(GovernanceEvent::Other(raw)),
+        Some(version) if version != EVENT_VERSION => {
+            return Err(ClientError::UnsupportedEventVersion(version))
+        }
+        Some(_) => {}
+    }
+
+    let event = match (raw.category.as_str(), raw.action.as_str()) {
+        (PROPOSAL, CREATED) => GovernanceEvent::ProposalCreated(from_sc_val(env, data)?),
+        (PROPOSAL, AMENDED) => GovernanceEvent::ProposalAmended(from_sc_val(env, data)?),
+        (PROPOSAL, FINALIZED) => GovernanceEvent::ProposalFinalized(from_sc_val(env, data)?),
+        // Other proposal actions share the status payload, except contract-specific ones such
+        // as LEDGER_WINDOW, which stay raw
+        (PROPOSAL, _) => match from_sc_val::<ProposalStatusEvent>(env, data) {
+            Ok(event) => GovernanceEvent::ProposalStatus {
+                action: raw.action,
+                event,
+            },
+            Err(_) => GovernanceEvent::Other(raw),
+        },
+        (VOTE, CAST) => GovernanceEvent::VoteCast(from_sc_val(env, data)?),
+        (VOTE, CHANGED) => GovernanceEvent::VoteChanged(from_sc_val(env, data)?),
+        (CONFIG, UPDATED) => GovernanceEvent::ConfigUpdated {
+            setting: raw
+                .subject
+                .as_ref()
+                .map(topic_text)
+                .ok_or(ClientError::MalformedEvent)??,
+            value: raw.data,
+        },
+        (ADMIN, TRANSFERRED) => GovernanceEvent::AdminTransferred(from_sc_val(env, data)?),
+        _ => GovernanceEvent::Other(raw),
+    };
+    Ok(event)
+}
+
+// Decodes a contract event read from transaction meta or an RPC getEvents result
+pub fn decode_contract_event(
+    env: &Env,
+    event: &ContractEvent,
+) -> Result<GovernanceEvent, ClientError> {
+    let ContractEventBody::V0(body) = &event.body;
+    decode_event(env, &body.topics, &body.data)
+}
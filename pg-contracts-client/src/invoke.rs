@@ -0,0 +1,45 @@
+use soroban_sdk::{
+    xdr::{InvokeContractArgs, ScAddress, ScSymbol, ScVal},
+    Address, Env, IntoVal, TryFromVal, Val, Vec,
+};
+
+use crate::ClientError;
+
+// Encodes a contract call as the InvokeContractArgs of an InvokeHostFunction operation, ready to
+// simulate and submit through RPC. Arguments are taken from the contract's generated Args builder,
+// such as `TokenGatedVoteContractArgs::vote(&voter, &id, &choice)`, so their types are checked
+// against the entrypoint at compile time; the invoke! macro also derives the function name
+pub fn invocation<A>(
+    env: &Env,
+    contract: &Address,
+    function: &str,
+    args: A,
+) -> Result<InvokeContractArgs, ClientError>
+where
+    A: IntoVal<Env, Vec<Val>>,
+{
+    let args: Vec<Val> = args.into_val(env);
+    let mut encoded = std::vec::Vec::with_capacity(args.len() as usize);
+    for arg in args.iter() {
+        encoded.push(ScVal::try_from_val(env, &arg).map_err(|_| ClientError::Conversion)?);
+    }
+    Ok(InvokeContractArgs {
+        contract_address: ScAddress::from(contract),
+        function_name: ScSymbol(function.try_into()?),
+        args: encoded.try_into()?,
+    })
+}
+
+// Encodes a call from the contract's Args builder and the entrypoint name it is named after:
+// `invoke!(&env, &contract, TokenGatedVoteContractArgs::vote(&voter, &id, &choice))`
+#[macro_export]
+macro_rules! invoke {
+    ($env:expr, $contract:expr, $args:ident :: $function:ident ( $($arg:expr),* $(,)? )) => {
+        $crate::invocation(
+            $env,
+            $contract,
+            stringify!($function),
+            $args::$function($($arg),*),
+        )
+    };
+}
@@ -0,0 +1,56 @@
+use core::fmt;
+use soroban_sdk::{xdr, ConversionError};
+
+pub mod contracts;
+mod convert;
+mod events;
+mod invoke;
+pub use convert::{address_from_strkey, from_sc_val, from_xdr_base64, to_sc_val, to_xdr_base64};
+pub use events::{decode_contract_event, decode_event, GovernanceEvent, RawEvent};
+pub use invoke::invocation;
+
+// Re-exported so consumers build values and read XDR with the SDK version the contracts use
+pub use governance_core;
+pub use pg_contracts_types;
+pub use soroban_sdk;
+
+// Enumerates the failures of encoding calls and decoding ledger values off-chain
+#[derive(Debug)]
+pub enum ClientError {
+    Xdr(xdr::Error),              // XDR encoding, decoding, or base64 handling failed
+    Conversion,                   // A value did not match the expected contract type
+    InvalidAddress,               // An address string is not a valid account or contract strkey
+    MalformedEvent,               // An event lacks the (category, action) topics of the schema
+    UnsupportedEventVersion(u32), // An event was published under a different schema version
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Xdr(error) => write!(f, "xdr error: {error}"),
+            ClientError::Conversion => write!(f, "value does not match the contract type"),
+            ClientError::InvalidAddress => write!(f, "invalid account or contract strkey"),
+            ClientError::MalformedEvent => write!(f, "event lacks the category and action topics"),
+            ClientError::UnsupportedEventVersion(version) => {
+                write!(f, "unsupported event schema version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<xdr::Error> for ClientError {
+    fn from(error: xdr::Error) -> Self {
+        ClientError::Xdr(error)
+    }
+}
+
+impl From<ConversionError> for ClientError {
+    fn from(_: ConversionError) -> Self {
+        ClientError::Conversion
+    }
+}
+
+// --- Test Module ---
+mod test;
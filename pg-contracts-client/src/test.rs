@@ -0,0 +1,304 @@
+#![cfg(test)]
+
+use super::*;
+use crate::contracts::participation_badge::ParticipationBadge;
+use crate::contracts::token_gated_vote::{
+    TokenGatedVoteContract, TokenGatedVoteContractArgs, TokenGatedVoteContractClient,
+};
+use governance_core::{
+    events::{ProposalStatusEvent, VoteCastEvent, EVENT_VERSION},
+    GovernanceStatus, TokenSource,
+};
+use governance_testutils::{contract_events, create_token_contract, setup_test_env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    xdr::{
+        ContractEvent, ContractEventBody, ContractEventType, ContractEventV0, ExtensionPoint,
+        InvokeContractArgs, ScAddress, ScVal,
+    },
+    Address, Env, String as SorobanString, Symbol, TryFromVal,
+};
+
+const PROPOSAL_DURATION: u64 = 432_000; // Minimum gated vote proposal duration (~5 days)
+
+// Encodes a list of topics and a payload the way a contract publishes them
+fn encode_event<T, D>(e: &Env, topics: T, data: D) -> (Vec<ScVal>, ScVal)
+where
+    T: soroban_sdk::IntoVal<Env, soroban_sdk::Vec<soroban_sdk::Val>>,
+    D: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    let topics: soroban_sdk::Vec<soroban_sdk::Val> = topics.into_val(e);
+    (
+        topics
+            .iter()
+            .map(|topic| ScVal::try_from_val(e, &topic).unwrap())
+            .collect(),
+        to_sc_val(e, &data.into_val(e)).unwrap(),
+    )
+}
+
+// Decodes every event the contract published during the last invocation
+fn decode_published(e: &Env, contract: &Address) -> Vec<GovernanceEvent> {
+    contract_events(e, contract)
+        .iter()
+        .map(|(topics, data)| {
+            let (topics, data) = encode_event(e, topics, data);
+            decode_event(e, &topics, &data).unwrap()
+        })
+        .collect()
+}
+
+// Tests encoding a vote and a read through the typed Args builders.
+// Expects: Contract address, entrypoint name, and arguments as ScVal, surviving a base64 round
+// trip.
+#[test]
+fn test_invocation() {
+    let e = setup_test_env();
+    let contract = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let id = symbol_short!("PROP001");
+
+    let args = invoke!(
+        &e,
+        &contract,
+        TokenGatedVoteContractArgs::vote(&voter, &id, &symbol_short!("FOR"))
+    )
+    .unwrap();
+    assert_eq!(args.contract_address, ScAddress::from(&contract));
+    assert_eq!(args.function_name.to_utf8_string_lossy(), "vote");
+    assert_eq!(
+        args.args.to_vec(),
+        vec![
+            ScVal::from(&voter),
+            to_sc_val(&e, &id).unwrap(),
+            to_sc_val(&e, &symbol_short!("FOR")).unwrap(),
+        ]
+    );
+    let encoded = to_xdr_base64(&args).unwrap();
+    assert_eq!(
+        from_xdr_base64::<InvokeContractArgs>(&encoded).unwrap(),
+        args
+    );
+
+    let read = invoke!(&e, &contract, TokenGatedVoteContractArgs::get_admin()).unwrap();
+    assert_eq!(read.function_name.to_utf8_string_lossy(), "get_admin");
+    assert!(read.args.is_empty());
+}
+
+// Tests converting contract types to and from ScVal.
+// Expects: Structs round trip unchanged, and a value of another shape fails with Conversion.
+#[test]
+fn test_sc_val_round_trip() {
+    let e = setup_test_env();
+    let badge = ParticipationBadge {
+        governance: Address::generate(&e),
+        proposal_id: symbol_short!("PROP001"),
+        minted_at: 1_000,
+    };
+
+    let value = to_sc_val(&e, &badge).unwrap();
+    assert!(matches!(value, ScVal::Map(Some(_))));
+    assert_eq!(
+        from_sc_val::<ParticipationBadge>(&e, &value).unwrap(),
+        badge
+    );
+    assert_eq!(from_sc_val::<u64>(&e, &ScVal::U64(7)).unwrap(), 7);
+    assert!(matches!(
+        from_sc_val::<ParticipationBadge>(&e, &ScVal::U32(1)),
+        Err(ClientError::Conversion)
+    ));
+}
+
+// Tests parsing account and contract strkeys.
+// Expects: Both round trip to the same address, and malformed strings fail with InvalidAddress.
+#[test]
+fn test_address_from_strkey() {
+    let e = setup_test_env();
+    let contract = Address::generate(&e);
+    let strkey = ScAddress::from(&contract).to_string();
+    assert!(strkey.starts_with('C'));
+    assert_eq!(address_from_strkey(&e, &strkey).unwrap(), contract);
+
+    let account = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    assert_eq!(
+        ScAddress::from(&address_from_strkey(&e, account).unwrap()).to_string(),
+        account
+    );
+    assert!(matches!(
+        address_from_strkey(&e, "GINVALID"),
+        Err(ClientError::InvalidAddress)
+    ));
+}
+
+// Tests decoding the events of a create, vote, and finalize flow on a live contract.
+// Expects: Each shared action decodes to its typed payload in publication order.
+#[test]
+fn test_decode_lifecycle_events() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &100);
+    let client = TokenGatedVoteContractClient::new(
+        &e,
+        &e.register(
+            TokenGatedVoteContract,
+            (admin.clone(), TokenSource::Stellar(token.address.clone())),
+        ),
+    );
+
+    let id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &id,
+        &SorobanString::from_str(&e, "Test proposal"),
+        &start_time,
+        &end_time,
+    );
+    match decode_published(&e, &client.address).as_slice() {
+        [GovernanceEvent::ProposalCreated(event)] => {
+            assert_eq!(event.id, id);
+            assert_eq!(event.proposer, admin);
+            assert_eq!(event.end_time, end_time);
+        }
+        events => panic!("unexpected events: {events:?}"),
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voter, &id, &symbol_short!("FOR"));
+    assert_eq!(
+        decode_published(&e, &client.address),
+        vec![
+            GovernanceEvent::ProposalStatus {
+                action: "ACTIVATED".into(),
+                event: ProposalStatusEvent {
+                    id: id.clone(),
+                    status: GovernanceStatus::Active,
+                },
+            },
+            GovernanceEvent::VoteCast(VoteCastEvent {
+                id: id.clone(),
+                voter: voter.clone(),
+                choice: symbol_short!("FOR"),
+                weight: 1,
+            }),
+        ]
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&id);
+    match decode_published(&e, &client.address).as_slice() {
+        [GovernanceEvent::ProposalFinalized(event)] => {
+            assert_eq!(event.status, GovernanceStatus::Succeeded);
+            assert_eq!(event.total_for, 1);
+        }
+        events => panic!("unexpected events: {events:?}"),
+    }
+}
+
+// Tests decoding configuration, contract-specific, and unversioned events.
+// Expects: Settings keep their name and raw value, other events keep their topics, and
+// unversioned events report no version.
+#[test]
+fn test_decode_raw_events() {
+    let e = setup_test_env();
+    let voter = Address::generate(&e);
+
+    let (topics, data) = encode_event(&e, ("CONFIG", "UPDATED", EVENT_VERSION, "PAUSED"), true);
+    assert_eq!(
+        decode_event(&e, &topics, &data).unwrap(),
+        GovernanceEvent::ConfigUpdated {
+            setting: "PAUSED".into(),
+            value: ScVal::Bool(true),
+        }
+    );
+
+    let (topics, data) = encode_event(
+        &e,
+        ("TREASURY", "PAID", EVENT_VERSION, symbol_short!("PROP001")),
+        500_i128,
+    );
+    assert_eq!(
+        decode_event(&e, &topics, &data).unwrap(),
+        GovernanceEvent::Other(RawEvent {
+            category: "TREASURY".into(),
+            action: "PAID".into(),
+            version: Some(EVENT_VERSION),
+            subject: Some(to_sc_val(&e, &symbol_short!("PROP001")).unwrap()),
+            data: to_sc_val(&e, &500_i128).unwrap(),
+        })
+    );
+
+    let (topics, data) = encode_event(&e, ("BADGE", "MINTED", voter.clone()), 1_u32);
+    match decode_event(&e, &topics, &data).unwrap() {
+        GovernanceEvent::Other(raw) => {
+            assert_eq!(raw.version, None);
+            assert_eq!(raw.subject, Some(ScVal::from(&voter)));
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+}
+
+// Tests decoding malformed events, other schema versions, and mismatched payloads.
+// Expects: MalformedEvent, UnsupportedEventVersion, and Conversion errors respectively.
+#[test]
+fn test_decode_errors() {
+    let e = setup_test_env();
+
+    let (topics, data) = encode_event(&e, (Symbol::new(&e, "VOTE"),), 1_u32);
+    assert!(matches!(
+        decode_event(&e, &topics, &data),
+        Err(ClientError::MalformedEvent)
+    ));
+
+    let (topics, data) = encode_event(&e, ("VOTE", "CAST", EVENT_VERSION + 1), 1_u32);
+    assert!(matches!(
+        decode_event(&e, &topics, &data),
+        Err(ClientError::UnsupportedEventVersion(version)) if version == EVENT_VERSION + 1
+    ));
+
+    let (topics, data) = encode_event(&e, ("VOTE", "CAST", EVENT_VERSION), 1_u32);
+    assert!(matches!(
+        decode_event(&e, &topics, &data),
+        Err(ClientError::Conversion)
+    ));
+}
+
+// Tests decoding a contract event read from base64 XDR, as RPC returns it.
+// Expects: The same typed event as decoding its topics and data directly.
+#[test]
+fn test_decode_contract_event() {
+    let e = setup_test_env();
+    let previous = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let payload = governance_core::events::AdminTransferredEvent {
+        previous: previous.clone(),
+        admin: admin.clone(),
+    };
+    let (topics, data) = encode_event(
+        &e,
+        ("ADMIN", "TRANSFERRED", EVENT_VERSION, admin.clone()),
+        payload.clone(),
+    );
+    let event = ContractEvent {
+        ext: ExtensionPoint::V0,
+        contract_id: None,
+        type_: ContractEventType::Contract,
+        body: ContractEventBody::V0(ContractEventV0 {
+            topics: topics.try_into().unwrap(),
+            data,
+        }),
+    };
+
+    let encoded = to_xdr_base64(&event).unwrap();
+    let event = from_xdr_base64::<ContractEvent>(&encoded).unwrap();
+    assert_eq!(
+        decode_contract_event(&e, &event).unwrap(),
+        GovernanceEvent::AdminTransferred(payload)
+    );
+}
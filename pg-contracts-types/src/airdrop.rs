@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, BytesN};
+
+// Stores the Merkle root and funding of an airdrop round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropRound {
+    pub root: BytesN<32>, // Merkle root of the (address, amount) entries
+    pub total: i128,      // Tokens funded for the round
+    pub claimed: i128,    // Tokens claimed so far
+    pub deadline: u64,    // UNIX timestamp after which claims close
+    pub swept: bool,      // Whether unclaimed tokens were returned to the admin
+}
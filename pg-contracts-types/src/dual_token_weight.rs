@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address};
+
+// Stores the coefficients used to combine both balances
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DualTokenWeightCoefficients {
+    pub gov_coefficient: i128, // Multiplier for the governance balance, in basis points
+    pub rep_coefficient: i128, // Multiplier for the reputation balance, in basis points
+}
+
+// Stores the vesting contract whose allocations count toward the governance balance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DualTokenWeightVesting {
+    pub contract: Address,      // Vesting contract holding team and investor tokens
+    pub include_unvested: bool, // Whether unvested tokens also count as voting power
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+// Determines how voters distribute their weight across candidates
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElectionMode {
+    Single,     // Full weight goes to one candidate
+    Cumulative, // Seats x weight points split across candidates
+}
+
+// Stores the configuration and state of an election
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionData {
+    pub seats: u32,               // Number of council seats being elected
+    pub mode: ElectionMode,       // How voters distribute their weight
+    pub registration_end: u64,    // End of the candidate registration window
+    pub voting_end: u64,          // End of the voting window
+    pub stake: i128,              // Stake required from each candidate
+    pub candidates: Vec<Address>, // Registered candidates in registration order
+    pub elected: Vec<Address>,    // Candidates seated on finalization
+    pub finalized: bool,          // Whether the result was pushed to the council
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::contracttype;
+
+// Stores the rate limits applied to claims
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaucetConfig {
+    pub amount: i128,     // Tokens dispensed per claim
+    pub cooldown: u64,    // Seconds an address must wait between claims
+    pub window: u64,      // Length of the drain-protection window in seconds
+    pub window_cap: i128, // Maximum tokens dispensed across all addresses per window
+}
+
+// Tracks the tokens dispensed in the current drain-protection window
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FaucetWindow {
+    pub start: u64,      // UNIX timestamp when the window started
+    pub dispensed: i128, // Tokens dispensed since the window started
+}
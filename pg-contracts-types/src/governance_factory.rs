@@ -0,0 +1,39 @@
+use governance_core::{TokenSource, WeightScaling};
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+// Identifies the vote contract a governance instance runs
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GovernanceFactoryModel {
+    Gated,    // Token-Gated Vote Contract
+    Weighted, // Token-Weighted Vote Contract
+}
+
+// Carries the constructor configuration of a governance instance per model
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceFactoryInstanceConfig {
+    Gated(TokenSource),               // Token whose holders get one vote each
+    Weighted(Address, WeightScaling), // Weighting strategy contract and scaling of its weights
+}
+
+impl GovernanceFactoryInstanceConfig {
+    // Returns the model the configuration deploys
+    pub fn model(&self) -> GovernanceFactoryModel {
+        match self {
+            GovernanceFactoryInstanceConfig::Gated(_) => GovernanceFactoryModel::Gated,
+            GovernanceFactoryInstanceConfig::Weighted(..) => GovernanceFactoryModel::Weighted,
+        }
+    }
+}
+
+// Stores the registry record of a deployed governance instance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceFactoryInstance {
+    pub community: String, // Community the experiment belongs to
+    pub config: GovernanceFactoryInstanceConfig, // Model and constructor configuration
+    pub admin: Address,    // Administrator of the deployed instance
+    pub wasm: BytesN<32>,  // WASM hash the instance was deployed from
+    pub deployed_at: u64,  // UNIX timestamp of the deployment
+}
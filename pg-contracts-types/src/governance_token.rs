@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+// Stores a spending allowance and the ledger it expires on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceTokenAllowance {
+    pub amount: i128,           // Tokens the spender may still move
+    pub expiration_ledger: u32, // Last ledger on which the allowance is valid
+}
@@ -0,0 +1,32 @@
+#![no_std]
+
+// One module per contract with the contracttype structs and enums it stores, returns, and
+// publishes; storage keys and error enums stay private to each contract
+pub mod airdrop;
+pub mod dual_token_weight;
+pub mod election;
+pub mod faucet;
+pub mod governance_factory;
+pub mod governance_token;
+pub mod lock_boost;
+pub mod lp_weight;
+pub mod membership_dao;
+pub mod membership_token;
+pub mod multi_token_weight;
+pub mod optimistic_governance;
+pub mod oracle_weight;
+pub mod participation_badge;
+pub mod quadratic_vote;
+pub mod registry;
+pub mod reputation;
+pub mod staked_vote;
+pub mod tenure_boost;
+pub mod token_factory;
+pub mod token_gated_vote;
+pub mod token_weighted_vote;
+pub mod treasury;
+pub mod vesting;
+pub mod wrapped_gov_token;
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+// Stores a user's token lock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockBoostLock {
+    pub amount: i128,     // Tokens locked in the contract
+    pub unlock_time: u64, // UNIX timestamp after which the tokens can be unlocked
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+// Pool state shares are valued against
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpWeightReserves {
+    pub governance_reserve: i128, // Governance tokens held by the pool
+    pub total_shares: i128,       // LP shares outstanding
+    pub synced_at: u64,           // UNIX timestamp the reserves were read at
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+// Stores the membership record of a single member
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MembershipDaoMember {
+    pub joined_at: u64,  // UNIX timestamp when the member joined
+    pub dues_paid: i128, // Dues paid on joining
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address};
+
+// Stores the membership record of a single member
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MembershipTokenMember {
+    pub joined_at: u64, // UNIX timestamp the membership was minted at
+}
+
+// Membership change applied once the governance proposal it is bound to has passed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MembershipTokenAction {
+    pub member: Address, // Address gaining or losing membership
+    pub grant: bool,     // Whether membership is minted (true) or revoked (false)
+    pub executed: bool,  // Whether the change has been applied
+}
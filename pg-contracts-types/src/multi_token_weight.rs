@@ -0,0 +1,10 @@
+use governance_core::TokenSource;
+use soroban_sdk::contracttype;
+
+// Stores one token counted toward voting power
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiTokenWeightEntry {
+    pub token: TokenSource, // Token read, normalized to 7 decimals by its precision
+    pub multiplier: i128,   // Multiplier for its balance, in basis points
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address, String};
+
+// Stores the timing and bond parameters applied to new proposals
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OptimisticGovernanceConfig {
+    pub challenge_period: u64, // Seconds a proposal can be challenged after creation
+    pub vote_duration: u64,    // Seconds the escalated token vote stays open
+    pub bond: i128,            // Tokens a challenger must lock to dispute a proposal
+}
+
+// Represents the lifecycle status of an optimistic proposal
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OptimisticGovernanceProposalStatus {
+    Pending,    // Within the challenge window, passes unless challenged
+    Challenged, // Disputed by a bonded challenger and escalated to a token vote
+    Approved,   // Passed unchallenged or upheld by the token vote
+    Rejected,   // Overturned by the token vote
+}
+
+// Stores the state of an optimistic proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticGovernanceProposal {
+    pub status: OptimisticGovernanceProposalStatus, // Current lifecycle status
+    pub proposer: Address,                          // Address that submitted the proposal
+    pub description: String,                        // Human-readable proposal description
+    pub challenge_end: u64,                         // End of the challenge window
+    pub challenger: Option<Address>,                // Address that posted the challenge bond
+    pub bond: i128,                                 // Bond locked by the challenger
+    pub vote_end: u64,                              // End of the escalated vote, 0 if unchallenged
+    pub votes_for: i128,                            // Weight supporting the proposal
+    pub votes_against: i128,                        // Weight opposing the proposal
+}
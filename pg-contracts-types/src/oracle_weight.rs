@@ -0,0 +1,20 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+// Voting power attested for one user within a signed attestation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleWeightUpdate {
+    pub user: Address, // User the weight is attested for
+    pub weight: i128,  // Attested voting power, never negative
+}
+
+// Structured message an oracle signs off-chain; its XDR encoding is the signed payload
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleWeightAttestation {
+    pub contract: Address, // Adapter contract the attestation is bound to
+    pub oracle: Address,   // Whitelisted oracle that signed it
+    pub updates: Vec<OracleWeightUpdate>, // Weights attested in this batch
+    pub nonce: u64,        // Oracle's current nonce, consumed on success
+    pub issued_at: u64,    // UNIX timestamp the weights were measured at
+}
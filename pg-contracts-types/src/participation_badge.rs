@@ -0,0 +1,10 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+// Stores a single participation badge
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParticipationBadge {
+    pub governance: Address, // Vote contract the proposal belongs to
+    pub proposal_id: Symbol, // Proposal the owner voted on
+    pub minted_at: u64,      // UNIX timestamp the badge was minted at
+}
@@ -0,0 +1,44 @@
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+// Determines what happens to the tokens paid for votes once voting ends
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuadraticVoteCostPolicy {
+    Refund, // Voters reclaim their costs after voting ends
+    Burn,   // Costs are burned when the proposal is finalized
+}
+
+// Stores the pricing applied to new proposals
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QuadraticVoteConfig {
+    pub price: i128,                     // Token units charged per squared vote
+    pub policy: QuadraticVoteCostPolicy, // Fate of the paid tokens once voting ends
+}
+
+// Stores the state of a quadratic vote proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuadraticVoteProposal {
+    pub proposer: Address,               // Address that created the proposal
+    pub description: String,             // Human-readable proposal description
+    pub start_time: u64,                 // UNIX timestamp when voting begins
+    pub end_time: u64,                   // UNIX timestamp when voting ends
+    pub price: i128,                     // Price per squared vote, pinned at creation
+    pub policy: QuadraticVoteCostPolicy, // Cost policy, pinned at creation
+    pub total_for: i128,                 // Votes bought FOR
+    pub total_against: i128,             // Votes bought AGAINST
+    pub total_abstain: i128,             // Votes bought ABSTAIN
+    pub collected: i128,                 // Paid tokens still held, not yet refunded or burned
+    pub finalized: bool,                 // Whether the outcome has been settled
+}
+
+// Stores the votes a voter bought on a proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuadraticVotePurchase {
+    pub choice: Symbol, // FOR, AGAINST, or ABSTAIN
+    pub votes: i128,    // Votes bought so far
+    pub paid: i128,     // Tokens paid for them, price * votes^2
+    pub refunded: bool, // Whether the payment has been returned
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+// Identifies the governance model a listed deployment runs
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RegistryModel {
+    Gated,      // Token-Gated Vote Contract
+    Weighted,   // Token-Weighted Vote Contract
+    Optimistic, // Optimistic Governance Contract
+    Election,   // Election Contract
+    Membership, // Membership DAO Contract
+}
+
+// Stores the registry entry of a governance deployment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryEntry {
+    pub name: Symbol,           // Human-readable name the deployment is listed under
+    pub contract: Address,      // Address of the governance deployment
+    pub model: RegistryModel,   // Governance model the deployment runs
+    pub token: Option<Address>, // Token the deployment votes with, if it has one
+    pub created_at: u64,        // UNIX timestamp the entry was listed
+    pub listed: bool,           // Whether the entry is currently listed
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracttype;
+
+// Stores the points awarded for each kind of participation
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReputationRates {
+    pub vote_points: i128,            // Points awarded for every recorded vote
+    pub proposal_passed_points: i128, // Points awarded to the proposer of a passed proposal
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+// Stores a user's stake
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakedVoteStake {
+    pub amount: i128,      // Tokens staked in the contract
+    pub staked_at: u64,    // Amount-weighted UNIX timestamp the stake started accruing from
+    pub cooldown_end: u64, // UNIX timestamp the tokens can be claimed at, 0 while staked
+}
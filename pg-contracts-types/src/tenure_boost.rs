@@ -0,0 +1,17 @@
+use soroban_sdk::contracttype;
+
+// Mirrors a vote checkpoint of the checkpoint token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenureBoostCheckpoint {
+    pub timestamp: u64, // UNIX timestamp of the ledger that wrote the value
+    pub value: i128,    // Votes of the account from that timestamp
+}
+
+// Stores one point of the multiplier curve
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenureBoostPoint {
+    pub tenure: u64,      // Seconds the tokens have been held
+    pub multiplier: i128, // Multiplier at that tenure in basis points
+}
@@ -0,0 +1,42 @@
+#![cfg(test)]
+
+use crate::registry::{RegistryEntry, RegistryModel};
+use crate::token_gated_vote::TokenGatedVoteProposalStatus;
+use soroban_sdk::{symbol_short, testutils::Address as _, xdr::ScVal, Address, Env, TryFromVal};
+
+// Tests decoding a ledger entry value from its XDR form on a native target.
+// Expects: The struct, its nested enum, and its optional field round trip unchanged.
+#[test]
+fn test_struct_xdr_round_trip() {
+    let e = Env::default();
+    let entry = RegistryEntry {
+        name: symbol_short!("DAO"),
+        contract: Address::generate(&e),
+        model: RegistryModel::Weighted,
+        token: Some(Address::generate(&e)),
+        created_at: 1_000,
+        listed: true,
+    };
+
+    let value = ScVal::try_from(&entry).unwrap();
+    assert_eq!(RegistryEntry::try_from_val(&e, &value).unwrap(), entry);
+}
+
+// Tests decoding unit enum variants, as statuses appear in storage and event payloads.
+// Expects: Each status encodes as its variant name and decodes back to the same status.
+#[test]
+fn test_enum_xdr_round_trip() {
+    let e = Env::default();
+    for status in [
+        TokenGatedVoteProposalStatus::Draft,
+        TokenGatedVoteProposalStatus::Succeeded,
+        TokenGatedVoteProposalStatus::Expired,
+    ] {
+        let value = ScVal::try_from(&status).unwrap();
+        assert!(matches!(value, ScVal::Vec(Some(_))));
+        assert_eq!(
+            TokenGatedVoteProposalStatus::try_from_val(&e, &value).unwrap(),
+            status
+        );
+    }
+}
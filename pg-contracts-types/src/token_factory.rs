@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address, String};
+
+// Stores the registry record of a deployed governance token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFactoryToken {
+    pub name: String,     // Human-readable token name
+    pub symbol: String,   // Token ticker symbol
+    pub decimals: u32,    // Number of decimal places
+    pub supply: i128,     // Initial supply minted to the token admin
+    pub admin: Address,   // Administrator of the deployed token
+    pub deployed_at: u64, // UNIX timestamp of the deployment
+}
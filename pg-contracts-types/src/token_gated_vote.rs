@@ -0,0 +1,488 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Val, Vec};
+
+// Stores the detailed information for a single proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenGatedVoteProposalData {
+    pub description: String,                  // Proposal description
+    pub category: Symbol,                     // Category whose rules the proposal follows
+    pub proposer: Address,                    // Address that created the proposal
+    pub proposer_power: i128,                 // Non-admin proposer's voting power (0 for admin)
+    pub start_time: u64,                      // UNIX timestamp when voting begins
+    pub end_time: u64,                        // UNIX timestamp when voting ends
+    pub start_ledger: u32,                    // Ledger sequence when voting begins
+    pub end_ledger: u32,                      // Ledger sequence when voting ends
+    pub timing: TokenGatedVoteTimingMode,     // Which of the two windows bounds voting
+    pub quorum: i128,                         // Minimum turnout required for a valid outcome
+    pub min_voters: u32,                      // Minimum unique voters required for a valid outcome
+    pub threshold: u32,                       // FOR share of FOR + AGAINST to exceed, in bps
+    pub status: TokenGatedVoteProposalStatus, // Stored lifecycle status
+    pub finalized_at: u64,                    // UNIX timestamp when finalized (0 if not yet)
+    pub queued_at: u64,                       // UNIX timestamp when queued (0 if never queued)
+    pub total_for: i128,                      // Total voting power cast FOR
+    pub total_against: i128,                  // Total voting power cast AGAINST
+    pub total_abstain: i128,                  // Total voting power cast ABSTAIN
+    pub voter_count: u32,                     // Addresses whose vote was accepted
+    pub burned: i128,                         // Governance tokens burned by votes on it
+    pub content_hash: Option<BytesN<32>>,     // SHA-256 of a full body kept off-chain, if any
+}
+
+// Stores how a user voted on a single proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteRecord {
+    pub choice: Symbol, // Chosen option (FOR, AGAINST, or ABSTAIN)
+    pub weight: i128,   // Weight counted toward the tally
+}
+
+// Represents one entry of a user's participation history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteHistoryEntry {
+    pub id: Symbol,                           // Proposal the user voted on
+    pub choice: Symbol,                       // Chosen option
+    pub weight: i128,                         // Weight counted toward the tally
+    pub status: TokenGatedVoteProposalStatus, // Current status of the proposal
+    pub passed: bool,                         // Whether the proposal ultimately passed
+}
+
+// Represents a summary of a governance proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenGatedVoteProposalSummary {
+    pub id: Symbol,                           // Unique identifier for the proposal
+    pub description: String,                  // Human-readable proposal description
+    pub status: TokenGatedVoteProposalStatus, // Lifecycle status of the proposal
+    pub voter_count: u32,                     // Addresses whose vote was accepted
+}
+
+// Represents the matches found in one bounded window of a status-filtered proposal scan
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenGatedVoteProposalPage {
+    pub proposals: Vec<TokenGatedVoteProposalSummary>, // Matching proposals in creation order
+    pub next_cursor: Option<u32>, // Position the next window resumes from (None when done)
+}
+
+// Represents the stored lifecycle status of a proposal
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteProposalStatus {
+    Draft,          // Created by a non-admin proposer, awaiting sponsorship
+    Pending,        // Created, voting has not started
+    Active,         // Voting window is open
+    Succeeded,      // Finalized with quorum met and more FOR than AGAINST
+    Defeated,       // Finalized with quorum met and FOR not exceeding AGAINST
+    QuorumFailed,   // Finalized without reaching the required turnout
+    Cancelled,      // Cancelled by the admin before finalization
+    Vetoed,         // Vetoed after succeeding or during the grace period after voting
+    Queued,         // Succeeded and queued for execution
+    ReadyToExecute, // Queued and co-approved by the council, awaiting execution
+    Executed,       // Executed within the execution window
+    Expired,        // Not executed before the execution window closed
+}
+
+// Restricts who may create proposals in a category
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteProposerRule {
+    Admin,   // Only the admin may propose
+    Holders, // Holders may propose under the general threshold, fee, and sponsorship rules
+}
+
+// Rules applied to proposals created in a category
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteCategoryRules {
+    pub min_duration: u64,                     // Shortest voting window in seconds
+    pub max_duration: u64,                     // Longest voting window in seconds
+    pub quorum: i128,                          // Minimum turnout for a valid outcome
+    pub min_voters: u32,                       // Minimum unique voters for a valid outcome
+    pub threshold: u32,                        // FOR share of FOR + AGAINST to exceed, in bps
+    pub proposers: TokenGatedVoteProposerRule, // Who may create proposals in the category
+}
+
+// Identifies an asset priced by the oracle, as defined by SEP-40
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenGatedVotePriceAsset {
+    Stellar(Address), // Asset issued on Stellar, by its contract address
+    Other(Symbol),    // Off-chain asset, by its ticker
+}
+
+// Price reported by the oracle, as defined by SEP-40
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVotePriceData {
+    pub price: i128,    // Price in the oracle's decimals
+    pub timestamp: u64, // UNIX timestamp the price was recorded at
+}
+
+// Direction a price is compared against the condition's bound
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVotePriceComparison {
+    AtLeast, // The price must be greater than or equal to the bound
+    AtMost,  // The price must be less than or equal to the bound
+}
+
+// Describes a price condition that must hold when a treasury proposal is executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteExecutionCondition {
+    pub oracle: Address,                           // SEP-40 price oracle contract
+    pub asset: TokenGatedVotePriceAsset,           // Asset whose price is checked
+    pub comparison: TokenGatedVotePriceComparison, // How the price compares against the bound
+    pub price: i128,                               // Bound in the oracle's decimals
+    pub max_age: u64,                              // Seconds an oracle price stays usable
+}
+
+// Consolidates every governance tunable into a single versioned configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteGovernanceConfig {
+    pub version: u32,                          // Incremented on every update
+    pub min_duration: u64,                     // Shortest voting window in seconds
+    pub max_duration: u64,                     // Longest voting window in seconds
+    pub quorum: i128,                          // Minimum turnout applied to new proposals
+    pub threshold: u32,                        // FOR share of FOR + AGAINST to exceed, in bps
+    pub dispute_bond: i128,                    // Bond required to dispute a result (0 disables)
+    pub proposal_fee: i128,                    // Fee charged to non-admin proposers (0 disables)
+    pub fee_sink: TokenGatedVoteFeeSink,       // Destination of collected proposal fees
+    pub min_balance: i128,                     // Minimum balance to vote, normalized to 7 decimals
+    pub proposal_threshold: i128,              // Minimum voting power of non-admin proposers
+    pub open_proposing: bool,                  // Whether non-admin addresses can create proposals
+    pub sponsors_required: u32,                // Endorsements a non-admin draft needs (0 disables)
+    pub spending_cap: i128,                    // Treasury payouts allowed per period (0 disables)
+    pub spending_period: u64,                  // Rolling window of the spending cap in seconds
+    pub vote_lock: bool,                       // Whether direct votes escrow the voter's tokens
+    pub vote_burn: i128,                       // Tokens burned per direct vote (0 disables)
+    pub sequential_ids: bool,                  // Whether new proposals must use sequential IDs
+    pub max_description_length: u32,           // Longest description stored on-chain, in bytes
+    pub timing_mode: TokenGatedVoteTimingMode, // Clock bounding the windows of new proposals
+    pub grace_period: u64,                     // Seconds after end_time before finalization
+    pub proposer_cooldown: u64,                // Wait between proposals in seconds (0 disables)
+    pub proposals_per_period: u32,             // Proposals per proposer per period (0 disables)
+    pub proposal_period: u64,                  // Rolling window of the proposer cap in seconds
+    pub max_active_proposals: u32,             // Pending and Active proposals allowed (0 disables)
+    pub paused: bool,                          // Whether proposal creation and voting are paused
+}
+
+// Sets a single governance tunable when a config change proposal is executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteConfigChange {
+    MinDuration(u64),                     // Shortest voting window in seconds
+    MaxDuration(u64),                     // Longest voting window in seconds
+    Quorum(i128),                         // Minimum turnout applied to new proposals
+    Threshold(u32),                       // FOR share of FOR + AGAINST to exceed, in bps
+    DisputeBond(i128),                    // Bond required to dispute a result (0 disables)
+    ProposalFee(i128),                    // Fee charged to non-admin proposers (0 disables)
+    FeeSink(TokenGatedVoteFeeSink),       // Destination of collected proposal fees
+    MinBalance(i128),                     // Minimum balance to vote, normalized to 7 decimals
+    ProposalThreshold(i128),              // Minimum voting power of non-admin proposers
+    OpenProposing(bool),                  // Whether non-admin addresses can create proposals
+    SponsorsRequired(u32),                // Endorsements a non-admin draft needs (0 disables)
+    SpendingCap(i128),                    // Treasury payouts allowed per period (0 disables)
+    SpendingPeriod(u64),                  // Rolling window of the spending cap in seconds
+    VoteLock(bool),                       // Whether direct votes escrow the voter's tokens
+    VoteBurn(i128),                       // Tokens burned per direct vote (0 disables)
+    SequentialIds(bool),                  // Whether new proposals must use sequential IDs
+    MaxDescriptionLength(u32),            // Longest description stored on-chain, in bytes
+    TimingMode(TokenGatedVoteTimingMode), // Clock bounding the windows of new proposals
+    GracePeriod(u64),                     // Seconds after end_time before finalization
+    ProposerCooldown(u64),                // Wait between proposals in seconds (0 disables)
+    ProposalsPerPeriod(u32),              // Proposals per proposer per period (0 disables)
+    ProposalPeriod(u64),                  // Rolling window of the proposer cap in seconds
+    MaxActiveProposals(u32),              // Pending and Active proposals allowed (0 disables)
+}
+
+// Payload of CONFIG/UPDATED for the governance configuration
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenGatedVoteConfigUpdate {
+    pub version: u32,                     // Configuration version after the update
+    pub changes: Vec<(Symbol, Val, Val)>, // (field, old, new) for every changed field
+}
+
+// Stores the cadence of recurring election cycles
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteCycleConfig {
+    pub period: u64,   // Seconds between the start of consecutive cycles
+    pub duration: u64, // Voting duration of each cycle's election proposal
+    pub seats: u32,    // Number of seats filled by each election
+}
+
+// Stores the most recently started election cycle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteElectionCycle {
+    pub number: u32,     // Sequential cycle number, starting at 1
+    pub id: Symbol,      // Election proposal ID (CYCLE<number>)
+    pub seats: u32,      // Seats filled by this election
+    pub start_time: u64, // UNIX timestamp when voting begins
+    pub end_time: u64,   // UNIX timestamp when voting ends
+}
+
+// Tracks a bonded dispute raised against a finalized proposal result
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteDispute {
+    pub challenger: Address, // Address that posted the dispute bond
+    pub bond: i128,          // Bond locked until the dispute is resolved
+    pub opened_at: u64,      // UNIX timestamp when the dispute was raised
+    pub resolved: bool,      // Whether the admin has ruled on the dispute
+    pub upheld: bool,        // Whether the dispute was upheld (bond released)
+}
+
+// Selects where proposal fees are sent
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteFeeSink {
+    Burn,          // Fee is burned from the proposer's balance
+    Treasury,      // Fee is added to the pooled treasury balance
+    CommunityPool, // Fee is added to the community pool spent by proposal
+}
+
+// Stores the fee charged to non-admin proposers
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteProposalFee {
+    pub amount: i128,                // Governance tokens charged per proposal
+    pub sink: TokenGatedVoteFeeSink, // Destination of collected fees
+}
+
+// Holds the guardian allowed to veto and pause until its powers sunset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteGuardian {
+    pub guardian: Address, // Address holding the emergency veto and pause powers
+    pub expires_at: u64,   // UNIX timestamp when the powers lapse
+}
+
+// Describes the guardian appointment made when a guardian proposal is executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteGuardianRenewal {
+    pub guardian: Address, // Address appointed or renewed as guardian
+    pub term: u64,         // Seconds the powers last from execution
+}
+
+// Defines what a parent may decide on a child's passed proposals
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteChildScope {
+    pub requires_ratification: bool, // Child proposals execute only once ratified
+    pub can_override: bool,          // Whether the parent may override passed child proposals
+    pub review_period: u64,          // Seconds after a child proposal ends the parent may act
+}
+
+// Summarizes a registered child governance contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteChild {
+    pub child: Address,                  // Child governance contract
+    pub scope: TokenGatedVoteChildScope, // Decisions the parent may take on the child
+    pub registered_at: u64,              // UNIX timestamp the child was registered or rescoped
+    pub ratified: u32,                   // Child proposals ratified by the parent
+    pub overridden: u32,                 // Child proposals overridden by the parent
+}
+
+// Reports the parent's position on a child proposal
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteRatification {
+    NotRequired, // The parent has no say, or its scope does not require ratification
+    Pending,     // Ratification is required and has not been given yet
+    Ratified,    // The parent ratified the proposal
+    Overridden,  // The parent overrode the proposal, which must not execute
+}
+
+// Participation counters of a single address
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenGatedVoteParticipation {
+    pub votes_cast: u32,        // Votes accepted from the address
+    pub proposals_created: u32, // Proposals the address created
+    pub proposals_passed: u32,  // Proposals of the address that finalized as Succeeded
+}
+
+// Ranked participant on the leaderboard
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteLeaderboardEntry {
+    pub user: Address,                              // Participating address
+    pub score: u32,                                 // Sum of the participation counters
+    pub participation: TokenGatedVoteParticipation, // Counters the score is derived from
+}
+
+// Participation counters of an address with the achievements they unlocked
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteAchievements {
+    pub participation: TokenGatedVoteParticipation, // Counters the achievements follow from
+    pub score: u32,                                 // Sum of the participation counters
+    pub unlocked: Vec<Symbol>,                      // Achievements unlocked, in table order
+}
+
+// State a proposal held before an amendment, with who amended it and when
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteRevision {
+    pub description_hash: BytesN<32>, // SHA-256 of the previous description's UTF-8 bytes
+    pub start_time: u64,              // Voting start before the amendment
+    pub end_time: u64,                // Voting end before the amendment
+    pub editor: Address,              // Proposer or admin that made the amendment
+    pub amended_at: u64,              // UNIX timestamp of the amendment
+}
+
+// Council whose co-approval passed proposals need before execution in dual-approval mode
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteCouncil {
+    pub council: Address,     // Multisig account or contract co-approving proposals
+    pub approval_window: u64, // Seconds after voting ends for the council to co-approve
+}
+
+// Off-chain discussion thread anchored to a proposal, with the hash of its content
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteDiscussion {
+    pub discussion_hash: BytesN<32>, // SHA-256 of the thread content as last anchored
+    pub discussion_url: String,      // Location of the forum thread
+    pub updated_at: u64,             // UNIX timestamp the anchor was last set
+}
+
+// Member of a bundle, created with the bundle's shared voting window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteBundleItem {
+    pub id: Symbol,          // Proposal ID of the member
+    pub description: String, // Description of the member proposal
+}
+
+// Proposals that pass or fail together, such as the line items of a budget package
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteBundle {
+    pub proposals: Vec<Symbol>, // Member proposal IDs, in creation order
+    pub created_at: u64,        // UNIX timestamp when the bundle was created
+    pub finalized: bool,        // Whether the members have been finalized together
+    pub passed: bool,           // Whether every member passed, so members may execute
+}
+
+// Describes the payout executed when a community pool spend proposal is executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVotePoolSpend {
+    pub recipient: Address, // Address receiving the pool payout
+    pub amount: i128,       // Governance tokens paid out of the pool on execution
+}
+
+// Derives the quorum of new proposals from recent participation instead of a fixed number
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteDynamicQuorum {
+    pub window: u32, // Most recent finalized proposals averaged, 1 to MAX_TURNOUT_WINDOW
+    pub bps: u32,    // Share of the average turnout required, in basis points
+}
+
+// Summarizes how recently and how often a proposer has created proposals
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenGatedVoteProposerActivity {
+    pub last_proposed_at: u64,    // UNIX timestamp of the latest proposal
+    pub proposals_in_period: u32, // Proposals created within the rolling cap period
+}
+
+// Tracks the participation reward pool funded for a single proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenGatedVoteRewardPool {
+    pub total: i128,      // Total amount funded by the admin
+    pub claimed: i128,    // Amount already paid out to voters
+    pub claim_count: u32, // Number of voters that have claimed
+    pub swept: i128,      // Unclaimed amount returned after the claim window
+}
+
+// Structured message a voter signs off-chain; its XDR encoding is the signed payload
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteSignedPayload {
+    pub contract: Address, // Vote contract the signature is bound to
+    pub user: Address,     // Voter the ballot is cast for
+    pub id: Symbol,        // Proposal being voted on
+    pub choice: Symbol,    // Chosen option (FOR, AGAINST, or ABSTAIN)
+    pub nonce: u64,        // Voter's current nonce, consumed on success
+    pub expiration: u64,   // UNIX timestamp after which the signature is rejected
+}
+
+// A vote signed off-chain, as submitted by a relayer in a batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteSignedVote {
+    pub user: Address,         // Voter that signed the ballot
+    pub id: Symbol,            // Proposal being voted on
+    pub choice: Symbol,        // Chosen option (FOR, AGAINST, or ABSTAIN)
+    pub expiration: u64,       // Expiration committed to in the signed payload
+    pub signature: BytesN<64>, // ed25519 signature over the payload
+}
+
+// Outcome of one signed vote within a relayed batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteSignedVoteResult {
+    pub user: Address,  // Voter of the batch item
+    pub id: Symbol,     // Proposal of the batch item
+    pub accepted: bool, // Whether the vote was tallied
+    pub error: u32,     // Contract error code when rejected, 0 when accepted
+}
+
+// Aggregate counters across every proposal in the contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteGovernanceStats {
+    pub total_proposals: u32,  // Number of proposals ever created
+    pub total_votes: u64,      // Number of votes ever accepted
+    pub unique_voters: u32,    // Number of distinct addresses that have voted
+    pub total_burned: i128,    // Governance tokens burned by votes across every proposal
+    pub active_proposals: u32, // Pending or Active proposals not yet finalized
+}
+
+// Participation figures for a single proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteProposalStats {
+    pub votes_cast: u32,      // Number of votes accepted on the proposal
+    pub turnout_bps: u32,     // votes_cast relative to unique_voters, in basis points
+    pub quorum_reached: bool, // Whether votes_cast meets the proposal quorum
+    pub burned: i128,         // Governance tokens burned by votes on the proposal
+}
+
+// Live progress of a proposal's participation toward its quorum
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteQuorumProgress {
+    pub participation: i128, // Votes counted so far across FOR, AGAINST, and ABSTAIN
+    pub quorum: i128,        // Turnout the proposal needs for a valid outcome
+    pub percent_bps: u32,    // participation relative to quorum in bps, capped at 10,000
+}
+
+// Selects which clock bounds the voting windows of new proposals
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteTimingMode {
+    Timestamp,      // Voting follows start_time and end_time
+    LedgerSequence, // Voting follows start_ledger and end_ledger, immune to timestamp drift
+    Both,           // Voting opens once both windows open and closes once either ends
+}
+
+// Describes the payout executed when a treasury proposal is executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGatedVoteTreasuryAction {
+    pub recipient: Address, // Address receiving the treasury payout
+    pub amount: i128,       // Governance tokens paid out on execution
+    pub duration: u64,      // Seconds the payout streams over after execution (0 pays at once)
+}
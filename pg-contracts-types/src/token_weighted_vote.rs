@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
+
+// Stores the detailed information for a single proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenWeightedVoteProposalData {
+    pub eligibility_root: Option<BytesN<32>>, // Merkle root of eligible voters and weights
+    pub proposer: Address,                    // Address that created the proposal
+    pub description: String,                  // Proposal description
+    pub start_time: u64,                      // UNIX timestamp when voting begins
+    pub end_time: u64,                        // UNIX timestamp when voting ends
+    pub strategy: Address,                    // Weighting strategy in effect when created
+    pub decimals: u32,                        // Decimal places of the strategy's weights
+    pub quorum: i128,                         // Minimum turnout, resolved at creation
+    pub total_for: i128,                      // Total voting power cast FOR
+    pub total_against: i128,                  // Total voting power cast AGAINST
+    pub total_abstain: i128,                  // Total voting power cast ABSTAIN
+    pub finalized_at: u64,                    // UNIX timestamp when finalized (0 if not yet)
+}
+
+// Quorum rule resolved into an absolute turnout whenever a proposal is created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenWeightedVoteQuorum {
+    pub absolute: i128,          // Fixed minimum turnout in normalized weight
+    pub bps: u32,                // Share of the supply token's total supply, 0 disables it
+    pub supply: Option<Address>, // Token exposing total_supply, required when bps is set
+}
+
+// Represents a summary of a governance proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenWeightedVoteProposalSummary {
+    pub id: Symbol,                              // Unique identifier for the proposal
+    pub description: String,                     // Human-readable proposal description
+    pub status: TokenWeightedVoteProposalStatus, // Lifecycle status of the proposal
+}
+
+// Represents lifecycle status of a proposal relative to the current ledger timestamp
+#[contracttype]
+#[derive(Clone, Copy)]
+pub enum TokenWeightedVoteProposalStatus {
+    Pending, // Current time is before start_time
+    Active,  // Current time is within [start_time, end_time]
+    Ended,   // Current time is after end_time
+}
+
+// Stores one outbound allocation of a holder's voting power
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenWeightedVoteDelegation {
+    pub delegatee: Address,      // Address voting with the delegated share
+    pub bps: u32,                // Share of the holder's power, in basis points
+    pub expires_at: Option<u64>, // UNIX timestamp after which the share reverts, None if open-ended
+}
+
+// Payload of DELEGATION/SET and DELEGATION/REVOKED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenWeightedVoteDelegationEvent {
+    pub delegator: Address,      // Holder whose power is delegated
+    pub delegatee: Address,      // Address voting with the delegated share
+    pub bps: u32,                // Share of the holder's power, in basis points
+    pub expires_at: Option<u64>, // UNIX timestamp after which the share reverts, None if open-ended
+}
+
+// Records the share of a holder's power a delegatee cast on a proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenWeightedVoteContribution {
+    pub delegatee: Address, // Delegatee that cast the share
+    pub choice: Symbol,     // Choice the share was counted towards
+    pub bps: u32,           // Share of the holder's power, in basis points
+    pub weight: i128,       // Weight added to the tally for this share
+}
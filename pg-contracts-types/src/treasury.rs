@@ -0,0 +1,56 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+// Tracks the pooled treasury funds and the shares issued against them
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TreasuryTotals {
+    pub balance: i128,      // Governance tokens held for the treasury
+    pub total_shares: i128, // Shares outstanding across all members
+}
+
+// Bounds the payouts the governor can make across a rolling period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasurySpendingLimit {
+    pub cap: i128,   // Payouts allowed per period (0 disables the cap)
+    pub period: u64, // Seconds in the rolling spending window
+}
+
+// Tracks a grant the treasury escrowed for linear vesting to its recipient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryStream {
+    pub recipient: Address,        // Address entitled to the vested payout
+    pub total: i128,               // Governance tokens reserved for the grant
+    pub withdrawn: i128,           // Governance tokens already pulled by the recipient
+    pub start_time: u64,           // UNIX timestamp when accrual began
+    pub duration: u64,             // Seconds over which the total vests linearly
+    pub cancelled_at: Option<u64>, // UNIX timestamp when the governor stopped accrual
+}
+
+// Identifies what paid tokens into the community pool
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreasuryPoolSource {
+    ProposalFee, // Proposal fee collected under the community pool fee sink
+    SlashedBond, // Dispute bond slashed when the dispute is rejected
+}
+
+// Tracks the community pool funded by governance fees, separate from the share-backed balance
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TreasuryCommunityPool {
+    pub balance: i128,        // Governance tokens available to community pool spends
+    pub total_received: i128, // Governance tokens paid into the pool over its lifetime
+    pub total_spent: i128,    // Governance tokens paid out by executed pool spends
+}
+
+// Records a single payment into the community pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryPoolInflow {
+    pub source: TreasuryPoolSource, // What paid into the pool
+    pub id: Symbol,                 // Proposal the fee or bond belonged to
+    pub amount: i128,               // Governance tokens paid in
+    pub received_at: u64,           // UNIX timestamp of the payment
+}
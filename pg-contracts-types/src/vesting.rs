@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+// Stores a beneficiary's linear vesting schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub total: i128,    // Tokens allocated to the beneficiary
+    pub released: i128, // Tokens already released to the beneficiary
+    pub start: u64,     // UNIX timestamp when vesting starts
+    pub cliff: u64,     // Seconds after start before any tokens vest
+    pub duration: u64,  // Seconds after start until the allocation is fully vested
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracttype;
+
+// Stores a spending allowance and the ledger it expires on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WrappedGovTokenAllowance {
+    pub amount: i128,           // Tokens the spender may still move
+    pub expiration_ledger: u32, // Last ledger on which the allowance is valid
+}
+
+// Records a vote or supply value from a ledger timestamp onwards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WrappedGovTokenCheckpoint {
+    pub timestamp: u64, // UNIX timestamp of the ledger that wrote the value
+    pub value: i128,    // Votes of the account, or total supply, from that timestamp
+}
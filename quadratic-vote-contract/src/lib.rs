@@ -0,0 +1,479 @@
+#![no_std]
+
+use governance_core::{
+    events, GovernanceStatus, InvalidChoice, ProposalTimeError, Tally, TallyOutcome, TallyStrategy,
+    VotingWindow, SIMPLE_MAJORITY,
+};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, Address, Env, String, Symbol,
+};
+
+pub use pg_contracts_types::quadratic_vote::{
+    QuadraticVoteConfig, QuadraticVoteCostPolicy, QuadraticVoteProposal, QuadraticVotePurchase,
+};
+
+// --- Proposal Duration Constraints (in seconds) ---
+const MAX_PROPOSAL_DURATION: u64 = 1292000; // ~15 days
+const MIN_PROPOSAL_DURATION: u64 = 432000; // ~5 days
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const PROPOSALS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
+const REFUND_TTL_BUFFER: u32 = 2_592_000; // ~30 days past end_time to claim refunds
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 0] = [];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum QuadraticVoteContractDataKey {
+    Admin,                     // Contract administrator address
+    Token,                     // Token votes are paid in
+    Config,                    // Vote price and cost policy applied to new proposals
+    Proposal(Symbol),          // Individual proposal data, keyed by its ID
+    Purchase(Address, Symbol), // Votes a voter bought on a proposal and their cost
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1900-1999 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuadraticVoteContractErrors {
+    ContractNotInitialized = 1,      // The contract has not been initialized
+    ContractAlreadyInitialized = 2,  // The contract has already been initialized
+    ProposalAlreadyExists = 3,       // Proposal with this ID already exists
+    ProposalNotFound = 4,            // Proposal with this ID does not exist
+    VotingNotActive = 7,             // Voting is not open at the current timestamp
+    InvalidChoice = 8,               // Vote choice must be FOR, AGAINST, or ABSTAIN
+    StartTimeAfterEnd = 9,           // Proposal start time is not before its end time
+    StartTimeInPast = 10,            // Proposal start time is before current timestamp
+    DurationTooLong = 11,            // Proposal duration exceeds maximum allowed period
+    DurationTooShort = 12,           // Proposal duration is below minimum required period
+    InvalidAmount = 13,              // Votes bought must be greater than zero
+    VotingNotEnded = 14,             // Voting period has not ended yet
+    InvalidConfig = 15,              // Vote price must be greater than zero
+    ChoiceLocked = 1901,             // Further votes must go to the choice already bought
+    CostOverflow = 1902,             // The cost of the votes exceeds the token amount range
+    ProposalAlreadyFinalized = 1903, // The proposal outcome has already been settled
+    NotRefundable = 1904,            // The proposal burns vote costs instead of refunding them
+    NothingToRefund = 1905,          // The voter bought no votes or was already refunded
+}
+
+// Maps shared proposal time failures onto this contract's error codes
+impl From<ProposalTimeError> for QuadraticVoteContractErrors {
+    fn from(error: ProposalTimeError) -> Self {
+        match error {
+            ProposalTimeError::StartTimeAfterEnd => Self::StartTimeAfterEnd,
+            ProposalTimeError::StartTimeInPast => Self::StartTimeInPast,
+            ProposalTimeError::DurationTooLong => Self::DurationTooLong,
+            ProposalTimeError::DurationTooShort => Self::DurationTooShort,
+        }
+    }
+}
+
+// Maps an unrecognized vote choice onto this contract's error code
+impl From<InvalidChoice> for QuadraticVoteContractErrors {
+    fn from(_: InvalidChoice) -> Self {
+        Self::InvalidChoice
+    }
+}
+
+// Returns the tokens N votes cost at a price, price * N^2, or None if it overflows
+pub fn vote_cost(price: i128, votes: i128) -> Option<i128> {
+    votes.checked_mul(votes)?.checked_mul(price)
+}
+
+#[contract]
+pub struct QuadraticVoteContract;
+
+#[contractimpl]
+impl QuadraticVoteContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, QuadraticVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&QuadraticVoteContractDataKey::Admin)
+            .ok_or(QuadraticVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the payment token client from instance storage
+    fn read_token<'a>(env: &Env) -> Result<TokenClient<'a>, QuadraticVoteContractErrors> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&QuadraticVoteContractDataKey::Token)
+            .ok_or(QuadraticVoteContractErrors::ContractNotInitialized)?;
+        Ok(TokenClient::new(env, &token))
+    }
+
+    // Validates that the vote price is greater than zero
+    fn validate_config(config: &QuadraticVoteConfig) -> Result<(), QuadraticVoteContractErrors> {
+        if config.price <= 0 {
+            return Err(QuadraticVoteContractErrors::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    // Derives TTL extension keeping proposal and purchase data claimable after voting ends
+    fn calculate_ttl(env: &Env, proposal_end_time: u64) -> u32 {
+        governance_core::proposal_ttl(
+            env.ledger().timestamp(),
+            proposal_end_time,
+            REFUND_TTL_BUFFER,
+            PROPOSALS_TTL_EXTENSION,
+        )
+    }
+
+    // Loads a proposal from persistent storage
+    fn read_proposal(
+        env: &Env,
+        id: &Symbol,
+    ) -> Result<QuadraticVoteProposal, QuadraticVoteContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&QuadraticVoteContractDataKey::Proposal(id.clone()))
+            .ok_or(QuadraticVoteContractErrors::ProposalNotFound)
+    }
+
+    // Saves a proposal and extends its TTL
+    fn write_proposal(env: &Env, id: &Symbol, proposal: &QuadraticVoteProposal) {
+        let proposal_key = QuadraticVoteContractDataKey::Proposal(id.clone());
+        let ttl = Self::calculate_ttl(env, proposal.end_time);
+        env.storage().persistent().set(&proposal_key, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&proposal_key, ttl, ttl);
+    }
+
+    // Saves a purchase for as long as the proposal it was made on
+    fn write_purchase(
+        env: &Env,
+        voter: &Address,
+        id: &Symbol,
+        purchase: &QuadraticVotePurchase,
+        end_time: u64,
+    ) {
+        let purchase_key = QuadraticVoteContractDataKey::Purchase(voter.clone(), id.clone());
+        let ttl = Self::calculate_ttl(env, end_time);
+        env.storage().persistent().set(&purchase_key, purchase);
+        env.storage()
+            .persistent()
+            .extend_ttl(&purchase_key, ttl, ttl);
+    }
+
+    // Builds the vote tally of a proposal
+    fn tally(proposal: &QuadraticVoteProposal) -> Tally {
+        Tally {
+            total_for: proposal.total_for,
+            total_against: proposal.total_against,
+            total_abstain: proposal.total_abstain,
+        }
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, payment token, vote price, and cost policy
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+        price: i128,
+        policy: QuadraticVoteCostPolicy,
+    ) -> Result<(), QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&QuadraticVoteContractDataKey::Admin)
+        {
+            return Err(QuadraticVoteContractErrors::ContractAlreadyInitialized);
+        }
+        let config = QuadraticVoteConfig { price, policy };
+        Self::validate_config(&config)?;
+
+        env.storage()
+            .instance()
+            .set(&QuadraticVoteContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&QuadraticVoteContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&QuadraticVoteContractDataKey::Config, &config);
+        Ok(())
+    }
+
+    // Creates a proposal pinning the current price and cost policy (admin only, 5-15 days)
+    pub fn create_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if env
+            .storage()
+            .persistent()
+            .has(&QuadraticVoteContractDataKey::Proposal(id.clone()))
+        {
+            return Err(QuadraticVoteContractErrors::ProposalAlreadyExists);
+        }
+        governance_core::validate_proposal_times(
+            env.ledger().timestamp(),
+            start_time,
+            end_time,
+            MIN_PROPOSAL_DURATION,
+            MAX_PROPOSAL_DURATION,
+        )?;
+        let config = Self::get_config(env.clone())?;
+
+        let proposal = QuadraticVoteProposal {
+            proposer: admin.clone(),
+            description,
+            start_time,
+            end_time,
+            price: config.price,
+            policy: config.policy,
+            total_for: 0,
+            total_against: 0,
+            total_abstain: 0,
+            collected: 0,
+            finalized: false,
+        };
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::proposal_created(&env, &id, &admin, start_time, end_time);
+        Ok(())
+    }
+
+    // Buys votes for a choice on an active proposal. A voter holding N votes pays the
+    // difference between price * (N + votes)^2 and what it already paid, so every extra vote
+    // costs more than the last; all votes of a voter go to one choice
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        id: Symbol,
+        choice: Symbol,
+        votes: i128,
+    ) -> Result<(), QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        voter.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        let window = governance_core::voting_window(
+            env.ledger().timestamp(),
+            proposal.start_time,
+            proposal.end_time,
+        );
+        if window != VotingWindow::Active {
+            return Err(QuadraticVoteContractErrors::VotingNotActive);
+        }
+        if votes <= 0 {
+            return Err(QuadraticVoteContractErrors::InvalidAmount);
+        }
+
+        let mut purchase = Self::get_purchase(env.clone(), voter.clone(), id.clone()).unwrap_or(
+            QuadraticVotePurchase {
+                choice: choice.clone(),
+                votes: 0,
+                paid: 0,
+                refunded: false,
+            },
+        );
+        if purchase.choice != choice {
+            return Err(QuadraticVoteContractErrors::ChoiceLocked);
+        }
+
+        let mut tally = Self::tally(&proposal);
+        tally.record(&choice, votes)?;
+
+        let total_votes = purchase
+            .votes
+            .checked_add(votes)
+            .ok_or(QuadraticVoteContractErrors::CostOverflow)?;
+        let total_cost = vote_cost(proposal.price, total_votes)
+            .ok_or(QuadraticVoteContractErrors::CostOverflow)?;
+        let cost = total_cost - purchase.paid;
+        Self::read_token(&env)?.transfer(&voter, &env.current_contract_address(), &cost);
+
+        purchase.votes = total_votes;
+        purchase.paid = total_cost;
+        Self::write_purchase(&env, &voter, &id, &purchase, proposal.end_time);
+
+        proposal.total_for = tally.total_for;
+        proposal.total_against = tally.total_against;
+        proposal.total_abstain = tally.total_abstain;
+        proposal.collected = proposal.collected.saturating_add(cost);
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::vote_cast(&env, &id, &voter, &choice, votes);
+        events::publish(&env, "COST", "PAID", id, (voter, cost));
+        Ok(())
+    }
+
+    // Settles an ended proposal: resolves the outcome by simple majority of votes and, under
+    // the Burn policy, burns every paid token it holds
+    pub fn finalize(env: Env, id: Symbol) -> Result<GovernanceStatus, QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if env.ledger().timestamp() <= proposal.end_time {
+            return Err(QuadraticVoteContractErrors::VotingNotEnded);
+        }
+        if proposal.finalized {
+            return Err(QuadraticVoteContractErrors::ProposalAlreadyFinalized);
+        }
+
+        let tally = Self::tally(&proposal);
+        let status = match tally.resolve(&env, 0, TallyStrategy::SimpleMajority) {
+            TallyOutcome::Succeeded => GovernanceStatus::Succeeded,
+            _ => GovernanceStatus::Defeated,
+        };
+        if proposal.policy == QuadraticVoteCostPolicy::Burn && proposal.collected > 0 {
+            Self::read_token(&env)?.burn(&env.current_contract_address(), &proposal.collected);
+            events::publish(&env, "COST", "BURNED", id.clone(), proposal.collected);
+            proposal.collected = 0;
+        }
+        proposal.finalized = true;
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::proposal_finalized(&env, &id, status, &tally, 0, SIMPLE_MAJORITY);
+        Ok(status)
+    }
+
+    // Returns a voter's payment once voting has ended on a Refund proposal (permissionless,
+    // the tokens always go back to the voter)
+    pub fn refund(
+        env: Env,
+        voter: Address,
+        id: Symbol,
+    ) -> Result<i128, QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if env.ledger().timestamp() <= proposal.end_time {
+            return Err(QuadraticVoteContractErrors::VotingNotEnded);
+        }
+        if proposal.policy != QuadraticVoteCostPolicy::Refund {
+            return Err(QuadraticVoteContractErrors::NotRefundable);
+        }
+        let mut purchase = Self::get_purchase(env.clone(), voter.clone(), id.clone())
+            .filter(|purchase| !purchase.refunded)
+            .ok_or(QuadraticVoteContractErrors::NothingToRefund)?;
+
+        Self::read_token(&env)?.transfer(&env.current_contract_address(), &voter, &purchase.paid);
+
+        purchase.refunded = true;
+        Self::write_purchase(&env, &voter, &id, &purchase, proposal.end_time);
+        proposal.collected = proposal.collected.saturating_sub(purchase.paid);
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::publish(&env, "COST", "REFUNDED", id, (voter, purchase.paid));
+        Ok(purchase.paid)
+    }
+
+    // Updates the price and cost policy applied to new proposals (admin only)
+    pub fn set_config(
+        env: Env,
+        price: i128,
+        policy: QuadraticVoteCostPolicy,
+    ) -> Result<(), QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let config = QuadraticVoteConfig { price, policy };
+        Self::validate_config(&config)?;
+        env.storage()
+            .instance()
+            .set(&QuadraticVoteContractDataKey::Config, &config);
+
+        events::config_updated(&env, "PRICING", config);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), QuadraticVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&QuadraticVoteContractDataKey::Admin, &new_admin);
+
+        events::admin_transferred(&env, &current_admin, &new_admin);
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the price and cost policy applied to new proposals
+    pub fn get_config(env: Env) -> Result<QuadraticVoteConfig, QuadraticVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&QuadraticVoteContractDataKey::Config)
+            .ok_or(QuadraticVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the tokens a voter would pay in total to hold a number of votes on a proposal
+    pub fn get_vote_cost(
+        env: Env,
+        id: Symbol,
+        votes: i128,
+    ) -> Result<i128, QuadraticVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        vote_cost(proposal.price, votes.max(0)).ok_or(QuadraticVoteContractErrors::CostOverflow)
+    }
+
+    // Returns the full state of a proposal
+    pub fn get_proposal(
+        env: Env,
+        id: Symbol,
+    ) -> Result<QuadraticVoteProposal, QuadraticVoteContractErrors> {
+        Self::read_proposal(&env, &id)
+    }
+
+    // Returns the votes a voter bought on a proposal, if any
+    pub fn get_purchase(env: Env, voter: Address, id: Symbol) -> Option<QuadraticVotePurchase> {
+        env.storage()
+            .persistent()
+            .get(&QuadraticVoteContractDataKey::Purchase(voter, id))
+    }
+
+    // Returns the token votes are paid in
+    pub fn get_token(env: Env) -> Result<Address, QuadraticVoteContractErrors> {
+        Ok(Self::read_token(&env)?.address)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,439 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::{VOTE_ABSTAIN, VOTE_AGAINST, VOTE_FOR};
+use governance_errors::{in_range, GovernanceError, QUADRATIC_VOTE_CODES};
+use governance_testutils::{
+    create_holders, create_token_contract, set_time, setup_test_env, TEST_START_TIME,
+};
+use proptest::prelude::*;
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::TokenClient, Address, Env, String,
+};
+
+const PRICE: i128 = 10_000_000; // One token per squared vote at 7 decimals
+const END_TIME: u64 = TEST_START_TIME + MIN_PROPOSAL_DURATION;
+
+fn create_quadratic_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+    policy: QuadraticVoteCostPolicy,
+) -> QuadraticVoteContractClient<'a> {
+    let contract_address = e.register(
+        QuadraticVoteContract,
+        (admin.clone(), token_address.clone(), PRICE, policy),
+    );
+    QuadraticVoteContractClient::new(e, &contract_address)
+}
+
+// Deploys the token and contract under a cost policy, then opens proposal "PROP1" right away
+fn setup_proposal<'a>(
+    e: &Env,
+    policy: QuadraticVoteCostPolicy,
+) -> (QuadraticVoteContractClient<'a>, TokenClient<'a>) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let client = create_quadratic_contract(e, &admin, &token.address, policy);
+
+    client.create_proposal(
+        &symbol_short!("PROP1"),
+        &String::from_str(e, "Fund the community garden"),
+        &TEST_START_TIME,
+        &END_TIME,
+    );
+    (client, token)
+}
+
+// Tests successful initialization with admin, token, price, and cost policy.
+// Expects: Stored configuration matches constructor arguments.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client =
+        create_quadratic_contract(&e, &admin, &token.address, QuadraticVoteCostPolicy::Burn);
+
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(
+        client.get_config(),
+        QuadraticVoteConfig {
+            price: PRICE,
+            policy: QuadraticVoteCostPolicy::Burn,
+        }
+    );
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+
+    e.register_at(
+        &client.address,
+        QuadraticVoteContract,
+        (
+            Address::generate(&e),
+            token.address.clone(),
+            PRICE,
+            QuadraticVoteCostPolicy::Refund,
+        ),
+    );
+}
+
+// Tests configuration update with a zero price.
+// Expects: InvalidConfig error (Error #15).
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_invalid_config() {
+    let e = setup_test_env();
+    let (client, _) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+
+    client.set_config(&0, &QuadraticVoteCostPolicy::Refund);
+}
+
+// Tests a configuration update after a proposal was created.
+// Expects: The running proposal keeps its pinned price and policy, new proposals use the update.
+#[test]
+fn test_config_pinned_per_proposal() {
+    let e = setup_test_env();
+    let (client, _) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+
+    client.set_config(&(PRICE * 2), &QuadraticVoteCostPolicy::Burn);
+    client.create_proposal(
+        &symbol_short!("PROP2"),
+        &String::from_str(&e, "Repaint the library"),
+        &TEST_START_TIME,
+        &END_TIME,
+    );
+
+    let first = client.get_proposal(&symbol_short!("PROP1"));
+    assert_eq!(first.price, PRICE);
+    assert_eq!(first.policy, QuadraticVoteCostPolicy::Refund);
+    let second = client.get_proposal(&symbol_short!("PROP2"));
+    assert_eq!(second.price, PRICE * 2);
+    assert_eq!(second.policy, QuadraticVoteCostPolicy::Burn);
+    assert_eq!(
+        client.get_vote_cost(&symbol_short!("PROP2"), &3),
+        18 * PRICE
+    );
+}
+
+// Tests proposal creation with a duration outside the 5 to 15-day bounds.
+// Expects: DurationTooShort error (Error #12).
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_create_proposal_too_short() {
+    let e = setup_test_env();
+    let (client, _) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+
+    client.create_proposal(
+        &symbol_short!("PROP2"),
+        &String::from_str(&e, "Too short"),
+        &TEST_START_TIME,
+        &(TEST_START_TIME + 3600),
+    );
+}
+
+// Tests buying votes on an active proposal.
+// Expects: Three votes cost nine tokens, held by the contract, and count three on the tally.
+#[test]
+fn test_vote_quadratic_cost() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[20 * PRICE]).get_unchecked(0);
+    let id = symbol_short!("PROP1");
+
+    assert_eq!(client.get_vote_cost(&id, &3), 9 * PRICE);
+    client.vote(&voter, &id, &VOTE_FOR, &3);
+
+    assert_eq!(token.balance(&voter), 11 * PRICE);
+    assert_eq!(token.balance(&client.address), 9 * PRICE);
+    assert_eq!(
+        client.get_purchase(&voter, &id),
+        Some(QuadraticVotePurchase {
+            choice: VOTE_FOR,
+            votes: 3,
+            paid: 9 * PRICE,
+            refunded: false,
+        })
+    );
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.total_for, 3);
+    assert_eq!(proposal.collected, 9 * PRICE);
+}
+
+// Tests buying more votes on the same choice in a second transaction.
+// Expects: Two then three more votes cost 4 then 21 tokens, the same 25 as five at once.
+#[test]
+fn test_vote_top_up() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[25 * PRICE]).get_unchecked(0);
+    let id = symbol_short!("PROP1");
+
+    client.vote(&voter, &id, &VOTE_AGAINST, &2);
+    assert_eq!(token.balance(&voter), 21 * PRICE);
+    client.vote(&voter, &id, &VOTE_AGAINST, &3);
+
+    assert_eq!(token.balance(&voter), 0);
+    assert_eq!(client.get_purchase(&voter, &id).unwrap().votes, 5);
+    assert_eq!(client.get_proposal(&id).total_against, 5);
+}
+
+// Tests the same budget spent by one whale and spread across four holders.
+// Expects: 100 tokens buy the whale 10 votes while 4 holders of 25 buy 20 together.
+#[test]
+fn test_quadratic_favors_breadth() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let id = symbol_short!("PROP1");
+    let whale = create_holders(&e, &token.address, &[100 * PRICE]).get_unchecked(0);
+    let holders = create_holders(&e, &token.address, &[25 * PRICE; 4]);
+
+    client.vote(&whale, &id, &VOTE_AGAINST, &10);
+    for holder in holders.iter() {
+        client.vote(&holder, &id, &VOTE_FOR, &5);
+    }
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.total_for, 20);
+    assert_eq!(proposal.total_against, 10);
+    assert_eq!(proposal.collected, 200 * PRICE);
+}
+
+// Tests buying further votes for a different choice.
+// Expects: ChoiceLocked error (Error #1901).
+#[test]
+#[should_panic(expected = "Error(Contract, #1901)")]
+fn test_vote_choice_locked() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[10 * PRICE]).get_unchecked(0);
+    let id = symbol_short!("PROP1");
+
+    client.vote(&voter, &id, &VOTE_FOR, &1);
+    client.vote(&voter, &id, &VOTE_ABSTAIN, &1);
+}
+
+// Tests buying zero votes or votes for an unknown choice.
+// Expects: InvalidAmount (Error #13) and InvalidChoice (Error #8) errors.
+#[test]
+fn test_vote_invalid() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[10 * PRICE]).get_unchecked(0);
+    let id = symbol_short!("PROP1");
+
+    assert_eq!(
+        client.try_vote(&voter, &id, &VOTE_FOR, &0),
+        Err(Ok(QuadraticVoteContractErrors::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_vote(&voter, &id, &symbol_short!("MAYBE"), &1),
+        Err(Ok(QuadraticVoteContractErrors::InvalidChoice))
+    );
+    assert_eq!(client.get_purchase(&voter, &id), None);
+}
+
+// Tests buying votes whose cost exceeds the token amount range.
+// Expects: CostOverflow error (Error #1902).
+#[test]
+#[should_panic(expected = "Error(Contract, #1902)")]
+fn test_vote_cost_overflow() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[PRICE]).get_unchecked(0);
+
+    client.vote(&voter, &symbol_short!("PROP1"), &VOTE_FOR, &i128::MAX);
+}
+
+// Tests buying votes after the proposal has ended.
+// Expects: VotingNotActive error (Error #7).
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_vote_after_end() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = create_holders(&e, &token.address, &[PRICE]).get_unchecked(0);
+
+    set_time(&e, END_TIME + 1);
+    client.vote(&voter, &symbol_short!("PROP1"), &VOTE_FOR, &1);
+}
+
+// Tests finalizing a Burn proposal.
+// Expects: FOR wins, every paid token is burned, and a second finalize fails (Error #1903).
+#[test]
+fn test_finalize_burns_costs() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Burn);
+    let holders = create_holders(&e, &token.address, &[16 * PRICE, 9 * PRICE]);
+    let id = symbol_short!("PROP1");
+
+    client.vote(&holders.get_unchecked(0), &id, &VOTE_FOR, &4);
+    client.vote(&holders.get_unchecked(1), &id, &VOTE_AGAINST, &3);
+    assert_eq!(
+        client.try_finalize(&id),
+        Err(Ok(QuadraticVoteContractErrors::VotingNotEnded))
+    );
+
+    set_time(&e, END_TIME + 1);
+    assert_eq!(client.finalize(&id), GovernanceStatus::Succeeded);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&holders.get_unchecked(0)), 0);
+    let proposal = client.get_proposal(&id);
+    assert!(proposal.finalized);
+    assert_eq!(proposal.collected, 0);
+
+    assert_eq!(
+        client.try_finalize(&id),
+        Err(Ok(QuadraticVoteContractErrors::ProposalAlreadyFinalized))
+    );
+    assert_eq!(
+        client.try_refund(&holders.get_unchecked(0), &id),
+        Err(Ok(QuadraticVoteContractErrors::NotRefundable))
+    );
+}
+
+// Tests refunds on a Refund proposal once voting has ended.
+// Expects: Each voter gets its full payment back once, and finalizing leaves funds untouched.
+#[test]
+fn test_refund_after_end() {
+    let e = setup_test_env();
+    let (client, token) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let holders = create_holders(&e, &token.address, &[4 * PRICE, 9 * PRICE]);
+    let (supporter, opponent) = (holders.get_unchecked(0), holders.get_unchecked(1));
+    let id = symbol_short!("PROP1");
+
+    client.vote(&supporter, &id, &VOTE_FOR, &2);
+    client.vote(&opponent, &id, &VOTE_AGAINST, &3);
+    assert_eq!(
+        client.try_refund(&supporter, &id),
+        Err(Ok(QuadraticVoteContractErrors::VotingNotEnded))
+    );
+
+    set_time(&e, END_TIME + 1);
+    assert_eq!(client.finalize(&id), GovernanceStatus::Defeated);
+    assert_eq!(token.balance(&client.address), 13 * PRICE);
+
+    assert_eq!(client.refund(&supporter, &id), 4 * PRICE);
+    assert_eq!(token.balance(&supporter), 4 * PRICE);
+    assert!(client.get_purchase(&supporter, &id).unwrap().refunded);
+    assert_eq!(client.get_proposal(&id).collected, 9 * PRICE);
+    assert_eq!(
+        client.try_refund(&supporter, &id),
+        Err(Ok(QuadraticVoteContractErrors::NothingToRefund))
+    );
+
+    client.refund(&opponent, &id);
+    assert_eq!(token.balance(&opponent), 9 * PRICE);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// Tests a refund for an address that never bought votes.
+// Expects: NothingToRefund error (Error #1905).
+#[test]
+#[should_panic(expected = "Error(Contract, #1905)")]
+fn test_refund_without_purchase() {
+    let e = setup_test_env();
+    let (client, _) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+    let voter = Address::generate(&e);
+
+    set_time(&e, END_TIME + 1);
+    client.refund(&voter, &symbol_short!("PROP1"));
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version and no support for any governance feature.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _) = setup_proposal(&e, QuadraticVoteCostPolicy::Refund);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_TOKEN));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1900 block.
+#[test]
+fn test_error_codes() {
+    for (error, shared) in [
+        (
+            QuadraticVoteContractErrors::ContractNotInitialized,
+            GovernanceError::ContractNotInitialized,
+        ),
+        (
+            QuadraticVoteContractErrors::ContractAlreadyInitialized,
+            GovernanceError::ContractAlreadyInitialized,
+        ),
+        (
+            QuadraticVoteContractErrors::ProposalAlreadyExists,
+            GovernanceError::ProposalAlreadyExists,
+        ),
+        (
+            QuadraticVoteContractErrors::ProposalNotFound,
+            GovernanceError::ProposalNotFound,
+        ),
+        (
+            QuadraticVoteContractErrors::VotingNotActive,
+            GovernanceError::VotingNotActive,
+        ),
+        (
+            QuadraticVoteContractErrors::InvalidChoice,
+            GovernanceError::InvalidChoice,
+        ),
+        (
+            QuadraticVoteContractErrors::DurationTooShort,
+            GovernanceError::DurationTooShort,
+        ),
+        (
+            QuadraticVoteContractErrors::InvalidAmount,
+            GovernanceError::InvalidAmount,
+        ),
+        (
+            QuadraticVoteContractErrors::VotingNotEnded,
+            GovernanceError::VotingNotEnded,
+        ),
+        (
+            QuadraticVoteContractErrors::InvalidConfig,
+            GovernanceError::InvalidConfig,
+        ),
+    ] {
+        assert_eq!(error as u32, shared as u32);
+    }
+    for error in [
+        QuadraticVoteContractErrors::ChoiceLocked,
+        QuadraticVoteContractErrors::CostOverflow,
+        QuadraticVoteContractErrors::ProposalAlreadyFinalized,
+        QuadraticVoteContractErrors::NotRefundable,
+        QuadraticVoteContractErrors::NothingToRefund,
+    ] {
+        assert!(in_range(error as u32, QUADRATIC_VOTE_CODES));
+    }
+}
+
+proptest! {
+    // Tests topping up votes against buying the same votes from a fresh address.
+    // Expects: The top-up always costs more, so every extra vote is dearer than the last.
+    #[test]
+    fn prop_top_up_costs_more(first in 1..=1_000_000i128, second in 1..=1_000_000i128) {
+        let marginal = vote_cost(PRICE, first + second).unwrap() - vote_cost(PRICE, first).unwrap();
+        prop_assert!(marginal > vote_cost(PRICE, second).unwrap());
+    }
+
+    // Tests the cost of any vote count at a positive price.
+    // Expects: Costs grow with every extra vote.
+    #[test]
+    fn prop_cost_increasing(price in 1..=PRICE, votes in 0..=1_000_000_000i128) {
+        let cost = vote_cost(price, votes).unwrap();
+        prop_assert!(vote_cost(price, votes + 1).unwrap() > cost);
+    }
+}
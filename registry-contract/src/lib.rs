@@ -0,0 +1,258 @@
+#![no_std]
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+pub use pg_contracts_types::registry::{RegistryEntry, RegistryModel};
+
+// --- Registry Constraints ---
+const MAX_ENTRIES: u32 = 500; // Maximum deployments listed at once
+const MAX_PAGE_LIMIT: u32 = 100; // Maximum entries returned by paginated reads
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REGISTRY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_REGISTRY];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum RegistryContractDataKey {
+    Admin,             // Contract administrator address
+    Names,             // Names of the listed deployments in listing order
+    Entry(Symbol),     // Registry entry per name, kept after delisting
+    Contract(Address), // Name a deployment is currently listed under
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1700-1799 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RegistryContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    NameTaken = 1701,               // Another listed deployment already uses this name
+    EntryNotFound = 1702,           // No deployment was ever listed under this name
+    EntryNotListed = 1703,          // The deployment under this name is already delisted
+    ContractAlreadyListed = 1704,   // The deployment is already listed under another name
+    RegistryFull = 1705,            // The registry has reached its listing limit
+}
+
+#[contract]
+pub struct RegistryContract;
+
+#[contractimpl]
+impl RegistryContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, RegistryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&RegistryContractDataKey::Admin)
+            .ok_or(RegistryContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the names of the listed deployments
+    fn read_names(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&RegistryContractDataKey::Names)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // Saves the names of the listed deployments and extends their TTL
+    fn write_names(env: &Env, names: &Vec<Symbol>) {
+        let names_key = RegistryContractDataKey::Names;
+        env.storage().persistent().set(&names_key, names);
+        env.storage().persistent().extend_ttl(
+            &names_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+    }
+
+    // Saves a registry entry and extends its TTL
+    fn write_entry(env: &Env, entry: &RegistryEntry) {
+        let entry_key = RegistryContractDataKey::Entry(entry.name.clone());
+        env.storage().persistent().set(&entry_key, entry);
+        env.storage().persistent().extend_ttl(
+            &entry_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin
+    pub fn __constructor(env: Env, admin: Address) -> Result<(), RegistryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&RegistryContractDataKey::Admin)
+        {
+            return Err(RegistryContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&RegistryContractDataKey::Admin, &admin);
+        Ok(())
+    }
+
+    // Lists a governance deployment under a name, replacing a delisted entry with the same
+    // name (admin only)
+    pub fn list(
+        env: Env,
+        name: Symbol,
+        contract: Address,
+        model: RegistryModel,
+        token: Option<Address>,
+    ) -> Result<(), RegistryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut names = Self::read_names(&env);
+        if names.contains(&name) {
+            return Err(RegistryContractErrors::NameTaken);
+        }
+        let contract_key = RegistryContractDataKey::Contract(contract.clone());
+        if env.storage().persistent().has(&contract_key) {
+            return Err(RegistryContractErrors::ContractAlreadyListed);
+        }
+        if names.len() >= MAX_ENTRIES {
+            return Err(RegistryContractErrors::RegistryFull);
+        }
+
+        let entry = RegistryEntry {
+            name: name.clone(),
+            contract: contract.clone(),
+            model,
+            token,
+            created_at: env.ledger().timestamp(),
+            listed: true,
+        };
+        Self::write_entry(&env, &entry);
+        env.storage().persistent().set(&contract_key, &name);
+        env.storage().persistent().extend_ttl(
+            &contract_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+        names.push_back(name.clone());
+        Self::write_names(&env, &names);
+
+        env.events()
+            .publish(("ENTRY", "LISTED", name), (contract, model));
+        Ok(())
+    }
+
+    // Delists the deployment under a name, keeping its entry for lookups (admin only)
+    pub fn delist(env: Env, name: Symbol) -> Result<(), RegistryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut entry = Self::get_entry(env.clone(), name.clone())?;
+        let mut names = Self::read_names(&env);
+        let Some(position) = names.first_index_of(&name) else {
+            return Err(RegistryContractErrors::EntryNotListed);
+        };
+        names.remove(position);
+        Self::write_names(&env, &names);
+        env.storage()
+            .persistent()
+            .remove(&RegistryContractDataKey::Contract(entry.contract.clone()));
+        entry.listed = false;
+        Self::write_entry(&env, &entry);
+
+        env.events()
+            .publish(("ENTRY", "DELISTED", name), entry.contract);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), RegistryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&RegistryContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns a page of the listed entries in listing order
+    pub fn get_entries(env: Env, offset: u32, limit: u32) -> Vec<RegistryEntry> {
+        let names = Self::read_names(&env);
+        let mut page = Vec::new(&env);
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(names.len());
+
+        for index in offset..end {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<RegistryContractDataKey, RegistryEntry>(&RegistryContractDataKey::Entry(
+                    names.get_unchecked(index),
+                ))
+            {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    // Returns the entry listed under a name, including a delisted one
+    pub fn get_entry(env: Env, name: Symbol) -> Result<RegistryEntry, RegistryContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&RegistryContractDataKey::Entry(name))
+            .ok_or(RegistryContractErrors::EntryNotFound)
+    }
+
+    // Returns the name a deployment is currently listed under, if any
+    pub fn get_name(env: Env, contract: Address) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&RegistryContractDataKey::Contract(contract))
+    }
+
+    // Returns the number of listed entries
+    pub fn entry_count(env: Env) -> u32 {
+        Self::read_names(&env).len()
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,254 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, REGISTRY_CODES};
+use governance_testutils::setup_test_env;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn create_registry_contract<'a>(e: &Env, admin: &Address) -> RegistryContractClient<'a> {
+    let contract_address = e.register(RegistryContract, RegistryContractArgs::__constructor(admin));
+    RegistryContractClient::new(e, &contract_address)
+}
+
+// Deploys a registry, returning its admin
+fn setup_registry<'a>(e: &Env) -> (RegistryContractClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let client = create_registry_contract(e, &admin);
+    (client, admin)
+}
+
+// Tests successful initialization with admin.
+// Expects: The registry starts empty.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+
+    assert_eq!(client.entry_count(), 0);
+    assert!(client.get_entries(&0, &10).is_empty());
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, admin) = setup_registry(&e);
+
+    e.register_at(
+        &client.address,
+        RegistryContract,
+        RegistryContractArgs::__constructor(&admin),
+    );
+}
+
+// Tests listing a governance deployment.
+// Expects: The entry records the contract, model, token, and listing time, and the contract
+// resolves back to its name.
+#[test]
+fn test_list_entry() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+    let contract = Address::generate(&e);
+    let token = Address::generate(&e);
+
+    client.list(
+        &symbol_short!("SHX_DAO"),
+        &contract,
+        &RegistryModel::Gated,
+        &Some(token.clone()),
+    );
+
+    assert_eq!(
+        client.get_entry(&symbol_short!("SHX_DAO")),
+        RegistryEntry {
+            name: symbol_short!("SHX_DAO"),
+            contract: contract.clone(),
+            model: RegistryModel::Gated,
+            token: Some(token),
+            created_at: 1000000,
+            listed: true,
+        }
+    );
+    assert_eq!(client.get_name(&contract), Some(symbol_short!("SHX_DAO")));
+    assert_eq!(client.entry_count(), 1);
+}
+
+// Tests listing under a name or for a contract that is already listed.
+// Expects: NameTaken error (Error #1701) for the name and ContractAlreadyListed error
+// (Error #1704) for the contract.
+#[test]
+fn test_list_duplicates() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+    let contract = Address::generate(&e);
+    client.list(
+        &symbol_short!("SHX_DAO"),
+        &contract,
+        &RegistryModel::Weighted,
+        &None,
+    );
+
+    assert_eq!(
+        client.try_list(
+            &symbol_short!("SHX_DAO"),
+            &Address::generate(&e),
+            &RegistryModel::Gated,
+            &None,
+        ),
+        Err(Ok(RegistryContractErrors::NameTaken))
+    );
+    assert_eq!(
+        client.try_list(
+            &symbol_short!("OTHER"),
+            &contract,
+            &RegistryModel::Weighted,
+            &None,
+        ),
+        Err(Ok(RegistryContractErrors::ContractAlreadyListed))
+    );
+}
+
+// Tests delisting a deployment and listing a new one under the freed name.
+// Expects: The delisted entry stays readable but leaves the enumeration, a second delist fails
+// with EntryNotListed (Error #1703), and the name can be reused.
+#[test]
+fn test_delist_and_relist() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+    let retired = Address::generate(&e);
+    let replacement = Address::generate(&e);
+    client.list(
+        &symbol_short!("SHX_DAO"),
+        &retired,
+        &RegistryModel::Gated,
+        &None,
+    );
+
+    client.delist(&symbol_short!("SHX_DAO"));
+    assert!(!client.get_entry(&symbol_short!("SHX_DAO")).listed);
+    assert_eq!(client.get_name(&retired), None);
+    assert_eq!(client.entry_count(), 0);
+    assert_eq!(
+        client.try_delist(&symbol_short!("SHX_DAO")),
+        Err(Ok(RegistryContractErrors::EntryNotListed))
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 100);
+    client.list(
+        &symbol_short!("SHX_DAO"),
+        &replacement,
+        &RegistryModel::Optimistic,
+        &None,
+    );
+    let entry = client.get_entry(&symbol_short!("SHX_DAO"));
+    assert_eq!(entry.contract, replacement);
+    assert_eq!(entry.created_at, 1000100);
+    assert!(entry.listed);
+}
+
+// Tests delisting a name that was never listed.
+// Expects: EntryNotFound error (Error #1702).
+#[test]
+#[should_panic(expected = "Error(Contract, #1702)")]
+fn test_delist_unknown() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+
+    client.delist(&symbol_short!("UNKNOWN"));
+}
+
+// Tests paginated enumeration of the listed entries.
+// Expects: Pages follow listing order, skip delisted entries, and stop at the end.
+#[test]
+fn test_get_entries_paginated() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+    let names = [
+        symbol_short!("ALPHA"),
+        symbol_short!("BRAVO"),
+        symbol_short!("CHARLIE"),
+        symbol_short!("DELTA"),
+        symbol_short!("ECHO"),
+    ];
+    for name in names.iter() {
+        client.list(name, &Address::generate(&e), &RegistryModel::Gated, &None);
+    }
+    client.delist(&symbol_short!("BRAVO"));
+
+    let first_page: std::vec::Vec<Symbol> = client
+        .get_entries(&0, &2)
+        .iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(first_page, [names[0].clone(), names[2].clone()]);
+    let second_page: std::vec::Vec<Symbol> = client
+        .get_entries(&2, &2)
+        .iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(second_page, [names[3].clone(), names[4].clone()]);
+    assert!(client.get_entries(&4, &2).is_empty());
+    assert!(client.get_entries(&0, &0).is_empty());
+}
+
+// Tests transferring the admin role.
+// Expects: The new admin is stored and can list deployments.
+#[test]
+fn test_transfer_admin() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+    let new_admin = Address::generate(&e);
+
+    client.transfer_admin(&new_admin);
+    client.list(
+        &symbol_short!("SHX_DAO"),
+        &Address::generate(&e),
+        &RegistryModel::Election,
+        &None,
+    );
+
+    assert_eq!(client.entry_count(), 1);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with registry supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _) = setup_registry(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_REGISTRY));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1700 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        RegistryContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        RegistryContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        RegistryContractErrors::NameTaken,
+        RegistryContractErrors::EntryNotFound,
+        RegistryContractErrors::EntryNotListed,
+        RegistryContractErrors::ContractAlreadyListed,
+        RegistryContractErrors::RegistryFull,
+    ] {
+        assert!(in_range(error as u32, REGISTRY_CODES));
+    }
+}
@@ -0,0 +1,303 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+pub use pg_contracts_types::reputation::ReputationRates;
+
+// --- Award Reason Constants ---
+const REASON_VOTE: Symbol = symbol_short!("VOTE");
+const REASON_PASSED: Symbol = symbol_short!("PASSED");
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REPUTATION_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 2] = [
+    governance_core::FEATURE_WEIGHT,
+    governance_core::FEATURE_REPUTATION,
+];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum ReputationContractDataKey {
+    Admin,               // Contract administrator address
+    Rates,               // Points awarded per accrual event
+    Minter(Address),     // Vote contracts allowed to award reputation
+    Reputation(Address), // Accrued reputation per address
+    TotalReputation,     // Sum of all reputation ever awarded
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 400-499 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReputationContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    MinterNotAuthorized = 403,      // The caller is not a registered minter
+    InvalidRates = 404,             // Award rates must not be negative
+}
+
+#[contract]
+pub struct ReputationContract;
+
+#[contractimpl]
+impl ReputationContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, ReputationContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ReputationContractDataKey::Admin)
+            .ok_or(ReputationContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the award rates from instance storage
+    fn read_rates(env: &Env) -> Result<ReputationRates, ReputationContractErrors> {
+        env.storage()
+            .instance()
+            .get(&ReputationContractDataKey::Rates)
+            .ok_or(ReputationContractErrors::ContractNotInitialized)
+    }
+
+    // Validates that award rates are non-negative
+    fn validate_rates(rates: &ReputationRates) -> Result<(), ReputationContractErrors> {
+        if rates.vote_points < 0 || rates.proposal_passed_points < 0 {
+            return Err(ReputationContractErrors::InvalidRates);
+        }
+        Ok(())
+    }
+
+    // Authenticates a minter and checks it has been registered by the admin
+    fn require_minter(env: &Env, minter: &Address) -> Result<(), ReputationContractErrors> {
+        minter.require_auth();
+        let is_minter = env
+            .storage()
+            .instance()
+            .get(&ReputationContractDataKey::Minter(minter.clone()))
+            .unwrap_or(false);
+        if !is_minter {
+            return Err(ReputationContractErrors::MinterNotAuthorized);
+        }
+        Ok(())
+    }
+
+    // Credits reputation to an address and emits an award event
+    fn award(env: &Env, to: &Address, amount: i128, reason: Symbol) {
+        if amount == 0 {
+            return;
+        }
+        let reputation_key = ReputationContractDataKey::Reputation(to.clone());
+        let current: i128 = env.storage().persistent().get(&reputation_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&reputation_key, &current.saturating_add(amount));
+        env.storage().persistent().extend_ttl(
+            &reputation_key,
+            REPUTATION_TTL_EXTENSION,
+            REPUTATION_TTL_EXTENSION,
+        );
+
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&ReputationContractDataKey::TotalReputation)
+            .unwrap_or(0);
+        env.storage().instance().set(
+            &ReputationContractDataKey::TotalReputation,
+            &total.saturating_add(amount),
+        );
+
+        env.events()
+            .publish(("REPUTATION", "AWARDED", to.clone()), (reason, amount));
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and award rates
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        vote_points: i128,
+        proposal_passed_points: i128,
+    ) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&ReputationContractDataKey::Admin)
+        {
+            return Err(ReputationContractErrors::ContractAlreadyInitialized);
+        }
+        let rates = ReputationRates {
+            vote_points,
+            proposal_passed_points,
+        };
+        Self::validate_rates(&rates)?;
+
+        env.storage()
+            .instance()
+            .set(&ReputationContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&ReputationContractDataKey::Rates, &rates);
+        Ok(())
+    }
+
+    // Registers or unregisters a vote contract allowed to award reputation
+    pub fn set_minter(
+        env: Env,
+        minter: Address,
+        enabled: bool,
+    ) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let minter_key = ReputationContractDataKey::Minter(minter.clone());
+        if enabled {
+            env.storage().instance().set(&minter_key, &true);
+        } else {
+            env.storage().instance().remove(&minter_key);
+        }
+
+        env.events()
+            .publish(("MINTER", "UPDATED"), (minter, enabled));
+        Ok(())
+    }
+
+    // Updates the points awarded per accrual event (admin only)
+    pub fn set_rates(
+        env: Env,
+        vote_points: i128,
+        proposal_passed_points: i128,
+    ) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let rates = ReputationRates {
+            vote_points,
+            proposal_passed_points,
+        };
+        Self::validate_rates(&rates)?;
+        env.storage()
+            .instance()
+            .set(&ReputationContractDataKey::Rates, &rates);
+
+        env.events()
+            .publish(("RATES", "UPDATED"), (vote_points, proposal_passed_points));
+        Ok(())
+    }
+
+    // Awards vote participation reputation (registered vote contracts only)
+    pub fn record_vote(
+        env: Env,
+        minter: Address,
+        voter: Address,
+    ) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_minter(&env, &minter)?;
+        let rates = Self::read_rates(&env)?;
+        Self::award(&env, &voter, rates.vote_points, REASON_VOTE);
+        Ok(())
+    }
+
+    // Awards reputation to the proposer of a passed proposal (registered vote contracts only)
+    pub fn record_proposal_passed(
+        env: Env,
+        minter: Address,
+        proposer: Address,
+    ) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_minter(&env, &minter)?;
+        let rates = Self::read_rates(&env)?;
+        Self::award(&env, &proposer, rates.proposal_passed_points, REASON_PASSED);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), ReputationContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&ReputationContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the accrued reputation of an address
+    pub fn reputation_of(env: Env, addr: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ReputationContractDataKey::Reputation(addr))
+            .unwrap_or(0)
+    }
+
+    // Token-compatible alias of reputation_of so balance-based strategies can read reputation
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Self::reputation_of(env, id)
+    }
+
+    // WeightCalculator-compatible entrypoint using reputation directly as voting weight
+    pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+        Self::reputation_of(env, user)
+    }
+
+    // Returns the sum of all reputation awarded
+    pub fn total_reputation(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&ReputationContractDataKey::TotalReputation)
+            .unwrap_or(0)
+    }
+
+    // Returns the configured award rates
+    pub fn get_rates(env: Env) -> Result<ReputationRates, ReputationContractErrors> {
+        Self::read_rates(&env)
+    }
+
+    // Returns whether an address is a registered minter
+    pub fn is_minter(env: Env, minter: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&ReputationContractDataKey::Minter(minter))
+            .unwrap_or(false)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,158 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, REPUTATION_CODES};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn create_reputation_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    vote_points: i128,
+    proposal_passed_points: i128,
+) -> ReputationContractClient<'a> {
+    let contract_address = e.register(
+        ReputationContract,
+        ReputationContractArgs::__constructor(admin, &vote_points, &proposal_passed_points),
+    );
+    ReputationContractClient::new(e, &contract_address)
+}
+
+fn setup_test_env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e
+}
+
+// Tests successful initialization with admin and award rates.
+// Expects: Stored rates match constructor arguments and no reputation exists yet.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 1, 10);
+
+    let rates = client.get_rates();
+    assert_eq!(rates.vote_points, 1);
+    assert_eq!(rates.proposal_passed_points, 10);
+    assert_eq!(client.total_reputation(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 1, 10);
+
+    e.register_at(
+        &client.address,
+        ReputationContract,
+        ReputationContractArgs::__constructor(&admin, &1, &10),
+    );
+}
+
+// Tests reputation accrual from votes and passed proposals by a registered minter.
+// Expects: Balances, weight entrypoints, and total reflect every award.
+#[test]
+fn test_record_vote_and_proposal_passed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let proposer = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 2, 25);
+
+    client.set_minter(&minter, &true);
+    assert!(client.is_minter(&minter));
+
+    client.record_vote(&minter, &voter);
+    client.record_vote(&minter, &voter);
+    client.record_proposal_passed(&minter, &proposer);
+
+    assert_eq!(client.reputation_of(&voter), 4);
+    assert_eq!(client.balance(&voter), 4);
+    assert_eq!(client.calculate_weight(&voter, &0), 4);
+    assert_eq!(client.reputation_of(&proposer), 25);
+    assert_eq!(client.total_reputation(), 29);
+}
+
+// Tests rejection of awards from an unregistered caller.
+// Expects: MinterNotAuthorized error (Error #403) for unknown minters.
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")]
+fn test_minter_not_authorized() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 1, 10);
+
+    client.record_vote(&minter, &voter);
+}
+
+// Tests that removing a minter revokes its ability to award reputation.
+// Expects: MinterNotAuthorized error (Error #403) after the minter is disabled.
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")]
+fn test_minter_removed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 1, 10);
+
+    client.set_minter(&minter, &true);
+    client.record_vote(&minter, &voter);
+    client.set_minter(&minter, &false);
+    client.record_vote(&minter, &voter);
+}
+
+// Tests rejection of negative award rates.
+// Expects: InvalidRates error (Error #404) when a rate is below zero.
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")]
+fn test_invalid_rates() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_reputation_contract(&e, &admin, 1, 10);
+
+    client.set_rates(&-1, &10);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight and reputation supported and unrelated
+// features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_reputation_contract(&e, &Address::generate(&e), 1, 10);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(client.supports(&governance_core::FEATURE_REPUTATION));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 400 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        ReputationContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        ReputationContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        ReputationContractErrors::MinterNotAuthorized,
+        ReputationContractErrors::InvalidRates,
+    ] {
+        assert!(in_range(error as u32, REPUTATION_CODES));
+    }
+}
@@ -0,0 +1,348 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+pub use pg_contracts_types::staked_vote::StakedVoteStake;
+
+// --- Staking Constraints ---
+const YEAR: u64 = 31_536_000; // 365 days in seconds, the staking duration of the full boost
+const DEFAULT_COOLDOWN: u64 = 604_800; // 7 days between unstaking and claiming
+const MAX_COOLDOWN: u64 = 2_592_000; // 30 days, the longest cooldown the admin can set
+
+// --- Multiplier Scale ---
+const MULTIPLIER_SCALE: i128 = 10_000; // Basis points, 10_000 = 1.0x
+const MAX_BOOST: i128 = 10_000; // Extra weight after a year of staking (2.0x total)
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const STAKE_TTL_EXTENSION: u32 = 6_220_800; // ~72 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_WEIGHT];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum StakedVoteContractDataKey {
+    Admin,          // Contract administrator address
+    Token,          // Governance token accepted for stakes
+    Cooldown,       // Seconds between unstaking and claiming
+    TotalStaked,    // Tokens staked and not unstaking across all users
+    Stake(Address), // Staked amount, start, and cooldown end per user
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2400-2499 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StakedVoteContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidAmount = 13,             // Amount must be greater than zero
+    InvalidConfig = 15,             // Cooldown must not exceed 30 days
+    StakeNotFound = 2401,           // The user has no stake
+    StakeUnstaking = 2402,          // The stake is in its cooldown and cannot change
+    CooldownActive = 2403,          // Tokens cannot be claimed before the cooldown ends
+    NotUnstaking = 2404,            // The stake must be unstaked before it can be claimed
+}
+
+// Returns the multiplier in basis points for a staking duration, rising linearly from 1.0x
+// when freshly staked to 2.0x after a year
+pub fn duration_multiplier(duration: u64) -> i128 {
+    let duration = duration.min(YEAR) as i128;
+    MULTIPLIER_SCALE + MAX_BOOST * duration / YEAR as i128
+}
+
+// Returns the weight of a staked amount for a staking duration
+pub fn staked_weight(amount: i128, duration: u64) -> i128 {
+    amount.max(0).saturating_mul(duration_multiplier(duration)) / MULTIPLIER_SCALE
+}
+
+#[contract]
+pub struct StakedVoteContract;
+
+#[contractimpl]
+impl StakedVoteContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, StakedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&StakedVoteContractDataKey::Admin)
+            .ok_or(StakedVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, StakedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&StakedVoteContractDataKey::Token)
+            .ok_or(StakedVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Saves a user's stake and extends its TTL
+    fn write_stake(env: &Env, user: &Address, stake: &StakedVoteStake) {
+        let stake_key = StakedVoteContractDataKey::Stake(user.clone());
+        env.storage().persistent().set(&stake_key, stake);
+        env.storage()
+            .persistent()
+            .extend_ttl(&stake_key, STAKE_TTL_EXTENSION, STAKE_TTL_EXTENSION);
+    }
+
+    // Adds a signed amount to the total of active stakes
+    fn adjust_total(env: &Env, delta: i128) {
+        let total = Self::get_total_staked(env.clone());
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::TotalStaked, &(total + delta));
+    }
+
+    // Returns the seconds a stake has been accruing its boost, zero while unstaking
+    fn duration(env: &Env, stake: &StakedVoteStake) -> u64 {
+        if stake.cooldown_end > 0 {
+            return 0;
+        }
+        env.ledger().timestamp().saturating_sub(stake.staked_at)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and governance token and the default 7-day cooldown
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&StakedVoteContractDataKey::Admin)
+        {
+            return Err(StakedVoteContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::Cooldown, &DEFAULT_COOLDOWN);
+        Ok(())
+    }
+
+    // Stakes tokens, or adds to an existing stake; a top-up moves the start of the stake to
+    // the amount-weighted average of the old start and now, so the added tokens accrue their
+    // boost from zero without resetting the boost already earned
+    pub fn stake(env: Env, user: Address, amount: i128) -> Result<(), StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        if amount <= 0 {
+            return Err(StakedVoteContractErrors::InvalidAmount);
+        }
+
+        let now = env.ledger().timestamp();
+        let (stake, action) = match Self::get_stake(env.clone(), user.clone()) {
+            Ok(stake) if stake.cooldown_end > 0 => {
+                return Err(StakedVoteContractErrors::StakeUnstaking);
+            }
+            Ok(stake) => {
+                let total = stake.amount + amount;
+                let started = stake.amount * stake.staked_at as i128 + amount * now as i128;
+                let stake = StakedVoteStake {
+                    amount: total,
+                    staked_at: (started / total) as u64,
+                    cooldown_end: 0,
+                };
+                (stake, "INCREASED")
+            }
+            Err(_) => {
+                let stake = StakedVoteStake {
+                    amount,
+                    staked_at: now,
+                    cooldown_end: 0,
+                };
+                (stake, "CREATED")
+            }
+        };
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &user,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        Self::write_stake(&env, &user, &stake);
+        Self::adjust_total(&env, amount);
+
+        env.events()
+            .publish(("STAKE", action, user), (stake.amount, stake.staked_at));
+        Ok(())
+    }
+
+    // Starts the cooldown on the whole stake; its voting power drops to zero immediately and
+    // the tokens can be claimed once the cooldown ends
+    pub fn unstake(env: Env, user: Address) -> Result<u64, StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let mut stake = Self::get_stake(env.clone(), user.clone())?;
+        if stake.cooldown_end > 0 {
+            return Err(StakedVoteContractErrors::StakeUnstaking);
+        }
+
+        stake.cooldown_end = env.ledger().timestamp() + Self::get_cooldown(env.clone());
+        Self::write_stake(&env, &user, &stake);
+        Self::adjust_total(&env, -stake.amount);
+
+        env.events().publish(
+            ("STAKE", "UNSTAKING", user),
+            (stake.amount, stake.cooldown_end),
+        );
+        Ok(stake.cooldown_end)
+    }
+
+    // Returns unstaked tokens to the user once the cooldown has ended
+    pub fn claim(env: Env, user: Address) -> Result<i128, StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let stake = Self::get_stake(env.clone(), user.clone())?;
+        if stake.cooldown_end == 0 {
+            return Err(StakedVoteContractErrors::NotUnstaking);
+        }
+        if env.ledger().timestamp() < stake.cooldown_end {
+            return Err(StakedVoteContractErrors::CooldownActive);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&StakedVoteContractDataKey::Stake(user.clone()));
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &user,
+            &stake.amount,
+        );
+
+        env.events()
+            .publish(("STAKE", "CLAIMED", user), stake.amount);
+        Ok(stake.amount)
+    }
+
+    // Sets the cooldown applied to later unstakes, up to 30 days; stakes already unstaking
+    // keep the cooldown end they were given (admin only)
+    pub fn set_cooldown(env: Env, cooldown: u64) -> Result<(), StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if cooldown > MAX_COOLDOWN {
+            return Err(StakedVoteContractErrors::InvalidConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::Cooldown, &cooldown);
+
+        env.events().publish(("COOLDOWN", "UPDATED"), cooldown);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), StakedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&StakedVoteContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns w = amount * multiplier / 10_000 for the user's stake, zero without a stake or
+    // while unstaking
+    pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+        match Self::get_stake(env.clone(), user) {
+            Ok(stake) if stake.cooldown_end == 0 => {
+                staked_weight(stake.amount, Self::duration(&env, &stake))
+            }
+            _ => 0,
+        }
+    }
+
+    // Returns the user's current multiplier in basis points, 1.0x without an active stake
+    pub fn get_multiplier(env: Env, user: Address) -> i128 {
+        match Self::get_stake(env.clone(), user) {
+            Ok(stake) => duration_multiplier(Self::duration(&env, &stake)),
+            Err(_) => MULTIPLIER_SCALE,
+        }
+    }
+
+    // Returns the user's stake
+    pub fn get_stake(env: Env, user: Address) -> Result<StakedVoteStake, StakedVoteContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&StakedVoteContractDataKey::Stake(user))
+            .ok_or(StakedVoteContractErrors::StakeNotFound)
+    }
+
+    // Returns the tokens staked and not unstaking across all users
+    pub fn get_total_staked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&StakedVoteContractDataKey::TotalStaked)
+            .unwrap_or(0)
+    }
+
+    // Returns the seconds between unstaking and claiming
+    pub fn get_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StakedVoteContractDataKey::Cooldown)
+            .unwrap_or(DEFAULT_COOLDOWN)
+    }
+
+    // Returns the governance token accepted for stakes
+    pub fn get_token(env: Env) -> Result<Address, StakedVoteContractErrors> {
+        Self::read_token(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
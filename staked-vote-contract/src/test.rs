@@ -0,0 +1,315 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, STAKED_VOTE_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use proptest::prelude::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+fn create_staked_vote_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> StakedVoteContractClient<'a> {
+    let contract_address = e.register(
+        StakedVoteContract,
+        StakedVoteContractArgs::__constructor(admin, token_address),
+    );
+    StakedVoteContractClient::new(e, &contract_address)
+}
+
+// Deploys the token and staked vote contract and mints 10,000 tokens to a user
+fn setup_staked_vote<'a>(e: &Env) -> (StakedVoteContractClient<'a>, TokenClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let user = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    StellarAssetClient::new(e, &token.address).mint(&user, &10000);
+    let client = create_staked_vote_contract(e, &admin, &token.address);
+    (client, token, user)
+}
+
+// Tests successful initialization with admin and governance token.
+// Expects: The default 7-day cooldown, nothing staked, and zero weight for users.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_staked_vote(&e);
+
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(client.get_cooldown(), DEFAULT_COOLDOWN);
+    assert_eq!(client.get_total_staked(), 0);
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+    assert_eq!(client.get_multiplier(&user), 10_000);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_staked_vote(&e);
+
+    e.register_at(
+        &client.address,
+        StakedVoteContract,
+        StakedVoteContractArgs::__constructor(&user, &token.address),
+    );
+}
+
+// Tests staking tokens.
+// Expects: Tokens move into the contract and a fresh stake counts 1.0x.
+#[test]
+fn test_stake() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+
+    let stake = client.get_stake(&user);
+    assert_eq!(stake.amount, 1000);
+    assert_eq!(stake.staked_at, 1000000);
+    assert_eq!(stake.cooldown_end, 0);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(client.get_total_staked(), 1000);
+    assert_eq!(client.calculate_weight(&user, &0), 1000);
+}
+
+// Tests the multiplier growing with the staking duration.
+// Expects: 1.5x after half a year, capped at 2.0x from one year on.
+#[test]
+fn test_weight_growth() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += YEAR / 2);
+    assert_eq!(client.get_multiplier(&user), 15_000);
+    assert_eq!(client.calculate_weight(&user, &0), 1500);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += YEAR);
+    assert_eq!(client.get_multiplier(&user), 20_000);
+    assert_eq!(client.calculate_weight(&user, &0), 2000);
+}
+
+// Tests adding to an existing stake.
+// Expects: The start moves to the amount-weighted average, halving the boost earned so far.
+#[test]
+fn test_stake_top_up() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    e.ledger().with_mut(|ledger| ledger.timestamp += YEAR / 2);
+    client.stake(&user, &1000);
+
+    let stake = client.get_stake(&user);
+    assert_eq!(stake.amount, 2000);
+    assert_eq!(stake.staked_at, 1000000 + YEAR / 4);
+    assert_eq!(client.get_total_staked(), 2000);
+    assert_eq!(client.calculate_weight(&user, &0), 2500);
+}
+
+// Tests staking a non-positive amount.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_stake_invalid_amount() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &0);
+}
+
+// Tests unstaking a boosted stake.
+// Expects: Voting power drops to zero immediately and the cooldown end is recorded.
+#[test]
+fn test_unstake() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    e.ledger().with_mut(|ledger| ledger.timestamp += YEAR);
+    assert_eq!(client.calculate_weight(&user, &0), 2000);
+
+    let cooldown_end = client.unstake(&user);
+
+    assert_eq!(cooldown_end, 1000000 + YEAR + DEFAULT_COOLDOWN);
+    assert_eq!(client.get_stake(&user).cooldown_end, cooldown_end);
+    assert_eq!(client.calculate_weight(&user, &0), 0);
+    assert_eq!(client.get_multiplier(&user), 10_000);
+    assert_eq!(client.get_total_staked(), 0);
+}
+
+// Tests staking again or unstaking twice during the cooldown.
+// Expects: StakeUnstaking error (Error #2402) for both.
+#[test]
+fn test_stake_while_unstaking() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    client.unstake(&user);
+
+    assert_eq!(
+        client.try_stake(&user, &1000),
+        Err(Ok(StakedVoteContractErrors::StakeUnstaking))
+    );
+    assert_eq!(
+        client.try_unstake(&user),
+        Err(Ok(StakedVoteContractErrors::StakeUnstaking))
+    );
+}
+
+// Tests claiming once the cooldown has ended.
+// Expects: Tokens return to the user and the stake is removed.
+#[test]
+fn test_claim() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    client.unstake(&user);
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp += DEFAULT_COOLDOWN);
+
+    assert_eq!(client.claim(&user), 1000);
+    assert_eq!(token.balance(&user), 10000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_get_stake(&user),
+        Err(Ok(StakedVoteContractErrors::StakeNotFound))
+    );
+}
+
+// Tests claiming before the cooldown has ended.
+// Expects: CooldownActive error (Error #2403).
+#[test]
+#[should_panic(expected = "Error(Contract, #2403)")]
+fn test_claim_before_cooldown() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    client.unstake(&user);
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp += DEFAULT_COOLDOWN - 1);
+    client.claim(&user);
+}
+
+// Tests claiming a stake that was never unstaked.
+// Expects: NotUnstaking error (Error #2404).
+#[test]
+#[should_panic(expected = "Error(Contract, #2404)")]
+fn test_claim_without_unstake() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.stake(&user, &1000);
+    client.claim(&user);
+}
+
+// Tests changing the cooldown.
+// Expects: Later unstakes use the new cooldown and values above 30 days fail with
+// InvalidConfig error (Error #15).
+#[test]
+fn test_set_cooldown() {
+    let e = setup_test_env();
+    let (client, _, user) = setup_staked_vote(&e);
+
+    client.set_cooldown(&0);
+    assert_eq!(client.get_cooldown(), 0);
+
+    client.stake(&user, &1000);
+    client.unstake(&user);
+    assert_eq!(client.claim(&user), 1000);
+
+    assert_eq!(
+        client.try_set_cooldown(&(MAX_COOLDOWN + 1)),
+        Err(Ok(StakedVoteContractErrors::InvalidConfig))
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_staked_vote(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2400 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        StakedVoteContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        StakedVoteContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        StakedVoteContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    assert_eq!(
+        StakedVoteContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    for error in [
+        StakedVoteContractErrors::StakeNotFound,
+        StakedVoteContractErrors::StakeUnstaking,
+        StakedVoteContractErrors::CooldownActive,
+        StakedVoteContractErrors::NotUnstaking,
+    ] {
+        assert!(in_range(error as u32, STAKED_VOTE_CODES));
+    }
+}
+
+proptest! {
+    // Tests the multiplier bounds for any staking duration.
+    // Expects: The multiplier stays between 1.0x and 2.0x.
+    #[test]
+    fn prop_multiplier_bounded(duration in any::<u64>()) {
+        let multiplier = duration_multiplier(duration);
+        prop_assert!(multiplier >= MULTIPLIER_SCALE);
+        prop_assert!(multiplier <= MULTIPLIER_SCALE + MAX_BOOST);
+    }
+
+    // Tests the multiplier ordering for any two staking durations.
+    // Expects: A longer stake never yields a smaller multiplier.
+    #[test]
+    fn prop_multiplier_monotonic(a in 0..=YEAR * 2, b in 0..=YEAR * 2) {
+        let (shorter, longer) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(duration_multiplier(shorter) <= duration_multiplier(longer));
+    }
+
+    // Tests the staked weight for any amount and staking duration.
+    // Expects: Weight is between the staked amount and twice the staked amount.
+    #[test]
+    fn prop_weight_bounded(amount in 0..=i64::MAX as i128, duration in any::<u64>()) {
+        let weight = staked_weight(amount, duration);
+        prop_assert!(weight >= amount);
+        prop_assert!(weight <= amount * 2);
+    }
+
+    // Tests negative amounts passed to the weight math.
+    // Expects: Weight is clamped to zero.
+    #[test]
+    fn prop_weight_non_negative(amount in i128::MIN..0, duration in any::<u64>()) {
+        prop_assert_eq!(staked_weight(amount, duration), 0);
+    }
+}
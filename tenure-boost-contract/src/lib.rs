@@ -0,0 +1,295 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Env, Symbol,
+    Vec,
+};
+
+pub use pg_contracts_types::tenure_boost::{TenureBoostCheckpoint, TenureBoostPoint};
+
+// --- Multiplier Scale ---
+const MULTIPLIER_SCALE: i128 = 10_000; // Basis points, 10_000 = 1.0x
+const MAX_MULTIPLIER: i128 = 50_000; // Highest multiplier a curve may reach (5.0x)
+
+// --- Curve Constraints ---
+const YEAR: u64 = 31_536_000; // 365 days in seconds
+const MAX_CURVE_POINTS: u32 = 8; // Most points a multiplier curve may have
+const DEFAULT_MAX_BOOST: i128 = 10_000; // Extra weight after a year of holding (2.0x total)
+
+// --- History Scan ---
+const MAX_CHECKPOINTS_SCANNED: u32 = 32; // Most checkpoints read per weight calculation
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 2] = [
+    governance_core::FEATURE_WEIGHT,
+    governance_core::FEATURE_SNAPSHOTS,
+];
+
+// Cross-contract interface of the checkpoint token whose vote history measures tenure
+#[contractclient(name = "CheckpointTokenClient")]
+pub trait CheckpointTokenInterface {
+    fn num_checkpoints(env: Env, account: Address) -> u32;
+    fn get_checkpoint(env: Env, account: Address, index: u32) -> Option<TenureBoostCheckpoint>;
+}
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum TenureBoostContractDataKey {
+    Admin, // Contract administrator address
+    Token, // Checkpoint token whose vote history is read
+    Curve, // Multiplier curve by tenure
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1800-1899 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TenureBoostContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidCurve = 1801,            // Curve must start at 0, rise in tenure, and stay in 1.0x-5.0x
+}
+
+// Returns the default curve, rising linearly from 1.0x for new tokens to 2.0x after a year
+pub fn default_curve(env: &Env) -> Vec<TenureBoostPoint> {
+    vec![
+        env,
+        TenureBoostPoint {
+            tenure: 0,
+            multiplier: MULTIPLIER_SCALE,
+        },
+        TenureBoostPoint {
+            tenure: YEAR,
+            multiplier: MULTIPLIER_SCALE + DEFAULT_MAX_BOOST,
+        },
+    ]
+}
+
+// Returns whether a curve has 1-8 points starting at tenure 0, with strictly increasing
+// tenures and non-decreasing multipliers between 1.0x and 5.0x
+pub fn is_valid_curve(curve: &Vec<TenureBoostPoint>) -> bool {
+    if curve.is_empty() || curve.len() > MAX_CURVE_POINTS || curve.get_unchecked(0).tenure != 0 {
+        return false;
+    }
+    let mut previous: Option<TenureBoostPoint> = None;
+    for point in curve.iter() {
+        if !(MULTIPLIER_SCALE..=MAX_MULTIPLIER).contains(&point.multiplier) {
+            return false;
+        }
+        if let Some(previous) = &previous {
+            if point.tenure <= previous.tenure || point.multiplier < previous.multiplier {
+                return false;
+            }
+        }
+        previous = Some(point);
+    }
+    true
+}
+
+// Returns the multiplier in basis points for a tenure, interpolating linearly between curve
+// points and holding the last point's multiplier beyond it
+pub fn curve_multiplier(curve: &Vec<TenureBoostPoint>, tenure: u64) -> i128 {
+    let mut previous = curve.get_unchecked(0);
+    for point in curve.iter().skip(1) {
+        if tenure < point.tenure {
+            let progress = (tenure - previous.tenure) as i128;
+            let span = (point.tenure - previous.tenure) as i128;
+            return previous.multiplier
+                + (point.multiplier - previous.multiplier) * progress / span;
+        }
+        previous = point;
+    }
+    previous.multiplier
+}
+
+#[contract]
+pub struct TenureBoostContract;
+
+#[contractimpl]
+impl TenureBoostContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, TenureBoostContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TenureBoostContractDataKey::Admin)
+            .ok_or(TenureBoostContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the checkpoint token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, TenureBoostContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TenureBoostContractDataKey::Token)
+            .ok_or(TenureBoostContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the number of checkpoints written strictly before a timestamp, matching the
+    // token's own snapshot lookups
+    fn checkpoints_before(client: &CheckpointTokenClient, user: &Address, timestamp: u64) -> u32 {
+        let mut low = 0;
+        let mut high = client.num_checkpoints(user);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match client.get_checkpoint(user, &middle) {
+                Some(checkpoint) if checkpoint.timestamp < timestamp => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        low
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and checkpoint token, starting from the default curve
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), TenureBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&TenureBoostContractDataKey::Admin)
+        {
+            return Err(TenureBoostContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&TenureBoostContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&TenureBoostContractDataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&TenureBoostContractDataKey::Curve, &default_curve(&env));
+        Ok(())
+    }
+
+    // Replaces the multiplier curve (admin only)
+    pub fn set_curve(
+        env: Env,
+        curve: Vec<TenureBoostPoint>,
+    ) -> Result<(), TenureBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if !is_valid_curve(&curve) {
+            return Err(TenureBoostContractErrors::InvalidCurve);
+        }
+        env.storage()
+            .instance()
+            .set(&TenureBoostContractDataKey::Curve, &curve);
+
+        env.events().publish(("CURVE", "UPDATED"), curve);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), TenureBoostContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&TenureBoostContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the user's votes at the snapshot, each token weighted by the curve multiplier for
+    // how long it has been held. Walking the checkpoints back from the snapshot, votes lost at
+    // an earlier checkpoint are only credited the time since that checkpoint, so every token
+    // counts the longest span it was held without a break. The walk stops once the curve's
+    // last tenure is reached or after 32 checkpoints, crediting what remains the tenure found
+    pub fn calculate_weight(env: Env, user: Address, snapshot_time: u64) -> i128 {
+        let (token, curve) = match (Self::read_token(&env), Self::get_curve(env.clone())) {
+            (Ok(token), Ok(curve)) => (token, curve),
+            _ => return 0,
+        };
+        let client = CheckpointTokenClient::new(&env, &token);
+        let max_tenure = curve.get_unchecked(curve.len() - 1).tenure;
+
+        let mut index = Self::checkpoints_before(&client, &user, snapshot_time);
+        let mut held: Option<i128> = None;
+        let mut since = snapshot_time;
+        let mut scanned = 0;
+        let mut boosted: i128 = 0;
+        while index > 0 && scanned < MAX_CHECKPOINTS_SCANNED {
+            index -= 1;
+            scanned += 1;
+            let checkpoint = match client.get_checkpoint(&user, &index) {
+                Some(checkpoint) => checkpoint,
+                None => break,
+            };
+            let value = checkpoint.value.max(0);
+            match held {
+                Some(amount) if value < amount => {
+                    let multiplier = curve_multiplier(&curve, snapshot_time - since);
+                    boosted = boosted.saturating_add((amount - value).saturating_mul(multiplier));
+                    held = Some(value);
+                }
+                Some(_) => {}
+                None => held = Some(value),
+            }
+            since = checkpoint.timestamp;
+            if held == Some(0) || snapshot_time - since >= max_tenure {
+                break;
+            }
+        }
+
+        let amount = held.unwrap_or(0);
+        let multiplier = curve_multiplier(&curve, snapshot_time - since);
+        boosted.saturating_add(amount.saturating_mul(multiplier)) / MULTIPLIER_SCALE
+    }
+
+    // Returns the multiplier in basis points the curve gives tokens held for a tenure
+    pub fn get_multiplier(env: Env, tenure: u64) -> Result<i128, TenureBoostContractErrors> {
+        Ok(curve_multiplier(&Self::get_curve(env)?, tenure))
+    }
+
+    // Returns the multiplier curve
+    pub fn get_curve(env: Env) -> Result<Vec<TenureBoostPoint>, TenureBoostContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TenureBoostContractDataKey::Curve)
+            .ok_or(TenureBoostContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the checkpoint token whose vote history is read
+    pub fn get_token(env: Env) -> Result<Address, TenureBoostContractErrors> {
+        Self::read_token(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
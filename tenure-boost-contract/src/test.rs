@@ -0,0 +1,306 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, TENURE_BOOST_CODES};
+use governance_testutils::{setup_test_env, TEST_START_TIME};
+use proptest::prelude::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+// Checkpoint token mock: stores vote checkpoints pushed by the test, oldest first
+#[contract]
+pub struct MockCheckpointToken;
+
+#[contractimpl]
+impl MockCheckpointToken {
+    pub fn push(env: Env, account: Address, timestamp: u64, value: i128) {
+        let mut checkpoints: Vec<TenureBoostCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&account)
+            .unwrap_or(Vec::new(&env));
+        checkpoints.push_back(TenureBoostCheckpoint { timestamp, value });
+        env.storage().persistent().set(&account, &checkpoints);
+    }
+
+    pub fn num_checkpoints(env: Env, account: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<Address, Vec<TenureBoostCheckpoint>>(&account)
+            .map_or(0, |checkpoints| checkpoints.len())
+    }
+
+    pub fn get_checkpoint(env: Env, account: Address, index: u32) -> Option<TenureBoostCheckpoint> {
+        env.storage()
+            .persistent()
+            .get::<Address, Vec<TenureBoostCheckpoint>>(&account)
+            .and_then(|checkpoints| checkpoints.get(index))
+    }
+}
+
+fn create_tenure_boost_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> TenureBoostContractClient<'a> {
+    let contract_address = e.register(
+        TenureBoostContract,
+        TenureBoostContractArgs::__constructor(admin, token_address),
+    );
+    TenureBoostContractClient::new(e, &contract_address)
+}
+
+// Deploys the checkpoint token mock and tenure-boost contract for a user without history
+fn setup_tenure_boost<'a>(
+    e: &Env,
+) -> (
+    TenureBoostContractClient<'a>,
+    MockCheckpointTokenClient<'a>,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let user = Address::generate(e);
+    let token = MockCheckpointTokenClient::new(e, &e.register(MockCheckpointToken, ()));
+    let client = create_tenure_boost_contract(e, &admin, &token.address);
+    (client, token, user)
+}
+
+// Tests successful initialization with admin and checkpoint token.
+// Expects: The default curve runs from 1.0x for new tokens to 2.0x after a year.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(client.get_curve(), default_curve(&e));
+    assert_eq!(client.get_multiplier(&0), 10_000);
+    assert_eq!(client.get_multiplier(&(YEAR / 2)), 15_000);
+    assert_eq!(client.get_multiplier(&(YEAR * 3)), 20_000);
+    assert_eq!(client.calculate_weight(&user, &TEST_START_TIME), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    e.register_at(
+        &client.address,
+        TenureBoostContract,
+        TenureBoostContractArgs::__constructor(&user, &token.address),
+    );
+}
+
+// Tests a holder that kept the same votes since its first checkpoint.
+// Expects: The weight grows from 1.0x to 2.0x over a year and stays there.
+#[test]
+fn test_long_term_holder() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    token.push(&user, &TEST_START_TIME, &1000);
+
+    assert_eq!(client.calculate_weight(&user, &(TEST_START_TIME + 1)), 1000);
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR / 2)),
+        1500
+    );
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR)),
+        2000
+    );
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + 2 * YEAR)),
+        2000
+    );
+}
+
+// Tests a holder that added votes half a year after its first deposit.
+// Expects: The original 1000 count 2.0x and the later 500 count 1.5x after a year.
+#[test]
+fn test_added_votes_have_shorter_tenure() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    token.push(&user, &TEST_START_TIME, &1000);
+    token.push(&user, &(TEST_START_TIME + YEAR / 2), &1500);
+
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR)),
+        2000 + 750
+    );
+}
+
+// Tests a holder that sold most of its votes and bought them back.
+// Expects: Only the 400 held throughout keep their tenure, the 600 restart from the rebuy.
+#[test]
+fn test_tenure_resets_after_selling() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    token.push(&user, &TEST_START_TIME, &1000);
+    token.push(&user, &(TEST_START_TIME + YEAR / 2), &400);
+    token.push(&user, &(TEST_START_TIME + 3 * YEAR / 4), &1000);
+
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR)),
+        800 + 750
+    );
+}
+
+// Tests checkpoints written at or after the snapshot time.
+// Expects: They are ignored, matching the token's start-of-timestamp snapshots.
+#[test]
+fn test_snapshot_ignores_later_checkpoints() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    token.push(&user, &TEST_START_TIME, &1000);
+    token.push(&user, &(TEST_START_TIME + YEAR), &5000);
+    token.push(&user, &(TEST_START_TIME + YEAR + DAY), &0);
+
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR)),
+        2000
+    );
+    assert_eq!(client.calculate_weight(&user, &TEST_START_TIME), 0);
+}
+
+// Tests a history longer than the scan limit without any change in votes.
+// Expects: The walk stops after 32 checkpoints and credits the tenure it reached.
+#[test]
+fn test_checkpoint_scan_limit() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    for day in 0..40 {
+        token.push(&user, &(TEST_START_TIME + day * DAY), &1000);
+    }
+
+    let snapshot_time = TEST_START_TIME + 40 * DAY;
+    let multiplier = curve_multiplier(&default_curve(&e), 32 * DAY);
+    assert_eq!(
+        client.calculate_weight(&user, &snapshot_time),
+        1000 * multiplier / 10_000
+    );
+}
+
+// Tests replacing the curve with a steeper, stepped one.
+// Expects: Multipliers interpolate between the new points and weights follow them.
+#[test]
+fn test_set_curve() {
+    let e = setup_test_env();
+    let (client, token, user) = setup_tenure_boost(&e);
+
+    let curve = vec![
+        &e,
+        TenureBoostPoint {
+            tenure: 0,
+            multiplier: 10_000,
+        },
+        TenureBoostPoint {
+            tenure: 30 * DAY,
+            multiplier: 15_000,
+        },
+        TenureBoostPoint {
+            tenure: YEAR,
+            multiplier: 30_000,
+        },
+    ];
+    client.set_curve(&curve);
+
+    assert_eq!(client.get_curve(), curve);
+    assert_eq!(client.get_multiplier(&(15 * DAY)), 12_500);
+    assert_eq!(client.get_multiplier(&(30 * DAY)), 15_000);
+    assert_eq!(client.get_multiplier(&(2 * YEAR)), 30_000);
+
+    token.push(&user, &TEST_START_TIME, &1000);
+    assert_eq!(
+        client.calculate_weight(&user, &(TEST_START_TIME + YEAR)),
+        3000
+    );
+}
+
+// Tests curves that do not start at 0, go back in tenure, fall, or leave 1.0x-5.0x.
+// Expects: InvalidCurve error (Error #1801) and the previous curve stays in place.
+#[test]
+fn test_set_curve_invalid() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_tenure_boost(&e);
+
+    let point = |tenure: u64, multiplier: i128| TenureBoostPoint { tenure, multiplier };
+    for curve in [
+        Vec::new(&e),
+        vec![&e, point(DAY, 10_000)],
+        vec![&e, point(0, 9_999)],
+        vec![&e, point(0, 10_000), point(YEAR, 50_001)],
+        vec![&e, point(0, 10_000), point(0, 20_000)],
+        vec![&e, point(0, 15_000), point(YEAR, 12_000)],
+    ] {
+        assert_eq!(
+            client.try_set_curve(&curve),
+            Err(Ok(TenureBoostContractErrors::InvalidCurve))
+        );
+    }
+    assert_eq!(client.get_curve(), default_curve(&e));
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with weight and snapshots supported and others not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_tenure_boost(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1800 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        TenureBoostContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        TenureBoostContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert!(in_range(
+        TenureBoostContractErrors::InvalidCurve as u32,
+        TENURE_BOOST_CODES
+    ));
+}
+
+proptest! {
+    // Tests the default curve multiplier for any tenure.
+    // Expects: The multiplier stays between 1.0x and 2.0x.
+    #[test]
+    fn prop_multiplier_bounded(tenure in any::<u64>()) {
+        let e = Env::default();
+        let multiplier = curve_multiplier(&default_curve(&e), tenure);
+        prop_assert!(multiplier >= MULTIPLIER_SCALE);
+        prop_assert!(multiplier <= MULTIPLIER_SCALE + DEFAULT_MAX_BOOST);
+    }
+
+    // Tests the default curve ordering for any two tenures.
+    // Expects: Holding longer never yields a smaller multiplier.
+    #[test]
+    fn prop_multiplier_monotonic(a in 0..=YEAR * 2, b in 0..=YEAR * 2) {
+        let e = Env::default();
+        let curve = default_curve(&e);
+        let (shorter, longer) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(curve_multiplier(&curve, shorter) <= curve_multiplier(&curve, longer));
+    }
+}
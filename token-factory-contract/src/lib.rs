@@ -0,0 +1,264 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, BytesN, Env,
+    String, Symbol, Vec,
+};
+
+pub use pg_contracts_types::token_factory::TokenFactoryToken;
+
+// --- Registry Constraints ---
+const MAX_TOKENS: u32 = 500; // Maximum tokens recorded in the registry
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REGISTRY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_DEPLOYMENT];
+
+// Interface of the governance token deployed by the factory
+#[contractclient(name = "GovernanceTokenClient")]
+pub trait GovernanceTokenInterface {
+    fn mint(env: Env, to: Address, amount: i128);
+    fn set_admin(env: Env, new_admin: Address);
+}
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum TokenFactoryContractDataKey {
+    Admin,          // Contract administrator address
+    TokenWasm,      // WASM hash of the governance token contract
+    Tokens,         // Addresses of all deployed tokens in deployment order
+    Token(Address), // Registry record per deployed token
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 900-999 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenFactoryContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidSupply = 903,            // Initial supply must be greater than zero
+    InvalidMetadata = 904,          // Token name and symbol must not be empty
+    TokenNotFound = 905,            // The token was not deployed by this factory
+    RegistryFull = 906,             // The registry has reached its token limit
+}
+
+#[contract]
+pub struct TokenFactoryContract;
+
+#[contractimpl]
+impl TokenFactoryContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, TokenFactoryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenFactoryContractDataKey::Admin)
+            .ok_or(TokenFactoryContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the addresses of all deployed tokens
+    fn read_tokens(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&TokenFactoryContractDataKey::Tokens)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // Saves the addresses of all deployed tokens and extends their TTL
+    fn write_tokens(env: &Env, tokens: &Vec<Address>) {
+        let tokens_key = TokenFactoryContractDataKey::Tokens;
+        env.storage().persistent().set(&tokens_key, tokens);
+        env.storage().persistent().extend_ttl(
+            &tokens_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+    }
+
+    // Derives a unique deployment salt from the registry position
+    fn deployment_salt(env: &Env, index: u32) -> BytesN<32> {
+        let mut salt = [0u8; 32];
+        salt[28..].copy_from_slice(&index.to_be_bytes());
+        BytesN::from_array(env, &salt)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and governance token WASM hash
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token_wasm: BytesN<32>,
+    ) -> Result<(), TokenFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&TokenFactoryContractDataKey::Admin)
+        {
+            return Err(TokenFactoryContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&TokenFactoryContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&TokenFactoryContractDataKey::TokenWasm, &token_wasm);
+        Ok(())
+    }
+
+    // Deploys a governance token, mints its supply to the token admin, and records it (admin only)
+    pub fn deploy_token(
+        env: Env,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        supply: i128,
+        token_admin: Address,
+    ) -> Result<Address, TokenFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if name.is_empty() || symbol.is_empty() {
+            return Err(TokenFactoryContractErrors::InvalidMetadata);
+        }
+        if supply <= 0 {
+            return Err(TokenFactoryContractErrors::InvalidSupply);
+        }
+        let mut tokens = Self::read_tokens(&env);
+        if tokens.len() >= MAX_TOKENS {
+            return Err(TokenFactoryContractErrors::RegistryFull);
+        }
+
+        // The factory deploys itself as token admin so it can mint, then hands the role over
+        let token_wasm = Self::get_token_wasm(env.clone())?;
+        let factory = env.current_contract_address();
+        let token_address = env
+            .deployer()
+            .with_current_contract(Self::deployment_salt(&env, tokens.len()))
+            .deploy_v2(
+                token_wasm,
+                (factory, decimals, name.clone(), symbol.clone()),
+            );
+        let token_client = GovernanceTokenClient::new(&env, &token_address);
+        token_client.mint(&token_admin, &supply);
+        token_client.set_admin(&token_admin);
+
+        let record = TokenFactoryToken {
+            name,
+            symbol,
+            decimals,
+            supply,
+            admin: token_admin,
+            deployed_at: env.ledger().timestamp(),
+        };
+        let token_key = TokenFactoryContractDataKey::Token(token_address.clone());
+        env.storage().persistent().set(&token_key, &record);
+        env.storage().persistent().extend_ttl(
+            &token_key,
+            REGISTRY_TTL_EXTENSION,
+            REGISTRY_TTL_EXTENSION,
+        );
+        tokens.push_back(token_address.clone());
+        Self::write_tokens(&env, &tokens);
+
+        env.events().publish(
+            ("TOKEN", "DEPLOYED", token_address.clone()),
+            (record.admin, record.symbol, supply),
+        );
+        Ok(token_address)
+    }
+
+    // Updates the governance token WASM hash used for future deployments (admin only)
+    pub fn set_token_wasm(
+        env: Env,
+        token_wasm: BytesN<32>,
+    ) -> Result<(), TokenFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&TokenFactoryContractDataKey::TokenWasm, &token_wasm);
+
+        env.events().publish(("TOKEN_WASM", "UPDATED"), token_wasm);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), TokenFactoryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&TokenFactoryContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the addresses of all deployed tokens in deployment order
+    pub fn get_tokens(env: Env) -> Vec<Address> {
+        Self::read_tokens(&env)
+    }
+
+    // Returns the registry record of a deployed token
+    pub fn get_token(
+        env: Env,
+        token: Address,
+    ) -> Result<TokenFactoryToken, TokenFactoryContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&TokenFactoryContractDataKey::Token(token))
+            .ok_or(TokenFactoryContractErrors::TokenNotFound)
+    }
+
+    // Returns the number of deployed tokens
+    pub fn token_count(env: Env) -> u32 {
+        Self::read_tokens(&env).len()
+    }
+
+    // Returns the governance token WASM hash used for deployments
+    pub fn get_token_wasm(env: Env) -> Result<BytesN<32>, TokenFactoryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenFactoryContractDataKey::TokenWasm)
+            .ok_or(TokenFactoryContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,232 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, TOKEN_FACTORY_CODES};
+use governance_testutils::{register_deployable, setup_test_env};
+use governance_token_contract::{GovernanceTokenContract, GovernanceTokenContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+
+const DEPLOYABLE_TOKENS: u32 = 2; // Native governance tokens registered ahead of deployment
+
+fn create_factory_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_wasm: &BytesN<32>,
+) -> TokenFactoryContractClient<'a> {
+    let contract_address = e.register(
+        TokenFactoryContract,
+        TokenFactoryContractArgs::__constructor(admin, token_wasm),
+    );
+    TokenFactoryContractClient::new(e, &contract_address)
+}
+
+// Deploys a factory and registers the native governance token at its first deployment addresses,
+// returning the factory admin
+fn setup_factory<'a>(e: &Env) -> (TokenFactoryContractClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let token_wasm = e.deployer().upload_contract_wasm(Bytes::new(e));
+    let client = create_factory_contract(e, &admin, &token_wasm);
+    for index in 0..DEPLOYABLE_TOKENS {
+        register_deployable(
+            e,
+            &client.address,
+            &TokenFactoryContract::deployment_salt(e, index),
+            GovernanceTokenContract,
+            (
+                client.address.clone(),
+                7_u32,
+                String::from_str(e, "Play Governance"),
+                String::from_str(e, "PLAY"),
+            ),
+        );
+    }
+    (client, admin)
+}
+
+// Tests successful initialization with admin and token WASM hash.
+// Expects: The WASM hash matches the registered token and the registry is empty.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+
+    assert_eq!(
+        client.get_token_wasm(),
+        e.deployer().upload_contract_wasm(Bytes::new(&e))
+    );
+    assert_eq!(client.token_count(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, admin) = setup_factory(&e);
+
+    e.register_at(
+        &client.address,
+        TokenFactoryContract,
+        TokenFactoryContractArgs::__constructor(&admin, &client.get_token_wasm()),
+    );
+}
+
+// Tests deploying a governance token through the factory.
+// Expects: The token carries the requested metadata, supply, and admin, and is registered.
+#[test]
+fn test_deploy_token() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+    let token_admin = Address::generate(&e);
+
+    let token_address = client.deploy_token(
+        &String::from_str(&e, "Experiment One"),
+        &String::from_str(&e, "EXP1"),
+        &7,
+        &1_000_000,
+        &token_admin,
+    );
+
+    let token = GovernanceTokenContractClient::new(&e, &token_address);
+    assert_eq!(token.name(), String::from_str(&e, "Experiment One"));
+    assert_eq!(token.symbol(), String::from_str(&e, "EXP1"));
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.total_supply(), 1_000_000);
+    assert_eq!(token.balance(&token_admin), 1_000_000);
+    assert_eq!(token.admin(), token_admin);
+
+    let record = client.get_token(&token_address);
+    assert_eq!(record.supply, 1_000_000);
+    assert_eq!(record.admin, token_admin);
+    assert_eq!(record.deployed_at, 1000000);
+    assert_eq!(client.get_tokens(), vec![&e, token_address]);
+}
+
+// Tests deploying several tokens from the same factory.
+// Expects: Each deployment gets a distinct address recorded in deployment order.
+#[test]
+fn test_deploy_multiple_tokens() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+    let token_admin = Address::generate(&e);
+
+    let first = client.deploy_token(
+        &String::from_str(&e, "Experiment One"),
+        &String::from_str(&e, "EXP1"),
+        &7,
+        &1000,
+        &token_admin,
+    );
+    let second = client.deploy_token(
+        &String::from_str(&e, "Experiment Two"),
+        &String::from_str(&e, "EXP2"),
+        &0,
+        &50,
+        &token_admin,
+    );
+
+    assert_ne!(first, second);
+    assert_eq!(client.token_count(), 2);
+    assert_eq!(client.get_tokens(), vec![&e, first, second.clone()]);
+    assert_eq!(
+        client.get_token(&second).symbol,
+        String::from_str(&e, "EXP2")
+    );
+}
+
+// Tests deploying a token with zero initial supply.
+// Expects: InvalidSupply error (Error #903).
+#[test]
+#[should_panic(expected = "Error(Contract, #903)")]
+fn test_deploy_invalid_supply() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+
+    client.deploy_token(
+        &String::from_str(&e, "Experiment One"),
+        &String::from_str(&e, "EXP1"),
+        &7,
+        &0,
+        &Address::generate(&e),
+    );
+}
+
+// Tests deploying a token with an empty symbol.
+// Expects: InvalidMetadata error (Error #904).
+#[test]
+#[should_panic(expected = "Error(Contract, #904)")]
+fn test_deploy_invalid_metadata() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+
+    client.deploy_token(
+        &String::from_str(&e, "Experiment One"),
+        &String::from_str(&e, ""),
+        &7,
+        &1000,
+        &Address::generate(&e),
+    );
+}
+
+// Tests querying a token that was not deployed by the factory.
+// Expects: TokenNotFound error (Error #905).
+#[test]
+#[should_panic(expected = "Error(Contract, #905)")]
+fn test_get_unknown_token() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+
+    client.get_token(&Address::generate(&e));
+}
+
+// Tests transferring the admin role.
+// Expects: The new admin is stored and can update the token WASM hash.
+#[test]
+fn test_transfer_admin() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+    let new_admin = Address::generate(&e);
+    let new_wasm = BytesN::from_array(&e, &[7; 32]);
+
+    client.transfer_admin(&new_admin);
+    client.set_token_wasm(&new_wasm);
+
+    assert_eq!(client.get_token_wasm(), new_wasm);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with deployment supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _) = setup_factory(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_DEPLOYMENT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 900 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        TokenFactoryContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        TokenFactoryContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        TokenFactoryContractErrors::InvalidSupply,
+        TokenFactoryContractErrors::InvalidMetadata,
+        TokenFactoryContractErrors::TokenNotFound,
+        TokenFactoryContractErrors::RegistryFull,
+    ] {
+        assert!(in_range(error as u32, TOKEN_FACTORY_CODES));
+    }
+}
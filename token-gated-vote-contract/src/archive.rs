@@ -0,0 +1,53 @@
+use soroban_sdk::{xdr::ToXdr, BytesN, Env, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteProposalData,
+};
+
+// Computes the SHA-256 digest of a proposal's XDR encoding
+pub(crate) fn digest(env: &Env, proposal: &TokenGatedVoteProposalData) -> BytesN<32> {
+    env.crypto()
+        .sha256(&proposal.clone().to_xdr(env))
+        .to_bytes()
+}
+
+// Loads the digest recorded when a proposal was last written
+pub(crate) fn read_digest(env: &Env, id: &Symbol) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ProposalDigest(id.clone()))
+}
+
+// Records the digest of a proposal's latest state for the longest TTL the network allows, so it
+// outlives the proposal entry and can authenticate a later restore
+pub(crate) fn record(env: &Env, id: &Symbol, proposal: &TokenGatedVoteProposalData) {
+    let digest_key = TokenGatedVoteContractDataKey::ProposalDigest(id.clone());
+    env.storage()
+        .persistent()
+        .set(&digest_key, &digest(env, proposal));
+    extend(env, id);
+}
+
+// Extends the digest of a proposal, if one is recorded, to the longest TTL the network allows
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let digest_key = TokenGatedVoteContractDataKey::ProposalDigest(id.clone());
+    if env.storage().persistent().has(&digest_key) {
+        let max_ttl = env.storage().max_ttl();
+        env.storage()
+            .persistent()
+            .extend_ttl(&digest_key, max_ttl, max_ttl);
+    }
+}
+
+// Checks a proposal supplied for re-hydration against the digest recorded for its ID
+pub(crate) fn verify(
+    env: &Env,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    let recorded = read_digest(env, id).ok_or(TokenGatedVoteContractErrors::ProposalNotFound)?;
+    if digest(env, proposal) != recorded {
+        return Err(TokenGatedVoteContractErrors::ProposalDigestMismatch);
+    }
+    Ok(())
+}
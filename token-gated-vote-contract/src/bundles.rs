@@ -0,0 +1,131 @@
+use governance_core::events;
+use soroban_sdk::{contracttype, Env, Error, Symbol, Vec};
+
+use crate::lifecycle;
+use crate::{
+    TokenGatedVoteBundle, TokenGatedVoteBundleItem, TokenGatedVoteContractDataKey,
+    TokenGatedVoteContractErrors, TokenGatedVoteModuleErrors, TokenGatedVoteProposalData,
+};
+
+// Defines the bundle storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum BundleKey {
+    Bundle(Symbol), // Proposals that pass or fail together, keyed by bundle ID
+    Member(Symbol), // Bundle each bundled proposal belongs to
+}
+
+// --- Bundle Constraints ---
+pub(crate) const MIN_BUNDLE_SIZE: u32 = 2; // Fewest proposals a bundle can hold
+pub(crate) const MAX_BUNDLE_SIZE: u32 = 10; // Most proposals a bundle can hold
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const BUNDLE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads a bundle, if any
+pub(crate) fn read(env: &Env, bundle_id: &Symbol) -> Option<TokenGatedVoteBundle> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Bundle(BundleKey::Bundle(
+            bundle_id.clone(),
+        )))
+}
+
+// Loads the bundle a proposal belongs to, if any
+pub(crate) fn read_bundle_of(env: &Env, id: &Symbol) -> Option<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Bundle(BundleKey::Member(
+            id.clone(),
+        )))
+}
+
+// Persists a bundle and extends its TTL
+fn write(env: &Env, bundle_id: &Symbol, bundle: &TokenGatedVoteBundle) {
+    let bundle_key = TokenGatedVoteContractDataKey::Bundle(BundleKey::Bundle(bundle_id.clone()));
+    env.storage().persistent().set(&bundle_key, bundle);
+    env.storage()
+        .persistent()
+        .extend_ttl(&bundle_key, BUNDLE_TTL_EXTENSION, BUNDLE_TTL_EXTENSION);
+}
+
+// Rejects taken bundle IDs and bundles too small or too large to hold together
+pub(crate) fn validate(
+    env: &Env,
+    bundle_id: &Symbol,
+    items: &Vec<TokenGatedVoteBundleItem>,
+) -> Result<(), Error> {
+    if read(env, bundle_id).is_some() {
+        return Err(TokenGatedVoteModuleErrors::BundleAlreadyExists.into());
+    }
+    if items.len() < MIN_BUNDLE_SIZE || items.len() > MAX_BUNDLE_SIZE {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig.into());
+    }
+    Ok(())
+}
+
+// Stores a bundle over its freshly created members and links each member back to it
+pub(crate) fn record(env: &Env, bundle_id: &Symbol, items: &Vec<TokenGatedVoteBundleItem>) {
+    let mut proposals = Vec::new(env);
+    for item in items.iter() {
+        let member_key = TokenGatedVoteContractDataKey::Bundle(BundleKey::Member(item.id.clone()));
+        env.storage().persistent().set(&member_key, bundle_id);
+        env.storage().persistent().extend_ttl(
+            &member_key,
+            BUNDLE_TTL_EXTENSION,
+            BUNDLE_TTL_EXTENSION,
+        );
+        proposals.push_back(item.id);
+    }
+    let bundle = TokenGatedVoteBundle {
+        proposals,
+        created_at: env.ledger().timestamp(),
+        finalized: false,
+        passed: false,
+    };
+    write(env, bundle_id, &bundle);
+
+    events::publish(
+        env,
+        "BUNDLE",
+        events::CREATED,
+        bundle_id.clone(),
+        bundle.proposals,
+    );
+}
+
+// Records the joint outcome once every open member of a bundle has been finalized
+pub(crate) fn finalize(
+    env: &Env,
+    bundle_id: &Symbol,
+    mut bundle: TokenGatedVoteBundle,
+    passed: bool,
+) {
+    bundle.finalized = true;
+    bundle.passed = passed;
+    write(env, bundle_id, &bundle);
+
+    events::publish(env, "BUNDLE", events::FINALIZED, bundle_id.clone(), passed);
+}
+
+// Fails unless every member of the proposal's bundle passed and still stands, so a member
+// vetoed or expired after finalization also blocks its siblings; proposals outside a bundle
+// always pass
+pub(crate) fn require_passed(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(bundle) = read_bundle_of(env, id).and_then(|bundle_id| read(env, &bundle_id)) else {
+        return Ok(());
+    };
+    if !bundle.passed {
+        return Err(TokenGatedVoteContractErrors::BundleFailed);
+    }
+    for member in bundle.proposals.iter() {
+        let proposal: Option<TokenGatedVoteProposalData> = env
+            .storage()
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposal(member));
+        if !proposal.is_some_and(|proposal| lifecycle::has_passed(proposal.status)) {
+            return Err(TokenGatedVoteContractErrors::BundleFailed);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use governance_core::{events, THRESHOLD_SCALE};
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::{
+    config, quorum, TokenGatedVoteCategoryRules, TokenGatedVoteContractDataKey,
+    TokenGatedVoteContractErrors, TokenGatedVoteGovernanceConfig, TokenGatedVoteProposerRule,
+};
+
+// Defines the proposal category storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum CategoryKey {
+    Categories,    // Proposal categories with their own rules
+    Rules(Symbol), // Duration, quorum, threshold, and proposer rules per category
+}
+
+// --- Category Constraints ---
+pub(crate) const DEFAULT_CATEGORY: Symbol = symbol_short!("GENERAL"); // Category of plain proposals
+pub(crate) const MAX_CATEGORIES: u32 = 20; // Most categories the rules table can hold
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const CATEGORY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the configured categories, in the order they were added
+pub(crate) fn read_categories(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Category(
+            CategoryKey::Categories,
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Loads the rules configured for a category, if any
+pub(crate) fn read_rules(env: &Env, category: &Symbol) -> Option<TokenGatedVoteCategoryRules> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Category(
+            CategoryKey::Rules(category.clone()),
+        ))
+}
+
+// Resolves the rules a new proposal in the category follows; the default category falls back
+// to the governance configuration and the current quorum, without a minimum voter count, when
+// it has no rules of its own
+pub(crate) fn resolve(
+    env: &Env,
+    category: &Symbol,
+    config: &TokenGatedVoteGovernanceConfig,
+) -> Result<TokenGatedVoteCategoryRules, TokenGatedVoteContractErrors> {
+    if let Some(rules) = read_rules(env, category) {
+        return Ok(rules);
+    }
+    if *category != DEFAULT_CATEGORY {
+        return Err(TokenGatedVoteContractErrors::CategoryNotFound);
+    }
+    Ok(TokenGatedVoteCategoryRules {
+        min_duration: config.min_duration,
+        max_duration: config.max_duration,
+        quorum: quorum::current(env, config),
+        min_voters: 0,
+        threshold: config.threshold,
+        proposers: TokenGatedVoteProposerRule::Holders,
+    })
+}
+
+// Validates the rules of a category against the configuration bounds
+fn validate(rules: &TokenGatedVoteCategoryRules) -> Result<(), TokenGatedVoteContractErrors> {
+    if rules.min_duration == 0
+        || rules.min_duration > rules.max_duration
+        || rules.max_duration > config::DURATION_CEILING
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    if rules.quorum < 0 {
+        return Err(TokenGatedVoteContractErrors::InvalidQuorum);
+    }
+    if rules.threshold < config::MIN_THRESHOLD || rules.threshold >= THRESHOLD_SCALE {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    Ok(())
+}
+
+// Adds a category or replaces its rules; proposals already created keep their snapshot
+pub(crate) fn set(
+    env: &Env,
+    category: &Symbol,
+    rules: &TokenGatedVoteCategoryRules,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    validate(rules)?;
+    let mut categories = read_categories(env);
+    if !categories.contains(category) {
+        if categories.len() >= MAX_CATEGORIES {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig);
+        }
+        categories.push_back(category.clone());
+        env.storage().instance().set(
+            &TokenGatedVoteContractDataKey::Category(CategoryKey::Categories),
+            &categories,
+        );
+    }
+
+    let rules_key = TokenGatedVoteContractDataKey::Category(CategoryKey::Rules(category.clone()));
+    env.storage().persistent().set(&rules_key, rules);
+    env.storage().persistent().extend_ttl(
+        &rules_key,
+        CATEGORY_TTL_EXTENSION,
+        CATEGORY_TTL_EXTENSION,
+    );
+
+    events::publish(env, "CATEGORY", "UPDATED", category.clone(), rules.clone());
+    Ok(())
+}
+
+// Removes a category from the rules table; the default category reverts to the configuration
+pub(crate) fn remove(env: &Env, category: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let mut categories = read_categories(env);
+    let Some(position) = categories.first_index_of(category) else {
+        return Err(TokenGatedVoteContractErrors::CategoryNotFound);
+    };
+    categories.remove(position);
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Category(CategoryKey::Categories),
+        &categories,
+    );
+    env.storage()
+        .persistent()
+        .remove(&TokenGatedVoteContractDataKey::Category(
+            CategoryKey::Rules(category.clone()),
+        ));
+
+    events::publish(env, "CATEGORY", "REMOVED", category.clone(), ());
+    Ok(())
+}
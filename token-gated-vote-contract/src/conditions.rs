@@ -0,0 +1,104 @@
+use governance_core::{events, VotingWindow};
+use soroban_sdk::{contractclient, Env, Error, Symbol, Vec};
+
+use crate::{
+    timing, treasury, TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors,
+    TokenGatedVoteExecutionCondition, TokenGatedVoteModuleErrors, TokenGatedVotePriceAsset,
+    TokenGatedVotePriceComparison, TokenGatedVotePriceData, TokenGatedVoteProposalData,
+    TokenGatedVoteProposalStatus,
+};
+
+// --- Execution Condition Bounds ---
+pub(crate) const MAX_EXECUTION_CONDITIONS: u32 = 5; // Most price conditions a proposal can carry
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const CONDITIONS_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Cross-contract interface of a SEP-40 price oracle read when executing proposals
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleInterface {
+    fn lastprice(env: Env, asset: TokenGatedVotePriceAsset) -> Option<TokenGatedVotePriceData>;
+}
+
+// Loads the execution conditions of a proposal, empty when it has none
+pub(crate) fn read_conditions(env: &Env, id: &Symbol) -> Vec<TokenGatedVoteExecutionCondition> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ExecutionConditions(
+            id.clone(),
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Replaces the execution conditions of a treasury proposal whose voting window has not opened,
+// removing the entry when the list is empty
+pub(crate) fn set(
+    env: &Env,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+    conditions: &Vec<TokenGatedVoteExecutionCondition>,
+) -> Result<(), Error> {
+    if treasury::read_action(env, id).is_none() {
+        return Err(TokenGatedVoteModuleErrors::NotTreasuryProposal.into());
+    }
+    if !matches!(
+        proposal.status,
+        TokenGatedVoteProposalStatus::Draft | TokenGatedVoteProposalStatus::Pending
+    ) || timing::window(env, proposal) != VotingWindow::Pending
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidStateTransition.into());
+    }
+    if conditions.len() > MAX_EXECUTION_CONDITIONS {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig.into());
+    }
+    for condition in conditions.iter() {
+        if condition.price <= 0 {
+            return Err(TokenGatedVoteContractErrors::InvalidAmount.into());
+        }
+        if condition.max_age == 0 {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig.into());
+        }
+    }
+
+    let conditions_key = TokenGatedVoteContractDataKey::ExecutionConditions(id.clone());
+    if conditions.is_empty() {
+        env.storage().persistent().remove(&conditions_key);
+    } else {
+        env.storage().persistent().set(&conditions_key, conditions);
+        env.storage().persistent().extend_ttl(
+            &conditions_key,
+            CONDITIONS_TTL_EXTENSION,
+            CONDITIONS_TTL_EXTENSION,
+        );
+    }
+    events::publish(env, "CONDITIONS", "SET", id.clone(), conditions.len());
+    Ok(())
+}
+
+// Returns whether the oracle reports a fresh price satisfying the condition; a failing oracle
+// call, a missing price, or one older than `max_age` never satisfies it
+fn evaluate(env: &Env, condition: &TokenGatedVoteExecutionCondition) -> bool {
+    let Ok(Ok(Some(data))) =
+        PriceOracleClient::new(env, &condition.oracle).try_lastprice(&condition.asset)
+    else {
+        return false;
+    };
+    if env.ledger().timestamp().saturating_sub(data.timestamp) > condition.max_age {
+        return false;
+    }
+    match condition.comparison {
+        TokenGatedVotePriceComparison::AtLeast => data.price >= condition.price,
+        TokenGatedVotePriceComparison::AtMost => data.price <= condition.price,
+    }
+}
+
+// Fails unless every execution condition of the proposal currently holds; the proposal stays
+// executable so execution can be retried until its window closes
+pub(crate) fn require_met(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    for condition in read_conditions(env, id).iter() {
+        if !evaluate(env, &condition) {
+            return Err(TokenGatedVoteContractErrors::ExecutionConditionFailed);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,375 @@
+use governance_core::{events, THRESHOLD_SCALE};
+use soroban_sdk::{Env, IntoVal, Symbol, Val, Vec};
+
+use crate::{
+    ratelimit, sponsors, treasury, TokenGatedVoteConfigChange, TokenGatedVoteConfigUpdate,
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteFeeSink,
+    TokenGatedVoteGovernanceConfig, TokenGatedVoteTimingMode, MAX_PROPOSAL_DURATION,
+    MIN_PROPOSAL_DURATION,
+};
+
+// --- Configuration Bounds ---
+pub(crate) const MIN_THRESHOLD: u32 = 5_000; // Passing requires at least a simple FOR majority
+pub(crate) const DURATION_CEILING: u64 = 2_592_000; // ~30 days, upper bound for max_duration
+const DEFAULT_SPENDING_PERIOD: u64 = 2_592_000; // ~30 days rolling treasury spending window
+const DEFAULT_PROPOSAL_PERIOD: u64 = 2_592_000; // ~30 days rolling proposer cap window
+const DEFAULT_MAX_DESCRIPTION_LENGTH: u32 = 1_000; // Bytes of description stored per proposal
+pub(crate) const DESCRIPTION_CEILING: u32 = 4_096; // Upper bound for max_description_length
+pub(crate) const GRACE_PERIOD_CEILING: u64 = 604_800; // ~7 days, upper bound for grace_period
+pub(crate) const MAX_CONFIG_CHANGES: u32 = 23; // Field changes a config change proposal can carry
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const CONFIG_CHANGE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the governance configuration, defaulting to the built-in tunables
+pub(crate) fn read(env: &Env) -> TokenGatedVoteGovernanceConfig {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Config)
+        .unwrap_or(TokenGatedVoteGovernanceConfig {
+            version: 0,
+            min_duration: MIN_PROPOSAL_DURATION,
+            max_duration: MAX_PROPOSAL_DURATION,
+            quorum: 0,
+            threshold: MIN_THRESHOLD,
+            dispute_bond: 0,
+            proposal_fee: 0,
+            fee_sink: TokenGatedVoteFeeSink::Burn,
+            min_balance: 1,
+            proposal_threshold: 0,
+            open_proposing: false,
+            sponsors_required: 0,
+            spending_cap: 0,
+            spending_period: DEFAULT_SPENDING_PERIOD,
+            vote_lock: false,
+            vote_burn: 0,
+            sequential_ids: false,
+            max_description_length: DEFAULT_MAX_DESCRIPTION_LENGTH,
+            timing_mode: TokenGatedVoteTimingMode::Timestamp,
+            grace_period: 0,
+            proposer_cooldown: 0,
+            proposals_per_period: 0,
+            proposal_period: DEFAULT_PROPOSAL_PERIOD,
+            max_active_proposals: 0,
+            paused: false,
+        })
+}
+
+// Validates every field of a configuration
+fn validate(config: &TokenGatedVoteGovernanceConfig) -> Result<(), TokenGatedVoteContractErrors> {
+    if config.min_duration == 0
+        || config.min_duration > config.max_duration
+        || config.max_duration > DURATION_CEILING
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    if config.quorum < 0 {
+        return Err(TokenGatedVoteContractErrors::InvalidQuorum);
+    }
+    if config.threshold < MIN_THRESHOLD || config.threshold >= THRESHOLD_SCALE {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    if config.dispute_bond < 0
+        || config.proposal_fee < 0
+        || config.proposal_threshold < 0
+        || config.spending_cap < 0
+        || config.vote_burn < 0
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidAmount);
+    }
+    if config.min_balance < 1
+        || config.sponsors_required > sponsors::MAX_SPONSORS_REQUIRED
+        || config.spending_period == 0
+        || config.spending_period > treasury::MAX_SPENDING_PERIOD
+        || config.max_description_length == 0
+        || config.max_description_length > DESCRIPTION_CEILING
+        || config.grace_period > GRACE_PERIOD_CEILING
+        || config.proposer_cooldown > ratelimit::MAX_RATE_PERIOD
+        || config.proposals_per_period > ratelimit::MAX_PROPOSALS_PER_PERIOD
+        || config.proposal_period == 0
+        || config.proposal_period > ratelimit::MAX_RATE_PERIOD
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    Ok(())
+}
+
+// Applies a list of field changes to a configuration in order, failing with InvalidConfig for
+// an empty or oversized list and with the usual validation errors for the result
+pub(crate) fn apply(
+    mut config: TokenGatedVoteGovernanceConfig,
+    changes: &Vec<TokenGatedVoteConfigChange>,
+) -> Result<TokenGatedVoteGovernanceConfig, TokenGatedVoteContractErrors> {
+    if changes.is_empty() || changes.len() > MAX_CONFIG_CHANGES {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    for change in changes.iter() {
+        match change {
+            TokenGatedVoteConfigChange::MinDuration(value) => config.min_duration = value,
+            TokenGatedVoteConfigChange::MaxDuration(value) => config.max_duration = value,
+            TokenGatedVoteConfigChange::Quorum(value) => config.quorum = value,
+            TokenGatedVoteConfigChange::Threshold(value) => config.threshold = value,
+            TokenGatedVoteConfigChange::DisputeBond(value) => config.dispute_bond = value,
+            TokenGatedVoteConfigChange::ProposalFee(value) => config.proposal_fee = value,
+            TokenGatedVoteConfigChange::FeeSink(value) => config.fee_sink = value,
+            TokenGatedVoteConfigChange::MinBalance(value) => config.min_balance = value,
+            TokenGatedVoteConfigChange::ProposalThreshold(value) => {
+                config.proposal_threshold = value
+            }
+            TokenGatedVoteConfigChange::OpenProposing(value) => config.open_proposing = value,
+            TokenGatedVoteConfigChange::SponsorsRequired(value) => config.sponsors_required = value,
+            TokenGatedVoteConfigChange::SpendingCap(value) => config.spending_cap = value,
+            TokenGatedVoteConfigChange::SpendingPeriod(value) => config.spending_period = value,
+            TokenGatedVoteConfigChange::VoteLock(value) => config.vote_lock = value,
+            TokenGatedVoteConfigChange::VoteBurn(value) => config.vote_burn = value,
+            TokenGatedVoteConfigChange::SequentialIds(value) => config.sequential_ids = value,
+            TokenGatedVoteConfigChange::MaxDescriptionLength(value) => {
+                config.max_description_length = value
+            }
+            TokenGatedVoteConfigChange::TimingMode(value) => config.timing_mode = value,
+            TokenGatedVoteConfigChange::GracePeriod(value) => config.grace_period = value,
+            TokenGatedVoteConfigChange::ProposerCooldown(value) => config.proposer_cooldown = value,
+            TokenGatedVoteConfigChange::ProposalsPerPeriod(value) => {
+                config.proposals_per_period = value
+            }
+            TokenGatedVoteConfigChange::ProposalPeriod(value) => config.proposal_period = value,
+            TokenGatedVoteConfigChange::MaxActiveProposals(value) => {
+                config.max_active_proposals = value
+            }
+        }
+    }
+    validate(&config)?;
+    Ok(config)
+}
+
+// Loads the field changes attached to a config change proposal, if any
+pub(crate) fn read_change(env: &Env, id: &Symbol) -> Option<Vec<TokenGatedVoteConfigChange>> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ConfigChange(id.clone()))
+}
+
+// Attaches field changes to a proposal, turning it into a config change proposal
+pub(crate) fn write_change(env: &Env, id: &Symbol, changes: &Vec<TokenGatedVoteConfigChange>) {
+    let change_key = TokenGatedVoteContractDataKey::ConfigChange(id.clone());
+    env.storage().persistent().set(&change_key, changes);
+    env.storage().persistent().extend_ttl(
+        &change_key,
+        CONFIG_CHANGE_TTL_EXTENSION,
+        CONFIG_CHANGE_TTL_EXTENSION,
+    );
+}
+
+// Applies the field changes of a config change proposal, if the proposal has one, to the
+// configuration current at execution time
+pub(crate) fn execute(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(changes) = read_change(env, id) else {
+        return Ok(());
+    };
+    update(env, apply(read(env), &changes)?)?;
+    Ok(())
+}
+
+// Appends (field, old, new) to the diff when a field changed
+fn record_change<T: IntoVal<Env, Val> + PartialEq>(
+    env: &Env,
+    diff: &mut Vec<(Symbol, Val, Val)>,
+    field: &str,
+    old: T,
+    new: T,
+) {
+    if old != new {
+        diff.push_back((
+            Symbol::new(env, field),
+            old.into_val(env),
+            new.into_val(env),
+        ));
+    }
+}
+
+// Validates and stores a new configuration, bumping its version and publishing the diff
+pub(crate) fn update(
+    env: &Env,
+    mut config: TokenGatedVoteGovernanceConfig,
+) -> Result<TokenGatedVoteGovernanceConfig, TokenGatedVoteContractErrors> {
+    validate(&config)?;
+    if config.proposal_fee > 0 && config.fee_sink != TokenGatedVoteFeeSink::Burn {
+        treasury::require_address(env)?;
+    }
+    let previous = read(env);
+    config.version = previous.version + 1;
+
+    let mut diff = Vec::new(env);
+    record_change(
+        env,
+        &mut diff,
+        "min_duration",
+        previous.min_duration,
+        config.min_duration,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "max_duration",
+        previous.max_duration,
+        config.max_duration,
+    );
+    record_change(env, &mut diff, "quorum", previous.quorum, config.quorum);
+    record_change(
+        env,
+        &mut diff,
+        "threshold",
+        previous.threshold,
+        config.threshold,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "dispute_bond",
+        previous.dispute_bond,
+        config.dispute_bond,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "proposal_fee",
+        previous.proposal_fee,
+        config.proposal_fee,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "fee_sink",
+        previous.fee_sink,
+        config.fee_sink,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "min_balance",
+        previous.min_balance,
+        config.min_balance,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "proposal_threshold",
+        previous.proposal_threshold,
+        config.proposal_threshold,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "open_proposing",
+        previous.open_proposing,
+        config.open_proposing,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "sponsors_required",
+        previous.sponsors_required,
+        config.sponsors_required,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "spending_cap",
+        previous.spending_cap,
+        config.spending_cap,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "spending_period",
+        previous.spending_period,
+        config.spending_period,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "vote_lock",
+        previous.vote_lock,
+        config.vote_lock,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "vote_burn",
+        previous.vote_burn,
+        config.vote_burn,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "sequential_ids",
+        previous.sequential_ids,
+        config.sequential_ids,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "max_description_length",
+        previous.max_description_length,
+        config.max_description_length,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "timing_mode",
+        previous.timing_mode,
+        config.timing_mode,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "grace_period",
+        previous.grace_period,
+        config.grace_period,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "proposer_cooldown",
+        previous.proposer_cooldown,
+        config.proposer_cooldown,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "proposals_per_period",
+        previous.proposals_per_period,
+        config.proposals_per_period,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "proposal_period",
+        previous.proposal_period,
+        config.proposal_period,
+    );
+    record_change(
+        env,
+        &mut diff,
+        "max_active_proposals",
+        previous.max_active_proposals,
+        config.max_active_proposals,
+    );
+    record_change(env, &mut diff, "paused", previous.paused, config.paused);
+
+    env.storage()
+        .instance()
+        .set(&TokenGatedVoteContractDataKey::Config, &config);
+    if config.spending_cap != previous.spending_cap
+        || config.spending_period != previous.spending_period
+    {
+        treasury::sync_spending_limit(env, &config);
+    }
+
+    let update = TokenGatedVoteConfigUpdate {
+        version: config.version,
+        changes: diff,
+    };
+    events::config_updated(env, "GOVERNANCE", update);
+    Ok(config)
+}
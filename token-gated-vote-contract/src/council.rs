@@ -0,0 +1,131 @@
+use governance_core::events;
+use soroban_sdk::{contracttype, Env, Error, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteCouncil,
+    TokenGatedVoteModuleErrors, TokenGatedVoteProposalData, TokenGatedVoteProposalStatus,
+};
+
+// Defines the dual-approval council storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum CouncilKey {
+    Council,          // Council co-approving passed proposals in dual-approval mode
+    Approval(Symbol), // When the council co-approved each proposal
+}
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const APPROVAL_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the council co-approving passed proposals, if dual-approval mode is on
+pub(crate) fn read_council(env: &Env) -> Option<TokenGatedVoteCouncil> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Council(CouncilKey::Council))
+}
+
+// Sets the council and enters dual-approval mode, or clears it to leave the mode
+pub(crate) fn write_council(
+    env: &Env,
+    council: &Option<TokenGatedVoteCouncil>,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    match council {
+        Some(council) if council.approval_window == 0 => {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig);
+        }
+        Some(council) => env.storage().instance().set(
+            &TokenGatedVoteContractDataKey::Council(CouncilKey::Council),
+            council,
+        ),
+        None => env
+            .storage()
+            .instance()
+            .remove(&TokenGatedVoteContractDataKey::Council(CouncilKey::Council)),
+    }
+    events::config_updated(env, "COUNCIL", council.clone());
+    Ok(())
+}
+
+// Loads when the council co-approved a proposal, if it did
+pub(crate) fn read_approval(env: &Env, id: &Symbol) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Council(
+            CouncilKey::Approval(id.clone()),
+        ))
+}
+
+// Publishes the holders' approval of a proposal that passed its vote in dual-approval mode
+pub(crate) fn record_holders_approval(env: &Env, id: &Symbol) {
+    if read_council(env).is_some() {
+        events::publish(
+            env,
+            "APPROVAL",
+            "HOLDERS",
+            id.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+// Records the council's co-approval of a proposal that passed its vote, within the approval
+// window after voting ended; the caller moves a queued proposal on to ReadyToExecute
+pub(crate) fn approve(
+    env: &Env,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), Error> {
+    let council = read_council(env).ok_or(TokenGatedVoteModuleErrors::NoCouncil)?;
+    council.council.require_auth();
+
+    if !matches!(
+        proposal.status,
+        TokenGatedVoteProposalStatus::Succeeded | TokenGatedVoteProposalStatus::Queued
+    ) {
+        return Err(TokenGatedVoteContractErrors::InvalidStateTransition.into());
+    }
+    if read_approval(env, id).is_some() {
+        return Err(TokenGatedVoteModuleErrors::CouncilAlreadyApproved.into());
+    }
+    let ledger_time = env.ledger().timestamp();
+    if ledger_time > proposal.end_time.saturating_add(council.approval_window) {
+        return Err(TokenGatedVoteModuleErrors::CouncilWindowClosed.into());
+    }
+
+    let approval_key = TokenGatedVoteContractDataKey::Council(CouncilKey::Approval(id.clone()));
+    env.storage().persistent().set(&approval_key, &ledger_time);
+    env.storage().persistent().extend_ttl(
+        &approval_key,
+        APPROVAL_TTL_EXTENSION,
+        APPROVAL_TTL_EXTENSION,
+    );
+
+    events::publish(env, "APPROVAL", "COUNCIL", id.clone(), council.council);
+    Ok(())
+}
+
+// Extends the council approval of a proposal, if one is recorded
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let approval_key = TokenGatedVoteContractDataKey::Council(CouncilKey::Approval(id.clone()));
+    if env.storage().persistent().has(&approval_key) {
+        env.storage().persistent().extend_ttl(
+            &approval_key,
+            APPROVAL_TTL_EXTENSION,
+            APPROVAL_TTL_EXTENSION,
+        );
+    }
+}
+
+// Fails in dual-approval mode until the proposal is ReadyToExecute; outside the mode proposals
+// always pass
+pub(crate) fn require_approved(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if read_council(env).is_none()
+        || proposal.status == TokenGatedVoteProposalStatus::ReadyToExecute
+    {
+        return Ok(());
+    }
+    Err(TokenGatedVoteContractErrors::AwaitingCouncilApproval)
+}
@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+use crate::{
+    config, sequence, TokenGatedVoteContractDataKey, TokenGatedVoteCycleConfig,
+    TokenGatedVoteElectionCycle, TokenGatedVoteModuleErrors,
+};
+
+// Defines the recurring election cycle storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum CycleKey {
+    Config,  // Recurring election cycle cadence
+    Current, // Most recently started election cycle
+}
+
+// --- Cycle Proposal ID Prefix ---
+const CYCLE_ID_PREFIX: &[u8] = b"CYCLE";
+
+// Rejects cadences whose elections would violate proposal duration bounds or overlap
+pub(crate) fn validate_config(
+    env: &Env,
+    config: &TokenGatedVoteCycleConfig,
+) -> Result<(), TokenGatedVoteModuleErrors> {
+    let governance = config::read(env);
+    if config.duration < governance.min_duration
+        || config.duration > governance.max_duration
+        || config.period < config.duration
+        || config.seats == 0
+    {
+        return Err(TokenGatedVoteModuleErrors::InvalidCycleConfig);
+    }
+    Ok(())
+}
+
+// Loads the recurring cycle configuration
+pub(crate) fn read_config(
+    env: &Env,
+) -> Result<TokenGatedVoteCycleConfig, TokenGatedVoteModuleErrors> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Cycle(CycleKey::Config))
+        .ok_or(TokenGatedVoteModuleErrors::CycleNotConfigured)
+}
+
+// Loads the most recently started cycle, if any
+pub(crate) fn read_current(env: &Env) -> Option<TokenGatedVoteElectionCycle> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Cycle(CycleKey::Current))
+}
+
+// Builds the next cycle once the previous election has ended; the next start keeps the
+// configured cadence but never lies in the past
+pub(crate) fn next_cycle(
+    env: &Env,
+    config: &TokenGatedVoteCycleConfig,
+) -> Result<TokenGatedVoteElectionCycle, TokenGatedVoteModuleErrors> {
+    let ledger_time = env.ledger().timestamp();
+    let (number, start_time) = match read_current(env) {
+        Some(previous) => {
+            if ledger_time <= previous.end_time {
+                return Err(TokenGatedVoteModuleErrors::CycleNotEnded);
+            }
+            (
+                previous.number + 1,
+                previous
+                    .start_time
+                    .saturating_add(config.period)
+                    .max(ledger_time),
+            )
+        }
+        None => (1, ledger_time),
+    };
+
+    Ok(TokenGatedVoteElectionCycle {
+        number,
+        id: cycle_id(env, number),
+        seats: config.seats,
+        start_time,
+        end_time: start_time + config.duration,
+    })
+}
+
+// Persists the most recently started cycle
+pub(crate) fn write_current(env: &Env, cycle: &TokenGatedVoteElectionCycle) {
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Cycle(CycleKey::Current),
+        cycle,
+    );
+}
+
+// Derives the election proposal ID of a cycle, e.g. CYCLE12
+fn cycle_id(env: &Env, number: u32) -> Symbol {
+    sequence::numbered_id(env, CYCLE_ID_PREFIX, number)
+}
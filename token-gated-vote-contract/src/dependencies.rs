@@ -0,0 +1,85 @@
+use governance_core::events;
+use soroban_sdk::{Env, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteProposalData,
+    TokenGatedVoteProposalStatus,
+};
+
+// --- Dependency Constraints ---
+pub(crate) const MAX_DEPENDENCY_DEPTH: u32 = 10; // Longest dependency chain behind a proposal
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const DEPENDENCY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the proposal a proposal depends on, if any
+pub(crate) fn read(env: &Env, id: &Symbol) -> Option<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Dependency(id.clone()))
+}
+
+// Rejects a dependency that would close a cycle back to the proposal, walking the chain of
+// dependencies already declared; the dependency may name a proposal not created yet, so cycles
+// through proposals declared later are caught when they are created
+pub(crate) fn validate(
+    env: &Env,
+    id: &Symbol,
+    dependency: &Symbol,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    let mut current = dependency.clone();
+    for _ in 0..MAX_DEPENDENCY_DEPTH {
+        if current == *id {
+            return Err(TokenGatedVoteContractErrors::DependencyCycle);
+        }
+        match read(env, &current) {
+            Some(next) => current = next,
+            None => return Ok(()),
+        }
+    }
+    Err(TokenGatedVoteContractErrors::InvalidConfig)
+}
+
+// Records the dependency of a freshly created proposal
+pub(crate) fn record(env: &Env, id: &Symbol, dependency: &Symbol) {
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::Dependency(id.clone()),
+        dependency,
+    );
+    extend(env, id);
+
+    events::publish(
+        env,
+        events::PROPOSAL,
+        "DEPENDENCY",
+        id.clone(),
+        dependency.clone(),
+    );
+}
+
+// Extends the dependency of a proposal, if one is recorded
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let dependency_key = TokenGatedVoteContractDataKey::Dependency(id.clone());
+    if env.storage().persistent().has(&dependency_key) {
+        env.storage().persistent().extend_ttl(
+            &dependency_key,
+            DEPENDENCY_TTL_EXTENSION,
+            DEPENDENCY_TTL_EXTENSION,
+        );
+    }
+}
+
+// Fails until the proposal's dependency has been executed; proposals without one always pass
+pub(crate) fn require_executed(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(dependency) = read(env, id) else {
+        return Ok(());
+    };
+    let proposal: Option<TokenGatedVoteProposalData> = env
+        .storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Proposal(dependency));
+    match proposal {
+        Some(proposal) if proposal.status == TokenGatedVoteProposalStatus::Executed => Ok(()),
+        _ => Err(TokenGatedVoteContractErrors::DependencyNotExecuted),
+    }
+}
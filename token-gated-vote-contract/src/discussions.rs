@@ -0,0 +1,56 @@
+use governance_core::events;
+use soroban_sdk::{BytesN, Env, String, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteDiscussion,
+};
+
+// --- Discussion Constraints ---
+pub(crate) const MAX_URL_LENGTH: u32 = 256; // Bytes of discussion URL stored per proposal
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const DISCUSSION_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the discussion anchored to a proposal, if any
+pub(crate) fn read(env: &Env, id: &Symbol) -> Option<TokenGatedVoteDiscussion> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Discussion(id.clone()))
+}
+
+// Anchors a proposal's discussion thread, replacing any earlier anchor
+pub(crate) fn record(
+    env: &Env,
+    id: &Symbol,
+    discussion_hash: BytesN<32>,
+    discussion_url: String,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if discussion_url.is_empty() || discussion_url.len() > MAX_URL_LENGTH {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    let discussion = TokenGatedVoteDiscussion {
+        discussion_hash,
+        discussion_url,
+        updated_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::Discussion(id.clone()),
+        &discussion,
+    );
+    extend(env, id);
+
+    events::publish(env, events::PROPOSAL, "DISCUSSION", id.clone(), discussion);
+    Ok(())
+}
+
+// Extends the discussion of a proposal, if one is anchored
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let discussion_key = TokenGatedVoteContractDataKey::Discussion(id.clone());
+    if env.storage().persistent().has(&discussion_key) {
+        env.storage().persistent().extend_ttl(
+            &discussion_key,
+            DISCUSSION_TTL_EXTENSION,
+            DISCUSSION_TTL_EXTENSION,
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use governance_core::events;
+use pg_contracts_types::treasury::TreasuryPoolSource;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, Env, Error, Symbol};
+
+use crate::{
+    config, pool, treasury, TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors,
+    TokenGatedVoteDispute, TokenGatedVoteModuleErrors, TokenGatedVoteProposalData,
+    TokenGatedVoteProposalStatus,
+};
+
+// --- Dispute Grace Period (in seconds) ---
+pub(crate) const DISPUTE_GRACE_PERIOD: u64 = 259_200; // ~3 days after finalization
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const DISPUTE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the dispute raised against a proposal, if any
+pub(crate) fn read_dispute(env: &Env, id: &Symbol) -> Option<TokenGatedVoteDispute> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Dispute(id.clone()))
+}
+
+// Persists the dispute of a proposal and extends its TTL
+fn write_dispute(env: &Env, id: &Symbol, dispute: &TokenGatedVoteDispute) {
+    let dispute_key = TokenGatedVoteContractDataKey::Dispute(id.clone());
+    env.storage().persistent().set(&dispute_key, dispute);
+    env.storage().persistent().extend_ttl(
+        &dispute_key,
+        DISPUTE_TTL_EXTENSION,
+        DISPUTE_TTL_EXTENSION,
+    );
+}
+
+// Returns the bond required to dispute a result (0 when disputes are disabled)
+pub(crate) fn read_bond(env: &Env) -> i128 {
+    config::read(env).dispute_bond
+}
+
+// Fails while an unresolved dispute freezes the proposal's execution path
+pub(crate) fn require_not_frozen(
+    env: &Env,
+    id: &Symbol,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    match read_dispute(env, id) {
+        Some(dispute) if !dispute.resolved => Err(TokenGatedVoteContractErrors::ProposalDisputed),
+        _ => Ok(()),
+    }
+}
+
+// Locks the challenger's bond and opens a dispute within the grace period after finalization
+pub(crate) fn open(
+    env: &Env,
+    token: &Address,
+    challenger: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<TokenGatedVoteDispute, Error> {
+    let bond = read_bond(env);
+    if bond <= 0 {
+        return Err(TokenGatedVoteModuleErrors::DisputesDisabled.into());
+    }
+    if !matches!(
+        proposal.status,
+        TokenGatedVoteProposalStatus::Succeeded
+            | TokenGatedVoteProposalStatus::Defeated
+            | TokenGatedVoteProposalStatus::QuorumFailed
+            | TokenGatedVoteProposalStatus::Queued
+            | TokenGatedVoteProposalStatus::ReadyToExecute
+    ) {
+        return Err(TokenGatedVoteContractErrors::InvalidStateTransition.into());
+    }
+    let ledger_time = env.ledger().timestamp();
+    if ledger_time > proposal.finalized_at.saturating_add(DISPUTE_GRACE_PERIOD) {
+        return Err(TokenGatedVoteModuleErrors::DisputeWindowClosed.into());
+    }
+    if read_dispute(env, id).is_some() {
+        return Err(TokenGatedVoteModuleErrors::DisputeAlreadyExists.into());
+    }
+
+    TokenClient::new(env, token).transfer(challenger, &env.current_contract_address(), &bond);
+
+    let dispute = TokenGatedVoteDispute {
+        challenger: challenger.clone(),
+        bond,
+        opened_at: ledger_time,
+        resolved: false,
+        upheld: false,
+    };
+    write_dispute(env, id, &dispute);
+
+    events::publish(env, "DISPUTE", "OPENED", id.clone(), dispute.clone());
+    Ok(dispute)
+}
+
+// Returns the bond of an unresolved dispute to its challenger during a shutdown, closing the
+// dispute without a ruling; returns the amount refunded
+pub(crate) fn refund(env: &Env, token: &Address, id: &Symbol) -> i128 {
+    let mut dispute = match read_dispute(env, id) {
+        Some(dispute) if !dispute.resolved => dispute,
+        _ => return 0,
+    };
+    TokenClient::new(env, token).transfer(
+        &env.current_contract_address(),
+        &dispute.challenger,
+        &dispute.bond,
+    );
+    dispute.resolved = true;
+    write_dispute(env, id, &dispute);
+
+    events::publish(
+        env,
+        "BOND",
+        "REFUNDED",
+        id.clone(),
+        (dispute.challenger.clone(), dispute.bond),
+    );
+    dispute.bond
+}
+
+// Settles an open dispute, releasing the bond to the challenger or slashing it into the
+// treasury contract's community pool
+pub(crate) fn resolve(
+    env: &Env,
+    token: &Address,
+    id: &Symbol,
+    upheld: bool,
+) -> Result<TokenGatedVoteDispute, Error> {
+    let mut dispute = match read_dispute(env, id) {
+        Some(dispute) if !dispute.resolved => dispute,
+        _ => return Err(TokenGatedVoteModuleErrors::DisputeNotFound.into()),
+    };
+
+    let recipient = if upheld {
+        TokenClient::new(env, token).transfer(
+            &env.current_contract_address(),
+            &dispute.challenger,
+            &dispute.bond,
+        );
+        dispute.challenger.clone()
+    } else {
+        let treasury = treasury::require_address(env)?;
+        pool::accrue(
+            env,
+            token,
+            &env.current_contract_address(),
+            TreasuryPoolSource::SlashedBond,
+            id,
+            dispute.bond,
+        )?;
+        treasury
+    };
+
+    dispute.resolved = true;
+    dispute.upheld = upheld;
+    write_dispute(env, id, &dispute);
+
+    let action = if upheld { "RELEASED" } else { "SLASHED" };
+    events::publish(
+        env,
+        "BOND",
+        action,
+        id.clone(),
+        (dispute.challenger.clone(), recipient, dispute.bond),
+    );
+    events::publish(env, "DISPUTE", "RESOLVED", id.clone(), dispute.clone());
+    Ok(dispute)
+}
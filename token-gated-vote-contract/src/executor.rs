@@ -0,0 +1,76 @@
+use governance_core::events;
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{TokenGatedVoteContractDataKey, TokenGatedVoteModuleErrors};
+
+// Defines the DAO-as-admin executor storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum ExecutorKey {
+    Executor, // Executor contract holding the admin role in DAO-as-admin mode
+    Pending,  // Executor contract nominated to take over the admin role
+}
+
+// Loads the executor contract nominated to take over as admin, if a migration is pending
+pub(crate) fn read_pending(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Executor(
+            ExecutorKey::Pending,
+        ))
+}
+
+// Loads the executor contract holding the admin role in DAO-as-admin mode, if enabled
+pub(crate) fn read_executor(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Executor(
+            ExecutorKey::Executor,
+        ))
+}
+
+// Nominates an executor contract to take over as admin; it becomes admin only once it accepts
+pub(crate) fn nominate(env: &Env, admin: &Address, executor: &Address) {
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Executor(ExecutorKey::Pending),
+        executor,
+    );
+    events::publish(env, "ADMIN", "NOMINATED", admin.clone(), executor.clone());
+}
+
+// Completes a pending migration with the executor's authorization, handing it the admin role
+// and entering DAO-as-admin mode; returns the executor
+pub(crate) fn accept(env: &Env) -> Result<Address, TokenGatedVoteModuleErrors> {
+    let executor = read_pending(env).ok_or(TokenGatedVoteModuleErrors::NoPendingExecutor)?;
+    executor.require_auth();
+
+    env.storage()
+        .instance()
+        .remove(&TokenGatedVoteContractDataKey::Executor(
+            ExecutorKey::Pending,
+        ));
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Executor(ExecutorKey::Executor),
+        &executor,
+    );
+    Ok(executor)
+}
+
+// Leaves DAO-as-admin mode and drops any pending nomination, e.g. when the admin role moves on
+pub(crate) fn clear(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&TokenGatedVoteContractDataKey::Executor(
+            ExecutorKey::Executor,
+        ));
+    env.storage()
+        .instance()
+        .remove(&TokenGatedVoteContractDataKey::Executor(
+            ExecutorKey::Pending,
+        ));
+}
+
+// Fails unless the admin role is held by an executor contract in DAO-as-admin mode
+pub(crate) fn require_dao_admin(env: &Env) -> Result<Address, TokenGatedVoteModuleErrors> {
+    read_executor(env).ok_or(TokenGatedVoteModuleErrors::NotDaoAdmin)
+}
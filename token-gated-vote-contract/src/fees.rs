@@ -0,0 +1,61 @@
+use governance_core::events;
+use pg_contracts_types::treasury::TreasuryPoolSource;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    config, pool, treasury, TokenGatedVoteContractErrors, TokenGatedVoteFeeSink,
+    TokenGatedVoteProposalFee,
+};
+
+// Loads the proposal fee from the governance configuration, if one is set
+pub(crate) fn read_fee(env: &Env) -> Option<TokenGatedVoteProposalFee> {
+    let config = config::read(env);
+    if config.proposal_fee == 0 {
+        return None;
+    }
+    Some(TokenGatedVoteProposalFee {
+        amount: config.proposal_fee,
+        sink: config.fee_sink,
+    })
+}
+
+// Charges the proposal fee, if one is configured, to a non-admin proposer, sending it to the
+// configured sink; returns the amount charged
+pub(crate) fn charge(
+    env: &Env,
+    token: &Address,
+    proposer: &Address,
+    id: &Symbol,
+) -> Result<i128, TokenGatedVoteContractErrors> {
+    let Some(fee) = read_fee(env) else {
+        return Ok(0);
+    };
+
+    let token_client = TokenClient::new(env, token);
+    match fee.sink {
+        TokenGatedVoteFeeSink::Burn => token_client.burn(proposer, &fee.amount),
+        TokenGatedVoteFeeSink::Treasury => {
+            let treasury = treasury::require_address(env)?;
+            token_client.transfer(proposer, &treasury, &fee.amount);
+            treasury::accrue(env, &treasury, fee.amount);
+        }
+        TokenGatedVoteFeeSink::CommunityPool => pool::accrue(
+            env,
+            token,
+            proposer,
+            TreasuryPoolSource::ProposalFee,
+            id,
+            fee.amount,
+        )?,
+    }
+
+    events::publish(
+        env,
+        "FEE",
+        "CHARGED",
+        id.clone(),
+        (proposer.clone(), fee.amount, fee.sink),
+    );
+    Ok(fee.amount)
+}
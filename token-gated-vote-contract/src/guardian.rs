@@ -0,0 +1,96 @@
+use governance_core::events;
+use soroban_sdk::{contracttype, Env, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteGuardian,
+    TokenGatedVoteGuardianRenewal,
+};
+
+// Defines the guardian storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum GuardianKey {
+    Guardian,        // Guardian holding expiring veto and pause powers
+    Renewal(Symbol), // Guardian appointed by a guardian proposal once executed
+}
+
+// --- Guardian Term Bounds (in seconds) ---
+pub(crate) const MAX_GUARDIAN_TERM: u64 = 15_552_000; // ~180 days of emergency powers at most
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const GUARDIAN_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the current guardian, including one whose powers have lapsed
+pub(crate) fn read_guardian(env: &Env) -> Option<TokenGatedVoteGuardian> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Guardian(
+            GuardianKey::Guardian,
+        ))
+}
+
+// Returns whether a guardian is appointed and its powers have not lapsed
+pub(crate) fn is_active(env: &Env) -> bool {
+    read_guardian(env).is_some_and(|guardian| env.ledger().timestamp() < guardian.expires_at)
+}
+
+// Requires the guardian's authorization, failing once its powers have lapsed
+pub(crate) fn require_guardian(env: &Env) -> Result<(), TokenGatedVoteContractErrors> {
+    let guardian = read_guardian(env).ok_or(TokenGatedVoteContractErrors::GuardianInactive)?;
+    if env.ledger().timestamp() >= guardian.expires_at {
+        return Err(TokenGatedVoteContractErrors::GuardianInactive);
+    }
+    guardian.guardian.require_auth();
+    Ok(())
+}
+
+// Loads the appointment attached to a guardian proposal, if any
+pub(crate) fn read_renewal(env: &Env, id: &Symbol) -> Option<TokenGatedVoteGuardianRenewal> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Guardian(
+            GuardianKey::Renewal(id.clone()),
+        ))
+}
+
+// Attaches a guardian appointment to a proposal after validating its term
+pub(crate) fn write_renewal(
+    env: &Env,
+    id: &Symbol,
+    renewal: &TokenGatedVoteGuardianRenewal,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if renewal.term == 0 || renewal.term > MAX_GUARDIAN_TERM {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    let renewal_key = TokenGatedVoteContractDataKey::Guardian(GuardianKey::Renewal(id.clone()));
+    env.storage().persistent().set(&renewal_key, renewal);
+    env.storage().persistent().extend_ttl(
+        &renewal_key,
+        GUARDIAN_TTL_EXTENSION,
+        GUARDIAN_TTL_EXTENSION,
+    );
+    Ok(())
+}
+
+// Appoints the guardian of an executed guardian proposal, if the proposal has one, with powers
+// lasting `term` seconds from now and replacing any earlier guardian
+pub(crate) fn execute(env: &Env, id: &Symbol) {
+    let Some(renewal) = read_renewal(env, id) else {
+        return;
+    };
+    let guardian = TokenGatedVoteGuardian {
+        guardian: renewal.guardian,
+        expires_at: env.ledger().timestamp().saturating_add(renewal.term),
+    };
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Guardian(GuardianKey::Guardian),
+        &guardian,
+    );
+    events::publish(
+        env,
+        "GUARDIAN",
+        "RENEWED",
+        id.clone(),
+        (guardian.guardian, guardian.expires_at),
+    );
+}
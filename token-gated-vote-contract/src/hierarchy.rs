@@ -0,0 +1,253 @@
+use governance_core::{events, GovernanceClient, GovernanceStatus};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Error, Symbol, Vec};
+
+use crate::{
+    TokenGatedVoteChild, TokenGatedVoteChildScope, TokenGatedVoteContractDataKey,
+    TokenGatedVoteContractErrors, TokenGatedVoteModuleErrors, TokenGatedVoteRatification,
+};
+
+// Defines the parent and child governance storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum HierarchyKey {
+    Parent,                    // Parent governance contract this contract answers to
+    Children,                  // Child governance contracts registered under this parent
+    Child(Address),            // Scope and decision counters per registered child
+    Decision(Address, Symbol), // Parent ratification or override per child proposal
+}
+
+// --- Hierarchy Constraints ---
+pub(crate) const MAX_CHILDREN: u32 = 20; // Most child governance contracts a parent can register
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const HIERARCHY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Cross-contract interface of the parent governance contract consulted before execution
+#[contractclient(name = "ParentGovernanceClient")]
+pub trait ParentGovernanceInterface {
+    fn get_ratification(env: Env, child: Address, id: Symbol) -> TokenGatedVoteRatification;
+}
+
+// Loads the registered children, in registration order
+pub(crate) fn read_children(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Hierarchy(
+            HierarchyKey::Children,
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Loads every registered child with its scope and decision counters
+pub(crate) fn list(env: &Env) -> Vec<TokenGatedVoteChild> {
+    let mut children = Vec::new(env);
+    for child in read_children(env).iter() {
+        if let Some(record) = read_child(env, &child) {
+            children.push_back(record);
+        }
+    }
+    children
+}
+
+// Loads a registered child, if any
+pub(crate) fn read_child(env: &Env, child: &Address) -> Option<TokenGatedVoteChild> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Hierarchy(
+            HierarchyKey::Child(child.clone()),
+        ))
+}
+
+// Persists a registered child and extends its TTL
+fn write_child(env: &Env, child: &TokenGatedVoteChild) {
+    let child_key =
+        TokenGatedVoteContractDataKey::Hierarchy(HierarchyKey::Child(child.child.clone()));
+    env.storage().persistent().set(&child_key, child);
+    env.storage().persistent().extend_ttl(
+        &child_key,
+        HIERARCHY_TTL_EXTENSION,
+        HIERARCHY_TTL_EXTENSION,
+    );
+}
+
+// Loads the parent's decision on a child proposal, if it took one
+pub(crate) fn read_decision(
+    env: &Env,
+    child: &Address,
+    id: &Symbol,
+) -> Option<TokenGatedVoteRatification> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Hierarchy(
+            HierarchyKey::Decision(child.clone(), id.clone()),
+        ))
+}
+
+// Registers a child or replaces its scope, keeping its decision counters
+pub(crate) fn register(
+    env: &Env,
+    child: &Address,
+    scope: TokenGatedVoteChildScope,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if *child == env.current_contract_address() || scope.review_period == 0 {
+        return Err(TokenGatedVoteContractErrors::InvalidConfig);
+    }
+    let mut children = read_children(env);
+    let mut record = match read_child(env, child) {
+        Some(record) => record,
+        None => {
+            if children.len() >= MAX_CHILDREN {
+                return Err(TokenGatedVoteContractErrors::InvalidConfig);
+            }
+            children.push_back(child.clone());
+            env.storage().instance().set(
+                &TokenGatedVoteContractDataKey::Hierarchy(HierarchyKey::Children),
+                &children,
+            );
+            TokenGatedVoteChild {
+                child: child.clone(),
+                scope: scope.clone(),
+                registered_at: 0,
+                ratified: 0,
+                overridden: 0,
+            }
+        }
+    };
+    record.scope = scope;
+    record.registered_at = env.ledger().timestamp();
+    write_child(env, &record);
+
+    events::publish(env, "CHILD", "REGISTERED", child.clone(), record.scope);
+    Ok(())
+}
+
+// Unregisters a child; decisions already taken stay on record
+pub(crate) fn remove(env: &Env, child: &Address) -> Result<(), TokenGatedVoteModuleErrors> {
+    let mut children = read_children(env);
+    let Some(position) = children.first_index_of(child) else {
+        return Err(TokenGatedVoteModuleErrors::ChildNotFound);
+    };
+    children.remove(position);
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Hierarchy(HierarchyKey::Children),
+        &children,
+    );
+    env.storage()
+        .persistent()
+        .remove(&TokenGatedVoteContractDataKey::Hierarchy(
+            HierarchyKey::Child(child.clone()),
+        ));
+
+    events::publish(env, "CHILD", "REMOVED", child.clone(), ());
+    Ok(())
+}
+
+// Records the parent ratifying or overriding a passed child proposal within the child's scope
+// and review period; the child's status is read through the shared governance interface
+pub(crate) fn decide(
+    env: &Env,
+    child: &Address,
+    id: &Symbol,
+    decision: TokenGatedVoteRatification,
+) -> Result<(), Error> {
+    let mut record = read_child(env, child).ok_or(TokenGatedVoteModuleErrors::ChildNotFound)?;
+    if decision == TokenGatedVoteRatification::Overridden && !record.scope.can_override {
+        return Err(TokenGatedVoteModuleErrors::OutsideParentScope.into());
+    }
+    if read_decision(env, child, id).is_some() {
+        return Err(TokenGatedVoteModuleErrors::ChildDecisionExists.into());
+    }
+    let proposal = GovernanceClient::new(env, child).get_proposal(id);
+    if !matches!(
+        proposal.status,
+        GovernanceStatus::Succeeded | GovernanceStatus::Queued
+    ) {
+        return Err(TokenGatedVoteContractErrors::InvalidStateTransition.into());
+    }
+    if env.ledger().timestamp() > proposal.end_time.saturating_add(record.scope.review_period) {
+        return Err(TokenGatedVoteModuleErrors::ReviewPeriodClosed.into());
+    }
+
+    let decision_key =
+        TokenGatedVoteContractDataKey::Hierarchy(HierarchyKey::Decision(child.clone(), id.clone()));
+    env.storage().persistent().set(&decision_key, &decision);
+    env.storage().persistent().extend_ttl(
+        &decision_key,
+        HIERARCHY_TTL_EXTENSION,
+        HIERARCHY_TTL_EXTENSION,
+    );
+    let action = if decision == TokenGatedVoteRatification::Ratified {
+        record.ratified = record.ratified.saturating_add(1);
+        "RATIFIED"
+    } else {
+        record.overridden = record.overridden.saturating_add(1);
+        "OVERRIDDEN"
+    };
+    write_child(env, &record);
+
+    events::publish(env, "CHILD", action, id.clone(), child.clone());
+    Ok(())
+}
+
+// Returns the parent's position on a child proposal, as consulted by the child before execution
+pub(crate) fn ratification(env: &Env, child: &Address, id: &Symbol) -> TokenGatedVoteRatification {
+    if let Some(decision) = read_decision(env, child, id) {
+        return decision;
+    }
+    match read_child(env, child) {
+        Some(record) if record.scope.requires_ratification => TokenGatedVoteRatification::Pending,
+        _ => TokenGatedVoteRatification::NotRequired,
+    }
+}
+
+// Loads the parent this contract answers to, if any
+pub(crate) fn read_parent(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Hierarchy(
+            HierarchyKey::Parent,
+        ))
+}
+
+// Sets or clears the parent this contract answers to
+pub(crate) fn write_parent(
+    env: &Env,
+    parent: &Option<Address>,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    match parent {
+        Some(parent) if *parent == env.current_contract_address() => {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig);
+        }
+        Some(parent) => env.storage().instance().set(
+            &TokenGatedVoteContractDataKey::Hierarchy(HierarchyKey::Parent),
+            parent,
+        ),
+        None => env
+            .storage()
+            .instance()
+            .remove(&TokenGatedVoteContractDataKey::Hierarchy(
+                HierarchyKey::Parent,
+            )),
+    }
+    events::config_updated(env, "PARENT", parent.clone());
+    Ok(())
+}
+
+// Fails while the parent withholds ratification of, or has overridden, a proposal of this
+// contract; contracts without a parent always pass
+pub(crate) fn require_cleared(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(parent) = read_parent(env) else {
+        return Ok(());
+    };
+    match ParentGovernanceClient::new(env, &parent)
+        .get_ratification(&env.current_contract_address(), id)
+    {
+        TokenGatedVoteRatification::Pending => {
+            Err(TokenGatedVoteContractErrors::AwaitingRatification)
+        }
+        TokenGatedVoteRatification::Overridden => {
+            Err(TokenGatedVoteContractErrors::OverriddenByParent)
+        }
+        TokenGatedVoteRatification::NotRequired | TokenGatedVoteRatification::Ratified => Ok(()),
+    }
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::{TokenGatedVoteContractDataKey, PROPOSALS_TTL_EXTENSION};
+
+// Loads the open (Draft, Pending, or Active) proposals ordered by ascending end_time
+pub(crate) fn read_expiry_index(env: &Env) -> Vec<(u64, Symbol)> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ExpiryIndex)
+        .unwrap_or(Vec::new(env))
+}
+
+// Persists the expiry index and extends its TTL
+fn write_expiry_index(env: &Env, index: &Vec<(u64, Symbol)>) {
+    env.storage()
+        .persistent()
+        .set(&TokenGatedVoteContractDataKey::ExpiryIndex, index);
+    env.storage().persistent().extend_ttl(
+        &TokenGatedVoteContractDataKey::ExpiryIndex,
+        PROPOSALS_TTL_EXTENSION,
+        PROPOSALS_TTL_EXTENSION,
+    );
+}
+
+// Inserts a proposal keeping the index sorted by end_time (ties keep creation order)
+pub(crate) fn insert(env: &Env, id: &Symbol, end_time: u64) {
+    let mut index = read_expiry_index(env);
+    let mut position = index.len();
+    for (i, (entry_end_time, _)) in index.iter().enumerate() {
+        if entry_end_time > end_time {
+            position = i as u32;
+            break;
+        }
+    }
+    index.insert(position, (end_time, id.clone()));
+    write_expiry_index(env, &index);
+}
+
+// Removes a proposal from the index once it leaves the voting phase
+pub(crate) fn remove(env: &Env, id: &Symbol) {
+    let mut index = read_expiry_index(env);
+    for (i, (_, entry_id)) in index.iter().enumerate() {
+        if entry_id == *id {
+            index.remove(i as u32);
+            write_expiry_index(env, &index);
+            return;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{
+    TokenGatedVoteAchievements, TokenGatedVoteContractDataKey, TokenGatedVoteParticipation,
+};
+
+// Defines the participation and leaderboard storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum LeaderboardKey {
+    Participation(Address), // Votes, proposals, and passed proposals counted per address
+    Leaderboard,            // Most active addresses ordered by participation score
+}
+
+// --- Leaderboard Bounds ---
+pub(crate) const MAX_LEADERBOARD_SIZE: u32 = 100; // Most participants ranked on the leaderboard
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const PARTICIPATION_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Counter an achievement is unlocked by
+#[derive(Clone, Copy)]
+enum Counter {
+    Votes,     // Votes cast
+    Proposals, // Proposals created
+    Passed,    // Proposals passed
+}
+
+// --- Achievements ---
+// (identifier, counter, threshold) unlocked once the counter reaches the threshold; derived from
+// the counters on read, so new entries apply to existing participants
+const ACHIEVEMENTS: [(Symbol, Counter, u32); 7] = [
+    (symbol_short!("FIRSTVOTE"), Counter::Votes, 1),
+    (symbol_short!("VOTES10"), Counter::Votes, 10),
+    (symbol_short!("VOTES100"), Counter::Votes, 100),
+    (symbol_short!("PROPOSER"), Counter::Proposals, 1),
+    (symbol_short!("PROPOSE10"), Counter::Proposals, 10),
+    (symbol_short!("PASSED"), Counter::Passed, 1),
+    (symbol_short!("PASSED10"), Counter::Passed, 10),
+];
+
+// Loads the participation counters of an address, defaulting to zero
+pub(crate) fn read_participation(env: &Env, user: &Address) -> TokenGatedVoteParticipation {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Leaderboard(
+            LeaderboardKey::Participation(user.clone()),
+        ))
+        .unwrap_or_default()
+}
+
+// Returns the leaderboard score of a set of counters
+pub(crate) fn score(participation: &TokenGatedVoteParticipation) -> u32 {
+    participation
+        .votes_cast
+        .saturating_add(participation.proposals_created)
+        .saturating_add(participation.proposals_passed)
+}
+
+// Returns the value of the counter an achievement follows
+fn counter(participation: &TokenGatedVoteParticipation, counter: Counter) -> u32 {
+    match counter {
+        Counter::Votes => participation.votes_cast,
+        Counter::Proposals => participation.proposals_created,
+        Counter::Passed => participation.proposals_passed,
+    }
+}
+
+// Lists the achievements a set of counters has unlocked
+pub(crate) fn achievements(
+    env: &Env,
+    participation: &TokenGatedVoteParticipation,
+) -> TokenGatedVoteAchievements {
+    let mut unlocked = Vec::new(env);
+    for (achievement, kind, threshold) in ACHIEVEMENTS.iter() {
+        if counter(participation, *kind) >= *threshold {
+            unlocked.push_back(achievement.clone());
+        }
+    }
+    TokenGatedVoteAchievements {
+        participation: participation.clone(),
+        score: score(participation),
+        unlocked,
+    }
+}
+
+// Loads the leaderboard as (user, score) ordered by descending score
+pub(crate) fn read_leaderboard(env: &Env) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Leaderboard(
+            LeaderboardKey::Leaderboard,
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Moves an address to the position its new score earns, keeping earlier holders of an equal
+// score ahead and dropping whoever falls beyond the leaderboard size
+fn rank(env: &Env, user: &Address, score: u32) {
+    let mut leaderboard = read_leaderboard(env);
+    if let Some(current) = leaderboard.iter().position(|(entry, _)| entry == *user) {
+        leaderboard.remove(current as u32);
+    }
+    let position = leaderboard
+        .iter()
+        .position(|(_, entry_score)| entry_score < score)
+        .map(|position| position as u32)
+        .unwrap_or(leaderboard.len());
+    if position >= MAX_LEADERBOARD_SIZE {
+        return;
+    }
+    leaderboard.insert(position, (user.clone(), score));
+    if leaderboard.len() > MAX_LEADERBOARD_SIZE {
+        leaderboard.pop_back();
+    }
+
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::Leaderboard(LeaderboardKey::Leaderboard),
+        &leaderboard,
+    );
+    env.storage().persistent().extend_ttl(
+        &TokenGatedVoteContractDataKey::Leaderboard(LeaderboardKey::Leaderboard),
+        PARTICIPATION_TTL_EXTENSION,
+        PARTICIPATION_TTL_EXTENSION,
+    );
+}
+
+// Increments one counter of an address and re-ranks it on the leaderboard
+fn record(env: &Env, user: &Address, kind: Counter) {
+    let mut participation = read_participation(env, user);
+    match kind {
+        Counter::Votes => participation.votes_cast = participation.votes_cast.saturating_add(1),
+        Counter::Proposals => {
+            participation.proposals_created = participation.proposals_created.saturating_add(1)
+        }
+        Counter::Passed => {
+            participation.proposals_passed = participation.proposals_passed.saturating_add(1)
+        }
+    }
+    let participation_key =
+        TokenGatedVoteContractDataKey::Leaderboard(LeaderboardKey::Participation(user.clone()));
+    env.storage()
+        .persistent()
+        .set(&participation_key, &participation);
+    env.storage().persistent().extend_ttl(
+        &participation_key,
+        PARTICIPATION_TTL_EXTENSION,
+        PARTICIPATION_TTL_EXTENSION,
+    );
+    rank(env, user, score(&participation));
+}
+
+// Counts an accepted vote toward the voter's participation
+pub(crate) fn record_vote(env: &Env, voter: &Address) {
+    record(env, voter, Counter::Votes);
+}
+
+// Counts a newly created proposal toward the proposer's participation
+pub(crate) fn record_proposal(env: &Env, proposer: &Address) {
+    record(env, proposer, Counter::Proposals);
+}
+
+// Counts a proposal finalized as Succeeded toward the proposer's participation
+pub(crate) fn record_passed(env: &Env, proposer: &Address) {
+    record(env, proposer, Counter::Passed);
+}
@@ -1,17 +1,80 @@
 #![no_std]
 
-use soroban_sdk::token::Client as TokenClient;
+use governance_core::{
+    events, BadgeClient, GovernanceProposal, GovernanceStatus, InvalidChoice, MembershipClient,
+    ProposalTimeError, Tally, TokenSource, VotingWindow,
+};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Map, String,
-    Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, BytesN, Env,
+    Error, String, Symbol, Vec,
 };
 
-// --- Vote Choice Constants ---
-const VOTE_FOR: Symbol = symbol_short!("FOR");
-const VOTE_AGAINST: Symbol = symbol_short!("AGAINST");
-const VOTE_ABSTAIN: Symbol = symbol_short!("ABSTAIN");
+mod archive;
+mod bundles;
+mod categories;
+mod conditions;
+mod config;
+mod council;
+mod cycles;
+mod dependencies;
+mod discussions;
+mod disputes;
+mod executor;
+mod fees;
+mod guardian;
+mod hierarchy;
+mod index;
+mod leaderboard;
+mod lifecycle;
+mod locks;
+mod membership;
+mod names;
+mod pool;
+mod quorum;
+mod ratelimit;
+mod revisions;
+mod rewards;
+mod schedule;
+mod sequence;
+mod shutdown;
+mod signatures;
+mod sponsors;
+mod stats;
+mod timing;
+mod treasury;
+pub use bundles::BundleKey;
+pub use categories::CategoryKey;
+pub use conditions::{PriceOracleClient, PriceOracleInterface};
+pub use council::CouncilKey;
+pub use cycles::CycleKey;
+pub use executor::ExecutorKey;
+pub use guardian::GuardianKey;
+pub use hierarchy::{HierarchyKey, ParentGovernanceClient, ParentGovernanceInterface};
+pub use leaderboard::LeaderboardKey;
+pub use locks::LockKey;
+pub use pg_contracts_types::token_gated_vote::{
+    TokenGatedVoteAchievements, TokenGatedVoteBundle, TokenGatedVoteBundleItem,
+    TokenGatedVoteCategoryRules, TokenGatedVoteChild, TokenGatedVoteChildScope,
+    TokenGatedVoteConfigChange, TokenGatedVoteConfigUpdate, TokenGatedVoteCouncil,
+    TokenGatedVoteCycleConfig, TokenGatedVoteDiscussion, TokenGatedVoteDispute,
+    TokenGatedVoteDynamicQuorum, TokenGatedVoteElectionCycle, TokenGatedVoteExecutionCondition,
+    TokenGatedVoteFeeSink, TokenGatedVoteGovernanceConfig, TokenGatedVoteGovernanceStats,
+    TokenGatedVoteGuardian, TokenGatedVoteGuardianRenewal, TokenGatedVoteHistoryEntry,
+    TokenGatedVoteLeaderboardEntry, TokenGatedVoteParticipation, TokenGatedVotePoolSpend,
+    TokenGatedVotePriceAsset, TokenGatedVotePriceComparison, TokenGatedVotePriceData,
+    TokenGatedVoteProposalData, TokenGatedVoteProposalFee, TokenGatedVoteProposalPage,
+    TokenGatedVoteProposalStats, TokenGatedVoteProposalStatus, TokenGatedVoteProposalSummary,
+    TokenGatedVoteProposerActivity, TokenGatedVoteProposerRule, TokenGatedVoteQuorumProgress,
+    TokenGatedVoteRatification, TokenGatedVoteRecord, TokenGatedVoteRevision,
+    TokenGatedVoteRewardPool, TokenGatedVoteSignedPayload, TokenGatedVoteSignedVote,
+    TokenGatedVoteSignedVoteResult, TokenGatedVoteTimingMode, TokenGatedVoteTreasuryAction,
+};
+pub use quorum::QuorumKey;
+pub use rewards::RewardKey;
+pub use signatures::SignatureKey;
+pub use treasury::{TreasuryClient, TreasuryInterface};
 
-// --- Proposal Duration Constraints (in seconds) ---
+// --- Default Proposal Duration Constraints (in seconds) ---
 const MAX_PROPOSAL_DURATION: u64 = 1292000; // ~15 days
 const MIN_PROPOSAL_DURATION: u64 = 432000; // ~5 days
 
@@ -20,62 +83,188 @@ const PROPOSALS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
 const PROPOSAL_TTL_BUFFER: u32 = 604_800; // ~7 days
 const VOTE_TTL_EXTENSION: u32 = 1_600_000; // ~18.5 days
 
-// Defines the structure for persistent and instance storage
-#[contracttype]
-pub enum TokenGatedVoteContractDataKey {
-    Admin,            // Contract administrator address
-    Token,            // Governance token address
-    Proposal(Symbol), // Individual proposal data, keyed by its ID
-    Proposals,        // List of all proposal IDs
-    Votes(Address),   // User voting records
-}
+// --- Pagination Constraints ---
+const MAX_PAGE_LIMIT: u32 = 100; // Maximum entries returned by paginated reads
 
-// Stores the detailed information for a single proposal
-#[contracttype]
-#[derive(Clone)]
-pub struct TokenGatedVoteProposalData {
-    pub description: String, // Proposal description
-    pub start_time: u64,     // UNIX timestamp when voting begins
-    pub end_time: u64,       // UNIX timestamp when voting ends
-    pub total_for: i128,     // Total voting power cast FOR
-    pub total_against: i128, // Total voting power cast AGAINST
-    pub total_abstain: i128, // Total voting power cast ABSTAIN
-}
+// --- Batch Constraints ---
+const MAX_SIGNED_BATCH: u32 = 50; // Maximum signed votes settled per transaction
+const MAX_KEEPER_ACTIVATIONS: u32 = 50; // Maximum scheduled proposals activated per keeper call
 
-// Represents a summary of a governance proposal
-#[contracttype]
-#[derive(Clone)]
-pub struct TokenGatedVoteProposalSummary {
-    pub id: Symbol,                           // Unique identifier for the proposal
-    pub description: String,                  // Human-readable proposal description
-    pub status: TokenGatedVoteProposalStatus, // Lifecycle status of the proposal
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 5] = [
+    governance_core::FEATURE_GOVERNANCE,
+    governance_core::FEATURE_EXECUTION,
+    governance_core::FEATURE_SIGNED_VOTES,
+    governance_core::FEATURE_TREASURY,
+    governance_core::FEATURE_HIERARCHY,
+];
+
+// Cross-contract interface of the reputation contract notified on participation
+#[contractclient(name = "ReputationClient")]
+pub trait ReputationInterface {
+    fn record_vote(env: Env, minter: Address, voter: Address);
+    fn record_proposal_passed(env: Env, minter: Address, proposer: Address);
 }
 
-// Represents lifecycle status of a proposal relative to the current ledger timestamp
+// Defines the structure for persistent and instance storage
 #[contracttype]
-#[derive(Clone, Copy)]
-pub enum TokenGatedVoteProposalStatus {
-    Pending, // Current time is before start_time
-    Active,  // Current time is within [start_time, end_time]
-    Ended,   // Current time is after end_time
+pub enum TokenGatedVoteContractDataKey {
+    Admin,                       // Contract administrator address
+    Token,                       // Governance token source
+    Reputation,                  // Optional reputation contract notified on votes
+    Badges,                      // Optional badge contract minting participation badges
+    Config,                      // Unified governance configuration
+    Treasury,                    // Treasury contract holding deposits and paying proposals
+    Cycle(CycleKey),             // Recurring election cycle storage
+    Proposal(Symbol),            // Individual proposal data, keyed by its ID
+    ProposalDigest(Symbol),      // SHA-256 digest of each proposal's latest state
+    ProposalName(Symbol),        // Name a proposal was created with, keyed by its ID
+    NamedProposal(String),       // ID of the proposal created under each name
+    Proposals,                   // List of all proposal IDs
+    NextProposalIndex,           // Index the next sequential proposal ID is derived from
+    ExpiryIndex,                 // Open proposals ordered by end_time
+    ScheduleQueue,               // Scheduled proposals awaiting activation (temporary)
+    Stats,                       // Aggregate governance counters
+    Vote(Address, Symbol),       // Vote record per voter and proposal
+    Voter(Address),              // Marks addresses that have voted at least once
+    Reward(RewardKey),           // Participation reward storage
+    Signature(SignatureKey),     // Signed vote key and nonce storage
+    Dispute(Symbol),             // Bonded dispute raised against a finalized result
+    TreasuryAction(Symbol),      // Payout executed by a treasury proposal
+    Quorum(QuorumKey),           // Dynamic quorum storage
+    Sponsors(Symbol),            // Holders endorsing a draft proposal
+    Lock(LockKey),               // Vote lock escrow storage
+    ExecutionConditions(Symbol), // Price conditions checked when a treasury proposal executes
+    Guardian(GuardianKey),       // Guardian storage
+    Shutdown,                    // UNIX timestamp of the permanent emergency shutdown
+    Category(CategoryKey),       // Proposal category storage
+    Hierarchy(HierarchyKey),     // Parent and child governance storage
+    PoolSpend(Symbol),           // Payout executed by a community pool spend proposal
+    ConfigChange(Symbol),        // Configuration fields applied by a config change proposal
+    Executor(ExecutorKey),       // DAO-as-admin executor storage
+    ProposerActivity(Address),   // Recent proposal creation times per non-admin proposer
+    Membership,                  // Membership contract gating voters, absent when balance-gated
+    Leaderboard(LeaderboardKey), // Participation and leaderboard storage
+    Bundle(BundleKey),           // Proposal bundle storage
+    Dependency(Symbol),          // Proposal that must execute before the keyed proposal can
+    Revisions(Symbol),           // States a proposal held before each of its amendments
+    Discussion(Symbol),          // Off-chain discussion thread anchored to a proposal
+    Council(CouncilKey),         // Dual-approval council storage
 }
 
 // Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 100-199 block
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TokenGatedVoteContractErrors {
-    ContractNotInitialized = 1,     // The contract has not been initialized
-    ContractAlreadyInitialized = 2, // The contract has already been initialized
-    ProposalAlreadyExists = 3,      // A proposal with this ID already exists
-    ProposalNotFound = 4,           // The specified proposal does not exist
-    UserAlreadyVoted = 5,           // User has already voted on this proposal
-    UserCannotVote = 6,             // User does not hold the required token
-    VotingNotActive = 7,            // The proposal is not currently active for voting
-    InvalidChoice = 8,              // The provided vote choice is invalid
-    StartTimeAfterEnd = 9,          // Proposal start time occurs after end time
-    StartTimeInPast = 10,           // Proposal start time is before current timestamp
-    DurationTooLong = 11,           // Proposal duration exceeds maximum allowed period
-    DurationTooShort = 12,          // Proposal duration is below minimum required period
+    ContractNotInitialized = 1,      // The contract has not been initialized
+    ContractAlreadyInitialized = 2,  // The contract has already been initialized
+    ProposalAlreadyExists = 3,       // A proposal with this ID already exists
+    ProposalNotFound = 4,            // The specified proposal does not exist
+    UserAlreadyVoted = 5,            // User has already voted on this proposal
+    UserCannotVote = 6,              // User does not hold the required token
+    VotingNotActive = 7,             // The proposal is not currently active for voting
+    InvalidChoice = 8,               // The provided vote choice is invalid
+    StartTimeAfterEnd = 9,           // Proposal start time occurs after end time
+    StartTimeInPast = 10,            // Proposal start time is before current timestamp
+    DurationTooLong = 11,            // Proposal duration exceeds maximum allowed period
+    DurationTooShort = 12,           // Proposal duration is below minimum required period
+    InvalidAmount = 13,              // The provided token amount must be positive
+    VotingNotEnded = 14,             // The proposal cannot be finalized before end_time
+    InvalidConfig = 15,              // Duration, threshold, or minimum balance out of bounds
+    ProposalsRestricted = 17,        // Only the admin can propose while open proposing is off
+    InvalidStateTransition = 120,    // The proposal status does not allow this action
+    ExecutionWindowClosed = 122,     // The execution window has already elapsed
+    ExecutionWindowOpen = 123,       // The proposal cannot expire while still executable
+    InvalidQuorum = 124,             // Negative quorum or invalid dynamic quorum settings
+    SignatureExpired = 125,          // The signed vote's expiration has passed
+    SigningKeyNotFound = 126,        // The user has not registered a signing key
+    BatchTooLarge = 127,             // The signed vote batch exceeds the maximum size
+    ProposalDisputed = 135,          // Execution is frozen until the dispute is resolved
+    ExitWindowOpen = 136,            // Treasury proposals execute after the rage-quit window
+    ContractPaused = 143,            // Proposal creation and voting are paused
+    InsufficientProposalPower = 144, // Proposer's voting power is below the proposal threshold
+    VoteLockRequiresAuth = 152,      // Signed votes cannot escrow tokens while vote lock is on
+    ExecutionConditionFailed = 153,  // A price condition does not hold yet; retry before expiry
+    GuardianInactive = 154,          // No guardian is appointed or its powers have expired
+    ContractShutDown = 155,          // Only withdrawals remain enabled after the shutdown
+    CategoryNotFound = 158,          // The proposal category has no rules configured
+    AwaitingRatification = 163,      // The parent has not ratified the proposal yet
+    OverriddenByParent = 164,        // The parent overrode the proposal, so it cannot execute
+    ProposalDigestMismatch = 165,    // Restored data differs from the proposal's recorded digest
+    SequentialIdsRequired = 166,     // Sequential IDs are enforced and this is not the next one
+    InvalidProposalName = 167,       // Name is empty, too long, or has unsupported characters
+    InvalidDescription = 168,        // Description is empty or longer than the configured limit
+    VoteBurnRequiresAuth = 169,      // Signed votes cannot burn tokens while vote burn is on
+    GracePeriodActive = 173,         // Finalization waits out the grace period after voting ends
+    ProposalRateLimited = 174,       // Proposer is in cooldown or reached the per-period cap
+    TooManyActiveProposals = 175,    // Pending and Active proposals are at the configured maximum
+    BundleFailed = 177,              // A member of the proposal's bundle did not pass
+    DependencyNotExecuted = 178,     // The proposal's dependency has not been executed yet
+    DependencyCycle = 179,           // The dependency chain would lead back to the proposal
+    AwaitingCouncilApproval = 185,   // The proposal needs the council's co-approval to execute
+    TreasuryNotSet = 186,            // No treasury contract has been set for treasury payouts
+}
+
+// Enumerates the error states of the opt-in modules, such as rewards, cycles, and disputes
+// An error enum holds at most 50 cases, so these take the rest of the 100-199 block; entry
+// points that can fail with them return `Error`, which carries either enum's code
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenGatedVoteModuleErrors {
+    RewardFundingClosed = 114,   // Rewards can only be funded until voting ends
+    RewardPoolNotFound = 115,    // No reward pool exists for this proposal
+    RewardNotClaimable = 116,    // Not finalized, window closed, or user did not vote
+    RewardAlreadyClaimed = 117,  // User has already claimed this proposal's reward
+    RewardClaimWindowOpen = 118, // Rewards cannot be swept during the claim window
+    RewardPoolEmpty = 119,       // No unclaimed rewards remain in the pool
+    CycleNotConfigured = 128,    // No recurring election cycle has been configured
+    CycleNotEnded = 129,         // The previous cycle's election has not ended yet
+    InvalidCycleConfig = 130,    // Duration out of bounds, period too short, or no seats
+    DisputesDisabled = 131,      // No dispute bond has been configured
+    DisputeWindowClosed = 132,   // The grace period after finalization has elapsed
+    DisputeAlreadyExists = 133,  // The proposal result has already been disputed
+    DisputeNotFound = 134,       // No open dispute exists for this proposal
+    RageQuitClosed = 137,        // Proposal not passed, already executed, or window over
+    RageQuitNotEligible = 138,   // Only AGAINST voters holding shares can rage-quit
+    NotTreasuryProposal = 140,   // The proposal has no treasury payout attached
+    SponsorshipClosed = 145,     // The proposal is not a draft or its window has opened
+    AlreadySponsored = 146,      // The sponsor has already endorsed this proposal
+    SponsorNotFound = 147,       // The sponsor has not endorsed this proposal
+    NoLockedTokens = 150,        // The voter has no tokens escrowed on this proposal
+    TokensStillLocked = 151,     // The proposal's voting window has not ended yet
+    ChildNotFound = 159,         // The child governance contract is not registered
+    OutsideParentScope = 160,    // The child's scope does not allow this parent decision
+    ReviewPeriodClosed = 161,    // The parent's review period for the proposal has ended
+    ChildDecisionExists = 162,   // The parent already ratified or overrode the proposal
+    NoPendingExecutor = 171,     // No executor contract has been nominated as admin
+    NotDaoAdmin = 172,           // The admin role is not held by an executor contract
+    BundleAlreadyExists = 176,   // A bundle with this ID already exists
+    NotProposalEditor = 180,     // Only the proposer or the admin can edit the proposal
+    RevisionLimitReached = 181,  // The proposal has been amended the maximum number of times
+    NoCouncil = 182,             // Dual-approval mode is off, so there is no council
+    CouncilAlreadyApproved = 183, // The council already co-approved the proposal
+    CouncilWindowClosed = 184,   // The council approval window after voting has elapsed
+}
+
+// Maps shared proposal time validation failures onto this contract's error codes
+impl From<ProposalTimeError> for TokenGatedVoteContractErrors {
+    fn from(error: ProposalTimeError) -> Self {
+        match error {
+            ProposalTimeError::StartTimeAfterEnd => Self::StartTimeAfterEnd,
+            ProposalTimeError::StartTimeInPast => Self::StartTimeInPast,
+            ProposalTimeError::DurationTooLong => Self::DurationTooLong,
+            ProposalTimeError::DurationTooShort => Self::DurationTooShort,
+        }
+    }
+}
+
+// Maps an unrecognized vote choice onto this contract's error code
+impl From<InvalidChoice> for TokenGatedVoteContractErrors {
+    fn from(_: InvalidChoice) -> Self {
+        Self::InvalidChoice
+    }
 }
 
 #[contract]
@@ -86,120 +275,293 @@ impl TokenGatedVoteContract {
     // --- Helper Functions ---
 
     // Derives TTL extension for a proposal based on current ledger time
-    fn calculate_proposal_ttl(env: &Env, proposal_end_time: u64) -> u32 {
-        let ledger_time = env.ledger().timestamp();
-        let proposal_duration = if proposal_end_time > ledger_time {
-            proposal_end_time - ledger_time
-        } else {
-            0
-        };
+    fn calculate_proposal_ttl(env: &Env, proposal: &TokenGatedVoteProposalData) -> u32 {
+        timing::proposal_ttl(env, proposal, PROPOSAL_TTL_BUFFER, PROPOSALS_TTL_EXTENSION)
+    }
 
-        let min_ttl = proposal_duration as u32 + PROPOSAL_TTL_BUFFER;
-        min_ttl.max(PROPOSALS_TTL_EXTENSION)
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, TokenGatedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenGatedVoteContractDataKey::Admin)
+            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)
     }
 
-    // Computes proposal status relative to a ledger timestamp
-    fn compute_proposal_status(
-        ledger_time: u64,
-        proposal: &TokenGatedVoteProposalData,
-    ) -> TokenGatedVoteProposalStatus {
-        if ledger_time < proposal.start_time {
-            TokenGatedVoteProposalStatus::Pending
-        } else if ledger_time <= proposal.end_time {
-            TokenGatedVoteProposalStatus::Active
-        } else {
-            TokenGatedVoteProposalStatus::Ended
+    // Loads the governance token source from instance storage
+    fn read_token_source(env: &Env) -> Result<TokenSource, TokenGatedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenGatedVoteContractDataKey::Token)
+            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address used for transfers
+    fn read_token(env: &Env) -> Result<Address, TokenGatedVoteContractErrors> {
+        Self::read_token_source(env).map(|source| source.address())
+    }
+
+    // Returns a holder's normalized voting balance, counting tokens escrowed by vote locks
+    fn voting_balance(env: &Env, user: &Address) -> Result<i128, TokenGatedVoteContractErrors> {
+        let source = Self::read_token_source(env)?;
+        let balance = source.balance(env, user) + locks::read_locked_balance(env, user);
+        Ok(governance_core::normalize(balance, source.decimals(env)))
+    }
+
+    // Returns whether an address may vote and sponsor: a current member while membership gating
+    // is enabled, otherwise a holder of at least the minimum balance
+    fn is_eligible(
+        env: &Env,
+        user: &Address,
+        config: &TokenGatedVoteGovernanceConfig,
+    ) -> Result<bool, TokenGatedVoteContractErrors> {
+        match membership::read_membership(env) {
+            Some(membership) => Ok(MembershipClient::new(env, &membership).is_member(user)),
+            None => Ok(Self::voting_balance(env, user)? >= config.min_balance),
         }
     }
 
-    // Validates proposal start/end times against ledger time and duration bounds
-    fn validate_proposal_times(
-        ledger_time: u64,
-        start_time: u64,
-        end_time: u64,
-    ) -> Result<(), TokenGatedVoteContractErrors> {
-        if start_time >= end_time {
-            return Err(TokenGatedVoteContractErrors::StartTimeAfterEnd);
+    // Loads a proposal from persistent storage
+    fn read_proposal(
+        env: &Env,
+        id: &Symbol,
+    ) -> Result<TokenGatedVoteProposalData, TokenGatedVoteContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposal(id.clone()))
+            .ok_or(TokenGatedVoteContractErrors::ProposalNotFound)
+    }
+
+    // Persists a proposal, refreshes its TTL, and records the digest a restore is checked against
+    fn write_proposal(env: &Env, id: &Symbol, proposal: &TokenGatedVoteProposalData) {
+        let proposal_key = TokenGatedVoteContractDataKey::Proposal(id.clone());
+        env.storage().persistent().set(&proposal_key, proposal);
+        let proposal_ttl = Self::calculate_proposal_ttl(env, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&proposal_key, proposal_ttl, proposal_ttl);
+        archive::record(env, id, proposal);
+    }
+
+    // Validates a one-token-one-vote ballot without mutating state, returning the proposal and
+    // its tally with the ballot applied
+    fn check_vote(
+        env: &Env,
+        user: &Address,
+        id: &Symbol,
+        choice: &Symbol,
+    ) -> Result<(TokenGatedVoteProposalData, Tally), TokenGatedVoteContractErrors> {
+        let config = config::read(env);
+        if config.paused {
+            return Err(TokenGatedVoteContractErrors::ContractPaused);
+        }
+        let proposal = Self::read_proposal(env, id)?;
+
+        if timing::window(env, &proposal) != VotingWindow::Active
+            || proposal.status == TokenGatedVoteProposalStatus::Draft
+            || lifecycle::is_finalized(proposal.status)
+        {
+            return Err(TokenGatedVoteContractErrors::VotingNotActive);
         }
-        if start_time < ledger_time {
-            return Err(TokenGatedVoteContractErrors::StartTimeInPast);
+
+        if env
+            .storage()
+            .persistent()
+            .has(&TokenGatedVoteContractDataKey::Vote(
+                user.clone(),
+                id.clone(),
+            ))
+        {
+            return Err(TokenGatedVoteContractErrors::UserAlreadyVoted);
         }
-        let duration = end_time - start_time;
-        if duration > MAX_PROPOSAL_DURATION {
-            return Err(TokenGatedVoteContractErrors::DurationTooLong);
+
+        if !Self::is_eligible(env, user, &config)? {
+            return Err(TokenGatedVoteContractErrors::UserCannotVote);
         }
-        if duration < MIN_PROPOSAL_DURATION {
-            return Err(TokenGatedVoteContractErrors::DurationTooShort);
+        // Burns come out of the spendable balance, so tokens escrowed by vote locks do not count
+        if config.vote_burn > 0
+            && Self::read_token_source(env)?.balance(env, user) < config.vote_burn
+        {
+            return Err(TokenGatedVoteContractErrors::UserCannotVote);
         }
-        Ok(())
-    }
 
-    // --- Write Functions ---
+        let mut tally = lifecycle::tally(&proposal);
+        tally.record(choice, 1)?;
+        Ok((proposal, tally))
+    }
 
-    // Initializes contract with admin and governance token
-    pub fn __constructor(
-        env: Env,
-        admin: Address,
-        token: Address,
+    // Tallies a one-token-one-vote ballot once the caller has been authenticated; `direct` marks
+    // ballots authorized by the voter itself, which burn and escrow its tokens while vote burn
+    // and vote lock are on
+    fn cast_vote(
+        env: &Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+        direct: bool,
     ) -> Result<(), TokenGatedVoteContractErrors> {
-        if env
+        let (mut proposal, tally) = Self::check_vote(env, &user, &id, &choice)?;
+        let config = config::read(env);
+        let mut burned = 0;
+        if config.vote_burn > 0 {
+            if !direct {
+                return Err(TokenGatedVoteContractErrors::VoteBurnRequiresAuth);
+            }
+            Self::read_token_source(env)?
+                .client(env)
+                .burn(&user, &config.vote_burn);
+            burned = config.vote_burn;
+            proposal.burned = proposal.burned.saturating_add(burned);
+            events::publish(
+                env,
+                events::VOTE,
+                "BURNED",
+                id.clone(),
+                (user.clone(), burned),
+            );
+        }
+        if config.vote_lock {
+            if !direct {
+                return Err(TokenGatedVoteContractErrors::VoteLockRequiresAuth);
+            }
+            locks::escrow(env, &Self::read_token_source(env)?, &user, &id);
+        }
+        let vote_key = TokenGatedVoteContractDataKey::Vote(user.clone(), id.clone());
+
+        proposal.total_for = tally.total_for;
+        proposal.total_against = tally.total_against;
+        proposal.total_abstain = tally.total_abstain;
+        proposal.voter_count = proposal.voter_count.saturating_add(1);
+        if proposal.status == TokenGatedVoteProposalStatus::Pending {
+            lifecycle::transition(
+                env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::Active,
+            )?;
+        }
+
+        let record = TokenGatedVoteRecord {
+            choice: choice.clone(),
+            weight: 1,
+        };
+        let voter_key = TokenGatedVoteContractDataKey::Voter(user.clone());
+        let first_vote = !env.storage().persistent().has(&voter_key);
+
+        Self::write_proposal(env, &id, &proposal);
+        env.storage().persistent().set(&vote_key, &record);
+        env.storage().persistent().set(&voter_key, &true);
+
+        env.storage()
+            .persistent()
+            .extend_ttl(&vote_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+        env.storage()
+            .persistent()
+            .extend_ttl(&voter_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+
+        stats::record_vote(env, first_vote, burned);
+        leaderboard::record_vote(env, &user);
+
+        if let Some(reputation) = env
             .storage()
             .instance()
-            .has(&TokenGatedVoteContractDataKey::Admin)
+            .get::<TokenGatedVoteContractDataKey, Address>(
+                &TokenGatedVoteContractDataKey::Reputation,
+            )
         {
-            return Err(TokenGatedVoteContractErrors::ContractAlreadyInitialized);
+            ReputationClient::new(env, &reputation)
+                .record_vote(&env.current_contract_address(), &user);
         }
-        env.storage()
-            .instance()
-            .set(&TokenGatedVoteContractDataKey::Admin, &admin);
-        env.storage()
+        if let Some(badges) = env
+            .storage()
             .instance()
-            .set(&TokenGatedVoteContractDataKey::Token, &token);
+            .get::<TokenGatedVoteContractDataKey, Address>(&TokenGatedVoteContractDataKey::Badges)
+        {
+            BadgeClient::new(env, &badges).mint_badge(&env.current_contract_address(), &user, &id);
+        }
+
+        events::vote_cast(env, &id, &user, &choice, 1);
         Ok(())
     }
 
-    // Creates a proposal after validating timing and uniqueness
-    pub fn create_proposal(
-        env: Env,
-        id: Symbol,
+    // Stores a new proposal after validating timing, uniqueness, and the proposer's voting power;
+    // non-admin proposals start as a Draft awaiting sponsorship while endorsements are required,
+    // all others as Pending
+    fn insert_proposal(
+        env: &Env,
+        id: &Symbol,
         description: String,
+        category: &Symbol,
         start_time: u64,
         end_time: u64,
+        proposer: &Address,
     ) -> Result<(), TokenGatedVoteContractErrors> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&TokenGatedVoteContractDataKey::Admin)
-            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
-        admin.require_auth();
+        let config = config::read(env);
+        if config.paused {
+            return Err(TokenGatedVoteContractErrors::ContractPaused);
+        }
+        let rules = categories::resolve(env, category, &config)?;
         let ledger_time = env.ledger().timestamp();
-        Self::validate_proposal_times(ledger_time, start_time, end_time)?;
+        governance_core::validate_proposal_times(
+            ledger_time,
+            start_time,
+            end_time,
+            rules.min_duration,
+            rules.max_duration,
+        )?;
+        if description.is_empty() || description.len() > config.max_description_length {
+            return Err(TokenGatedVoteContractErrors::InvalidDescription);
+        }
 
+        // A recorded digest reserves the ID of a proposal whose entry was lost until it is restored
         let proposal_key = TokenGatedVoteContractDataKey::Proposal(id.clone());
-        if env.storage().persistent().has(&proposal_key) {
+        if env.storage().persistent().has(&proposal_key) || archive::read_digest(env, id).is_some()
+        {
             return Err(TokenGatedVoteContractErrors::ProposalAlreadyExists);
         }
+        let mut proposer_power = 0;
+        let mut status = TokenGatedVoteProposalStatus::Pending;
+        if *proposer != Self::read_admin(env)? {
+            proposer_power = Self::voting_balance(env, proposer)?;
+            if proposer_power < config.proposal_threshold {
+                return Err(TokenGatedVoteContractErrors::InsufficientProposalPower);
+            }
+            ratelimit::record(env, proposer, &config)?;
+            if config.sponsors_required > 0 {
+                status = TokenGatedVoteProposalStatus::Draft;
+            }
+        }
 
+        let (start_ledger, end_ledger) = timing::ledger_window(env, start_time, end_time);
         let proposal = TokenGatedVoteProposalData {
             description,
+            category: category.clone(),
+            proposer: proposer.clone(),
+            proposer_power,
             start_time,
             end_time,
+            start_ledger,
+            end_ledger,
+            timing: config.timing_mode,
+            quorum: rules.quorum,
+            min_voters: rules.min_voters,
+            threshold: rules.threshold,
+            status,
+            finalized_at: 0,
+            queued_at: 0,
             total_for: 0,
             total_against: 0,
             total_abstain: 0,
+            voter_count: 0,
+            burned: 0,
+            content_hash: None,
         };
-        env.storage().persistent().set(&proposal_key, &proposal);
-
-        let proposal_ttl = Self::calculate_proposal_ttl(&env, end_time);
-        env.storage()
-            .persistent()
-            .extend_ttl(&proposal_key, proposal_ttl, proposal_ttl);
+        Self::write_proposal(env, id, &proposal);
 
         let mut proposals: Vec<Symbol> = env
             .storage()
             .persistent()
             .get(&TokenGatedVoteContractDataKey::Proposals)
-            .unwrap_or(Vec::new(&env));
+            .unwrap_or(Vec::new(env));
         proposals.push_back(id.clone());
         env.storage()
             .persistent()
@@ -211,115 +573,2071 @@ impl TokenGatedVoteContract {
             PROPOSALS_TTL_EXTENSION,
         );
 
-        env.events().publish(("PROPOSAL", "CREATED"), id);
+        if status == TokenGatedVoteProposalStatus::Pending {
+            stats::record_opened(env)?;
+        }
+        index::insert(env, id, end_time);
+        stats::record_proposal(env);
+        leaderboard::record_proposal(env, proposer);
+
+        events::proposal_created(env, id, proposer, start_time, end_time);
         Ok(())
     }
 
-    // Records a user's vote on an active proposal after eligibility checks
-    pub fn vote(
-        env: Env,
-        user: Address,
-        id: Symbol,
-        choice: Symbol,
-    ) -> Result<(), TokenGatedVoteContractErrors> {
-        user.require_auth();
-
-        let proposal_key = TokenGatedVoteContractDataKey::Proposal(id.clone());
-        let mut proposal: TokenGatedVoteProposalData = env
-            .storage()
-            .persistent()
-            .get(&proposal_key)
-            .ok_or(TokenGatedVoteContractErrors::ProposalNotFound)?;
+    // Returns whether a proposal is queued, with or without the council's co-approval
+    fn is_queued(proposal: &TokenGatedVoteProposalData) -> bool {
+        matches!(
+            proposal.status,
+            TokenGatedVoteProposalStatus::Queued | TokenGatedVoteProposalStatus::ReadyToExecute
+        )
+    }
 
-        let ledger_time = env.ledger().timestamp();
-        if ledger_time < proposal.start_time || ledger_time > proposal.end_time {
-            return Err(TokenGatedVoteContractErrors::VotingNotActive);
+    // Fails unless the editor is the proposer or the admin and the proposal is a draft or
+    // pending proposal whose voting window has not opened
+    fn require_editable(
+        env: &Env,
+        editor: &Address,
+        proposal: &TokenGatedVoteProposalData,
+    ) -> Result<(), Error> {
+        if *editor != proposal.proposer && *editor != Self::read_admin(env)? {
+            return Err(TokenGatedVoteModuleErrors::NotProposalEditor.into());
         }
+        if !matches!(
+            proposal.status,
+            TokenGatedVoteProposalStatus::Draft | TokenGatedVoteProposalStatus::Pending
+        ) || timing::window(env, proposal) != VotingWindow::Pending
+        {
+            return Err(TokenGatedVoteContractErrors::InvalidStateTransition.into());
+        }
+        Ok(())
+    }
 
-        let votes_key = TokenGatedVoteContractDataKey::Votes(user.clone());
-        let mut votes: Map<Symbol, bool> = env
-            .storage()
-            .persistent()
-            .get(&votes_key)
-            .unwrap_or(Map::new(&env));
+    // Requires the proposer's authorization and submits the proposal, see submit_proposal
+    fn propose(
+        env: &Env,
+        proposer: Address,
+        id: Symbol,
+        category: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        proposer.require_auth();
+        Self::submit_proposal(
+            env,
+            &proposer,
+            id,
+            category,
+            description,
+            start_time,
+            end_time,
+        )
+    }
 
-        if votes.contains_key(id.clone()) {
-            return Err(TokenGatedVoteContractErrors::UserAlreadyVoted);
+    // Checks an authorized proposer against the open proposing flag and then the category's
+    // proposer rule, stores the proposal, and charges non-admin proposers the proposal fee
+    fn submit_proposal(
+        env: &Env,
+        proposer: &Address,
+        id: Symbol,
+        category: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        let admin = Self::read_admin(env)?;
+        let config = config::read(env);
+        let sequential = sequence::is_next(env, &id);
+        if config.sequential_ids && !sequential {
+            return Err(TokenGatedVoteContractErrors::SequentialIdsRequired);
+        }
+        if *proposer != admin {
+            if !config.open_proposing {
+                return Err(TokenGatedVoteContractErrors::ProposalsRestricted);
+            }
+            // An admin-only category stays closed even while open proposing is enabled
+            if categories::resolve(env, &category, &config)?.proposers
+                == TokenGatedVoteProposerRule::Admin
+            {
+                return Err(TokenGatedVoteContractErrors::ProposalsRestricted);
+            }
         }
 
-        let token_address: Address = env
-            .storage()
-            .instance()
-            .get(&TokenGatedVoteContractDataKey::Token)
-            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
-        let token_client = TokenClient::new(&env, &token_address);
-        let token_balance = token_client.balance(&user);
-        if token_balance <= 0 {
-            return Err(TokenGatedVoteContractErrors::UserCannotVote);
+        Self::insert_proposal(
+            env,
+            &id,
+            description,
+            &category,
+            start_time,
+            end_time,
+            proposer,
+        )?;
+        if sequential {
+            sequence::advance(env);
+        }
+        if *proposer != admin {
+            let token = Self::read_token(env)?;
+            fees::charge(env, &token, proposer, &id)?;
         }
+        Ok(())
+    }
 
-        if choice == VOTE_FOR {
-            proposal.total_for = proposal.total_for.saturating_add(1);
-        } else if choice == VOTE_AGAINST {
-            proposal.total_against = proposal.total_against.saturating_add(1);
-        } else if choice == VOTE_ABSTAIN {
-            proposal.total_abstain = proposal.total_abstain.saturating_add(1);
-        } else {
-            return Err(TokenGatedVoteContractErrors::InvalidChoice);
+    // Creates an admin proposal carrying a treasury payout executed with it
+    fn insert_treasury_proposal(
+        env: &Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        action: TokenGatedVoteTreasuryAction,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        if action.amount <= 0 {
+            return Err(TokenGatedVoteContractErrors::InvalidAmount);
         }
+        let admin = Self::read_admin(env)?;
+        Self::create_proposal(
+            env.clone(),
+            admin,
+            id.clone(),
+            description,
+            start_time,
+            end_time,
+        )?;
+        treasury::write_action(env, &id, &action);
 
-        votes.set(id.clone(), true);
+        events::publish(
+            env,
+            "TREASURY",
+            "PROPOSED",
+            id,
+            (action.recipient, action.amount),
+        );
+        Ok(())
+    }
 
-        env.storage().persistent().set(&proposal_key, &proposal);
-        env.storage().persistent().set(&votes_key, &votes);
+    // Stores the finalized outcome of a proposal and credits its turnout, and its proposer when
+    // it succeeded
+    fn settle_outcome(
+        env: &Env,
+        id: &Symbol,
+        mut proposal: TokenGatedVoteProposalData,
+        outcome: TokenGatedVoteProposalStatus,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        lifecycle::transition(env, id, &mut proposal, outcome)?;
+        proposal.finalized_at = env.ledger().timestamp();
+        Self::write_proposal(env, id, &proposal);
+        quorum::record_turnout(env, lifecycle::tally(&proposal).turnout());
 
-        let proposal_ttl = Self::calculate_proposal_ttl(&env, proposal.end_time);
-        env.storage()
-            .persistent()
-            .extend_ttl(&proposal_key, proposal_ttl, proposal_ttl);
+        if outcome == TokenGatedVoteProposalStatus::Succeeded {
+            council::record_holders_approval(env, id);
+            leaderboard::record_passed(env, &proposal.proposer);
+            if let Some(reputation) = env
+                .storage()
+                .instance()
+                .get::<TokenGatedVoteContractDataKey, Address>(
+                    &TokenGatedVoteContractDataKey::Reputation,
+                )
+            {
+                ReputationClient::new(env, &reputation)
+                    .record_proposal_passed(&env.current_contract_address(), &proposal.proposer);
+            }
+        }
+        Ok(())
+    }
 
-        env.storage()
-            .persistent()
-            .extend_ttl(&votes_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+    // Finalizes every open member of a bundle together: while all members pass each keeps its
+    // outcome, otherwise members that passed on their own are Defeated so none can execute.
+    // Members cancelled, vetoed, or still in draft count as failed; returns the outcome of the
+    // member finalization was requested for
+    fn finalize_bundle(
+        env: &Env,
+        id: &Symbol,
+        bundle_id: &Symbol,
+    ) -> Result<TokenGatedVoteProposalStatus, TokenGatedVoteContractErrors> {
+        let bundle =
+            bundles::read(env, bundle_id).ok_or(TokenGatedVoteContractErrors::ProposalNotFound)?;
+        if bundle.finalized {
+            return Err(TokenGatedVoteContractErrors::InvalidStateTransition);
+        }
+        let is_open = |proposal: &TokenGatedVoteProposalData| {
+            !lifecycle::is_finalized(proposal.status)
+                && proposal.status != TokenGatedVoteProposalStatus::Draft
+        };
+
+        let mut passed = true;
+        for member in bundle.proposals.iter() {
+            let proposal = Self::read_proposal(env, &member)?;
+            passed &= if is_open(&proposal) {
+                lifecycle::resolve_outcome(env, &proposal)
+                    == TokenGatedVoteProposalStatus::Succeeded
+            } else {
+                lifecycle::has_passed(proposal.status)
+            };
+        }
+        let mut outcome = TokenGatedVoteProposalStatus::Defeated;
+        for member in bundle.proposals.iter() {
+            let proposal = Self::read_proposal(env, &member)?;
+            if !is_open(&proposal) {
+                continue;
+            }
+            let member_outcome = match lifecycle::resolve_outcome(env, &proposal) {
+                TokenGatedVoteProposalStatus::Succeeded if !passed => {
+                    TokenGatedVoteProposalStatus::Defeated
+                }
+                member_outcome => member_outcome,
+            };
+            Self::settle_outcome(env, &member, proposal, member_outcome)?;
+            if member == *id {
+                outcome = member_outcome;
+            }
+        }
+        bundles::finalize(env, bundle_id, bundle, passed);
+        Ok(outcome)
+    }
 
-        env.events().publish(("VOTE", id, user), (choice, 1));
+    // Requires the admin's authorization to queue or execute a proposal, except for config change
+    // proposals, which anyone can queue and execute so passed rule changes need no admin action
+    fn require_executor(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        let admin = Self::read_admin(env)?;
+        if config::read_change(env, id).is_none() {
+            admin.require_auth();
+        }
         Ok(())
     }
 
-    // Transfers admin role to a new address
-    pub fn transfer_admin(
+    // --- Write Functions ---
+
+    // Initializes contract with admin and governance token, either a Stellar Asset Contract or
+    // any contract implementing the standard token interface
+    pub fn __constructor(
         env: Env,
-        new_admin: Address,
+        admin: Address,
+        token: TokenSource,
     ) -> Result<(), TokenGatedVoteContractErrors> {
-        let current_admin: Address = env
+        governance_core::extend_instance_ttl(&env);
+
+        if env
             .storage()
             .instance()
-            .get(&TokenGatedVoteContractDataKey::Admin)
-            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
-
-        current_admin.require_auth();
-
+            .has(&TokenGatedVoteContractDataKey::Admin)
+        {
+            return Err(TokenGatedVoteContractErrors::ContractAlreadyInitialized);
+        }
         env.storage()
             .instance()
-            .set(&TokenGatedVoteContractDataKey::Admin, &new_admin);
-
-        env.events()
-            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+            .set(&TokenGatedVoteContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&TokenGatedVoteContractDataKey::Token, &token);
         Ok(())
     }
 
-    // --- Read-Only Functions ---
+    // Creates a proposal after validating timing and uniqueness; the admin proposes for free,
+    // other proposers need the configured proposal threshold of voting power, pay the fee if
+    // one is set, and gather sponsors if endorsements are required
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
 
-    // Returns summaries (id, description, status) for all proposals
-    pub fn get_governance_details(env: Env) -> Vec<TokenGatedVoteProposalSummary> {
-        let proposals: Vec<Symbol> = env
-            .storage()
-            .persistent()
-            .get(&TokenGatedVoteContractDataKey::Proposals)
+        shutdown::require_live(&env)?;
+        Self::propose(
+            &env,
+            proposer,
+            id,
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Creates a proposal whose full body is kept off-chain, storing a short description with the
+    // SHA-256 of the body so storage entries stay bounded while the body remains verifiable;
+    // the proposer is held to the same rules as `create_proposal`
+    pub fn create_proposal_with_content(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        content_hash: BytesN<32>,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        Self::propose(
+            &env,
+            proposer,
+            id.clone(),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        proposal.content_hash = Some(content_hash.clone());
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::publish(&env, events::PROPOSAL, "CONTENT", id, content_hash);
+        Ok(())
+    }
+
+    // Creates a proposal under the next sequential ID, e.g. PROP7, and returns its index; the
+    // proposer is held to the same rules as `create_proposal`, and the index can be read back
+    // through `get_proposal_by_index`
+    pub fn create_sequential_proposal(
+        env: Env,
+        proposer: Address,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<u32, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let index = sequence::next_index(&env);
+        Self::propose(
+            &env,
+            proposer,
+            sequence::sequential_id(&env, index),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+        Ok(index)
+    }
+
+    // Creates a proposal identified by a name longer than a short symbol allows, such as
+    // Q3-2025-BUDGET-REALLOCATION, under the next sequential ID and returns that ID, which it is
+    // voted on under; the proposer is held to the same rules as `create_proposal`
+    pub fn create_named_proposal(
+        env: Env,
+        proposer: Address,
+        name: String,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Symbol, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let id = names::next_id(&env, &name)?;
+        Self::propose(
+            &env,
+            proposer,
+            id.clone(),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+        names::record(&env, &id, &name);
+        Ok(id)
+    }
+
+    // Creates a proposal following the duration, quorum, threshold, and proposer rules of a
+    // category; non-admin proposers are otherwise held to the same rules as `create_proposal`
+    pub fn create_categorized_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        category: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        Self::propose(
+            &env,
+            proposer,
+            id,
+            category,
+            description,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Creates a proposal that pays treasury funds to a recipient once executed (admin only)
+    pub fn create_treasury_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let action = TokenGatedVoteTreasuryAction {
+            recipient,
+            amount,
+            duration: 0,
+        };
+        Self::insert_treasury_proposal(&env, id, description, start_time, end_time, action)
+    }
+
+    // Creates a proposal whose grant streams linearly from the treasury to the recipient over
+    // `grant.duration` seconds once executed (admin only)
+    pub fn create_stream_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        grant: TokenGatedVoteTreasuryAction,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        if grant.duration == 0 || grant.duration > treasury::MAX_STREAM_DURATION {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig);
+        }
+        Self::insert_treasury_proposal(&env, id, description, start_time, end_time, grant)
+    }
+
+    // Creates a proposal that pays community pool funds to a recipient once executed; the pool
+    // is funded by proposal fees and slashed dispute bonds, not treasury deposits (admin only)
+    pub fn create_pool_spend_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        spend: TokenGatedVotePoolSpend,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        if spend.amount <= 0 {
+            return Err(TokenGatedVoteContractErrors::InvalidAmount);
+        }
+        let admin = Self::read_admin(&env)?;
+        Self::create_proposal(
+            env.clone(),
+            admin,
+            id.clone(),
+            description,
+            start_time,
+            end_time,
+        )?;
+        pool::write_spend(&env, &id, &spend);
+
+        events::publish(
+            &env,
+            "POOL",
+            "PROPOSED",
+            id,
+            (spend.recipient, spend.amount),
+        );
+        Ok(())
+    }
+
+    // Creates a proposal that applies a list of configuration field changes once executed, so the
+    // governance rules can be amended by vote; the changes are validated against the current
+    // configuration now and again at execution, and the proposer is held to the same rules as
+    // `create_proposal`
+    pub fn create_config_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        changes: Vec<TokenGatedVoteConfigChange>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        config::apply(config::read(&env), &changes)?;
+        Self::propose(
+            &env,
+            proposer,
+            id.clone(),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+        config::write_change(&env, &id, &changes);
+
+        events::publish(&env, "CONFIG", "PROPOSED", id, changes);
+        Ok(())
+    }
+
+    // Creates a proposal whose voting window is given in ledger sequence numbers rather than
+    // timestamps, so validator clock drift cannot stretch or shorten it; the timestamps are
+    // estimated from the ledger close time for duration checks and display, and the window is
+    // bounded by ledgers alone unless the configured timing mode is Both
+    pub fn create_ledger_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_ledger: u32,
+        end_ledger: u32,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let (start_time, end_time) = timing::time_window(&env, start_ledger, end_ledger)?;
+        Self::propose(
+            &env,
+            proposer,
+            id.clone(),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        proposal.start_ledger = start_ledger;
+        proposal.end_ledger = end_ledger;
+        if proposal.timing == TokenGatedVoteTimingMode::Timestamp {
+            proposal.timing = TokenGatedVoteTimingMode::LedgerSequence;
+        }
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::publish(
+            &env,
+            "PROPOSAL",
+            "LEDGER_WINDOW",
+            id,
+            (start_ledger, end_ledger, proposal.timing),
+        );
+        Ok(())
+    }
+
+    // Creates a proposal that appoints or renews the guardian for `renewal.term` seconds once
+    // executed; guardian powers can only be granted this way (admin only)
+    pub fn create_guardian_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        renewal: TokenGatedVoteGuardianRenewal,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        Self::create_proposal(
+            env.clone(),
+            admin,
+            id.clone(),
+            description,
+            start_time,
+            end_time,
+        )?;
+        guardian::write_renewal(&env, &id, &renewal)?;
+
+        events::publish(
+            &env,
+            "GUARDIAN",
+            "PROPOSED",
+            id,
+            (renewal.guardian, renewal.term),
+        );
+        Ok(())
+    }
+
+    // Creates a proposal that can only execute once the proposal it depends on has executed,
+    // such as a payout that needs an approved budget first; the dependency may be created later,
+    // and a dependency chain leading back to the proposal is rejected. The proposer is held to
+    // the same rules as `create_proposal`
+    pub fn create_dependent_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        depends_on: Symbol,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        dependencies::validate(&env, &id, &depends_on)?;
+        Self::propose(
+            &env,
+            proposer,
+            id.clone(),
+            categories::DEFAULT_CATEGORY,
+            description,
+            start_time,
+            end_time,
+        )?;
+        dependencies::record(&env, &id, &depends_on);
+        Ok(())
+    }
+
+    // Creates a bundle of proposals sharing one voting window that pass or fail together, such
+    // as the interdependent line items of a budget package; every member is held to the same
+    // rules as `create_proposal`, and finalizing any member finalizes the whole bundle
+    pub fn create_bundle(
+        env: Env,
+        proposer: Address,
+        bundle_id: Symbol,
+        items: Vec<TokenGatedVoteBundleItem>,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        proposer.require_auth();
+        bundles::validate(&env, &bundle_id, &items)?;
+        for item in items.iter() {
+            Self::submit_proposal(
+                &env,
+                &proposer,
+                item.id,
+                categories::DEFAULT_CATEGORY,
+                item.description,
+                start_time,
+                end_time,
+            )?;
+        }
+        bundles::record(&env, &bundle_id, &items);
+        Ok(())
+    }
+
+    // Amends the description and voting window of a draft or pending proposal before voting
+    // opens, recording the replaced state in its bounded revision history; the new window and
+    // description are held to the rules of the proposal's category, and bundled proposals keep
+    // their shared window (proposer or admin only)
+    pub fn amend_proposal(
+        env: Env,
+        editor: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        editor.require_auth();
+
+        let config = config::read(&env);
+        if config.paused {
+            return Err(TokenGatedVoteContractErrors::ContractPaused.into());
+        }
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        Self::require_editable(&env, &editor, &proposal)?;
+        if bundles::read_bundle_of(&env, &id).is_some()
+            && (start_time != proposal.start_time || end_time != proposal.end_time)
+        {
+            return Err(TokenGatedVoteContractErrors::InvalidConfig.into());
+        }
+
+        let rules = categories::resolve(&env, &proposal.category, &config)?;
+        governance_core::validate_proposal_times(
+            env.ledger().timestamp(),
+            start_time,
+            end_time,
+            rules.min_duration,
+            rules.max_duration,
+        )
+        .map_err(TokenGatedVoteContractErrors::from)?;
+        if description.is_empty() || description.len() > config.max_description_length {
+            return Err(TokenGatedVoteContractErrors::InvalidDescription.into());
+        }
+
+        revisions::record(&env, &id, &editor, &proposal)?;
+        let (start_ledger, end_ledger) = timing::ledger_window(&env, start_time, end_time);
+        proposal.description = description;
+        proposal.start_time = start_time;
+        proposal.end_time = end_time;
+        proposal.start_ledger = start_ledger;
+        proposal.end_ledger = end_ledger;
+        Self::write_proposal(&env, &id, &proposal);
+        index::remove(&env, &id);
+        index::insert(&env, &id, end_time);
+
+        events::proposal_amended(&env, &id, &proposal.description, start_time, end_time);
+        Ok(())
+    }
+
+    // Anchors the off-chain discussion thread of a draft or pending proposal before voting opens,
+    // storing its URL with the SHA-256 of its content; later calls replace the anchor, so the
+    // hash can follow the thread until voting starts (proposer or admin only)
+    pub fn set_discussion(
+        env: Env,
+        editor: Address,
+        id: Symbol,
+        discussion_hash: BytesN<32>,
+        discussion_url: String,
+    ) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        editor.require_auth();
+
+        if config::read(&env).paused {
+            return Err(TokenGatedVoteContractErrors::ContractPaused.into());
+        }
+        let proposal = Self::read_proposal(&env, &id)?;
+        Self::require_editable(&env, &editor, &proposal)?;
+        discussions::record(&env, &id, discussion_hash, discussion_url)?;
+        Ok(())
+    }
+
+    // Creates a proposal and queues it for keeper activation at its start_time (admin only)
+    pub fn schedule_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        Self::create_proposal(
+            env.clone(),
+            admin,
+            id.clone(),
+            description,
+            start_time,
+            end_time,
+        )?;
+        schedule::enqueue(&env, &id, start_time);
+
+        events::publish(&env, events::PROPOSAL, "SCHEDULED", id, start_time);
+        Ok(())
+    }
+
+    // Configures the cadence of recurring election cycles (admin only)
+    pub fn set_cycle_config(env: Env, period: u64, duration: u64, seats: u32) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let config = TokenGatedVoteCycleConfig {
+            period,
+            duration,
+            seats,
+        };
+        cycles::validate_config(&env, &config)?;
+        env.storage().instance().set(
+            &TokenGatedVoteContractDataKey::Cycle(CycleKey::Config),
+            &config,
+        );
+
+        events::config_updated(&env, "CYCLE", config);
+        Ok(())
+    }
+
+    // Creates the next cycle's election proposal once the previous one ended (permissionless)
+    pub fn start_next_cycle(env: Env) -> Result<TokenGatedVoteElectionCycle, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        let config = cycles::read_config(&env)?;
+        let cycle = cycles::next_cycle(&env, &config)?;
+
+        Self::insert_proposal(
+            &env,
+            &cycle.id,
+            String::from_str(&env, "Recurring election cycle"),
+            &categories::DEFAULT_CATEGORY,
+            cycle.start_time,
+            cycle.end_time,
+            &admin,
+        )?;
+        schedule::enqueue(&env, &cycle.id, cycle.start_time);
+        cycles::write_current(&env, &cycle);
+
+        events::publish(&env, "CYCLE", "STARTED", cycle.id.clone(), cycle.clone());
+        Ok(cycle)
+    }
+
+    // Records a user's vote on an active proposal after eligibility checks
+    pub fn vote(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        user.require_auth();
+
+        Self::cast_vote(&env, user, id, choice, true)
+    }
+
+    // Returns the tokens a voter escrowed on a proposal once its voting window has ended or
+    // the proposal was finalized early
+    pub fn release_locked(env: Env, user: Address, id: Symbol) -> Result<i128, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        if timing::window(&env, &proposal) != VotingWindow::Ended
+            && !lifecycle::is_finalized(proposal.status)
+        {
+            return Err(TokenGatedVoteModuleErrors::TokensStillLocked.into());
+        }
+        let token = Self::read_token_source(&env)?;
+        Ok(locks::release(&env, &token, &user, &id)?)
+    }
+
+    // Registers the ed25519 public key used to verify the caller's off-chain signed votes
+    pub fn set_signing_key(
+        env: Env,
+        user: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        user.require_auth();
+        signatures::write_signing_key(&env, &user, &public_key);
+
+        events::publish(&env, "SIGNER", "UPDATED", user, public_key);
+        Ok(())
+    }
+
+    // Records a vote signed off-chain, submitted by any relayer
+    pub fn vote_by_sig(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+        expiration: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        signatures::verify(&env, &user, &id, &choice, expiration, &signature)?;
+        Self::cast_vote(&env, user.clone(), id, choice, false)?;
+        signatures::consume_nonce(&env, &user);
+        Ok(())
+    }
+
+    // Verifies and tallies a batch of signed votes, reporting the outcome of each item;
+    // rejected items are skipped while an invalid signature aborts the whole batch
+    pub fn submit_signed_votes(
+        env: Env,
+        relayer: Address,
+        votes: Vec<TokenGatedVoteSignedVote>,
+    ) -> Result<Vec<TokenGatedVoteSignedVoteResult>, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        relayer.require_auth();
+        if votes.len() > MAX_SIGNED_BATCH {
+            return Err(TokenGatedVoteContractErrors::BatchTooLarge);
+        }
+
+        let mut results = Vec::new(&env);
+        let mut accepted_count: u32 = 0;
+        for signed in votes.iter() {
+            let outcome = signatures::verify(
+                &env,
+                &signed.user,
+                &signed.id,
+                &signed.choice,
+                signed.expiration,
+                &signed.signature,
+            )
+            .and_then(|_| {
+                Self::cast_vote(
+                    &env,
+                    signed.user.clone(),
+                    signed.id.clone(),
+                    signed.choice.clone(),
+                    false,
+                )
+            });
+            if outcome.is_ok() {
+                signatures::consume_nonce(&env, &signed.user);
+                accepted_count += 1;
+            }
+            results.push_back(TokenGatedVoteSignedVoteResult {
+                user: signed.user,
+                id: signed.id,
+                accepted: outcome.is_ok(),
+                error: outcome.err().map_or(0, |error| error as u32),
+            });
+        }
+
+        events::publish(
+            &env,
+            "SIGNED",
+            "SETTLED",
+            relayer,
+            (accepted_count, votes.len()),
+        );
+        Ok(results)
+    }
+
+    // Endorses a draft proposal as an eligible holder, promoting it to Pending once enough
+    // distinct holders have endorsed it
+    pub fn sponsor(env: Env, sponsor: Address, id: Symbol) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        sponsor.require_auth();
+
+        let config = config::read(&env);
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if !Self::is_eligible(&env, &sponsor, &config)? {
+            return Err(TokenGatedVoteContractErrors::UserCannotVote.into());
+        }
+        if sponsors::sponsor(&env, &sponsor, &id, &mut proposal, config.sponsors_required)? {
+            Self::write_proposal(&env, &id, &proposal);
+        }
+        Ok(())
+    }
+
+    // Withdraws an endorsement from a proposal that is still a draft
+    pub fn withdraw_sponsorship(env: Env, sponsor: Address, id: Symbol) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        sponsor.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(sponsors::withdraw(&env, &sponsor, &id, &proposal)?)
+    }
+
+    // Moves a Pending proposal to Active once its voting window has opened
+    pub fn activate_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if timing::window(&env, &proposal) != VotingWindow::Active {
+            return Err(TokenGatedVoteContractErrors::VotingNotActive);
+        }
+        lifecycle::transition(
+            &env,
+            &id,
+            &mut proposal,
+            TokenGatedVoteProposalStatus::Active,
+        )?;
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Activates every scheduled proposal whose start_time has passed (permissionless keeper)
+    pub fn activate_due_proposals(env: Env) -> u32 {
+        governance_core::extend_instance_ttl(&env);
+
+        if shutdown::is_shut_down(&env) {
+            return 0;
+        }
+        let ledger_time = env.ledger().timestamp();
+        let mut activated = 0;
+        for id in schedule::take_due(&env, ledger_time, MAX_KEEPER_ACTIVATIONS).iter() {
+            let mut proposal = match Self::read_proposal(&env, &id) {
+                Ok(proposal) => proposal,
+                Err(_) => continue,
+            };
+            // Proposals already activated by a vote, cancelled, or past their window are dropped,
+            // and ledger-timed proposals whose start ledger has not closed yet are requeued
+            if proposal.status != TokenGatedVoteProposalStatus::Pending {
+                continue;
+            }
+            match timing::window(&env, &proposal) {
+                VotingWindow::Ended => continue,
+                VotingWindow::Pending => {
+                    let retry_at = ledger_time.saturating_add(timing::LEDGER_SECONDS);
+                    schedule::enqueue(&env, &id, retry_at);
+                    continue;
+                }
+                VotingWindow::Active => {}
+            }
+            if lifecycle::transition(
+                &env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::Active,
+            )
+            .is_ok()
+            {
+                Self::write_proposal(&env, &id, &proposal);
+                activated += 1;
+            }
+        }
+        activated
+    }
+
+    // Resolves the outcome of a proposal after its voting window has closed; finalizing a member
+    // of a bundle finalizes the whole bundle
+    pub fn finalize_proposal(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteProposalStatus, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let proposal = Self::read_proposal(&env, &id)?;
+        if timing::window(&env, &proposal) != VotingWindow::Ended {
+            return Err(TokenGatedVoteContractErrors::VotingNotEnded);
+        }
+        if lifecycle::in_grace_period(&env, &proposal, config::read(&env).grace_period) {
+            return Err(TokenGatedVoteContractErrors::GracePeriodActive);
+        }
+        if let Some(bundle_id) = bundles::read_bundle_of(&env, &id) {
+            if !lifecycle::can_transition(proposal.status, TokenGatedVoteProposalStatus::Defeated) {
+                return Err(TokenGatedVoteContractErrors::InvalidStateTransition);
+            }
+            return Self::finalize_bundle(&env, &id, &bundle_id);
+        }
+
+        let outcome = lifecycle::resolve_outcome(&env, &proposal);
+        Self::settle_outcome(&env, &id, proposal, outcome)?;
+        Ok(outcome)
+    }
+
+    // Resolves the outcome through the governance interface, see finalize_proposal
+    pub fn finalize(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceStatus, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        Self::finalize_proposal(env, id).map(lifecycle::governance_status)
+    }
+
+    // Extends the TTL of a proposal and its recorded digest so a long-lived record is not
+    // archived; open to anyone, such as a keeper watching TTLs
+    pub fn extend_proposal_ttl(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        let proposal_ttl = Self::calculate_proposal_ttl(&env, &proposal);
+        env.storage().persistent().extend_ttl(
+            &TokenGatedVoteContractDataKey::Proposal(id.clone()),
+            proposal_ttl,
+            proposal_ttl,
+        );
+        archive::extend(&env, &id);
+        names::extend(&env, &id);
+        dependencies::extend(&env, &id);
+        revisions::extend(&env, &id);
+        discussions::extend(&env, &id);
+        council::extend(&env, &id);
+        Ok(())
+    }
+
+    // Re-hydrates a proposal whose entry is gone from contract storage; the data must match the
+    // digest recorded when the proposal was last written, so only its exact last state returns.
+    // An entry that is merely archived comes back through the network's restore operation
+    // instead (admin only)
+    pub fn restore_proposal(
+        env: Env,
+        id: Symbol,
+        proposal: TokenGatedVoteProposalData,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if env
+            .storage()
+            .persistent()
+            .has(&TokenGatedVoteContractDataKey::Proposal(id.clone()))
+        {
+            return Err(TokenGatedVoteContractErrors::ProposalAlreadyExists);
+        }
+        archive::verify(&env, &id, &proposal)?;
+        Self::write_proposal(&env, &id, &proposal);
+
+        events::publish(
+            &env,
+            events::PROPOSAL,
+            "RESTORED",
+            id,
+            archive::digest(&env, &proposal),
+        );
+        Ok(())
+    }
+
+    // Cancels a proposal that has not been finalized yet (admin only)
+    pub fn cancel_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        lifecycle::transition(
+            &env,
+            &id,
+            &mut proposal,
+            TokenGatedVoteProposalStatus::Cancelled,
+        )?;
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Vetoes a succeeded or queued proposal before execution, or one whose voting ended within
+    // the grace period (admin only)
+    pub fn veto_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        lifecycle::veto(&env, &id, &mut proposal, config::read(&env).grace_period)?;
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Vetoes a succeeded or queued proposal, or one whose voting ended within the grace period,
+    // while the guardian's powers last (guardian only)
+    pub fn guardian_veto(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        guardian::require_guardian(&env)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        lifecycle::veto(&env, &id, &mut proposal, config::read(&env).grace_period)?;
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Pauses or resumes proposal creation and voting while the guardian's powers last
+    // (guardian only)
+    pub fn guardian_set_paused(env: Env, paused: bool) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        guardian::require_guardian(&env)?;
+        let mut config = config::read(&env);
+        config.paused = paused;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Queues a succeeded proposal for execution within the execution window (admin only, or
+    // anyone for config change proposals)
+    pub fn queue_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        Self::require_executor(&env, &id)?;
+
+        disputes::require_not_frozen(&env, &id)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        let ledger_time = env.ledger().timestamp();
+        if ledger_time
+            > proposal
+                .end_time
+                .saturating_add(lifecycle::EXECUTION_WINDOW)
+        {
+            return Err(TokenGatedVoteContractErrors::ExecutionWindowClosed);
+        }
+        lifecycle::transition(
+            &env,
+            &id,
+            &mut proposal,
+            TokenGatedVoteProposalStatus::Queued,
+        )?;
+        proposal.queued_at = ledger_time;
+        if council::read_council(&env).is_some() && council::read_approval(&env, &id).is_some() {
+            lifecycle::transition(
+                &env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::ReadyToExecute,
+            )?;
+        }
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Marks a queued proposal as executed, applying its payout or config change (admin only, or
+    // anyone for config change proposals)
+    pub fn execute_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        Self::require_executor(&env, &id)?;
+
+        disputes::require_not_frozen(&env, &id)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        if Self::is_queued(&proposal)
+            && env.ledger().timestamp()
+                > proposal
+                    .queued_at
+                    .saturating_add(lifecycle::EXECUTION_WINDOW)
+        {
+            return Err(TokenGatedVoteContractErrors::ExecutionWindowClosed);
+        }
+        council::require_approved(&env, &proposal)?;
+        treasury::require_exit_window_closed(&env, &id, &proposal)?;
+        bundles::require_passed(&env, &id)?;
+        dependencies::require_executed(&env, &id)?;
+        conditions::require_met(&env, &id)?;
+        hierarchy::require_cleared(&env, &id)?;
+        lifecycle::transition(
+            &env,
+            &id,
+            &mut proposal,
+            TokenGatedVoteProposalStatus::Executed,
+        )?;
+        Self::write_proposal(&env, &id, &proposal);
+
+        treasury::execute(&env, &id)?;
+        pool::execute(&env, &id)?;
+        config::execute(&env, &id)?;
+        guardian::execute(&env, &id);
+        Ok(())
+    }
+
+    // Expires a succeeded or queued proposal whose execution window has elapsed
+    pub fn expire_proposal(env: Env, id: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        let window_start = if Self::is_queued(&proposal) {
+            proposal.queued_at
+        } else {
+            proposal.end_time
+        };
+        if env.ledger().timestamp() <= window_start.saturating_add(lifecycle::EXECUTION_WINDOW) {
+            return Err(TokenGatedVoteContractErrors::ExecutionWindowOpen);
+        }
+        lifecycle::transition(
+            &env,
+            &id,
+            &mut proposal,
+            TokenGatedVoteProposalStatus::Expired,
+        )?;
+        Self::write_proposal(&env, &id, &proposal);
+        Ok(())
+    }
+
+    // Co-approves a proposal that passed its vote in dual-approval mode, within the council's
+    // approval window after voting ends; a queued proposal becomes ReadyToExecute at once, a
+    // succeeded one when it is queued (council only)
+    pub fn council_approve(env: Env, id: Symbol) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        disputes::require_not_frozen(&env, &id)?;
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        council::approve(&env, &id, &proposal)?;
+        if proposal.status == TokenGatedVoteProposalStatus::Queued {
+            lifecycle::transition(
+                &env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::ReadyToExecute,
+            )?;
+            Self::write_proposal(&env, &id, &proposal);
+        }
+        Ok(())
+    }
+
+    // Attaches price conditions to a treasury proposal before its voting window opens, replacing
+    // any set earlier; an empty list removes them (admin only)
+    pub fn set_execution_conditions(
+        env: Env,
+        id: Symbol,
+        conditions: Vec<TokenGatedVoteExecutionCondition>,
+    ) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        conditions::set(&env, &id, &proposal, &conditions)
+    }
+
+    // Has the treasury cancel a streamed grant, stopping future accrual and returning the
+    // unvested remainder to the treasury balance (admin only)
+    pub fn cancel_stream(env: Env, grant_id: Symbol) -> Result<i128, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        treasury::cancel_stream(&env, &grant_id)
+    }
+
+    // Sets the treasury contract that treasury proposals, treasury fees, and rage-quit exits go
+    // through; it must name this contract as its governor (admin only)
+    pub fn set_treasury(env: Env, treasury: Address) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        treasury::set_address(&env, &treasury, &config::read(&env));
+
+        events::publish(&env, "TREASURY", "SET", admin, treasury);
+        Ok(())
+    }
+
+    // Sets the treasury payouts allowed across executed proposals per rolling period, a cap of
+    // 0 disables the limit (admin only)
+    pub fn set_spending_limit(
+        env: Env,
+        cap: i128,
+        period: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.spending_cap = cap;
+        config.spending_period = period;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Enables or disables escrowing each direct voter's tokens until the proposal ends; locks
+    // taken earlier stay releasable either way (admin only)
+    pub fn set_vote_lock(env: Env, enabled: bool) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.vote_lock = enabled;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the governance tokens burned from each direct voter's balance as a costly signal,
+    // 0 disables burning (admin only)
+    pub fn set_vote_burn(env: Env, amount: i128) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.vote_burn = amount;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets which clock bounds the voting windows of new proposals: timestamps, ledger sequence
+    // numbers, or both with the stricter window applying (admin only)
+    pub fn set_timing_mode(
+        env: Env,
+        mode: TokenGatedVoteTimingMode,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.timing_mode = mode;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the most proposals that can be Pending or Active at once, keeping reads and voter
+    // attention bounded; 0 removes the cap (admin only)
+    pub fn set_max_active_proposals(
+        env: Env,
+        max_active: u32,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.max_active_proposals = max_active;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the cooldown non-admin proposers wait between proposals and the most proposals each
+    // can create per rolling period; 0 disables either limit (admin only)
+    pub fn set_proposer_limits(
+        env: Env,
+        cooldown: u64,
+        cap: u32,
+        period: u64,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.proposer_cooldown = cooldown;
+        config.proposals_per_period = cap;
+        config.proposal_period = period;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the seconds after a proposal's end_time during which finalization is rejected and
+    // only vetoes are allowed, at most ~7 days; 0 disables the grace period (admin only)
+    pub fn set_grace_period(env: Env, seconds: u64) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.grace_period = seconds;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the longest description new proposals can store on-chain, in bytes; longer bodies
+    // belong off-chain behind a content hash (admin only)
+    pub fn set_max_description_length(
+        env: Env,
+        length: u32,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.max_description_length = length;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Enables or disables enforcing sequential proposal IDs; while enforced, every proposal
+    // created with an ID of the caller's choosing must use the next sequential one (admin only)
+    pub fn set_sequential_ids(env: Env, enabled: bool) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.sequential_ids = enabled;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Enables or disables proposal creation by non-admin addresses, which stay subject to the
+    // proposal threshold, fee, and sponsorship rules (admin only)
+    pub fn set_open_proposing(env: Env, enabled: bool) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.open_proposing = enabled;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the endorsements non-admin drafts need before becoming Pending, 0 disables the
+    // sponsorship phase (admin only)
+    pub fn set_sponsors_required(env: Env, count: u32) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.sponsors_required = count;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the voting power non-admin proposers must hold to create proposals (admin only)
+    pub fn set_proposal_threshold(
+        env: Env,
+        threshold: i128,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.proposal_threshold = threshold;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the quorum applied to proposals created from now on (admin only)
+    pub fn set_quorum(env: Env, quorum: i128) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.quorum = quorum;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Enables or disables the turnout-based quorum of new proposals, which the static quorum
+    // still bounds from below (admin only)
+    pub fn set_dynamic_quorum(
+        env: Env,
+        dynamic: Option<TokenGatedVoteDynamicQuorum>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        match dynamic {
+            Some(settings) => {
+                quorum::validate(&settings)?;
+                env.storage().instance().set(
+                    &TokenGatedVoteContractDataKey::Quorum(QuorumKey::Dynamic),
+                    &settings,
+                );
+            }
+            None => env
+                .storage()
+                .instance()
+                .remove(&TokenGatedVoteContractDataKey::Quorum(QuorumKey::Dynamic)),
+        }
+
+        events::config_updated(&env, "DYNAMIC_QUORUM", dynamic);
+        Ok(())
+    }
+
+    // Replaces the governance configuration after validating every field (admin only)
+    pub fn set_config(
+        env: Env,
+        config: TokenGatedVoteGovernanceConfig,
+    ) -> Result<TokenGatedVoteGovernanceConfig, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        config::update(&env, config)
+    }
+
+    // Sets or clears the reputation contract notified on every accepted vote
+    pub fn set_reputation_contract(
+        env: Env,
+        reputation: Option<Address>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&TokenGatedVoteContractDataKey::Admin)
+            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
+        admin.require_auth();
+
+        match reputation.clone() {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&TokenGatedVoteContractDataKey::Reputation, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&TokenGatedVoteContractDataKey::Reputation),
+        }
+
+        events::config_updated(&env, "REPUTATION", reputation);
+        Ok(())
+    }
+
+    // Sets or clears the badge contract minting a proposal-specific participation badge to every
+    // accepted voter; this contract must be registered there as a minter (admin only)
+    pub fn set_badge_contract(
+        env: Env,
+        badges: Option<Address>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        match badges.clone() {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&TokenGatedVoteContractDataKey::Badges, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&TokenGatedVoteContractDataKey::Badges),
+        }
+
+        events::config_updated(&env, "BADGES", badges);
+        Ok(())
+    }
+
+    // Gates voting and sponsorship on membership of a non-transferable membership contract
+    // instead of the governance token balance, so the electorate follows contribution rather
+    // than wealth; None returns to balance gating (admin only)
+    pub fn set_membership_gate(
+        env: Env,
+        membership: Option<Address>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        membership::write_membership(&env, membership);
+        Ok(())
+    }
+
+    // Funds the participation reward pool of a proposal from the admin's balance
+    pub fn fund_rewards(env: Env, id: Symbol, amount: i128) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        let token = Self::read_token(&env)?;
+        let pool = rewards::fund(&env, &token, &admin, &id, &proposal, amount)?;
+
+        events::publish(&env, "REWARDS", "FUNDED", id, (amount, pool.total));
+        Ok(())
+    }
+
+    // Pays a voter's pro-rata share of the proposal reward pool after voting ends
+    pub fn claim_reward(env: Env, user: Address, id: Symbol) -> Result<i128, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        let token = Self::read_token(&env)?;
+        let payout = rewards::claim(&env, &token, &user, &id, &proposal)?;
+
+        events::publish(&env, "REWARDS", "CLAIMED", id, (user, payout));
+        Ok(payout)
+    }
+
+    // Returns unclaimed rewards to a recipient once the claim window has closed
+    pub fn sweep_rewards(env: Env, id: Symbol, to: Address) -> Result<i128, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        let token = Self::read_token(&env)?;
+        let swept = rewards::sweep(&env, &token, &to, &id, &proposal)?;
+
+        events::publish(&env, "REWARDS", "SWEPT", id, (to, swept));
+        Ok(swept)
+    }
+
+    // Lets an AGAINST voter exit with their pro-rata treasury share before a passed
+    // treasury proposal executes
+    pub fn rage_quit(env: Env, user: Address, id: Symbol) -> Result<i128, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        treasury::rage_quit(&env, &user, &id, &proposal)
+    }
+
+    // Sets the bond required to dispute a finalized result, 0 disables disputes (admin only)
+    pub fn set_dispute_bond(env: Env, bond: i128) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.dispute_bond = bond;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Sets the fee charged to non-admin proposers, 0 removes it (admin only)
+    pub fn set_proposal_fee(
+        env: Env,
+        amount: i128,
+        sink: TokenGatedVoteFeeSink,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut config = config::read(&env);
+        config.proposal_fee = amount;
+        config.fee_sink = sink;
+        config::update(&env, config)?;
+        Ok(())
+    }
+
+    // Disputes a finalized result within the grace period, freezing queue and execution
+    pub fn dispute_proposal(
+        env: Env,
+        challenger: Address,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteDispute, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        challenger.require_auth();
+
+        let proposal = Self::read_proposal(&env, &id)?;
+        let token = Self::read_token(&env)?;
+        disputes::open(&env, &token, &challenger, &id, &proposal)
+    }
+
+    // Rules on an open dispute: upheld releases the bond and vetoes a passed result,
+    // rejected slashes the bond into the community pool and unfreezes execution (admin only)
+    pub fn resolve_dispute(
+        env: Env,
+        id: Symbol,
+        upheld: bool,
+    ) -> Result<TokenGatedVoteDispute, Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        let mut proposal = Self::read_proposal(&env, &id)?;
+        let token = Self::read_token(&env)?;
+        let dispute = disputes::resolve(&env, &token, &id, upheld)?;
+
+        if upheld && lifecycle::has_passed(proposal.status) {
+            lifecycle::transition(
+                &env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::Vetoed,
+            )?;
+            Self::write_proposal(&env, &id, &proposal);
+        }
+        Ok(dispute)
+    }
+
+    // Transfers admin role to a new address, leaving DAO-as-admin mode if it was enabled
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let current_admin: Address = env
+            .storage()
+            .instance()
+            .get(&TokenGatedVoteContractDataKey::Admin)
+            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&TokenGatedVoteContractDataKey::Admin, &new_admin);
+        executor::clear(&env);
+
+        events::admin_transferred(&env, &current_admin, &new_admin);
+        Ok(())
+    }
+
+    // Nominates an executor or timelock contract to hold the admin role, after which admin-only
+    // actions can only happen through proposals it executes; the migration completes once the
+    // executor calls `accept_dao_admin` (admin only)
+    pub fn migrate_to_dao_admin(
+        env: Env,
+        executor: Address,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        executor::nominate(&env, &admin, &executor);
+        Ok(())
+    }
+
+    // Completes a DAO-as-admin migration, handing the admin role to the nominated executor
+    // (nominated executor only)
+    pub fn accept_dao_admin(env: Env) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let previous_admin = Self::read_admin(&env)?;
+        let executor = executor::accept(&env)?;
+        env.storage()
+            .instance()
+            .set(&TokenGatedVoteContractDataKey::Admin, &executor);
+
+        events::admin_transferred(&env, &previous_admin, &executor);
+        Ok(())
+    }
+
+    // Break-glass path out of DAO-as-admin mode: hands the admin role from the executor to
+    // `new_admin` when the executor is stuck or compromised (guardian only)
+    pub fn break_glass(env: Env, new_admin: Address) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        guardian::require_guardian(&env)?;
+        let executor = executor::require_dao_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&TokenGatedVoteContractDataKey::Admin, &new_admin);
+        executor::clear(&env);
+
+        events::admin_transferred(&env, &executor, &new_admin);
+        events::publish(&env, "EMERGENCY", "BREAK_GLASS", executor, new_admin);
+        Ok(())
+    }
+
+    // Replaces the contract code with previously uploaded WASM, keeping storage; in DAO-as-admin
+    // mode this only happens through a passed proposal (admin only)
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(wasm_hash.clone());
+        events::publish(&env, "CONTRACT", "UPGRADED", admin, wasm_hash);
+        Ok(())
+    }
+
+    // Adds a proposal category or replaces its rules; proposals already created keep the rules
+    // they were created under (admin only)
+    pub fn set_category_rules(
+        env: Env,
+        category: Symbol,
+        rules: TokenGatedVoteCategoryRules,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        categories::set(&env, &category, &rules)
+    }
+
+    // Removes a proposal category from the rules table (admin only)
+    pub fn remove_category(env: Env, category: Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        categories::remove(&env, &category)
+    }
+
+    // Registers a child governance contract or replaces its scope (admin only)
+    pub fn register_child(
+        env: Env,
+        child: Address,
+        scope: TokenGatedVoteChildScope,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        hierarchy::register(&env, &child, scope)
+    }
+
+    // Unregisters a child governance contract (admin only)
+    pub fn remove_child(env: Env, child: Address) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Ok(hierarchy::remove(&env, &child)?)
+    }
+
+    // Ratifies a passed proposal of a child within its review period (admin only)
+    pub fn ratify(env: Env, child: Address, id: Symbol) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        hierarchy::decide(&env, &child, &id, TokenGatedVoteRatification::Ratified)
+    }
+
+    // Overrides a passed proposal of a child within its review period, blocking its execution;
+    // only allowed when the child's scope grants overrides (admin only)
+    pub fn override_decision(env: Env, child: Address, id: Symbol) -> Result<(), Error> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        hierarchy::decide(&env, &child, &id, TokenGatedVoteRatification::Overridden)
+    }
+
+    // Sets the council whose co-approval passed proposals need before execution, entering
+    // dual-approval mode, or clears it to leave the mode (admin only)
+    pub fn set_council(
+        env: Env,
+        council: Option<TokenGatedVoteCouncil>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        council::write_council(&env, &council)
+    }
+
+    // Sets or clears the parent governance contract consulted before executing proposals
+    // (admin only)
+    pub fn set_parent(
+        env: Env,
+        parent: Option<Address>,
+    ) -> Result<(), TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        hierarchy::write_parent(&env, &parent)
+    }
+
+    // Permanently shuts the contract down: cancels every open proposal, refunds the bonds of
+    // unresolved disputes, and leaves only withdrawals enabled; requires both the admin and an
+    // active guardian
+    pub fn emergency_shutdown(env: Env) -> Result<u32, TokenGatedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        shutdown::require_live(&env)?;
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+        guardian::require_guardian(&env)?;
+
+        let mut cancelled = 0;
+        for (_, id) in index::read_expiry_index(&env).iter() {
+            let mut proposal = Self::read_proposal(&env, &id)?;
+            lifecycle::transition(
+                &env,
+                &id,
+                &mut proposal,
+                TokenGatedVoteProposalStatus::Cancelled,
+            )?;
+            Self::write_proposal(&env, &id, &proposal);
+            cancelled += 1;
+        }
+
+        let token = Self::read_token(&env)?;
+        let proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposals)
             .unwrap_or(Vec::new(&env));
-        let mut summary = Vec::new(&env);
+        let mut refunded = 0i128;
+        for id in proposals.iter() {
+            refunded = refunded.saturating_add(disputes::refund(&env, &token, &id));
+        }
 
-        let ledger_time = env.ledger().timestamp();
+        treasury::shut_down(&env);
+        shutdown::record(&env);
+        events::publish(&env, "EMERGENCY", "SHUTDOWN", admin, (cancelled, refunded));
+        Ok(cancelled)
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the registered signing key of a user, if any
+    pub fn get_signing_key(env: Env, user: Address) -> Option<BytesN<32>> {
+        signatures::read_signing_key(&env, &user)
+    }
+
+    // Returns the nonce the user's next signed vote must commit to
+    pub fn get_nonce(env: Env, user: Address) -> u64 {
+        signatures::read_nonce(&env, &user)
+    }
+
+    // Returns summaries (id, description, status, voter count) for all proposals
+    pub fn get_governance_details(env: Env) -> Vec<TokenGatedVoteProposalSummary> {
+        let proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(&env));
+        let mut summary = Vec::new(&env);
 
         for id in proposals.iter() {
             if let Some(proposal) = env
@@ -329,17 +2647,198 @@ impl TokenGatedVoteContract {
                     &TokenGatedVoteContractDataKey::Proposal(id.clone()),
                 )
             {
-                let status = Self::compute_proposal_status(ledger_time, &proposal);
+                let status = lifecycle::effective_status(&env, &proposal);
                 summary.push_back(TokenGatedVoteProposalSummary {
                     id: id.clone(),
                     description: proposal.description.clone(),
                     status,
+                    voter_count: proposal.voter_count,
                 });
             }
         }
         summary
     }
 
+    // Returns up to `limit` proposals whose current status matches the filter, scanning at most
+    // MAX_PAGE_LIMIT proposal IDs in creation order from `cursor`; a sparse status can yield an
+    // empty page before the end, so clients follow `next_cursor` until it is None
+    pub fn get_proposals_by_status(
+        env: Env,
+        status: TokenGatedVoteProposalStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> TokenGatedVoteProposalPage {
+        let proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(&env));
+        let limit = limit.min(MAX_PAGE_LIMIT);
+        let window_end = cursor.saturating_add(MAX_PAGE_LIMIT).min(proposals.len());
+        let mut position = cursor.min(window_end);
+        let mut page = Vec::new(&env);
+
+        while position < window_end && page.len() < limit {
+            let id = proposals.get_unchecked(position);
+            position += 1;
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<TokenGatedVoteContractDataKey, TokenGatedVoteProposalData>(
+                    &TokenGatedVoteContractDataKey::Proposal(id.clone()),
+                )
+            {
+                let proposal_status = lifecycle::effective_status(&env, &proposal);
+                if proposal_status == status {
+                    page.push_back(TokenGatedVoteProposalSummary {
+                        id,
+                        description: proposal.description,
+                        status: proposal_status,
+                        voter_count: proposal.voter_count,
+                    });
+                }
+            }
+        }
+        TokenGatedVoteProposalPage {
+            proposals: page,
+            next_cursor: (position < proposals.len()).then_some(position),
+        }
+    }
+
+    // Returns up to `limit` open proposals (id, end_time) that end soonest
+    pub fn get_expiring_soon(env: Env, limit: u32) -> Vec<(Symbol, u64)> {
+        let index = index::read_expiry_index(&env);
+        let limit = limit.min(MAX_PAGE_LIMIT);
+        let mut expiring = Vec::new(&env);
+        for (end_time, id) in index.iter() {
+            if expiring.len() >= limit {
+                break;
+            }
+            expiring.push_back((id, end_time));
+        }
+        expiring
+    }
+
+    // Returns the quorum the next proposal would receive, adjusted to recent turnout when the
+    // dynamic quorum is enabled
+    pub fn get_dynamic_quorum(env: Env) -> i128 {
+        quorum::current(&env, &config::read(&env))
+    }
+
+    // Returns the dynamic quorum settings, if enabled
+    pub fn get_dynamic_quorum_config(env: Env) -> Option<TokenGatedVoteDynamicQuorum> {
+        quorum::read_dynamic(&env)
+    }
+
+    // Returns the turnouts of the most recently finalized proposals, oldest first
+    pub fn get_turnout_history(env: Env) -> Vec<i128> {
+        quorum::read_history(&env)
+    }
+
+    // Returns the holders endorsing a proposal, in endorsement order
+    pub fn get_sponsors(env: Env, id: Symbol) -> Vec<Address> {
+        sponsors::read_sponsors(&env, &id)
+    }
+
+    // Returns the recurring election cycle configuration
+    pub fn get_cycle_config(
+        env: Env,
+    ) -> Result<TokenGatedVoteCycleConfig, TokenGatedVoteModuleErrors> {
+        cycles::read_config(&env)
+    }
+
+    // Returns the most recently started election cycle, if any
+    pub fn get_current_cycle(env: Env) -> Option<TokenGatedVoteElectionCycle> {
+        cycles::read_current(&env)
+    }
+
+    // Returns scheduled proposals awaiting keeper activation as (id, start_time) pairs
+    pub fn get_scheduled_proposals(env: Env) -> Vec<(Symbol, u64)> {
+        let mut scheduled = Vec::new(&env);
+        for (start_time, id) in schedule::read_queue(&env).iter() {
+            scheduled.push_back((id, start_time));
+        }
+        scheduled
+    }
+
+    // Returns the current lifecycle status (including the finalized outcome) of one proposal
+    pub fn get_proposal_status(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteProposalStatus, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(lifecycle::effective_status(&env, &proposal))
+    }
+
+    // Returns the current status of one proposal through the governance interface
+    pub fn get_status(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceStatus, TokenGatedVoteContractErrors> {
+        Self::get_proposal_status(env, id).map(lifecycle::governance_status)
+    }
+
+    // Returns one proposal in the shared governance interface view
+    pub fn get_proposal(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceProposal, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        let status = lifecycle::effective_status(&env, &proposal);
+        Ok(GovernanceProposal {
+            id,
+            description: proposal.description,
+            start_time: proposal.start_time,
+            end_time: proposal.end_time,
+            total_for: proposal.total_for,
+            total_against: proposal.total_against,
+            total_abstain: proposal.total_abstain,
+            status: lifecycle::governance_status(status),
+        })
+    }
+
+    // Returns aggregate counters (proposals, votes, unique voters)
+    pub fn get_governance_stats(env: Env) -> TokenGatedVoteGovernanceStats {
+        stats::read(&env)
+    }
+
+    // Returns participation figures for a single proposal
+    pub fn get_proposal_stats(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteProposalStats, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(stats::proposal_stats(&env, &proposal))
+    }
+
+    // Returns a proposal's participation so far, the quorum it needs, and the share of the quorum
+    // reached, for live progress bars
+    pub fn get_quorum_progress(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteQuorumProgress, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(stats::quorum_progress(&proposal))
+    }
+
+    // Returns the outcome a proposal would be finalized with if voting ended now: Succeeded,
+    // Defeated, or QuorumFailed under its quorum, minimum voter, and threshold snapshots
+    pub fn get_projected_outcome(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteProposalStatus, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(lifecycle::resolve_outcome(&env, &proposal))
+    }
+
+    // Returns the seconds until a draft or pending proposal opens, or until an active one closes,
+    // measured against the ledger timestamp so countdowns do not depend on client clocks; 0 once
+    // voting has ended
+    pub fn get_time_remaining(env: Env, id: Symbol) -> Result<u64, TokenGatedVoteContractErrors> {
+        let proposal = Self::read_proposal(&env, &id)?;
+        Ok(lifecycle::time_remaining(&env, &proposal))
+    }
+
     // Returns full stored data for a single proposal
     pub fn get_proposal_details(
         env: Env,
@@ -353,45 +2852,379 @@ impl TokenGatedVoteContract {
         Ok(proposal)
     }
 
-    // Returns user's vote participation and eligibility per proposal
-    pub fn get_user_details(
+    // Returns the SHA-256 digest of a proposal's XDR recorded when it was last written, which
+    // data passed to restore_proposal must match
+    pub fn get_proposal_digest(env: Env, id: Symbol) -> Option<BytesN<32>> {
+        archive::read_digest(&env, &id)
+    }
+
+    // Returns full stored data for the proposal created under a name
+    pub fn get_proposal_by_name(
+        env: Env,
+        name: String,
+    ) -> Result<TokenGatedVoteProposalData, TokenGatedVoteContractErrors> {
+        names::validate(&name)?;
+        let id =
+            names::lookup(&env, &name).ok_or(TokenGatedVoteContractErrors::ProposalNotFound)?;
+        Self::get_proposal_details(env, id)
+    }
+
+    // Returns the ID of the proposal created under a name, if any
+    pub fn get_named_proposal_id(env: Env, name: String) -> Option<Symbol> {
+        names::lookup(&env, &name)
+    }
+
+    // Returns the name a proposal was created with, if it was created by name
+    pub fn get_proposal_name(env: Env, id: Symbol) -> Option<String> {
+        names::read(&env, &id)
+    }
+
+    // Returns full stored data for the proposal with the sequential ID of an index
+    pub fn get_proposal_by_index(
+        env: Env,
+        index: u32,
+    ) -> Result<TokenGatedVoteProposalData, TokenGatedVoteContractErrors> {
+        Self::get_proposal_details(env.clone(), sequence::sequential_id(&env, index))
+    }
+
+    // Returns the proposal ID a sequential index maps to, e.g. PROP7 for 7
+    pub fn get_sequential_id(env: Env, index: u32) -> Symbol {
+        sequence::sequential_id(&env, index)
+    }
+
+    // Returns the index the next sequential proposal receives
+    pub fn get_next_proposal_index(env: Env) -> u32 {
+        sequence::next_index(&env)
+    }
+
+    // Returns the dispute raised against a proposal result, if any
+    pub fn get_dispute(env: Env, id: Symbol) -> Option<TokenGatedVoteDispute> {
+        disputes::read_dispute(&env, &id)
+    }
+
+    // Returns the configuration field changes attached to a config change proposal, if any
+    pub fn get_config_change(env: Env, id: Symbol) -> Option<Vec<TokenGatedVoteConfigChange>> {
+        config::read_change(&env, &id)
+    }
+
+    // Returns a page of the most active addresses, highest participation score first
+    pub fn get_leaderboard(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<TokenGatedVoteLeaderboardEntry> {
+        let mut page = Vec::new(&env);
+        let limit = limit.min(MAX_PAGE_LIMIT);
+        if limit == 0 {
+            return page;
+        }
+
+        for (user, score) in leaderboard::read_leaderboard(&env)
+            .iter()
+            .skip(offset as usize)
+        {
+            let participation = leaderboard::read_participation(&env, &user);
+            page.push_back(TokenGatedVoteLeaderboardEntry {
+                user,
+                score,
+                participation,
+            });
+            if page.len() >= limit {
+                break;
+            }
+        }
+        page
+    }
+
+    // Returns the participation counters of an address and the achievements they unlocked
+    pub fn get_achievements(env: Env, user: Address) -> TokenGatedVoteAchievements {
+        leaderboard::achievements(&env, &leaderboard::read_participation(&env, &user))
+    }
+
+    // Returns a bundle with its members and joint outcome, if any
+    pub fn get_bundle(env: Env, bundle_id: Symbol) -> Option<TokenGatedVoteBundle> {
+        bundles::read(&env, &bundle_id)
+    }
+
+    // Returns the bundle a proposal belongs to, if any
+    pub fn get_proposal_bundle(env: Env, id: Symbol) -> Option<Symbol> {
+        bundles::read_bundle_of(&env, &id)
+    }
+
+    // Returns the states a proposal held before each of its amendments, oldest first
+    pub fn get_proposal_revisions(env: Env, id: Symbol) -> Vec<TokenGatedVoteRevision> {
+        revisions::read(&env, &id)
+    }
+
+    // Returns the discussion thread anchored to a proposal, if any
+    pub fn get_discussion(env: Env, id: Symbol) -> Option<TokenGatedVoteDiscussion> {
+        discussions::read(&env, &id)
+    }
+
+    // Returns the proposal that must execute before a proposal can, if any
+    pub fn get_dependency(env: Env, id: Symbol) -> Option<Symbol> {
+        dependencies::read(&env, &id)
+    }
+
+    // Returns the current admin, the executor contract while in DAO-as-admin mode
+    pub fn get_admin(env: Env) -> Result<Address, TokenGatedVoteContractErrors> {
+        Self::read_admin(&env)
+    }
+
+    // Returns the executor contract holding the admin role, if DAO-as-admin mode is enabled
+    pub fn get_dao_executor(env: Env) -> Option<Address> {
+        executor::read_executor(&env)
+    }
+
+    // Returns the badge contract minting participation badges to voters, if any
+    pub fn get_badge_contract(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&TokenGatedVoteContractDataKey::Badges)
+    }
+
+    // Returns the membership contract gating the electorate, if membership gating is enabled
+    pub fn get_membership_gate(env: Env) -> Option<Address> {
+        membership::read_membership(&env)
+    }
+
+    // Returns the executor contract nominated to take over the admin role, if any
+    pub fn get_pending_executor(env: Env) -> Option<Address> {
+        executor::read_pending(&env)
+    }
+
+    // Returns the payout attached to a community pool spend proposal, if any
+    pub fn get_pool_spend(env: Env, id: Symbol) -> Option<TokenGatedVotePoolSpend> {
+        pool::read_spend(&env, &id)
+    }
+
+    // Returns the bond required to dispute a finalized result (0 when disabled)
+    pub fn get_dispute_bond(env: Env) -> i128 {
+        disputes::read_bond(&env)
+    }
+
+    // Returns the unified governance configuration
+    pub fn get_config(env: Env) -> TokenGatedVoteGovernanceConfig {
+        config::read(&env)
+    }
+
+    // Returns when a proposer last created a proposal and how many fall within the cap period
+    pub fn get_proposer_activity(env: Env, proposer: Address) -> TokenGatedVoteProposerActivity {
+        ratelimit::activity(&env, &proposer, &config::read(&env))
+    }
+
+    // Returns the number of Pending or Active proposals not yet finalized, which the
+    // max_active_proposals cap applies to
+    pub fn get_active_proposal_count(env: Env) -> u32 {
+        stats::read(&env).active_proposals
+    }
+
+    // Returns the fee charged to non-admin proposers, if any
+    pub fn get_proposal_fee(env: Env) -> Option<TokenGatedVoteProposalFee> {
+        fees::read_fee(&env)
+    }
+
+    // Returns the treasury contract treasury proposals pay out of, if one has been set
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        treasury::read_address(&env)
+    }
+
+    // Returns the governance tokens a voter escrowed when voting on a proposal
+    pub fn get_locked(env: Env, user: Address, id: Symbol) -> i128 {
+        locks::read_lock(&env, &user, &id)
+    }
+
+    // Returns the governance tokens a voter has escrowed across all proposals
+    pub fn get_locked_balance(env: Env, user: Address) -> i128 {
+        locks::read_locked_balance(&env, &user)
+    }
+
+    // Returns the governance tokens escrowed by all voters
+    pub fn get_total_locked(env: Env) -> i128 {
+        locks::read_total(&env)
+    }
+
+    // Returns the proposal categories with their own rules, in the order they were added
+    pub fn get_categories(env: Env) -> Vec<Symbol> {
+        categories::read_categories(&env)
+    }
+
+    // Returns the rules new proposals in a category follow
+    pub fn get_category_rules(
+        env: Env,
+        category: Symbol,
+    ) -> Result<TokenGatedVoteCategoryRules, TokenGatedVoteContractErrors> {
+        categories::resolve(&env, &category, &config::read(&env))
+    }
+
+    // Returns the registered child governance contracts with their scopes and decision counts
+    pub fn get_children(env: Env) -> Vec<TokenGatedVoteChild> {
+        hierarchy::list(&env)
+    }
+
+    // Returns this contract's position on a child proposal; children call it before executing
+    pub fn get_ratification(env: Env, child: Address, id: Symbol) -> TokenGatedVoteRatification {
+        hierarchy::ratification(&env, &child, &id)
+    }
+
+    // Returns the council co-approving passed proposals, if dual-approval mode is on
+    pub fn get_council(env: Env) -> Option<TokenGatedVoteCouncil> {
+        council::read_council(&env)
+    }
+
+    // Returns the UNIX timestamp of the council's co-approval of a proposal, if it approved
+    pub fn get_council_approval(env: Env, id: Symbol) -> Option<u64> {
+        council::read_approval(&env, &id)
+    }
+
+    // Returns the parent governance contract this contract answers to, if any
+    pub fn get_parent(env: Env) -> Option<Address> {
+        hierarchy::read_parent(&env)
+    }
+
+    // Returns the UNIX timestamp of the emergency shutdown, if the contract was shut down
+    pub fn get_shutdown(env: Env) -> Option<u64> {
+        shutdown::read_shutdown(&env)
+    }
+
+    // Returns the current guardian and when its powers expire, if one was ever appointed
+    pub fn get_guardian(env: Env) -> Option<TokenGatedVoteGuardian> {
+        guardian::read_guardian(&env)
+    }
+
+    // Returns whether a guardian currently holds unexpired powers
+    pub fn is_guardian_active(env: Env) -> bool {
+        guardian::is_active(&env)
+    }
+
+    // Returns the guardian appointment attached to a guardian proposal, if any
+    pub fn get_guardian_renewal(env: Env, id: Symbol) -> Option<TokenGatedVoteGuardianRenewal> {
+        guardian::read_renewal(&env, &id)
+    }
+
+    // Returns the price conditions a treasury proposal must meet to execute
+    pub fn get_execution_conditions(env: Env, id: Symbol) -> Vec<TokenGatedVoteExecutionCondition> {
+        conditions::read_conditions(&env, &id)
+    }
+
+    // Returns whether every price condition of a proposal currently holds
+    pub fn check_execution_conditions(env: Env, id: Symbol) -> bool {
+        conditions::require_met(&env, &id).is_ok()
+    }
+
+    // Returns the payout attached to a treasury proposal, if any
+    pub fn get_treasury_action(env: Env, id: Symbol) -> Option<TokenGatedVoteTreasuryAction> {
+        treasury::read_action(&env, &id)
+    }
+
+    // Returns the participation reward pool of a proposal
+    pub fn get_reward_pool(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenGatedVoteRewardPool, TokenGatedVoteModuleErrors> {
+        rewards::read_pool(&env, &id)
+    }
+
+    // Dry-runs a vote without mutating state, returning the weight it would count or the error
+    // it would fail with
+    pub fn simulate_vote(
         env: Env,
         user: Address,
-    ) -> Result<Vec<(Symbol, bool, i128)>, TokenGatedVoteContractErrors> {
+        id: Symbol,
+        choice: Symbol,
+    ) -> Result<i128, TokenGatedVoteContractErrors> {
+        Self::check_vote(&env, &user, &id, &choice).map(|_| 1)
+    }
+
+    // Returns whether a user has voted on a proposal
+    pub fn has_voted(env: Env, user: Address, id: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&TokenGatedVoteContractDataKey::Vote(user, id))
+    }
+
+    // Returns the choice a user voted with on a proposal, if any
+    pub fn get_vote(env: Env, user: Address, id: Symbol) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get::<TokenGatedVoteContractDataKey, TokenGatedVoteRecord>(
+                &TokenGatedVoteContractDataKey::Vote(user, id),
+            )
+            .map(|record| record.choice)
+    }
+
+    // Returns choice, counted weight, and outcome for every proposal the user voted on
+    pub fn get_user_history(env: Env, user: Address) -> Vec<TokenGatedVoteHistoryEntry> {
         let proposals: Vec<Symbol> = env
             .storage()
             .persistent()
             .get(&TokenGatedVoteContractDataKey::Proposals)
             .unwrap_or(Vec::new(&env));
 
-        let votes_key = TokenGatedVoteContractDataKey::Votes(user.clone());
-        let votes: Map<Symbol, bool> = env
-            .storage()
-            .persistent()
-            .get(&votes_key)
-            .unwrap_or(Map::new(&env));
+        let mut history = Vec::new(&env);
+        for id in proposals.iter() {
+            let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<TokenGatedVoteContractDataKey, TokenGatedVoteRecord>(
+                    &TokenGatedVoteContractDataKey::Vote(user.clone(), id.clone()),
+                )
+            else {
+                continue;
+            };
+            if let Ok(proposal) = Self::read_proposal(&env, &id) {
+                let status = lifecycle::effective_status(&env, &proposal);
+                history.push_back(TokenGatedVoteHistoryEntry {
+                    id: id.clone(),
+                    choice: record.choice,
+                    weight: record.weight,
+                    status,
+                    passed: lifecycle::has_passed(status),
+                });
+            }
+        }
+        history
+    }
 
-        let token_address: Address = env
+    // Returns user's vote participation and eligibility per proposal
+    pub fn get_user_details(
+        env: Env,
+        user: Address,
+    ) -> Result<Vec<(Symbol, bool, i128)>, TokenGatedVoteContractErrors> {
+        let proposals: Vec<Symbol> = env
             .storage()
-            .instance()
-            .get(&TokenGatedVoteContractDataKey::Token)
-            .ok_or(TokenGatedVoteContractErrors::ContractNotInitialized)?;
-        let token_client = TokenClient::new(&env, &token_address);
-        let token_balance = token_client.balance(&user);
+            .persistent()
+            .get(&TokenGatedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(&env));
 
-        let voting_power = if token_balance > 0 { 1 } else { 0 };
+        let eligible = Self::is_eligible(&env, &user, &config::read(&env))?;
+        let voting_power = if eligible { 1 } else { 0 };
 
         let mut results = Vec::new(&env);
         for id in proposals.iter() {
-            if let Some(_) = votes.get(id.clone()) {
-                results.push_back((id.clone(), true, voting_power));
-            } else {
-                results.push_back((id.clone(), false, voting_power));
-            }
+            let has_voted = env
+                .storage()
+                .persistent()
+                .has(&TokenGatedVoteContractDataKey::Vote(
+                    user.clone(),
+                    id.clone(),
+                ));
+            results.push_back((id.clone(), has_voted, voting_power));
         }
         Ok(results)
     }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
 }
 
 // --- Test Module ---
 mod test;
+mod test_auth;
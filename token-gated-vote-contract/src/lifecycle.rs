@@ -0,0 +1,224 @@
+use governance_core::{events, GovernanceStatus, Tally, TallyOutcome, TallyStrategy, VotingWindow};
+use soroban_sdk::{Env, Symbol};
+
+use crate::{index, stats, timing};
+use crate::{
+    TokenGatedVoteContractErrors, TokenGatedVoteProposalData, TokenGatedVoteProposalStatus,
+};
+
+// --- Execution Window (in seconds) ---
+pub(crate) const EXECUTION_WINDOW: u64 = 1_209_600; // ~14 days to queue and execute
+
+// Returns whether the state machine allows moving from one status to another
+pub(crate) fn can_transition(
+    from: TokenGatedVoteProposalStatus,
+    to: TokenGatedVoteProposalStatus,
+) -> bool {
+    use TokenGatedVoteProposalStatus::*;
+    matches!(
+        (from, to),
+        (Draft, Pending)
+            | (Draft, Cancelled)
+            | (Draft, Expired)
+            | (Pending, Active)
+            | (Pending, Cancelled)
+            | (Pending, Succeeded)
+            | (Pending, Defeated)
+            | (Pending, QuorumFailed)
+            | (Pending, Vetoed)
+            | (Active, Succeeded)
+            | (Active, Defeated)
+            | (Active, QuorumFailed)
+            | (Active, Cancelled)
+            | (Active, Vetoed)
+            | (Succeeded, Queued)
+            | (Succeeded, Vetoed)
+            | (Succeeded, Expired)
+            | (Queued, Executed)
+            | (Queued, Vetoed)
+            | (Queued, Expired)
+            | (Queued, ReadyToExecute)
+            | (ReadyToExecute, Executed)
+            | (ReadyToExecute, Vetoed)
+            | (ReadyToExecute, Expired)
+    )
+}
+
+// Returns whether the proposal has left the sponsorship and voting phases
+pub(crate) fn is_finalized(status: TokenGatedVoteProposalStatus) -> bool {
+    !matches!(
+        status,
+        TokenGatedVoteProposalStatus::Draft
+            | TokenGatedVoteProposalStatus::Pending
+            | TokenGatedVoteProposalStatus::Active
+    )
+}
+
+// Returns whether the proposal passed its vote, regardless of later execution steps
+pub(crate) fn has_passed(status: TokenGatedVoteProposalStatus) -> bool {
+    matches!(
+        status,
+        TokenGatedVoteProposalStatus::Succeeded
+            | TokenGatedVoteProposalStatus::Queued
+            | TokenGatedVoteProposalStatus::ReadyToExecute
+            | TokenGatedVoteProposalStatus::Executed
+    )
+}
+
+// Reports stored status, surfacing Pending proposals as Active once their window opens
+pub(crate) fn effective_status(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+) -> TokenGatedVoteProposalStatus {
+    if proposal.status == TokenGatedVoteProposalStatus::Pending
+        && timing::window(env, proposal) != VotingWindow::Pending
+    {
+        TokenGatedVoteProposalStatus::Active
+    } else {
+        proposal.status
+    }
+}
+
+// Returns the seconds until voting opens for draft and pending proposals or until it closes for
+// active ones, and 0 once voting has ended or the proposal has left the voting phase
+pub(crate) fn time_remaining(env: &Env, proposal: &TokenGatedVoteProposalData) -> u64 {
+    match effective_status(env, proposal) {
+        TokenGatedVoteProposalStatus::Draft | TokenGatedVoteProposalStatus::Pending => {
+            timing::seconds_until_start(env, proposal)
+        }
+        TokenGatedVoteProposalStatus::Active => timing::seconds_until_end(env, proposal),
+        _ => 0,
+    }
+}
+
+// Returns whether voting on a proposal has ended but the grace period after end_time, during
+// which only vetoes are allowed and finalization waits, is still running
+pub(crate) fn in_grace_period(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+    grace_period: u64,
+) -> bool {
+    !is_finalized(proposal.status)
+        && timing::window(env, proposal) == VotingWindow::Ended
+        && env.ledger().timestamp() <= proposal.end_time.saturating_add(grace_period)
+}
+
+// Vetoes a passed proposal before execution, or an unfinalized one during its grace period,
+// which also marks it finalized
+pub(crate) fn veto(
+    env: &Env,
+    id: &Symbol,
+    proposal: &mut TokenGatedVoteProposalData,
+    grace_period: u64,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if !is_finalized(proposal.status) {
+        if !in_grace_period(env, proposal, grace_period) {
+            return Err(TokenGatedVoteContractErrors::InvalidStateTransition);
+        }
+        proposal.finalized_at = env.ledger().timestamp();
+    }
+    transition(env, id, proposal, TokenGatedVoteProposalStatus::Vetoed)
+}
+
+// Resolves the final outcome of a proposal by counting its tallies as a supermajority at its
+// passing threshold against its quorum; too few unique voters fails the quorum however much
+// weight they cast
+pub(crate) fn resolve_outcome(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+) -> TokenGatedVoteProposalStatus {
+    if proposal.voter_count < proposal.min_voters {
+        return TokenGatedVoteProposalStatus::QuorumFailed;
+    }
+    let strategy = TallyStrategy::Supermajority(proposal.threshold);
+    match tally(proposal).resolve(env, proposal.quorum, strategy) {
+        TallyOutcome::QuorumFailed => TokenGatedVoteProposalStatus::QuorumFailed,
+        TallyOutcome::Succeeded => TokenGatedVoteProposalStatus::Succeeded,
+        TallyOutcome::Defeated => TokenGatedVoteProposalStatus::Defeated,
+    }
+}
+
+// Maps a lifecycle status onto the status reported through the governance interface
+pub(crate) fn governance_status(status: TokenGatedVoteProposalStatus) -> GovernanceStatus {
+    match status {
+        TokenGatedVoteProposalStatus::Draft | TokenGatedVoteProposalStatus::Pending => {
+            GovernanceStatus::Pending
+        }
+        TokenGatedVoteProposalStatus::Active => GovernanceStatus::Active,
+        TokenGatedVoteProposalStatus::Succeeded => GovernanceStatus::Succeeded,
+        TokenGatedVoteProposalStatus::Defeated
+        | TokenGatedVoteProposalStatus::QuorumFailed
+        | TokenGatedVoteProposalStatus::Vetoed => GovernanceStatus::Defeated,
+        TokenGatedVoteProposalStatus::Queued | TokenGatedVoteProposalStatus::ReadyToExecute => {
+            GovernanceStatus::Queued
+        }
+        TokenGatedVoteProposalStatus::Executed => GovernanceStatus::Executed,
+        TokenGatedVoteProposalStatus::Cancelled => GovernanceStatus::Cancelled,
+        TokenGatedVoteProposalStatus::Expired => GovernanceStatus::Expired,
+    }
+}
+
+// Reads the per-choice totals of a proposal as a shared tally
+pub(crate) fn tally(proposal: &TokenGatedVoteProposalData) -> Tally {
+    Tally {
+        total_for: proposal.total_for,
+        total_against: proposal.total_against,
+        total_abstain: proposal.total_abstain,
+    }
+}
+
+// Maps a target status to the event action published for the transition
+fn transition_event(to: TokenGatedVoteProposalStatus) -> &'static str {
+    match to {
+        TokenGatedVoteProposalStatus::Draft => "DRAFTED",
+        TokenGatedVoteProposalStatus::Pending => "PENDING",
+        TokenGatedVoteProposalStatus::Active => "ACTIVATED",
+        TokenGatedVoteProposalStatus::Succeeded
+        | TokenGatedVoteProposalStatus::Defeated
+        | TokenGatedVoteProposalStatus::QuorumFailed => events::FINALIZED,
+        TokenGatedVoteProposalStatus::Cancelled => events::CANCELLED,
+        TokenGatedVoteProposalStatus::Vetoed => "VETOED",
+        TokenGatedVoteProposalStatus::Queued => "QUEUED",
+        TokenGatedVoteProposalStatus::ReadyToExecute => "READY",
+        TokenGatedVoteProposalStatus::Executed => "EXECUTED",
+        TokenGatedVoteProposalStatus::Expired => "EXPIRED",
+    }
+}
+
+// Applies a guarded status transition and publishes the matching event
+pub(crate) fn transition(
+    env: &Env,
+    id: &Symbol,
+    proposal: &mut TokenGatedVoteProposalData,
+    to: TokenGatedVoteProposalStatus,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if !can_transition(proposal.status, to) {
+        return Err(TokenGatedVoteContractErrors::InvalidStateTransition);
+    }
+    if proposal.status == TokenGatedVoteProposalStatus::Draft
+        && to == TokenGatedVoteProposalStatus::Pending
+    {
+        stats::record_opened(env)?;
+    }
+    if !is_finalized(proposal.status) && is_finalized(to) {
+        index::remove(env, id);
+        if proposal.status != TokenGatedVoteProposalStatus::Draft {
+            stats::record_closed(env);
+        }
+    }
+    proposal.status = to;
+    let action = transition_event(to);
+    if action == events::FINALIZED {
+        events::proposal_finalized(
+            env,
+            id,
+            governance_status(to),
+            &tally(proposal),
+            proposal.quorum,
+            proposal.threshold,
+        );
+    } else {
+        events::proposal_transitioned(env, action, id, governance_status(to));
+    }
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use governance_core::{events, TokenSource};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::{TokenGatedVoteContractDataKey, TokenGatedVoteModuleErrors};
+
+// Defines the vote lock escrow storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum LockKey {
+    Lock(Address, Symbol), // Governance tokens escrowed by a vote per voter and proposal
+    Balance(Address),      // Governance tokens escrowed per voter across proposals
+    Total,                 // Governance tokens escrowed by all voters
+}
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const LOCK_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the governance tokens a voter escrowed when voting on a proposal
+pub(crate) fn read_lock(env: &Env, user: &Address, id: &Symbol) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Lock(LockKey::Lock(
+            user.clone(),
+            id.clone(),
+        )))
+        .unwrap_or(0)
+}
+
+// Loads the governance tokens a voter has escrowed across all proposals
+pub(crate) fn read_locked_balance(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Lock(LockKey::Balance(
+            user.clone(),
+        )))
+        .unwrap_or(0)
+}
+
+// Loads the governance tokens escrowed by all voters, held apart from the treasury
+pub(crate) fn read_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Lock(LockKey::Total))
+        .unwrap_or(0)
+}
+
+// Persists a lock amount and extends its TTL, removing the entry once it reaches zero
+fn write_amount(env: &Env, key: &TokenGatedVoteContractDataKey, amount: i128) {
+    if amount == 0 {
+        env.storage().persistent().remove(key);
+        return;
+    }
+    env.storage().persistent().set(key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LOCK_TTL_EXTENSION, LOCK_TTL_EXTENSION);
+}
+
+// Applies a signed change to a voter's escrow on a proposal and to every aggregate
+fn adjust(env: &Env, user: &Address, id: &Symbol, delta: i128) {
+    write_amount(
+        env,
+        &TokenGatedVoteContractDataKey::Lock(LockKey::Lock(user.clone(), id.clone())),
+        read_lock(env, user, id) + delta,
+    );
+    write_amount(
+        env,
+        &TokenGatedVoteContractDataKey::Lock(LockKey::Balance(user.clone())),
+        read_locked_balance(env, user) + delta,
+    );
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Lock(LockKey::Total),
+        &(read_total(env) + delta),
+    );
+}
+
+// Moves the voter's whole wallet balance into escrow until the proposal ends, returning the
+// amount locked; tokens already escrowed for other proposals stay where they are
+pub(crate) fn escrow(env: &Env, token: &TokenSource, user: &Address, id: &Symbol) -> i128 {
+    let amount = token.balance(env, user);
+    if amount <= 0 {
+        return 0;
+    }
+    token
+        .client(env)
+        .transfer(user, &env.current_contract_address(), &amount);
+    adjust(env, user, id, amount);
+
+    events::publish(env, "LOCK", "ESCROWED", id.clone(), (user.clone(), amount));
+    amount
+}
+
+// Pays back the tokens a voter escrowed on a proposal, returning the amount released
+pub(crate) fn release(
+    env: &Env,
+    token: &TokenSource,
+    user: &Address,
+    id: &Symbol,
+) -> Result<i128, TokenGatedVoteModuleErrors> {
+    let amount = read_lock(env, user, id);
+    if amount == 0 {
+        return Err(TokenGatedVoteModuleErrors::NoLockedTokens);
+    }
+    adjust(env, user, id, -amount);
+    token
+        .client(env)
+        .transfer(&env.current_contract_address(), user, &amount);
+
+    events::publish(env, "LOCK", "RELEASED", id.clone(), (user.clone(), amount));
+    Ok(amount)
+}
@@ -0,0 +1,26 @@
+use governance_core::events;
+use soroban_sdk::{Address, Env};
+
+use crate::TokenGatedVoteContractDataKey;
+
+// Loads the membership contract gating the electorate, if membership gating is enabled
+pub(crate) fn read_membership(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Membership)
+}
+
+// Stores the membership contract gating the electorate, or returns to balance gating
+pub(crate) fn write_membership(env: &Env, membership: Option<Address>) {
+    match membership.clone() {
+        Some(address) => env
+            .storage()
+            .instance()
+            .set(&TokenGatedVoteContractDataKey::Membership, &address),
+        None => env
+            .storage()
+            .instance()
+            .remove(&TokenGatedVoteContractDataKey::Membership),
+    }
+    events::config_updated(env, "MEMBERSHIP", membership);
+}
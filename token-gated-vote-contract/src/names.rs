@@ -0,0 +1,82 @@
+use soroban_sdk::{Env, String, Symbol};
+
+use crate::{sequence, TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors};
+
+// --- Proposal Names ---
+const MAX_NAME_LENGTH: u32 = 64; // Longest name a proposal can be created under
+
+// Validates a proposal name: 1 to 64 letters, digits, underscores, and hyphens, e.g.
+// Q3-2025-BUDGET-REALLOCATION
+pub(crate) fn validate(name: &String) -> Result<(), TokenGatedVoteContractErrors> {
+    let len = name.len();
+    if len == 0 || len > MAX_NAME_LENGTH {
+        return Err(TokenGatedVoteContractErrors::InvalidProposalName);
+    }
+
+    let mut buffer = [0u8; MAX_NAME_LENGTH as usize];
+    let bytes = &mut buffer[..len as usize];
+    name.copy_into_slice(bytes);
+    if !bytes
+        .iter()
+        .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-'))
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidProposalName);
+    }
+    Ok(())
+}
+
+// Validates a new proposal name and reserves the next sequential ID for it; names are kept
+// verbatim, so two names only clash when they are identical
+pub(crate) fn next_id(env: &Env, name: &String) -> Result<Symbol, TokenGatedVoteContractErrors> {
+    validate(name)?;
+    if lookup(env, name).is_some() {
+        return Err(TokenGatedVoteContractErrors::ProposalAlreadyExists);
+    }
+    Ok(sequence::sequential_id(env, sequence::next_index(env)))
+}
+
+// Loads the ID of the proposal created under a name, if any
+pub(crate) fn lookup(env: &Env, name: &String) -> Option<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::NamedProposal(name.clone()))
+}
+
+// Loads the name a proposal was created with, if it was created by name
+pub(crate) fn read(env: &Env, id: &Symbol) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ProposalName(id.clone()))
+}
+
+// Records the name of a proposal in both directions for the longest TTL the network allows,
+// like its digest
+pub(crate) fn record(env: &Env, id: &Symbol, name: &String) {
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::ProposalName(id.clone()),
+        name,
+    );
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::NamedProposal(name.clone()),
+        id,
+    );
+    extend(env, id);
+}
+
+// Extends the name of a proposal, if one is recorded, to the longest TTL the network allows
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let Some(name) = read(env, id) else {
+        return;
+    };
+    let max_ttl = env.storage().max_ttl();
+    env.storage().persistent().extend_ttl(
+        &TokenGatedVoteContractDataKey::ProposalName(id.clone()),
+        max_ttl,
+        max_ttl,
+    );
+    env.storage().persistent().extend_ttl(
+        &TokenGatedVoteContractDataKey::NamedProposal(name),
+        max_ttl,
+        max_ttl,
+    );
+}
@@ -0,0 +1,52 @@
+use pg_contracts_types::treasury::TreasuryPoolSource;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    treasury, TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVotePoolSpend,
+    TreasuryClient,
+};
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const POOL_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the payout attached to a community pool spend proposal, if any
+pub(crate) fn read_spend(env: &Env, id: &Symbol) -> Option<TokenGatedVotePoolSpend> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::PoolSpend(id.clone()))
+}
+
+// Attaches a pool payout to a proposal, turning it into a community pool spend proposal
+pub(crate) fn write_spend(env: &Env, id: &Symbol, spend: &TokenGatedVotePoolSpend) {
+    let spend_key = TokenGatedVoteContractDataKey::PoolSpend(id.clone());
+    env.storage().persistent().set(&spend_key, spend);
+    env.storage()
+        .persistent()
+        .extend_ttl(&spend_key, POOL_TTL_EXTENSION, POOL_TTL_EXTENSION);
+}
+
+// Transfers tokens from `from` to the treasury contract and adds them to its community pool
+pub(crate) fn accrue(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    source: TreasuryPoolSource,
+    id: &Symbol,
+    amount: i128,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    let treasury = treasury::require_address(env)?;
+    TokenClient::new(env, token).transfer(from, &treasury, &amount);
+    TreasuryClient::new(env, &treasury).pool_accrue(&source, id, &amount);
+    Ok(())
+}
+
+// Has the treasury pay out the spend of a community pool proposal, if the proposal has one
+pub(crate) fn execute(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(spend) = read_spend(env, id) else {
+        return Ok(());
+    };
+    let treasury = treasury::require_address(env)?;
+    TreasuryClient::new(env, &treasury).pool_pay(id, &spend.recipient, &spend.amount);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use governance_core::THRESHOLD_SCALE;
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteDynamicQuorum,
+    TokenGatedVoteGovernanceConfig,
+};
+
+// Defines the dynamic quorum storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum QuorumKey {
+    Dynamic,        // Turnout-based quorum settings, absent when disabled
+    TurnoutHistory, // Turnouts of the most recently finalized proposals
+}
+
+// --- Turnout History Bounds ---
+pub(crate) const MAX_TURNOUT_WINDOW: u32 = 20; // Finalized proposals kept for the rolling average
+
+// Rejects empty or oversized windows and shares outside (0, 100%]
+pub(crate) fn validate(
+    dynamic: &TokenGatedVoteDynamicQuorum,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if dynamic.window == 0
+        || dynamic.window > MAX_TURNOUT_WINDOW
+        || dynamic.bps == 0
+        || dynamic.bps > THRESHOLD_SCALE
+    {
+        return Err(TokenGatedVoteContractErrors::InvalidQuorum);
+    }
+    Ok(())
+}
+
+// Loads the dynamic quorum settings, if enabled
+pub(crate) fn read_dynamic(env: &Env) -> Option<TokenGatedVoteDynamicQuorum> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Quorum(QuorumKey::Dynamic))
+}
+
+// Loads the turnouts of the most recently finalized proposals, oldest first
+pub(crate) fn read_history(env: &Env) -> Vec<i128> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Quorum(
+            QuorumKey::TurnoutHistory,
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Appends the turnout of a finalized proposal, dropping the oldest beyond the maximum window
+pub(crate) fn record_turnout(env: &Env, turnout: i128) {
+    let mut history = read_history(env);
+    history.push_back(turnout);
+    while history.len() > MAX_TURNOUT_WINDOW {
+        history.pop_front();
+    }
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Quorum(QuorumKey::TurnoutHistory),
+        &history,
+    );
+}
+
+// Resolves the quorum applied to a new proposal: the configured share of the average turnout
+// over the window, never below the static quorum, which also applies while no history exists
+pub(crate) fn current(env: &Env, config: &TokenGatedVoteGovernanceConfig) -> i128 {
+    let Some(dynamic) = read_dynamic(env) else {
+        return config.quorum;
+    };
+    let history = read_history(env);
+    let count = history.len().min(dynamic.window);
+    if count == 0 {
+        return config.quorum;
+    }
+
+    let mut total: i128 = 0;
+    for turnout in history.slice(history.len() - count..).iter() {
+        total = total.saturating_add(turnout);
+    }
+    let average = total / count as i128;
+    let adaptive = average.saturating_mul(dynamic.bps as i128) / THRESHOLD_SCALE as i128;
+    adaptive.max(config.quorum)
+}
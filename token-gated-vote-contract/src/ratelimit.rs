@@ -0,0 +1,91 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteGovernanceConfig,
+    TokenGatedVoteProposerActivity,
+};
+
+// --- Proposer Rate Limit Bounds ---
+pub(crate) const MAX_PROPOSALS_PER_PERIOD: u32 = 100; // Highest per-period cap a proposer can get
+pub(crate) const MAX_RATE_PERIOD: u64 = 31_536_000; // ~365 days, upper bound for the cap period
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const ACTIVITY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the creation times of a proposer's proposals within the rolling cap period, oldest first
+fn read_recent(env: &Env, proposer: &Address, config: &TokenGatedVoteGovernanceConfig) -> Vec<u64> {
+    let proposed: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::ProposerActivity(
+            proposer.clone(),
+        ))
+        .unwrap_or(Vec::new(env));
+    let window_start = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(config.proposal_period);
+    let mut recent = Vec::new(env);
+    for proposed_at in proposed.iter() {
+        if proposed_at > window_start {
+            recent.push_back(proposed_at);
+        }
+    }
+    recent
+}
+
+// Returns the latest proposal time of a proposer and their proposals within the cap period
+pub(crate) fn activity(
+    env: &Env,
+    proposer: &Address,
+    config: &TokenGatedVoteGovernanceConfig,
+) -> TokenGatedVoteProposerActivity {
+    let last_proposed_at = env
+        .storage()
+        .persistent()
+        .get::<TokenGatedVoteContractDataKey, Vec<u64>>(
+            &TokenGatedVoteContractDataKey::ProposerActivity(proposer.clone()),
+        )
+        .and_then(|proposed| proposed.last())
+        .unwrap_or(0);
+    TokenGatedVoteProposerActivity {
+        last_proposed_at,
+        proposals_in_period: read_recent(env, proposer, config).len(),
+    }
+}
+
+// Records a new proposal by a proposer, failing while their cooldown since the latest proposal
+// runs or once they reached the cap for the rolling period
+pub(crate) fn record(
+    env: &Env,
+    proposer: &Address,
+    config: &TokenGatedVoteGovernanceConfig,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    let ledger_time = env.ledger().timestamp();
+    let last_proposed_at = activity(env, proposer, config).last_proposed_at;
+    if config.proposer_cooldown > 0
+        && last_proposed_at > 0
+        && ledger_time < last_proposed_at.saturating_add(config.proposer_cooldown)
+    {
+        return Err(TokenGatedVoteContractErrors::ProposalRateLimited);
+    }
+    let mut recent = read_recent(env, proposer, config);
+    if config.proposals_per_period > 0 && recent.len() >= config.proposals_per_period {
+        return Err(TokenGatedVoteContractErrors::ProposalRateLimited);
+    }
+
+    // Entries outside the cap period, or beyond the largest cap, are dropped; the stored list
+    // always ends with the latest
+    recent.push_back(ledger_time);
+    while recent.len() > MAX_PROPOSALS_PER_PERIOD {
+        recent.pop_front();
+    }
+    let activity_key = TokenGatedVoteContractDataKey::ProposerActivity(proposer.clone());
+    env.storage().persistent().set(&activity_key, &recent);
+    env.storage().persistent().extend_ttl(
+        &activity_key,
+        ACTIVITY_TTL_EXTENSION,
+        ACTIVITY_TTL_EXTENSION,
+    );
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+use crate::config::DESCRIPTION_CEILING;
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteModuleErrors, TokenGatedVoteProposalData,
+    TokenGatedVoteRevision,
+};
+
+// --- Revision Bounds ---
+pub(crate) const MAX_REVISIONS: u32 = 10; // Most amendments kept, and allowed, per proposal
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REVISIONS_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Computes the SHA-256 of a description's UTF-8 bytes, which readers can recompute from the text
+pub(crate) fn description_hash(env: &Env, description: &String) -> BytesN<32> {
+    let mut buffer = [0u8; DESCRIPTION_CEILING as usize];
+    let text = &mut buffer[..description.len() as usize];
+    description.copy_into_slice(text);
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, text))
+        .to_bytes()
+}
+
+// Loads the revisions of a proposal, oldest first
+pub(crate) fn read(env: &Env, id: &Symbol) -> Vec<TokenGatedVoteRevision> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Revisions(id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Records the state a proposal is about to leave behind through an amendment; the history is
+// bounded, so amendments past the limit fail instead of dropping earlier revisions
+pub(crate) fn record(
+    env: &Env,
+    id: &Symbol,
+    editor: &Address,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteModuleErrors> {
+    let mut revisions = read(env, id);
+    if revisions.len() >= MAX_REVISIONS {
+        return Err(TokenGatedVoteModuleErrors::RevisionLimitReached);
+    }
+    revisions.push_back(TokenGatedVoteRevision {
+        description_hash: description_hash(env, &proposal.description),
+        start_time: proposal.start_time,
+        end_time: proposal.end_time,
+        editor: editor.clone(),
+        amended_at: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(
+        &TokenGatedVoteContractDataKey::Revisions(id.clone()),
+        &revisions,
+    );
+    extend(env, id);
+    Ok(())
+}
+
+// Extends the revisions of a proposal, if any are recorded
+pub(crate) fn extend(env: &Env, id: &Symbol) {
+    let revisions_key = TokenGatedVoteContractDataKey::Revisions(id.clone());
+    if env.storage().persistent().has(&revisions_key) {
+        env.storage().persistent().extend_ttl(
+            &revisions_key,
+            REVISIONS_TTL_EXTENSION,
+            REVISIONS_TTL_EXTENSION,
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use governance_core::VotingWindow;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contracttype, Address, Env, Error, Symbol};
+
+use crate::{lifecycle, timing};
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteModuleErrors,
+    TokenGatedVoteProposalData, TokenGatedVoteRewardPool,
+};
+
+// Defines the participation reward storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum RewardKey {
+    Pool(Symbol),             // Participation reward pool per proposal
+    Claimed(Symbol, Address), // Reward claim receipt per proposal and voter
+}
+
+// --- Reward Claim Window (in seconds) ---
+pub(crate) const REWARD_CLAIM_WINDOW: u64 = 2_592_000; // ~30 days after end_time
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const REWARD_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the reward pool of a proposal
+pub(crate) fn read_pool(
+    env: &Env,
+    id: &Symbol,
+) -> Result<TokenGatedVoteRewardPool, TokenGatedVoteModuleErrors> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Reward(RewardKey::Pool(
+            id.clone(),
+        )))
+        .ok_or(TokenGatedVoteModuleErrors::RewardPoolNotFound)
+}
+
+// Persists the reward pool of a proposal and extends its TTL
+fn write_pool(env: &Env, id: &Symbol, pool: &TokenGatedVoteRewardPool) {
+    let pool_key = TokenGatedVoteContractDataKey::Reward(RewardKey::Pool(id.clone()));
+    env.storage().persistent().set(&pool_key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&pool_key, REWARD_TTL_EXTENSION, REWARD_TTL_EXTENSION);
+}
+
+// Transfers tokens from the funder into the contract and grows the proposal pool
+pub(crate) fn fund(
+    env: &Env,
+    token: &Address,
+    funder: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+    amount: i128,
+) -> Result<TokenGatedVoteRewardPool, Error> {
+    if amount <= 0 {
+        return Err(TokenGatedVoteContractErrors::InvalidAmount.into());
+    }
+    if timing::window(env, proposal) == VotingWindow::Ended {
+        return Err(TokenGatedVoteModuleErrors::RewardFundingClosed.into());
+    }
+
+    TokenClient::new(env, token).transfer(funder, &env.current_contract_address(), &amount);
+
+    let mut pool = read_pool(env, id).unwrap_or(TokenGatedVoteRewardPool {
+        total: 0,
+        claimed: 0,
+        claim_count: 0,
+        swept: 0,
+    });
+    pool.total = pool.total.saturating_add(amount);
+    write_pool(env, id, &pool);
+    Ok(pool)
+}
+
+// Pays the caller's pro-rata share of the pool once the proposal is finalized
+pub(crate) fn claim(
+    env: &Env,
+    token: &Address,
+    user: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<i128, Error> {
+    let mut pool = read_pool(env, id)?;
+
+    let ledger_time = env.ledger().timestamp();
+    if !lifecycle::is_finalized(proposal.status)
+        || ledger_time > proposal.end_time.saturating_add(REWARD_CLAIM_WINDOW)
+    {
+        return Err(TokenGatedVoteModuleErrors::RewardNotClaimable.into());
+    }
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&TokenGatedVoteContractDataKey::Vote(
+            user.clone(),
+            id.clone(),
+        ))
+    {
+        return Err(TokenGatedVoteModuleErrors::RewardNotClaimable.into());
+    }
+
+    let claimed_key =
+        TokenGatedVoteContractDataKey::Reward(RewardKey::Claimed(id.clone(), user.clone()));
+    if env.storage().persistent().has(&claimed_key) {
+        return Err(TokenGatedVoteModuleErrors::RewardAlreadyClaimed.into());
+    }
+
+    let payout = pool.total / i128::from(proposal.voter_count.max(1));
+
+    env.storage().persistent().set(&claimed_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&claimed_key, REWARD_TTL_EXTENSION, REWARD_TTL_EXTENSION);
+
+    pool.claimed = pool.claimed.saturating_add(payout);
+    pool.claim_count = pool.claim_count.saturating_add(1);
+    write_pool(env, id, &pool);
+
+    if payout > 0 {
+        TokenClient::new(env, token).transfer(&env.current_contract_address(), user, &payout);
+    }
+    Ok(payout)
+}
+
+// Returns unclaimed funds to the recipient once the claim window has closed
+pub(crate) fn sweep(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<i128, Error> {
+    let mut pool = read_pool(env, id)?;
+
+    if env.ledger().timestamp() <= proposal.end_time.saturating_add(REWARD_CLAIM_WINDOW) {
+        return Err(TokenGatedVoteModuleErrors::RewardClaimWindowOpen.into());
+    }
+
+    let remaining = pool.total - pool.claimed - pool.swept;
+    if remaining <= 0 {
+        return Err(TokenGatedVoteModuleErrors::RewardPoolEmpty.into());
+    }
+
+    pool.swept = pool.swept.saturating_add(remaining);
+    write_pool(env, id, &pool);
+
+    TokenClient::new(env, token).transfer(&env.current_contract_address(), to, &remaining);
+    Ok(remaining)
+}
@@ -0,0 +1,69 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::TokenGatedVoteContractDataKey;
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const QUEUE_TTL_BUFFER: u32 = 86_400; // ~1 day past the latest scheduled start_time
+
+// Loads the scheduled proposals awaiting activation ordered by ascending start_time
+pub(crate) fn read_queue(env: &Env) -> Vec<(u64, Symbol)> {
+    env.storage()
+        .temporary()
+        .get(&TokenGatedVoteContractDataKey::ScheduleQueue)
+        .unwrap_or(Vec::new(env))
+}
+
+// Keeps the schedule queue in temporary storage until shortly after its latest start_time, or
+// for the longest TTL the network allows; if it expires, scheduled proposals still activate
+// through activate_proposal or a vote
+fn write_queue(env: &Env, queue: &Vec<(u64, Symbol)>) {
+    let key = TokenGatedVoteContractDataKey::ScheduleQueue;
+    let Some((last_start_time, _)) = queue.last() else {
+        env.storage().temporary().remove(&key);
+        return;
+    };
+    env.storage().temporary().set(&key, queue);
+    let queue_ttl = governance_core::proposal_ttl(
+        env.ledger().timestamp(),
+        last_start_time,
+        QUEUE_TTL_BUFFER,
+        QUEUE_TTL_BUFFER,
+    )
+    .min(env.storage().max_ttl());
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, queue_ttl, queue_ttl);
+}
+
+// Inserts a proposal keeping the queue sorted by start_time (ties keep scheduling order)
+pub(crate) fn enqueue(env: &Env, id: &Symbol, start_time: u64) {
+    let mut queue = read_queue(env);
+    let mut position = queue.len();
+    for (i, (entry_start_time, _)) in queue.iter().enumerate() {
+        if entry_start_time > start_time {
+            position = i as u32;
+            break;
+        }
+    }
+    queue.insert(position, (start_time, id.clone()));
+    write_queue(env, &queue);
+}
+
+// Removes and returns up to `limit` proposals whose start_time has been reached
+pub(crate) fn take_due(env: &Env, ledger_time: u64, limit: u32) -> Vec<Symbol> {
+    let mut queue = read_queue(env);
+    let mut due = Vec::new(env);
+    while due.len() < limit {
+        match queue.first() {
+            Some((start_time, id)) if start_time <= ledger_time => {
+                due.push_back(id);
+                queue.pop_front();
+            }
+            _ => break,
+        }
+    }
+    if !due.is_empty() {
+        write_queue(env, &queue);
+    }
+    due
+}
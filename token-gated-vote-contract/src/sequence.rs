@@ -0,0 +1,72 @@
+use soroban_sdk::{Env, Symbol};
+
+use crate::{archive, TokenGatedVoteContractDataKey};
+
+// --- Sequential Proposal IDs ---
+const SEQUENTIAL_ID_PREFIX: &[u8] = b"PROP"; // Prefix of auto-generated proposal IDs
+const FIRST_INDEX: u32 = 1; // Index of the first sequential proposal
+
+// Derives an ID from a prefix and a number, e.g. PROP12 or CYCLE3
+pub(crate) fn numbered_id(env: &Env, prefix: &[u8], number: u32) -> Symbol {
+    let mut digits = [0u8; 10];
+    let mut remaining = number;
+    let mut digit_count = 0;
+    loop {
+        digits[digit_count] = b'0' + (remaining % 10) as u8;
+        digit_count += 1;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    let mut id = [0u8; 32];
+    id[..prefix.len()].copy_from_slice(prefix);
+    for i in 0..digit_count {
+        id[prefix.len() + i] = digits[digit_count - 1 - i];
+    }
+    let id_len = prefix.len() + digit_count;
+    Symbol::new(env, core::str::from_utf8(&id[..id_len]).unwrap_or("PROP"))
+}
+
+// Derives the proposal ID of a sequential index
+pub(crate) fn sequential_id(env: &Env, index: u32) -> Symbol {
+    numbered_id(env, SEQUENTIAL_ID_PREFIX, index)
+}
+
+// Returns the index the next sequential proposal receives, skipping any whose ID a caller
+// already chose for a proposal of its own
+pub(crate) fn next_index(env: &Env) -> u32 {
+    let mut index = env
+        .storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::NextProposalIndex)
+        .unwrap_or(FIRST_INDEX);
+    while is_taken(env, &sequential_id(env, index)) {
+        index += 1;
+    }
+    index
+}
+
+// Returns whether an ID is the next sequential one, i.e. the ID a new proposal must use while
+// sequential IDs are enforced
+pub(crate) fn is_next(env: &Env, id: &Symbol) -> bool {
+    *id == sequential_id(env, next_index(env))
+}
+
+// Moves the stored counter past a sequential proposal once it is stored, so later lookups do
+// not rescan taken IDs
+pub(crate) fn advance(env: &Env) {
+    let next = next_index(env);
+    env.storage()
+        .instance()
+        .set(&TokenGatedVoteContractDataKey::NextProposalIndex, &next);
+}
+
+// Returns whether a proposal, or the digest reserving an archived one, exists under an ID
+fn is_taken(env: &Env, id: &Symbol) -> bool {
+    env.storage()
+        .persistent()
+        .has(&TokenGatedVoteContractDataKey::Proposal(id.clone()))
+        || archive::read_digest(env, id).is_some()
+}
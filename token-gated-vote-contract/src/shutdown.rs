@@ -0,0 +1,31 @@
+use soroban_sdk::Env;
+
+use crate::{TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors};
+
+// Returns the UNIX timestamp of the emergency shutdown, if the contract was shut down
+pub(crate) fn read_shutdown(env: &Env) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Shutdown)
+}
+
+// Returns whether the contract has been permanently shut down
+pub(crate) fn is_shut_down(env: &Env) -> bool {
+    read_shutdown(env).is_some()
+}
+
+// Fails once the contract is shut down; guards every write except withdrawals
+pub(crate) fn require_live(env: &Env) -> Result<(), TokenGatedVoteContractErrors> {
+    if is_shut_down(env) {
+        return Err(TokenGatedVoteContractErrors::ContractShutDown);
+    }
+    Ok(())
+}
+
+// Records the shutdown permanently at the current ledger timestamp
+pub(crate) fn record(env: &Env) {
+    env.storage().instance().set(
+        &TokenGatedVoteContractDataKey::Shutdown,
+        &env.ledger().timestamp(),
+    );
+}
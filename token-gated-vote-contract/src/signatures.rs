@@ -0,0 +1,86 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteSignedPayload,
+};
+
+// Defines the signed vote key and nonce storage keys nested under the contract data key
+#[contracttype]
+#[derive(Clone)]
+pub enum SignatureKey {
+    SigningKey(Address), // Registered ed25519 key for signed votes
+    Nonce(Address),      // Next nonce a signed vote must commit to
+}
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const SIGNER_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the ed25519 public key a voter registered for off-chain signing
+pub(crate) fn read_signing_key(env: &Env, user: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Signature(
+            SignatureKey::SigningKey(user.clone()),
+        ))
+}
+
+// Registers or replaces the ed25519 public key of a voter
+pub(crate) fn write_signing_key(env: &Env, user: &Address, public_key: &BytesN<32>) {
+    let key = TokenGatedVoteContractDataKey::Signature(SignatureKey::SigningKey(user.clone()));
+    env.storage().persistent().set(&key, public_key);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, SIGNER_TTL_EXTENSION, SIGNER_TTL_EXTENSION);
+}
+
+// Loads the next nonce a voter's signature must commit to
+pub(crate) fn read_nonce(env: &Env, user: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Signature(
+            SignatureKey::Nonce(user.clone()),
+        ))
+        .unwrap_or(0)
+}
+
+// Advances a voter's nonce once its signed vote is accepted so it can never be replayed
+pub(crate) fn consume_nonce(env: &Env, user: &Address) {
+    let key = TokenGatedVoteContractDataKey::Signature(SignatureKey::Nonce(user.clone()));
+    env.storage()
+        .persistent()
+        .set(&key, &(read_nonce(env, user) + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, SIGNER_TTL_EXTENSION, SIGNER_TTL_EXTENSION);
+}
+
+// Verifies a voter's signature over the payload for their current nonce;
+// an invalid signature traps the invocation
+pub(crate) fn verify(
+    env: &Env,
+    user: &Address,
+    id: &Symbol,
+    choice: &Symbol,
+    expiration: u64,
+    signature: &BytesN<64>,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if env.ledger().timestamp() > expiration {
+        return Err(TokenGatedVoteContractErrors::SignatureExpired);
+    }
+    let public_key =
+        read_signing_key(env, user).ok_or(TokenGatedVoteContractErrors::SigningKeyNotFound)?;
+
+    let nonce = read_nonce(env, user);
+    let payload = TokenGatedVoteSignedPayload {
+        contract: env.current_contract_address(),
+        user: user.clone(),
+        id: id.clone(),
+        choice: choice.clone(),
+        nonce,
+        expiration,
+    };
+    env.crypto()
+        .ed25519_verify(&public_key, &payload.to_xdr(env), signature);
+    Ok(())
+}
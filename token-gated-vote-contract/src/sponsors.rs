@@ -0,0 +1,101 @@
+use governance_core::{events, VotingWindow};
+use soroban_sdk::{Address, Env, Error, Symbol, Vec};
+
+use crate::{
+    lifecycle, timing, TokenGatedVoteContractDataKey, TokenGatedVoteModuleErrors,
+    TokenGatedVoteProposalData, TokenGatedVoteProposalStatus,
+};
+
+// --- Sponsorship Bounds ---
+pub(crate) const MAX_SPONSORS_REQUIRED: u32 = 20; // Highest endorsement count a draft can need
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const SPONSORS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
+
+// Loads the holders endorsing a proposal, in endorsement order
+pub(crate) fn read_sponsors(env: &Env, id: &Symbol) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::Sponsors(id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Persists the sponsors of a proposal and extends their TTL
+fn write_sponsors(env: &Env, id: &Symbol, sponsors: &Vec<Address>) {
+    let sponsors_key = TokenGatedVoteContractDataKey::Sponsors(id.clone());
+    env.storage().persistent().set(&sponsors_key, sponsors);
+    env.storage().persistent().extend_ttl(
+        &sponsors_key,
+        SPONSORS_TTL_EXTENSION,
+        SPONSORS_TTL_EXTENSION,
+    );
+}
+
+// Fails unless the proposal is a draft whose voting window has not opened yet
+fn require_open(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteModuleErrors> {
+    if proposal.status != TokenGatedVoteProposalStatus::Draft
+        || timing::window(env, proposal) != VotingWindow::Pending
+    {
+        return Err(TokenGatedVoteModuleErrors::SponsorshipClosed);
+    }
+    Ok(())
+}
+
+// Records an endorsement of a draft, promoting it to Pending once `required` distinct holders
+// endorse it; returns whether the draft was promoted
+pub(crate) fn sponsor(
+    env: &Env,
+    sponsor: &Address,
+    id: &Symbol,
+    proposal: &mut TokenGatedVoteProposalData,
+    required: u32,
+) -> Result<bool, Error> {
+    require_open(env, proposal)?;
+    let mut sponsors = read_sponsors(env, id);
+    if sponsors.contains(sponsor) {
+        return Err(TokenGatedVoteModuleErrors::AlreadySponsored.into());
+    }
+    sponsors.push_back(sponsor.clone());
+    write_sponsors(env, id, &sponsors);
+
+    events::publish(
+        env,
+        "SPONSOR",
+        "ADDED",
+        id.clone(),
+        (sponsor.clone(), sponsors.len()),
+    );
+    if sponsors.len() < required {
+        return Ok(false);
+    }
+    lifecycle::transition(env, id, proposal, TokenGatedVoteProposalStatus::Pending)?;
+    Ok(true)
+}
+
+// Withdraws an endorsement while the proposal is still a draft
+pub(crate) fn withdraw(
+    env: &Env,
+    sponsor: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteModuleErrors> {
+    require_open(env, proposal)?;
+    let mut sponsors = read_sponsors(env, id);
+    let Some(index) = sponsors.first_index_of(sponsor) else {
+        return Err(TokenGatedVoteModuleErrors::SponsorNotFound);
+    };
+    sponsors.remove(index);
+    write_sponsors(env, id, &sponsors);
+
+    events::publish(
+        env,
+        "SPONSOR",
+        "WITHDRAWN",
+        id.clone(),
+        (sponsor.clone(), sponsors.len()),
+    );
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use governance_core::THRESHOLD_SCALE;
+use soroban_sdk::Env;
+
+use crate::{
+    config, TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors,
+    TokenGatedVoteGovernanceStats, TokenGatedVoteProposalData, TokenGatedVoteProposalStats,
+    TokenGatedVoteQuorumProgress,
+};
+
+// Loads the aggregate counters, defaulting to zero
+pub(crate) fn read(env: &Env) -> TokenGatedVoteGovernanceStats {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Stats)
+        .unwrap_or(TokenGatedVoteGovernanceStats {
+            total_proposals: 0,
+            total_votes: 0,
+            unique_voters: 0,
+            total_burned: 0,
+            active_proposals: 0,
+        })
+}
+
+// Persists the aggregate counters
+fn write(env: &Env, stats: &TokenGatedVoteGovernanceStats) {
+    env.storage()
+        .instance()
+        .set(&TokenGatedVoteContractDataKey::Stats, stats);
+}
+
+// Counts a newly created proposal
+pub(crate) fn record_proposal(env: &Env) {
+    let mut stats = read(env);
+    stats.total_proposals = stats.total_proposals.saturating_add(1);
+    write(env, &stats);
+}
+
+// Counts a proposal entering the Pending or Active phase, failing once the configured maximum
+// of concurrently open proposals is reached
+pub(crate) fn record_opened(env: &Env) -> Result<(), TokenGatedVoteContractErrors> {
+    let mut stats = read(env);
+    let max_active = config::read(env).max_active_proposals;
+    if max_active > 0 && stats.active_proposals >= max_active {
+        return Err(TokenGatedVoteContractErrors::TooManyActiveProposals);
+    }
+    stats.active_proposals = stats.active_proposals.saturating_add(1);
+    write(env, &stats);
+    Ok(())
+}
+
+// Counts a Pending or Active proposal leaving the voting phase
+pub(crate) fn record_closed(env: &Env) {
+    let mut stats = read(env);
+    stats.active_proposals = stats.active_proposals.saturating_sub(1);
+    write(env, &stats);
+}
+
+// Counts an accepted vote, a new unique voter on their first vote, and the tokens it burned
+pub(crate) fn record_vote(env: &Env, first_vote: bool, burned: i128) {
+    let mut stats = read(env);
+    stats.total_votes = stats.total_votes.saturating_add(1);
+    if first_vote {
+        stats.unique_voters = stats.unique_voters.saturating_add(1);
+    }
+    stats.total_burned = stats.total_burned.saturating_add(burned);
+    write(env, &stats);
+}
+
+// Derives participation figures for a proposal from its tallies
+pub(crate) fn proposal_stats(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+) -> TokenGatedVoteProposalStats {
+    let votes = proposal
+        .total_for
+        .saturating_add(proposal.total_against)
+        .saturating_add(proposal.total_abstain);
+    let votes_cast = u32::try_from(votes.max(0)).unwrap_or(u32::MAX);
+
+    let unique_voters = read(env).unique_voters;
+    let turnout_bps = if unique_voters == 0 {
+        0
+    } else {
+        ((votes_cast as u64 * 10_000) / unique_voters as u64) as u32
+    };
+
+    TokenGatedVoteProposalStats {
+        votes_cast,
+        turnout_bps,
+        quorum_reached: votes >= proposal.quorum,
+        burned: proposal.burned,
+    }
+}
+
+// Measures a proposal's participation against its quorum snapshot; a zero quorum is always met
+pub(crate) fn quorum_progress(
+    proposal: &TokenGatedVoteProposalData,
+) -> TokenGatedVoteQuorumProgress {
+    let participation = proposal
+        .total_for
+        .saturating_add(proposal.total_against)
+        .saturating_add(proposal.total_abstain);
+    let percent_bps = if participation >= proposal.quorum {
+        THRESHOLD_SCALE
+    } else {
+        (participation.max(0).saturating_mul(THRESHOLD_SCALE as i128) / proposal.quorum) as u32
+    };
+
+    TokenGatedVoteQuorumProgress {
+        participation,
+        quorum: proposal.quorum,
+        percent_bps,
+    }
+}
@@ -2,18 +2,135 @@
 extern crate std;
 
 use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use governance_core::GovernanceClient;
+use governance_errors::{in_range, GovernanceError, TOKEN_GATED_VOTE_CODES};
+use governance_testutils::{
+    assert_events, assert_last_event, assert_within_budget, contract_events, create_holders,
+    create_token_contract, event, setup_test_env, GovernanceHarness, BUDGET_FULL_LIST_COUNT,
+    BUDGET_PROPOSAL_COUNTS, PROPOSAL_START_DELAY,
+};
+use pg_contracts_types::treasury::{
+    TreasuryCommunityPool, TreasuryPoolInflow, TreasuryPoolSource, TreasurySpendingLimit,
+    TreasuryTotals,
+};
+use proptest::prelude::*;
 use soroban_sdk::{
-    symbol_short,
-    testutils::{Address as _, Ledger},
+    contract, contractimpl, symbol_short,
+    testutils::{
+        storage::{Instance as _, Persistent as _, Temporary as _},
+        Address as _, Events, Ledger,
+    },
     token::{StellarAssetClient, TokenClient},
-    Address, Env, FromVal, String,
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, FromVal, IntoVal, InvokeError, String,
 };
+use treasury_contract::{TreasuryContract, TreasuryContractClient, TreasuryContractErrors};
+
+// Custom token mock: standard balance and decimals reads with 9 decimal places
+#[contract]
+pub struct MockNineDecimalToken;
+
+#[contractimpl]
+impl MockNineDecimalToken {
+    pub fn set_balance(env: Env, id: Address, amount: i128) {
+        env.storage().instance().set(&id, &amount);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        9
+    }
+}
+
+// Reputation mock: counts recorded votes per voter
+#[contract]
+pub struct MockReputationContract;
+
+#[contractimpl]
+impl MockReputationContract {
+    pub fn record_vote(env: Env, _minter: Address, voter: Address) {
+        let votes: u32 = env.storage().instance().get(&voter).unwrap_or(0);
+        env.storage().instance().set(&voter, &(votes + 1));
+    }
+
+    pub fn record_proposal_passed(env: Env, _minter: Address, proposer: Address) {
+        let passed: u32 = env
+            .storage()
+            .instance()
+            .get(&(symbol_short!("PASSED"), proposer.clone()))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&(symbol_short!("PASSED"), proposer), &(passed + 1));
+    }
+
+    pub fn votes_of(env: Env, voter: Address) -> u32 {
+        env.storage().instance().get(&voter).unwrap_or(0)
+    }
+
+    pub fn passed_of(env: Env, proposer: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&(symbol_short!("PASSED"), proposer))
+            .unwrap_or(0)
+    }
+}
+
+// Badge mock: records the badges minted per voter and proposal
+#[contract]
+pub struct MockBadges;
+
+#[contractimpl]
+impl MockBadges {
+    pub fn mint_badge(env: Env, minter: Address, voter: Address, proposal_id: Symbol) {
+        minter.require_auth();
+        env.storage()
+            .instance()
+            .set(&(voter, minter, proposal_id), &true);
+    }
+
+    pub fn has_badge(env: Env, owner: Address, governance: Address, proposal_id: Symbol) -> bool {
+        env.storage()
+            .instance()
+            .has(&(owner, governance, proposal_id))
+    }
+}
+
+// Membership mock: reports membership of addresses admitted by the test
+#[contract]
+pub struct MockMembership;
+
+#[contractimpl]
+impl MockMembership {
+    pub fn admit(env: Env, addr: Address) {
+        env.storage().instance().set(&addr, &true);
+    }
+
+    pub fn is_member(env: Env, addr: Address) -> bool {
+        env.storage().instance().has(&addr)
+    }
+}
 
-fn create_token_contract<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
-    let token_address = e
-        .register_stellar_asset_contract_v2(admin.clone())
-        .address();
-    TokenClient::new(e, &token_address)
+// SEP-40 price oracle mock: reports the last price set per asset
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, asset: TokenGatedVotePriceAsset, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&asset, &TokenGatedVotePriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, asset: TokenGatedVotePriceAsset) -> Option<TokenGatedVotePriceData> {
+        env.storage().instance().get(&asset)
+    }
 }
 
 fn create_vote_contract<'a>(
@@ -23,18 +140,28 @@ fn create_vote_contract<'a>(
 ) -> TokenGatedVoteContractClient<'a> {
     let contract_address = e.register(
         TokenGatedVoteContract,
-        TokenGatedVoteContractArgs::__constructor(admin, token_address),
+        (admin.clone(), TokenSource::Stellar(token_address.clone())),
     );
     TokenGatedVoteContractClient::new(e, &contract_address)
 }
 
-fn setup_test_env() -> Env {
-    let e = Env::default();
-    e.mock_all_auths();
-    e.ledger().with_mut(|ledger| {
-        ledger.timestamp = 1000000;
-    });
-    e
+// Deploys a treasury contract governed by the vote contract and sets it as its treasury
+fn create_treasury<'a>(
+    e: &Env,
+    client: &TokenGatedVoteContractClient,
+    token_address: &Address,
+) -> TreasuryContractClient<'a> {
+    let treasury_address = e.register(
+        TreasuryContract,
+        (client.address.clone(), token_address.clone()),
+    );
+    client.set_treasury(&treasury_address);
+    TreasuryContractClient::new(e, &treasury_address)
+}
+
+fn sign_vote(e: &Env, signer: &SigningKey, payload: &TokenGatedVoteSignedPayload) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = payload.clone().to_xdr(e).iter().collect();
+    BytesN::from_array(e, &signer.sign(&message).to_bytes())
 }
 
 // Tests successful contract initialization with admin and token configuration.
@@ -46,7 +173,7 @@ fn test_initialization() {
     let token_address = Address::generate(&e);
     let contract_address = e.register(
         TokenGatedVoteContract,
-        TokenGatedVoteContractArgs::__constructor(&admin, &token_address),
+        (admin.clone(), TokenSource::Stellar(token_address.clone())),
     );
     let client = TokenGatedVoteContractClient::new(&e, &contract_address);
 
@@ -64,7 +191,7 @@ fn test_reinitialization() {
     let token_address = Address::generate(&e);
     let contract_address = e.register(
         TokenGatedVoteContract,
-        TokenGatedVoteContractArgs::__constructor(&admin, &token_address),
+        (admin.clone(), TokenSource::Stellar(token_address.clone())),
     );
     let client = TokenGatedVoteContractClient::new(&e, &contract_address);
 
@@ -74,7 +201,7 @@ fn test_reinitialization() {
     e.register_at(
         &contract_address,
         TokenGatedVoteContract,
-        TokenGatedVoteContractArgs::__constructor(&admin, &token_address),
+        (admin.clone(), TokenSource::Stellar(token_address.clone())),
     );
 }
 
@@ -93,7 +220,8 @@ fn test_create_proposal() {
     let start_time = ledger_time + 100;
     let end_time = start_time + 500000;
 
-    let result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
     assert!(result.is_ok());
 
     let governance_details = client.get_governance_details();
@@ -117,7 +245,7 @@ fn test_start_time_after_end() {
     let start_time = ledger_time + 500000;
     let end_time = ledger_time + 100;
 
-    client.create_proposal(&proposal_id, &description, &start_time, &end_time);
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 }
 
 // Tests start time in past validation.
@@ -136,7 +264,7 @@ fn test_start_time_in_past() {
     let start_time = ledger_time - 100;
     let end_time = ledger_time + 500000;
 
-    client.create_proposal(&proposal_id, &description, &start_time, &end_time);
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 }
 
 // Tests duration too long validation.
@@ -155,7 +283,7 @@ fn test_duration_too_long() {
     let start_time = ledger_time + 100;
     let end_time = start_time + 2000000;
 
-    client.create_proposal(&proposal_id, &description, &start_time, &end_time);
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 }
 
 // Tests duration too short validation.
@@ -174,7 +302,7 @@ fn test_duration_too_short() {
     let start_time = ledger_time + 100;
     let end_time = start_time + 200;
 
-    client.create_proposal(&proposal_id, &description, &start_time, &end_time);
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 }
 
 // Tests duplicate proposal creation rejection.
@@ -193,10 +321,307 @@ fn test_proposal_already_exists() {
     let start_time = ledger_time + 100;
     let end_time = start_time + 500000;
 
-    let result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
     assert!(result.is_ok());
 
-    client.create_proposal(&proposal_id, &description, &start_time, &end_time);
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+}
+
+// Tests proposals created under auto-generated sequential IDs.
+// Expects: Indexes count up from 1, each maps to its PROP ID and reads back by index, and an ID
+// a caller already chose is skipped rather than colliding.
+#[test]
+fn test_sequential_proposals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    assert_eq!(client.get_next_proposal_index(), 1);
+    assert_eq!(
+        client.create_sequential_proposal(&admin, &description, &start_time, &end_time),
+        1
+    );
+    assert_eq!(
+        client.create_sequential_proposal(&admin, &description, &(start_time + 1), &(end_time + 1)),
+        2
+    );
+    assert_eq!(client.get_sequential_id(&2), Symbol::new(&e, "PROP2"));
+    assert_eq!(client.get_proposal_by_index(&1).start_time, start_time);
+    assert_eq!(
+        client.get_proposal(&Symbol::new(&e, "PROP2")).start_time,
+        start_time + 1
+    );
+
+    client.create_proposal(
+        &admin,
+        &Symbol::new(&e, "PROP4"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(client.get_next_proposal_index(), 3);
+    assert_eq!(
+        client.create_sequential_proposal(&admin, &description, &start_time, &end_time),
+        3
+    );
+    assert_eq!(client.get_next_proposal_index(), 5);
+    assert!(matches!(
+        client.try_get_proposal_by_index(&99),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    ));
+}
+
+// Tests enforcing sequential IDs for every new proposal.
+// Expects: A caller-chosen ID fails with SequentialIdsRequired (Error #166) while the next
+// sequential ID is accepted, and disabling the mode allows chosen IDs again.
+#[test]
+fn test_sequential_ids_enforced() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    client.set_sequential_ids(&true);
+    assert!(client.get_config().sequential_ids);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    assert_eq!(
+        client.try_create_proposal(
+            &admin,
+            &symbol_short!("PROP001"),
+            &description,
+            &start_time,
+            &end_time
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::SequentialIdsRequired))
+    );
+
+    let next_id = client.get_sequential_id(&client.get_next_proposal_index());
+    client.create_proposal(&admin, &next_id, &description, &start_time, &end_time);
+    assert_eq!(
+        client.create_sequential_proposal(&admin, &description, &start_time, &end_time),
+        2
+    );
+
+    client.set_sequential_ids(&false);
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(client.get_next_proposal_index(), 3);
+}
+
+// Tests a proposal identified by a name longer than a short symbol allows.
+// Expects: The proposal takes the next sequential ID that votes and reads use, the name reads back
+// by ID and the ID by name, similar or longer names get their own IDs, an unknown name fails with
+// ProposalNotFound (Error #4), and a duplicate name fails with ProposalAlreadyExists (Error #3).
+#[test]
+fn test_named_proposals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &100);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let name = String::from_val(&e, &"Q3-2025-BUDGET-REALLOCATION");
+    let description = String::from_val(&e, &"Reallocate the Q3 budget");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let id = client.create_named_proposal(&admin, &name, &description, &start_time, &end_time);
+    assert_eq!(id, Symbol::new(&e, "PROP1"));
+    assert_eq!(client.get_named_proposal_id(&name), Some(id.clone()));
+    assert_eq!(client.get_proposal_name(&id), Some(name.clone()));
+    assert_eq!(client.get_proposal_name(&symbol_short!("PROP001")), None);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &id, &symbol_short!("FOR"));
+    assert_eq!(client.get_proposal_by_name(&name).total_for, 1);
+
+    // Names differing only in hyphens and underscores, or past 32 characters, stay distinct
+    let underscored = String::from_val(&e, &"Q3_2025_BUDGET_REALLOCATION");
+    let long_name = String::from_val(&e, &"Q3-2025-BUDGET-REALLOCATION-ROUND-2");
+    let underscored_id = client.create_named_proposal(
+        &admin,
+        &underscored,
+        &description,
+        &(start_time + 1),
+        &(end_time + 1),
+    );
+    let long_id = client.create_named_proposal(
+        &admin,
+        &long_name,
+        &description,
+        &(start_time + 1),
+        &(end_time + 1),
+    );
+    assert_eq!(underscored_id, Symbol::new(&e, "PROP2"));
+    assert_eq!(long_id, Symbol::new(&e, "PROP3"));
+    assert_eq!(client.get_proposal_name(&long_id), Some(long_name));
+    assert_eq!(client.get_proposal_by_name(&name).total_for, 1);
+    assert!(matches!(
+        client.try_get_proposal_by_name(&String::from_val(&e, &"Q3-2025-UNKNOWN")),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    ));
+
+    assert_eq!(
+        client.try_create_named_proposal(
+            &admin,
+            &name,
+            &description,
+            &(start_time + 1),
+            &(end_time + 1)
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalAlreadyExists))
+    );
+}
+
+// Tests names that cannot identify a proposal.
+// Expects: Empty names, names over 64 characters, and names with characters other than letters,
+// digits, underscores, and hyphens fail with InvalidProposalName (Error #167).
+#[test]
+fn test_invalid_proposal_name() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for name in [
+        "",
+        "Q3-2025-BUDGET-REALLOCATION-ROUND-2-SUPPLEMENTAL-COMMUNITY-GRANTS",
+        "Q3 BUDGET",
+        "BUDGET!",
+    ] {
+        let name = String::from_val(&e, &name);
+        assert_eq!(
+            client.try_create_named_proposal(&admin, &name, &description, &start_time, &end_time),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidProposalName))
+        );
+        assert!(matches!(
+            client.try_get_proposal_by_name(&name),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidProposalName))
+        ));
+    }
+}
+
+// Tests description validation on proposal creation.
+// Expects: Empty descriptions and descriptions over the configured limit fail with
+// InvalidDescription (Error #168), a raised limit admits the longer description, and limits of 0
+// or above 4,096 bytes fail with InvalidConfig (Error #15).
+#[test]
+fn test_invalid_description() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let long_description = String::from_str(&e, &"a".repeat(1_001));
+    for description in [String::from_str(&e, ""), long_description.clone()] {
+        assert_eq!(
+            client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidDescription))
+        );
+    }
+
+    client.set_max_description_length(&2_000);
+    assert_eq!(client.get_config().max_description_length, 2_000);
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &long_description,
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(
+        client.get_proposal(&proposal_id).description,
+        long_description
+    );
+
+    for length in [0, 4_097] {
+        assert_eq!(
+            client.try_set_max_description_length(&length),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+        );
+    }
+}
+
+// Tests a proposal whose full body is kept off-chain behind a content hash.
+// Expects: The proposal stores the short description with the hash of the body and publishes
+// PROPOSAL/CONTENT after PROPOSAL/CREATED, while plain proposals carry no hash.
+#[test]
+fn test_proposal_with_content() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_str(&e, "Reallocate the Q3 budget, full text off-chain");
+    let body = Bytes::from_slice(&e, "a".repeat(10_000).as_bytes());
+    let content_hash = e.crypto().sha256(&body).to_bytes();
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal_with_content(
+        &admin,
+        &proposal_id,
+        &description,
+        &content_hash,
+        &start_time,
+        &end_time,
+    );
+
+    assert_events(
+        &e,
+        &client.address,
+        &[
+            event(
+                &e,
+                (
+                    events::PROPOSAL,
+                    events::CREATED,
+                    events::EVENT_VERSION,
+                    proposal_id.clone(),
+                ),
+                events::ProposalCreatedEvent {
+                    id: proposal_id.clone(),
+                    proposer: admin.clone(),
+                    start_time,
+                    end_time,
+                },
+            ),
+            event(
+                &e,
+                (
+                    events::PROPOSAL,
+                    "CONTENT",
+                    events::EVENT_VERSION,
+                    proposal_id.clone(),
+                ),
+                content_hash.clone(),
+            ),
+        ],
+    );
+
+    let proposal = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal.description, description);
+    assert_eq!(proposal.content_hash, Some(content_hash));
+
+    let plain_id = symbol_short!("PROP002");
+    client.create_proposal(&admin, &plain_id, &description, &start_time, &end_time);
+    assert_eq!(client.get_proposal_details(&plain_id).content_hash, None);
 }
 
 // Tests voting with three users casting different vote types.
@@ -223,7 +648,8 @@ fn test_vote() {
     let start_time = ledger_time + 50;
     let end_time = ledger_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     e.ledger().with_mut(|ledger| {
         ledger.timestamp = ledger_time + 100;
@@ -264,7 +690,8 @@ fn test_vote_boundary_inclusive() {
     let start_time = ledger_time + 60;
     let end_time = start_time + MIN_PROPOSAL_DURATION;
     let desc = String::from_val(&e, &"Test proposal");
-    let create_res = client.try_create_proposal(&proposal_id, &desc, &start_time, &end_time);
+    let create_res =
+        client.try_create_proposal(&admin, &proposal_id, &desc, &start_time, &end_time);
     assert!(
         create_res.is_ok(),
         "Proposal creation failed: {:?}",
@@ -337,7 +764,8 @@ fn test_user_already_voted() {
     let start_time = ledger_time + 50;
     let end_time = ledger_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     e.ledger().with_mut(|ledger| {
         ledger.timestamp = ledger_time + 100;
@@ -367,7 +795,8 @@ fn test_user_cannot_vote() {
     let start_time = ledger_time + 50;
     let end_time = ledger_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     e.ledger().with_mut(|ledger| {
         ledger.timestamp = ledger_time + 100;
@@ -397,7 +826,8 @@ fn test_voting_not_active() {
     let start_time = ledger_time + 1000;
     let end_time = start_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     client.vote(&user, &proposal_id, &symbol_short!("FOR"));
 }
@@ -423,7 +853,8 @@ fn test_invalid_choice() {
     let start_time = ledger_time + 50;
     let end_time = ledger_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     e.ledger().with_mut(|ledger| {
         ledger.timestamp = ledger_time + 100;
@@ -462,13 +893,13 @@ fn test_get_governance_details() {
     let prop1_desc = String::from_val(&e, &"First proposal");
     let start1 = ledger_time + 100;
     let end1 = ledger_time + 500000;
-    let _result1 = client.try_create_proposal(&prop1_id, &prop1_desc, &start1, &end1);
+    let _result1 = client.try_create_proposal(&admin, &prop1_id, &prop1_desc, &start1, &end1);
 
     let prop2_id = symbol_short!("PROP002");
     let prop2_desc = String::from_val(&e, &"Second proposal");
     let start2 = ledger_time + 200;
     let end2 = ledger_time + 600000;
-    let _result2 = client.try_create_proposal(&prop2_id, &prop2_desc, &start2, &end2);
+    let _result2 = client.try_create_proposal(&admin, &prop2_id, &prop2_desc, &start2, &end2);
 
     let governance_details = client.get_governance_details();
     assert_eq!(governance_details.len(), 2);
@@ -497,7 +928,8 @@ fn test_get_proposal_details() {
     let start_time = ledger_time + 100;
     let end_time = start_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     let details = client.get_proposal_details(&proposal_id);
 
@@ -510,7 +942,8 @@ fn test_get_proposal_details() {
 }
 
 // Tests user voting history and eligibility information retrieval.
-// Expects: Non-empty user details containing voting participation and eligibility status.
+// Expects: Non-empty user details containing voting participation and eligibility status, with
+// eligibility following the same minimum balance as voting.
 #[test]
 fn test_get_user_details() {
     let e = setup_test_env();
@@ -529,7 +962,8 @@ fn test_get_user_details() {
     let start_time = ledger_time + 50;
     let end_time = ledger_time + 500000;
 
-    let _result = client.try_create_proposal(&proposal_id, &description, &start_time, &end_time);
+    let _result =
+        client.try_create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
 
     e.ledger().with_mut(|ledger| {
         ledger.timestamp = ledger_time + 100;
@@ -539,4 +973,6452 @@ fn test_get_user_details() {
 
     let user_details = client.get_user_details(&user);
     assert!(!user_details.is_empty());
+    assert_eq!(user_details.get(0).unwrap(), (proposal_id.clone(), true, 1));
+
+    let mut config = client.get_config();
+    config.min_balance = 1001;
+    client.set_config(&config);
+    let user_details = client.get_user_details(&user);
+    assert_eq!(user_details.get(0).unwrap(), (proposal_id, true, 0));
+}
+
+// Tests that accepted votes are reported to the configured reputation contract.
+// Expects: Reputation mock records one vote, and none after the hook is cleared.
+#[test]
+fn test_reputation_hook() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1000);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let reputation_address = e.register(MockReputationContract, ());
+    let reputation = MockReputationContractClient::new(&e, &reputation_address);
+    client.set_reputation_contract(&Some(reputation_address.clone()));
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = ledger_time + 500000;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    assert_eq!(reputation.votes_of(&user), 1);
+
+    client.set_reputation_contract(&None);
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+    assert_eq!(reputation.votes_of(&user), 1);
+}
+
+// Tests that accepted votes mint a proposal-specific badge through the configured badge contract.
+// Expects: Voters hold the badge of the proposal they voted on, and none after the hook is
+// cleared.
+#[test]
+fn test_badge_hook() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &1000);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let badges = MockBadgesClient::new(&e, &e.register(MockBadges, ()));
+
+    client.set_badge_contract(&Some(badges.address.clone()));
+    assert_last_event(
+        &e,
+        &client.address,
+        ("CONFIG", "UPDATED", events::EVENT_VERSION, "BADGES"),
+        Some(badges.address.clone()),
+    );
+    assert_eq!(client.get_badge_contract(), Some(badges.address.clone()));
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = ledger_time + 500000;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    assert!(badges.has_badge(&user, &client.address, &symbol_short!("PROP001")));
+    assert!(!badges.has_badge(&user, &client.address, &symbol_short!("PROP002")));
+
+    client.set_badge_contract(&None);
+    assert_eq!(client.get_badge_contract(), None);
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+    assert!(!badges.has_badge(&user, &client.address, &symbol_short!("PROP002")));
+}
+
+// Tests gating voting on membership instead of the governance token balance.
+// Expects: Members vote without tokens, holders without membership get UserCannotVote
+// (Error #6), and clearing the gate returns to balance gating.
+#[test]
+fn test_membership_gate() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let member = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&holder, &1000);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let membership = MockMembershipClient::new(&e, &e.register(MockMembership, ()));
+    membership.admit(&member);
+
+    client.set_membership_gate(&Some(membership.address.clone()));
+    assert_last_event(
+        &e,
+        &client.address,
+        ("CONFIG", "UPDATED", events::EVENT_VERSION, "MEMBERSHIP"),
+        Some(membership.address.clone()),
+    );
+    assert_eq!(
+        client.get_membership_gate(),
+        Some(membership.address.clone())
+    );
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = ledger_time + 500000;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&member, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    assert_eq!(
+        client.try_vote(&holder, &symbol_short!("PROP001"), &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote))
+    );
+    assert_eq!(
+        client.get_user_details(&member).get(0).unwrap(),
+        (symbol_short!("PROP001"), true, 1)
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .total_for,
+        1
+    );
+
+    client.set_membership_gate(&None);
+    assert_eq!(client.get_membership_gate(), None);
+    client.vote(&holder, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+    assert_eq!(
+        client.try_vote(&member, &symbol_short!("PROP002"), &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote))
+    );
+}
+
+// Tests participation counters, the leaderboard ranking, and achievement unlocks.
+// Expects: Votes, proposals, and passed proposals count toward each score, the leaderboard orders
+// addresses by score with earlier holders of a tie first, and achievements unlock at thresholds.
+#[test]
+fn test_leaderboard_and_achievements() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    assert_eq!(client.get_leaderboard(&0, &10).len(), 0);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user1, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user2, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user2, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&symbol_short!("PROP001"));
+
+    let leaderboard = client.get_leaderboard(&0, &10);
+    assert_eq!(leaderboard.len(), 3);
+    assert_eq!(leaderboard.get(0).unwrap().user, admin);
+    assert_eq!(leaderboard.get(0).unwrap().score, 3);
+    assert_eq!(leaderboard.get(1).unwrap().user, user2);
+    assert_eq!(leaderboard.get(1).unwrap().score, 2);
+    assert_eq!(leaderboard.get(2).unwrap().user, user1);
+    assert_eq!(
+        leaderboard.get(2).unwrap().participation,
+        TokenGatedVoteParticipation {
+            votes_cast: 1,
+            proposals_created: 0,
+            proposals_passed: 0,
+        }
+    );
+    let page = client.get_leaderboard(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().user, user2);
+    assert_eq!(client.get_leaderboard(&3, &10).len(), 0);
+    assert_eq!(client.get_leaderboard(&0, &0).len(), 0);
+
+    let achievements = client.get_achievements(&admin);
+    assert_eq!(achievements.score, 3);
+    assert_eq!(
+        achievements.unlocked,
+        vec![&e, symbol_short!("PROPOSER"), symbol_short!("PASSED")]
+    );
+    assert_eq!(
+        client.get_achievements(&user1).unlocked,
+        vec![&e, symbol_short!("FIRSTVOTE")]
+    );
+    assert_eq!(client.get_achievements(&Address::generate(&e)).score, 0);
+}
+
+// Creates the BUDGET bundle of B1, B2, and B3 by the admin, with two holders able to vote, and
+// opens its voting window; returns the client, the voters, and the bundle's end_time
+fn setup_bundle<'a>(e: &Env) -> (TokenGatedVoteContractClient<'a>, [Address; 2], u64) {
+    let admin = Address::generate(e);
+    let voters = [Address::generate(e), Address::generate(e)];
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    for voter in voters.iter() {
+        stellar_asset.mint(voter, &100);
+    }
+    let client = create_vote_contract(e, &admin, &token.address);
+
+    let items = vec![
+        e,
+        TokenGatedVoteBundleItem {
+            id: symbol_short!("B1"),
+            description: String::from_val(e, &"Fund the audit"),
+        },
+        TokenGatedVoteBundleItem {
+            id: symbol_short!("B2"),
+            description: String::from_val(e, &"Fund the bug bounty"),
+        },
+        TokenGatedVoteBundleItem {
+            id: symbol_short!("B3"),
+            description: String::from_val(e, &"Fund the launch"),
+        },
+    ];
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_bundle(
+        &admin,
+        &symbol_short!("BUDGET"),
+        &items,
+        &start_time,
+        &end_time,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    (client, voters, end_time)
+}
+
+// Tests finalizing a bundle in which one member loses its vote.
+// Expects: Finalizing any member finalizes all three, members that passed on their own are
+// Defeated, and the bundle is recorded as failed.
+#[test]
+fn test_bundle_fails_together() {
+    let e = setup_test_env();
+    let (client, voters, end_time) = setup_bundle(&e);
+    assert_eq!(
+        client.get_proposal_bundle(&symbol_short!("B2")),
+        Some(symbol_short!("BUDGET"))
+    );
+    assert_eq!(client.get_proposal_bundle(&symbol_short!("OTHER")), None);
+
+    client.vote(&voters[0], &symbol_short!("B1"), &symbol_short!("FOR"));
+    client.vote(&voters[0], &symbol_short!("B2"), &symbol_short!("FOR"));
+    client.vote(&voters[0], &symbol_short!("B3"), &symbol_short!("AGAINST"));
+    client.vote(&voters[1], &symbol_short!("B3"), &symbol_short!("AGAINST"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&symbol_short!("B2")),
+        TokenGatedVoteProposalStatus::Defeated
+    );
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "BUNDLE",
+            "FINALIZED",
+            events::EVENT_VERSION,
+            symbol_short!("BUDGET"),
+        ),
+        false,
+    );
+    for id in [
+        symbol_short!("B1"),
+        symbol_short!("B2"),
+        symbol_short!("B3"),
+    ] {
+        assert_eq!(
+            client.get_proposal_status(&id),
+            TokenGatedVoteProposalStatus::Defeated
+        );
+    }
+    let bundle = client.get_bundle(&symbol_short!("BUDGET")).unwrap();
+    assert!(bundle.finalized);
+    assert!(!bundle.passed);
+
+    assert_eq!(
+        client.try_finalize_proposal(&symbol_short!("B1")),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidStateTransition))
+    );
+    assert_eq!(
+        client.try_queue_proposal(&symbol_short!("B1")),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidStateTransition))
+    );
+}
+
+// Tests finalizing and executing a bundle whose members all pass, then vetoing one of them.
+// Expects: Every member Succeeds, but once a sibling is vetoed the others cannot execute
+// (Error #177).
+#[test]
+fn test_bundle_passes_together() {
+    let e = setup_test_env();
+    let (client, voters, end_time) = setup_bundle(&e);
+    for id in [
+        symbol_short!("B1"),
+        symbol_short!("B2"),
+        symbol_short!("B3"),
+    ] {
+        client.vote(&voters[0], &id, &symbol_short!("FOR"));
+    }
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&symbol_short!("B1")),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+    assert_eq!(
+        client.get_proposal_status(&symbol_short!("B3")),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+    let bundle = client.get_bundle(&symbol_short!("BUDGET")).unwrap();
+    assert_eq!(
+        bundle.proposals,
+        vec![
+            &e,
+            symbol_short!("B1"),
+            symbol_short!("B2"),
+            symbol_short!("B3")
+        ]
+    );
+    assert!(bundle.passed);
+
+    client.queue_proposal(&symbol_short!("B1"));
+    client.execute_proposal(&symbol_short!("B1"));
+    client.veto_proposal(&symbol_short!("B3"));
+    client.queue_proposal(&symbol_short!("B2"));
+    assert_eq!(
+        client.try_execute_proposal(&symbol_short!("B2")),
+        Err(Ok(TokenGatedVoteContractErrors::BundleFailed))
+    );
+}
+
+// Tests creating bundles with a taken ID, too few or too many members, or a taken member ID.
+// Expects: BundleAlreadyExists (Error #176), InvalidConfig (Error #15), and
+// ProposalAlreadyExists (Error #3), with no member of a rejected bundle stored.
+#[test]
+fn test_invalid_bundle() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let item = |id: Symbol| TokenGatedVoteBundleItem {
+        id,
+        description: String::from_val(&e, &"Line item"),
+    };
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let create = |bundle_id: Symbol, items: Vec<TokenGatedVoteBundleItem>| {
+        client.try_create_bundle(&admin, &bundle_id, &items, &start_time, &end_time)
+    };
+
+    assert_eq!(
+        create(symbol_short!("BUDGET"), vec![&e, item(symbol_short!("B1"))]),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+    );
+    let mut oversized = Vec::new(&e);
+    for index in 0..=bundles::MAX_BUNDLE_SIZE {
+        oversized.push_back(item(sequence::sequential_id(&e, index)));
+    }
+    assert_eq!(
+        create(symbol_short!("BUDGET"), oversized),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+    );
+    assert_eq!(
+        create(
+            symbol_short!("BUDGET"),
+            vec![&e, item(symbol_short!("B1")), item(symbol_short!("B1"))]
+        ),
+        Err(Ok(
+            TokenGatedVoteContractErrors::ProposalAlreadyExists.into()
+        ))
+    );
+    assert!(client
+        .try_get_proposal_details(&symbol_short!("B1"))
+        .is_err());
+
+    assert!(create(
+        symbol_short!("BUDGET"),
+        vec![&e, item(symbol_short!("B1")), item(symbol_short!("B2"))]
+    )
+    .is_ok());
+    assert_eq!(
+        create(
+            symbol_short!("BUDGET"),
+            vec![&e, item(symbol_short!("B3")), item(symbol_short!("B4"))]
+        ),
+        Err(Ok(TokenGatedVoteModuleErrors::BundleAlreadyExists.into()))
+    );
+}
+
+// Tests executing a proposal that depends on another before and after the dependency executes.
+// Expects: The dependency is recorded and published, execution fails with DependencyNotExecuted
+// (Error #178) until the dependency has executed, and succeeds afterwards.
+#[test]
+fn test_dependent_proposal_execution() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &100);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let budget = symbol_short!("BUDGET");
+    let payout = symbol_short!("PAYOUT");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &budget,
+        &String::from_val(&e, &"Approve the budget"),
+        &start_time,
+        &end_time,
+    );
+    client.create_dependent_proposal(
+        &admin,
+        &payout,
+        &String::from_val(&e, &"Pay out the budget"),
+        &start_time,
+        &end_time,
+        &budget,
+    );
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "PROPOSAL",
+            "DEPENDENCY",
+            events::EVENT_VERSION,
+            payout.clone(),
+        ),
+        budget.clone(),
+    );
+    assert_eq!(client.get_dependency(&payout), Some(budget.clone()));
+    assert_eq!(client.get_dependency(&budget), None);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voter, &budget, &symbol_short!("FOR"));
+    client.vote(&voter, &payout, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    for id in [&budget, &payout] {
+        client.finalize_proposal(id);
+        client.queue_proposal(id);
+    }
+
+    assert_eq!(
+        client.try_execute_proposal(&payout),
+        Err(Ok(TokenGatedVoteContractErrors::DependencyNotExecuted))
+    );
+    client.execute_proposal(&budget);
+    client.execute_proposal(&payout);
+    assert_eq!(
+        client.get_proposal_status(&payout),
+        TokenGatedVoteProposalStatus::Executed
+    );
+}
+
+// Tests declaring dependencies that lead back to the proposal or form an overlong chain.
+// Expects: Self-dependencies and cycles through proposals declared earlier fail with
+// DependencyCycle (Error #179), and chains past 10 proposals with InvalidConfig (Error #15).
+#[test]
+fn test_dependency_cycle() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let description = String::from_val(&e, &"Dependent proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let create = |id: &Symbol, depends_on: &Symbol| {
+        client.try_create_dependent_proposal(
+            &admin,
+            id,
+            &description,
+            &start_time,
+            &end_time,
+            depends_on,
+        )
+    };
+
+    let (first, second) = (symbol_short!("FIRST"), symbol_short!("SECOND"));
+    assert_eq!(
+        create(&first, &first),
+        Err(Ok(TokenGatedVoteContractErrors::DependencyCycle))
+    );
+    assert!(create(&first, &second).is_ok());
+    assert_eq!(
+        create(&second, &first),
+        Err(Ok(TokenGatedVoteContractErrors::DependencyCycle))
+    );
+    assert!(client.try_get_proposal_details(&second).is_err());
+
+    client.create_proposal(&admin, &second, &description, &start_time, &end_time);
+    for index in 1..=dependencies::MAX_DEPENDENCY_DEPTH {
+        let dependency = match index {
+            1 => second.clone(),
+            _ => sequence::sequential_id(&e, index - 1),
+        };
+        assert!(create(&sequence::sequential_id(&e, index), &dependency).is_ok());
+    }
+    let deepest = sequence::sequential_id(&e, dependencies::MAX_DEPENDENCY_DEPTH);
+    assert_eq!(
+        create(&symbol_short!("TOODEEP"), &deepest),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+}
+
+// Tests amending a proposal's description and voting window twice before voting opens.
+// Expects: The proposal takes the new values, each amendment publishes PROPOSAL/AMENDED, and the
+// revision history keeps the replaced description hash, window, editor, and time, oldest first.
+#[test]
+fn test_amend_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let proposal_id = symbol_short!("PROP001");
+    let original = String::from_val(&e, &"Fund the audit");
+    let start_time = e.ledger().timestamp() + 1_000;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &original, &start_time, &end_time);
+    assert_eq!(client.get_proposal_revisions(&proposal_id).len(), 0);
+
+    let amended = String::from_val(&e, &"Fund the audit and the bug bounty");
+    client.amend_proposal(
+        &admin,
+        &proposal_id,
+        &amended,
+        &(start_time + 100),
+        &(end_time + 100),
+    );
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "PROPOSAL",
+            "AMENDED",
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        governance_core::events::ProposalAmendedEvent {
+            id: proposal_id.clone(),
+            description: amended.clone(),
+            start_time: start_time + 100,
+            end_time: end_time + 100,
+        },
+    );
+    let proposal = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal.description, amended);
+    assert_eq!(proposal.start_time, start_time + 100);
+    assert_eq!(
+        client.get_expiring_soon(&1).get(0).unwrap(),
+        (proposal_id.clone(), end_time + 100)
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 10);
+    client.amend_proposal(&admin, &proposal_id, &amended, &start_time, &end_time);
+    let revisions = client.get_proposal_revisions(&proposal_id);
+    assert_eq!(revisions.len(), 2);
+    let first = revisions.get(0).unwrap();
+    assert_eq!(
+        first.description_hash,
+        e.crypto()
+            .sha256(&Bytes::from_slice(&e, b"Fund the audit"))
+            .to_bytes()
+    );
+    assert_eq!((first.start_time, first.end_time), (start_time, end_time));
+    assert_eq!(first.editor, admin);
+    let second = revisions.get(1).unwrap();
+    assert_eq!(second.start_time, start_time + 100);
+    assert_eq!(second.amended_at, first.amended_at + 10);
+}
+
+// Tests amending proposals by other addresses, after voting opens, past the revision limit,
+// and moving a bundled proposal's window.
+// Expects: NotProposalEditor (Error #180), InvalidStateTransition (Error #120),
+// RevisionLimitReached (Error #181), and InvalidConfig (Error #15) respectively.
+#[test]
+fn test_amend_proposal_restrictions() {
+    let e = setup_test_env();
+    let (client, voters, end_time) = setup_bundle(&e);
+    let description = String::from_val(&e, &"Fund the audit");
+    assert_eq!(
+        client.try_amend_proposal(
+            &voters[0],
+            &symbol_short!("B1"),
+            &description,
+            &(end_time - MIN_PROPOSAL_DURATION),
+            &end_time,
+        ),
+        Err(Ok(TokenGatedVoteModuleErrors::NotProposalEditor.into()))
+    );
+    assert_eq!(
+        client.try_amend_proposal(
+            &client.get_admin(),
+            &symbol_short!("B1"),
+            &description,
+            &(end_time - MIN_PROPOSAL_DURATION),
+            &end_time,
+        ),
+        Err(Ok(
+            TokenGatedVoteContractErrors::InvalidStateTransition.into()
+        ))
+    );
+
+    let admin = client.get_admin();
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 1_000;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    for _ in 0..revisions::MAX_REVISIONS {
+        client.amend_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    }
+    assert_eq!(
+        client.try_amend_proposal(&admin, &proposal_id, &description, &start_time, &end_time),
+        Err(Ok(TokenGatedVoteModuleErrors::RevisionLimitReached.into()))
+    );
+
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+    let description = String::from_val(&e, &"Line item");
+    let item = |id: Symbol| TokenGatedVoteBundleItem {
+        id,
+        description: String::from_val(&e, &"Line item"),
+    };
+    let start_time = e.ledger().timestamp() + 1_000;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_bundle(
+        &admin,
+        &symbol_short!("BUDGET"),
+        &vec![&e, item(symbol_short!("B1")), item(symbol_short!("B2"))],
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(
+        client.try_amend_proposal(
+            &admin,
+            &symbol_short!("B1"),
+            &description,
+            &start_time,
+            &(end_time + 100),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+    );
+    assert!(client
+        .try_amend_proposal(
+            &admin,
+            &symbol_short!("B1"),
+            &String::from_val(&e, &"Line item with a clearer scope"),
+            &start_time,
+            &end_time,
+        )
+        .is_ok());
+}
+
+// Tests anchoring a proposal's discussion thread and updating its hash before voting opens.
+// Expects: Each call publishes PROPOSAL/DISCUSSION and replaces the anchor returned by
+// get_discussion, which is absent until the first call.
+#[test]
+fn test_set_discussion() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 1_000;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Fund the audit"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    assert_eq!(client.get_discussion(&proposal_id), None);
+
+    let discussion_url = String::from_val(&e, &"https://forum.example.org/t/fund-the-audit/42");
+    let first_hash = BytesN::from_array(&e, &[1; 32]);
+    client.set_discussion(&admin, &proposal_id, &first_hash, &discussion_url);
+    let discussion = TokenGatedVoteDiscussion {
+        discussion_hash: first_hash,
+        discussion_url: discussion_url.clone(),
+        updated_at: e.ledger().timestamp(),
+    };
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "PROPOSAL",
+            "DISCUSSION",
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        discussion.clone(),
+    );
+    assert_eq!(client.get_discussion(&proposal_id), Some(discussion));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp += 10);
+    let second_hash = BytesN::from_array(&e, &[2; 32]);
+    client.set_discussion(&admin, &proposal_id, &second_hash, &discussion_url);
+    assert_eq!(
+        client.get_discussion(&proposal_id),
+        Some(TokenGatedVoteDiscussion {
+            discussion_hash: second_hash,
+            discussion_url,
+            updated_at: e.ledger().timestamp(),
+        })
+    );
+}
+
+// Tests anchoring a discussion from another address, with an empty or oversized URL, and once
+// voting opens.
+// Expects: NotProposalEditor (Error #180), InvalidConfig (Error #15), and
+// InvalidStateTransition (Error #120) respectively.
+#[test]
+fn test_set_discussion_restrictions() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 1_000;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Fund the audit"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    let discussion_hash = BytesN::from_array(&e, &[1; 32]);
+    let discussion_url = String::from_val(&e, &"https://forum.example.org/t/fund-the-audit/42");
+    assert_eq!(
+        client.try_set_discussion(
+            &Address::generate(&e),
+            &proposal_id,
+            &discussion_hash,
+            &discussion_url,
+        ),
+        Err(Ok(TokenGatedVoteModuleErrors::NotProposalEditor.into()))
+    );
+    for url in [
+        String::from_val(&e, &""),
+        String::from_bytes(&e, &[b'a'; discussions::MAX_URL_LENGTH as usize + 1]),
+    ] {
+        assert_eq!(
+            client.try_set_discussion(&admin, &proposal_id, &discussion_hash, &url),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+        );
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.try_set_discussion(&admin, &proposal_id, &discussion_hash, &discussion_url),
+        Err(Ok(
+            TokenGatedVoteContractErrors::InvalidStateTransition.into()
+        ))
+    );
+    assert_eq!(client.get_discussion(&proposal_id), None);
+}
+
+// Tests the per-proposal voter count across choices, proposals, and a rejected duplicate vote.
+// Expects: Each accepted vote counts once on its own proposal, a rejected vote leaves the count
+// unchanged, and both summary reads report it.
+#[test]
+fn test_voter_count() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let voters = create_holders(&e, &token.address, &[100, 100, 100]);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.create_proposal(&admin, &id, &description, &start_time, &end_time);
+    }
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .voter_count,
+        0
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    for (voter, choice) in voters.iter().zip([
+        symbol_short!("FOR"),
+        symbol_short!("AGAINST"),
+        symbol_short!("ABSTAIN"),
+    ]) {
+        client.vote(&voter, &symbol_short!("PROP001"), &choice);
+    }
+    client.vote(
+        &voters.get(0).unwrap(),
+        &symbol_short!("PROP002"),
+        &symbol_short!("FOR"),
+    );
+    assert_eq!(
+        client.try_vote(
+            &voters.get(0).unwrap(),
+            &symbol_short!("PROP001"),
+            &symbol_short!("AGAINST"),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::UserAlreadyVoted))
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .voter_count,
+        3
+    );
+
+    let details = client.get_governance_details();
+    assert_eq!(details.get(0).unwrap().voter_count, 3);
+    assert_eq!(details.get(1).unwrap().voter_count, 1);
+    let active = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &0, &10);
+    assert_eq!(active.proposals.get(0).unwrap().voter_count, 3);
+    assert_eq!(active.proposals.get(1).unwrap().voter_count, 1);
+}
+
+// Tests a category requiring a minimum number of unique voters on top of its weight quorum.
+// Expects: A proposal meeting the weight quorum with too few voters projects and finalizes as
+// QuorumFailed, while one reaching both thresholds succeeds.
+#[test]
+fn test_min_voters_turnout() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let voters = create_holders(&e, &token.address, &[1_000_000, 100]);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_category_rules(
+        &symbol_short!("TURNOUT"),
+        &TokenGatedVoteCategoryRules {
+            min_duration: MIN_PROPOSAL_DURATION,
+            max_duration: MIN_PROPOSAL_DURATION,
+            quorum: 1,
+            threshold: 5_000,
+            min_voters: 2,
+            proposers: TokenGatedVoteProposerRule::Admin,
+        },
+    );
+
+    let description = String::from_val(&e, &"Turnout proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.create_categorized_proposal(
+            &admin,
+            &id,
+            &symbol_short!("TURNOUT"),
+            &description,
+            &start_time,
+            &end_time,
+        );
+    }
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .min_voters,
+        2
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(
+        &voters.get(0).unwrap(),
+        &symbol_short!("PROP001"),
+        &symbol_short!("FOR"),
+    );
+    for voter in voters.iter() {
+        client.vote(&voter, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+    }
+    assert!(
+        client
+            .get_quorum_progress(&symbol_short!("PROP001"))
+            .participation
+            >= 1
+    );
+    assert_eq!(
+        client.get_projected_outcome(&symbol_short!("PROP001")),
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&symbol_short!("PROP001"));
+    client.finalize_proposal(&symbol_short!("PROP002"));
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .status,
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .status,
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+}
+
+// Creates a contract in dual-approval mode with a one-day council window and finalizes two
+// proposals that passed their vote
+fn setup_council<'a>(e: &Env) -> (TokenGatedVoteContractClient<'a>, Address, u64) {
+    let admin = Address::generate(e);
+    let council = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let voters = create_holders(e, &token.address, &[100]);
+    let client = create_vote_contract(e, &admin, &token.address);
+    client.set_council(&Some(TokenGatedVoteCouncil {
+        council: council.clone(),
+        approval_window: 86_400,
+    }));
+
+    let description = String::from_val(e, &"Council proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.create_proposal(&admin, &id, &description, &start_time, &end_time);
+    }
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.vote(&voters.get(0).unwrap(), &id, &symbol_short!("FOR"));
+    }
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&symbol_short!("PROP001"));
+    (client, council, end_time)
+}
+
+// Tests executing proposals that need both the holders' vote and the council's co-approval.
+// Expects: Each approval publishes an APPROVAL event, execution waits for the council
+// (Error #185), and proposals become ReadyToExecute once queued and co-approved, in either order.
+#[test]
+fn test_dual_approval_execution() {
+    let e = setup_test_env();
+    let (client, council, _) = setup_council(&e);
+    let first = symbol_short!("PROP001");
+    let second = symbol_short!("PROP002");
+    let holders_approval = event(
+        &e,
+        ("APPROVAL", "HOLDERS", events::EVENT_VERSION, first.clone()),
+        e.ledger().timestamp(),
+    );
+    assert!(contract_events(&e, &client.address).contains(holders_approval));
+
+    client.queue_proposal(&first);
+    assert_eq!(
+        client.try_execute_proposal(&first),
+        Err(Ok(TokenGatedVoteContractErrors::AwaitingCouncilApproval))
+    );
+    client.council_approve(&first);
+    assert_events(
+        &e,
+        &client.address,
+        &[
+            event(
+                &e,
+                ("APPROVAL", "COUNCIL", events::EVENT_VERSION, first.clone()),
+                council,
+            ),
+            event(
+                &e,
+                ("PROPOSAL", "READY", events::EVENT_VERSION, first.clone()),
+                governance_core::events::ProposalStatusEvent {
+                    id: first.clone(),
+                    status: GovernanceStatus::Queued,
+                },
+            ),
+        ],
+    );
+    assert_eq!(
+        client.get_proposal_details(&first).status,
+        TokenGatedVoteProposalStatus::ReadyToExecute
+    );
+    assert_eq!(
+        client.get_council_approval(&first),
+        Some(e.ledger().timestamp())
+    );
+    client.execute_proposal(&first);
+    assert_eq!(
+        client.get_proposal_details(&first).status,
+        TokenGatedVoteProposalStatus::Executed
+    );
+
+    client.finalize_proposal(&second);
+    client.council_approve(&second);
+    assert_eq!(
+        client.get_proposal_details(&second).status,
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+    client.queue_proposal(&second);
+    assert_eq!(
+        client.get_proposal_details(&second).status,
+        TokenGatedVoteProposalStatus::ReadyToExecute
+    );
+    client.execute_proposal(&second);
+}
+
+// Tests co-approving outside dual-approval mode, twice, after the approval window, and for a
+// proposal that has not passed, and setting a council without a window.
+// Expects: NoCouncil (Error #182), CouncilAlreadyApproved (Error #183), CouncilWindowClosed
+// (Error #184), InvalidStateTransition (Error #120), and InvalidConfig (Error #15) respectively.
+#[test]
+fn test_council_approval_restrictions() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let plain = create_vote_contract(&e, &admin, &Address::generate(&e));
+    let start_time = e.ledger().timestamp() + 50;
+    plain.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &String::from_val(&e, &"Plain proposal"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    assert_eq!(
+        plain.try_council_approve(&symbol_short!("PROP001")),
+        Err(Ok(TokenGatedVoteModuleErrors::NoCouncil.into()))
+    );
+    assert_eq!(
+        plain.try_set_council(&Some(TokenGatedVoteCouncil {
+            council: Address::generate(&e),
+            approval_window: 0,
+        })),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+
+    let (client, _, end_time) = setup_council(&e);
+    assert_eq!(
+        client.try_council_approve(&symbol_short!("PROP002")),
+        Err(Ok(
+            TokenGatedVoteContractErrors::InvalidStateTransition.into()
+        ))
+    );
+    client.council_approve(&symbol_short!("PROP001"));
+    assert_eq!(
+        client.try_council_approve(&symbol_short!("PROP001")),
+        Err(Ok(TokenGatedVoteModuleErrors::CouncilAlreadyApproved.into()))
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 86_401);
+    client.finalize_proposal(&symbol_short!("PROP002"));
+    assert_eq!(
+        client.try_council_approve(&symbol_short!("PROP002")),
+        Err(Ok(TokenGatedVoteModuleErrors::CouncilWindowClosed.into()))
+    );
+    client.set_council(&None);
+    assert_eq!(client.get_council(), None);
+    client.queue_proposal(&symbol_short!("PROP002"));
+    client.execute_proposal(&symbol_short!("PROP002"));
+}
+
+// Tests funding, pro-rata claiming, and sweeping of a participation reward pool.
+// Expects: Each voter receives an equal share and the remainder is swept to the admin.
+#[test]
+fn test_rewards_fund_claim_and_sweep() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&admin, &1000);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+    stellar_asset.mint(&user3, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    client.fund_rewards(&proposal_id, &1000);
+    assert_eq!(token.balance(&client.address), 1000);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user2, &proposal_id, &symbol_short!("AGAINST"));
+    client.vote(&user3, &proposal_id, &symbol_short!("ABSTAIN"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(client.claim_reward(&user1, &proposal_id), 333);
+    assert_eq!(client.claim_reward(&user2, &proposal_id), 333);
+    assert_eq!(token.balance(&user1), 433);
+
+    let pool = client.get_reward_pool(&proposal_id);
+    assert_eq!(pool.claimed, 666);
+    assert_eq!(pool.claim_count, 2);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = end_time + rewards::REWARD_CLAIM_WINDOW + 1;
+    });
+    assert!(client.try_claim_reward(&user3, &proposal_id).is_err());
+    assert_eq!(client.sweep_rewards(&proposal_id, &admin), 334);
+    assert_eq!(token.balance(&admin), 334);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+// Tests double-claim protection on a reward pool.
+// Expects: RewardAlreadyClaimed error (Error #117) on the second claim.
+#[test]
+#[should_panic(expected = "Error(Contract, #117)")]
+fn test_reward_already_claimed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&admin, &500);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    client.fund_rewards(&proposal_id, &500);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.claim_reward(&user, &proposal_id);
+    client.claim_reward(&user, &proposal_id);
+}
+
+// Tests that rewards cannot be claimed before the proposal is finalized.
+// Expects: RewardNotClaimable error (Error #116) while voting is still open.
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_reward_not_claimable() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&admin, &500);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    client.fund_rewards(&proposal_id, &500);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+    client.claim_reward(&user, &proposal_id);
+}
+
+// Tests that unclaimed rewards cannot be swept while the claim window is open.
+// Expects: RewardClaimWindowOpen error (Error #118) right after voting ends.
+#[test]
+#[should_panic(expected = "Error(Contract, #118)")]
+fn test_reward_sweep_window_open() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&admin, &500);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    client.fund_rewards(&proposal_id, &500);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.sweep_rewards(&proposal_id, &admin);
+}
+
+// Tests explicit activation once the voting window opens.
+// Expects: Activation rejected before start_time, then stored status becomes Active.
+#[test]
+fn test_activate_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Pending
+    );
+
+    assert!(client.try_activate_proposal(&proposal_id).is_err());
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.activate_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Active
+    );
+}
+
+// Tests finalization of a proposal with more FOR than AGAINST votes.
+// Expects: Succeeded status stored, reported in summaries, and proposer rewarded.
+#[test]
+fn test_finalize_proposal_succeeded() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+    stellar_asset.mint(&user3, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let reputation_address = e.register(MockReputationContract, ());
+    let reputation = MockReputationContractClient::new(&e, &reputation_address);
+    client.set_reputation_contract(&Some(reputation_address));
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user2, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user3, &proposal_id, &symbol_short!("AGAINST"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    let outcome = client.finalize_proposal(&proposal_id);
+    assert_eq!(outcome, TokenGatedVoteProposalStatus::Succeeded);
+    assert_eq!(
+        client.get_governance_details().get(0).unwrap().status,
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+    assert_eq!(reputation.passed_of(&admin), 1);
+}
+
+// Tests finalization of a proposal that did not reach the configured quorum.
+// Expects: QuorumFailed status even though FOR votes outnumber AGAINST votes.
+#[test]
+fn test_finalize_proposal_quorum_failed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_quorum(&2);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    assert_eq!(client.get_proposal_details(&proposal_id).quorum, 2);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&proposal_id),
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+}
+
+// Tests finalization attempt while the voting window is still open.
+// Expects: VotingNotEnded error (Error #14) at end_time.
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_finalize_before_end() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = end_time);
+    client.finalize_proposal(&proposal_id);
+}
+
+// Tests that a cancelled proposal no longer accepts votes.
+// Expects: VotingNotActive error (Error #7) after cancellation inside the voting window.
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_cancel_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    client.cancel_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Cancelled
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+}
+
+// Tests the full Succeeded -> Queued -> Executed path.
+// Expects: Each transition is stored and a second execution is rejected.
+#[test]
+fn test_queue_and_execute_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.queue_proposal(&proposal_id);
+
+    let details = client.get_proposal_details(&proposal_id);
+    assert_eq!(details.status, TokenGatedVoteProposalStatus::Queued);
+    assert_eq!(details.queued_at, end_time + 1);
+
+    client.execute_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Executed
+    );
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+}
+
+// Tests that a vetoed proposal can no longer be queued.
+// Expects: InvalidStateTransition error (Error #120) when queueing after veto.
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn test_veto_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.veto_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Vetoed
+    );
+
+    client.queue_proposal(&proposal_id);
+}
+
+// Tests the grace period between the end of voting and finalization.
+// Expects: Finalization rejected during it (Error #173), vetoes allowed only once voting ended.
+#[test]
+fn test_grace_period() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    client.set_grace_period(&100);
+    assert_eq!(client.get_config().grace_period, 100);
+    assert_eq!(
+        client.try_set_grace_period(&(config::GRACE_PERIOD_CEILING + 1)),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let vetoed_id = symbol_short!("PROP001");
+    let passed_id = symbol_short!("PROP002");
+    for id in [&vetoed_id, &passed_id] {
+        client.create_proposal(&admin, id, &description, &start_time, &end_time);
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    for id in [&vetoed_id, &passed_id] {
+        client.vote(&voters[0], id, &symbol_short!("FOR"));
+    }
+    assert_eq!(
+        client.try_veto_proposal(&vetoed_id),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidStateTransition))
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 100);
+    assert_eq!(
+        client.try_finalize_proposal(&passed_id),
+        Err(Ok(TokenGatedVoteContractErrors::GracePeriodActive))
+    );
+    client.veto_proposal(&vetoed_id);
+    let vetoed = client.get_proposal_details(&vetoed_id);
+    assert_eq!(vetoed.status, TokenGatedVoteProposalStatus::Vetoed);
+    assert_eq!(vetoed.finalized_at, end_time + 100);
+    assert_eq!(
+        client.try_finalize_proposal(&vetoed_id),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidStateTransition))
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 101);
+    assert_eq!(
+        client.finalize_proposal(&passed_id),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+}
+
+// Tests expiry of a queued proposal that was not executed in time.
+// Expects: Execution rejected after the window (Error #122) and status becomes Expired.
+#[test]
+fn test_expire_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.queue_proposal(&proposal_id);
+    assert!(client.try_expire_proposal(&proposal_id).is_err());
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = end_time + 2 + lifecycle::EXECUTION_WINDOW;
+    });
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ExecutionWindowClosed))
+    );
+    client.expire_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Expired
+    );
+}
+
+// Tests direct status lookup for a single proposal across its lifecycle.
+// Expects: Pending, Active, then the finalized outcome without scanning summaries.
+#[test]
+fn test_get_proposal_status() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Pending
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Active
+    );
+    client.vote(&user, &proposal_id, &symbol_short!("AGAINST"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Defeated
+    );
+    assert!(client
+        .try_get_proposal_status(&symbol_short!("FAKE"))
+        .is_err());
+}
+
+// Tests status-filtered proposal queries with cursor and limit pagination.
+// Expects: Only matching proposals are returned in creation order, each page resuming after the
+// last proposal it scanned.
+#[test]
+fn test_get_proposals_by_status() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let early_start = ledger_time + 50;
+    let late_start = ledger_time + 10_000;
+
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &early_start,
+        &(early_start + MIN_PROPOSAL_DURATION),
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &late_start,
+        &(late_start + MIN_PROPOSAL_DURATION),
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP003"),
+        &description,
+        &early_start,
+        &(early_start + MIN_PROPOSAL_DURATION),
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP004"),
+        &description,
+        &early_start,
+        &(early_start + MIN_PROPOSAL_DURATION),
+    );
+    client.cancel_proposal(&symbol_short!("PROP004"));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = early_start);
+
+    let active = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &0, &10);
+    assert_eq!(active.proposals.len(), 2);
+    assert_eq!(
+        active.proposals.get(0).unwrap().id,
+        symbol_short!("PROP001")
+    );
+    assert_eq!(
+        active.proposals.get(1).unwrap().id,
+        symbol_short!("PROP003")
+    );
+    assert_eq!(active.next_cursor, None);
+
+    let first_page = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &0, &1);
+    assert_eq!(first_page.proposals.len(), 1);
+    assert_eq!(first_page.next_cursor, Some(1));
+    let second_page = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &1, &1);
+    assert_eq!(second_page.proposals.len(), 1);
+    assert_eq!(
+        second_page.proposals.get(0).unwrap().id,
+        symbol_short!("PROP003")
+    );
+    assert_eq!(second_page.next_cursor, Some(3));
+
+    let pending = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Pending, &0, &10);
+    assert_eq!(pending.proposals.len(), 1);
+    assert_eq!(
+        pending.proposals.get(0).unwrap().id,
+        symbol_short!("PROP002")
+    );
+
+    let cancelled =
+        client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Cancelled, &3, &10);
+    assert_eq!(cancelled.proposals.len(), 1);
+    assert_eq!(cancelled.next_cursor, None);
+
+    let empty = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &0, &0);
+    assert_eq!(empty.proposals.len(), 0);
+    assert_eq!(empty.next_cursor, Some(0));
+    let past_end = client.get_proposals_by_status(&TokenGatedVoteProposalStatus::Active, &10, &10);
+    assert_eq!(past_end.proposals.len(), 0);
+    assert_eq!(past_end.next_cursor, None);
+}
+
+// Tests the end_time-ordered index of open proposals.
+// Expects: Soonest-ending proposals first, finalized and cancelled ones removed.
+#[test]
+fn test_get_expiring_soon() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let short_end = start_time + MIN_PROPOSAL_DURATION;
+    let medium_end = short_end + 1_000;
+    let long_end = start_time + MAX_PROPOSAL_DURATION;
+
+    client.create_proposal(
+        &admin,
+        &symbol_short!("LONG"),
+        &description,
+        &start_time,
+        &long_end,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("SHORT"),
+        &description,
+        &start_time,
+        &short_end,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("MEDIUM"),
+        &description,
+        &start_time,
+        &medium_end,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("CANCEL"),
+        &description,
+        &start_time,
+        &short_end,
+    );
+    client.cancel_proposal(&symbol_short!("CANCEL"));
+
+    let expiring = client.get_expiring_soon(&10);
+    assert_eq!(expiring.len(), 3);
+    assert_eq!(
+        expiring.get(0).unwrap(),
+        (symbol_short!("SHORT"), short_end)
+    );
+    assert_eq!(
+        expiring.get(1).unwrap(),
+        (symbol_short!("MEDIUM"), medium_end)
+    );
+    assert_eq!(expiring.get(2).unwrap(), (symbol_short!("LONG"), long_end));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = short_end + 1);
+    client.finalize_proposal(&symbol_short!("SHORT"));
+
+    let expiring = client.get_expiring_soon(&1);
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().0, symbol_short!("MEDIUM"));
+}
+
+// Tests aggregate and per-proposal statistics across two proposals.
+// Expects: Counters track proposals, votes, unique voters, and per-proposal turnout.
+#[test]
+fn test_governance_stats() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_quorum(&2);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user1, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user2, &symbol_short!("PROP001"), &symbol_short!("AGAINST"));
+    client.vote(&user1, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+
+    let stats = client.get_governance_stats();
+    assert_eq!(stats.total_proposals, 2);
+    assert_eq!(stats.total_votes, 3);
+    assert_eq!(stats.unique_voters, 2);
+
+    let first = client.get_proposal_stats(&symbol_short!("PROP001"));
+    assert_eq!(first.votes_cast, 2);
+    assert_eq!(first.turnout_bps, 10_000);
+    assert!(first.quorum_reached);
+
+    let second = client.get_proposal_stats(&symbol_short!("PROP002"));
+    assert_eq!(second.votes_cast, 1);
+    assert_eq!(second.turnout_bps, 5_000);
+    assert!(!second.quorum_reached);
+}
+
+// Tests live quorum progress and projected outcomes while votes come in.
+// Expects: Progress tracks participation against the quorum snapshot, the projection flips from
+// QuorumFailed to Succeeded, finalization matches it, and unknown proposals fail (Error #4).
+#[test]
+fn test_quorum_progress_and_projection() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    client.set_quorum(&3);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    assert_eq!(
+        client.get_quorum_progress(&proposal_id),
+        TokenGatedVoteQuorumProgress {
+            participation: 0,
+            quorum: 3,
+            percent_bps: 0,
+        }
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(client.get_quorum_progress(&proposal_id).percent_bps, 3_333);
+    assert_eq!(
+        client.get_projected_outcome(&proposal_id),
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+
+    client.vote(&voters[1], &proposal_id, &symbol_short!("FOR"));
+    client.vote(&voters[2], &proposal_id, &symbol_short!("AGAINST"));
+    assert_eq!(
+        client.get_quorum_progress(&proposal_id),
+        TokenGatedVoteQuorumProgress {
+            participation: 3,
+            quorum: 3,
+            percent_bps: 10_000,
+        }
+    );
+    assert_eq!(
+        client.get_projected_outcome(&proposal_id),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&proposal_id),
+        client.get_projected_outcome(&proposal_id)
+    );
+    assert_eq!(
+        client.try_get_quorum_progress(&symbol_short!("MISSING")),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    );
+}
+
+// Tests the countdown of a proposal through its voting phase.
+// Expects: Seconds until start while pending, until end while active, and 0 once voting has
+// ended, both before and after finalization.
+#[test]
+fn test_get_time_remaining() {
+    let e = setup_test_env();
+    let (client, admin, _) = setup_config(&e);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    assert_eq!(client.get_time_remaining(&proposal_id), 50);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = start_time + 100);
+    assert_eq!(
+        client.get_time_remaining(&proposal_id),
+        MIN_PROPOSAL_DURATION - 100
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = end_time);
+    assert_eq!(client.get_time_remaining(&proposal_id), 0);
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(client.get_time_remaining(&proposal_id), 0);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(client.get_time_remaining(&proposal_id), 0);
+}
+
+// Tests a proposal whose voting window is given in ledger sequence numbers.
+// Expects: Voting follows the ledger window and ignores timestamps past the estimated bounds.
+#[test]
+fn test_ledger_sequence_proposal() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_ledger = e.ledger().sequence() + 10;
+    let end_ledger = start_ledger + (MIN_PROPOSAL_DURATION / timing::LEDGER_SECONDS) as u32;
+    client.create_ledger_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &start_ledger,
+        &end_ledger,
+    );
+
+    let details = client.get_proposal_details(&proposal_id);
+    assert_eq!(details.timing, TokenGatedVoteTimingMode::LedgerSequence);
+    assert_eq!(
+        (details.start_ledger, details.end_ledger),
+        (start_ledger, end_ledger)
+    );
+    assert_eq!(details.start_time, e.ledger().timestamp() + 50);
+    assert_eq!(details.end_time, details.start_time + MIN_PROPOSAL_DURATION);
+
+    // A timestamp past the estimated start does not open voting before the start ledger
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = details.start_time + 10);
+    assert_eq!(
+        client.try_vote(&voters[0], &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::VotingNotActive))
+    );
+    assert_eq!(client.get_time_remaining(&proposal_id), 50);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = start_ledger);
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+
+    // Nor does a timestamp past the estimated end close it before the end ledger
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = details.end_time + 10);
+    assert_eq!(
+        client.try_finalize_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::VotingNotEnded))
+    );
+    client.vote(&voters[1], &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = end_ledger + 1);
+    client.finalize_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+}
+
+// Tests proposals created while the timing mode requires both windows.
+// Expects: Voting opens once both windows open and closes once either ends.
+#[test]
+fn test_both_timing_mode() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    client.set_timing_mode(&TokenGatedVoteTimingMode::Both);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+    let details = client.get_proposal_details(&proposal_id);
+    assert_eq!(details.timing, TokenGatedVoteTimingMode::Both);
+    assert_eq!(details.start_ledger, e.ledger().sequence() + 10);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Pending
+    );
+    assert_eq!(client.get_time_remaining(&proposal_id), 50);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number = details.start_ledger);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Active
+    );
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.try_vote(&voters[1], &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::VotingNotActive))
+    );
+    client.finalize_proposal(&proposal_id);
+}
+
+// Tests creating ledger-sequence proposals with invalid windows.
+// Expects: StartTimeAfterEnd for a window not moving forward, StartTimeInPast for a closed start.
+#[test]
+fn test_ledger_proposal_invalid_window() {
+    let e = setup_test_env();
+    let (client, admin, _) = setup_config(&e);
+    e.ledger().with_mut(|ledger| ledger.sequence_number = 100);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    assert_eq!(
+        client.try_create_ledger_proposal(&admin, &proposal_id, &description, &200, &200),
+        Err(Ok(TokenGatedVoteContractErrors::StartTimeAfterEnd))
+    );
+    assert_eq!(
+        client.try_create_ledger_proposal(&admin, &proposal_id, &description, &99, &2_000),
+        Err(Ok(TokenGatedVoteContractErrors::StartTimeInPast))
+    );
+}
+
+// Tests the participation history of a user across finalized and open proposals.
+// Expects: Only voted proposals listed, with choice, weight, and pass outcome.
+#[test]
+fn test_get_user_history() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let short_end = start_time + MIN_PROPOSAL_DURATION;
+    let long_end = start_time + MAX_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &short_end,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &long_end,
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP003"),
+        &description,
+        &start_time,
+        &long_end,
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("AGAINST"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = short_end + 1);
+    client.finalize_proposal(&symbol_short!("PROP001"));
+
+    let history = client.get_user_history(&user);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.id, symbol_short!("PROP001"));
+    assert_eq!(first.choice, symbol_short!("FOR"));
+    assert_eq!(first.weight, 1);
+    assert_eq!(first.status, TokenGatedVoteProposalStatus::Succeeded);
+    assert!(first.passed);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.id, symbol_short!("PROP002"));
+    assert_eq!(second.choice, symbol_short!("AGAINST"));
+    assert_eq!(second.status, TokenGatedVoteProposalStatus::Active);
+    assert!(!second.passed);
+}
+
+// Tests a relayer submitting a vote the user signed off-chain without the user's auth.
+// Expects: Vote is tallied for the signer and the signer's nonce advances.
+#[test]
+fn test_vote_by_sig() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_signing_key(
+        &user,
+        &BytesN::from_array(&e, &signer.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    let expiration = ledger_time + 3600;
+    let signature = sign_vote(
+        &e,
+        &signer,
+        &TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: client.get_nonce(&user),
+            expiration,
+        },
+    );
+
+    e.set_auths(&[]);
+    client.vote_by_sig(
+        &user,
+        &proposal_id,
+        &symbol_short!("FOR"),
+        &expiration,
+        &signature,
+    );
+
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 1);
+    assert_eq!(client.get_nonce(&user), 1);
+}
+
+// Tests that a consumed signature cannot be replayed.
+// Expects: Signature verification failure once the nonce has advanced.
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_vote_by_sig_replay() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_signing_key(
+        &user,
+        &BytesN::from_array(&e, &signer.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    let expiration = ledger_time + 3600;
+    let signature = sign_vote(
+        &e,
+        &signer,
+        &TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: 0,
+            expiration,
+        },
+    );
+
+    client.vote_by_sig(
+        &user,
+        &proposal_id,
+        &symbol_short!("FOR"),
+        &expiration,
+        &signature,
+    );
+    client.vote_by_sig(
+        &user,
+        &proposal_id,
+        &symbol_short!("FOR"),
+        &expiration,
+        &signature,
+    );
+}
+
+// Tests rejection of a signed vote submitted after its expiration.
+// Expects: SignatureExpired error (Error #125).
+#[test]
+#[should_panic(expected = "Error(Contract, #125)")]
+fn test_vote_by_sig_expired() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+
+    let token = create_token_contract(&e, &admin);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_signing_key(
+        &user,
+        &BytesN::from_array(&e, &signer.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    let signature = sign_vote(
+        &e,
+        &signer,
+        &TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: 0,
+            expiration: ledger_time - 1,
+        },
+    );
+
+    client.vote_by_sig(
+        &user,
+        &proposal_id,
+        &symbol_short!("FOR"),
+        &(ledger_time - 1),
+        &signature,
+    );
+}
+
+// Tests rejection of a signed vote from a user without a registered key.
+// Expects: SigningKeyNotFound error (Error #126).
+#[test]
+#[should_panic(expected = "Error(Contract, #126)")]
+fn test_vote_by_sig_without_key() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let ledger_time = e.ledger().timestamp();
+    client.vote_by_sig(
+        &user,
+        &symbol_short!("PROP001"),
+        &symbol_short!("FOR"),
+        &(ledger_time + 3600),
+        &BytesN::from_array(&e, &[0; 64]),
+    );
+}
+
+// Tests relayed settlement of several signed votes with per-item reporting.
+// Expects: Valid votes are tallied while rejected items report their error code.
+#[test]
+fn test_submit_signed_votes() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let unregistered = Address::generate(&e);
+    let signer1 = SigningKey::from_bytes(&[1; 32]);
+    let signer2 = SigningKey::from_bytes(&[2; 32]);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_signing_key(
+        &user1,
+        &BytesN::from_array(&e, &signer1.verifying_key().to_bytes()),
+    );
+    client.set_signing_key(
+        &user2,
+        &BytesN::from_array(&e, &signer2.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    let expiration = ledger_time + 3600;
+    let mut votes = Vec::new(&e);
+    for (user, signer) in [(&user1, &signer1), (&user2, &signer2)] {
+        let payload = TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: 0,
+            expiration,
+        };
+        votes.push_back(TokenGatedVoteSignedVote {
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            expiration,
+            signature: sign_vote(&e, signer, &payload),
+        });
+    }
+    votes.push_back(TokenGatedVoteSignedVote {
+        user: unregistered.clone(),
+        id: proposal_id.clone(),
+        choice: symbol_short!("FOR"),
+        expiration,
+        signature: BytesN::from_array(&e, &[0; 64]),
+    });
+
+    let results = client.submit_signed_votes(&relayer, &votes);
+    assert_eq!(results.len(), 3);
+    assert!(results.get(0).unwrap().accepted);
+    assert_eq!(results.get(1).unwrap().error, 6);
+    assert_eq!(results.get(2).unwrap().error, 126);
+
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 1);
+    assert_eq!(client.get_nonce(&user1), 1);
+    assert_eq!(client.get_nonce(&user2), 0);
+}
+
+// Tests rejection of relayed batches above the maximum size.
+// Expects: BatchTooLarge error (Error #127) before any item is processed.
+#[test]
+#[should_panic(expected = "Error(Contract, #127)")]
+fn test_submit_signed_votes_batch_too_large() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let mut votes = Vec::new(&e);
+    for _ in 0..=MAX_SIGNED_BATCH {
+        votes.push_back(TokenGatedVoteSignedVote {
+            user: user.clone(),
+            id: symbol_short!("PROP001"),
+            choice: symbol_short!("FOR"),
+            expiration: 0,
+            signature: BytesN::from_array(&e, &[0; 64]),
+        });
+    }
+
+    client.submit_signed_votes(&relayer, &votes);
+}
+
+// Tests keeper activation of scheduled proposals as their start times pass.
+// Expects: Each call activates only the proposals that are due and drains the queue in order.
+#[test]
+fn test_schedule_and_activate_due_proposals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let early_start = ledger_time + 100;
+    let late_start = ledger_time + 200;
+    client.schedule_proposal(
+        &symbol_short!("PROP002"),
+        &description,
+        &late_start,
+        &(late_start + MIN_PROPOSAL_DURATION),
+    );
+    client.schedule_proposal(
+        &symbol_short!("PROP001"),
+        &description,
+        &early_start,
+        &(early_start + MIN_PROPOSAL_DURATION),
+    );
+
+    let scheduled = client.get_scheduled_proposals();
+    assert_eq!(scheduled.len(), 2);
+    assert_eq!(
+        scheduled.get(0).unwrap(),
+        (symbol_short!("PROP001"), early_start)
+    );
+    assert_eq!(client.activate_due_proposals(), 0);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = early_start);
+    assert_eq!(client.activate_due_proposals(), 1);
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .status,
+        TokenGatedVoteProposalStatus::Active
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .status,
+        TokenGatedVoteProposalStatus::Pending
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = late_start);
+    assert_eq!(client.activate_due_proposals(), 1);
+    assert_eq!(client.get_scheduled_proposals().len(), 0);
+}
+
+// Tests that the keeper drops scheduled proposals cancelled before their start time.
+// Expects: No activation and the cancelled proposal stays Cancelled.
+#[test]
+fn test_activate_due_proposals_skips_cancelled() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 100;
+    client.schedule_proposal(
+        &proposal_id,
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    client.cancel_proposal(&proposal_id);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(client.activate_due_proposals(), 0);
+    assert_eq!(client.get_scheduled_proposals().len(), 0);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Cancelled
+    );
+}
+
+// Tests automatic creation of consecutive election proposals on a quarterly cadence.
+// Expects: Cycle 1 starts immediately and cycle 2 keeps the configured period.
+#[test]
+fn test_recurring_election_cycles() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    let period = 7_776_000; // ~90 days
+    client.set_cycle_config(&period, &MIN_PROPOSAL_DURATION, &5);
+    assert_eq!(client.get_current_cycle(), None);
+
+    let ledger_time = e.ledger().timestamp();
+    let first = client.start_next_cycle();
+    assert_eq!(first.number, 1);
+    assert_eq!(first.id, Symbol::new(&e, "CYCLE1"));
+    assert_eq!(first.seats, 5);
+    assert_eq!(first.start_time, ledger_time);
+    assert_eq!(
+        client.get_proposal_details(&first.id).end_time,
+        ledger_time + MIN_PROPOSAL_DURATION
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first.end_time + 1);
+    let second = client.start_next_cycle();
+    assert_eq!(second.number, 2);
+    assert_eq!(second.id, Symbol::new(&e, "CYCLE2"));
+    assert_eq!(second.start_time, ledger_time + period);
+    assert_eq!(client.get_current_cycle(), Some(second));
+    assert_eq!(client.get_governance_details().len(), 2);
+}
+
+// Tests that a new cycle cannot start while the previous election is still running.
+// Expects: CycleNotEnded error (Error #129).
+#[test]
+#[should_panic(expected = "Error(Contract, #129)")]
+fn test_start_next_cycle_not_ended() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    client.set_cycle_config(&7_776_000, &MIN_PROPOSAL_DURATION, &5);
+    client.start_next_cycle();
+    client.start_next_cycle();
+}
+
+// Tests rejection of a cadence shorter than the election duration.
+// Expects: InvalidCycleConfig error (Error #130).
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn test_invalid_cycle_config() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    client.set_cycle_config(&(MIN_PROPOSAL_DURATION - 1), &MIN_PROPOSAL_DURATION, &5);
+}
+
+// Creates, votes FOR, and finalizes proposal "PROP001" so it ends in the Succeeded state
+fn setup_succeeded_proposal<'a>(
+    e: &Env,
+    admin: &Address,
+) -> (TokenGatedVoteContractClient<'a>, TokenClient<'a>, Symbol) {
+    let user = Address::generate(e);
+    let token = create_token_contract(e, admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(e, admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    (client, token, proposal_id)
+}
+
+// Tests that a bonded dispute freezes queueing and execution of a finalized result.
+// Expects: Bond is locked, dispute is stored, and queueing fails with ProposalDisputed.
+#[test]
+fn test_dispute_freezes_execution() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, token, proposal_id) = setup_succeeded_proposal(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&challenger, &500);
+
+    client.set_dispute_bond(&200);
+    let dispute = client.dispute_proposal(&challenger, &proposal_id);
+
+    assert_eq!(dispute.challenger, challenger);
+    assert_eq!(dispute.bond, 200);
+    assert!(!dispute.resolved);
+    assert_eq!(client.get_dispute(&proposal_id), Some(dispute));
+    assert_eq!(token.balance(&challenger), 300);
+    assert_eq!(token.balance(&client.address), 200);
+    assert_eq!(
+        client.try_queue_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalDisputed))
+    );
+}
+
+// Tests the admin rejecting a dispute, first without a treasury contract to slash into.
+// Expects: TreasuryNotSet error (Error #186) leaves the dispute open, then the bond is slashed
+// into the treasury contract's community pool and the proposal can be queued again.
+#[test]
+fn test_dispute_rejected_slashes_bond() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, token, proposal_id) = setup_succeeded_proposal(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&challenger, &200);
+
+    client.set_dispute_bond(&200);
+    client.dispute_proposal(&challenger, &proposal_id);
+    assert_eq!(
+        client.try_resolve_dispute(&proposal_id, &false),
+        Err(Ok(TokenGatedVoteContractErrors::TreasuryNotSet.into()))
+    );
+    let treasury = create_treasury(&e, &client, &token.address);
+    let dispute = client.resolve_dispute(&proposal_id, &false);
+
+    assert!(dispute.resolved);
+    assert!(!dispute.upheld);
+    assert_eq!(token.balance(&admin), 0);
+    assert_eq!(token.balance(&challenger), 0);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&treasury.address), 200);
+    assert_eq!(treasury.get_community_pool().balance, 200);
+    assert_eq!(
+        treasury.get_pool_inflows().get(0).unwrap().source,
+        TreasuryPoolSource::SlashedBond
+    );
+
+    client.queue_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Queued
+    );
+}
+
+// Tests the admin upholding a dispute against a passed result.
+// Expects: Bond is released to the challenger and the proposal is vetoed.
+#[test]
+fn test_dispute_upheld_vetoes_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, token, proposal_id) = setup_succeeded_proposal(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&challenger, &200);
+
+    client.set_dispute_bond(&200);
+    client.dispute_proposal(&challenger, &proposal_id);
+    let dispute = client.resolve_dispute(&proposal_id, &true);
+
+    assert!(dispute.upheld);
+    assert_eq!(token.balance(&challenger), 200);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Vetoed
+    );
+}
+
+// Tests disputing before the admin has configured a dispute bond.
+// Expects: DisputesDisabled error (Error #131).
+#[test]
+#[should_panic(expected = "Error(Contract, #131)")]
+fn test_dispute_disabled() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, _, proposal_id) = setup_succeeded_proposal(&e, &admin);
+
+    client.dispute_proposal(&challenger, &proposal_id);
+}
+
+// Tests disputing after the grace period following finalization.
+// Expects: DisputeWindowClosed error (Error #132).
+#[test]
+#[should_panic(expected = "Error(Contract, #132)")]
+fn test_dispute_window_closed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, token, proposal_id) = setup_succeeded_proposal(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&challenger, &200);
+
+    client.set_dispute_bond(&200);
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + disputes::DISPUTE_GRACE_PERIOD + 1;
+    });
+    client.dispute_proposal(&challenger, &proposal_id);
+}
+
+// Tests disputing a result that already has a dispute.
+// Expects: DisputeAlreadyExists error (Error #133).
+#[test]
+#[should_panic(expected = "Error(Contract, #133)")]
+fn test_dispute_already_exists() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let challenger = Address::generate(&e);
+    let (client, token, proposal_id) = setup_succeeded_proposal(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&challenger, &400);
+
+    client.set_dispute_bond(&200);
+    client.dispute_proposal(&challenger, &proposal_id);
+    client.dispute_proposal(&challenger, &proposal_id);
+}
+
+// Tests resolving a proposal that has no open dispute.
+// Expects: DisputeNotFound error (Error #134).
+#[test]
+#[should_panic(expected = "Error(Contract, #134)")]
+fn test_resolve_dispute_not_found() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let (client, _, proposal_id) = setup_succeeded_proposal(&e, &admin);
+
+    client.resolve_dispute(&proposal_id, &true);
+}
+
+// Funds the treasury from two members and passes treasury proposal "TREAS1" paying `amount`,
+// with member2 voting AGAINST; returns (client, treasury, token, id, member1, member2, recipient)
+fn setup_treasury_proposal<'a>(
+    e: &Env,
+    amount: i128,
+) -> (
+    TokenGatedVoteContractClient<'a>,
+    TreasuryContractClient<'a>,
+    TokenClient<'a>,
+    Symbol,
+    Address,
+    Address,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let member1 = Address::generate(e);
+    let member2 = Address::generate(e);
+    let member3 = Address::generate(e);
+    let recipient = Address::generate(e);
+
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    for member in [&member1, &member2, &member3] {
+        stellar_asset.mint(member, &1000);
+    }
+
+    let client = create_vote_contract(e, &admin, &token.address);
+    let treasury = create_treasury(e, &client, &token.address);
+    treasury.deposit(&member1, &600);
+    treasury.deposit(&member2, &400);
+
+    let proposal_id = symbol_short!("TREAS1");
+    let description = String::from_val(e, &"Fund community grants");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_treasury_proposal(
+        &proposal_id,
+        &description,
+        &start_time,
+        &end_time,
+        &recipient,
+        &amount,
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&member2, &proposal_id, &symbol_short!("AGAINST"));
+    client.vote(&member3, &proposal_id, &symbol_short!("FOR"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    (
+        client,
+        treasury,
+        token,
+        proposal_id,
+        member1,
+        member2,
+        recipient,
+    )
+}
+
+// Tests an AGAINST voter rage-quitting before a passed treasury proposal executes.
+// Expects: Pro-rata payout and share burn, execution blocked until the exit window closes.
+#[test]
+fn test_rage_quit_before_execution() {
+    let e = setup_test_env();
+    let (client, treasury, token, proposal_id, _, member2, recipient) =
+        setup_treasury_proposal(&e, 300);
+
+    client.queue_proposal(&proposal_id);
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ExitWindowOpen))
+    );
+
+    assert_eq!(client.rage_quit(&member2, &proposal_id), 400);
+    assert_eq!(token.balance(&member2), 1000);
+    assert_eq!(treasury.get_shares(&member2), 0);
+
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    client.execute_proposal(&proposal_id);
+
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(
+        treasury.get_treasury(),
+        TreasuryTotals {
+            balance: 300,
+            total_shares: 600,
+        }
+    );
+}
+
+// Tests rage-quit by a member who voted FOR the proposal.
+// Expects: RageQuitNotEligible error (Error #138).
+#[test]
+#[should_panic(expected = "Error(Contract, #138)")]
+fn test_rage_quit_requires_against_vote() {
+    let e = setup_test_env();
+    let (client, _, _, proposal_id, member1, _, _) = setup_treasury_proposal(&e, 300);
+
+    client.rage_quit(&member1, &proposal_id);
+}
+
+// Tests rage-quit after the exit window has closed.
+// Expects: RageQuitClosed error (Error #137).
+#[test]
+#[should_panic(expected = "Error(Contract, #137)")]
+fn test_rage_quit_after_exit_window() {
+    let e = setup_test_env();
+    let (client, _, _, proposal_id, _, member2, _) = setup_treasury_proposal(&e, 300);
+
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    client.rage_quit(&member2, &proposal_id);
+}
+
+// Tests execution of a treasury proposal whose payout exceeds the remaining treasury.
+// Expects: InsufficientTreasury error from the treasury contract (Error #2501).
+#[test]
+#[should_panic(expected = "Error(Contract, #2501)")]
+fn test_treasury_proposal_insufficient_funds() {
+    let e = setup_test_env();
+    let (client, _, _, proposal_id, _, member2, _) = setup_treasury_proposal(&e, 800);
+
+    client.queue_proposal(&proposal_id);
+    client.rage_quit(&member2, &proposal_id);
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    client.execute_proposal(&proposal_id);
+}
+
+// Tests execution of a passed treasury proposal before any treasury contract has been set.
+// Expects: TreasuryNotSet error (Error #186).
+#[test]
+fn test_treasury_proposal_without_treasury() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let member = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&member, &1000);
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("TREAS1");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_treasury_proposal(
+        &proposal_id,
+        &String::from_val(&e, &"Fund a grant"),
+        &start_time,
+        &end_time,
+        &Address::generate(&e),
+        &300,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member, &proposal_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.queue_proposal(&proposal_id);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = end_time + 1 + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::TreasuryNotSet))
+    );
+    assert_eq!(client.get_treasury(), None);
+}
+
+// Tests a treasury spending cap across two executed proposals within a 10-day rolling period.
+// Expects: The cap reaches the treasury contract, which fails with SpendingLimitExceeded
+// (Error #2502) while the first payout is in the window and pays once it has rolled out.
+#[test]
+fn test_treasury_spending_limit() {
+    let e = setup_test_env();
+    let (client, treasury, token, proposal_id, member1, _, recipient) =
+        setup_treasury_proposal(&e, 300);
+    client.set_spending_limit(&500, &864_000);
+    assert_eq!(
+        treasury.get_spending_limit(),
+        TreasurySpendingLimit {
+            cap: 500,
+            period: 864_000,
+        }
+    );
+    client.queue_proposal(&proposal_id);
+
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    client.execute_proposal(&proposal_id);
+    let first_paid_at = e.ledger().timestamp();
+    assert_eq!(treasury.get_period_spending(), 300);
+
+    let second_id = symbol_short!("TREAS2");
+    let start_time = first_paid_at + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_treasury_proposal(
+        &second_id,
+        &String::from_val(&e, &"Fund more grants"),
+        &start_time,
+        &end_time,
+        &recipient,
+        &300,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member1, &second_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&second_id);
+    client.queue_proposal(&second_id);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = end_time + 1 + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    assert_eq!(
+        client.try_execute_proposal(&second_id),
+        Err(Err(InvokeError::Contract(
+            TreasuryContractErrors::SpendingLimitExceeded as u32
+        )))
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_paid_at + 864_000);
+    client.execute_proposal(&second_id);
+    assert_eq!(treasury.get_period_spending(), 300);
+    assert_eq!(token.balance(&recipient), 600);
+}
+
+// Tests lowering the spending cap through the configuration once the treasury contract is set.
+// Expects: The treasury contract receives the new cap and fails a payout above it with
+// SpendingLimitExceeded (Error #2502), then pays it once the cap is raised again.
+#[test]
+fn test_treasury_spending_limit_updated() {
+    let e = setup_test_env();
+    let (client, treasury, token, proposal_id, _, _, recipient) = setup_treasury_proposal(&e, 300);
+    client.set_spending_limit(&500, &864_000);
+
+    let mut config = client.get_config();
+    config.spending_cap = 200;
+    client.set_config(&config);
+    assert_eq!(
+        treasury.get_spending_limit(),
+        TreasurySpendingLimit {
+            cap: 200,
+            period: 864_000,
+        }
+    );
+
+    client.queue_proposal(&proposal_id);
+    let finalized_at = client.get_proposal_details(&proposal_id).finalized_at;
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = finalized_at + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Err(InvokeError::Contract(
+            TreasuryContractErrors::SpendingLimitExceeded as u32
+        )))
+    );
+
+    client.set_spending_limit(&300, &864_000);
+    assert_eq!(treasury.get_spending_limit().cap, 300);
+    client.execute_proposal(&proposal_id);
+    assert_eq!(treasury.get_period_spending(), 300);
+    assert_eq!(token.balance(&recipient), 300);
+}
+
+// Tests spending limits with a negative cap or an out-of-range period.
+// Expects: InvalidAmount error (Error #13) and InvalidConfig error (Error #15).
+#[test]
+fn test_set_spending_limit_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    assert_eq!(
+        client.try_set_spending_limit(&-1, &864_000),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidAmount))
+    );
+    for period in [0, treasury::MAX_SPENDING_PERIOD + 1] {
+        assert_eq!(
+            client.try_set_spending_limit(&500, &period),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+        );
+    }
+}
+
+// Tests a streamed grant vesting linearly after execution and cancelled halfway.
+// Expects: Execution has the treasury contract escrow the grant, withdrawals from the treasury
+// pay the vested portion, cancellation returns the unvested remainder to the treasury and
+// freezes accrual, and a second cancellation fails with StreamNotFound from the treasury
+// contract (Error #2506).
+#[test]
+fn test_treasury_stream() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let member = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&member, &2000);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let treasury = create_treasury(&e, &client, &token.address);
+    treasury.deposit(&member, &1000);
+
+    let grant_id = symbol_short!("GRANT1");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let grant = TokenGatedVoteTreasuryAction {
+        recipient: recipient.clone(),
+        amount: 1000,
+        duration: 1000,
+    };
+    client.create_stream_proposal(
+        &grant_id,
+        &String::from_val(&e, &"Stream a grant"),
+        &start_time,
+        &end_time,
+        &grant,
+    );
+    assert_eq!(client.get_treasury_action(&grant_id), Some(grant));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member, &grant_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&grant_id);
+    client.queue_proposal(&grant_id);
+    let opened_at = end_time + 1 + treasury::RAGE_QUIT_WINDOW + 1;
+    e.ledger().with_mut(|ledger| ledger.timestamp = opened_at);
+    client.execute_proposal(&grant_id);
+    assert_eq!(treasury.get_treasury().balance, 0);
+    assert_eq!(token.balance(&recipient), 0);
+    assert_eq!(
+        treasury.get_stream(&grant_id).unwrap().start_time,
+        opened_at
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 250);
+    assert_eq!(treasury.get_withdrawable(&grant_id), 250);
+    assert_eq!(treasury.withdraw_streamed(&grant_id), 250);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 500);
+    assert_eq!(client.cancel_stream(&grant_id), 500);
+    assert_eq!(treasury.get_treasury().balance, 500);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 1000);
+    assert_eq!(treasury.withdraw_streamed(&grant_id), 250);
+    assert_eq!(treasury.withdraw_streamed(&grant_id), 0);
+    assert_eq!(token.balance(&recipient), 500);
+    assert_eq!(treasury.get_stream(&grant_id).unwrap().withdrawn, 500);
+    assert_eq!(
+        client.try_cancel_stream(&grant_id),
+        Err(Err(InvokeError::Contract(
+            TreasuryContractErrors::StreamNotFound as u32
+        )))
+    );
+}
+
+// Tests a stream proposal without a duration and a cancellation before a treasury is set.
+// Expects: InvalidConfig error (Error #15) and TreasuryNotSet error (Error #186).
+#[test]
+fn test_treasury_stream_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let start_time = e.ledger().timestamp() + 50;
+    assert_eq!(
+        client.try_create_stream_proposal(
+            &symbol_short!("GRANT1"),
+            &String::from_val(&e, &"Stream a grant"),
+            &start_time,
+            &(start_time + MIN_PROPOSAL_DURATION),
+            &TokenGatedVoteTreasuryAction {
+                recipient: Address::generate(&e),
+                amount: 1000,
+                duration: 0,
+            },
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_cancel_stream(&symbol_short!("GRANT1")),
+        Err(Ok(TokenGatedVoteContractErrors::TreasuryNotSet))
+    );
+}
+
+// Tests a treasury proposal that only executes while the token price is at least 100.
+// Expects: Execution fails with ExecutionConditionFailed (Error #153) without a price, below the
+// bound, or with a stale price, leaving the proposal executable, and succeeds once a fresh price
+// meets the bound.
+#[test]
+fn test_execution_conditions() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let member = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&member, &1000);
+    let client = create_vote_contract(&e, &admin, &token.address);
+    create_treasury(&e, &client, &token.address).deposit(&member, &600);
+
+    let oracle = MockPriceOracleClient::new(&e, &e.register(MockPriceOracle, ()));
+    let asset = TokenGatedVotePriceAsset::Stellar(token.address.clone());
+    let condition = TokenGatedVoteExecutionCondition {
+        oracle: oracle.address.clone(),
+        asset: asset.clone(),
+        comparison: TokenGatedVotePriceComparison::AtLeast,
+        price: 100,
+        max_age: 3600,
+    };
+
+    let proposal_id = symbol_short!("TREAS1");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_treasury_proposal(
+        &proposal_id,
+        &String::from_val(&e, &"Fund grants while the price holds"),
+        &start_time,
+        &end_time,
+        &recipient,
+        &300,
+    );
+    client.set_execution_conditions(&proposal_id, &vec![&e, condition.clone()]);
+    assert_eq!(
+        client.get_execution_conditions(&proposal_id),
+        vec![&e, condition]
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member, &proposal_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.queue_proposal(&proposal_id);
+    let executable_at = end_time + 1 + treasury::RAGE_QUIT_WINDOW + 1;
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = executable_at);
+
+    assert!(!client.check_execution_conditions(&proposal_id));
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ExecutionConditionFailed))
+    );
+    oracle.set_price(&asset, &90, &executable_at);
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ExecutionConditionFailed))
+    );
+    oracle.set_price(&asset, &120, &(executable_at - 3601));
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ExecutionConditionFailed))
+    );
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Queued
+    );
+
+    oracle.set_price(&asset, &120, &(executable_at - 3600));
+    assert!(client.check_execution_conditions(&proposal_id));
+    client.execute_proposal(&proposal_id);
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Executed
+    );
+}
+
+// Tests attaching execution conditions that are out of bounds or out of time.
+// Expects: NotTreasuryProposal error (Error #140) for plain proposals, InvalidAmount error
+// (Error #13) for non-positive bounds, InvalidConfig error (Error #15) for a zero maximum age or
+// too many conditions, and InvalidStateTransition error (Error #120) once voting has opened.
+#[test]
+fn test_set_execution_conditions_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let description = String::from_val(&e, &"Conditional spend");
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PLAIN"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.create_treasury_proposal(
+        &symbol_short!("TREAS1"),
+        &description,
+        &start_time,
+        &end_time,
+        &Address::generate(&e),
+        &300,
+    );
+    let condition = TokenGatedVoteExecutionCondition {
+        oracle: Address::generate(&e),
+        asset: TokenGatedVotePriceAsset::Other(symbol_short!("XLM")),
+        comparison: TokenGatedVotePriceComparison::AtMost,
+        price: 100,
+        max_age: 3600,
+    };
+
+    assert_eq!(
+        client.try_set_execution_conditions(&symbol_short!("PLAIN"), &vec![&e, condition.clone()]),
+        Err(Ok(TokenGatedVoteModuleErrors::NotTreasuryProposal.into()))
+    );
+    let mut zero_price = condition.clone();
+    zero_price.price = 0;
+    assert_eq!(
+        client.try_set_execution_conditions(&symbol_short!("TREAS1"), &vec![&e, zero_price]),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidAmount.into()))
+    );
+    let mut zero_age = condition.clone();
+    zero_age.max_age = 0;
+    assert_eq!(
+        client.try_set_execution_conditions(&symbol_short!("TREAS1"), &vec![&e, zero_age]),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+    );
+    let mut too_many = Vec::new(&e);
+    for _ in 0..=conditions::MAX_EXECUTION_CONDITIONS {
+        too_many.push_back(condition.clone());
+    }
+    assert_eq!(
+        client.try_set_execution_conditions(&symbol_short!("TREAS1"), &too_many),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig.into()))
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.try_set_execution_conditions(&symbol_short!("TREAS1"), &vec![&e, condition]),
+        Err(Ok(
+            TokenGatedVoteContractErrors::InvalidStateTransition.into()
+        ))
+    );
+}
+
+// Tests vote locking escrowing a voter's tokens across two concurrent proposals.
+// Expects: The first vote escrows the whole balance, escrowed tokens keep the voter eligible on
+// the second proposal, release before the end fails (Error #151), release afterwards returns the
+// tokens, and a second release fails (Error #150).
+#[test]
+fn test_vote_lock() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &1000);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_vote_lock(&true);
+    assert!(client.get_config().vote_lock);
+
+    let first_id = symbol_short!("PROP001");
+    let second_id = symbol_short!("PROP002");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [&first_id, &second_id] {
+        client.create_proposal(
+            &admin,
+            id,
+            &String::from_val(&e, &"Test proposal"),
+            &start_time,
+            &end_time,
+        );
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voter, &first_id, &symbol_short!("FOR"));
+    assert_eq!(token.balance(&voter), 0);
+    assert_eq!(client.get_locked(&voter, &first_id), 1000);
+    assert_eq!(client.get_locked_balance(&voter), 1000);
+    assert_eq!(client.get_total_locked(), 1000);
+
+    client.vote(&voter, &second_id, &symbol_short!("AGAINST"));
+    assert_eq!(client.get_proposal_details(&second_id).total_against, 1);
+    assert_eq!(client.get_locked(&voter, &second_id), 0);
+    assert_eq!(
+        client.try_release_locked(&voter, &first_id),
+        Err(Ok(TokenGatedVoteModuleErrors::TokensStillLocked.into()))
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(client.release_locked(&voter, &first_id), 1000);
+    assert_eq!(token.balance(&voter), 1000);
+    assert_eq!(client.get_locked_balance(&voter), 0);
+    assert_eq!(client.get_total_locked(), 0);
+    assert_eq!(
+        client.try_release_locked(&voter, &first_id),
+        Err(Ok(TokenGatedVoteModuleErrors::NoLockedTokens.into()))
+    );
+}
+
+// Tests a relayed signed vote while vote locking is enabled.
+// Expects: VoteLockRequiresAuth error (Error #152) and no tokens escrowed.
+#[test]
+fn test_vote_lock_signed_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_vote_lock(&true);
+    client.set_signing_key(
+        &user,
+        &BytesN::from_array(&e, &signer.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 100);
+
+    let expiration = ledger_time + 3600;
+    let signature = sign_vote(
+        &e,
+        &signer,
+        &TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: client.get_nonce(&user),
+            expiration,
+        },
+    );
+    assert_eq!(
+        client.try_vote_by_sig(
+            &user,
+            &proposal_id,
+            &symbol_short!("FOR"),
+            &expiration,
+            &signature,
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::VoteLockRequiresAuth))
+    );
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(client.get_nonce(&user), 0);
+}
+
+// Tests votes burning a configured amount of governance tokens.
+// Expects: Each vote burns 10 tokens, tracked per proposal and overall, holders below the burn
+// cannot vote (Error #6), and negative amounts are rejected (Error #13).
+#[test]
+fn test_vote_burn() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let voters = create_holders(&e, &token.address, &[100, 100, 5]);
+    let (first, second, poor) = (
+        voters.get_unchecked(0),
+        voters.get_unchecked(1),
+        voters.get_unchecked(2),
+    );
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    assert_eq!(
+        client.try_set_vote_burn(&-1),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidAmount))
+    );
+    client.set_vote_burn(&10);
+    assert_eq!(client.get_config().vote_burn, 10);
+
+    let first_id = symbol_short!("PROP001");
+    let second_id = symbol_short!("PROP002");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [&first_id, &second_id] {
+        client.create_proposal(
+            &admin,
+            id,
+            &String::from_val(&e, &"Test proposal"),
+            &start_time,
+            &end_time,
+        );
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&first, &first_id, &symbol_short!("FOR"));
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            events::VOTE,
+            events::CAST,
+            events::EVENT_VERSION,
+            first_id.clone(),
+        ),
+        events::VoteCastEvent {
+            id: first_id.clone(),
+            voter: first.clone(),
+            choice: symbol_short!("FOR"),
+            weight: 1,
+        },
+    );
+    client.vote(&second, &first_id, &symbol_short!("AGAINST"));
+    client.vote(&first, &second_id, &symbol_short!("FOR"));
+    assert_eq!(token.balance(&first), 80);
+    assert_eq!(token.balance(&second), 90);
+
+    assert_eq!(
+        client.try_vote(&poor, &first_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote))
+    );
+    assert_eq!(token.balance(&poor), 5);
+
+    assert_eq!(client.get_proposal_details(&first_id).burned, 20);
+    assert_eq!(client.get_proposal_stats(&first_id).burned, 20);
+    assert_eq!(client.get_proposal_stats(&second_id).burned, 10);
+    assert_eq!(client.get_governance_stats().total_burned, 30);
+
+    client.set_vote_burn(&0);
+    client.vote(&second, &second_id, &symbol_short!("AGAINST"));
+    assert_eq!(token.balance(&second), 90);
+    assert_eq!(client.get_governance_stats().total_burned, 30);
+}
+
+// Tests a relayed signed vote while vote burning is enabled.
+// Expects: VoteBurnRequiresAuth error (Error #169) and no tokens burned.
+#[test]
+fn test_vote_burn_signed_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_vote_burn(&10);
+    client.set_signing_key(
+        &user,
+        &BytesN::from_array(&e, &signer.verifying_key().to_bytes()),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 100);
+
+    let expiration = ledger_time + 3600;
+    let signature = sign_vote(
+        &e,
+        &signer,
+        &TokenGatedVoteSignedPayload {
+            contract: client.address.clone(),
+            user: user.clone(),
+            id: proposal_id.clone(),
+            choice: symbol_short!("FOR"),
+            nonce: client.get_nonce(&user),
+            expiration,
+        },
+    );
+    assert_eq!(
+        client.try_vote_by_sig(
+            &user,
+            &proposal_id,
+            &symbol_short!("FOR"),
+            &expiration,
+            &signature,
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::VoteBurnRequiresAuth))
+    );
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(client.get_proposal_details(&proposal_id).burned, 0);
+}
+
+// Tests rage-quit on a proposal without a treasury payout.
+// Expects: NotTreasuryProposal error (Error #140).
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn test_rage_quit_not_treasury_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let (client, _, proposal_id) = setup_succeeded_proposal(&e, &admin);
+
+    client.rage_quit(&user, &proposal_id);
+}
+
+// Deploys a gated contract open to proposals, whose token is minted to a non-admin proposer, with
+// an optional fee
+fn setup_proposal_fee<'a>(
+    e: &Env,
+    fee: Option<TokenGatedVoteProposalFee>,
+) -> (
+    TokenGatedVoteContractClient<'a>,
+    TokenClient<'a>,
+    Address,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let proposer = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    stellar_asset.mint(&admin, &1000);
+    stellar_asset.mint(&proposer, &1000);
+
+    let client = create_vote_contract(e, &admin, &token.address);
+    create_treasury(e, &client, &token.address);
+    client.set_open_proposing(&true);
+    if let Some(fee) = fee {
+        client.set_proposal_fee(&fee.amount, &fee.sink);
+    }
+    (client, token, admin, proposer)
+}
+
+// Creates proposal PROP001 with a valid time window for the given proposer
+fn create_fee_proposal(e: &Env, client: &TokenGatedVoteContractClient, proposer: &Address) {
+    let description = String::from_val(e, &"Community proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        proposer,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+}
+
+// Tests a non-admin proposal with a fee burned from the proposer.
+// Expects: The proposer's balance drops by the fee, the contract holds nothing, and the
+// proposal records the proposer.
+#[test]
+fn test_proposal_fee_burn() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Burn,
+    };
+    let (client, token, _, proposer) = setup_proposal_fee(&e, Some(fee));
+
+    create_fee_proposal(&e, &client, &proposer);
+
+    assert_eq!(client.get_proposal_fee(), Some(fee));
+    assert_eq!(token.balance(&proposer), 900);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .proposer,
+        proposer
+    );
+}
+
+// Tests a non-admin proposal with a fee sent to the treasury.
+// Expects: The fee is transferred to the treasury contract and added to its balance without
+// shares.
+#[test]
+fn test_proposal_fee_treasury() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Treasury,
+    };
+    let (client, token, _, proposer) = setup_proposal_fee(&e, Some(fee));
+
+    create_fee_proposal(&e, &client, &proposer);
+
+    let treasury = TreasuryContractClient::new(&e, &client.get_treasury().unwrap());
+    assert_eq!(token.balance(&proposer), 900);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&treasury.address), 100);
+    assert_eq!(
+        treasury.get_treasury(),
+        TreasuryTotals {
+            balance: 100,
+            total_shares: 0,
+        }
+    );
+}
+
+// Tests a non-admin proposal with a fee sent to the community pool.
+// Expects: The fee is transferred to the treasury contract, added to its community pool rather
+// than the share-backed balance, and recorded in the inflow history.
+#[test]
+fn test_proposal_fee_community_pool() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::CommunityPool,
+    };
+    let (client, token, _, proposer) = setup_proposal_fee(&e, Some(fee));
+
+    create_fee_proposal(&e, &client, &proposer);
+
+    let treasury = TreasuryContractClient::new(&e, &client.get_treasury().unwrap());
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(token.balance(&treasury.address), 100);
+    assert_eq!(treasury.get_treasury().balance, 0);
+    assert_eq!(
+        treasury.get_community_pool(),
+        TreasuryCommunityPool {
+            balance: 100,
+            total_received: 100,
+            total_spent: 0,
+        }
+    );
+    assert_eq!(
+        treasury.get_pool_inflows(),
+        vec![
+            &e,
+            TreasuryPoolInflow {
+                source: TreasuryPoolSource::ProposalFee,
+                id: symbol_short!("PROP001"),
+                amount: 100,
+                received_at: e.ledger().timestamp(),
+            }
+        ]
+    );
+}
+
+// Tests the cooldown and per-period cap on non-admin proposers.
+// Expects: Proposals inside the cooldown or beyond the cap fail (Error #174) until the window
+// rolls on, the admin is exempt, and caps above 100 fail (Error #15).
+#[test]
+fn test_proposer_rate_limit() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Burn,
+    };
+    let (client, _, admin, proposer) = setup_proposal_fee(&e, Some(fee));
+    client.set_proposer_limits(&100, &2, &1_000);
+    assert_eq!(
+        client.try_set_proposer_limits(&100, &101, &1_000),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+
+    let description = String::from_val(&e, &"Community proposal");
+    let propose = |proposer: &Address, id: Symbol| {
+        let start_time = e.ledger().timestamp() + 50;
+        let end_time = start_time + MIN_PROPOSAL_DURATION;
+        client.try_create_proposal(proposer, &id, &description, &start_time, &end_time)
+    };
+    let first_at = e.ledger().timestamp();
+    assert!(propose(&proposer, symbol_short!("PROP001")).is_ok());
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_at + 50);
+    assert_eq!(
+        propose(&proposer, symbol_short!("PROP002")),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalRateLimited))
+    );
+    assert!(propose(&admin, symbol_short!("ADMIN001")).is_ok());
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_at + 100);
+    assert!(propose(&proposer, symbol_short!("PROP002")).is_ok());
+    assert_eq!(
+        client.get_proposer_activity(&proposer),
+        TokenGatedVoteProposerActivity {
+            last_proposed_at: first_at + 100,
+            proposals_in_period: 2,
+        }
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_at + 200);
+    assert_eq!(
+        propose(&proposer, symbol_short!("PROP003")),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalRateLimited))
+    );
+
+    // The first proposal leaves the rolling period, freeing a slot under the cap
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_at + 1_000);
+    assert!(propose(&proposer, symbol_short!("PROP003")).is_ok());
+    assert_eq!(
+        client.get_proposer_activity(&proposer).proposals_in_period,
+        2
+    );
+}
+
+// Tests the cap on concurrently open proposals.
+// Expects: Creation fails at the cap (Error #175) until a proposal is cancelled or finalized.
+#[test]
+fn test_max_active_proposals() {
+    let e = setup_test_env();
+    let (client, admin, _) = setup_config(&e);
+    client.set_max_active_proposals(&2);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let propose =
+        |id: Symbol| client.try_create_proposal(&admin, &id, &description, &start_time, &end_time);
+    assert!(propose(symbol_short!("PROP001")).is_ok());
+    assert!(propose(symbol_short!("PROP002")).is_ok());
+    assert_eq!(client.get_active_proposal_count(), 2);
+    assert_eq!(
+        propose(symbol_short!("PROP003")),
+        Err(Ok(TokenGatedVoteContractErrors::TooManyActiveProposals))
+    );
+
+    client.cancel_proposal(&symbol_short!("PROP001"));
+    assert_eq!(client.get_active_proposal_count(), 1);
+    assert!(propose(symbol_short!("PROP003")).is_ok());
+    assert_eq!(client.get_governance_stats().active_proposals, 2);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&symbol_short!("PROP002"));
+    assert_eq!(client.get_active_proposal_count(), 1);
+}
+
+// Creates a community pool spend proposal funded by a 100-token pool fee, passes it with the
+// fee payer's vote, and queues it for execution
+fn setup_pool_spend_proposal<'a>(
+    e: &Env,
+    amount: i128,
+) -> (
+    TokenGatedVoteContractClient<'a>,
+    TokenClient<'a>,
+    Symbol,
+    Address,
+) {
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::CommunityPool,
+    };
+    let (client, token, _, proposer) = setup_proposal_fee(e, Some(fee));
+    create_fee_proposal(e, &client, &proposer);
+
+    let proposal_id = symbol_short!("POOL1");
+    let recipient = Address::generate(e);
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    let spend = TokenGatedVotePoolSpend {
+        recipient: recipient.clone(),
+        amount,
+    };
+    client.create_pool_spend_proposal(
+        &proposal_id,
+        &String::from_val(e, &"Fund a community meetup"),
+        &start_time,
+        &end_time,
+        &spend,
+    );
+    assert_eq!(client.get_pool_spend(&proposal_id), Some(spend));
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&proposer, &proposal_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.queue_proposal(&proposal_id);
+    (client, token, proposal_id, recipient)
+}
+
+// Tests executing a passed community pool spend proposal.
+// Expects: The treasury contract pays the recipient from its pool and records the spend, while
+// its share-backed balance and spending cap are untouched.
+#[test]
+fn test_pool_spend_proposal() {
+    let e = setup_test_env();
+    let (client, token, proposal_id, recipient) = setup_pool_spend_proposal(&e, 60);
+
+    client.execute_proposal(&proposal_id);
+
+    let treasury = TreasuryContractClient::new(&e, &client.get_treasury().unwrap());
+    assert_eq!(token.balance(&recipient), 60);
+    assert_eq!(token.balance(&treasury.address), 40);
+    assert_eq!(
+        treasury.get_community_pool(),
+        TreasuryCommunityPool {
+            balance: 40,
+            total_received: 100,
+            total_spent: 60,
+        }
+    );
+    assert_eq!(treasury.get_treasury().balance, 0);
+    assert_eq!(treasury.get_period_spending(), 0);
+}
+
+// Tests community pool spends that are empty or exceed the pool.
+// Expects: InvalidAmount error (Error #13) at creation and InsufficientCommunityPool error from
+// the treasury contract (Error #2508) at execution.
+#[test]
+fn test_pool_spend_insufficient_funds() {
+    let e = setup_test_env();
+    let (client, _, proposal_id, recipient) = setup_pool_spend_proposal(&e, 500);
+
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Err(InvokeError::Contract(
+            TreasuryContractErrors::InsufficientCommunityPool as u32
+        )))
+    );
+    let treasury = TreasuryContractClient::new(&e, &client.get_treasury().unwrap());
+    assert_eq!(treasury.get_community_pool().balance, 100);
+
+    let start_time = e.ledger().timestamp() + 50;
+    assert_eq!(
+        client.try_create_pool_spend_proposal(
+            &symbol_short!("POOL2"),
+            &String::from_val(&e, &"Empty spend"),
+            &start_time,
+            &(start_time + MIN_PROPOSAL_DURATION),
+            &TokenGatedVotePoolSpend {
+                recipient,
+                amount: 0,
+            },
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidAmount))
+    );
+}
+
+// Tests a proposal created by the admin while a fee is configured.
+// Expects: The admin is exempt and keeps their full balance.
+#[test]
+fn test_proposal_fee_admin_exempt() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Burn,
+    };
+    let (client, token, admin, _) = setup_proposal_fee(&e, Some(fee));
+
+    create_fee_proposal(&e, &client, &admin);
+
+    assert_eq!(token.balance(&admin), 1000);
+}
+
+// Tests a non-admin proposal while open proposing is off, with and without a fee configured.
+// Expects: ProposalsRestricted error (Error #17) either way, and no fee once it is cleared.
+#[test]
+fn test_proposal_fee_not_configured() {
+    let e = setup_test_env();
+    let (client, _, _, proposer) = setup_proposal_fee(&e, None);
+    client.set_open_proposing(&false);
+    let description = String::from_val(&e, &"Community proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+
+    for fee in [0, 100] {
+        client.set_proposal_fee(&fee, &TokenGatedVoteFeeSink::Burn);
+        let result = client.try_create_proposal(
+            &proposer,
+            &symbol_short!("PROP001"),
+            &description,
+            &start_time,
+            &end_time,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(TokenGatedVoteContractErrors::ProposalsRestricted))
+        );
+    }
+
+    client.set_proposal_fee(&0, &TokenGatedVoteFeeSink::Burn);
+    assert_eq!(client.get_proposal_fee(), None);
+}
+
+// Tests open proposing against a proposal threshold of voting power.
+// Expects: InsufficientProposalPower error (Error #144) below it, and the power recorded at it.
+#[test]
+fn test_proposal_threshold() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Burn,
+    };
+    let (client, _, admin, proposer) = setup_proposal_fee(&e, Some(fee));
+    let description = String::from_val(&e, &"Community proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+
+    client.set_proposal_threshold(&1001);
+    assert_eq!(client.get_config().proposal_threshold, 1001);
+    assert_eq!(
+        client.try_create_proposal(
+            &proposer,
+            &symbol_short!("PROP001"),
+            &description,
+            &start_time,
+            &end_time,
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::InsufficientProposalPower))
+    );
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .proposer_power,
+        0
+    );
+
+    client.set_proposal_threshold(&1000);
+    create_fee_proposal(&e, &client, &proposer);
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .proposer_power,
+        1000
+    );
+}
+
+// Tests setting a negative proposal threshold.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_set_proposal_threshold_negative() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_proposal_fee(&e, None);
+    client.set_proposal_threshold(&-1);
+}
+
+// Tests open proposing enabled with no proposal fee and no sponsors required.
+// Expects: A non-admin proposer creates a Pending proposal without being charged.
+#[test]
+fn test_open_proposing() {
+    let e = setup_test_env();
+    let (client, token, _, proposer) = setup_proposal_fee(&e, None);
+
+    let config = client.get_config();
+    assert!(config.open_proposing);
+    assert_eq!(config.proposal_fee, 0);
+    assert_eq!(config.sponsors_required, 0);
+    create_fee_proposal(&e, &client, &proposer);
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .status,
+        TokenGatedVoteProposalStatus::Pending
+    );
+    assert_eq!(token.balance(&proposer), 1000);
+}
+
+// Tests the sponsorship phase of a fee-free open proposal needing two endorsements.
+// Expects: The draft becomes Pending on the second distinct sponsor, withdrawals and repeats
+// are handled, and endorsements close once the draft is promoted.
+#[test]
+fn test_sponsorship() {
+    let e = setup_test_env();
+    let (client, token, admin, proposer) = setup_proposal_fee(&e, None);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let sponsor1 = Address::generate(&e);
+    let sponsor2 = Address::generate(&e);
+    let sponsor3 = Address::generate(&e);
+    for sponsor in [&sponsor1, &sponsor2, &sponsor3] {
+        stellar_asset.mint(sponsor, &100);
+    }
+    client.set_sponsors_required(&2);
+
+    create_fee_proposal(&e, &client, &proposer);
+    let proposal_id = symbol_short!("PROP001");
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Draft
+    );
+    assert_eq!(token.balance(&proposer), 1000);
+
+    client.sponsor(&sponsor1, &proposal_id);
+    assert_eq!(
+        client.try_sponsor(&sponsor1, &proposal_id),
+        Err(Ok(TokenGatedVoteModuleErrors::AlreadySponsored.into()))
+    );
+    client.withdraw_sponsorship(&sponsor1, &proposal_id);
+    assert_eq!(client.get_sponsors(&proposal_id).len(), 0);
+    assert_eq!(
+        client.try_withdraw_sponsorship(&sponsor1, &proposal_id),
+        Err(Ok(TokenGatedVoteModuleErrors::SponsorNotFound.into()))
+    );
+    assert_eq!(
+        client.try_sponsor(&Address::generate(&e), &proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote.into()))
+    );
+
+    client.sponsor(&sponsor1, &proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Draft
+    );
+    client.sponsor(&sponsor2, &proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Pending
+    );
+    assert_eq!(
+        client.get_sponsors(&proposal_id),
+        vec![&e, sponsor1.clone(), sponsor2]
+    );
+    assert_eq!(
+        client.try_sponsor(&sponsor3, &proposal_id),
+        Err(Ok(TokenGatedVoteModuleErrors::SponsorshipClosed.into()))
+    );
+    assert_eq!(
+        client.try_withdraw_sponsorship(&sponsor1, &proposal_id),
+        Err(Ok(TokenGatedVoteModuleErrors::SponsorshipClosed.into()))
+    );
+
+    let admin_proposal = symbol_short!("PROP002");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &admin_proposal,
+        &String::from_val(&e, &"Admin proposal"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    assert_eq!(
+        client.get_proposal_details(&admin_proposal).status,
+        TokenGatedVoteProposalStatus::Pending
+    );
+}
+
+// Tests a draft whose voting window opens before it gathers enough sponsors.
+// Expects: Votes fail with VotingNotActive (Error #7), sponsorship is closed (Error #145), and
+// the admin can still cancel the draft.
+#[test]
+fn test_sponsorship_unsponsored_draft() {
+    let e = setup_test_env();
+    let (client, _, _, proposer) = setup_proposal_fee(&e, None);
+    client.set_sponsors_required(&1);
+
+    create_fee_proposal(&e, &client, &proposer);
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = client.get_proposal_details(&proposal_id).start_time;
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+
+    assert_eq!(
+        client.try_vote(&proposer, &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::VotingNotActive))
+    );
+    assert_eq!(
+        client.try_sponsor(&proposer, &proposal_id),
+        Err(Ok(TokenGatedVoteModuleErrors::SponsorshipClosed.into()))
+    );
+    client.cancel_proposal(&proposal_id);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).status,
+        TokenGatedVoteProposalStatus::Cancelled
+    );
+}
+
+// Tests setting the sponsorship requirement above the supported maximum.
+// Expects: InvalidConfig error (Error #15).
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_set_sponsors_required_invalid() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_proposal_fee(&e, None);
+    client.set_sponsors_required(&21);
+}
+
+// Tests setting a negative proposal fee.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_set_proposal_fee_negative() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_proposal_fee(&e, None);
+
+    client.set_proposal_fee(&-1, &TokenGatedVoteFeeSink::Treasury);
+}
+
+// Tests "budget" and "signal" categories with different rules inside one contract.
+// Expects: Proposals snapshot their category's quorum and threshold and follow its duration
+// bounds (Error #12), admin-only categories reject holders even without a fee while open
+// proposing is on and holder categories reject them while it is off (Error #17), unknown
+// categories fail (Error #158), and plain proposals use the GENERAL category backed by the
+// configuration.
+#[test]
+fn test_proposal_categories() {
+    let e = setup_test_env();
+    let fee = TokenGatedVoteProposalFee {
+        amount: 100,
+        sink: TokenGatedVoteFeeSink::Burn,
+    };
+    let (client, _, admin, proposer) = setup_proposal_fee(&e, Some(fee));
+    let budget = TokenGatedVoteCategoryRules {
+        min_duration: 604_800,
+        max_duration: 1_209_600,
+        quorum: 2,
+        min_voters: 0,
+        threshold: 6_667,
+        proposers: TokenGatedVoteProposerRule::Admin,
+    };
+    let signal = TokenGatedVoteCategoryRules {
+        min_duration: 86_400,
+        max_duration: 432_000,
+        quorum: 0,
+        min_voters: 0,
+        threshold: 5_000,
+        proposers: TokenGatedVoteProposerRule::Holders,
+    };
+    client.set_category_rules(&symbol_short!("BUDGET"), &budget);
+    client.set_category_rules(&symbol_short!("SIGNAL"), &signal);
+    assert_eq!(
+        client.get_categories(),
+        vec![&e, symbol_short!("BUDGET"), symbol_short!("SIGNAL")]
+    );
+    assert_eq!(client.get_category_rules(&symbol_short!("BUDGET")), budget);
+
+    let description = String::from_val(&e, &"Categorized proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    assert_eq!(
+        client.try_create_categorized_proposal(
+            &admin,
+            &symbol_short!("BUDGET1"),
+            &symbol_short!("BUDGET"),
+            &description,
+            &start_time,
+            &(start_time + MIN_PROPOSAL_DURATION),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::DurationTooShort))
+    );
+    client.create_categorized_proposal(
+        &admin,
+        &symbol_short!("BUDGET1"),
+        &symbol_short!("BUDGET"),
+        &description,
+        &start_time,
+        &(start_time + 604_800),
+    );
+    let budget_proposal = client.get_proposal_details(&symbol_short!("BUDGET1"));
+    assert_eq!(budget_proposal.category, symbol_short!("BUDGET"));
+    assert_eq!(budget_proposal.quorum, 2);
+    assert_eq!(budget_proposal.threshold, 6_667);
+
+    assert_eq!(
+        client.try_create_categorized_proposal(
+            &proposer,
+            &symbol_short!("BUDGET2"),
+            &symbol_short!("BUDGET"),
+            &description,
+            &start_time,
+            &(start_time + 604_800),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalsRestricted))
+    );
+    client.create_categorized_proposal(
+        &proposer,
+        &symbol_short!("SIGNAL1"),
+        &symbol_short!("SIGNAL"),
+        &description,
+        &start_time,
+        &(start_time + 86_400),
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("SIGNAL1"))
+            .category,
+        symbol_short!("SIGNAL")
+    );
+
+    client.set_proposal_fee(&0, &TokenGatedVoteFeeSink::Burn);
+    for (open_proposing, id, category, duration) in [
+        (true, "BUDGET2", "BUDGET", 604_800),
+        (false, "SIGNAL2", "SIGNAL", 86_400),
+    ] {
+        client.set_open_proposing(&open_proposing);
+        assert_eq!(
+            client.try_create_categorized_proposal(
+                &proposer,
+                &Symbol::new(&e, id),
+                &Symbol::new(&e, category),
+                &description,
+                &start_time,
+                &(start_time + duration),
+            ),
+            Err(Ok(TokenGatedVoteContractErrors::ProposalsRestricted))
+        );
+    }
+    assert_eq!(
+        client.try_create_categorized_proposal(
+            &admin,
+            &symbol_short!("OTHER1"),
+            &symbol_short!("OTHER"),
+            &description,
+            &start_time,
+            &(start_time + MIN_PROPOSAL_DURATION),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::CategoryNotFound))
+    );
+
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    let config = client.get_config();
+    let general = client.get_proposal_details(&symbol_short!("PROP001"));
+    assert_eq!(general.category, symbol_short!("GENERAL"));
+    assert_eq!(general.threshold, config.threshold);
+    assert_eq!(
+        client
+            .get_category_rules(&symbol_short!("GENERAL"))
+            .max_duration,
+        config.max_duration
+    );
+
+    client.remove_category(&symbol_short!("SIGNAL"));
+    assert_eq!(client.get_categories(), vec![&e, symbol_short!("BUDGET")]);
+    assert_eq!(
+        client.try_remove_category(&symbol_short!("SIGNAL")),
+        Err(Ok(TokenGatedVoteContractErrors::CategoryNotFound))
+    );
+}
+
+// Tests category rules outside the configuration bounds and a full rules table.
+// Expects: InvalidConfig error (Error #15) for inverted durations, low thresholds, or too many
+// categories, and InvalidQuorum error (Error #124) for a negative quorum.
+#[test]
+fn test_set_category_rules_invalid() {
+    let e = setup_test_env();
+    let (client, _, _, _) = setup_proposal_fee(&e, None);
+    let rules = TokenGatedVoteCategoryRules {
+        min_duration: 86_400,
+        max_duration: 432_000,
+        quorum: 0,
+        min_voters: 0,
+        threshold: 5_000,
+        proposers: TokenGatedVoteProposerRule::Holders,
+    };
+
+    let mut inverted = rules.clone();
+    inverted.min_duration = 432_001;
+    let mut low_threshold = rules.clone();
+    low_threshold.threshold = 4_999;
+    for invalid in [inverted, low_threshold] {
+        assert_eq!(
+            client.try_set_category_rules(&symbol_short!("SIGNAL"), &invalid),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+        );
+    }
+    let mut negative_quorum = rules.clone();
+    negative_quorum.quorum = -1;
+    assert_eq!(
+        client.try_set_category_rules(&symbol_short!("SIGNAL"), &negative_quorum),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidQuorum))
+    );
+
+    for index in 0..categories::MAX_CATEGORIES {
+        let category = Symbol::new(&e, &std::format!("CAT{}", index));
+        client.set_category_rules(&category, &rules);
+    }
+    assert_eq!(
+        client.try_set_category_rules(&symbol_short!("SIGNAL"), &rules),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+}
+
+// Deploys a gated contract with three voters holding 100 tokens each
+fn setup_config<'a>(e: &Env) -> (TokenGatedVoteContractClient<'a>, Address, [Address; 3]) {
+    let admin = Address::generate(e);
+    let voters = [
+        Address::generate(e),
+        Address::generate(e),
+        Address::generate(e),
+    ];
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    for voter in voters.iter() {
+        stellar_asset.mint(voter, &100);
+    }
+    let client = create_vote_contract(e, &admin, &token.address);
+    (client, admin, voters)
+}
+
+// Tests the configuration of a freshly deployed contract.
+// Expects: Built-in defaults at version 0.
+#[test]
+fn test_get_config_defaults() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_config(&e);
+
+    assert_eq!(
+        client.get_config(),
+        TokenGatedVoteGovernanceConfig {
+            version: 0,
+            min_duration: MIN_PROPOSAL_DURATION,
+            max_duration: MAX_PROPOSAL_DURATION,
+            quorum: 0,
+            threshold: 5_000,
+            dispute_bond: 0,
+            proposal_fee: 0,
+            fee_sink: TokenGatedVoteFeeSink::Burn,
+            min_balance: 1,
+            proposal_threshold: 0,
+            open_proposing: false,
+            sponsors_required: 0,
+            spending_cap: 0,
+            spending_period: 2_592_000,
+            vote_lock: false,
+            vote_burn: 0,
+            sequential_ids: false,
+            max_description_length: 1_000,
+            timing_mode: TokenGatedVoteTimingMode::Timestamp,
+            grace_period: 0,
+            proposer_cooldown: 0,
+            proposals_per_period: 0,
+            proposal_period: 2_592_000,
+            max_active_proposals: 0,
+            paused: false,
+        }
+    );
+}
+
+// Tests replacing the configuration and updating single fields through legacy setters.
+// Expects: Every update bumps the version, and new proposals snapshot quorum and threshold.
+#[test]
+fn test_set_config() {
+    let e = setup_test_env();
+    let (client, admin, _) = setup_config(&e);
+
+    let mut config = client.get_config();
+    config.quorum = 3;
+    config.threshold = 6_000;
+    config.min_duration = 86_400;
+    let updated = client.set_config(&config);
+    assert_eq!(updated.version, 1);
+    assert_eq!(client.get_config(), updated);
+
+    client.set_dispute_bond(&50);
+    assert_eq!(client.get_config().version, 2);
+    assert_eq!(client.get_dispute_bond(), 50);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Short proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + 86_400;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    let proposal = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal.quorum, 3);
+    assert_eq!(proposal.threshold, 6_000);
+}
+
+// Tests configurations with an out-of-range threshold or inverted durations.
+// Expects: InvalidConfig error (Error #15) for both.
+#[test]
+fn test_set_config_invalid() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_config(&e);
+
+    let mut config = client.get_config();
+    config.threshold = 10_000;
+    assert_eq!(
+        client.try_set_config(&config),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+
+    let mut config = client.get_config();
+    config.min_duration = config.max_duration + 1;
+    assert_eq!(
+        client.try_set_config(&config),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    assert_eq!(client.get_config().version, 0);
+}
+
+// Tests a supermajority threshold against a two-to-one FOR result.
+// Expects: 6,666 bps passes the proposal, while 6,667 bps defeats it.
+#[test]
+fn test_config_threshold() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+
+    let description = String::from_val(&e, &"Supermajority proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for (id, threshold) in [
+        (symbol_short!("PROP001"), 6_666),
+        (symbol_short!("PROP002"), 6_667),
+    ] {
+        let mut config = client.get_config();
+        config.threshold = threshold;
+        client.set_config(&config);
+        client.create_proposal(&admin, &id, &description, &start_time, &end_time);
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.vote(&voters[0], &id, &symbol_short!("FOR"));
+        client.vote(&voters[1], &id, &symbol_short!("FOR"));
+        client.vote(&voters[2], &id, &symbol_short!("AGAINST"));
+    }
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&symbol_short!("PROP001")),
+        TokenGatedVoteProposalStatus::Succeeded
+    );
+    assert_eq!(
+        client.finalize_proposal(&symbol_short!("PROP002")),
+        TokenGatedVoteProposalStatus::Defeated
+    );
+}
+
+// Tests voting with a balance below the configured minimum.
+// Expects: UserCannotVote error (Error #6).
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_config_min_balance() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+
+    let mut config = client.get_config();
+    config.min_balance = 101;
+    client.set_config(&config);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+}
+
+// Tests proposal creation and voting while the contract is paused.
+// Expects: ContractPaused error (Error #143) for both until the pause is lifted.
+#[test]
+fn test_config_paused() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    let mut config = client.get_config();
+    config.paused = true;
+    client.set_config(&config);
+
+    assert_eq!(
+        client.try_create_proposal(
+            &admin,
+            &symbol_short!("PROP002"),
+            &description,
+            &start_time,
+            &end_time
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::ContractPaused))
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.try_vote(&voters[0], &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::ContractPaused))
+    );
+
+    config.paused = false;
+    client.set_config(&config);
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+}
+
+// Creates a config change proposal by the admin, passes it with two FOR votes, and finalizes it
+fn pass_config_proposal(
+    e: &Env,
+    client: &TokenGatedVoteContractClient,
+    admin: &Address,
+    voters: &[Address; 3],
+    id: &Symbol,
+    changes: &Vec<TokenGatedVoteConfigChange>,
+) {
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_config_proposal(
+        admin,
+        id,
+        &String::from_val(e, &"Amend the governance rules"),
+        &start_time,
+        &end_time,
+        changes,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[0], id, &symbol_short!("FOR"));
+    client.vote(&voters[1], id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(id);
+}
+
+// Tests a passed config change proposal amending the governance rules.
+// Expects: The configuration is untouched until execution, which applies every change in one
+// version bump while leaving other fields as they were.
+#[test]
+fn test_config_change_proposal() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    let proposal_id = symbol_short!("CONFIG1");
+    let changes = vec![
+        &e,
+        TokenGatedVoteConfigChange::Quorum(2),
+        TokenGatedVoteConfigChange::Threshold(6_000),
+        TokenGatedVoteConfigChange::FeeSink(TokenGatedVoteFeeSink::CommunityPool),
+    ];
+    pass_config_proposal(&e, &client, &admin, &voters, &proposal_id, &changes);
+    assert_eq!(client.get_config_change(&proposal_id), Some(changes));
+    assert_eq!(client.get_config().version, 0);
+
+    client.queue_proposal(&proposal_id);
+    client.execute_proposal(&proposal_id);
+
+    let config = client.get_config();
+    assert_eq!(config.version, 1);
+    assert_eq!(config.quorum, 2);
+    assert_eq!(config.threshold, 6_000);
+    assert_eq!(config.fee_sink, TokenGatedVoteFeeSink::CommunityPool);
+    assert_eq!(config.min_duration, MIN_PROPOSAL_DURATION);
+    assert_eq!(client.get_config_change(&symbol_short!("PROP001")), None);
+}
+
+// Tests config change proposals that are empty or would leave the configuration invalid, and
+// one invalidated by an admin update made while it was pending.
+// Expects: InvalidConfig (Error #15) or InvalidAmount (Error #13) at creation, and
+// InvalidConfig at execution with the configuration unchanged.
+#[test]
+fn test_config_change_invalid() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    let description = String::from_val(&e, &"Amend the governance rules");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for (changes, error) in [
+        (Vec::new(&e), TokenGatedVoteContractErrors::InvalidConfig),
+        (
+            vec![&e, TokenGatedVoteConfigChange::Threshold(10_000)],
+            TokenGatedVoteContractErrors::InvalidConfig,
+        ),
+        (
+            vec![&e, TokenGatedVoteConfigChange::ProposalFee(-1)],
+            TokenGatedVoteContractErrors::InvalidAmount,
+        ),
+    ] {
+        assert_eq!(
+            client.try_create_config_proposal(
+                &admin,
+                &symbol_short!("CONFIG1"),
+                &description,
+                &start_time,
+                &end_time,
+                &changes,
+            ),
+            Err(Ok(error))
+        );
+    }
+
+    let proposal_id = symbol_short!("CONFIG2");
+    let changes = vec![&e, TokenGatedVoteConfigChange::MinDuration(1_000_000)];
+    pass_config_proposal(&e, &client, &admin, &voters, &proposal_id, &changes);
+    let mut config = client.get_config();
+    config.max_duration = 900_000;
+    let config = client.set_config(&config);
+    client.queue_proposal(&proposal_id);
+
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    assert_eq!(client.get_config(), config);
+}
+
+// Creates a guardian proposal, passes it with a single FOR vote, and executes it
+fn pass_guardian_proposal(
+    e: &Env,
+    client: &TokenGatedVoteContractClient,
+    voter: &Address,
+    id: &Symbol,
+    renewal: &TokenGatedVoteGuardianRenewal,
+) {
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_guardian_proposal(
+        id,
+        &String::from_val(e, &"Appoint the guardian"),
+        &start_time,
+        &end_time,
+        renewal,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(voter, id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(id);
+    client.queue_proposal(id);
+    client.execute_proposal(id);
+}
+
+// Tests a guardian appointed by a passed proposal, its sunset, and its renewal.
+// Expects: The guardian can pause, resume, and veto until its term ends, then fails with
+// GuardianInactive (Error #154) until a second proposal renews it.
+#[test]
+fn test_guardian_powers_expire() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    let guardian = Address::generate(&e);
+    let renewal = TokenGatedVoteGuardianRenewal {
+        guardian: guardian.clone(),
+        term: 2_592_000,
+    };
+
+    pass_guardian_proposal(&e, &client, &voters[0], &symbol_short!("GUARD1"), &renewal);
+    let appointed_at = e.ledger().timestamp();
+    assert_eq!(
+        client.get_guardian(),
+        Some(TokenGatedVoteGuardian {
+            guardian: guardian.clone(),
+            expires_at: appointed_at + 2_592_000,
+        })
+    );
+    assert_eq!(
+        client.get_guardian_renewal(&symbol_short!("GUARD1")),
+        Some(renewal.clone())
+    );
+    assert!(client.is_guardian_active());
+
+    client.guardian_set_paused(&true);
+    assert!(client.get_config().paused);
+    client.guardian_set_paused(&false);
+    assert!(!client.get_config().paused);
+
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &start_time,
+        &end_time,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[1], &proposal_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+    client.guardian_veto(&proposal_id);
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::Vetoed
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = appointed_at + 2_592_000);
+    assert!(!client.is_guardian_active());
+    assert_eq!(
+        client.try_guardian_set_paused(&true),
+        Err(Ok(TokenGatedVoteContractErrors::GuardianInactive))
+    );
+
+    pass_guardian_proposal(&e, &client, &voters[2], &symbol_short!("GUARD2"), &renewal);
+    assert!(client.is_guardian_active());
+    client.guardian_set_paused(&true);
+    assert!(client.get_config().paused);
+}
+
+// Tests an emergency shutdown with open proposals, escrowed votes, an open dispute, and treasury
+// deposits.
+// Expects: Open proposals are cancelled, the dispute bond is refunded, escrowed tokens can be
+// withdrawn, and the treasury contract is shut down for redemptions (NoTreasuryShares,
+// Error #2503, without shares; TreasuryShutDown, Error #2504, for deposits), while every other
+// write fails with ContractShutDown (Error #155).
+#[test]
+fn test_emergency_shutdown() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let voters = [
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    ];
+    let token = create_token_contract(&e, &admin);
+    for voter in voters.iter() {
+        StellarAssetClient::new(&e, &token.address).mint(voter, &100);
+    }
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let renewal = TokenGatedVoteGuardianRenewal {
+        guardian,
+        term: 2_592_000,
+    };
+    pass_guardian_proposal(&e, &client, &voters[0], &symbol_short!("GUARD1"), &renewal);
+
+    client.set_vote_lock(&true);
+    client.set_dispute_bond(&10);
+    let treasury = create_treasury(&e, &client, &token.address);
+    treasury.deposit(&voters[0], &50);
+    let disputed_id = symbol_short!("PROP001");
+    let open_id = symbol_short!("PROP002");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &disputed_id, &description, &start_time, &end_time);
+    client.create_proposal(
+        &admin,
+        &open_id,
+        &description,
+        &start_time,
+        &(start_time + MAX_PROPOSAL_DURATION),
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[0], &disputed_id, &symbol_short!("FOR"));
+    client.vote(&voters[1], &open_id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&disputed_id);
+    client.dispute_proposal(&voters[2], &disputed_id);
+    assert_eq!(token.balance(&voters[2]), 90);
+
+    assert_eq!(client.emergency_shutdown(), 1);
+    assert_eq!(client.get_shutdown(), Some(end_time + 1));
+    assert_eq!(
+        client.get_proposal_status(&open_id),
+        TokenGatedVoteProposalStatus::Cancelled
+    );
+    assert!(client.get_dispute(&disputed_id).unwrap().resolved);
+    assert_eq!(token.balance(&voters[2]), 100);
+
+    assert_eq!(client.release_locked(&voters[1], &open_id), 100);
+    assert_eq!(client.release_locked(&voters[0], &disputed_id), 50);
+    assert_eq!(treasury.get_shutdown(), Some(end_time + 1));
+    assert_eq!(treasury.redeem(&voters[0]), 50);
+    assert_eq!(token.balance(&voters[0]), 100);
+    assert_eq!(token.balance(&voters[1]), 100);
+    assert_eq!(
+        treasury.try_redeem(&voters[2]),
+        Err(Ok(TreasuryContractErrors::NoTreasuryShares))
+    );
+
+    assert_eq!(
+        client.try_create_proposal(
+            &admin,
+            &symbol_short!("PROP003"),
+            &description,
+            &(end_time + 50),
+            &(end_time + 50 + MIN_PROPOSAL_DURATION),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::ContractShutDown))
+    );
+    assert_eq!(
+        treasury.try_deposit(&voters[1], &10),
+        Err(Ok(TreasuryContractErrors::TreasuryShutDown))
+    );
+    assert_eq!(
+        client.try_emergency_shutdown(),
+        Err(Ok(TokenGatedVoteContractErrors::ContractShutDown))
+    );
+}
+
+// Tests an emergency shutdown without an active guardian and redemption before a shutdown.
+// Expects: GuardianInactive error (Error #154) and TreasuryNotShutDown error from the treasury
+// contract (Error #2505).
+#[test]
+fn test_emergency_shutdown_requires_guardian() {
+    let e = setup_test_env();
+    let (client, _, voters) = setup_config(&e);
+    let treasury = create_treasury(&e, &client, &Address::generate(&e));
+
+    assert_eq!(
+        client.try_emergency_shutdown(),
+        Err(Ok(TokenGatedVoteContractErrors::GuardianInactive))
+    );
+    assert_eq!(client.get_shutdown(), None);
+    assert_eq!(
+        treasury.try_redeem(&voters[0]),
+        Err(Ok(TreasuryContractErrors::TreasuryNotShutDown))
+    );
+}
+
+// Tests guardian powers before any appointment and guardian proposals with invalid terms.
+// Expects: GuardianInactive error (Error #154) and InvalidConfig error (Error #15).
+#[test]
+fn test_guardian_invalid() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_config(&e);
+
+    assert_eq!(client.get_guardian(), None);
+    assert_eq!(
+        client.try_guardian_veto(&symbol_short!("PROP001")),
+        Err(Ok(TokenGatedVoteContractErrors::GuardianInactive))
+    );
+    assert_eq!(
+        client.try_guardian_set_paused(&true),
+        Err(Ok(TokenGatedVoteContractErrors::GuardianInactive))
+    );
+
+    let start_time = e.ledger().timestamp() + 50;
+    for term in [0, guardian::MAX_GUARDIAN_TERM + 1] {
+        assert_eq!(
+            client.try_create_guardian_proposal(
+                &symbol_short!("GUARD1"),
+                &String::from_val(&e, &"Appoint the guardian"),
+                &start_time,
+                &(start_time + MIN_PROPOSAL_DURATION),
+                &TokenGatedVoteGuardianRenewal {
+                    guardian: Address::generate(&e),
+                    term,
+                },
+            ),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+        );
+    }
+}
+
+// Passes a child proposal created by the child's admin and queues it for execution
+fn pass_child_proposal(
+    e: &Env,
+    child: &TokenGatedVoteContractClient,
+    admin: &Address,
+    voter: &Address,
+    id: &Symbol,
+) {
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    child.create_proposal(
+        admin,
+        id,
+        &String::from_val(e, &"Child proposal"),
+        &start_time,
+        &end_time,
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    child.vote(voter, id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    child.finalize_proposal(id);
+    child.queue_proposal(id);
+}
+
+// Tests migrating the admin role to an executor contract and leaving DAO-as-admin mode again.
+// Expects: NoPendingExecutor (Error #171) before a nomination, NotDaoAdmin (Error #172) for the
+// break-glass path outside DAO-as-admin mode, and the executor holding the admin role from its
+// acceptance until it transfers the role away.
+#[test]
+fn test_dao_admin_migration() {
+    let e = setup_test_env();
+    let (client, admin, voters) = setup_config(&e);
+    let executor = Address::generate(&e);
+    let renewal = TokenGatedVoteGuardianRenewal {
+        guardian: Address::generate(&e),
+        term: 2_592_000,
+    };
+    pass_guardian_proposal(&e, &client, &voters[0], &symbol_short!("GUARD1"), &renewal);
+
+    assert_eq!(
+        client.try_accept_dao_admin(),
+        Err(Ok(TokenGatedVoteModuleErrors::NoPendingExecutor.into()))
+    );
+    assert_eq!(
+        client.try_break_glass(&admin),
+        Err(Ok(TokenGatedVoteModuleErrors::NotDaoAdmin.into()))
+    );
+
+    client.migrate_to_dao_admin(&executor);
+    assert_eq!(client.get_pending_executor(), Some(executor.clone()));
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_dao_executor(), None);
+
+    client.accept_dao_admin();
+    assert_eq!(client.get_admin(), executor);
+    assert_eq!(client.get_dao_executor(), Some(executor.clone()));
+    assert_eq!(client.get_pending_executor(), None);
+
+    client.transfer_admin(&admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_dao_executor(), None);
+}
+
+// Tests a parent ratifying and overriding the passed proposals of a registered child.
+// Expects: The child cannot execute until ratified, failing with AwaitingRatification
+// (Error #163), an overridden proposal fails with OverriddenByParent (Error #164), and the
+// children list reports the decision counts.
+#[test]
+fn test_parent_ratifies_child_proposals() {
+    let e = setup_test_env();
+    let (parent, _, _) = setup_config(&e);
+    let (child, child_admin, voters) = setup_config(&e);
+    let scope = TokenGatedVoteChildScope {
+        requires_ratification: true,
+        can_override: true,
+        review_period: 604_800,
+    };
+    let registered_at = e.ledger().timestamp();
+    parent.register_child(&child.address, &scope);
+    child.set_parent(&Some(parent.address.clone()));
+    assert_eq!(child.get_parent(), Some(parent.address.clone()));
+
+    let ratified_id = symbol_short!("CHILD1");
+    pass_child_proposal(&e, &child, &child_admin, &voters[0], &ratified_id);
+    assert_eq!(
+        parent.get_ratification(&child.address, &ratified_id),
+        TokenGatedVoteRatification::Pending
+    );
+    assert_eq!(
+        child.try_execute_proposal(&ratified_id),
+        Err(Ok(TokenGatedVoteContractErrors::AwaitingRatification))
+    );
+    parent.ratify(&child.address, &ratified_id);
+    child.execute_proposal(&ratified_id);
+    assert_eq!(
+        child.get_proposal_status(&ratified_id),
+        TokenGatedVoteProposalStatus::Executed
+    );
+
+    let overridden_id = symbol_short!("CHILD2");
+    pass_child_proposal(&e, &child, &child_admin, &voters[0], &overridden_id);
+    parent.override_decision(&child.address, &overridden_id);
+    assert_eq!(
+        child.try_execute_proposal(&overridden_id),
+        Err(Ok(TokenGatedVoteContractErrors::OverriddenByParent))
+    );
+
+    assert_eq!(
+        parent.get_children(),
+        vec![
+            &e,
+            TokenGatedVoteChild {
+                child: child.address.clone(),
+                scope,
+                registered_at,
+                ratified: 1,
+                overridden: 1,
+            }
+        ]
+    );
+    parent.remove_child(&child.address);
+    assert!(parent.get_children().is_empty());
+    assert_eq!(
+        parent.get_ratification(&child.address, &overridden_id),
+        TokenGatedVoteRatification::Overridden
+    );
+}
+
+// Tests parent decisions outside a child's registration, scope, or review period.
+// Expects: ChildNotFound (Error #159) for unregistered children, OutsideParentScope
+// (Error #160) for overrides the scope denies, InvalidStateTransition (Error #120) for proposals
+// that have not passed, ChildDecisionExists (Error #162) for repeat decisions, and
+// ReviewPeriodClosed (Error #161) once the review period ends.
+#[test]
+fn test_parent_decisions_invalid() {
+    let e = setup_test_env();
+    let (parent, _, _) = setup_config(&e);
+    let (child, child_admin, voters) = setup_config(&e);
+    let scope = TokenGatedVoteChildScope {
+        requires_ratification: false,
+        can_override: false,
+        review_period: 100,
+    };
+
+    assert_eq!(
+        parent.try_ratify(&child.address, &symbol_short!("CHILD1")),
+        Err(Ok(TokenGatedVoteModuleErrors::ChildNotFound.into()))
+    );
+    assert_eq!(
+        parent.try_remove_child(&child.address),
+        Err(Ok(TokenGatedVoteModuleErrors::ChildNotFound.into()))
+    );
+    let mut closed = scope.clone();
+    closed.review_period = 0;
+    assert_eq!(
+        parent.try_register_child(&child.address, &closed),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    assert_eq!(
+        parent.try_register_child(&parent.address, &scope),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    assert_eq!(
+        child.try_set_parent(&Some(child.address.clone())),
+        Err(Ok(TokenGatedVoteContractErrors::InvalidConfig))
+    );
+    parent.register_child(&child.address, &scope);
+    child.set_parent(&Some(parent.address.clone()));
+
+    let passed_id = symbol_short!("CHILD1");
+    pass_child_proposal(&e, &child, &child_admin, &voters[0], &passed_id);
+    assert_eq!(
+        parent.try_override_decision(&child.address, &passed_id),
+        Err(Ok(TokenGatedVoteModuleErrors::OutsideParentScope.into()))
+    );
+
+    let pending_id = symbol_short!("CHILD2");
+    let start_time = e.ledger().timestamp() + 50;
+    child.create_proposal(
+        &child_admin,
+        &pending_id,
+        &String::from_val(&e, &"Child proposal"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    assert_eq!(
+        parent.try_ratify(&child.address, &pending_id),
+        Err(Ok(
+            TokenGatedVoteContractErrors::InvalidStateTransition.into()
+        ))
+    );
+
+    parent.ratify(&child.address, &passed_id);
+    assert_eq!(
+        parent.try_ratify(&child.address, &passed_id),
+        Err(Ok(TokenGatedVoteModuleErrors::ChildDecisionExists.into()))
+    );
+
+    let late_id = symbol_short!("CHILD3");
+    pass_child_proposal(&e, &child, &child_admin, &voters[1], &late_id);
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp += scope.review_period);
+    assert_eq!(
+        parent.try_ratify(&child.address, &late_id),
+        Err(Ok(TokenGatedVoteModuleErrors::ReviewPeriodClosed.into()))
+    );
+    assert_eq!(
+        parent.get_ratification(&child.address, &late_id),
+        TokenGatedVoteRatification::NotRequired
+    );
+    child.execute_proposal(&late_id);
+}
+
+// Tests driving a proposal through the shared governance interface client.
+// Expects: Interface calls create and tally the proposal, and a failed quorum reads as Defeated.
+#[test]
+fn test_governance_interface() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let governance = GovernanceClient::new(&e, &client.address);
+    client.set_quorum(&3);
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    governance.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 100),
+        &(ledger_time + 500100),
+    );
+    assert_eq!(
+        governance.get_status(&proposal_id),
+        GovernanceStatus::Pending
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 200);
+    governance.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    governance.vote(&user2, &proposal_id, &symbol_short!("FOR"));
+
+    let proposal = governance.get_proposal(&proposal_id);
+    assert_eq!(proposal.id, proposal_id);
+    assert_eq!(proposal.total_for, 2);
+    assert_eq!(proposal.status, GovernanceStatus::Active);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 500101);
+    assert_eq!(
+        governance.finalize(&proposal_id),
+        GovernanceStatus::Defeated
+    );
+    assert_eq!(
+        client.get_proposal_status(&proposal_id),
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+}
+
+// Tests a proposal lifecycle driven through the shared governance test harness.
+// Expects: One vote per holder, a Succeeded outcome, and a matching finalization event.
+#[test]
+fn test_governance_harness() {
+    let harness =
+        GovernanceHarness::new(|e, admin, token| create_vote_contract(e, admin, token).address);
+    let voter1 = harness.holder(100);
+    let voter2 = harness.holder(500);
+    let voter3 = harness.holder(100);
+    let proposal_id = symbol_short!("PROP001");
+
+    harness.propose(&proposal_id, MIN_PROPOSAL_DURATION);
+    harness.open_voting(&proposal_id);
+    harness.vote(&voter1, &proposal_id, &symbol_short!("FOR"));
+    harness.vote(&voter2, &proposal_id, &symbol_short!("FOR"));
+    harness.vote(&voter3, &proposal_id, &symbol_short!("AGAINST"));
+    harness.assert_tally(&proposal_id, 2, 1, 0);
+
+    assert_eq!(harness.finalize(&proposal_id), GovernanceStatus::Succeeded);
+    assert_last_event(
+        &harness.env,
+        &harness.contract(),
+        (
+            events::PROPOSAL,
+            events::FINALIZED,
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        events::ProposalFinalizedEvent {
+            id: proposal_id,
+            status: GovernanceStatus::Succeeded,
+            total_for: 2,
+            total_against: 1,
+            total_abstain: 0,
+            turnout: 3,
+            quorum: 0,
+            quorum_met: true,
+            threshold: 5_000,
+        },
+    );
+}
+
+// Tests the resources of proposal creation, voting, and the read functions as proposals accumulate.
+// Expects: Every call stays under the Soroban CPU instruction and memory limits at 1, 100, and
+// 1000 stored proposals, except the whole-list reads, which are held to them up to 100.
+#[test]
+fn test_resource_budget() {
+    for count in BUDGET_PROPOSAL_COUNTS {
+        let harness =
+            GovernanceHarness::new(|e, admin, token| create_vote_contract(e, admin, token).address);
+        let e = &harness.env;
+        let client = TokenGatedVoteContractClient::new(e, &harness.contract());
+        let voter = harness.holder(100);
+        let description = String::from_str(e, "Benchmark proposal");
+        let start_time = e.ledger().timestamp() + PROPOSAL_START_DELAY;
+        let end_time = start_time + MIN_PROPOSAL_DURATION;
+
+        for index in 1..count {
+            let id = Symbol::new(e, &std::format!("PROP{}", index));
+            client.create_proposal(&harness.admin, &id, &description, &start_time, &end_time);
+        }
+        let proposal_id = symbol_short!("PROP0");
+        assert_within_budget(e, "create_proposal", || {
+            client.create_proposal(
+                &harness.admin,
+                &proposal_id,
+                &description,
+                &start_time,
+                &end_time,
+            )
+        });
+
+        harness.open_voting(&proposal_id);
+        assert_within_budget(e, "vote", || {
+            client.vote(&voter, &proposal_id, &symbol_short!("FOR"))
+        });
+
+        assert_within_budget(e, "get_proposal", || client.get_proposal(&proposal_id));
+        assert_within_budget(e, "get_status", || client.get_status(&proposal_id));
+        let page = assert_within_budget(e, "get_proposals_by_status", || {
+            client.get_proposals_by_status(
+                &TokenGatedVoteProposalStatus::Active,
+                &0,
+                &MAX_PAGE_LIMIT,
+            )
+        });
+        assert_eq!(page.proposals.len(), count.min(MAX_PAGE_LIMIT));
+        assert_within_budget(e, "get_expiring_soon", || {
+            client.get_expiring_soon(&MAX_PAGE_LIMIT)
+        });
+
+        // The whole-list reads load every proposal, so they are only held to the limits up to
+        // BUDGET_FULL_LIST_COUNT proposals; larger sets are read through get_proposals_by_status
+        if count > BUDGET_FULL_LIST_COUNT {
+            continue;
+        }
+        let details = assert_within_budget(e, "get_governance_details", || {
+            client.get_governance_details()
+        });
+        assert_eq!(details.len(), count);
+        let history =
+            assert_within_budget(e, "get_user_history", || client.get_user_history(&voter));
+        assert_eq!(history.len(), 1);
+        let user_details =
+            assert_within_budget(e, "get_user_details", || client.get_user_details(&voter));
+        assert_eq!(user_details.len(), count);
+    }
+}
+
+// Tests the resources of status-filtered reads when the matching proposals are sparse.
+// Expects: Every window of the scan stays under the Soroban CPU instruction and memory limits at
+// 1, 100, and 1000 stored proposals, and following the cursor finds the one cancelled proposal.
+#[test]
+fn test_resource_budget_sparse_status() {
+    for count in BUDGET_PROPOSAL_COUNTS {
+        let harness =
+            GovernanceHarness::new(|e, admin, token| create_vote_contract(e, admin, token).address);
+        let e = &harness.env;
+        let client = TokenGatedVoteContractClient::new(e, &harness.contract());
+        let description = String::from_str(e, "Benchmark proposal");
+        let start_time = e.ledger().timestamp() + PROPOSAL_START_DELAY;
+        let end_time = start_time + MIN_PROPOSAL_DURATION;
+
+        for index in 0..count {
+            let id = Symbol::new(e, &std::format!("PROP{}", index));
+            client.create_proposal(&harness.admin, &id, &description, &start_time, &end_time);
+        }
+        let cancelled_id = Symbol::new(e, &std::format!("PROP{}", count - 1));
+        client.cancel_proposal(&cancelled_id);
+
+        let mut cursor = Some(0);
+        let mut found = Vec::new(e);
+        while let Some(position) = cursor {
+            let page = assert_within_budget(e, "get_proposals_by_status", || {
+                client.get_proposals_by_status(
+                    &TokenGatedVoteProposalStatus::Cancelled,
+                    &position,
+                    &MAX_PAGE_LIMIT,
+                )
+            });
+            found.append(&page.proposals);
+            cursor = page.next_cursor;
+        }
+        assert_eq!(found.len(), 1);
+        assert_eq!(found.get(0).unwrap().id, cancelled_id);
+    }
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with governance, execution, signed votes, treasury and
+// hierarchy supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_vote_contract(&e, &Address::generate(&e), &Address::generate(&e));
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(client.supports(&governance_core::FEATURE_EXECUTION));
+    assert!(client.supports(&governance_core::FEATURE_SIGNED_VOTES));
+    assert!(client.supports(&governance_core::FEATURE_TREASURY));
+    assert!(client.supports(&governance_core::FEATURE_HIERARCHY));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(!client.supports(&governance_core::FEATURE_SNAPSHOTS));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 100 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        TokenGatedVoteContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::ProposalAlreadyExists as u32,
+        GovernanceError::ProposalAlreadyExists as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::ProposalNotFound as u32,
+        GovernanceError::ProposalNotFound as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::UserAlreadyVoted as u32,
+        GovernanceError::UserAlreadyVoted as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::UserCannotVote as u32,
+        GovernanceError::UserCannotVote as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::VotingNotActive as u32,
+        GovernanceError::VotingNotActive as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::InvalidChoice as u32,
+        GovernanceError::InvalidChoice as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::StartTimeAfterEnd as u32,
+        GovernanceError::StartTimeAfterEnd as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::StartTimeInPast as u32,
+        GovernanceError::StartTimeInPast as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::DurationTooLong as u32,
+        GovernanceError::DurationTooLong as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::DurationTooShort as u32,
+        GovernanceError::DurationTooShort as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::VotingNotEnded as u32,
+        GovernanceError::VotingNotEnded as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    assert_eq!(
+        TokenGatedVoteContractErrors::ProposalsRestricted as u32,
+        GovernanceError::ProposalsRestricted as u32
+    );
+    for error in [
+        TokenGatedVoteContractErrors::InvalidStateTransition,
+        TokenGatedVoteContractErrors::ExecutionWindowClosed,
+        TokenGatedVoteContractErrors::ExecutionWindowOpen,
+        TokenGatedVoteContractErrors::InvalidQuorum,
+        TokenGatedVoteContractErrors::SignatureExpired,
+        TokenGatedVoteContractErrors::SigningKeyNotFound,
+        TokenGatedVoteContractErrors::BatchTooLarge,
+        TokenGatedVoteContractErrors::ProposalDisputed,
+        TokenGatedVoteContractErrors::ExitWindowOpen,
+        TokenGatedVoteContractErrors::ContractPaused,
+        TokenGatedVoteContractErrors::InsufficientProposalPower,
+        TokenGatedVoteContractErrors::VoteLockRequiresAuth,
+        TokenGatedVoteContractErrors::ExecutionConditionFailed,
+        TokenGatedVoteContractErrors::GuardianInactive,
+        TokenGatedVoteContractErrors::ContractShutDown,
+        TokenGatedVoteContractErrors::CategoryNotFound,
+        TokenGatedVoteContractErrors::AwaitingRatification,
+        TokenGatedVoteContractErrors::OverriddenByParent,
+        TokenGatedVoteContractErrors::ProposalDigestMismatch,
+        TokenGatedVoteContractErrors::SequentialIdsRequired,
+        TokenGatedVoteContractErrors::InvalidProposalName,
+        TokenGatedVoteContractErrors::InvalidDescription,
+        TokenGatedVoteContractErrors::VoteBurnRequiresAuth,
+        TokenGatedVoteContractErrors::GracePeriodActive,
+        TokenGatedVoteContractErrors::ProposalRateLimited,
+        TokenGatedVoteContractErrors::TooManyActiveProposals,
+        TokenGatedVoteContractErrors::BundleFailed,
+        TokenGatedVoteContractErrors::DependencyNotExecuted,
+        TokenGatedVoteContractErrors::DependencyCycle,
+        TokenGatedVoteContractErrors::AwaitingCouncilApproval,
+        TokenGatedVoteContractErrors::TreasuryNotSet,
+    ] {
+        assert!(in_range(error as u32, TOKEN_GATED_VOTE_CODES));
+    }
+    for error in [
+        TokenGatedVoteModuleErrors::RewardFundingClosed,
+        TokenGatedVoteModuleErrors::RewardPoolNotFound,
+        TokenGatedVoteModuleErrors::RewardNotClaimable,
+        TokenGatedVoteModuleErrors::RewardAlreadyClaimed,
+        TokenGatedVoteModuleErrors::RewardClaimWindowOpen,
+        TokenGatedVoteModuleErrors::RewardPoolEmpty,
+        TokenGatedVoteModuleErrors::CycleNotConfigured,
+        TokenGatedVoteModuleErrors::CycleNotEnded,
+        TokenGatedVoteModuleErrors::InvalidCycleConfig,
+        TokenGatedVoteModuleErrors::DisputesDisabled,
+        TokenGatedVoteModuleErrors::DisputeWindowClosed,
+        TokenGatedVoteModuleErrors::DisputeAlreadyExists,
+        TokenGatedVoteModuleErrors::DisputeNotFound,
+        TokenGatedVoteModuleErrors::RageQuitClosed,
+        TokenGatedVoteModuleErrors::RageQuitNotEligible,
+        TokenGatedVoteModuleErrors::NotTreasuryProposal,
+        TokenGatedVoteModuleErrors::SponsorshipClosed,
+        TokenGatedVoteModuleErrors::AlreadySponsored,
+        TokenGatedVoteModuleErrors::SponsorNotFound,
+        TokenGatedVoteModuleErrors::NoLockedTokens,
+        TokenGatedVoteModuleErrors::TokensStillLocked,
+        TokenGatedVoteModuleErrors::ChildNotFound,
+        TokenGatedVoteModuleErrors::OutsideParentScope,
+        TokenGatedVoteModuleErrors::ReviewPeriodClosed,
+        TokenGatedVoteModuleErrors::ChildDecisionExists,
+        TokenGatedVoteModuleErrors::NoPendingExecutor,
+        TokenGatedVoteModuleErrors::NotDaoAdmin,
+        TokenGatedVoteModuleErrors::BundleAlreadyExists,
+        TokenGatedVoteModuleErrors::NotProposalEditor,
+        TokenGatedVoteModuleErrors::RevisionLimitReached,
+        TokenGatedVoteModuleErrors::NoCouncil,
+        TokenGatedVoteModuleErrors::CouncilAlreadyApproved,
+        TokenGatedVoteModuleErrors::CouncilWindowClosed,
+    ] {
+        assert!(in_range(error as u32, TOKEN_GATED_VOTE_CODES));
+    }
+}
+
+// Tests vote records kept per voter and proposal across several proposals.
+// Expects: Each proposal tracks its own vote, a repeat vote fails only where one was cast.
+#[test]
+fn test_vote_records_per_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    for id in [symbol_short!("PROP001"), symbol_short!("PROP002")] {
+        client.create_proposal(&admin, &id, &description, &start_time, &end_time);
+    }
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+
+    let details = client.get_user_details(&user);
+    assert_eq!(details.get(0).unwrap(), (symbol_short!("PROP001"), true, 1));
+    assert_eq!(
+        details.get(1).unwrap(),
+        (symbol_short!("PROP002"), false, 1)
+    );
+    assert_eq!(
+        client.try_vote(&user, &symbol_short!("PROP001"), &symbol_short!("AGAINST")),
+        Err(Ok(TokenGatedVoteContractErrors::UserAlreadyVoted))
+    );
+
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("AGAINST"));
+    let details = client.get_user_details(&user);
+    assert_eq!(details.get(1).unwrap(), (symbol_short!("PROP002"), true, 1));
+    assert_eq!(client.get_governance_stats().unique_voters, 1);
+}
+
+// Tests where the schedule queue is stored and how long it lives.
+// Expects: The queue sits in temporary storage until past the latest start and is removed once
+// every scheduled proposal has been activated.
+#[test]
+fn test_schedule_queue_temporary() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 1000;
+    client.schedule_proposal(
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+
+    let queue_key = TokenGatedVoteContractDataKey::ScheduleQueue;
+    e.as_contract(&client.address, || {
+        assert!(!e.storage().persistent().has(&queue_key));
+        assert!(e.storage().temporary().get_ttl(&queue_key) >= 1000);
+    });
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(client.activate_due_proposals(), 1);
+    e.as_contract(&client.address, || {
+        assert!(!e.storage().temporary().has(&queue_key));
+    });
+}
+
+// Tests that write paths keep the instance storage alive after a long idle period.
+// Expects: A vote after the TTL ran low restores the full instance TTL.
+#[test]
+fn test_write_extends_instance_ttl() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token);
+    let instance_ttl = || e.as_contract(&client.address, || e.storage().instance().get_ttl());
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 1_000_000);
+    assert!(instance_ttl() < governance_core::INSTANCE_TTL_THRESHOLD);
+
+    client.set_quorum(&2);
+    assert_eq!(instance_ttl(), governance_core::INSTANCE_TTL_EXTENSION);
+}
+
+// Tests keeping a proposal alive as its TTL runs out.
+// Expects: The digest outlives the proposal entry, and extend_proposal_ttl restores both TTLs on
+// the last ledger before the entry would be archived.
+#[test]
+fn test_extend_proposal_ttl() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+
+    let proposal_key = TokenGatedVoteContractDataKey::Proposal(proposal_id.clone());
+    let digest_key = TokenGatedVoteContractDataKey::ProposalDigest(proposal_id.clone());
+    let ttls = || {
+        e.as_contract(&client.address, || {
+            (
+                e.storage().persistent().get_ttl(&proposal_key),
+                e.storage().persistent().get_ttl(&digest_key),
+            )
+        })
+    };
+    let (proposal_ttl, digest_ttl) = ttls();
+    assert!(digest_ttl > proposal_ttl);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number += proposal_ttl - 1);
+    assert_eq!(ttls().0, 1);
+
+    client.extend_proposal_ttl(&proposal_id);
+    assert_eq!(ttls(), (proposal_ttl, digest_ttl));
+    assert_eq!(
+        client.try_extend_proposal_ttl(&symbol_short!("PROP002")),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    );
+}
+
+// Tests reading a proposal after its entry outlived its TTL without an extension.
+// Expects: The digest stays readable while the archived proposal entry fails to load.
+#[test]
+#[should_panic(expected = "Error(Storage, ")]
+fn test_proposal_archived_after_ttl() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+    let proposal_id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    let proposal_key = TokenGatedVoteContractDataKey::Proposal(proposal_id.clone());
+    let proposal_ttl = e.as_contract(&client.address, || {
+        e.storage().persistent().get_ttl(&proposal_key)
+    });
+
+    e.ledger()
+        .with_mut(|ledger| ledger.sequence_number += proposal_ttl + 1);
+    assert!(client.get_proposal_digest(&proposal_id).is_some());
+
+    client.get_proposal(&proposal_id);
+}
+
+// Tests re-hydrating a proposal whose entry was lost from contract storage.
+// Expects: The lost proposal reads as not found (Error #4) and keeps its ID reserved (Error #3),
+// tampered data (Error #165) and unknown IDs (Error #4) are rejected, and the recorded state
+// restores once, after which voting resumes.
+#[test]
+fn test_restore_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voters = [Address::generate(&e), Address::generate(&e)];
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    for voter in voters.iter() {
+        stellar_asset.mint(voter, &100);
+    }
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&voters[0], &proposal_id, &symbol_short!("FOR"));
+    let recorded = client.get_proposal_details(&proposal_id);
+    let digest = client.get_proposal_digest(&proposal_id).unwrap();
+
+    // Simulates an archived entry that can no longer be restored from the ledger
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .remove(&TokenGatedVoteContractDataKey::Proposal(
+                proposal_id.clone(),
+            ));
+    });
+    assert_eq!(
+        client.try_get_proposal(&proposal_id),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    );
+    let later_start = e.ledger().timestamp() + 50;
+    assert_eq!(
+        client.try_create_proposal(
+            &admin,
+            &proposal_id,
+            &description,
+            &later_start,
+            &(later_start + MIN_PROPOSAL_DURATION),
+        ),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalAlreadyExists))
+    );
+
+    let mut tampered = recorded.clone();
+    tampered.total_for += 10;
+    assert_eq!(
+        client.try_restore_proposal(&proposal_id, &tampered),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalDigestMismatch))
+    );
+    assert_eq!(
+        client.try_restore_proposal(&symbol_short!("PROP002"), &recorded),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalNotFound))
+    );
+
+    client.restore_proposal(&proposal_id, &recorded);
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            events::PROPOSAL,
+            "RESTORED",
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        digest.clone(),
+    );
+    assert_eq!(client.get_proposal_digest(&proposal_id), Some(digest));
+    assert_eq!(
+        client.try_restore_proposal(&proposal_id, &recorded),
+        Err(Ok(TokenGatedVoteContractErrors::ProposalAlreadyExists))
+    );
+
+    client.vote(&voters[1], &proposal_id, &symbol_short!("AGAINST"));
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!((proposal.total_for, proposal.total_against), (1, 1));
+}
+
+// Tests dry-running votes for holders, non-holders, and repeat voters.
+// Expects: Simulation reports a weight of 1 or the error without changing the tally.
+#[test]
+fn test_simulate_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let outsider = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    assert_eq!(
+        client.simulate_vote(&user, &proposal_id, &symbol_short!("FOR")),
+        1
+    );
+    assert_eq!(
+        client.try_simulate_vote(&outsider, &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote))
+    );
+    let details = client.get_proposal_details(&proposal_id);
+    assert_eq!(details.total_for, 0);
+    assert_eq!(details.status, TokenGatedVoteProposalStatus::Pending);
+
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(
+        client.try_simulate_vote(&user, &proposal_id, &symbol_short!("AGAINST")),
+        Err(Ok(TokenGatedVoteContractErrors::UserAlreadyVoted))
+    );
+}
+
+// Tests single-proposal vote lookups for a voter and a non-voter.
+// Expects: has_voted and get_vote reflect the recorded choice, and nothing for the non-voter.
+#[test]
+fn test_has_voted_and_get_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let outsider = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+    assert!(!client.has_voted(&user, &proposal_id));
+    assert_eq!(client.get_vote(&user, &proposal_id), None);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("AGAINST"));
+
+    assert!(client.has_voted(&user, &proposal_id));
+    assert_eq!(
+        client.get_vote(&user, &proposal_id),
+        Some(symbol_short!("AGAINST"))
+    );
+    assert!(!client.has_voted(&outsider, &proposal_id));
+    assert_eq!(client.get_vote(&outsider, &proposal_id), None);
+}
+
+// Tests the versioned topics and typed payloads published across a proposal's lifecycle.
+// Expects: CREATED, CAST, and FINALIZED events carry the schema version and decode to payloads.
+#[test]
+fn test_event_schema() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    let (contract, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(contract, client.address);
+    assert_eq!(
+        topics,
+        (
+            events::PROPOSAL,
+            events::CREATED,
+            events::EVENT_VERSION,
+            proposal_id.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(
+        events::ProposalCreatedEvent::from_val(&e, &data),
+        events::ProposalCreatedEvent {
+            id: proposal_id.clone(),
+            proposer: admin.clone(),
+            start_time,
+            end_time,
+        }
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            events::VOTE,
+            events::CAST,
+            events::EVENT_VERSION,
+            proposal_id.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(
+        events::VoteCastEvent::from_val(&e, &data),
+        events::VoteCastEvent {
+            id: proposal_id.clone(),
+            voter: user.clone(),
+            choice: symbol_short!("FOR"),
+            weight: 1,
+        }
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&proposal_id);
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            events::PROPOSAL,
+            events::FINALIZED,
+            events::EVENT_VERSION,
+            proposal_id.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(
+        events::ProposalFinalizedEvent::from_val(&e, &data),
+        events::ProposalFinalizedEvent {
+            id: proposal_id,
+            status: GovernanceStatus::Succeeded,
+            total_for: 1,
+            total_against: 0,
+            total_abstain: 0,
+            turnout: 1,
+            quorum: 0,
+            quorum_met: true,
+            threshold: 5_000,
+        }
+    );
+}
+
+// Tests the finalization event of a proposal that missed its quorum.
+// Expects: The payload carries every total, the turnout, the unmet quorum, and a Defeated outcome.
+#[test]
+fn test_finalize_event_quorum_failed() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &100);
+    stellar_asset.mint(&user2, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_quorum(&3);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user2, &proposal_id, &symbol_short!("ABSTAIN"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    assert_eq!(
+        client.finalize_proposal(&proposal_id),
+        TokenGatedVoteProposalStatus::QuorumFailed
+    );
+
+    let (_, _, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        events::ProposalFinalizedEvent::from_val(&e, &data),
+        events::ProposalFinalizedEvent {
+            id: proposal_id,
+            status: GovernanceStatus::Defeated,
+            total_for: 1,
+            total_against: 0,
+            total_abstain: 1,
+            turnout: 2,
+            quorum: 3,
+            quorum_met: false,
+            threshold: 5_000,
+        }
+    );
+}
+
+// Tests the complete event list of each call in a create, vote, finalize, queue, and execute flow.
+// Expects: Every call publishes exactly the listed events in order, with the versioned topics
+// and payloads the indexer decodes.
+#[test]
+fn test_event_snapshot_lifecycle() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &100);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+
+    let id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &id,
+        &String::from_val(&e, &"Test proposal"),
+        &start_time,
+        &end_time,
+    );
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                events::CREATED,
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalCreatedEvent {
+                id: id.clone(),
+                proposer: admin.clone(),
+                start_time,
+                end_time,
+            },
+        )],
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &id, &symbol_short!("FOR"));
+    assert_events(
+        &e,
+        &client.address,
+        &[
+            event(
+                &e,
+                (
+                    events::PROPOSAL,
+                    "ACTIVATED",
+                    events::EVENT_VERSION,
+                    id.clone(),
+                ),
+                events::ProposalStatusEvent {
+                    id: id.clone(),
+                    status: GovernanceStatus::Active,
+                },
+            ),
+            event(
+                &e,
+                (
+                    events::VOTE,
+                    events::CAST,
+                    events::EVENT_VERSION,
+                    id.clone(),
+                ),
+                events::VoteCastEvent {
+                    id: id.clone(),
+                    voter: user.clone(),
+                    choice: symbol_short!("FOR"),
+                    weight: 1,
+                },
+            ),
+        ],
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&id);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                events::FINALIZED,
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalFinalizedEvent {
+                id: id.clone(),
+                status: GovernanceStatus::Succeeded,
+                total_for: 1,
+                total_against: 0,
+                total_abstain: 0,
+                turnout: 1,
+                quorum: 0,
+                quorum_met: true,
+                threshold: 5_000,
+            },
+        )],
+    );
+
+    client.queue_proposal(&id);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                "QUEUED",
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalStatusEvent {
+                id: id.clone(),
+                status: GovernanceStatus::Queued,
+            },
+        )],
+    );
+
+    client.execute_proposal(&id);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                "EXECUTED",
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalStatusEvent {
+                id,
+                status: GovernanceStatus::Executed,
+            },
+        )],
+    );
+}
+
+// Tests the complete event list of the calls that create and execute a treasury payout.
+// Expects: Creation adds TREASURY/PROPOSED after PROPOSAL/CREATED, execution adds TREASURY/PAID
+// after PROPOSAL/EXECUTED, and the token's own transfer event is not counted as the contract's.
+#[test]
+fn test_event_snapshot_treasury() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let member = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&member, &2000);
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    let treasury = create_treasury(&e, &client, &token.address);
+    treasury.deposit(&member, &1000);
+    assert_events(
+        &e,
+        &treasury.address,
+        &[event(
+            &e,
+            (
+                "TREASURY",
+                "DEPOSITED",
+                events::EVENT_VERSION,
+                member.clone(),
+            ),
+            (1000i128, 1000i128),
+        )],
+    );
+
+    let id = symbol_short!("GRANT1");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_treasury_proposal(
+        &id,
+        &String::from_val(&e, &"Fund a grant"),
+        &start_time,
+        &end_time,
+        &recipient,
+        &400,
+    );
+    assert_events(
+        &e,
+        &client.address,
+        &[
+            event(
+                &e,
+                (
+                    events::PROPOSAL,
+                    events::CREATED,
+                    events::EVENT_VERSION,
+                    id.clone(),
+                ),
+                events::ProposalCreatedEvent {
+                    id: id.clone(),
+                    proposer: admin.clone(),
+                    start_time,
+                    end_time,
+                },
+            ),
+            event(
+                &e,
+                ("TREASURY", "PROPOSED", events::EVENT_VERSION, id.clone()),
+                (recipient.clone(), 400i128),
+            ),
+        ],
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&member, &id, &symbol_short!("FOR"));
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize_proposal(&id);
+    client.queue_proposal(&id);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = end_time + 1 + treasury::RAGE_QUIT_WINDOW + 1;
+    });
+
+    client.execute_proposal(&id);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                "EXECUTED",
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalStatusEvent {
+                id: id.clone(),
+                status: GovernanceStatus::Executed,
+            },
+        )],
+    );
+    assert_events(
+        &e,
+        &treasury.address,
+        &[event(
+            &e,
+            ("TREASURY", "PAID", events::EVENT_VERSION, id),
+            (recipient.clone(), 400i128),
+        )],
+    );
+    assert_eq!(token.balance(&recipient), 400);
+}
+
+// Tests eligibility with a custom token exposing 9 decimals instead of a Stellar asset.
+// Expects: Balances are normalized to 7 decimals, so dust below one unit cannot vote (Error #6).
+#[test]
+fn test_custom_token_source() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let dust = Address::generate(&e);
+
+    let token_address = e.register(MockNineDecimalToken, ());
+    let token = MockNineDecimalTokenClient::new(&e, &token_address);
+    token.set_balance(&holder, &100);
+    token.set_balance(&dust, &99);
+
+    let contract_address = e.register(
+        TokenGatedVoteContract,
+        (admin.clone(), TokenSource::Custom(token_address)),
+    );
+    let client = TokenGatedVoteContractClient::new(&e, &contract_address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 50;
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &start_time,
+        &(start_time + MIN_PROPOSAL_DURATION),
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&holder, &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(
+        client.try_vote(&dust, &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenGatedVoteContractErrors::UserCannotVote))
+    );
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 1);
+}
+
+// Tests a quorum adapting to the rolling average turnout of recently finalized proposals.
+// Expects: New proposals take the share of the windowed average, never below the static quorum.
+#[test]
+fn test_dynamic_quorum() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let voters = [
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    ];
+    for voter in voters.iter() {
+        stellar_asset.mint(voter, &100);
+    }
+
+    let client = create_vote_contract(&e, &admin, &token.address);
+    client.set_quorum(&1);
+    let dynamic = TokenGatedVoteDynamicQuorum {
+        window: 2,
+        bps: 7_500,
+    };
+    client.set_dynamic_quorum(&Some(dynamic));
+    assert_eq!(client.get_dynamic_quorum_config(), Some(dynamic));
+    assert_eq!(client.get_dynamic_quorum(), 1);
+
+    let description = String::from_val(&e, &"Test proposal");
+    for (id, turnout, expected_quorum, next_quorum) in [
+        (symbol_short!("PROP001"), 4, 1, 3),
+        (symbol_short!("PROP002"), 2, 3, 2),
+        (symbol_short!("PROP003"), 0, 2, 1),
+    ] {
+        let start_time = e.ledger().timestamp() + 50;
+        let end_time = start_time + MIN_PROPOSAL_DURATION;
+        client.create_proposal(&admin, &id, &description, &start_time, &end_time);
+        assert_eq!(client.get_proposal_details(&id).quorum, expected_quorum);
+
+        e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+        for voter in voters.iter().take(turnout) {
+            client.vote(voter, &id, &symbol_short!("FOR"));
+        }
+        e.ledger()
+            .with_mut(|ledger| ledger.timestamp = end_time + 1);
+        client.finalize_proposal(&id);
+        assert_eq!(client.get_dynamic_quorum(), next_quorum);
+    }
+    assert_eq!(client.get_turnout_history(), vec![&e, 4, 2, 0]);
+
+    client.set_dynamic_quorum(&None);
+    assert_eq!(client.get_dynamic_quorum_config(), None);
+    assert_eq!(client.get_dynamic_quorum(), 1);
+}
+
+// Tests dynamic quorum settings with an empty or oversized window or share.
+// Expects: InvalidQuorum error (Error #124).
+#[test]
+fn test_dynamic_quorum_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let token_address = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &token_address);
+
+    for (window, bps) in [(0, 5_000), (21, 5_000), (5, 0), (5, 10_001)] {
+        assert_eq!(
+            client.try_set_dynamic_quorum(&Some(TokenGatedVoteDynamicQuorum { window, bps })),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidQuorum))
+        );
+    }
+}
+
+const PROP_VOTERS: usize = 6; // Holders taking part in randomized vote sequences
+
+// Vote choices drawn by randomized sequences, the last one invalid
+const PROP_CHOICES: [Symbol; 4] = [
+    symbol_short!("FOR"),
+    symbol_short!("AGAINST"),
+    symbol_short!("ABSTAIN"),
+    symbol_short!("MAYBE"),
+];
+
+// Generates a balance per holder, zero balances making a holder ineligible
+fn balances_strategy() -> impl Strategy<Value = std::vec::Vec<i128>> {
+    prop::collection::vec(0..=1_000i128, PROP_VOTERS)
+}
+
+// Generates a sequence of (holder, choice) votes, repeats included
+fn votes_strategy() -> impl Strategy<Value = std::vec::Vec<(usize, usize)>> {
+    prop::collection::vec((0..PROP_VOTERS, 0..PROP_CHOICES.len()), 0..24)
+}
+
+// Deploys a vote contract with a proposal open for voting, returning the holders
+fn setup_prop_proposal<'a>(
+    balances: &[i128],
+) -> (
+    GovernanceHarness<'a>,
+    TokenGatedVoteContractClient<'a>,
+    std::vec::Vec<Address>,
+    Symbol,
+) {
+    let harness =
+        GovernanceHarness::new(|e, admin, token| create_vote_contract(e, admin, token).address);
+    let client = TokenGatedVoteContractClient::new(&harness.env, &harness.contract());
+    let holders = balances
+        .iter()
+        .map(|balance| harness.holder(*balance))
+        .collect();
+    let proposal_id = symbol_short!("PROP001");
+    harness.propose(&proposal_id, MIN_PROPOSAL_DURATION);
+    harness.open_voting(&proposal_id);
+    (harness, client, holders, proposal_id)
+}
+
+// Returns the FOR, AGAINST, and ABSTAIN totals of a proposal
+fn read_totals(governance: &GovernanceClient, id: &Symbol) -> [i128; 3] {
+    let proposal = governance.get_proposal(id);
+    [
+        proposal.total_for,
+        proposal.total_against,
+        proposal.total_abstain,
+    ]
+}
+
+proptest! {
+    // Every case deploys contracts and casts up to 24 votes, so fewer cases than the default run
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // Tests tallies across a randomized vote sequence with repeats, ineligible holders, and an
+    // invalid choice.
+    // Expects: A vote counts only for an eligible first-time voter with a valid choice, no tally
+    // ever decreases, and the tallies always sum to the votes counted.
+    #[test]
+    fn prop_tally_sums_counted_votes(balances in balances_strategy(), votes in votes_strategy()) {
+        let (harness, client, holders, proposal_id) = setup_prop_proposal(&balances);
+        let mut voted = [false; PROP_VOTERS];
+        let mut expected = [0i128; 3];
+        let mut counted = 0i128;
+
+        for (voter, choice) in votes {
+            let before = read_totals(&harness.governance, &proposal_id);
+            let result = client.try_vote(&holders[voter], &proposal_id, &PROP_CHOICES[choice]);
+            let eligible = !voted[voter] && balances[voter] > 0 && choice < 3;
+            prop_assert_eq!(result.is_ok(), eligible);
+            if eligible {
+                voted[voter] = true;
+                expected[choice] += 1;
+                counted += 1;
+            }
+
+            let after = read_totals(&harness.governance, &proposal_id);
+            prop_assert!(after.iter().zip(before.iter()).all(|(now, then)| now >= then));
+            prop_assert_eq!(after, expected);
+            prop_assert_eq!(after.iter().sum::<i128>(), counted);
+        }
+    }
+
+    // Tests finalizing a randomized proposal a second time.
+    // Expects: The repeat is rejected with InvalidStateTransition (Error #120) and leaves the
+    // outcome, status, and tallies unchanged.
+    #[test]
+    fn prop_finalize_idempotent(balances in balances_strategy(), votes in votes_strategy()) {
+        let (harness, client, holders, proposal_id) = setup_prop_proposal(&balances);
+        for (voter, choice) in votes {
+            let _ = client.try_vote(&holders[voter], &proposal_id, &PROP_CHOICES[choice]);
+        }
+
+        let status = harness.finalize(&proposal_id);
+        let proposal = harness.governance.get_proposal(&proposal_id);
+        let outcome = client.get_proposal_status(&proposal_id);
+
+        prop_assert_eq!(
+            client.try_finalize(&proposal_id),
+            Err(Ok(TokenGatedVoteContractErrors::InvalidStateTransition))
+        );
+        prop_assert_eq!(harness.governance.get_proposal(&proposal_id), proposal);
+        prop_assert_eq!(harness.governance.get_status(&proposal_id), status);
+        prop_assert_eq!(client.get_proposal_status(&proposal_id), outcome);
+    }
 }
@@ -0,0 +1,480 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use core::fmt::Debug;
+use governance_testutils::{create_token_contract, mint, set_time, setup_test_env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Env, Error, IntoVal, InvokeError, String, Val, Vec,
+};
+
+const GUARDIAN_TERM: u64 = 2_592_000; // ~30 days
+
+// Deployment with an admin, a token holder, and an active guardian; set up with every
+// authorization mocked, after which each test restricts authorizations per call
+struct AuthFixture<'a> {
+    e: Env,
+    client: TokenGatedVoteContractClient<'a>,
+    admin: Address,
+    voter: Address,
+    guardian: Address,
+}
+
+fn setup_fixture<'a>() -> AuthFixture<'a> {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    mint(&e, &token.address, &voter, 100);
+    let contract_address = e.register(
+        TokenGatedVoteContract,
+        (admin.clone(), TokenSource::Stellar(token.address.clone())),
+    );
+    let client = TokenGatedVoteContractClient::new(&e, &contract_address);
+
+    let id = symbol_short!("GUARD1");
+    let (start_time, end_time) = proposal_window(&e);
+    client.create_guardian_proposal(
+        &id,
+        &String::from_str(&e, "Appoint the guardian"),
+        &start_time,
+        &end_time,
+        &TokenGatedVoteGuardianRenewal {
+            guardian: guardian.clone(),
+            term: GUARDIAN_TERM,
+        },
+    );
+    set_time(&e, start_time);
+    client.vote(&voter, &id, &symbol_short!("FOR"));
+    set_time(&e, end_time + 1);
+    client.finalize_proposal(&id);
+    client.queue_proposal(&id);
+    client.execute_proposal(&id);
+
+    AuthFixture {
+        e,
+        client,
+        admin,
+        voter,
+        guardian,
+    }
+}
+
+// Returns the (start_time, end_time) of a proposal opening shortly after the current ledger time
+fn proposal_window(e: &Env) -> (u64, u64) {
+    let start_time = e.ledger().timestamp() + 50;
+    (start_time, start_time + MIN_PROPOSAL_DURATION)
+}
+
+// Creates a proposal by the admin with every authorization mocked and opens its voting window
+fn open_proposal(fixture: &AuthFixture, id: &Symbol) -> (u64, u64) {
+    let (start_time, end_time) = proposal_window(&fixture.e);
+    fixture.e.mock_all_auths();
+    fixture.client.create_proposal(
+        &fixture.admin,
+        id,
+        &String::from_str(&fixture.e, "Test proposal"),
+        &start_time,
+        &end_time,
+    );
+    set_time(&fixture.e, start_time);
+    (start_time, end_time)
+}
+
+// Restricts authorizations to the signers of the next call to fn_name with args
+fn authorize(fixture: &AuthFixture, signers: &[&Address], fn_name: &str, args: Vec<Val>) {
+    let invoke = MockAuthInvoke {
+        contract: &fixture.client.address,
+        fn_name,
+        args,
+        sub_invokes: &[],
+    };
+    let auths: std::vec::Vec<MockAuth> = signers
+        .iter()
+        .map(|address| MockAuth {
+            address,
+            invoke: &invoke,
+        })
+        .collect();
+    fixture.e.mock_auths(&auths);
+}
+
+// Asserts that a call failed in the host's authorization check rather than with a contract error;
+// entry points returning `soroban_sdk::Error` surface that failure as the host's auth error code
+fn assert_unauthorized<T: Debug, E: Debug + Copy + Into<Error>>(
+    result: Result<T, Result<E, InvokeError>>,
+) {
+    let auth_failure = Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction);
+    assert!(
+        match result {
+            Err(Err(InvokeError::Abort)) => true,
+            Err(Ok(error)) => error.into() == auth_failure,
+            _ => false,
+        },
+        "expected an authorization failure, got {:?}",
+        result
+    );
+}
+
+// Tests creating a proposal with only one address authorizing.
+// Expects: The admin cannot propose on a holder's behalf; the proposer itself can, once holders
+// may propose as sponsored drafts.
+#[test]
+fn test_create_proposal_requires_proposer_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    client.set_open_proposing(&true);
+    client.set_sponsors_required(&1);
+    let id = symbol_short!("PROP001");
+    let description = String::from_str(&fixture.e, "Test proposal");
+    let (start_time, end_time) = proposal_window(&fixture.e);
+    let args: Vec<Val> = (
+        fixture.voter.clone(),
+        id.clone(),
+        description.clone(),
+        start_time,
+        end_time,
+    )
+        .into_val(&fixture.e);
+
+    authorize(&fixture, &[&fixture.admin], "create_proposal", args.clone());
+    assert_unauthorized(client.try_create_proposal(
+        &fixture.voter,
+        &id,
+        &description,
+        &start_time,
+        &end_time,
+    ));
+
+    authorize(&fixture, &[&fixture.voter], "create_proposal", args);
+    client.create_proposal(&fixture.voter, &id, &description, &start_time, &end_time);
+    assert_eq!(client.get_proposal(&id).start_time, start_time);
+}
+
+// Tests voting with only one address authorizing.
+// Expects: Neither the admin nor the guardian can vote for a holder; the holder itself can.
+#[test]
+fn test_vote_requires_voter_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let id = symbol_short!("PROP001");
+    open_proposal(&fixture, &id);
+    let choice = symbol_short!("FOR");
+    let args: Vec<Val> = (fixture.voter.clone(), id.clone(), choice.clone()).into_val(&fixture.e);
+
+    authorize(&fixture, &[&fixture.admin], "vote", args.clone());
+    assert_unauthorized(client.try_vote(&fixture.voter, &id, &choice));
+    authorize(&fixture, &[&fixture.guardian], "vote", args.clone());
+    assert_unauthorized(client.try_vote(&fixture.voter, &id, &choice));
+
+    authorize(&fixture, &[&fixture.voter], "vote", args);
+    client.vote(&fixture.voter, &id, &choice);
+    assert_eq!(client.get_proposal(&id).total_for, 1);
+}
+
+// Tests an admin configuration change with only one address authorizing.
+// Expects: A holder and the guardian are rejected; the admin can set the quorum.
+#[test]
+fn test_set_quorum_requires_admin_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let args: Vec<Val> = (5i128,).into_val(&fixture.e);
+
+    authorize(&fixture, &[&fixture.voter], "set_quorum", args.clone());
+    assert_unauthorized(client.try_set_quorum(&5));
+    authorize(&fixture, &[&fixture.guardian], "set_quorum", args.clone());
+    assert_unauthorized(client.try_set_quorum(&5));
+
+    authorize(&fixture, &[&fixture.admin], "set_quorum", args);
+    client.set_quorum(&5);
+    assert_eq!(client.get_config().quorum, 5);
+}
+
+// Tests transferring the admin role with only one address authorizing.
+// Expects: Only the current admin can transfer, after which only the new admin holds admin rights.
+#[test]
+fn test_transfer_admin_requires_current_admin_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let new_admin = Address::generate(&fixture.e);
+    let args: Vec<Val> = (new_admin.clone(),).into_val(&fixture.e);
+
+    authorize(&fixture, &[&new_admin], "transfer_admin", args.clone());
+    assert_unauthorized(client.try_transfer_admin(&new_admin));
+
+    authorize(&fixture, &[&fixture.admin], "transfer_admin", args);
+    client.transfer_admin(&new_admin);
+
+    let args: Vec<Val> = (true,).into_val(&fixture.e);
+    authorize(&fixture, &[&fixture.admin], "set_vote_lock", args.clone());
+    assert_unauthorized(client.try_set_vote_lock(&true));
+    authorize(&fixture, &[&new_admin], "set_vote_lock", args);
+    client.set_vote_lock(&true);
+    assert!(client.get_config().vote_lock);
+
+    let args: Vec<Val> = (25_i128,).into_val(&fixture.e);
+    authorize(&fixture, &[&fixture.admin], "set_vote_burn", args.clone());
+    assert_unauthorized(client.try_set_vote_burn(&25));
+    authorize(&fixture, &[&new_admin], "set_vote_burn", args);
+    client.set_vote_burn(&25);
+    assert_eq!(client.get_config().vote_burn, 25);
+}
+
+// Tests cancelling a proposal with only one address authorizing.
+// Expects: Neither a holder nor the guardian can cancel; the admin can.
+#[test]
+fn test_cancel_proposal_requires_admin_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let id = symbol_short!("PROP001");
+    open_proposal(&fixture, &id);
+    let args: Vec<Val> = (id.clone(),).into_val(&fixture.e);
+
+    authorize(&fixture, &[&fixture.voter], "cancel_proposal", args.clone());
+    assert_unauthorized(client.try_cancel_proposal(&id));
+    authorize(
+        &fixture,
+        &[&fixture.guardian],
+        "cancel_proposal",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_cancel_proposal(&id));
+
+    authorize(&fixture, &[&fixture.admin], "cancel_proposal", args);
+    client.cancel_proposal(&id);
+    assert_eq!(
+        client.get_proposal_status(&id),
+        TokenGatedVoteProposalStatus::Cancelled
+    );
+}
+
+// Tests the guardian's pause with only one address authorizing.
+// Expects: The admin cannot use the guardian's power; the guardian can pause.
+#[test]
+fn test_guardian_set_paused_requires_guardian_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let args: Vec<Val> = (true,).into_val(&fixture.e);
+
+    authorize(
+        &fixture,
+        &[&fixture.admin],
+        "guardian_set_paused",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_guardian_set_paused(&true));
+
+    authorize(&fixture, &[&fixture.guardian], "guardian_set_paused", args);
+    client.guardian_set_paused(&true);
+    assert!(client.get_config().paused);
+}
+
+// Tests both veto paths on a succeeded proposal with only one address authorizing.
+// Expects: The guardian cannot use the admin veto and the admin cannot use the guardian veto;
+// the guardian vetoes through its own entrypoint.
+#[test]
+fn test_vetoes_require_their_own_role() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let id = symbol_short!("PROP001");
+    let (_, end_time) = open_proposal(&fixture, &id);
+    client.vote(&fixture.voter, &id, &symbol_short!("FOR"));
+    set_time(&fixture.e, end_time + 1);
+    client.finalize_proposal(&id);
+    let args: Vec<Val> = (id.clone(),).into_val(&fixture.e);
+
+    authorize(
+        &fixture,
+        &[&fixture.guardian],
+        "veto_proposal",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_veto_proposal(&id));
+    authorize(&fixture, &[&fixture.admin], "guardian_veto", args.clone());
+    assert_unauthorized(client.try_guardian_veto(&id));
+
+    authorize(&fixture, &[&fixture.guardian], "guardian_veto", args);
+    client.guardian_veto(&id);
+    assert_eq!(
+        client.get_proposal_status(&id),
+        TokenGatedVoteProposalStatus::Vetoed
+    );
+}
+
+// Tests co-approving a passed proposal in dual-approval mode with only one address authorizing.
+// Expects: Neither the admin nor a holder can approve for the council; the council itself can.
+#[test]
+fn test_council_approve_requires_council_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let council = Address::generate(&fixture.e);
+    client.set_council(&Some(TokenGatedVoteCouncil {
+        council: council.clone(),
+        approval_window: GUARDIAN_TERM,
+    }));
+    let id = symbol_short!("PROP001");
+    let (_, end_time) = open_proposal(&fixture, &id);
+    client.vote(&fixture.voter, &id, &symbol_short!("FOR"));
+    set_time(&fixture.e, end_time + 1);
+    client.finalize_proposal(&id);
+    let args: Vec<Val> = (id.clone(),).into_val(&fixture.e);
+
+    for signer in [&fixture.admin, &fixture.voter] {
+        authorize(&fixture, &[signer], "council_approve", args.clone());
+        assert_unauthorized(client.try_council_approve(&id));
+    }
+    authorize(&fixture, &[&council], "council_approve", args);
+    client.council_approve(&id);
+    assert!(client.get_council_approval(&id).is_some());
+}
+
+// Tests the emergency shutdown with different sets of authorizing addresses.
+// Expects: Neither the admin nor the guardian alone can shut down; both together can.
+#[test]
+fn test_emergency_shutdown_requires_admin_and_guardian_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let args: Vec<Val> = ().into_val(&fixture.e);
+
+    authorize(
+        &fixture,
+        &[&fixture.admin],
+        "emergency_shutdown",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_emergency_shutdown());
+    authorize(
+        &fixture,
+        &[&fixture.guardian],
+        "emergency_shutdown",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_emergency_shutdown());
+    assert_eq!(client.get_shutdown(), None);
+
+    authorize(
+        &fixture,
+        &[&fixture.admin, &fixture.guardian],
+        "emergency_shutdown",
+        args,
+    );
+    client.emergency_shutdown();
+    assert!(client.get_shutdown().is_some());
+}
+
+// Tests finalizing a proposal with no address authorizing.
+// Expects: Finalization is permissionless once voting has ended.
+#[test]
+fn test_finalize_requires_no_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let id = symbol_short!("PROP001");
+    let (_, end_time) = open_proposal(&fixture, &id);
+    client.vote(&fixture.voter, &id, &symbol_short!("FOR"));
+    set_time(&fixture.e, end_time + 1);
+
+    fixture.e.set_auths(&[]);
+    assert_eq!(client.finalize(&id), GovernanceStatus::Succeeded);
+}
+
+// Tests queueing and executing proposals with no address authorizing.
+// Expects: A passed config change proposal is applied without the admin, while other
+// proposals still need the admin's authorization to be queued.
+#[test]
+fn test_config_change_executes_without_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let id = symbol_short!("PROP001");
+    let (_, end_time) = open_proposal(&fixture, &id);
+    client.vote(&fixture.voter, &id, &symbol_short!("FOR"));
+    set_time(&fixture.e, end_time + 1);
+    client.finalize_proposal(&id);
+
+    let config_id = symbol_short!("CONFIG1");
+    let (start_time, end_time) = proposal_window(&fixture.e);
+    client.create_config_proposal(
+        &fixture.admin,
+        &config_id,
+        &String::from_str(&fixture.e, "Raise the quorum"),
+        &start_time,
+        &end_time,
+        &Vec::from_array(&fixture.e, [TokenGatedVoteConfigChange::Quorum(50)]),
+    );
+    set_time(&fixture.e, start_time);
+    client.vote(&fixture.voter, &config_id, &symbol_short!("FOR"));
+    set_time(&fixture.e, end_time + 1);
+    client.finalize_proposal(&config_id);
+
+    fixture.e.set_auths(&[]);
+    assert_unauthorized(client.try_queue_proposal(&id));
+    client.queue_proposal(&config_id);
+    client.execute_proposal(&config_id);
+    assert_eq!(client.get_config().quorum, 50);
+}
+
+// Executor mock: forwards admin actions to the vote contract, authorizing them as the invoker
+#[contract]
+pub struct MockExecutorContract;
+
+#[contractimpl]
+impl MockExecutorContract {
+    pub fn accept_dao_admin(env: Env, vote: Address) {
+        TokenGatedVoteContractClient::new(&env, &vote).accept_dao_admin();
+    }
+
+    pub fn set_quorum(env: Env, vote: Address, quorum: i128) {
+        TokenGatedVoteContractClient::new(&env, &vote).set_quorum(&quorum);
+    }
+}
+
+// Tests DAO-as-admin mode with an executor contract holding the admin role.
+// Expects: The executor accepts and exercises the role by invoking the contract with no mocked
+// authorization, the former admin loses it, and only the guardian can break glass.
+#[test]
+fn test_dao_admin_requires_executor_auth() {
+    let fixture = setup_fixture();
+    let client = &fixture.client;
+    let executor = fixture.e.register(MockExecutorContract, ());
+    let executor_client = MockExecutorContractClient::new(&fixture.e, &executor);
+
+    let args: Vec<Val> = (executor.clone(),).into_val(&fixture.e);
+    authorize(
+        &fixture,
+        &[&fixture.voter],
+        "migrate_to_dao_admin",
+        args.clone(),
+    );
+    assert_unauthorized(client.try_migrate_to_dao_admin(&executor));
+    authorize(&fixture, &[&fixture.admin], "migrate_to_dao_admin", args);
+    client.migrate_to_dao_admin(&executor);
+
+    authorize(
+        &fixture,
+        &[&fixture.admin],
+        "accept_dao_admin",
+        Vec::new(&fixture.e),
+    );
+    assert_unauthorized(client.try_accept_dao_admin());
+    fixture.e.set_auths(&[]);
+    executor_client.accept_dao_admin(&client.address);
+    assert_eq!(client.get_admin(), executor);
+
+    let args: Vec<Val> = (5_i128,).into_val(&fixture.e);
+    authorize(&fixture, &[&fixture.admin], "set_quorum", args);
+    assert_unauthorized(client.try_set_quorum(&5));
+    fixture.e.set_auths(&[]);
+    executor_client.set_quorum(&client.address, &5);
+    assert_eq!(client.get_config().quorum, 5);
+
+    let args: Vec<Val> = (fixture.admin.clone(),).into_val(&fixture.e);
+    authorize(&fixture, &[&fixture.admin], "break_glass", args.clone());
+    assert_unauthorized(client.try_break_glass(&fixture.admin));
+    authorize(&fixture, &[&fixture.guardian], "break_glass", args);
+    client.break_glass(&fixture.admin);
+    assert_eq!(client.get_admin(), fixture.admin);
+    assert_eq!(client.get_dao_executor(), None);
+}
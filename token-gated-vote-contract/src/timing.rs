@@ -0,0 +1,115 @@
+use governance_core::VotingWindow;
+use soroban_sdk::Env;
+
+use crate::{TokenGatedVoteContractErrors, TokenGatedVoteProposalData, TokenGatedVoteTimingMode};
+
+// --- Ledger Timing Estimates (in seconds) ---
+pub(crate) const LEDGER_SECONDS: u64 = 5; // Expected close time of a single ledger
+
+// Converts a timestamp window into the ledger sequence window expected to cover it
+pub(crate) fn ledger_window(env: &Env, start_time: u64, end_time: u64) -> (u32, u32) {
+    let ledger_time = env.ledger().timestamp();
+    let sequence = env.ledger().sequence() as u64;
+    let to_ledger = |time: u64| {
+        let ledgers = time.saturating_sub(ledger_time) / LEDGER_SECONDS;
+        u32::try_from(sequence.saturating_add(ledgers)).unwrap_or(u32::MAX)
+    };
+    (to_ledger(start_time), to_ledger(end_time))
+}
+
+// Converts a ledger sequence window into the timestamp window it is expected to cover, failing
+// for a start ledger already closed or a window that does not move forward
+pub(crate) fn time_window(
+    env: &Env,
+    start_ledger: u32,
+    end_ledger: u32,
+) -> Result<(u64, u64), TokenGatedVoteContractErrors> {
+    let sequence = env.ledger().sequence();
+    if start_ledger >= end_ledger {
+        return Err(TokenGatedVoteContractErrors::StartTimeAfterEnd);
+    }
+    if start_ledger < sequence {
+        return Err(TokenGatedVoteContractErrors::StartTimeInPast);
+    }
+    let ledger_time = env.ledger().timestamp();
+    let to_time = |ledger: u32| {
+        ledger_time.saturating_add(((ledger - sequence) as u64).saturating_mul(LEDGER_SECONDS))
+    };
+    Ok((to_time(start_ledger), to_time(end_ledger)))
+}
+
+// Computes where the current ledger falls relative to a proposal's voting window under its
+// timing mode; in Both mode the stricter of the two windows applies
+pub(crate) fn window(env: &Env, proposal: &TokenGatedVoteProposalData) -> VotingWindow {
+    let by_time = governance_core::voting_window(
+        env.ledger().timestamp(),
+        proposal.start_time,
+        proposal.end_time,
+    );
+    let by_ledger = governance_core::voting_window(
+        env.ledger().sequence() as u64,
+        proposal.start_ledger as u64,
+        proposal.end_ledger as u64,
+    );
+    match proposal.timing {
+        TokenGatedVoteTimingMode::Timestamp => by_time,
+        TokenGatedVoteTimingMode::LedgerSequence => by_ledger,
+        TokenGatedVoteTimingMode::Both => {
+            if by_time == VotingWindow::Ended || by_ledger == VotingWindow::Ended {
+                VotingWindow::Ended
+            } else if by_time == VotingWindow::Pending || by_ledger == VotingWindow::Pending {
+                VotingWindow::Pending
+            } else {
+                VotingWindow::Active
+            }
+        }
+    }
+}
+
+// Returns the seconds until a proposal's voting window opens, estimating ledger bounds at
+// LEDGER_SECONDS per ledger; in Both mode the later opening applies
+pub(crate) fn seconds_until_start(env: &Env, proposal: &TokenGatedVoteProposalData) -> u64 {
+    let by_time = proposal.start_time.saturating_sub(env.ledger().timestamp());
+    let by_ledger = ledgers_to_seconds(env, proposal.start_ledger);
+    match proposal.timing {
+        TokenGatedVoteTimingMode::Timestamp => by_time,
+        TokenGatedVoteTimingMode::LedgerSequence => by_ledger,
+        TokenGatedVoteTimingMode::Both => by_time.max(by_ledger),
+    }
+}
+
+// Returns the seconds until a proposal's voting window closes, estimating ledger bounds at
+// LEDGER_SECONDS per ledger; in Both mode the earlier close applies
+pub(crate) fn seconds_until_end(env: &Env, proposal: &TokenGatedVoteProposalData) -> u64 {
+    let by_time = proposal.end_time.saturating_sub(env.ledger().timestamp());
+    let by_ledger = ledgers_to_seconds(env, proposal.end_ledger);
+    match proposal.timing {
+        TokenGatedVoteTimingMode::Timestamp => by_time,
+        TokenGatedVoteTimingMode::LedgerSequence => by_ledger,
+        TokenGatedVoteTimingMode::Both => by_time.min(by_ledger),
+    }
+}
+
+// Estimates the seconds until a ledger closes from the current sequence
+fn ledgers_to_seconds(env: &Env, ledger: u32) -> u64 {
+    (ledger.saturating_sub(env.ledger().sequence()) as u64).saturating_mul(LEDGER_SECONDS)
+}
+
+// Derives the TTL extension keeping a proposal alive past the end of both of its windows plus
+// a buffer, never below the minimum extension nor above the longest TTL the network allows
+pub(crate) fn proposal_ttl(
+    env: &Env,
+    proposal: &TokenGatedVoteProposalData,
+    buffer: u32,
+    minimum: u32,
+) -> u32 {
+    let by_time =
+        governance_core::proposal_ttl(env.ledger().timestamp(), proposal.end_time, buffer, minimum);
+    let by_ledger = governance_core::proposal_ttl(
+        env.ledger().sequence() as u64,
+        proposal.end_ledger as u64,
+        buffer,
+        minimum,
+    );
+    by_time.max(by_ledger).min(env.storage().max_ttl())
+}
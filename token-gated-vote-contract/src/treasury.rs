@@ -0,0 +1,168 @@
+use governance_core::VOTE_AGAINST;
+use pg_contracts_types::treasury::{TreasuryPoolSource, TreasurySpendingLimit};
+use soroban_sdk::{contractclient, Address, Env, Error, Symbol};
+
+use crate::{
+    TokenGatedVoteContractDataKey, TokenGatedVoteContractErrors, TokenGatedVoteGovernanceConfig,
+    TokenGatedVoteModuleErrors, TokenGatedVoteProposalData, TokenGatedVoteProposalStatus,
+    TokenGatedVoteRecord, TokenGatedVoteTreasuryAction,
+};
+
+// --- Rage-Quit Exit Window (in seconds) ---
+pub(crate) const RAGE_QUIT_WINDOW: u64 = 259_200; // ~3 days between pass and execution
+
+// --- Stream Duration Bounds (in seconds) ---
+pub(crate) const MAX_STREAM_DURATION: u64 = 126_144_000; // ~4 years
+
+// --- Spending Limit Bounds ---
+pub(crate) const MAX_SPENDING_PERIOD: u64 = 31_536_000; // ~365 days rolling window at most
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const TREASURY_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Cross-contract interface of the treasury contract holding deposits and the community pool and
+// paying treasury and pool spend proposals; this contract is its governor
+#[contractclient(name = "TreasuryClient")]
+pub trait TreasuryInterface {
+    fn accrue(env: Env, amount: i128);
+    fn pay(env: Env, id: Symbol, recipient: Address, amount: i128);
+    fn open_stream(env: Env, id: Symbol, recipient: Address, amount: i128, duration: u64);
+    fn cancel_stream(env: Env, id: Symbol) -> i128;
+    fn pool_accrue(env: Env, source: TreasuryPoolSource, id: Symbol, amount: i128);
+    fn pool_pay(env: Env, id: Symbol, recipient: Address, amount: i128);
+    fn rage_quit(env: Env, member: Address, id: Symbol) -> i128;
+    fn set_spending_limit(env: Env, limit: TreasurySpendingLimit);
+    fn shut_down(env: Env);
+}
+
+// Loads the treasury contract, if one has been set
+pub(crate) fn read_address(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TokenGatedVoteContractDataKey::Treasury)
+}
+
+// Loads the treasury contract, failing until one has been set
+pub(crate) fn require_address(env: &Env) -> Result<Address, TokenGatedVoteContractErrors> {
+    read_address(env).ok_or(TokenGatedVoteContractErrors::TreasuryNotSet)
+}
+
+// Points treasury proposals, fees, and exits at a treasury contract governed by this contract
+// and hands it the configured spending limit
+pub(crate) fn set_address(env: &Env, treasury: &Address, config: &TokenGatedVoteGovernanceConfig) {
+    env.storage()
+        .instance()
+        .set(&TokenGatedVoteContractDataKey::Treasury, treasury);
+    sync_spending_limit(env, config);
+}
+
+// Hands the configured spending limit to the treasury contract, if one is set
+pub(crate) fn sync_spending_limit(env: &Env, config: &TokenGatedVoteGovernanceConfig) {
+    if let Some(treasury) = read_address(env) {
+        TreasuryClient::new(env, &treasury).set_spending_limit(&TreasurySpendingLimit {
+            cap: config.spending_cap,
+            period: config.spending_period,
+        });
+    }
+}
+
+// Moves tokens already held by this contract into the treasury without minting shares
+pub(crate) fn accrue(env: &Env, treasury: &Address, amount: i128) {
+    TreasuryClient::new(env, treasury).accrue(&amount);
+}
+
+// Shuts the treasury contract down with this contract, opening member redemptions
+pub(crate) fn shut_down(env: &Env) {
+    if let Some(treasury) = read_address(env) {
+        TreasuryClient::new(env, &treasury).shut_down();
+    }
+}
+
+// Loads the payout attached to a treasury proposal, if any
+pub(crate) fn read_action(env: &Env, id: &Symbol) -> Option<TokenGatedVoteTreasuryAction> {
+    env.storage()
+        .persistent()
+        .get(&TokenGatedVoteContractDataKey::TreasuryAction(id.clone()))
+}
+
+// Attaches a payout to a proposal, turning it into a treasury proposal
+pub(crate) fn write_action(env: &Env, id: &Symbol, action: &TokenGatedVoteTreasuryAction) {
+    let action_key = TokenGatedVoteContractDataKey::TreasuryAction(id.clone());
+    env.storage().persistent().set(&action_key, action);
+    env.storage().persistent().extend_ttl(
+        &action_key,
+        TREASURY_TTL_EXTENSION,
+        TREASURY_TTL_EXTENSION,
+    );
+}
+
+// Fails while AGAINST voters can still rage-quit a passed treasury proposal
+pub(crate) fn require_exit_window_closed(
+    env: &Env,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<(), TokenGatedVoteContractErrors> {
+    if read_action(env, id).is_some()
+        && env.ledger().timestamp() <= proposal.finalized_at.saturating_add(RAGE_QUIT_WINDOW)
+    {
+        return Err(TokenGatedVoteContractErrors::ExitWindowOpen);
+    }
+    Ok(())
+}
+
+// Has the treasury pay out the action of a treasury proposal, if the proposal has one, within
+// its spending cap; streamed grants are escrowed by the treasury to vest instead
+pub(crate) fn execute(env: &Env, id: &Symbol) -> Result<(), TokenGatedVoteContractErrors> {
+    let Some(action) = read_action(env, id) else {
+        return Ok(());
+    };
+    let treasury = TreasuryClient::new(env, &require_address(env)?);
+    if action.duration > 0 {
+        treasury.open_stream(id, &action.recipient, &action.amount, &action.duration);
+        return Ok(());
+    }
+    treasury.pay(id, &action.recipient, &action.amount);
+    Ok(())
+}
+
+// Has the treasury stop a streamed grant, returning the unvested remainder to its balance
+pub(crate) fn cancel_stream(env: &Env, id: &Symbol) -> Result<i128, TokenGatedVoteContractErrors> {
+    let treasury = require_address(env)?;
+    Ok(TreasuryClient::new(env, &treasury).cancel_stream(id))
+}
+
+// Checks that a member voted AGAINST a passed treasury proposal still inside its exit window,
+// then has the treasury burn their shares and pay out their pro-rata share
+pub(crate) fn rage_quit(
+    env: &Env,
+    user: &Address,
+    id: &Symbol,
+    proposal: &TokenGatedVoteProposalData,
+) -> Result<i128, Error> {
+    if read_action(env, id).is_none() {
+        return Err(TokenGatedVoteModuleErrors::NotTreasuryProposal.into());
+    }
+    if !matches!(
+        proposal.status,
+        TokenGatedVoteProposalStatus::Succeeded
+            | TokenGatedVoteProposalStatus::Queued
+            | TokenGatedVoteProposalStatus::ReadyToExecute
+    ) || env.ledger().timestamp() > proposal.finalized_at.saturating_add(RAGE_QUIT_WINDOW)
+    {
+        return Err(TokenGatedVoteModuleErrors::RageQuitClosed.into());
+    }
+
+    let voted_against = env
+        .storage()
+        .persistent()
+        .get::<TokenGatedVoteContractDataKey, TokenGatedVoteRecord>(
+            &TokenGatedVoteContractDataKey::Vote(user.clone(), id.clone()),
+        )
+        .is_some_and(|record| record.choice == VOTE_AGAINST);
+    if !voted_against {
+        return Err(TokenGatedVoteModuleErrors::RageQuitNotEligible.into());
+    }
+
+    let treasury = require_address(env)?;
+    Ok(TreasuryClient::new(env, &treasury).rage_quit(user, id))
+}
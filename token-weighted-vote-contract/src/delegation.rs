@@ -0,0 +1,443 @@
+use governance_core::events;
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::{
+    TokenWeightedVoteContract, TokenWeightedVoteContractDataKey, TokenWeightedVoteContractErrors,
+    TokenWeightedVoteContribution, TokenWeightedVoteDelegation, TokenWeightedVoteDelegationEvent,
+    WeightCalculatorClient,
+};
+
+// --- Allocation Scale ---
+const BPS_SCALE: u32 = 10_000; // Basis points, 10_000 = 100% of a holder's power
+
+// --- Delegation Limits ---
+pub(crate) const MAX_DELEGATORS: u32 = 50; // Most delegators weighed into one delegatee's vote
+
+// Delegated shares cast on a proposal, keyed by the delegator they came from
+pub(crate) type Contributions = Map<Address, Vec<TokenWeightedVoteContribution>>;
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const DELEGATION_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Returns whether a delegation is still in force at the given ledger time
+pub(crate) fn is_active(delegation: &TokenWeightedVoteDelegation, ledger_time: u64) -> bool {
+    match delegation.expires_at {
+        Some(expires_at) => ledger_time < expires_at,
+        None => true,
+    }
+}
+
+// Publishes DELEGATION/SET or DELEGATION/REVOKED with the delegator as subject
+fn publish(
+    env: &Env,
+    action: &'static str,
+    delegator: &Address,
+    delegation: TokenWeightedVoteDelegation,
+) {
+    events::publish(
+        env,
+        "DELEGATION",
+        action,
+        delegator.clone(),
+        TokenWeightedVoteDelegationEvent {
+            delegator: delegator.clone(),
+            delegatee: delegation.delegatee,
+            bps: delegation.bps,
+            expires_at: delegation.expires_at,
+        },
+    );
+}
+
+// Loads the stored allocations of a holder, expired or not
+pub(crate) fn read_delegations(env: &Env, delegator: &Address) -> Vec<TokenWeightedVoteDelegation> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::Delegation(
+            delegator.clone(),
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Persists the allocations of a holder, dropping the entry once empty
+fn write_delegations(
+    env: &Env,
+    delegator: &Address,
+    delegations: &Vec<TokenWeightedVoteDelegation>,
+) {
+    let delegation_key = TokenWeightedVoteContractDataKey::Delegation(delegator.clone());
+    if delegations.is_empty() {
+        env.storage().persistent().remove(&delegation_key);
+        return;
+    }
+    env.storage().persistent().set(&delegation_key, delegations);
+    env.storage().persistent().extend_ttl(
+        &delegation_key,
+        DELEGATION_TTL_EXTENSION,
+        DELEGATION_TTL_EXTENSION,
+    );
+}
+
+// Finds the position of the allocation a holder made to a delegatee
+fn position_of(delegations: &Vec<TokenWeightedVoteDelegation>, delegatee: &Address) -> Option<u32> {
+    delegations
+        .iter()
+        .position(|delegation| &delegation.delegatee == delegatee)
+        .map(|position| position as u32)
+}
+
+// Loads the addresses that delegated to a delegatee
+pub(crate) fn read_delegators(env: &Env, delegatee: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::Delegators(
+            delegatee.clone(),
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+// Persists the inbound delegator list of a delegatee, dropping the entry once empty
+fn write_delegators(env: &Env, delegatee: &Address, delegators: &Vec<Address>) {
+    let delegators_key = TokenWeightedVoteContractDataKey::Delegators(delegatee.clone());
+    if delegators.is_empty() {
+        env.storage().persistent().remove(&delegators_key);
+        return;
+    }
+    env.storage().persistent().set(&delegators_key, delegators);
+    env.storage().persistent().extend_ttl(
+        &delegators_key,
+        DELEGATION_TTL_EXTENSION,
+        DELEGATION_TTL_EXTENSION,
+    );
+}
+
+// Removes a delegator from its delegatee's inbound list
+fn unlink(env: &Env, delegatee: &Address, delegator: &Address) {
+    let mut delegators = read_delegators(env, delegatee);
+    if let Some(position) = delegators.first_index_of(delegator) {
+        delegators.remove(position);
+        write_delegators(env, delegatee, &delegators);
+    }
+}
+
+// Drops the expired allocations of a holder, unlinking each from its delegatee and publishing
+// it as revoked, and returns the allocations still in force
+fn drop_expired(
+    env: &Env,
+    delegator: &Address,
+    ledger_time: u64,
+) -> Vec<TokenWeightedVoteDelegation> {
+    let mut active = Vec::new(env);
+    for delegation in read_delegations(env, delegator).iter() {
+        if is_active(&delegation, ledger_time) {
+            active.push_back(delegation);
+        } else {
+            unlink(env, &delegation.delegatee, delegator);
+            publish(env, "REVOKED", delegator, delegation);
+        }
+    }
+    active
+}
+
+// Sums the active allocations of a holder, skipping the given delegatee if any
+fn active_bps(
+    delegations: &Vec<TokenWeightedVoteDelegation>,
+    ledger_time: u64,
+    skip: Option<&Address>,
+) -> u32 {
+    delegations
+        .iter()
+        .filter(|delegation| is_active(delegation, ledger_time))
+        .filter(|delegation| skip != Some(&delegation.delegatee))
+        .map(|delegation| delegation.bps)
+        .sum()
+}
+
+// Records an allocation to a delegatee, replacing any previous share given to it and dropping
+// the holder's expired allocations so its stored list only grows with active ones
+pub(crate) fn set(
+    env: &Env,
+    delegator: &Address,
+    delegatee: &Address,
+    bps: u32,
+    expires_at: Option<u64>,
+) -> Result<(), TokenWeightedVoteContractErrors> {
+    let ledger_time = env.ledger().timestamp();
+    if delegator == delegatee || bps == 0 || bps > BPS_SCALE {
+        return Err(TokenWeightedVoteContractErrors::InvalidDelegation);
+    }
+    if let Some(expires_at) = expires_at {
+        if expires_at <= ledger_time {
+            return Err(TokenWeightedVoteContractErrors::InvalidDelegation);
+        }
+    }
+
+    let mut delegations = drop_expired(env, delegator, ledger_time);
+    if active_bps(&delegations, ledger_time, Some(delegatee)) + bps > BPS_SCALE {
+        return Err(TokenWeightedVoteContractErrors::DelegationExceedsPower);
+    }
+
+    let position = position_of(&delegations, delegatee);
+    let mut delegators = read_delegators(env, delegatee);
+    if position.is_none() && delegators.len() >= MAX_DELEGATORS {
+        // Expired allocations free their slots before a full delegatee turns holders away
+        prune_expired(env, delegatee);
+        delegators = read_delegators(env, delegatee);
+        if delegators.len() >= MAX_DELEGATORS {
+            return Err(TokenWeightedVoteContractErrors::TooManyDelegators);
+        }
+    }
+
+    let delegation = TokenWeightedVoteDelegation {
+        delegatee: delegatee.clone(),
+        bps,
+        expires_at,
+    };
+    match position {
+        Some(position) => delegations.set(position, delegation.clone()),
+        None => {
+            delegations.push_back(delegation.clone());
+            delegators.push_back(delegator.clone());
+            write_delegators(env, delegatee, &delegators);
+        }
+    }
+    write_delegations(env, delegator, &delegations);
+
+    publish(env, "SET", delegator, delegation);
+    Ok(())
+}
+
+// Deletes the allocation a holder made to a delegatee
+pub(crate) fn clear(
+    env: &Env,
+    delegator: &Address,
+    delegatee: &Address,
+) -> Result<(), TokenWeightedVoteContractErrors> {
+    let mut delegations = read_delegations(env, delegator);
+    let position = position_of(&delegations, delegatee)
+        .ok_or(TokenWeightedVoteContractErrors::DelegationNotFound)?;
+    let delegation = delegations.get_unchecked(position);
+    delegations.remove(position);
+    write_delegations(env, delegator, &delegations);
+    unlink(env, delegatee, delegator);
+
+    publish(env, "REVOKED", delegator, delegation);
+    Ok(())
+}
+
+// Drops expired allocations pointing at a delegatee, publishing each as revoked, and returns
+// how many were removed
+pub(crate) fn prune_expired(env: &Env, delegatee: &Address) -> u32 {
+    let ledger_time = env.ledger().timestamp();
+    let delegators = read_delegators(env, delegatee);
+    let mut remaining = Vec::new(env);
+    let mut pruned = 0;
+
+    for delegator in delegators.iter() {
+        let mut delegations = read_delegations(env, &delegator);
+        match position_of(&delegations, delegatee) {
+            Some(position) => {
+                let delegation = delegations.get_unchecked(position);
+                if is_active(&delegation, ledger_time) {
+                    remaining.push_back(delegator);
+                } else {
+                    delegations.remove(position);
+                    write_delegations(env, &delegator, &delegations);
+                    publish(env, "REVOKED", &delegator, delegation);
+                    pruned += 1;
+                }
+            }
+            None => pruned += 1,
+        }
+    }
+
+    if pruned > 0 {
+        write_delegators(env, delegatee, &remaining);
+    }
+    pruned
+}
+
+// Loads the allocations of a holder still in force, skipping expired ones not yet pruned
+pub(crate) fn read_active_delegations(
+    env: &Env,
+    delegator: &Address,
+) -> Vec<TokenWeightedVoteDelegation> {
+    let ledger_time = env.ledger().timestamp();
+    let mut active = Vec::new(env);
+    for delegation in read_delegations(env, delegator).iter() {
+        if is_active(&delegation, ledger_time) {
+            active.push_back(delegation);
+        }
+    }
+    active
+}
+
+// Sums the share of every active delegator's current strategy weight a delegatee holds,
+// with weights normalized from `decimals` places and scaled as votes count them
+pub(crate) fn delegated_power(
+    env: &Env,
+    delegatee: &Address,
+    strategy_client: &WeightCalculatorClient,
+    decimals: u32,
+) -> i128 {
+    let ledger_time = env.ledger().timestamp();
+    let mut power: i128 = 0;
+    for delegator in read_delegators(env, delegatee).iter() {
+        let delegations = read_delegations(env, &delegator);
+        let delegation = match position_of(&delegations, delegatee) {
+            Some(position) => delegations.get_unchecked(position),
+            None => continue,
+        };
+        if !is_active(&delegation, ledger_time) {
+            continue;
+        }
+        let weight = TokenWeightedVoteContract::strategy_weight(
+            env,
+            strategy_client,
+            &delegator,
+            ledger_time,
+            decimals,
+        );
+        power = power.saturating_add(share_of(weight, delegation.bps));
+    }
+    power
+}
+
+// Loads the delegated shares cast on a proposal, keyed by the delegator they came from
+pub(crate) fn read_contributions(env: &Env, id: &Symbol) -> Contributions {
+    env.storage()
+        .temporary()
+        .get(&TokenWeightedVoteContractDataKey::Contributions(id.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+// Keeps the delegated shares of a proposal in temporary storage until shortly after voting
+// ends, since they are only needed to withdraw shares when a delegator votes directly
+pub(crate) fn write_contributions(
+    env: &Env,
+    id: &Symbol,
+    contributions: &Contributions,
+    window_ttl: u32,
+) {
+    let contributions_key = TokenWeightedVoteContractDataKey::Contributions(id.clone());
+    env.storage()
+        .temporary()
+        .set(&contributions_key, contributions);
+    env.storage()
+        .temporary()
+        .extend_ttl(&contributions_key, window_ttl, window_ttl);
+}
+
+// Loads the delegators that overrode their delegatees by voting directly on a proposal
+pub(crate) fn read_overrides(env: &Env, id: &Symbol) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::Overrides(id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Scales a full strategy weight down to a basis-point share
+fn share_of(weight: i128, bps: u32) -> i128 {
+    weight.max(0).saturating_mul(bps as i128) / BPS_SCALE as i128
+}
+
+// Returns whether an address has cast a direct vote on a proposal
+fn has_voted(env: &Env, user: &Address, id: &Symbol) -> bool {
+    env.storage()
+        .persistent()
+        .has(&TokenWeightedVoteContractDataKey::Vote(
+            user.clone(),
+            id.clone(),
+        ))
+}
+
+// Sums the delegated share of every active delegator that has not voted directly, with strategy
+// weights normalized from `decimals` places, returning the weight and, if any share was cast,
+// the contributions updated with each share counted for the delegatee's choice
+pub(crate) fn delegated_weight(
+    env: &Env,
+    delegatee: &Address,
+    id: &Symbol,
+    choice: &Symbol,
+    strategy_client: &WeightCalculatorClient,
+    snapshot_time: u64,
+    decimals: u32,
+) -> (i128, Option<Contributions>) {
+    let ledger_time = env.ledger().timestamp();
+    let mut contributions = read_contributions(env, id);
+    let mut delegated_weight: i128 = 0;
+    let mut updated = false;
+
+    for delegator in read_delegators(env, delegatee).iter() {
+        let delegations = read_delegations(env, &delegator);
+        let delegation = match position_of(&delegations, delegatee) {
+            Some(position) => delegations.get_unchecked(position),
+            None => continue,
+        };
+        if !is_active(&delegation, ledger_time) || has_voted(env, &delegator, id) {
+            continue;
+        }
+
+        let mut cast = contributions
+            .get(delegator.clone())
+            .unwrap_or(Vec::new(env));
+        let cast_bps: u32 = cast.iter().map(|contribution| contribution.bps).sum();
+        let share = delegation.bps.min(BPS_SCALE.saturating_sub(cast_bps));
+        if share == 0 {
+            continue;
+        }
+
+        let weight = share_of(
+            TokenWeightedVoteContract::strategy_weight(
+                env,
+                strategy_client,
+                &delegator,
+                snapshot_time,
+                decimals,
+            ),
+            share,
+        );
+        delegated_weight = delegated_weight.saturating_add(weight);
+        cast.push_back(TokenWeightedVoteContribution {
+            delegatee: delegatee.clone(),
+            choice: choice.clone(),
+            bps: share,
+            weight,
+        });
+        contributions.set(delegator, cast);
+        updated = true;
+    }
+
+    (delegated_weight, updated.then_some(contributions))
+}
+
+// Removes the shares delegatees already cast for a directly voting holder, returning them
+// so their weight can be taken back out of the tallies, and records the override
+pub(crate) fn withdraw_overridden(
+    env: &Env,
+    user: &Address,
+    id: &Symbol,
+    proposal_ttl: u32,
+    window_ttl: u32,
+) -> Vec<TokenWeightedVoteContribution> {
+    let ledger_time = env.ledger().timestamp();
+    let mut contributions = read_contributions(env, id);
+    let withdrawn = contributions.get(user.clone()).unwrap_or(Vec::new(env));
+    if !withdrawn.is_empty() {
+        contributions.remove(user.clone());
+        write_contributions(env, id, &contributions, window_ttl);
+    }
+
+    let delegating = read_delegations(env, user)
+        .iter()
+        .any(|delegation| is_active(&delegation, ledger_time));
+    if delegating || !withdrawn.is_empty() {
+        let overrides_key = TokenWeightedVoteContractDataKey::Overrides(id.clone());
+        let mut overrides = read_overrides(env, id);
+        overrides.push_back(user.clone());
+        env.storage().persistent().set(&overrides_key, &overrides);
+        env.storage()
+            .persistent()
+            .extend_ttl(&overrides_key, proposal_ttl, proposal_ttl);
+    }
+    withdrawn
+}
@@ -0,0 +1,1083 @@
+#![no_std]
+
+use governance_core::{
+    events, GovernanceProposal, GovernanceStatus, InvalidChoice, ProposalTimeError, SupplyClient,
+    Tally, TallyStrategy, TokenSource, VotingWindow, WeightScaling, CANONICAL_DECIMALS,
+    MAX_DECIMALS, SIMPLE_MAJORITY, THRESHOLD_SCALE,
+};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, BytesN, Env,
+    String, Symbol, Vec,
+};
+
+mod delegation;
+mod names;
+mod snapshots;
+mod splits;
+pub use pg_contracts_types::token_weighted_vote::{
+    TokenWeightedVoteContribution, TokenWeightedVoteDelegation, TokenWeightedVoteDelegationEvent,
+    TokenWeightedVoteProposalData, TokenWeightedVoteProposalStatus,
+    TokenWeightedVoteProposalSummary, TokenWeightedVoteQuorum,
+};
+
+// --- Proposal Duration Constraints (in seconds) ---
+const MAX_PROPOSAL_DURATION: u64 = 1292000; // ~15 days
+const MIN_PROPOSAL_DURATION: u64 = 432000; // ~5 days
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const PROPOSALS_TTL_EXTENSION: u32 = 2_100_000; // ~24 days
+const PROPOSAL_TTL_BUFFER: u32 = 604_800; // ~7 days
+const VOTE_TTL_EXTENSION: u32 = 1_600_000; // ~18.5 days
+const WINDOW_TTL_BUFFER: u32 = 86_400; // ~1 day past end_time for voting-window data
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 3] = [
+    governance_core::FEATURE_GOVERNANCE,
+    governance_core::FEATURE_DELEGATION,
+    governance_core::FEATURE_SNAPSHOTS,
+];
+
+// Cross-contract interface implemented by weighting strategy contracts
+#[contractclient(name = "WeightCalculatorClient")]
+pub trait WeightCalculator {
+    // Returns the voting weight of a user as of the given snapshot timestamp
+    fn calculate_weight(env: Env, user: Address, snapshot_time: u64) -> i128;
+}
+
+// Cross-contract interface of the reputation contract notified on participation
+#[contractclient(name = "ReputationClient")]
+pub trait ReputationInterface {
+    fn record_vote(env: Env, minter: Address, voter: Address);
+    fn record_proposal_passed(env: Env, minter: Address, proposer: Address);
+}
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum TokenWeightedVoteContractDataKey {
+    Admin,                  // Contract administrator address
+    Strategy,               // Weighting strategy contract address
+    WeightDecimals,         // Decimal places of the weights returned by the strategy
+    Scaling,                // Scaling applied to strategy weights, chosen at initialization
+    Quorum,                 // Quorum rule applied to new proposals
+    Reputation,             // Optional reputation contract notified on votes
+    Proposal(Symbol),       // Individual proposal data, keyed by its ID
+    Proposals,              // List of all proposal IDs
+    Vote(Address, Symbol),  // Choice of a direct vote per voter and proposal
+    Delegation(Address),    // Outbound allocations of a holder
+    Delegators(Address),    // Inbound delegator list of a delegatee
+    Contributions(Symbol),  // Delegated shares cast during voting, by delegator (temporary)
+    Overrides(Symbol),      // Delegators that voted directly on a proposal over their delegatees
+    Split(Address, Symbol), // Allocations of a split vote per voter and proposal
+    ProposalName(Symbol),   // Name a proposal was created with, keyed by its ID
+    NamedProposal(String),  // ID of the proposal created under a name
+    NextProposalIndex,      // Index the next named proposal's PROP<index> ID starts from
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 200-299 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenWeightedVoteContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    ProposalAlreadyExists = 3,      // A proposal with this ID already exists
+    ProposalNotFound = 4,           // The specified proposal does not exist
+    UserAlreadyVoted = 5,           // User has already voted on this proposal
+    UserCannotVote = 6,             // User has no voting weight under the strategy
+    VotingNotActive = 7,            // The proposal is not currently active for voting
+    InvalidChoice = 8,              // The provided vote choice is invalid
+    StartTimeAfterEnd = 9,          // Proposal start time occurs after end time
+    StartTimeInPast = 10,           // Proposal start time is before current timestamp
+    DurationTooLong = 11,           // Proposal duration exceeds maximum allowed period
+    DurationTooShort = 12,          // Proposal duration is below minimum required period
+    VotingNotEnded = 14,            // Voting period has not ended yet
+    InvalidProof = 16,              // The proof does not match the proposal's eligibility root
+    ProposalsRestricted = 17,       // Only the admin can create proposals
+    InvalidDelegation = 213,        // Self-delegation, share outside 1..=10_000 bps, or past expiry
+    DelegationNotFound = 214,       // The holder has no delegation to that delegatee
+    DelegationExceedsPower = 216,   // Allocations would exceed 100% of the holder's power
+    ProofRequired = 218,            // Snapshot proposals only accept votes with a Merkle proof
+    NotSnapshotProposal = 219,      // The proposal has no eligibility root to prove against
+    InvalidDecimals = 221,          // Weight precision exceeds the supported maximum
+    InvalidQuorum = 222,            // Negative quorum, share above 100%, or no supply token
+    InvalidSplit = 223,             // Empty, zero, duplicate, or over 100% split allocations
+    InvalidProposalName = 224,      // Name is empty, too long, or has unsupported characters
+    TooManyDelegators = 225,        // The delegatee already carries the most delegators allowed
+}
+
+// Maps shared proposal time validation failures onto this contract's error codes
+impl From<ProposalTimeError> for TokenWeightedVoteContractErrors {
+    fn from(error: ProposalTimeError) -> Self {
+        match error {
+            ProposalTimeError::StartTimeAfterEnd => Self::StartTimeAfterEnd,
+            ProposalTimeError::StartTimeInPast => Self::StartTimeInPast,
+            ProposalTimeError::DurationTooLong => Self::DurationTooLong,
+            ProposalTimeError::DurationTooShort => Self::DurationTooShort,
+        }
+    }
+}
+
+// Maps an unrecognized vote choice onto this contract's error code
+impl From<InvalidChoice> for TokenWeightedVoteContractErrors {
+    fn from(_: InvalidChoice) -> Self {
+        Self::InvalidChoice
+    }
+}
+
+#[contract]
+pub struct TokenWeightedVoteContract;
+
+#[contractimpl]
+impl TokenWeightedVoteContract {
+    // --- Helper Functions ---
+
+    // Derives TTL extension for a proposal based on current ledger time
+    fn calculate_proposal_ttl(env: &Env, proposal_end_time: u64) -> u32 {
+        governance_core::proposal_ttl(
+            env.ledger().timestamp(),
+            proposal_end_time,
+            PROPOSAL_TTL_BUFFER,
+            PROPOSALS_TTL_EXTENSION,
+        )
+    }
+
+    // Derives TTL extension for temporary data that only matters while voting is open
+    fn calculate_window_ttl(env: &Env, proposal_end_time: u64) -> u32 {
+        governance_core::proposal_ttl(
+            env.ledger().timestamp(),
+            proposal_end_time,
+            WINDOW_TTL_BUFFER,
+            WINDOW_TTL_BUFFER,
+        )
+    }
+
+    // Computes proposal status relative to a ledger timestamp
+    fn compute_proposal_status(
+        ledger_time: u64,
+        proposal: &TokenWeightedVoteProposalData,
+    ) -> TokenWeightedVoteProposalStatus {
+        match governance_core::voting_window(ledger_time, proposal.start_time, proposal.end_time) {
+            VotingWindow::Pending => TokenWeightedVoteProposalStatus::Pending,
+            VotingWindow::Active => TokenWeightedVoteProposalStatus::Active,
+            VotingWindow::Ended => TokenWeightedVoteProposalStatus::Ended,
+        }
+    }
+
+    // Validates and stores a new proposal, appending it to the proposal index
+    fn insert_proposal(
+        env: &Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        eligibility_root: Option<BytesN<32>>,
+        proposer: &Address,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        let ledger_time = env.ledger().timestamp();
+        governance_core::validate_proposal_times(
+            ledger_time,
+            start_time,
+            end_time,
+            MIN_PROPOSAL_DURATION,
+            MAX_PROPOSAL_DURATION,
+        )?;
+
+        let proposal_key = TokenWeightedVoteContractDataKey::Proposal(id.clone());
+        if env.storage().persistent().has(&proposal_key) {
+            return Err(TokenWeightedVoteContractErrors::ProposalAlreadyExists);
+        }
+
+        let proposal = TokenWeightedVoteProposalData {
+            eligibility_root,
+            proposer: proposer.clone(),
+            description,
+            start_time,
+            end_time,
+            strategy: Self::read_strategy(env)?,
+            decimals: Self::read_weight_decimals(env),
+            quorum: Self::resolve_quorum(env, &Self::read_quorum(env)),
+            total_for: 0,
+            total_against: 0,
+            total_abstain: 0,
+            finalized_at: 0,
+        };
+        env.storage().persistent().set(&proposal_key, &proposal);
+
+        let proposal_ttl = Self::calculate_proposal_ttl(env, end_time);
+        env.storage()
+            .persistent()
+            .extend_ttl(&proposal_key, proposal_ttl, proposal_ttl);
+
+        let mut proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(env));
+        proposals.push_back(id.clone());
+        env.storage()
+            .persistent()
+            .set(&TokenWeightedVoteContractDataKey::Proposals, &proposals);
+
+        env.storage().persistent().extend_ttl(
+            &TokenWeightedVoteContractDataKey::Proposals,
+            PROPOSALS_TTL_EXTENSION,
+            PROPOSALS_TTL_EXTENSION,
+        );
+
+        events::proposal_created(env, &id, proposer, start_time, end_time);
+        Ok(())
+    }
+
+    // Computes the interface status, counting ended proposals by simple FOR majority
+    fn governance_status(env: &Env, proposal: &TokenWeightedVoteProposalData) -> GovernanceStatus {
+        let ledger_time = env.ledger().timestamp();
+        match governance_core::voting_window(ledger_time, proposal.start_time, proposal.end_time) {
+            VotingWindow::Pending => GovernanceStatus::Pending,
+            VotingWindow::Active => GovernanceStatus::Active,
+            VotingWindow::Ended => Self::tally(proposal)
+                .resolve(env, proposal.quorum, TallyStrategy::SimpleMajority)
+                .into(),
+        }
+    }
+
+    // Reads the per-choice totals of a proposal as a shared tally
+    fn tally(proposal: &TokenWeightedVoteProposalData) -> Tally {
+        Tally {
+            total_for: proposal.total_for,
+            total_against: proposal.total_against,
+            total_abstain: proposal.total_abstain,
+        }
+    }
+
+    // Adds (or with a negative weight, removes) weight on the tally of a vote choice
+    fn add_to_tally(
+        proposal: &mut TokenWeightedVoteProposalData,
+        choice: &Symbol,
+        weight: i128,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        let mut tally = Self::tally(proposal);
+        tally.record(choice, weight)?;
+        proposal.total_for = tally.total_for;
+        proposal.total_against = tally.total_against;
+        proposal.total_abstain = tally.total_abstain;
+        Ok(())
+    }
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, TokenWeightedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenWeightedVoteContractDataKey::Admin)
+            .ok_or(TokenWeightedVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the current weighting strategy address from instance storage
+    fn read_strategy(env: &Env) -> Result<Address, TokenWeightedVoteContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TokenWeightedVoteContractDataKey::Strategy)
+            .ok_or(TokenWeightedVoteContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the scaling applied to strategy weights
+    fn read_scaling(env: &Env) -> WeightScaling {
+        env.storage()
+            .instance()
+            .get(&TokenWeightedVoteContractDataKey::Scaling)
+            .unwrap_or(WeightScaling::Linear)
+    }
+
+    // Resolves a holder's weight as counted: the strategy weight at the snapshot time,
+    // normalized to 7 decimals and then scaled
+    fn strategy_weight(
+        env: &Env,
+        strategy_client: &WeightCalculatorClient,
+        user: &Address,
+        snapshot_time: u64,
+        decimals: u32,
+    ) -> i128 {
+        Self::read_scaling(env).apply(governance_core::normalize(
+            strategy_client
+                .calculate_weight(user, &snapshot_time)
+                .max(0),
+            decimals,
+        ))
+    }
+
+    // Loads the decimal places of strategy weights, defaulting to Stellar asset precision
+    fn read_weight_decimals(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&TokenWeightedVoteContractDataKey::WeightDecimals)
+            .unwrap_or(CANONICAL_DECIMALS)
+    }
+
+    // Loads the quorum rule, defaulting to no quorum
+    fn read_quorum(env: &Env) -> TokenWeightedVoteQuorum {
+        env.storage()
+            .instance()
+            .get(&TokenWeightedVoteContractDataKey::Quorum)
+            .unwrap_or(TokenWeightedVoteQuorum {
+                absolute: 0,
+                bps: 0,
+                supply: None,
+            })
+    }
+
+    // Resolves a quorum rule into an absolute turnout, the larger of the fixed amount and the
+    // share of the supply token's current total supply normalized to 7 decimals
+    fn resolve_quorum(env: &Env, rule: &TokenWeightedVoteQuorum) -> i128 {
+        match &rule.supply {
+            Some(supply) if rule.bps > 0 => {
+                let total_supply = governance_core::normalize(
+                    SupplyClient::new(env, supply).total_supply(),
+                    TokenSource::Custom(supply.clone()).decimals(env),
+                );
+                rule.absolute
+                    .max(governance_core::supply_quorum(total_supply, rule.bps))
+            }
+            _ => rule.absolute,
+        }
+    }
+
+    // Validates a weighted ballot without mutating state, returning the proposal, the weight it
+    // would count, and the delegated contributions it would record; snapshot proposals take the
+    // voter's weight from a Merkle proof instead of the weighting strategy
+    fn check_vote(
+        env: &Env,
+        user: &Address,
+        id: &Symbol,
+        choice: &Symbol,
+        snapshot: Option<(i128, Vec<BytesN<32>>)>,
+    ) -> Result<
+        (
+            TokenWeightedVoteProposalData,
+            i128,
+            Option<delegation::Contributions>,
+        ),
+        TokenWeightedVoteContractErrors,
+    > {
+        let proposal: TokenWeightedVoteProposalData = env
+            .storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Proposal(id.clone()))
+            .ok_or(TokenWeightedVoteContractErrors::ProposalNotFound)?;
+
+        let ledger_time = env.ledger().timestamp();
+        if governance_core::voting_window(ledger_time, proposal.start_time, proposal.end_time)
+            != VotingWindow::Active
+        {
+            return Err(TokenWeightedVoteContractErrors::VotingNotActive);
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&TokenWeightedVoteContractDataKey::Vote(
+                user.clone(),
+                id.clone(),
+            ))
+        {
+            return Err(TokenWeightedVoteContractErrors::UserAlreadyVoted);
+        }
+
+        let (weight, contributions) = match (&proposal.eligibility_root, snapshot) {
+            // Snapshot proposals count the proven weight; delegations do not apply
+            (Some(root), Some((weight, proof))) => {
+                snapshots::verify(env, root, user, weight, &proof)?;
+                (weight, None)
+            }
+            (Some(_), None) => return Err(TokenWeightedVoteContractErrors::ProofRequired),
+            (None, Some(_)) => return Err(TokenWeightedVoteContractErrors::NotSnapshotProposal),
+            (None, None) => {
+                let strategy_client = WeightCalculatorClient::new(env, &proposal.strategy);
+                let own_weight = Self::strategy_weight(
+                    env,
+                    &strategy_client,
+                    user,
+                    proposal.start_time,
+                    proposal.decimals,
+                );
+                let (delegated_weight, contributions) = delegation::delegated_weight(
+                    env,
+                    user,
+                    id,
+                    choice,
+                    &strategy_client,
+                    proposal.start_time,
+                    proposal.decimals,
+                );
+                (own_weight.saturating_add(delegated_weight), contributions)
+            }
+        };
+        if weight <= 0 {
+            return Err(TokenWeightedVoteContractErrors::UserCannotVote);
+        }
+        Tally::default().record(choice, weight)?;
+        Ok((proposal, weight, contributions))
+    }
+
+    // Tallies a weighted ballot once the caller has been authenticated; a single-choice vote is
+    // one allocation of the full power, a split vote spreads it over several choices
+    fn cast_vote(
+        env: &Env,
+        user: Address,
+        id: Symbol,
+        allocations: Vec<(Symbol, u32)>,
+        snapshot: Option<(i128, Vec<BytesN<32>>)>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        let choice = allocations.get_unchecked(0).0;
+        let (mut proposal, weight, contributions) =
+            Self::check_vote(env, &user, &id, &choice, snapshot)?;
+        let (totals, contributions) =
+            splits::allocate(env, &user, weight, contributions, &allocations);
+        let proposal_key = TokenWeightedVoteContractDataKey::Proposal(id.clone());
+        let vote_key = TokenWeightedVoteContractDataKey::Vote(user.clone(), id.clone());
+
+        let proposal_ttl = Self::calculate_proposal_ttl(env, proposal.end_time);
+        let window_ttl = Self::calculate_window_ttl(env, proposal.end_time);
+        if let Some(contributions) = contributions {
+            delegation::write_contributions(env, &id, &contributions, window_ttl);
+        }
+
+        // A direct vote overrides the delegatees: their share of this voter moves back out
+        if proposal.eligibility_root.is_none() {
+            let withdrawn =
+                delegation::withdraw_overridden(env, &user, &id, proposal_ttl, window_ttl);
+            for contribution in withdrawn.iter() {
+                Self::add_to_tally(&mut proposal, &contribution.choice, -contribution.weight)?;
+            }
+        }
+        for (choice, total) in totals.iter() {
+            Self::add_to_tally(&mut proposal, &choice, total)?;
+        }
+
+        let whole = splits::is_whole(&allocations);
+        env.storage().persistent().set(&proposal_key, &proposal);
+        if whole {
+            env.storage().persistent().set(&vote_key, &choice);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&vote_key, &splits::SPLIT_CHOICE);
+            splits::write(env, &user, &id, &allocations, VOTE_TTL_EXTENSION);
+        }
+
+        env.storage()
+            .persistent()
+            .extend_ttl(&proposal_key, proposal_ttl, proposal_ttl);
+
+        env.storage()
+            .persistent()
+            .extend_ttl(&vote_key, VOTE_TTL_EXTENSION, VOTE_TTL_EXTENSION);
+
+        if let Some(reputation) = env
+            .storage()
+            .instance()
+            .get::<TokenWeightedVoteContractDataKey, Address>(
+                &TokenWeightedVoteContractDataKey::Reputation,
+            )
+        {
+            ReputationClient::new(env, &reputation)
+                .record_vote(&env.current_contract_address(), &user);
+        }
+
+        if whole {
+            events::vote_cast(env, &id, &user, &choice, weight);
+        } else {
+            events::publish(env, events::VOTE, "SPLIT", id.clone(), (user, totals));
+        }
+        Ok(())
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin, weighting strategy, and the scaling applied to its weights
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        strategy: Address,
+        scaling: WeightScaling,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&TokenWeightedVoteContractDataKey::Admin)
+        {
+            return Err(TokenWeightedVoteContractErrors::ContractAlreadyInitialized);
+        }
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Strategy, &strategy);
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Scaling, &scaling);
+        Ok(())
+    }
+
+    // Creates a proposal after validating timing and uniqueness (admin only)
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        proposer.require_auth();
+        if proposer != Self::read_admin(&env)? {
+            return Err(TokenWeightedVoteContractErrors::ProposalsRestricted);
+        }
+        Self::insert_proposal(&env, id, description, start_time, end_time, None, &proposer)
+    }
+
+    // Creates a proposal identified by a name longer than a short symbol allows, such as
+    // Q3-2025-BUDGET-REALLOCATION, under the next sequential ID and returns that ID, which it is
+    // voted on under (admin only)
+    pub fn create_named_proposal(
+        env: Env,
+        proposer: Address,
+        name: String,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Symbol, TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let id = names::next_id(&env, &name)?;
+        Self::create_proposal(
+            env.clone(),
+            proposer,
+            id.clone(),
+            description,
+            start_time,
+            end_time,
+        )?;
+        names::record(&env, &id, &name);
+        Ok(id)
+    }
+
+    // Creates a proposal whose eligibility root replaces strategy weights with an off-chain
+    // snapshot of (voter, weight) entries (admin only)
+    pub fn create_snapshot_proposal(
+        env: Env,
+        id: Symbol,
+        description: String,
+        start_time: u64,
+        end_time: u64,
+        eligibility_root: BytesN<32>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+        Self::insert_proposal(
+            &env,
+            id,
+            description,
+            start_time,
+            end_time,
+            Some(eligibility_root),
+            &admin,
+        )
+    }
+
+    // Records a user's weighted vote on an active proposal
+    pub fn vote(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        Self::cast_vote(&env, user, id, splits::whole(&env, choice), None)
+    }
+
+    // Records a weighted vote that splits the user's power across choices, each allocation a
+    // share in basis points; shares may total less than 10_000, leaving the rest uncast
+    pub fn vote_split(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        allocations: Vec<(Symbol, u32)>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+        splits::validate(&allocations)?;
+
+        Self::cast_vote(&env, user, id, allocations, None)
+    }
+
+    // Records a vote on a snapshot proposal, proving the voter's weight against its root
+    pub fn vote_with_proof(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+        weight: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        user.require_auth();
+
+        Self::cast_vote(
+            &env,
+            user,
+            id,
+            splits::whole(&env, choice),
+            Some((weight, proof)),
+        )
+    }
+
+    // Resolves the outcome by simple FOR majority once the voting window has closed, recording it
+    // and crediting a passed proposal to its proposer's reputation only on the first call
+    pub fn finalize(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceStatus, TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut proposal = Self::get_proposal_details(env.clone(), id.clone())?;
+        let ledger_time = env.ledger().timestamp();
+        if ledger_time <= proposal.end_time {
+            return Err(TokenWeightedVoteContractErrors::VotingNotEnded);
+        }
+
+        // Tallies are frozen once voting ends, so a repeat call reports the recorded outcome
+        let status = Self::governance_status(&env, &proposal);
+        if proposal.finalized_at > 0 {
+            return Ok(status);
+        }
+
+        proposal.finalized_at = ledger_time;
+        let proposal_key = TokenWeightedVoteContractDataKey::Proposal(id.clone());
+        env.storage().persistent().set(&proposal_key, &proposal);
+        env.storage().persistent().extend_ttl(
+            &proposal_key,
+            PROPOSALS_TTL_EXTENSION,
+            PROPOSALS_TTL_EXTENSION,
+        );
+
+        events::proposal_finalized(
+            &env,
+            &id,
+            status,
+            &Self::tally(&proposal),
+            proposal.quorum,
+            SIMPLE_MAJORITY,
+        );
+
+        if status == GovernanceStatus::Succeeded {
+            if let Some(reputation) = env
+                .storage()
+                .instance()
+                .get::<TokenWeightedVoteContractDataKey, Address>(
+                    &TokenWeightedVoteContractDataKey::Reputation,
+                )
+            {
+                ReputationClient::new(&env, &reputation)
+                    .record_proposal_passed(&env.current_contract_address(), &proposal.proposer);
+            }
+        }
+        Ok(status)
+    }
+
+    // Delegates a basis-point share of the caller's power, optionally reverting at expires_at
+    pub fn delegate(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        bps: u32,
+        expires_at: Option<u64>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        delegator.require_auth();
+        delegation::set(&env, &delegator, &delegatee, bps, expires_at)
+    }
+
+    // Revokes the caller's allocation to a delegatee so that share returns immediately
+    pub fn undelegate(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        delegator.require_auth();
+        delegation::clear(&env, &delegator, &delegatee)
+    }
+
+    // Removes expired allocations pointing at a delegatee (permissionless cleanup)
+    pub fn prune_expired_delegations(env: Env, delegatee: Address) -> u32 {
+        governance_core::extend_instance_ttl(&env);
+
+        let pruned = delegation::prune_expired(&env, &delegatee);
+        if pruned > 0 {
+            events::publish(&env, "DELEGATION", "PRUNED", delegatee, pruned);
+        }
+        pruned
+    }
+
+    // Sets or clears the reputation contract notified on every accepted vote
+    pub fn set_reputation_contract(
+        env: Env,
+        reputation: Option<Address>,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        match reputation.clone() {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&TokenWeightedVoteContractDataKey::Reputation, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&TokenWeightedVoteContractDataKey::Reputation),
+        }
+
+        events::config_updated(&env, "REPUTATION", reputation);
+        Ok(())
+    }
+
+    // Replaces the weighting strategy used for proposals created from now on
+    pub fn set_strategy(
+        env: Env,
+        new_strategy: Address,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        Self::read_strategy(&env)?;
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Strategy, &new_strategy);
+
+        events::config_updated(&env, "STRATEGY", new_strategy);
+        Ok(())
+    }
+
+    // Sets the decimal places of the strategy's weights for proposals created from now on, so
+    // weights from tokens of any precision are normalized to 7 decimals and stay comparable
+    pub fn set_weight_decimals(
+        env: Env,
+        decimals: u32,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if decimals > MAX_DECIMALS {
+            return Err(TokenWeightedVoteContractErrors::InvalidDecimals);
+        }
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::WeightDecimals, &decimals);
+
+        events::config_updated(&env, "DECIMALS", decimals);
+        Ok(())
+    }
+
+    // Sets the quorum rule resolved into every proposal created from now on; a supply share
+    // is measured against the supply token's total supply at creation (admin only)
+    pub fn set_quorum(
+        env: Env,
+        quorum: TokenWeightedVoteQuorum,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if quorum.absolute < 0
+            || quorum.bps > THRESHOLD_SCALE
+            || (quorum.bps > 0 && quorum.supply.is_none())
+        {
+            return Err(TokenWeightedVoteContractErrors::InvalidQuorum);
+        }
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Quorum, &quorum);
+
+        events::config_updated(&env, "QUORUM", quorum);
+        Ok(())
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(
+        env: Env,
+        new_admin: Address,
+    ) -> Result<(), TokenWeightedVoteContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&TokenWeightedVoteContractDataKey::Admin, &new_admin);
+
+        events::admin_transferred(&env, &current_admin, &new_admin);
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the weighting strategy applied to newly created proposals
+    pub fn get_strategy(env: Env) -> Result<Address, TokenWeightedVoteContractErrors> {
+        Self::read_strategy(&env)
+    }
+
+    // Returns the quorum rule applied to newly created proposals
+    pub fn get_quorum(env: Env) -> TokenWeightedVoteQuorum {
+        Self::read_quorum(&env)
+    }
+
+    // Returns the decimal places of strategy weights applied to newly created proposals
+    pub fn get_weight_decimals(env: Env) -> u32 {
+        Self::read_weight_decimals(&env)
+    }
+
+    // Returns the scaling applied to strategy weights, fixed at initialization
+    pub fn get_weight_scaling(env: Env) -> WeightScaling {
+        Self::read_scaling(&env)
+    }
+
+    // Returns the stored allocations of a holder, including expired ones not yet pruned
+    pub fn get_delegations(env: Env, delegator: Address) -> Vec<TokenWeightedVoteDelegation> {
+        delegation::read_delegations(&env, &delegator)
+    }
+
+    // Returns the Merkle leaf of a snapshot entry, for building eligibility trees off-chain
+    pub fn get_snapshot_leaf(env: Env, voter: Address, weight: i128) -> BytesN<32> {
+        snapshots::hash_leaf(&env, &voter, weight)
+    }
+
+    // Returns the addresses that delegated their power to a delegatee
+    pub fn get_delegators(env: Env, delegatee: Address) -> Vec<Address> {
+        delegation::read_delegators(&env, &delegatee)
+    }
+
+    // Returns the allocations of a holder still in force, without expired ones not yet pruned
+    pub fn get_delegation(env: Env, user: Address) -> Vec<TokenWeightedVoteDelegation> {
+        delegation::read_active_delegations(&env, &user)
+    }
+
+    // Returns the voting power a delegatee currently receives from active delegations, as the
+    // delegated share of each delegator's weight under the active strategy; the delegatee's own
+    // power is not included, and a delegator voting directly on a proposal takes its share back
+    pub fn get_delegated_power(
+        env: Env,
+        delegate: Address,
+    ) -> Result<i128, TokenWeightedVoteContractErrors> {
+        let strategy = Self::read_strategy(&env)?;
+        let strategy_client = WeightCalculatorClient::new(&env, &strategy);
+        Ok(delegation::delegated_power(
+            &env,
+            &delegate,
+            &strategy_client,
+            Self::read_weight_decimals(&env),
+        ))
+    }
+
+    // Returns the delegators that overrode their delegatees on a proposal
+    pub fn get_overrides(env: Env, id: Symbol) -> Vec<Address> {
+        delegation::read_overrides(&env, &id)
+    }
+
+    // Returns summaries (id, description, status) for all proposals
+    pub fn get_governance_details(env: Env) -> Vec<TokenWeightedVoteProposalSummary> {
+        let proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(&env));
+        let mut summary = Vec::new(&env);
+
+        let ledger_time = env.ledger().timestamp();
+
+        for id in proposals.iter() {
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<TokenWeightedVoteContractDataKey, TokenWeightedVoteProposalData>(
+                    &TokenWeightedVoteContractDataKey::Proposal(id.clone()),
+                )
+            {
+                let status = Self::compute_proposal_status(ledger_time, &proposal);
+                summary.push_back(TokenWeightedVoteProposalSummary {
+                    id: id.clone(),
+                    description: proposal.description.clone(),
+                    status,
+                });
+            }
+        }
+        summary
+    }
+
+    // Returns full stored data for a single proposal
+    pub fn get_proposal_details(
+        env: Env,
+        id: Symbol,
+    ) -> Result<TokenWeightedVoteProposalData, TokenWeightedVoteContractErrors> {
+        let proposal: TokenWeightedVoteProposalData = env
+            .storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Proposal(id))
+            .ok_or(TokenWeightedVoteContractErrors::ProposalNotFound)?;
+        Ok(proposal)
+    }
+
+    // Returns full stored data for the proposal created under a name
+    pub fn get_proposal_by_name(
+        env: Env,
+        name: String,
+    ) -> Result<TokenWeightedVoteProposalData, TokenWeightedVoteContractErrors> {
+        names::validate(&name)?;
+        let id =
+            names::lookup(&env, &name).ok_or(TokenWeightedVoteContractErrors::ProposalNotFound)?;
+        Self::get_proposal_details(env, id)
+    }
+
+    // Returns the ID of the proposal created under a name, if any
+    pub fn get_named_proposal_id(env: Env, name: String) -> Option<Symbol> {
+        names::lookup(&env, &name)
+    }
+
+    // Returns the name a proposal was created with, if it was created by name
+    pub fn get_proposal_name(env: Env, id: Symbol) -> Option<String> {
+        names::read(&env, &id)
+    }
+
+    // Returns the current status of one proposal through the governance interface
+    pub fn get_status(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceStatus, TokenWeightedVoteContractErrors> {
+        let proposal = Self::get_proposal_details(env.clone(), id)?;
+        Ok(Self::governance_status(&env, &proposal))
+    }
+
+    // Returns one proposal in the shared governance interface view
+    pub fn get_proposal(
+        env: Env,
+        id: Symbol,
+    ) -> Result<GovernanceProposal, TokenWeightedVoteContractErrors> {
+        let proposal = Self::get_proposal_details(env.clone(), id.clone())?;
+        let status = Self::governance_status(&env, &proposal);
+        Ok(GovernanceProposal {
+            id,
+            description: proposal.description,
+            start_time: proposal.start_time,
+            end_time: proposal.end_time,
+            total_for: proposal.total_for,
+            total_against: proposal.total_against,
+            total_abstain: proposal.total_abstain,
+            status,
+        })
+    }
+
+    // Dry-runs a vote without mutating state, returning the weight it would count (including
+    // delegated shares) or the error it would fail with; snapshot proposals need a proof
+    pub fn simulate_vote(
+        env: Env,
+        user: Address,
+        id: Symbol,
+        choice: Symbol,
+    ) -> Result<i128, TokenWeightedVoteContractErrors> {
+        Self::check_vote(&env, &user, &id, &choice, None).map(|(_, weight, _)| weight)
+    }
+
+    // Returns whether a user has voted directly on a proposal
+    pub fn has_voted(env: Env, user: Address, id: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&TokenWeightedVoteContractDataKey::Vote(user, id))
+    }
+
+    // Returns the choice a user voted with on a proposal, if any
+    pub fn get_vote(env: Env, user: Address, id: Symbol) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Vote(user, id))
+    }
+
+    // Returns the allocations of a user's split vote on a proposal, if they split their power
+    pub fn get_vote_split(env: Env, user: Address, id: Symbol) -> Option<Vec<(Symbol, u32)>> {
+        splits::read(&env, &user, &id)
+    }
+
+    // Returns user's vote participation and current voting power per proposal
+    pub fn get_user_details(
+        env: Env,
+        user: Address,
+    ) -> Result<Vec<(Symbol, bool, i128)>, TokenWeightedVoteContractErrors> {
+        let proposals: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&TokenWeightedVoteContractDataKey::Proposals)
+            .unwrap_or(Vec::new(&env));
+
+        let strategy = Self::read_strategy(&env)?;
+        let strategy_client = WeightCalculatorClient::new(&env, &strategy);
+        let voting_power = Self::strategy_weight(
+            &env,
+            &strategy_client,
+            &user,
+            env.ledger().timestamp(),
+            Self::read_weight_decimals(&env),
+        );
+
+        let mut results = Vec::new(&env);
+        for id in proposals.iter() {
+            let has_voted =
+                env.storage()
+                    .persistent()
+                    .has(&TokenWeightedVoteContractDataKey::Vote(
+                        user.clone(),
+                        id.clone(),
+                    ));
+            results.push_back((id.clone(), has_voted, voting_power));
+        }
+        Ok(results)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
@@ -0,0 +1,118 @@
+use soroban_sdk::{Env, String, Symbol};
+
+use crate::{TokenWeightedVoteContractDataKey, TokenWeightedVoteContractErrors};
+
+// --- Proposal Names ---
+const MAX_NAME_LENGTH: u32 = 64; // Longest name a proposal can be created under
+const SEQUENTIAL_ID_PREFIX: &[u8] = b"PROP"; // Prefix of the IDs named proposals are stored under
+const FIRST_INDEX: u32 = 1; // Index of the first named proposal
+
+// Validates a proposal name: 1 to 64 letters, digits, underscores, and hyphens, e.g.
+// Q3-2025-BUDGET-REALLOCATION
+pub(crate) fn validate(name: &String) -> Result<(), TokenWeightedVoteContractErrors> {
+    let len = name.len();
+    if len == 0 || len > MAX_NAME_LENGTH {
+        return Err(TokenWeightedVoteContractErrors::InvalidProposalName);
+    }
+
+    let mut buffer = [0u8; MAX_NAME_LENGTH as usize];
+    let bytes = &mut buffer[..len as usize];
+    name.copy_into_slice(bytes);
+    if !bytes
+        .iter()
+        .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-'))
+    {
+        return Err(TokenWeightedVoteContractErrors::InvalidProposalName);
+    }
+    Ok(())
+}
+
+// Validates a new proposal name and reserves the next sequential ID for it, e.g. PROP7; names
+// are kept verbatim, so two names only clash when they are identical
+pub(crate) fn next_id(env: &Env, name: &String) -> Result<Symbol, TokenWeightedVoteContractErrors> {
+    validate(name)?;
+    if lookup(env, name).is_some() {
+        return Err(TokenWeightedVoteContractErrors::ProposalAlreadyExists);
+    }
+    Ok(sequential_id(env, next_index(env)))
+}
+
+// Loads the ID of the proposal created under a name, if any
+pub(crate) fn lookup(env: &Env, name: &String) -> Option<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::NamedProposal(
+            name.clone(),
+        ))
+}
+
+// Loads the name a proposal was created with, if it was created by name
+pub(crate) fn read(env: &Env, id: &Symbol) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::ProposalName(id.clone()))
+}
+
+// Records the name of a proposal in both directions for the longest TTL the network allows and
+// moves the stored counter past its ID
+pub(crate) fn record(env: &Env, id: &Symbol, name: &String) {
+    let name_key = TokenWeightedVoteContractDataKey::ProposalName(id.clone());
+    let id_key = TokenWeightedVoteContractDataKey::NamedProposal(name.clone());
+    env.storage().persistent().set(&name_key, name);
+    env.storage().persistent().set(&id_key, id);
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(&name_key, max_ttl, max_ttl);
+    env.storage()
+        .persistent()
+        .extend_ttl(&id_key, max_ttl, max_ttl);
+
+    let next = next_index(env);
+    env.storage()
+        .instance()
+        .set(&TokenWeightedVoteContractDataKey::NextProposalIndex, &next);
+}
+
+// Derives the proposal ID of a sequential index, e.g. PROP7 for 7
+fn sequential_id(env: &Env, index: u32) -> Symbol {
+    let mut digits = [0u8; 10];
+    let mut remaining = index;
+    let mut digit_count = 0;
+    loop {
+        digits[digit_count] = b'0' + (remaining % 10) as u8;
+        digit_count += 1;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    let mut id = [0u8; 32];
+    id[..SEQUENTIAL_ID_PREFIX.len()].copy_from_slice(SEQUENTIAL_ID_PREFIX);
+    for i in 0..digit_count {
+        id[SEQUENTIAL_ID_PREFIX.len() + i] = digits[digit_count - 1 - i];
+    }
+    let id_len = SEQUENTIAL_ID_PREFIX.len() + digit_count;
+    Symbol::new(env, core::str::from_utf8(&id[..id_len]).unwrap_or("PROP"))
+}
+
+// Returns the index the next named proposal receives, skipping any whose ID a caller already
+// chose for a proposal of its own
+fn next_index(env: &Env) -> u32 {
+    let mut index = env
+        .storage()
+        .instance()
+        .get(&TokenWeightedVoteContractDataKey::NextProposalIndex)
+        .unwrap_or(FIRST_INDEX);
+    while env
+        .storage()
+        .persistent()
+        .has(&TokenWeightedVoteContractDataKey::Proposal(sequential_id(
+            env, index,
+        )))
+    {
+        index += 1;
+    }
+    index
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+use crate::TokenWeightedVoteContractErrors;
+
+// --- Proof Constraints ---
+const MAX_PROOF_LENGTH: u32 = 32; // Maximum Merkle proof depth accepted per vote
+
+// Hashes an (address, weight) snapshot entry into a Merkle leaf
+pub(crate) fn hash_leaf(env: &Env, voter: &Address, weight: i128) -> BytesN<32> {
+    let entry = (voter.clone(), weight).to_xdr(env);
+    env.crypto().sha256(&entry).to_bytes()
+}
+
+// Hashes two nodes in sorted order so proofs need no position flags
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    env.crypto().sha256(&data).to_bytes()
+}
+
+// Checks that a voter's snapshot weight is included under the proposal's eligibility root
+pub(crate) fn verify(
+    env: &Env,
+    root: &BytesN<32>,
+    voter: &Address,
+    weight: i128,
+    proof: &Vec<BytesN<32>>,
+) -> Result<(), TokenWeightedVoteContractErrors> {
+    if proof.len() > MAX_PROOF_LENGTH {
+        return Err(TokenWeightedVoteContractErrors::InvalidProof);
+    }
+    let mut node = hash_leaf(env, voter, weight);
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    if node != *root {
+        return Err(TokenWeightedVoteContractErrors::InvalidProof);
+    }
+    Ok(())
+}
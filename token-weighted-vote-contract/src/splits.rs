@@ -0,0 +1,144 @@
+use governance_core::Tally;
+use soroban_sdk::{symbol_short, vec, Address, Env, Map, Symbol, Vec};
+
+use crate::{
+    delegation::Contributions, TokenWeightedVoteContractDataKey, TokenWeightedVoteContractErrors,
+    TokenWeightedVoteContribution,
+};
+
+// --- Allocation Scale ---
+pub(crate) const BPS_SCALE: u32 = 10_000; // Basis points, 10_000 = 100% of a voter's power
+
+// Choice recorded as a split voter's vote, whose allocations are stored under Split
+pub(crate) const SPLIT_CHOICE: Symbol = symbol_short!("SPLIT");
+
+// Returns the allocation of a single-choice vote, the full power on one choice
+pub(crate) fn whole(env: &Env, choice: Symbol) -> Vec<(Symbol, u32)> {
+    vec![env, (choice, BPS_SCALE)]
+}
+
+// Returns whether allocations cast the full power on one choice
+pub(crate) fn is_whole(allocations: &Vec<(Symbol, u32)>) -> bool {
+    allocations.len() == 1 && allocations.get_unchecked(0).1 == BPS_SCALE
+}
+
+// Validates split allocations: at least one, each a distinct valid choice with a nonzero share,
+// and at most 100% of the voter's power in total
+pub(crate) fn validate(
+    allocations: &Vec<(Symbol, u32)>,
+) -> Result<(), TokenWeightedVoteContractErrors> {
+    if allocations.is_empty() {
+        return Err(TokenWeightedVoteContractErrors::InvalidSplit);
+    }
+    let mut tally = Tally::default();
+    let mut total_bps: u32 = 0;
+    for (choice, bps) in allocations.iter() {
+        if bps == 0 {
+            return Err(TokenWeightedVoteContractErrors::InvalidSplit);
+        }
+        tally.record(&choice, 1)?;
+        total_bps = total_bps.saturating_add(bps);
+    }
+    // Each choice counted once per distinct allocation, so duplicates show up as a count above 1
+    if tally.total_for > 1
+        || tally.total_against > 1
+        || tally.total_abstain > 1
+        || total_bps > BPS_SCALE
+    {
+        return Err(TokenWeightedVoteContractErrors::InvalidSplit);
+    }
+    Ok(())
+}
+
+// Splits a weight across allocations; each piece is the difference of the rounded-down
+// cumulative shares, so the pieces add up to exactly weight * total bps / 10_000 rounded down
+// and no weight is created or destroyed between choices
+pub(crate) fn split(
+    env: &Env,
+    weight: i128,
+    allocations: &Vec<(Symbol, u32)>,
+) -> Vec<(Symbol, i128)> {
+    let mut pieces = Vec::new(env);
+    let mut cumulative_bps: u32 = 0;
+    let mut cumulative_weight: i128 = 0;
+    for (choice, bps) in allocations.iter() {
+        cumulative_bps += bps;
+        let share = weight.saturating_mul(cumulative_bps as i128) / BPS_SCALE as i128;
+        pieces.push_back((choice, share - cumulative_weight));
+        cumulative_weight = share;
+    }
+    pieces
+}
+
+// Allocates a ballot's weight across its choices, returning the weight each allocation adds to
+// the tally and the contributions to record; the voter's own power and every delegated share it
+// cast are split separately, so each share can be withdrawn exactly if its delegator votes
+// directly later. The first piece of a share keeps its basis points, the others carry none
+pub(crate) fn allocate(
+    env: &Env,
+    voter: &Address,
+    weight: i128,
+    contributions: Option<Contributions>,
+    allocations: &Vec<(Symbol, u32)>,
+) -> (Vec<(Symbol, i128)>, Option<Contributions>) {
+    let mut own_weight = weight;
+    let mut delegated_pieces = Vec::new(env);
+    let contributions = contributions.map(|contributions| {
+        let mut spread = Map::new(env);
+        for (delegator, cast) in contributions.iter() {
+            let mut pieces = Vec::new(env);
+            for contribution in cast.iter() {
+                if contribution.delegatee != *voter {
+                    pieces.push_back(contribution);
+                    continue;
+                }
+                own_weight -= contribution.weight;
+                let mut bps = contribution.bps;
+                for (choice, piece) in split(env, contribution.weight, allocations).iter() {
+                    delegated_pieces.push_back(piece);
+                    pieces.push_back(TokenWeightedVoteContribution {
+                        delegatee: voter.clone(),
+                        choice,
+                        bps,
+                        weight: piece,
+                    });
+                    bps = 0;
+                }
+            }
+            spread.set(delegator, pieces);
+        }
+        spread
+    });
+
+    // Delegated pieces are laid out share by share, one per allocation
+    let mut totals = split(env, own_weight, allocations);
+    for (i, piece) in delegated_pieces.iter().enumerate() {
+        let slot = i as u32 % allocations.len();
+        let (choice, total) = totals.get_unchecked(slot);
+        totals.set(slot, (choice, total.saturating_add(piece)));
+    }
+    (totals, contributions)
+}
+
+// Loads the allocations of a split vote
+pub(crate) fn read(env: &Env, user: &Address, id: &Symbol) -> Option<Vec<(Symbol, u32)>> {
+    env.storage()
+        .persistent()
+        .get(&TokenWeightedVoteContractDataKey::Split(
+            user.clone(),
+            id.clone(),
+        ))
+}
+
+// Stores the allocations of a split vote for as long as the vote record itself
+pub(crate) fn write(
+    env: &Env,
+    user: &Address,
+    id: &Symbol,
+    allocations: &Vec<(Symbol, u32)>,
+    ttl: u32,
+) {
+    let split_key = TokenWeightedVoteContractDataKey::Split(user.clone(), id.clone());
+    env.storage().persistent().set(&split_key, allocations);
+    env.storage().persistent().extend_ttl(&split_key, ttl, ttl);
+}
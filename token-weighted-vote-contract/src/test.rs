@@ -0,0 +1,2501 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use capped::CappedWeightCalculator;
+use governance_core::GovernanceClient;
+use governance_errors::{in_range, GovernanceError, TOKEN_WEIGHTED_VOTE_CODES};
+use governance_testutils::{
+    assert_events, assert_last_event, assert_within_budget, create_token_contract, event,
+    setup_test_env, GovernanceHarness, BUDGET_FULL_LIST_COUNT, BUDGET_PROPOSAL_COUNTS,
+    PROPOSAL_START_DELAY,
+};
+use linear::LinearWeightCalculator;
+use proptest::prelude::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Bytes, BytesN, Env, FromVal, IntoVal, String,
+};
+
+mod linear {
+    use soroban_sdk::{contract, contractimpl, symbol_short, token::TokenClient, Address, Env};
+
+    // Linear strategy: voting weight equals the holder's token balance
+    #[contract]
+    pub struct LinearWeightCalculator;
+
+    #[contractimpl]
+    impl LinearWeightCalculator {
+        pub fn __constructor(env: Env, token: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("TOKEN"), &token);
+        }
+
+        pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+            let token: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("TOKEN"))
+                .unwrap();
+            TokenClient::new(&env, &token).balance(&user)
+        }
+    }
+}
+
+mod capped {
+    use soroban_sdk::{contract, contractimpl, symbol_short, token::TokenClient, Address, Env};
+
+    // Capped strategy: voting weight equals the balance, limited to a fixed maximum
+    #[contract]
+    pub struct CappedWeightCalculator;
+
+    #[contractimpl]
+    impl CappedWeightCalculator {
+        pub fn __constructor(env: Env, token: Address, cap: i128) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("TOKEN"), &token);
+            env.storage().instance().set(&symbol_short!("CAP"), &cap);
+        }
+
+        pub fn calculate_weight(env: Env, user: Address, _snapshot_time: u64) -> i128 {
+            let token: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("TOKEN"))
+                .unwrap();
+            let cap: i128 = env.storage().instance().get(&symbol_short!("CAP")).unwrap();
+            TokenClient::new(&env, &token).balance(&user).min(cap)
+        }
+    }
+}
+
+// Reputation mock: counts recorded votes per voter and passed proposals per proposer
+#[contract]
+pub struct MockReputationContract;
+
+#[contractimpl]
+impl MockReputationContract {
+    pub fn record_vote(env: Env, _minter: Address, voter: Address) {
+        let votes: u32 = env.storage().instance().get(&voter).unwrap_or(0);
+        env.storage().instance().set(&voter, &(votes + 1));
+    }
+
+    pub fn record_proposal_passed(env: Env, _minter: Address, proposer: Address) {
+        let key = (symbol_short!("PASSED"), proposer);
+        let passed: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(passed + 1));
+    }
+
+    pub fn votes_of(env: Env, voter: Address) -> u32 {
+        env.storage().instance().get(&voter).unwrap_or(0)
+    }
+
+    pub fn passed_of(env: Env, proposer: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&(symbol_short!("PASSED"), proposer))
+            .unwrap_or(0)
+    }
+}
+
+// Supply mock: token reporting an adjustable total supply with 7 decimals
+#[contract]
+pub struct MockSupplyToken;
+
+#[contractimpl]
+impl MockSupplyToken {
+    pub fn set_supply(env: Env, supply: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SUPPLY"), &supply);
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("SUPPLY"))
+            .unwrap_or(0)
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+}
+
+fn create_linear_strategy(e: &Env, token_address: &Address) -> Address {
+    e.register(LinearWeightCalculator, (token_address,))
+}
+
+fn create_vote_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    strategy_address: &Address,
+) -> TokenWeightedVoteContractClient<'a> {
+    let contract_address = e.register(
+        TokenWeightedVoteContract,
+        (
+            admin.clone(),
+            strategy_address.clone(),
+            WeightScaling::Linear,
+        ),
+    );
+    TokenWeightedVoteContractClient::new(e, &contract_address)
+}
+
+// Tests successful contract initialization with admin and strategy configuration.
+// Expects: Empty governance details list and the configured strategy address.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let governance_details = client.get_governance_details();
+    assert_eq!(governance_details.len(), 0);
+    assert_eq!(client.get_strategy(), strategy);
+    assert_eq!(client.get_weight_scaling(), WeightScaling::Linear);
+}
+
+// Tests weighted voting on a contract initialized with logarithmic scaling.
+// Expects: 1,023 tokens count as 10 tokens and 1 token as 1, for tallies and voting power.
+#[test]
+fn test_log2_scaling() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let whale = Address::generate(&e);
+    let holder = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&whale, &10_230_000_000);
+    stellar_asset.mint(&holder, &10_000_000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let contract_address = e.register(
+        TokenWeightedVoteContract,
+        (admin.clone(), strategy, WeightScaling::Log2),
+    );
+    let client = TokenWeightedVoteContractClient::new(&e, &contract_address);
+    assert_eq!(client.get_weight_scaling(), WeightScaling::Log2);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&whale, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&holder, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 100_000_000);
+    assert_eq!(proposal_details.total_against, 10_000_000);
+    assert_eq!(
+        client.get_user_details(&whale).get(0).unwrap().2,
+        100_000_000
+    );
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    e.register_at(
+        &client.address,
+        TokenWeightedVoteContract,
+        (admin, strategy, WeightScaling::Linear),
+    );
+}
+
+// Tests successful proposal creation records the active strategy.
+// Expects: Proposal appears in governance details and stores the strategy address.
+#[test]
+fn test_create_proposal() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal description");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 100;
+    let end_time = start_time + 500000;
+
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    let governance_details = client.get_governance_details();
+    assert_eq!(governance_details.len(), 1);
+    assert_eq!(governance_details.get(0).unwrap().id, proposal_id);
+    assert_eq!(client.get_proposal_details(&proposal_id).strategy, strategy);
+}
+
+// Tests weighted voting through a linear strategy with three different balances.
+// Expects: Each tally equals the token balance of the user who voted for that option.
+#[test]
+fn test_vote_linear_strategy() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &500);
+    stellar_asset.mint(&user2, &300);
+    stellar_asset.mint(&user3, &200);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = ledger_time + 500000;
+    client.create_proposal(&admin, &proposal_id, &description, &start_time, &end_time);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user2, &proposal_id, &symbol_short!("AGAINST"));
+    client.vote(&user3, &proposal_id, &symbol_short!("ABSTAIN"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 500);
+    assert_eq!(proposal_details.total_against, 300);
+    assert_eq!(proposal_details.total_abstain, 200);
+}
+
+// Tests swapping the strategy only affects proposals created afterwards.
+// Expects: Old proposal keeps linear weights while the new proposal applies the cap.
+#[test]
+fn test_set_strategy_applies_to_new_proposals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1000);
+
+    let linear = create_linear_strategy(&e, &token.address);
+    let capped = e.register(CappedWeightCalculator, (&token.address, 100_i128));
+    let client = create_vote_contract(&e, &admin, &linear);
+
+    let ledger_time = e.ledger().timestamp();
+    let start_time = ledger_time + 50;
+    let end_time = ledger_time + 500000;
+    let description = String::from_val(&e, &"Test proposal");
+
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    client.set_strategy(&capped);
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    assert_eq!(client.get_strategy(), capped);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .total_for,
+        1000
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .total_for,
+        100
+    );
+}
+
+// Tests prevention of multiple votes by same user on same proposal.
+// Expects: UserAlreadyVoted error (Error #5) to maintain voting integrity.
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_user_already_voted() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user, &proposal_id, &symbol_short!("AGAINST"));
+}
+
+// Tests that a zero weight returned by the strategy blocks voting.
+// Expects: UserCannotVote error (Error #6) for users without voting weight.
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_user_cannot_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+}
+
+// Tests voting with invalid choice option (not FOR/AGAINST/ABSTAIN).
+// Expects: InvalidChoice error (Error #8) to enforce standardized vote options.
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_invalid_choice() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &proposal_id, &symbol_short!("INVALID"));
+}
+
+// Tests secure admin privilege transfer to new address.
+// Expects: Successful transfer without errors, maintaining operational continuity.
+#[test]
+fn test_transfer_admin() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let result = client.try_transfer_admin(&new_admin);
+    assert!(result.is_ok());
+}
+
+// Tests user details report participation and strategy-derived voting power.
+// Expects: Voted flag set for the proposal and voting power equal to the balance.
+#[test]
+fn test_get_user_details() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &750);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+
+    let user_details = client.get_user_details(&user);
+    assert_eq!(user_details.len(), 1);
+    assert_eq!(user_details.get(0).unwrap(), (proposal_id, true, 750));
+}
+
+// Tests that votes and passed proposals are reported to the configured reputation contract.
+// Expects: Reputation mock records one vote for the voter and, across repeated finalize calls,
+// one passed proposal for the proposer.
+#[test]
+fn test_reputation_hook() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    let reputation_address = e.register(MockReputationContract, ());
+    let reputation = MockReputationContractClient::new(&e, &reputation_address);
+    client.set_reputation_contract(&Some(reputation_address));
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&user, &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(reputation.votes_of(&user), 1);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 500001;
+    });
+    client.finalize(&proposal_id);
+    client.finalize(&proposal_id);
+    assert_eq!(reputation.passed_of(&admin), 1);
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).finalized_at,
+        ledger_time + 500001
+    );
+}
+
+// Tests that a delegatee votes with its own weight plus every active delegator's weight.
+// Expects: FOR tally equals both balances and the delegator is marked as represented.
+#[test]
+fn test_delegate_and_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &300);
+    stellar_asset.mint(&delegatee, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    client.delegate(&delegator, &delegatee, &10_000, &None);
+    assert_eq!(client.get_delegators(&delegatee).len(), 1);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&delegatee, &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 800);
+
+    client.undelegate(&delegator, &delegatee);
+    assert_eq!(client.get_delegators(&delegatee).len(), 0);
+}
+
+// Tests that a delegator voting directly overrides the delegatee that already voted for it.
+// Expects: Delegated weight moves to the delegator's choice and the override is tracked.
+#[test]
+fn test_principal_override() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &300);
+    stellar_asset.mint(&delegatee, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &10_000, &None);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&delegatee, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&delegator, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 500);
+    assert_eq!(proposal_details.total_against, 300);
+    assert_eq!(
+        client.get_overrides(&proposal_id),
+        Vec::from_array(&e, [delegator])
+    );
+}
+
+// Tests that delegated power reverts to the principal once expires_at has passed.
+// Expects: Delegatee votes with its own weight only and the principal can vote directly.
+#[test]
+fn test_delegation_expiry_reverts_power() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &300);
+    stellar_asset.mint(&delegatee, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &10_000, &Some(ledger_time + 1000));
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 1000;
+    });
+
+    client.vote(&delegatee, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&delegator, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 500);
+    assert_eq!(proposal_details.total_against, 300);
+}
+
+// Tests permissionless cleanup of expired delegations.
+// Expects: Only the expired entry is removed from both the delegator and delegatee records.
+#[test]
+fn test_prune_expired_delegations() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let short_delegator = Address::generate(&e);
+    let open_delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    client.delegate(
+        &short_delegator,
+        &delegatee,
+        &5_000,
+        &Some(ledger_time + 100),
+    );
+    client.delegate(&open_delegator, &delegatee, &5_000, &None);
+    assert_eq!(client.prune_expired_delegations(&delegatee), 0);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    assert_eq!(client.prune_expired_delegations(&delegatee), 1);
+    assert_eq!(client.get_delegations(&short_delegator).len(), 0);
+    assert_eq!(
+        client.get_delegators(&delegatee),
+        Vec::from_array(&e, [open_delegator])
+    );
+}
+
+// Tests a new allocation made after one of the holder's earlier allocations expired.
+// Expects: The expired entry is dropped from the holder's stored list and its delegatee's
+// delegators, and a later allocation to that delegatee links the holder once.
+#[test]
+fn test_delegate_drops_expired_allocations() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let expired_delegatee = Address::generate(&e);
+    let open_delegatee = Address::generate(&e);
+    let new_delegatee = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    client.delegate(
+        &delegator,
+        &expired_delegatee,
+        &6_000,
+        &Some(ledger_time + 100),
+    );
+    client.delegate(&delegator, &open_delegatee, &2_000, &None);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.delegate(&delegator, &new_delegatee, &5_000, &None);
+    assert_eq!(
+        client.get_delegations(&delegator),
+        Vec::from_array(
+            &e,
+            [
+                TokenWeightedVoteDelegation {
+                    delegatee: open_delegatee,
+                    bps: 2_000,
+                    expires_at: None,
+                },
+                TokenWeightedVoteDelegation {
+                    delegatee: new_delegatee,
+                    bps: 5_000,
+                    expires_at: None,
+                },
+            ]
+        )
+    );
+    assert_eq!(client.get_delegators(&expired_delegatee).len(), 0);
+
+    client.delegate(&delegator, &expired_delegatee, &3_000, &None);
+    assert_eq!(client.get_delegations(&delegator).len(), 3);
+    assert_eq!(
+        client.get_delegators(&expired_delegatee),
+        Vec::from_array(&e, [delegator])
+    );
+}
+
+// Tests rejection of delegations to oneself or with an expiry already in the past.
+// Expects: InvalidDelegation error (Error #213) for a self-delegation.
+#[test]
+#[should_panic(expected = "Error(Contract, #213)")]
+fn test_invalid_delegation() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    client.delegate(&user, &user, &10_000, &None);
+}
+
+// Tests splitting one holder's power across two delegatees by basis points.
+// Expects: Each delegatee carries its own balance plus 60% and 40% of the holder's weight.
+#[test]
+fn test_split_delegation() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee_a = Address::generate(&e);
+    let delegatee_b = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &1000);
+    stellar_asset.mint(&delegatee_a, &100);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee_a, &6_000, &None);
+    client.delegate(&delegator, &delegatee_b, &4_000, &None);
+    assert_eq!(client.get_delegations(&delegator).len(), 2);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&delegatee_a, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&delegatee_b, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 700);
+    assert_eq!(proposal_details.total_against, 400);
+}
+
+// Tests a partially delegating holder voting before its delegatee.
+// Expects: Holder casts its full weight and the delegatee only its own balance.
+#[test]
+fn test_split_delegation_principal_votes() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &1000);
+    stellar_asset.mint(&delegatee, &100);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &6_000, &None);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote(&delegator, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&delegatee, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 1000);
+    assert_eq!(proposal_details.total_against, 100);
+}
+
+// Tests rejection of allocations that together exceed the holder's full power.
+// Expects: DelegationExceedsPower error (Error #216) for 60% + 50%.
+#[test]
+#[should_panic(expected = "Error(Contract, #216)")]
+fn test_delegation_exceeds_power() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee_a = Address::generate(&e);
+    let delegatee_b = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    client.delegate(&delegator, &delegatee_a, &6_000, &None);
+    client.delegate(&delegator, &delegatee_b, &5_000, &None);
+}
+
+// Tests the events published when delegations are set, revoked, and pruned.
+// Expects: SET and REVOKED carry the delegator, delegatee, share, and expiry, and pruning
+// revokes each expired allocation before reporting the count.
+#[test]
+fn test_delegation_events() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    let expires_at = Some(ledger_time + 100);
+    let delegation_event = TokenWeightedVoteDelegationEvent {
+        delegator: delegator.clone(),
+        delegatee: delegatee.clone(),
+        bps: 5_000,
+        expires_at,
+    };
+
+    client.delegate(&delegator, &delegatee, &5_000, &expires_at);
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "DELEGATION",
+            "SET",
+            events::EVENT_VERSION,
+            delegator.clone(),
+        ),
+        delegation_event.clone(),
+    );
+
+    client.undelegate(&delegator, &delegatee);
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            "DELEGATION",
+            "REVOKED",
+            events::EVENT_VERSION,
+            delegator.clone(),
+        ),
+        delegation_event.clone(),
+    );
+
+    client.delegate(&delegator, &delegatee, &5_000, &expires_at);
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    client.prune_expired_delegations(&delegatee);
+    assert_events(
+        &e,
+        &client.address,
+        &[
+            event(
+                &e,
+                (
+                    "DELEGATION",
+                    "REVOKED",
+                    events::EVENT_VERSION,
+                    delegator.clone(),
+                ),
+                delegation_event,
+            ),
+            event(
+                &e,
+                ("DELEGATION", "PRUNED", events::EVENT_VERSION, delegatee),
+                1_u32,
+            ),
+        ],
+    );
+}
+
+// Tests the delegation read functions wallets use to show effective voting power.
+// Expects: Only allocations in force are listed and the delegatee's inbound power is the
+// delegated share of each active delegator's weight, dropping expired shares.
+#[test]
+fn test_delegation_introspection() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let short_delegator = Address::generate(&e);
+    let open_delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&short_delegator, &1000);
+    stellar_asset.mint(&open_delegator, &400);
+    stellar_asset.mint(&delegatee, &100);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    assert_eq!(client.get_delegated_power(&delegatee), 0);
+
+    let ledger_time = e.ledger().timestamp();
+    client.delegate(
+        &short_delegator,
+        &delegatee,
+        &6_000,
+        &Some(ledger_time + 100),
+    );
+    client.delegate(&open_delegator, &delegatee, &10_000, &None);
+
+    assert_eq!(
+        client.get_delegation(&short_delegator),
+        Vec::from_array(
+            &e,
+            [TokenWeightedVoteDelegation {
+                delegatee: delegatee.clone(),
+                bps: 6_000,
+                expires_at: Some(ledger_time + 100),
+            }]
+        )
+    );
+    assert_eq!(client.get_delegated_power(&delegatee), 1000);
+    assert_eq!(client.get_delegated_power(&short_delegator), 0);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    assert_eq!(client.get_delegation(&short_delegator).len(), 0);
+    assert_eq!(client.get_delegations(&short_delegator).len(), 1);
+    assert_eq!(client.get_delegated_power(&delegatee), 400);
+}
+
+// Tests splitting an odd balance into thirds across all three choices.
+// Expects: Rounded pieces 333/334/334 add up to the full 1001, the split is recorded and emitted.
+#[test]
+fn test_vote_split() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &1001);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    let allocations = vec![
+        &e,
+        (symbol_short!("FOR"), 3_333),
+        (symbol_short!("AGAINST"), 3_333),
+        (symbol_short!("ABSTAIN"), 3_334),
+    ];
+    client.vote_split(&voter, &proposal_id, &allocations);
+
+    assert_last_event(
+        &e,
+        &client.address,
+        (
+            events::VOTE,
+            "SPLIT",
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        (
+            voter.clone(),
+            vec![
+                &e,
+                (symbol_short!("FOR"), 333_i128),
+                (symbol_short!("AGAINST"), 334_i128),
+                (symbol_short!("ABSTAIN"), 334_i128),
+            ],
+        ),
+    );
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 333);
+    assert_eq!(proposal_details.total_against, 334);
+    assert_eq!(proposal_details.total_abstain, 334);
+
+    assert!(client.has_voted(&voter, &proposal_id));
+    assert_eq!(
+        client.get_vote(&voter, &proposal_id),
+        Some(symbol_short!("SPLIT"))
+    );
+    assert_eq!(
+        client.get_vote_split(&voter, &proposal_id),
+        Some(allocations.clone())
+    );
+    assert_eq!(
+        client.try_vote_split(&voter, &proposal_id, &allocations),
+        Err(Ok(TokenWeightedVoteContractErrors::UserAlreadyVoted))
+    );
+}
+
+// Tests a split that casts only part of the voter's power.
+// Expects: 60% of 1000 counts FOR and the remaining 40% is left uncast.
+#[test]
+fn test_vote_split_partial() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &1000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote_split(
+        &voter,
+        &proposal_id,
+        &vec![&e, (symbol_short!("FOR"), 6_000)],
+    );
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 600);
+    assert_eq!(proposal_details.total_against, 0);
+    assert_eq!(proposal_details.total_abstain, 0);
+}
+
+// Tests a delegatee splitting its own and delegated power, then the delegator voting directly.
+// Expects: Each share is split on its own, and the override withdraws exactly the pieces it added.
+#[test]
+fn test_vote_split_with_delegation() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &1000);
+    stellar_asset.mint(&delegatee, &101);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &5_000, &None);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote_split(
+        &delegatee,
+        &proposal_id,
+        &vec![
+            &e,
+            (symbol_short!("FOR"), 5_000),
+            (symbol_short!("AGAINST"), 5_000),
+        ],
+    );
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 50 + 250);
+    assert_eq!(proposal_details.total_against, 51 + 250);
+
+    client.vote(&delegator, &proposal_id, &symbol_short!("ABSTAIN"));
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 50);
+    assert_eq!(proposal_details.total_against, 51);
+    assert_eq!(proposal_details.total_abstain, 1000);
+}
+
+// Tests rejection of empty, zero, duplicate, unknown, and over-allocated splits.
+// Expects: InvalidSplit error (Error #223), or InvalidChoice for an unknown choice.
+#[test]
+fn test_invalid_vote_split() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let voter = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&voter, &1000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    for allocations in [
+        vec![&e],
+        vec![&e, (symbol_short!("FOR"), 0)],
+        vec![
+            &e,
+            (symbol_short!("FOR"), 3_000),
+            (symbol_short!("FOR"), 3_000),
+        ],
+        vec![
+            &e,
+            (symbol_short!("FOR"), 6_000),
+            (symbol_short!("AGAINST"), 5_000),
+        ],
+    ] {
+        assert_eq!(
+            client.try_vote_split(&voter, &proposal_id, &allocations),
+            Err(Ok(TokenWeightedVoteContractErrors::InvalidSplit))
+        );
+    }
+    assert_eq!(
+        client.try_vote_split(
+            &voter,
+            &proposal_id,
+            &vec![&e, (symbol_short!("MAYBE"), 5_000)]
+        ),
+        Err(Ok(TokenWeightedVoteContractErrors::InvalidChoice))
+    );
+    assert!(!client.has_voted(&voter, &proposal_id));
+}
+
+// Hashes two nodes in sorted order, matching the contract's snapshot tree construction
+fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(e, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+// Creates an active snapshot proposal over two entries (alice: 500, bob: 300) for voters
+// holding no tokens, returning the voters and each one's proof
+fn setup_snapshot_proposal<'a>(
+    e: &Env,
+) -> (
+    TokenWeightedVoteContractClient<'a>,
+    (Address, BytesN<32>),
+    (Address, BytesN<32>),
+) {
+    let admin = Address::generate(e);
+    let alice = Address::generate(e);
+    let bob = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    let strategy = create_linear_strategy(e, &token.address);
+    let client = create_vote_contract(e, &admin, &strategy);
+
+    let alice_leaf = client.get_snapshot_leaf(&alice, &500);
+    let bob_leaf = client.get_snapshot_leaf(&bob, &300);
+    let root = hash_pair(e, &alice_leaf, &bob_leaf);
+    let ledger_time = e.ledger().timestamp();
+    client.create_snapshot_proposal(
+        &symbol_short!("SNAP001"),
+        &String::from_val(e, &"Snapshot proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+        &root,
+    );
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    (client, (alice, bob_leaf), (bob, alice_leaf))
+}
+
+// Tests voting on a snapshot proposal with Merkle proofs instead of live balances.
+// Expects: Tallies equal the snapshot weights although the voters hold no tokens.
+#[test]
+fn test_snapshot_vote_with_proof() {
+    let e = setup_test_env();
+    let (client, (alice, alice_proof), (bob, bob_proof)) = setup_snapshot_proposal(&e);
+    let proposal_id = symbol_short!("SNAP001");
+
+    client.vote_with_proof(
+        &alice,
+        &proposal_id,
+        &symbol_short!("FOR"),
+        &500,
+        &vec![&e, alice_proof],
+    );
+    client.vote_with_proof(
+        &bob,
+        &proposal_id,
+        &symbol_short!("AGAINST"),
+        &300,
+        &vec![&e, bob_proof],
+    );
+
+    let proposal_details = client.get_proposal_details(&proposal_id);
+    assert_eq!(proposal_details.total_for, 500);
+    assert_eq!(proposal_details.total_against, 300);
+}
+
+// Tests claiming a higher weight than the snapshot entry holds.
+// Expects: InvalidProof error (Error #16).
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_snapshot_vote_invalid_proof() {
+    let e = setup_test_env();
+    let (client, (alice, alice_proof), _) = setup_snapshot_proposal(&e);
+
+    client.vote_with_proof(
+        &alice,
+        &symbol_short!("SNAP001"),
+        &symbol_short!("FOR"),
+        &501,
+        &vec![&e, alice_proof],
+    );
+}
+
+// Tests a plain vote on a snapshot proposal.
+// Expects: ProofRequired error (Error #218) since live balances are not consulted.
+#[test]
+#[should_panic(expected = "Error(Contract, #218)")]
+fn test_snapshot_vote_requires_proof() {
+    let e = setup_test_env();
+    let (client, (alice, _), _) = setup_snapshot_proposal(&e);
+
+    client.vote(&alice, &symbol_short!("SNAP001"), &symbol_short!("FOR"));
+}
+
+// Tests a proof-based vote on a proposal created without an eligibility root.
+// Expects: NotSnapshotProposal error (Error #219).
+#[test]
+#[should_panic(expected = "Error(Contract, #219)")]
+fn test_vote_with_proof_not_snapshot() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+
+    client.vote_with_proof(
+        &user,
+        &symbol_short!("PROP001"),
+        &symbol_short!("FOR"),
+        &100,
+        &vec![&e],
+    );
+}
+
+// Tests creating a proposal with a proposer other than the admin.
+// Expects: ProposalsRestricted error (Error #17).
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_create_proposal_restricted() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &user,
+        &symbol_short!("PROP001"),
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+}
+
+// Tests driving a proposal through the shared governance interface client.
+// Expects: Interface calls create and tally the proposal, which finalizes by FOR majority.
+#[test]
+fn test_governance_interface() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &500);
+    stellar_asset.mint(&user2, &300);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    let governance = GovernanceClient::new(&e, &client.address);
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    governance.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    assert_eq!(
+        governance.get_status(&proposal_id),
+        GovernanceStatus::Pending
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 100);
+    governance.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    governance.vote(&user2, &proposal_id, &symbol_short!("AGAINST"));
+
+    let proposal = governance.get_proposal(&proposal_id);
+    assert_eq!(proposal.total_for, 500);
+    assert_eq!(proposal.total_against, 300);
+    assert_eq!(proposal.status, GovernanceStatus::Active);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 500001);
+    assert_eq!(
+        governance.finalize(&proposal_id),
+        GovernanceStatus::Succeeded
+    );
+    assert_eq!(
+        governance.get_status(&proposal_id),
+        GovernanceStatus::Succeeded
+    );
+}
+
+// Tests finalizing a proposal while its voting window is still open.
+// Expects: VotingNotEnded error (Error #14).
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_finalize_before_end() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.finalize(&proposal_id);
+}
+
+// Tests a proposal lifecycle driven through the shared governance test harness.
+// Expects: Balance-weighted totals, a Succeeded outcome, and a matching finalization event.
+#[test]
+fn test_governance_harness() {
+    let harness = GovernanceHarness::new(|e, admin, token| {
+        let strategy = create_linear_strategy(e, token);
+        create_vote_contract(e, admin, &strategy).address
+    });
+    let voter1 = harness.holder(100);
+    let voter2 = harness.holder(500);
+    let voter3 = harness.holder(100);
+    let proposal_id = symbol_short!("PROP001");
+
+    harness.propose(&proposal_id, MIN_PROPOSAL_DURATION);
+    harness.open_voting(&proposal_id);
+    harness.vote(&voter1, &proposal_id, &symbol_short!("FOR"));
+    harness.vote(&voter2, &proposal_id, &symbol_short!("FOR"));
+    harness.vote(&voter3, &proposal_id, &symbol_short!("AGAINST"));
+    harness.assert_tally(&proposal_id, 600, 100, 0);
+
+    assert_eq!(harness.finalize(&proposal_id), GovernanceStatus::Succeeded);
+    assert_last_event(
+        &harness.env,
+        &harness.contract(),
+        (
+            events::PROPOSAL,
+            events::FINALIZED,
+            events::EVENT_VERSION,
+            proposal_id.clone(),
+        ),
+        events::ProposalFinalizedEvent {
+            id: proposal_id,
+            status: GovernanceStatus::Succeeded,
+            total_for: 600,
+            total_against: 100,
+            total_abstain: 0,
+            turnout: 700,
+            quorum: 0,
+            quorum_met: true,
+            threshold: SIMPLE_MAJORITY,
+        },
+    );
+}
+
+// Tests the resources of proposal creation, voting, and the read functions as proposals accumulate.
+// Expects: Every call stays under the Soroban CPU instruction and memory limits at 1, 100, and
+// 1000 stored proposals, except the whole-list reads, which are held to them up to 100.
+#[test]
+fn test_resource_budget() {
+    for count in BUDGET_PROPOSAL_COUNTS {
+        let harness = GovernanceHarness::new(|e, admin, token| {
+            let strategy = create_linear_strategy(e, token);
+            create_vote_contract(e, admin, &strategy).address
+        });
+        let e = &harness.env;
+        let client = TokenWeightedVoteContractClient::new(e, &harness.contract());
+        let voter = harness.holder(100);
+        let description = String::from_str(e, "Benchmark proposal");
+        let start_time = e.ledger().timestamp() + PROPOSAL_START_DELAY;
+        let end_time = start_time + MIN_PROPOSAL_DURATION;
+
+        for index in 1..count {
+            let id = Symbol::new(e, &std::format!("PROP{}", index));
+            client.create_proposal(&harness.admin, &id, &description, &start_time, &end_time);
+        }
+        let proposal_id = symbol_short!("PROP0");
+        assert_within_budget(e, "create_proposal", || {
+            client.create_proposal(
+                &harness.admin,
+                &proposal_id,
+                &description,
+                &start_time,
+                &end_time,
+            )
+        });
+
+        harness.open_voting(&proposal_id);
+        assert_within_budget(e, "vote", || {
+            client.vote(&voter, &proposal_id, &symbol_short!("FOR"))
+        });
+
+        assert_within_budget(e, "get_proposal", || client.get_proposal(&proposal_id));
+        assert_within_budget(e, "get_status", || client.get_status(&proposal_id));
+        assert_within_budget(e, "get_proposal_details", || {
+            client.get_proposal_details(&proposal_id)
+        });
+        assert_within_budget(e, "get_vote", || client.get_vote(&voter, &proposal_id));
+
+        // The whole-list reads load every proposal, so they are only held to the limits up to
+        // BUDGET_FULL_LIST_COUNT proposals
+        if count > BUDGET_FULL_LIST_COUNT {
+            continue;
+        }
+        let details = assert_within_budget(e, "get_governance_details", || {
+            client.get_governance_details()
+        });
+        assert_eq!(details.len(), count);
+        let user_details =
+            assert_within_budget(e, "get_user_details", || client.get_user_details(&voter));
+        assert_eq!(user_details.len(), count);
+    }
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with governance, delegation and snapshots supported and
+// unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let client = create_vote_contract(&e, &Address::generate(&e), &Address::generate(&e));
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 200 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        TokenWeightedVoteContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::ProposalAlreadyExists as u32,
+        GovernanceError::ProposalAlreadyExists as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::ProposalNotFound as u32,
+        GovernanceError::ProposalNotFound as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::UserAlreadyVoted as u32,
+        GovernanceError::UserAlreadyVoted as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::UserCannotVote as u32,
+        GovernanceError::UserCannotVote as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::VotingNotActive as u32,
+        GovernanceError::VotingNotActive as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::InvalidChoice as u32,
+        GovernanceError::InvalidChoice as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::StartTimeAfterEnd as u32,
+        GovernanceError::StartTimeAfterEnd as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::StartTimeInPast as u32,
+        GovernanceError::StartTimeInPast as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::DurationTooLong as u32,
+        GovernanceError::DurationTooLong as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::DurationTooShort as u32,
+        GovernanceError::DurationTooShort as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::VotingNotEnded as u32,
+        GovernanceError::VotingNotEnded as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::InvalidProof as u32,
+        GovernanceError::InvalidProof as u32
+    );
+    assert_eq!(
+        TokenWeightedVoteContractErrors::ProposalsRestricted as u32,
+        GovernanceError::ProposalsRestricted as u32
+    );
+    for error in [
+        TokenWeightedVoteContractErrors::InvalidDelegation,
+        TokenWeightedVoteContractErrors::DelegationNotFound,
+        TokenWeightedVoteContractErrors::DelegationExceedsPower,
+        TokenWeightedVoteContractErrors::ProofRequired,
+        TokenWeightedVoteContractErrors::NotSnapshotProposal,
+        TokenWeightedVoteContractErrors::InvalidDecimals,
+        TokenWeightedVoteContractErrors::InvalidQuorum,
+        TokenWeightedVoteContractErrors::InvalidSplit,
+        TokenWeightedVoteContractErrors::InvalidProposalName,
+        TokenWeightedVoteContractErrors::TooManyDelegators,
+    ] {
+        assert!(in_range(error as u32, TOKEN_WEIGHTED_VOTE_CODES));
+    }
+}
+
+// Tests dry-running votes before, during, and after casting a delegated vote.
+// Expects: Simulation reports the delegated weight or the error without changing the tally.
+#[test]
+fn test_simulate_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &300);
+    stellar_asset.mint(&delegatee, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &10_000, &None);
+
+    assert_eq!(
+        client.try_simulate_vote(&delegatee, &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenWeightedVoteContractErrors::VotingNotActive))
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    assert_eq!(
+        client.simulate_vote(&delegatee, &proposal_id, &symbol_short!("FOR")),
+        800
+    );
+    assert_eq!(
+        client.try_simulate_vote(&delegatee, &proposal_id, &symbol_short!("MAYBE")),
+        Err(Ok(TokenWeightedVoteContractErrors::InvalidChoice))
+    );
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 0);
+
+    client.vote(&delegatee, &proposal_id, &symbol_short!("FOR"));
+    assert_eq!(client.get_proposal_details(&proposal_id).total_for, 800);
+    assert_eq!(
+        client.try_simulate_vote(&delegatee, &proposal_id, &symbol_short!("FOR")),
+        Err(Ok(TokenWeightedVoteContractErrors::UserAlreadyVoted))
+    );
+}
+
+// Tests single-proposal vote lookups for a delegatee and the delegator it voted for.
+// Expects: Only the direct vote is recorded, delegated weight does not count as a vote.
+#[test]
+fn test_has_voted_and_get_vote() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegator = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&delegator, &300);
+    stellar_asset.mint(&delegatee, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.delegate(&delegator, &delegatee, &10_000, &None);
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    client.vote(&delegatee, &proposal_id, &symbol_short!("ABSTAIN"));
+
+    assert!(client.has_voted(&delegatee, &proposal_id));
+    assert_eq!(
+        client.get_vote(&delegatee, &proposal_id),
+        Some(symbol_short!("ABSTAIN"))
+    );
+    assert!(!client.has_voted(&delegator, &proposal_id));
+    assert_eq!(client.get_vote(&delegator, &proposal_id), None);
+}
+
+// Tests the versioned topics and typed payloads of admin and config events.
+// Expects: STRATEGY and ADMIN events carry the schema version and their new values.
+#[test]
+fn test_event_schema() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let new_strategy = create_linear_strategy(&e, &token.address);
+    client.set_strategy(&new_strategy);
+
+    let (contract, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(contract, client.address);
+    assert_eq!(
+        topics,
+        (
+            events::CONFIG,
+            events::UPDATED,
+            events::EVENT_VERSION,
+            "STRATEGY"
+        )
+            .into_val(&e)
+    );
+    assert_eq!(Address::from_val(&e, &data), new_strategy);
+
+    client.transfer_admin(&new_admin);
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            events::ADMIN,
+            events::TRANSFERRED,
+            events::EVENT_VERSION,
+            new_admin.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(
+        events::AdminTransferredEvent::from_val(&e, &data),
+        events::AdminTransferredEvent {
+            previous: admin,
+            admin: new_admin,
+        }
+    );
+}
+
+// Tests the finalization event of a weighted proposal.
+// Expects: The payload carries every weighted total, the turnout, and the Succeeded outcome, and
+// a repeat finalize publishes nothing.
+#[test]
+fn test_finalize_event() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user1, &500);
+    stellar_asset.mint(&user2, &300);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let proposal_id = symbol_short!("PROP001");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 100);
+    client.vote(&user1, &proposal_id, &symbol_short!("FOR"));
+    client.vote(&user2, &proposal_id, &symbol_short!("AGAINST"));
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = ledger_time + 500001);
+    client.finalize(&proposal_id);
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            events::PROPOSAL,
+            events::FINALIZED,
+            events::EVENT_VERSION,
+            proposal_id.clone()
+        )
+            .into_val(&e)
+    );
+    assert_eq!(
+        events::ProposalFinalizedEvent::from_val(&e, &data),
+        events::ProposalFinalizedEvent {
+            id: proposal_id.clone(),
+            status: GovernanceStatus::Succeeded,
+            total_for: 500,
+            total_against: 300,
+            total_abstain: 0,
+            turnout: 800,
+            quorum: 0,
+            quorum_met: true,
+            threshold: SIMPLE_MAJORITY,
+        }
+    );
+
+    client.finalize(&proposal_id);
+    assert_events(&e, &client.address, &[]);
+}
+
+// Tests the complete event list of each call in a create, vote, and finalize flow.
+// Expects: Every call publishes exactly the listed events in order, with weighted payloads under
+// the same versioned topics as the gated contract.
+#[test]
+fn test_event_snapshot_lifecycle() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &500);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let id = symbol_short!("PROP001");
+    let start_time = e.ledger().timestamp() + 50;
+    let end_time = start_time + MIN_PROPOSAL_DURATION;
+    client.create_proposal(
+        &admin,
+        &id,
+        &String::from_val(&e, &"Test proposal"),
+        &start_time,
+        &end_time,
+    );
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                events::CREATED,
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalCreatedEvent {
+                id: id.clone(),
+                proposer: admin.clone(),
+                start_time,
+                end_time,
+            },
+        )],
+    );
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &id, &symbol_short!("AGAINST"));
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::VOTE,
+                events::CAST,
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::VoteCastEvent {
+                id: id.clone(),
+                voter: user.clone(),
+                choice: symbol_short!("AGAINST"),
+                weight: 500,
+            },
+        )],
+    );
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = end_time + 1);
+    client.finalize(&id);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            (
+                events::PROPOSAL,
+                events::FINALIZED,
+                events::EVENT_VERSION,
+                id.clone(),
+            ),
+            events::ProposalFinalizedEvent {
+                id,
+                status: GovernanceStatus::Defeated,
+                total_for: 0,
+                total_against: 500,
+                total_abstain: 0,
+                turnout: 500,
+                quorum: 0,
+                quorum_met: true,
+                threshold: SIMPLE_MAJORITY,
+            },
+        )],
+    );
+}
+
+// Tests weight normalization for a strategy reporting weights with 9 decimals.
+// Expects: New proposals scale weights to 7 decimals, earlier proposals keep their precision.
+#[test]
+fn test_weight_decimals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    stellar_asset.mint(&user, &1_000);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    assert_eq!(client.get_weight_decimals(), 7);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    client.set_weight_decimals(&9);
+    assert_eq!(client.get_weight_decimals(), 9);
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .total_for,
+        1_000
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .total_for,
+        10
+    );
+}
+
+// Tests setting a weight precision above the supported maximum.
+// Expects: InvalidDecimals error (Error #221).
+#[test]
+fn test_weight_decimals_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    assert_eq!(
+        client.try_set_weight_decimals(&19),
+        Err(Ok(TokenWeightedVoteContractErrors::InvalidDecimals))
+    );
+}
+
+// Tests a quorum defined as a share of the supply token's total supply.
+// Expects: The quorum rounds up, is pinned at creation, and decides the outcome.
+#[test]
+fn test_supply_quorum() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &250);
+
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    let supply = e.register(MockSupplyToken, ());
+    let supply_client = MockSupplyTokenClient::new(&e, &supply);
+    supply_client.set_supply(&1_001);
+
+    let quorum = TokenWeightedVoteQuorum {
+        absolute: 0,
+        bps: 2_500,
+        supply: Some(supply.clone()),
+    };
+    client.set_quorum(&quorum);
+    assert_eq!(client.get_quorum(), quorum);
+
+    let description = String::from_val(&e, &"Test proposal");
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    supply_client.set_supply(&1_000);
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP002"),
+        &description,
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .quorum,
+        251
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP002"))
+            .quorum,
+        250
+    );
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 100;
+    });
+    client.vote(&user, &symbol_short!("PROP001"), &symbol_short!("FOR"));
+    client.vote(&user, &symbol_short!("PROP002"), &symbol_short!("FOR"));
+
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 500001;
+    });
+    assert_eq!(
+        client.finalize(&symbol_short!("PROP001")),
+        GovernanceStatus::Defeated
+    );
+    assert_eq!(
+        client.finalize(&symbol_short!("PROP002")),
+        GovernanceStatus::Succeeded
+    );
+}
+
+// Tests a supply-based quorum when the supply token reports no supply.
+// Expects: The quorum falls back to the absolute minimum.
+#[test]
+fn test_supply_quorum_zero_supply() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    let supply = e.register(MockSupplyToken, ());
+
+    client.set_quorum(&TokenWeightedVoteQuorum {
+        absolute: 100,
+        bps: 5_000,
+        supply: Some(supply),
+    });
+
+    let ledger_time = e.ledger().timestamp();
+    client.create_proposal(
+        &admin,
+        &symbol_short!("PROP001"),
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 50),
+        &(ledger_time + 500000),
+    );
+    assert_eq!(
+        client
+            .get_proposal_details(&symbol_short!("PROP001"))
+            .quorum,
+        100
+    );
+}
+
+// Tests quorum rules that cannot be resolved.
+// Expects: InvalidQuorum error (Error #222).
+#[test]
+fn test_set_quorum_invalid() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let strategy = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &strategy);
+    let supply = e.register(MockSupplyToken, ());
+
+    for quorum in [
+        TokenWeightedVoteQuorum {
+            absolute: -1,
+            bps: 0,
+            supply: None,
+        },
+        TokenWeightedVoteQuorum {
+            absolute: 0,
+            bps: 10_001,
+            supply: Some(supply),
+        },
+        TokenWeightedVoteQuorum {
+            absolute: 0,
+            bps: 2_500,
+            supply: None,
+        },
+    ] {
+        assert_eq!(
+            client.try_set_quorum(&quorum),
+            Err(Ok(TokenWeightedVoteContractErrors::InvalidQuorum))
+        );
+    }
+}
+
+// Tests a proposal identified by a name longer than a short symbol allows.
+// Expects: The proposal takes the next sequential ID that votes and reads use, skipping one a
+// caller already chose, the name reads back by ID and the ID by name, a name differing only in
+// hyphens gets its own ID, an unknown name fails with ProposalNotFound (Error #4), and a duplicate
+// name fails with ProposalAlreadyExists (Error #3).
+#[test]
+fn test_named_proposals() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    StellarAssetClient::new(&e, &token.address).mint(&user, &100);
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let name = String::from_val(&e, &"Q3-2025-BUDGET-REALLOCATION");
+    let underscored = String::from_val(&e, &"Q3_2025_BUDGET_REALLOCATION");
+    let description = String::from_val(&e, &"Reallocate the Q3 budget");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + 500000;
+    assert_eq!(client.get_named_proposal_id(&name), None);
+    let id = client.create_named_proposal(&admin, &name, &description, &start_time, &end_time);
+    client.create_proposal(
+        &admin,
+        &Symbol::new(&e, "PROP2"),
+        &description,
+        &start_time,
+        &end_time,
+    );
+    let underscored_id =
+        client.create_named_proposal(&admin, &underscored, &description, &start_time, &end_time);
+    assert_eq!(id, Symbol::new(&e, "PROP1"));
+    assert_eq!(underscored_id, Symbol::new(&e, "PROP3"));
+    assert_eq!(client.get_named_proposal_id(&name), Some(id.clone()));
+    assert_eq!(client.get_proposal_name(&id), Some(name.clone()));
+    assert_eq!(client.get_proposal_name(&symbol_short!("PROP001")), None);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = start_time);
+    client.vote(&user, &id, &symbol_short!("FOR"));
+    assert_eq!(client.get_proposal_by_name(&name).total_for, 100);
+    assert_eq!(client.get_proposal_by_name(&underscored).total_for, 0);
+    assert!(matches!(
+        client.try_get_proposal_by_name(&String::from_val(&e, &"Q3-2025-UNKNOWN")),
+        Err(Ok(TokenWeightedVoteContractErrors::ProposalNotFound))
+    ));
+
+    assert_eq!(
+        client.try_create_named_proposal(
+            &admin,
+            &name,
+            &description,
+            &(start_time + 1),
+            &(end_time + 1)
+        ),
+        Err(Ok(TokenWeightedVoteContractErrors::ProposalAlreadyExists))
+    );
+}
+
+// Tests names that cannot identify a proposal.
+// Expects: Empty names, names over 64 characters, and names with characters other than letters,
+// digits, underscores, and hyphens fail with InvalidProposalName (Error #224).
+#[test]
+fn test_invalid_proposal_name() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let client = create_vote_contract(&e, &admin, &Address::generate(&e));
+
+    let description = String::from_val(&e, &"Test proposal");
+    let start_time = e.ledger().timestamp() + 100;
+    let end_time = start_time + 500000;
+    for name in [
+        "",
+        "Q3-2025-BUDGET-REALLOCATION-ROUND-2-SUPPLEMENTAL-COMMUNITY-GRANTS",
+        "Q3 BUDGET",
+        "BUDGET!",
+    ] {
+        let name = String::from_val(&e, &name);
+        assert_eq!(
+            client.try_create_named_proposal(&admin, &name, &description, &start_time, &end_time),
+            Err(Ok(TokenWeightedVoteContractErrors::InvalidProposalName))
+        );
+        assert!(matches!(
+            client.try_get_proposal_by_name(&name),
+            Err(Ok(TokenWeightedVoteContractErrors::InvalidProposalName))
+        ));
+    }
+}
+
+// Tests the cap on delegators carried by one delegatee.
+// Expects: A new delegator past the cap fails with TooManyDelegators (Error #225) while an
+// existing one can still change its share, an expired allocation frees its slot, and a vote
+// weighing a full delegator list stays within the Soroban budget.
+#[test]
+fn test_delegators_limit() {
+    let e = setup_test_env();
+    let admin = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+
+    let token = create_token_contract(&e, &admin);
+    let stellar_asset = StellarAssetClient::new(&e, &token.address);
+    let strategy = create_linear_strategy(&e, &token.address);
+    let client = create_vote_contract(&e, &admin, &strategy);
+
+    let ledger_time = e.ledger().timestamp();
+    let short_delegator = Address::generate(&e);
+    stellar_asset.mint(&short_delegator, &10);
+    client.delegate(
+        &short_delegator,
+        &delegatee,
+        &10_000,
+        &Some(ledger_time + 100),
+    );
+    for _ in 1..delegation::MAX_DELEGATORS {
+        let delegator = Address::generate(&e);
+        stellar_asset.mint(&delegator, &10);
+        client.delegate(&delegator, &delegatee, &10_000, &None);
+    }
+
+    let late_delegator = Address::generate(&e);
+    stellar_asset.mint(&late_delegator, &10);
+    assert_eq!(
+        client.try_delegate(&late_delegator, &delegatee, &10_000, &None),
+        Err(Ok(TokenWeightedVoteContractErrors::TooManyDelegators))
+    );
+    client.delegate(
+        &short_delegator,
+        &delegatee,
+        &5_000,
+        &Some(ledger_time + 100),
+    );
+
+    let proposal_id = symbol_short!("PROP001");
+    client.create_proposal(
+        &admin,
+        &proposal_id,
+        &String::from_val(&e, &"Test proposal"),
+        &(ledger_time + 150),
+        &(ledger_time + 500000),
+    );
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = ledger_time + 200;
+    });
+    client.delegate(&late_delegator, &delegatee, &10_000, &None);
+    assert_eq!(
+        client.get_delegators(&delegatee).len(),
+        delegation::MAX_DELEGATORS
+    );
+
+    assert_within_budget(&e, "vote with a full delegator list", || {
+        client.vote(&delegatee, &proposal_id, &symbol_short!("FOR"))
+    });
+    assert_eq!(
+        client.get_proposal_details(&proposal_id).total_for,
+        10 * delegation::MAX_DELEGATORS as i128
+    );
+}
+
+const PROP_VOTERS: usize = 6; // Holders taking part in randomized vote sequences
+
+// Vote choices drawn by randomized sequences, the last one invalid
+const PROP_CHOICES: [Symbol; 4] = [
+    symbol_short!("FOR"),
+    symbol_short!("AGAINST"),
+    symbol_short!("ABSTAIN"),
+    symbol_short!("MAYBE"),
+];
+
+// Generates a balance per holder, zero balances leaving a holder only delegated power
+fn balances_strategy() -> impl Strategy<Value = std::vec::Vec<i128>> {
+    prop::collection::vec(0..=1_000_000i128, PROP_VOTERS)
+}
+
+// Generates an optional (delegatee offset, bps) allocation per holder, the offset keeping each
+// delegatee distinct from its delegator
+fn delegations_strategy() -> impl Strategy<Value = std::vec::Vec<Option<(usize, u32)>>> {
+    prop::collection::vec(
+        prop::option::of((1..PROP_VOTERS, 1..=10_000u32)),
+        PROP_VOTERS,
+    )
+}
+
+// Generates a sequence of (holder, choice) votes, repeats included
+fn votes_strategy() -> impl Strategy<Value = std::vec::Vec<(usize, usize)>> {
+    prop::collection::vec((0..PROP_VOTERS, 0..PROP_CHOICES.len()), 0..24)
+}
+
+// Deploys a vote contract with a linear strategy, funds and delegates for each holder, and opens
+// a proposal for voting, returning the holders
+fn setup_prop_proposal<'a>(
+    balances: &[i128],
+    delegations: &[Option<(usize, u32)>],
+) -> (
+    GovernanceHarness<'a>,
+    TokenWeightedVoteContractClient<'a>,
+    std::vec::Vec<Address>,
+    Symbol,
+) {
+    let harness = GovernanceHarness::new(|e, admin, token| {
+        let strategy = create_linear_strategy(e, token);
+        create_vote_contract(e, admin, &strategy).address
+    });
+    let client = TokenWeightedVoteContractClient::new(&harness.env, &harness.contract());
+    let holders: std::vec::Vec<Address> = balances
+        .iter()
+        .map(|balance| harness.holder(*balance))
+        .collect();
+    for (delegator, delegation) in delegations.iter().enumerate() {
+        if let Some((offset, bps)) = delegation {
+            let delegatee = &holders[(delegator + offset) % PROP_VOTERS];
+            client.delegate(&holders[delegator], delegatee, bps, &None);
+        }
+    }
+    let proposal_id = symbol_short!("PROP001");
+    harness.propose(&proposal_id, MIN_PROPOSAL_DURATION);
+    harness.open_voting(&proposal_id);
+    (harness, client, holders, proposal_id)
+}
+
+// Returns the FOR, AGAINST, and ABSTAIN totals of a proposal
+fn read_totals(governance: &GovernanceClient, id: &Symbol) -> [i128; 3] {
+    let proposal = governance.get_proposal(id);
+    [
+        proposal.total_for,
+        proposal.total_against,
+        proposal.total_abstain,
+    ]
+}
+
+proptest! {
+    // Every case deploys contracts and casts up to 24 votes, so fewer cases than the default run
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // Tests tallies across a randomized vote sequence with delegations, repeats, and an invalid
+    // choice.
+    // Expects: Each vote counts the voter's balance plus the shares of delegators yet to vote, a
+    // tally decreases only when a delegator overrides the share its delegatee cast, and the
+    // tallies always sum to the weights counted.
+    #[test]
+    fn prop_tally_sums_counted_weights(
+        balances in balances_strategy(),
+        delegations in delegations_strategy(),
+        votes in votes_strategy(),
+    ) {
+        let (harness, client, holders, proposal_id) = setup_prop_proposal(&balances, &delegations);
+        let mut voted = [false; PROP_VOTERS];
+        let mut cast_shares: [Option<(usize, i128)>; PROP_VOTERS] = [None; PROP_VOTERS];
+        let mut expected = [0i128; 3];
+        let mut counted = 0i128;
+
+        for (voter, choice) in votes {
+            let before = read_totals(&harness.governance, &proposal_id);
+            let mut weight = balances[voter];
+            let mut shares = std::vec::Vec::new();
+            for (delegator, delegation) in delegations.iter().enumerate() {
+                if let Some((offset, bps)) = delegation {
+                    if (delegator + offset) % PROP_VOTERS == voter && !voted[delegator] {
+                        let share = balances[delegator] * *bps as i128 / 10_000;
+                        weight += share;
+                        shares.push((delegator, share));
+                    }
+                }
+            }
+
+            let result = client.try_vote(&holders[voter], &proposal_id, &PROP_CHOICES[choice]);
+            let eligible = !voted[voter] && weight > 0 && choice < 3;
+            prop_assert_eq!(result.is_ok(), eligible);
+            let mut overridden = false;
+            if eligible {
+                if let Some((delegatee_choice, share)) = cast_shares[voter].take() {
+                    expected[delegatee_choice] -= share;
+                    counted -= share;
+                    overridden = true;
+                }
+                for (delegator, share) in shares {
+                    cast_shares[delegator] = Some((choice, share));
+                }
+                voted[voter] = true;
+                expected[choice] += weight;
+                counted += weight;
+            }
+
+            let after = read_totals(&harness.governance, &proposal_id);
+            if !overridden {
+                prop_assert!(after.iter().zip(before.iter()).all(|(now, then)| now >= then));
+            }
+            prop_assert_eq!(after, expected);
+            prop_assert_eq!(after.iter().sum::<i128>(), counted);
+        }
+    }
+
+    // Tests finalizing a randomized proposal a second time.
+    // Expects: The repeat returns the same status and leaves the tallies unchanged.
+    #[test]
+    fn prop_finalize_idempotent(
+        balances in balances_strategy(),
+        delegations in delegations_strategy(),
+        votes in votes_strategy(),
+    ) {
+        let (harness, client, holders, proposal_id) = setup_prop_proposal(&balances, &delegations);
+        for (voter, choice) in votes {
+            let _ = client.try_vote(&holders[voter], &proposal_id, &PROP_CHOICES[choice]);
+        }
+
+        let status = harness.finalize(&proposal_id);
+        let proposal = harness.governance.get_proposal(&proposal_id);
+
+        prop_assert_eq!(client.finalize(&proposal_id), status);
+        prop_assert_eq!(harness.governance.get_proposal(&proposal_id), proposal);
+        prop_assert_eq!(harness.governance.get_status(&proposal_id), status);
+    }
+}
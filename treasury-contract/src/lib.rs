@@ -0,0 +1,643 @@
+#![no_std]
+
+use governance_core::events;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+pub use pg_contracts_types::treasury::{
+    TreasuryCommunityPool, TreasuryPoolInflow, TreasuryPoolSource, TreasurySpendingLimit,
+    TreasuryStream, TreasuryTotals,
+};
+
+mod pool;
+mod streams;
+
+// --- Spending Limit Bounds ---
+const DEFAULT_SPENDING_PERIOD: u64 = 2_592_000; // ~30 days rolling spending window
+const MAX_SPENDING_PERIOD: u64 = 31_536_000; // ~365 days rolling window at most
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const SHARES_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_TREASURY];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum TreasuryContractDataKey {
+    Governor,        // Governance contract authorizing payouts, exits, and the shutdown
+    Token,           // Governance token held by the treasury
+    Totals,          // Pooled treasury balance and outstanding shares
+    SpendingLimit,   // Payout cap and its rolling period
+    Spends,          // Payouts within the rolling spending period
+    Shutdown,        // UNIX timestamp of the permanent shutdown
+    CommunityPool,   // Community pool balance funded by fees and slashed bonds
+    PoolInflows,     // Most recent payments into the community pool
+    Shares(Address), // Treasury shares held per member
+    Stream(Symbol),  // Grant escrowed for linear vesting, keyed by its proposal
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 2500-2599 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TreasuryContractErrors {
+    ContractNotInitialized = 1,       // The contract has not been initialized
+    ContractAlreadyInitialized = 2,   // The contract has already been initialized
+    InvalidAmount = 13,               // Amount must be positive, or a cap must not be negative
+    InvalidConfig = 15,               // Spending period or stream duration out of range
+    InsufficientTreasury = 2501,      // The treasury cannot cover the payout
+    SpendingLimitExceeded = 2502,     // The payout would exceed the cap for the period
+    NoTreasuryShares = 2503,          // The member holds no treasury shares
+    TreasuryShutDown = 2504,          // Deposits and payouts stop after the shutdown
+    TreasuryNotShutDown = 2505,       // Redemption opens only after the shutdown
+    StreamNotFound = 2506,            // No stream, or no active stream to cancel, for this grant
+    StreamAlreadyExists = 2507,       // A stream was already opened for this grant
+    InsufficientCommunityPool = 2508, // The community pool cannot cover the payout
+}
+
+#[contract]
+pub struct TreasuryContract;
+
+#[contractimpl]
+impl TreasuryContract {
+    // --- Helper Functions ---
+
+    // Loads the governance contract address from instance storage
+    fn read_governor(env: &Env) -> Result<Address, TreasuryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TreasuryContractDataKey::Governor)
+            .ok_or(TreasuryContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, TreasuryContractErrors> {
+        env.storage()
+            .instance()
+            .get(&TreasuryContractDataKey::Token)
+            .ok_or(TreasuryContractErrors::ContractNotInitialized)
+    }
+
+    // Persists the treasury totals
+    fn write_totals(env: &Env, totals: &TreasuryTotals) {
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::Totals, totals);
+    }
+
+    // Persists the treasury shares of a member, removing the entry when none remain
+    fn write_shares(env: &Env, member: &Address, shares: i128) {
+        let shares_key = TreasuryContractDataKey::Shares(member.clone());
+        if shares == 0 {
+            env.storage().persistent().remove(&shares_key);
+            return;
+        }
+        env.storage().persistent().set(&shares_key, &shares);
+        env.storage().persistent().extend_ttl(
+            &shares_key,
+            SHARES_TTL_EXTENSION,
+            SHARES_TTL_EXTENSION,
+        );
+    }
+
+    // Loads the payouts made within the rolling spending period, as (paid_at, amount)
+    fn read_spends(env: &Env) -> Vec<(u64, i128)> {
+        let spends: Vec<(u64, i128)> = env
+            .storage()
+            .instance()
+            .get(&TreasuryContractDataKey::Spends)
+            .unwrap_or(Vec::new(env));
+        let window_start = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(Self::get_spending_limit(env.clone()).period);
+        let mut recent = Vec::new(env);
+        for (paid_at, amount) in spends.iter() {
+            if paid_at > window_start {
+                recent.push_back((paid_at, amount));
+            }
+        }
+        recent
+    }
+
+    // Sums the amounts of a list of payouts
+    fn total(spends: &Vec<(u64, i128)>) -> i128 {
+        spends
+            .iter()
+            .fold(0i128, |total, (_, amount)| total.saturating_add(amount))
+    }
+
+    // Records a payout against the spending cap, failing if it would exceed the cap for the
+    // rolling period; spends outside the period are dropped
+    fn record_spend(env: &Env, amount: i128) -> Result<(), TreasuryContractErrors> {
+        let cap = Self::get_spending_limit(env.clone()).cap;
+        let mut spends = Self::read_spends(env);
+        if cap > 0 && Self::total(&spends).saturating_add(amount) > cap {
+            return Err(TreasuryContractErrors::SpendingLimitExceeded);
+        }
+        spends.push_back((env.ledger().timestamp(), amount));
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::Spends, &spends);
+        Ok(())
+    }
+
+    // Fails once the treasury is shut down
+    fn require_live(env: &Env) -> Result<(), TreasuryContractErrors> {
+        if Self::get_shutdown(env.clone()).is_some() {
+            return Err(TreasuryContractErrors::TreasuryShutDown);
+        }
+        Ok(())
+    }
+
+    // Burns all `shares` held by a member and transfers their pro-rata treasury share to them
+    fn burn_shares(
+        env: &Env,
+        member: &Address,
+        shares: i128,
+    ) -> Result<i128, TreasuryContractErrors> {
+        let mut totals = Self::get_treasury(env.clone());
+        let payout = shares
+            .checked_mul(totals.balance)
+            .map(|value| value / totals.total_shares)
+            .ok_or(TreasuryContractErrors::InvalidAmount)?;
+        totals.balance -= payout;
+        totals.total_shares -= shares;
+        Self::write_totals(env, &totals);
+        Self::write_shares(env, member, 0);
+
+        if payout > 0 {
+            TokenClient::new(env, &Self::read_token(env)?).transfer(
+                &env.current_contract_address(),
+                member,
+                &payout,
+            );
+        }
+        Ok(payout)
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with the governance contract it answers to and the governance token
+    pub fn __constructor(
+        env: Env,
+        governor: Address,
+        token: Address,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&TreasuryContractDataKey::Governor)
+        {
+            return Err(TreasuryContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::Governor, &governor);
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::Token, &token);
+        Ok(())
+    }
+
+    // Transfers tokens into the treasury and mints shares at the current share price
+    pub fn deposit(
+        env: Env,
+        member: Address,
+        amount: i128,
+    ) -> Result<i128, TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_live(&env)?;
+        member.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        let mut totals = Self::get_treasury(env.clone());
+        let minted = if totals.total_shares == 0 || totals.balance == 0 {
+            amount
+        } else {
+            amount
+                .checked_mul(totals.total_shares)
+                .map(|value| value / totals.balance)
+                .ok_or(TreasuryContractErrors::InvalidAmount)?
+        };
+        if minted <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+
+        TokenClient::new(&env, &Self::read_token(&env)?).transfer(
+            &member,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        totals.balance = totals.balance.saturating_add(amount);
+        totals.total_shares = totals.total_shares.saturating_add(minted);
+        Self::write_totals(&env, &totals);
+        let shares = Self::get_shares(env.clone(), member.clone());
+        Self::write_shares(&env, &member, shares.saturating_add(minted));
+
+        events::publish(&env, "TREASURY", "DEPOSITED", member, (amount, minted));
+        Ok(minted)
+    }
+
+    // Adds tokens already transferred to the treasury to its balance without minting shares,
+    // raising the share price for existing members (governor only)
+    pub fn accrue(env: Env, amount: i128) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::read_governor(&env)?.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        let mut totals = Self::get_treasury(env.clone());
+        totals.balance = totals.balance.saturating_add(amount);
+        Self::write_totals(&env, &totals);
+
+        events::publish(
+            &env,
+            "TREASURY",
+            "ACCRUED",
+            env.current_contract_address(),
+            amount,
+        );
+        Ok(())
+    }
+
+    // Pays treasury funds to a recipient for a passed proposal, within the spending cap
+    // (governor only)
+    pub fn pay(
+        env: Env,
+        id: Symbol,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_live(&env)?;
+        Self::read_governor(&env)?.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        let mut totals = Self::get_treasury(env.clone());
+        if totals.balance < amount {
+            return Err(TreasuryContractErrors::InsufficientTreasury);
+        }
+        Self::record_spend(&env, amount)?;
+        totals.balance -= amount;
+        Self::write_totals(&env, &totals);
+
+        TokenClient::new(&env, &Self::read_token(&env)?).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+        events::publish(&env, "TREASURY", "PAID", id, (recipient, amount));
+        Ok(())
+    }
+
+    // Escrows treasury funds for a grant that vests linearly to the recipient over `duration`
+    // seconds from now, within the spending cap (governor only)
+    pub fn open_stream(
+        env: Env,
+        id: Symbol,
+        recipient: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_live(&env)?;
+        Self::read_governor(&env)?.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        if duration == 0 || duration > streams::MAX_STREAM_DURATION {
+            return Err(TreasuryContractErrors::InvalidConfig);
+        }
+        if streams::read_stream(&env, &id).is_some() {
+            return Err(TreasuryContractErrors::StreamAlreadyExists);
+        }
+        let mut totals = Self::get_treasury(env.clone());
+        if totals.balance < amount {
+            return Err(TreasuryContractErrors::InsufficientTreasury);
+        }
+        Self::record_spend(&env, amount)?;
+        totals.balance -= amount;
+        Self::write_totals(&env, &totals);
+
+        let stream = TreasuryStream {
+            recipient,
+            total: amount,
+            withdrawn: 0,
+            start_time: env.ledger().timestamp(),
+            duration,
+            cancelled_at: None,
+        };
+        streams::write_stream(&env, &id, &stream);
+
+        events::publish(&env, "STREAM", "OPENED", id, stream);
+        Ok(())
+    }
+
+    // Pays the recipient of a streamed grant the portion vested so far and not yet withdrawn,
+    // also after the shutdown
+    pub fn withdraw_streamed(env: Env, id: Symbol) -> Result<i128, TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let mut stream =
+            streams::read_stream(&env, &id).ok_or(TreasuryContractErrors::StreamNotFound)?;
+        stream.recipient.require_auth();
+
+        let amount = streams::vested(&stream, env.ledger().timestamp()) - stream.withdrawn;
+        if amount > 0 {
+            stream.withdrawn += amount;
+            streams::write_stream(&env, &id, &stream);
+            TokenClient::new(&env, &Self::read_token(&env)?).transfer(
+                &env.current_contract_address(),
+                &stream.recipient,
+                &amount,
+            );
+        }
+
+        events::publish(&env, "STREAM", "WITHDRAWN", id, (stream.recipient, amount));
+        Ok(amount)
+    }
+
+    // Stops future accrual of an active stream and returns the unvested remainder to the
+    // treasury balance; the recipient can still withdraw what vested before (governor only)
+    pub fn cancel_stream(env: Env, id: Symbol) -> Result<i128, TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::read_governor(&env)?.require_auth();
+
+        let mut stream = match streams::read_stream(&env, &id) {
+            Some(stream) if stream.cancelled_at.is_none() => stream,
+            _ => return Err(TreasuryContractErrors::StreamNotFound),
+        };
+        let ledger_time = env.ledger().timestamp();
+        stream.cancelled_at = Some(ledger_time);
+        let returned = stream.total - streams::vested(&stream, ledger_time);
+        streams::write_stream(&env, &id, &stream);
+        if returned > 0 {
+            let mut totals = Self::get_treasury(env.clone());
+            totals.balance = totals.balance.saturating_add(returned);
+            Self::write_totals(&env, &totals);
+        }
+
+        events::publish(&env, "STREAM", "CANCELLED", id, returned);
+        Ok(returned)
+    }
+
+    // Adds tokens already transferred to the treasury to the community pool, apart from the
+    // share-backed balance, and records the payment (governor only)
+    pub fn pool_accrue(
+        env: Env,
+        source: TreasuryPoolSource,
+        id: Symbol,
+        amount: i128,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::read_governor(&env)?.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        let mut community_pool = pool::read_pool(&env);
+        community_pool.balance = community_pool.balance.saturating_add(amount);
+        community_pool.total_received = community_pool.total_received.saturating_add(amount);
+        pool::write_pool(&env, &community_pool);
+        pool::record_inflow(
+            &env,
+            TreasuryPoolInflow {
+                source,
+                id: id.clone(),
+                amount,
+                received_at: env.ledger().timestamp(),
+            },
+        );
+
+        events::publish(&env, "POOL", "RECEIVED", id, (source, amount));
+        Ok(())
+    }
+
+    // Pays community pool funds to a recipient for a passed pool spend proposal, outside the
+    // spending cap (governor only)
+    pub fn pool_pay(
+        env: Env,
+        id: Symbol,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_live(&env)?;
+        Self::read_governor(&env)?.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        let mut community_pool = pool::read_pool(&env);
+        if community_pool.balance < amount {
+            return Err(TreasuryContractErrors::InsufficientCommunityPool);
+        }
+        community_pool.balance -= amount;
+        community_pool.total_spent = community_pool.total_spent.saturating_add(amount);
+        pool::write_pool(&env, &community_pool);
+
+        TokenClient::new(&env, &Self::read_token(&env)?).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+        events::publish(&env, "POOL", "PAID", id, (recipient, amount));
+        Ok(())
+    }
+
+    // Burns all shares of a member the governor found eligible to exit before proposal `id`
+    // executes and pays out their pro-rata treasury share (governor only)
+    pub fn rage_quit(
+        env: Env,
+        member: Address,
+        id: Symbol,
+    ) -> Result<i128, TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::read_governor(&env)?.require_auth();
+
+        let shares = Self::get_shares(env.clone(), member.clone());
+        if shares <= 0 {
+            return Err(TreasuryContractErrors::NoTreasuryShares);
+        }
+        let payout = Self::burn_shares(&env, &member, shares)?;
+        events::publish(&env, "TREASURY", "RAGE_QUIT", id, (member, shares, payout));
+        Ok(payout)
+    }
+
+    // Burns all shares of a member after the shutdown and pays out their pro-rata share
+    pub fn redeem(env: Env, member: Address) -> Result<i128, TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if Self::get_shutdown(env.clone()).is_none() {
+            return Err(TreasuryContractErrors::TreasuryNotShutDown);
+        }
+        member.require_auth();
+
+        let shares = Self::get_shares(env.clone(), member.clone());
+        if shares <= 0 {
+            return Err(TreasuryContractErrors::NoTreasuryShares);
+        }
+        let payout = Self::burn_shares(&env, &member, shares)?;
+        events::publish(&env, "TREASURY", "REDEEMED", member, (shares, payout));
+        Ok(payout)
+    }
+
+    // Sets the payouts allowed per rolling period, a cap of 0 disables the limit (governor only)
+    pub fn set_spending_limit(
+        env: Env,
+        limit: TreasurySpendingLimit,
+    ) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::read_governor(&env)?.require_auth();
+
+        if limit.cap < 0 {
+            return Err(TreasuryContractErrors::InvalidAmount);
+        }
+        if limit.period == 0 || limit.period > MAX_SPENDING_PERIOD {
+            return Err(TreasuryContractErrors::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::SpendingLimit, &limit);
+
+        events::publish(
+            &env,
+            "TREASURY",
+            "LIMIT_SET",
+            env.current_contract_address(),
+            limit,
+        );
+        Ok(())
+    }
+
+    // Shuts the treasury down permanently, stopping deposits and payouts and opening
+    // redemption (governor only)
+    pub fn shut_down(env: Env) -> Result<(), TreasuryContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        Self::require_live(&env)?;
+        let governor = Self::read_governor(&env)?;
+        governor.require_auth();
+
+        let ledger_time = env.ledger().timestamp();
+        env.storage()
+            .instance()
+            .set(&TreasuryContractDataKey::Shutdown, &ledger_time);
+
+        events::publish(&env, "TREASURY", "SHUTDOWN", governor, ledger_time);
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the pooled treasury balance and outstanding shares
+    pub fn get_treasury(env: Env) -> TreasuryTotals {
+        env.storage()
+            .instance()
+            .get(&TreasuryContractDataKey::Totals)
+            .unwrap_or_default()
+    }
+
+    // Returns the treasury shares held by a member
+    pub fn get_shares(env: Env, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&TreasuryContractDataKey::Shares(member))
+            .unwrap_or(0)
+    }
+
+    // Returns the payout cap and its rolling period, uncapped over ~30 days by default
+    pub fn get_spending_limit(env: Env) -> TreasurySpendingLimit {
+        env.storage()
+            .instance()
+            .get(&TreasuryContractDataKey::SpendingLimit)
+            .unwrap_or(TreasurySpendingLimit {
+                cap: 0,
+                period: DEFAULT_SPENDING_PERIOD,
+            })
+    }
+
+    // Returns the payouts made within the rolling spending period
+    pub fn get_period_spending(env: Env) -> i128 {
+        Self::total(&Self::read_spends(&env))
+    }
+
+    // Returns the community pool balance with its lifetime totals received and spent
+    pub fn get_community_pool(env: Env) -> TreasuryCommunityPool {
+        pool::read_pool(&env)
+    }
+
+    // Returns the most recent payments into the community pool, oldest first
+    pub fn get_pool_inflows(env: Env) -> Vec<TreasuryPoolInflow> {
+        pool::read_inflows(&env)
+    }
+
+    // Returns the streamed grant escrowed for a proposal, if any
+    pub fn get_stream(env: Env, id: Symbol) -> Option<TreasuryStream> {
+        streams::read_stream(&env, &id)
+    }
+
+    // Returns the portion of a streamed grant the recipient can withdraw now
+    pub fn get_withdrawable(env: Env, id: Symbol) -> i128 {
+        streams::read_stream(&env, &id).map_or(0, |stream| {
+            streams::vested(&stream, env.ledger().timestamp()) - stream.withdrawn
+        })
+    }
+
+    // Returns the UNIX timestamp of the shutdown, if the treasury was shut down
+    pub fn get_shutdown(env: Env) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&TreasuryContractDataKey::Shutdown)
+    }
+
+    // Returns the governance contract the treasury answers to
+    pub fn get_governor(env: Env) -> Result<Address, TreasuryContractErrors> {
+        Self::read_governor(&env)
+    }
+
+    // Returns the governance token held by the treasury
+    pub fn get_token(env: Env) -> Result<Address, TreasuryContractErrors> {
+        Self::read_token(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
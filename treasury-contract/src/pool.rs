@@ -0,0 +1,42 @@
+use pg_contracts_types::treasury::{TreasuryCommunityPool, TreasuryPoolInflow};
+use soroban_sdk::{Env, Vec};
+
+use crate::TreasuryContractDataKey;
+
+// --- Inflow History Bounds ---
+pub(crate) const MAX_POOL_HISTORY: u32 = 50; // Most recent inflows kept in the history
+
+// Loads the community pool totals
+pub(crate) fn read_pool(env: &Env) -> TreasuryCommunityPool {
+    env.storage()
+        .instance()
+        .get(&TreasuryContractDataKey::CommunityPool)
+        .unwrap_or_default()
+}
+
+// Persists the community pool totals
+pub(crate) fn write_pool(env: &Env, pool: &TreasuryCommunityPool) {
+    env.storage()
+        .instance()
+        .set(&TreasuryContractDataKey::CommunityPool, pool);
+}
+
+// Loads the most recent payments into the pool, oldest first
+pub(crate) fn read_inflows(env: &Env) -> Vec<TreasuryPoolInflow> {
+    env.storage()
+        .instance()
+        .get(&TreasuryContractDataKey::PoolInflows)
+        .unwrap_or(Vec::new(env))
+}
+
+// Appends a payment to the history, dropping the oldest entries beyond the maximum
+pub(crate) fn record_inflow(env: &Env, inflow: TreasuryPoolInflow) {
+    let mut inflows = read_inflows(env);
+    inflows.push_back(inflow);
+    while inflows.len() > MAX_POOL_HISTORY {
+        inflows.pop_front();
+    }
+    env.storage()
+        .instance()
+        .set(&TreasuryContractDataKey::PoolInflows, &inflows);
+}
@@ -0,0 +1,42 @@
+use pg_contracts_types::treasury::TreasuryStream;
+use soroban_sdk::{Env, Symbol};
+
+use crate::TreasuryContractDataKey;
+
+// --- Stream Duration Bounds (in seconds) ---
+pub(crate) const MAX_STREAM_DURATION: u64 = 126_144_000; // ~4 years
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const STREAM_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Loads the stream of a grant, if any
+pub(crate) fn read_stream(env: &Env, id: &Symbol) -> Option<TreasuryStream> {
+    env.storage()
+        .persistent()
+        .get(&TreasuryContractDataKey::Stream(id.clone()))
+}
+
+// Persists the stream of a grant and extends its TTL
+pub(crate) fn write_stream(env: &Env, id: &Symbol, stream: &TreasuryStream) {
+    let stream_key = TreasuryContractDataKey::Stream(id.clone());
+    env.storage().persistent().set(&stream_key, stream);
+    env.storage()
+        .persistent()
+        .extend_ttl(&stream_key, STREAM_TTL_EXTENSION, STREAM_TTL_EXTENSION);
+}
+
+// Returns the portion of the grant vested at `now`, frozen at cancellation
+pub(crate) fn vested(stream: &TreasuryStream, now: u64) -> i128 {
+    let end = stream
+        .cancelled_at
+        .map_or(now, |cancelled_at| cancelled_at.min(now));
+    let elapsed = end.saturating_sub(stream.start_time).min(stream.duration);
+    if elapsed == stream.duration {
+        return stream.total;
+    }
+    stream
+        .total
+        .checked_mul(elapsed as i128)
+        .map(|value| value / stream.duration as i128)
+        .unwrap_or(stream.total)
+}
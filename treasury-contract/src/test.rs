@@ -0,0 +1,526 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_core::events::EVENT_VERSION;
+use governance_errors::{in_range, GovernanceError, TREASURY_CODES};
+use governance_testutils::{assert_events, create_token_contract, event, setup_test_env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+// Deploys the token and a treasury answering to a generated governor, and mints 1,000 tokens
+// to two members; returns (client, token, governor, member1, member2)
+fn setup_treasury<'a>(
+    e: &Env,
+) -> (
+    TreasuryContractClient<'a>,
+    TokenClient<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    let admin = Address::generate(e);
+    let governor = Address::generate(e);
+    let member1 = Address::generate(e);
+    let member2 = Address::generate(e);
+
+    let token = create_token_contract(e, &admin);
+    let stellar_asset = StellarAssetClient::new(e, &token.address);
+    stellar_asset.mint(&member1, &1000);
+    stellar_asset.mint(&member2, &1000);
+
+    let contract_address = e.register(TreasuryContract, (governor.clone(), token.address.clone()));
+    let client = TreasuryContractClient::new(e, &contract_address);
+    (client, token, governor, member1, member2)
+}
+
+// Tests successful initialization with the governor and governance token.
+// Expects: An empty treasury, an uncapped ~30-day spending window, and no shutdown.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, _) = setup_treasury(&e);
+
+    assert_eq!(client.get_governor(), governor);
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(client.get_treasury(), TreasuryTotals::default());
+    assert_eq!(client.get_shares(&member1), 0);
+    assert_eq!(
+        client.get_spending_limit(),
+        TreasurySpendingLimit {
+            cap: 0,
+            period: DEFAULT_SPENDING_PERIOD,
+        }
+    );
+    assert_eq!(client.get_period_spending(), 0);
+    assert_eq!(client.get_shutdown(), None);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, _, member1, _) = setup_treasury(&e);
+
+    e.register_at(
+        &client.address,
+        TreasuryContract,
+        (member1.clone(), token.address.clone()),
+    );
+}
+
+// Tests deposits minting shares at the current share price.
+// Expects: Shares match deposits 1:1 until fees accrue, after which new deposits mint fewer.
+#[test]
+fn test_deposit_mints_shares() {
+    let e = setup_test_env();
+    let (client, token, _, member1, member2) = setup_treasury(&e);
+
+    assert_eq!(client.deposit(&member1, &600), 600);
+    assert_eq!(client.deposit(&member2, &400), 400);
+    assert_eq!(
+        client.get_treasury(),
+        TreasuryTotals {
+            balance: 1000,
+            total_shares: 1000,
+        }
+    );
+    assert_eq!(token.balance(&client.address), 1000);
+
+    StellarAssetClient::new(&e, &token.address).mint(&client.address, &1000);
+    client.accrue(&1000);
+    assert_eq!(client.deposit(&member2, &400), 200);
+    assert_eq!(client.get_shares(&member2), 600);
+    assert_eq!(
+        client.get_treasury(),
+        TreasuryTotals {
+            balance: 2400,
+            total_shares: 1200,
+        }
+    );
+}
+
+// Tests deposits and accruals of non-positive amounts.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+fn test_deposit_invalid_amount() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+
+    assert_eq!(
+        client.try_deposit(&member1, &0),
+        Err(Ok(TreasuryContractErrors::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_accrue(&-1),
+        Err(Ok(TreasuryContractErrors::InvalidAmount))
+    );
+}
+
+// Tests a payout authorized by the governor.
+// Expects: The recipient receives the amount, the balance drops, and the spend is counted.
+#[test]
+fn test_pay() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, _) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+    client.deposit(&member1, &1000);
+
+    client.pay(&symbol_short!("GRANT1"), &recipient, &300);
+
+    assert_eq!(e.auths()[0].0, governor);
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(client.get_treasury().balance, 700);
+    assert_eq!(client.get_treasury().total_shares, 1000);
+    assert_eq!(client.get_period_spending(), 300);
+}
+
+// Tests a payout exceeding the treasury balance.
+// Expects: InsufficientTreasury error (Error #2501).
+#[test]
+#[should_panic(expected = "Error(Contract, #2501)")]
+fn test_pay_insufficient_funds() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+    client.deposit(&member1, &500);
+
+    client.pay(&symbol_short!("GRANT1"), &member1, &501);
+}
+
+// Tests a spending cap across two payouts within a 10-day rolling period.
+// Expects: SpendingLimitExceeded error (Error #2502) while the first payout is in the window,
+// and the payout once it has rolled out.
+#[test]
+fn test_spending_limit() {
+    let e = setup_test_env();
+    let (client, token, _, member1, member2) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+    client.deposit(&member1, &1000);
+    client.deposit(&member2, &1000);
+    let limit = TreasurySpendingLimit {
+        cap: 500,
+        period: 864_000,
+    };
+    client.set_spending_limit(&limit);
+    assert_eq!(client.get_spending_limit(), limit);
+
+    client.pay(&symbol_short!("GRANT1"), &recipient, &300);
+    let first_paid_at = e.ledger().timestamp();
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_paid_at + 1000);
+    assert_eq!(
+        client.try_pay(&symbol_short!("GRANT2"), &recipient, &300),
+        Err(Ok(TreasuryContractErrors::SpendingLimitExceeded))
+    );
+    client.pay(&symbol_short!("GRANT2"), &recipient, &200);
+    assert_eq!(client.get_period_spending(), 500);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = first_paid_at + 864_000);
+    client.pay(&symbol_short!("GRANT3"), &recipient, &300);
+    assert_eq!(client.get_period_spending(), 500);
+    assert_eq!(token.balance(&recipient), 800);
+}
+
+// Tests spending limits with a negative cap or an out-of-range period.
+// Expects: InvalidAmount error (Error #13) and InvalidConfig error (Error #15).
+#[test]
+fn test_set_spending_limit_invalid() {
+    let e = setup_test_env();
+    let (client, _, _, _, _) = setup_treasury(&e);
+
+    assert_eq!(
+        client.try_set_spending_limit(&TreasurySpendingLimit {
+            cap: -1,
+            period: 864_000,
+        }),
+        Err(Ok(TreasuryContractErrors::InvalidAmount))
+    );
+    for period in [0, MAX_SPENDING_PERIOD + 1] {
+        assert_eq!(
+            client.try_set_spending_limit(&TreasurySpendingLimit { cap: 500, period }),
+            Err(Ok(TreasuryContractErrors::InvalidConfig))
+        );
+    }
+}
+
+// Tests a rage-quit exit authorized by the governor.
+// Expects: The member's shares are burned and the pro-rata balance is paid out.
+#[test]
+fn test_rage_quit() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, member2) = setup_treasury(&e);
+    client.deposit(&member1, &600);
+    client.deposit(&member2, &400);
+    client.pay(&symbol_short!("GRANT1"), &member1, &500);
+
+    assert_eq!(client.rage_quit(&member2, &symbol_short!("GRANT2")), 200);
+
+    assert_eq!(e.auths()[0].0, governor);
+    assert_eq!(token.balance(&member2), 800);
+    assert_eq!(client.get_shares(&member2), 0);
+    assert_eq!(
+        client.get_treasury(),
+        TreasuryTotals {
+            balance: 300,
+            total_shares: 600,
+        }
+    );
+}
+
+// Tests a rage-quit exit by a member without shares.
+// Expects: NoTreasuryShares error (Error #2503).
+#[test]
+#[should_panic(expected = "Error(Contract, #2503)")]
+fn test_rage_quit_without_shares() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+
+    client.rage_quit(&member1, &symbol_short!("GRANT1"));
+}
+
+// Tests redemption across the shutdown.
+// Expects: TreasuryNotShutDown error (Error #2505) before it, pro-rata redemption after it
+// (NoTreasuryShares, Error #2503, without shares), and TreasuryShutDown error (Error #2504) for
+// deposits and payouts.
+#[test]
+fn test_shutdown_and_redeem() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, member2) = setup_treasury(&e);
+    client.deposit(&member1, &600);
+    assert_eq!(
+        client.try_redeem(&member1),
+        Err(Ok(TreasuryContractErrors::TreasuryNotShutDown))
+    );
+
+    client.shut_down();
+    assert_eq!(e.auths()[0].0, governor);
+    assert_eq!(client.get_shutdown(), Some(e.ledger().timestamp()));
+
+    assert_eq!(client.redeem(&member1), 600);
+    assert_eq!(token.balance(&member1), 1000);
+    assert_eq!(client.get_treasury(), TreasuryTotals::default());
+    assert_eq!(
+        client.try_redeem(&member2),
+        Err(Ok(TreasuryContractErrors::NoTreasuryShares))
+    );
+    assert_eq!(
+        client.try_deposit(&member2, &100),
+        Err(Ok(TreasuryContractErrors::TreasuryShutDown))
+    );
+    assert_eq!(
+        client.try_pay(&symbol_short!("GRANT1"), &member2, &100),
+        Err(Ok(TreasuryContractErrors::TreasuryShutDown))
+    );
+}
+
+// Tests a streamed grant vesting linearly and cancelled halfway.
+// Expects: Opening escrows the grant within the spending cap, withdrawals pay the vested
+// portion, cancellation returns the unvested remainder to the balance and freezes accrual, and
+// a second cancellation fails (Error #2506).
+#[test]
+fn test_stream() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, _) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+    let id = symbol_short!("GRANT1");
+    client.deposit(&member1, &1000);
+
+    let opened_at = e.ledger().timestamp();
+    client.open_stream(&id, &recipient, &1000, &1000);
+    assert_eq!(e.auths()[0].0, governor);
+    assert_eq!(client.get_treasury().balance, 0);
+    assert_eq!(client.get_period_spending(), 1000);
+    assert_eq!(token.balance(&client.address), 1000);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 250);
+    assert_eq!(client.get_withdrawable(&id), 250);
+    assert_eq!(client.withdraw_streamed(&id), 250);
+    assert_eq!(e.auths()[0].0, recipient);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 500);
+    assert_eq!(client.cancel_stream(&id), 500);
+    assert_eq!(client.get_treasury().balance, 500);
+
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = opened_at + 1000);
+    assert_eq!(client.withdraw_streamed(&id), 250);
+    assert_eq!(client.withdraw_streamed(&id), 0);
+    assert_eq!(token.balance(&recipient), 500);
+    assert_eq!(client.get_stream(&id).unwrap().withdrawn, 500);
+    assert_eq!(
+        client.try_cancel_stream(&id),
+        Err(Ok(TreasuryContractErrors::StreamNotFound))
+    );
+}
+
+// Tests streams without a valid duration, repeated for the same grant, or never opened.
+// Expects: InvalidConfig error (Error #15), StreamAlreadyExists error (Error #2507), and
+// StreamNotFound error (Error #2506).
+#[test]
+fn test_stream_invalid() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+    let id = symbol_short!("GRANT1");
+    client.deposit(&member1, &1000);
+
+    for duration in [0, streams::MAX_STREAM_DURATION + 1] {
+        assert_eq!(
+            client.try_open_stream(&id, &recipient, &100, &duration),
+            Err(Ok(TreasuryContractErrors::InvalidConfig))
+        );
+    }
+    client.open_stream(&id, &recipient, &100, &1000);
+    assert_eq!(
+        client.try_open_stream(&id, &recipient, &100, &1000),
+        Err(Ok(TreasuryContractErrors::StreamAlreadyExists))
+    );
+    assert_eq!(
+        client.try_withdraw_streamed(&symbol_short!("GRANT2")),
+        Err(Ok(TreasuryContractErrors::StreamNotFound))
+    );
+    assert_eq!(client.get_withdrawable(&symbol_short!("GRANT2")), 0);
+}
+
+// Tests community pool payments and spends next to share-backed deposits.
+// Expects: Inflows raise the pool apart from the treasury balance and are recorded with their
+// source, the 50 most recent are kept, spends pay out of the pool only, and a spend beyond the
+// pool fails with InsufficientCommunityPool (Error #2508).
+#[test]
+fn test_community_pool() {
+    let e = setup_test_env();
+    let (client, token, governor, member1, _) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+    client.deposit(&member1, &500);
+    token.transfer(&member1, &client.address, &100);
+
+    client.pool_accrue(
+        &TreasuryPoolSource::ProposalFee,
+        &symbol_short!("PROP1"),
+        &60,
+    );
+    assert_eq!(e.auths()[0].0, governor);
+    client.pool_accrue(
+        &TreasuryPoolSource::SlashedBond,
+        &symbol_short!("PROP2"),
+        &40,
+    );
+    assert_eq!(
+        client.get_pool_inflows().get(1).unwrap(),
+        TreasuryPoolInflow {
+            source: TreasuryPoolSource::SlashedBond,
+            id: symbol_short!("PROP2"),
+            amount: 40,
+            received_at: e.ledger().timestamp(),
+        }
+    );
+
+    client.pool_pay(&symbol_short!("POOL1"), &recipient, &70);
+    assert_eq!(token.balance(&recipient), 70);
+    assert_eq!(
+        client.get_community_pool(),
+        TreasuryCommunityPool {
+            balance: 30,
+            total_received: 100,
+            total_spent: 70,
+        }
+    );
+    assert_eq!(client.get_treasury().balance, 500);
+    assert_eq!(client.get_period_spending(), 0);
+    assert_eq!(
+        client.try_pool_pay(&symbol_short!("POOL2"), &recipient, &31),
+        Err(Ok(TreasuryContractErrors::InsufficientCommunityPool))
+    );
+
+    for _ in 0..pool::MAX_POOL_HISTORY {
+        client.pool_accrue(
+            &TreasuryPoolSource::ProposalFee,
+            &symbol_short!("PROP3"),
+            &1,
+        );
+    }
+    let inflows = client.get_pool_inflows();
+    assert_eq!(inflows.len(), pool::MAX_POOL_HISTORY);
+    assert_eq!(inflows.get(0).unwrap().id, symbol_short!("PROP3"));
+}
+
+// Tests governor-only calls made without the governor's authorization.
+// Expects: Each call fails authorization.
+#[test]
+fn test_governor_auth() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+    e.set_auths(&[]);
+
+    assert!(client.try_accrue(&100).is_err());
+    assert!(client
+        .try_pay(&symbol_short!("GRANT1"), &member1, &100)
+        .is_err());
+    assert!(client
+        .try_rage_quit(&member1, &symbol_short!("GRANT1"))
+        .is_err());
+    assert!(client
+        .try_open_stream(&symbol_short!("GRANT1"), &member1, &100, &1000)
+        .is_err());
+    assert!(client.try_cancel_stream(&symbol_short!("GRANT1")).is_err());
+    assert!(client
+        .try_pool_accrue(
+            &TreasuryPoolSource::ProposalFee,
+            &symbol_short!("PROP1"),
+            &100
+        )
+        .is_err());
+    assert!(client
+        .try_pool_pay(&symbol_short!("POOL1"), &member1, &100)
+        .is_err());
+    assert!(client.try_shut_down().is_err());
+}
+
+// Tests the complete event list of deposits and payouts.
+// Expects: TREASURY/DEPOSITED with the amount and shares minted, and TREASURY/PAID keyed by the
+// proposal with the recipient and amount.
+#[test]
+fn test_event_snapshot() {
+    let e = setup_test_env();
+    let (client, _, _, member1, _) = setup_treasury(&e);
+    let recipient = Address::generate(&e);
+
+    client.deposit(&member1, &1000);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            ("TREASURY", "DEPOSITED", EVENT_VERSION, member1.clone()),
+            (1000i128, 1000i128),
+        )],
+    );
+
+    let id = symbol_short!("GRANT1");
+    client.pay(&id, &recipient, &400);
+    assert_events(
+        &e,
+        &client.address,
+        &[event(
+            &e,
+            ("TREASURY", "PAID", EVENT_VERSION, id),
+            (recipient, 400i128),
+        )],
+    );
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with treasury supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _, _, _) = setup_treasury(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_TREASURY));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_WEIGHT));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 2500 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        TreasuryContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        TreasuryContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        TreasuryContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    assert_eq!(
+        TreasuryContractErrors::InvalidConfig as u32,
+        GovernanceError::InvalidConfig as u32
+    );
+    for error in [
+        TreasuryContractErrors::InsufficientTreasury,
+        TreasuryContractErrors::SpendingLimitExceeded,
+        TreasuryContractErrors::NoTreasuryShares,
+        TreasuryContractErrors::TreasuryShutDown,
+        TreasuryContractErrors::TreasuryNotShutDown,
+        TreasuryContractErrors::StreamNotFound,
+        TreasuryContractErrors::StreamAlreadyExists,
+        TreasuryContractErrors::InsufficientCommunityPool,
+    ] {
+        assert!(in_range(error as u32, TREASURY_CODES));
+    }
+}
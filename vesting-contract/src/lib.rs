@@ -0,0 +1,249 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+pub use pg_contracts_types::vesting::VestingSchedule;
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const SCHEDULE_TTL_EXTENSION: u32 = 6_220_800; // ~72 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 1] = [governance_core::FEATURE_VESTING];
+
+// Defines the structure for persistent and instance storage
+#[contracttype]
+pub enum VestingContractDataKey {
+    Admin,             // Contract administrator address
+    Token,             // Governance token held in vesting
+    Schedule(Address), // Vesting schedule per beneficiary
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1200-1299 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VestingContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidSchedule = 1203,         // Amount and duration must be positive, cliff <= duration
+    ScheduleAlreadyExists = 1204,   // The beneficiary already has a vesting schedule
+    ScheduleNotFound = 1205,        // The beneficiary has no vesting schedule
+    NothingToRelease = 1206,        // No vested tokens are waiting to be released
+}
+
+#[contract]
+pub struct VestingContract;
+
+#[contractimpl]
+impl VestingContract {
+    // --- Helper Functions ---
+
+    // Loads the administrator address from instance storage
+    fn read_admin(env: &Env) -> Result<Address, VestingContractErrors> {
+        env.storage()
+            .instance()
+            .get(&VestingContractDataKey::Admin)
+            .ok_or(VestingContractErrors::ContractNotInitialized)
+    }
+
+    // Loads the governance token address from instance storage
+    fn read_token(env: &Env) -> Result<Address, VestingContractErrors> {
+        env.storage()
+            .instance()
+            .get(&VestingContractDataKey::Token)
+            .ok_or(VestingContractErrors::ContractNotInitialized)
+    }
+
+    // Saves a beneficiary's schedule and extends its TTL
+    fn write_schedule(env: &Env, beneficiary: &Address, schedule: &VestingSchedule) {
+        let schedule_key = VestingContractDataKey::Schedule(beneficiary.clone());
+        env.storage().persistent().set(&schedule_key, schedule);
+        env.storage().persistent().extend_ttl(
+            &schedule_key,
+            SCHEDULE_TTL_EXTENSION,
+            SCHEDULE_TTL_EXTENSION,
+        );
+    }
+
+    // Calculates the tokens vested so far, linearly between start and start + duration
+    fn vested_amount(env: &Env, schedule: &VestingSchedule) -> i128 {
+        let ledger_time = env.ledger().timestamp();
+        if ledger_time < schedule.start.saturating_add(schedule.cliff) {
+            return 0;
+        }
+        let elapsed = ledger_time - schedule.start;
+        if elapsed >= schedule.duration {
+            return schedule.total;
+        }
+        schedule.total * elapsed as i128 / schedule.duration as i128
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with admin and governance token
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), VestingContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env.storage().instance().has(&VestingContractDataKey::Admin) {
+            return Err(VestingContractErrors::ContractAlreadyInitialized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&VestingContractDataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&VestingContractDataKey::Token, &token);
+        Ok(())
+    }
+
+    // Creates a team or investor allocation, funded by the admin (admin only)
+    pub fn create_schedule(
+        env: Env,
+        beneficiary: Address,
+        amount: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+    ) -> Result<(), VestingContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let admin = Self::read_admin(&env)?;
+        admin.require_auth();
+
+        if amount <= 0 || duration == 0 || cliff > duration {
+            return Err(VestingContractErrors::InvalidSchedule);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&VestingContractDataKey::Schedule(beneficiary.clone()))
+        {
+            return Err(VestingContractErrors::ScheduleAlreadyExists);
+        }
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &admin,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let schedule = VestingSchedule {
+            total: amount,
+            released: 0,
+            start,
+            cliff,
+            duration,
+        };
+        Self::write_schedule(&env, &beneficiary, &schedule);
+
+        env.events().publish(
+            ("VESTING", "CREATED", beneficiary),
+            (amount, start, cliff, duration),
+        );
+        Ok(())
+    }
+
+    // Releases all vested but unreleased tokens to the beneficiary
+    pub fn release(env: Env, beneficiary: Address) -> Result<i128, VestingContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        beneficiary.require_auth();
+
+        let mut schedule = Self::get_schedule(env.clone(), beneficiary.clone())?;
+        let amount = Self::vested_amount(&env, &schedule) - schedule.released;
+        if amount <= 0 {
+            return Err(VestingContractErrors::NothingToRelease);
+        }
+
+        schedule.released += amount;
+        Self::write_schedule(&env, &beneficiary, &schedule);
+
+        let token_address = Self::read_token(&env)?;
+        TokenClient::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &beneficiary,
+            &amount,
+        );
+
+        env.events()
+            .publish(("VESTING", "RELEASED", beneficiary), amount);
+        Ok(amount)
+    }
+
+    // Transfers admin role to a new address
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), VestingContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        let current_admin = Self::read_admin(&env)?;
+
+        current_admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&VestingContractDataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("ADMIN", "TRANSFERRED"), (current_admin, new_admin));
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns a beneficiary's vesting schedule
+    pub fn get_schedule(
+        env: Env,
+        beneficiary: Address,
+    ) -> Result<VestingSchedule, VestingContractErrors> {
+        env.storage()
+            .persistent()
+            .get(&VestingContractDataKey::Schedule(beneficiary))
+            .ok_or(VestingContractErrors::ScheduleNotFound)
+    }
+
+    // Returns vested tokens still held by the contract, zero without a schedule
+    pub fn vested_balance(env: Env, user: Address) -> i128 {
+        match Self::get_schedule(env.clone(), user) {
+            Ok(schedule) => Self::vested_amount(&env, &schedule) - schedule.released,
+            Err(_) => 0,
+        }
+    }
+
+    // Returns tokens that have not vested yet, zero without a schedule
+    pub fn unvested_balance(env: Env, user: Address) -> i128 {
+        match Self::get_schedule(env.clone(), user) {
+            Ok(schedule) => schedule.total - Self::vested_amount(&env, &schedule),
+            Err(_) => 0,
+        }
+    }
+
+    // Returns the governance token held in vesting
+    pub fn get_token(env: Env) -> Result<Address, VestingContractErrors> {
+        Self::read_token(&env)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
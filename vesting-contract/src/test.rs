@@ -0,0 +1,211 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, VESTING_CODES};
+use governance_testutils::{create_token_contract, setup_test_env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+fn create_vesting_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    token_address: &Address,
+) -> VestingContractClient<'a> {
+    let contract_address = e.register(
+        VestingContract,
+        VestingContractArgs::__constructor(admin, token_address),
+    );
+    VestingContractClient::new(e, &contract_address)
+}
+
+// Deploys the token and vesting contract and mints 10,000 tokens to the admin
+fn setup_vesting<'a>(e: &Env) -> (VestingContractClient<'a>, TokenClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let token = create_token_contract(e, &admin);
+    StellarAssetClient::new(e, &token.address).mint(&admin, &10000);
+    let client = create_vesting_contract(e, &admin, &token.address);
+    (client, token, admin)
+}
+
+// Tests successful initialization with admin and governance token.
+// Expects: Token matches the constructor argument and no schedules exist.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    assert_eq!(client.get_token(), token.address);
+    assert_eq!(
+        client.try_get_schedule(&user),
+        Err(Ok(VestingContractErrors::ScheduleNotFound))
+    );
+    assert_eq!(client.vested_balance(&user), 0);
+    assert_eq!(client.unvested_balance(&user), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, token, admin) = setup_vesting(&e);
+
+    e.register_at(
+        &client.address,
+        VestingContract,
+        VestingContractArgs::__constructor(&admin, &token.address),
+    );
+}
+
+// Tests creating a funded schedule.
+// Expects: The allocation moves from the admin into the contract and is fully unvested.
+#[test]
+fn test_create_schedule() {
+    let e = setup_test_env();
+    let (client, token, admin) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+
+    let schedule = client.get_schedule(&user);
+    assert_eq!(schedule.total, 1000);
+    assert_eq!(schedule.released, 0);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(token.balance(&admin), 9000);
+    assert_eq!(client.vested_balance(&user), 0);
+    assert_eq!(client.unvested_balance(&user), 1000);
+}
+
+// Tests creating a schedule whose cliff exceeds its duration.
+// Expects: InvalidSchedule error (Error #1203).
+#[test]
+#[should_panic(expected = "Error(Contract, #1203)")]
+fn test_create_schedule_invalid() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+
+    client.create_schedule(&Address::generate(&e), &1000, &1000000, &1001, &1000);
+}
+
+// Tests creating a second schedule for the same beneficiary.
+// Expects: ScheduleAlreadyExists error (Error #1204).
+#[test]
+#[should_panic(expected = "Error(Contract, #1204)")]
+fn test_create_schedule_duplicate() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+}
+
+// Tests balances before the cliff, midway through, and after the full duration.
+// Expects: Nothing vests before the cliff, then vesting is linear from the start.
+#[test]
+fn test_vesting_progress() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1000099);
+    assert_eq!(client.vested_balance(&user), 0);
+    assert_eq!(client.unvested_balance(&user), 1000);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1000250);
+    assert_eq!(client.vested_balance(&user), 250);
+    assert_eq!(client.unvested_balance(&user), 750);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1002000);
+    assert_eq!(client.vested_balance(&user), 1000);
+    assert_eq!(client.unvested_balance(&user), 0);
+}
+
+// Tests releasing vested tokens in two steps.
+// Expects: Each release pays out the newly vested amount and lowers the vested balance.
+#[test]
+fn test_release() {
+    let e = setup_test_env();
+    let (client, token, _) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1000400);
+    assert_eq!(client.release(&user), 400);
+    assert_eq!(token.balance(&user), 400);
+    assert_eq!(client.vested_balance(&user), 0);
+    assert_eq!(client.unvested_balance(&user), 600);
+
+    e.ledger().with_mut(|ledger| ledger.timestamp = 1001000);
+    assert_eq!(client.release(&user), 600);
+    assert_eq!(token.balance(&user), 1000);
+    assert_eq!(client.get_schedule(&user).released, 1000);
+}
+
+// Tests releasing before the cliff.
+// Expects: NothingToRelease error (Error #1206).
+#[test]
+#[should_panic(expected = "Error(Contract, #1206)")]
+fn test_release_before_cliff() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+    let user = Address::generate(&e);
+
+    client.create_schedule(&user, &1000, &1000000, &100, &1000);
+    client.release(&user);
+}
+
+// Tests releasing for an address without a schedule.
+// Expects: ScheduleNotFound error (Error #1205).
+#[test]
+#[should_panic(expected = "Error(Contract, #1205)")]
+fn test_release_without_schedule() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+
+    client.release(&Address::generate(&e));
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with vesting supported and unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_vesting(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_VESTING));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_DELEGATION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1200 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        VestingContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        VestingContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    for error in [
+        VestingContractErrors::InvalidSchedule,
+        VestingContractErrors::ScheduleAlreadyExists,
+        VestingContractErrors::ScheduleNotFound,
+        VestingContractErrors::NothingToRelease,
+    ] {
+        assert!(in_range(error as u32, VESTING_CODES));
+    }
+}
@@ -0,0 +1,117 @@
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Val};
+
+use crate::{WrappedGovTokenCheckpoint, WrappedGovTokenContractDataKey};
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const CHECKPOINT_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// Resolves the key counting the checkpoints of an account, or of the total supply for None
+fn count_key(owner: &Option<Address>) -> WrappedGovTokenContractDataKey {
+    match owner {
+        Some(account) => WrappedGovTokenContractDataKey::CheckpointCount(account.clone()),
+        None => WrappedGovTokenContractDataKey::SupplyCheckpointCount,
+    }
+}
+
+// Resolves the key of one checkpoint of an account, or of the total supply for None
+fn entry_key(owner: &Option<Address>, index: u32) -> WrappedGovTokenContractDataKey {
+    match owner {
+        Some(account) => WrappedGovTokenContractDataKey::Checkpoint(account.clone(), index),
+        None => WrappedGovTokenContractDataKey::SupplyCheckpoint(index),
+    }
+}
+
+// Persists a storage entry and extends its TTL
+fn write<V: IntoVal<Env, Val>>(env: &Env, key: &WrappedGovTokenContractDataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, CHECKPOINT_TTL_EXTENSION, CHECKPOINT_TTL_EXTENSION);
+}
+
+// Returns the number of checkpoints written for an account, or for the total supply
+pub(crate) fn count(env: &Env, owner: &Option<Address>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&count_key(owner))
+        .unwrap_or(0)
+}
+
+// Loads one checkpoint by position, oldest first
+pub(crate) fn read(
+    env: &Env,
+    owner: &Option<Address>,
+    index: u32,
+) -> Option<WrappedGovTokenCheckpoint> {
+    env.storage().persistent().get(&entry_key(owner, index))
+}
+
+// Returns the most recent value, 0 before the first checkpoint
+pub(crate) fn latest(env: &Env, owner: &Option<Address>) -> i128 {
+    match count(env, owner) {
+        0 => 0,
+        count => read(env, owner, count - 1).map_or(0, |checkpoint| checkpoint.value),
+    }
+}
+
+// Records a new value at the current ledger timestamp, overwriting the latest checkpoint when
+// it was written in the same second so each timestamp keeps one entry
+pub(crate) fn push(env: &Env, owner: &Option<Address>, value: i128) {
+    let timestamp = env.ledger().timestamp();
+    let count = count(env, owner);
+    let index = match count.checked_sub(1).and_then(|last| read(env, owner, last)) {
+        Some(checkpoint) if checkpoint.timestamp == timestamp => count - 1,
+        _ => {
+            write(env, &count_key(owner), &(count + 1));
+            count
+        }
+    };
+    write(
+        env,
+        &entry_key(owner, index),
+        &WrappedGovTokenCheckpoint { timestamp, value },
+    );
+}
+
+// Returns the value held at the start of `timestamp`, i.e. from the last checkpoint written
+// strictly before it, so changes within that same second never count towards the snapshot
+pub(crate) fn lookup(env: &Env, owner: &Option<Address>, timestamp: u64) -> i128 {
+    let mut low = 0;
+    let mut high = count(env, owner);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        match read(env, owner, middle) {
+            Some(checkpoint) if checkpoint.timestamp < timestamp => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+    match low {
+        0 => 0,
+        found => read(env, owner, found - 1).map_or(0, |checkpoint| checkpoint.value),
+    }
+}
+
+// Moves votes between delegatees, publishing the old and new votes of each side
+pub(crate) fn move_votes(env: &Env, from: Option<Address>, to: Option<Address>, amount: i128) {
+    if from == to || amount == 0 {
+        return;
+    }
+    if let Some(account) = from {
+        let owner = Some(account.clone());
+        let previous = latest(env, &owner);
+        push(env, &owner, previous - amount);
+        env.events().publish(
+            (symbol_short!("votes"), account),
+            (previous, previous - amount),
+        );
+    }
+    if let Some(account) = to {
+        let owner = Some(account.clone());
+        let previous = latest(env, &owner);
+        push(env, &owner, previous + amount);
+        env.events().publish(
+            (symbol_short!("votes"), account),
+            (previous, previous + amount),
+        );
+    }
+}
@@ -0,0 +1,526 @@
+#![no_std]
+
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+
+mod checkpoints;
+pub use pg_contracts_types::wrapped_gov_token::{
+    WrappedGovTokenAllowance, WrappedGovTokenCheckpoint,
+};
+
+// --- Storage Time-To-Live (TTL) Constants (in ledger seconds) ---
+const BALANCE_TTL_EXTENSION: u32 = 3_110_400; // ~36 days
+
+// --- Interface Discovery ---
+// Features reported by `supports`
+const FEATURES: [Symbol; 4] = [
+    governance_core::FEATURE_TOKEN,
+    governance_core::FEATURE_DELEGATION,
+    governance_core::FEATURE_SNAPSHOTS,
+    governance_core::FEATURE_WEIGHT,
+];
+
+// Defines the structure for persistent, temporary, and instance storage
+#[contracttype]
+pub enum WrappedGovTokenContractDataKey {
+    Underlying,                  // Stellar asset held 1:1 against the wrapped supply
+    Decimals,                    // Number of decimal places, copied from the underlying asset
+    Name,                        // Human-readable token name
+    Symbol,                      // Token ticker symbol
+    TotalSupply,                 // Wrapped tokens in circulation
+    Balance(Address),            // Wrapped token balance per holder
+    Allowance(Address, Address), // Spending allowance per (owner, spender) pair
+    Delegate(Address),           // Delegatee chosen per holder, absent while self-delegated
+    Checkpoint(Address, u32),    // Vote checkpoint per delegatee and position
+    CheckpointCount(Address),    // Number of vote checkpoints per delegatee
+    SupplyCheckpoint(u32),       // Total supply checkpoint per position
+    SupplyCheckpointCount,       // Number of total supply checkpoints
+}
+
+// Enumerates the possible error states for the contract
+// Shared conditions reuse GovernanceError codes, the rest use the 1400-1499 block
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WrappedGovTokenContractErrors {
+    ContractNotInitialized = 1,     // The contract has not been initialized
+    ContractAlreadyInitialized = 2, // The contract has already been initialized
+    InvalidAmount = 13,             // Amounts must not be negative, deposits must be positive
+    InsufficientBalance = 1401,     // The holder does not have enough wrapped tokens
+    InsufficientAllowance = 1402,   // The spender's allowance is too low or expired
+    InvalidExpiration = 1403,       // A non-zero allowance cannot expire in the past
+}
+
+#[contract]
+pub struct WrappedGovTokenContract;
+
+#[contractimpl]
+impl WrappedGovTokenContract {
+    // --- Helper Functions ---
+
+    // Loads the underlying Stellar asset address from instance storage
+    fn read_underlying(env: &Env) -> Result<Address, WrappedGovTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&WrappedGovTokenContractDataKey::Underlying)
+            .ok_or(WrappedGovTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Rejects negative amounts
+    fn check_amount(amount: i128) -> Result<(), WrappedGovTokenContractErrors> {
+        if amount < 0 {
+            return Err(WrappedGovTokenContractErrors::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    // Rejects deposits and withdrawals that would not move any tokens
+    fn check_positive(amount: i128) -> Result<(), WrappedGovTokenContractErrors> {
+        if amount <= 0 {
+            return Err(WrappedGovTokenContractErrors::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    // Saves the balance of a holder and extends its TTL
+    fn write_balance(env: &Env, id: &Address, amount: i128) {
+        let balance_key = WrappedGovTokenContractDataKey::Balance(id.clone());
+        env.storage().persistent().set(&balance_key, &amount);
+        env.storage().persistent().extend_ttl(
+            &balance_key,
+            BALANCE_TTL_EXTENSION,
+            BALANCE_TTL_EXTENSION,
+        );
+    }
+
+    // Credits tokens to a holder
+    fn receive_balance(env: &Env, id: &Address, amount: i128) {
+        let balance = Self::balance(env.clone(), id.clone());
+        Self::write_balance(env, id, balance.saturating_add(amount));
+    }
+
+    // Debits tokens from a holder, failing if the balance is too low
+    fn spend_balance(
+        env: &Env,
+        id: &Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        let balance = Self::balance(env.clone(), id.clone());
+        if balance < amount {
+            return Err(WrappedGovTokenContractErrors::InsufficientBalance);
+        }
+        Self::write_balance(env, id, balance - amount);
+        Ok(())
+    }
+
+    // Moves tokens between holders together with the votes of their delegatees
+    fn move_balance(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        Self::spend_balance(env, from, amount)?;
+        Self::receive_balance(env, to, amount);
+        checkpoints::move_votes(
+            env,
+            Some(Self::delegates(env.clone(), from.clone())),
+            Some(Self::delegates(env.clone(), to.clone())),
+            amount,
+        );
+        Ok(())
+    }
+
+    // Loads an allowance, treating expired allowances as zero
+    fn read_allowance(env: &Env, from: &Address, spender: &Address) -> WrappedGovTokenAllowance {
+        let allowance_key =
+            WrappedGovTokenContractDataKey::Allowance(from.clone(), spender.clone());
+        let allowance: Option<WrappedGovTokenAllowance> =
+            env.storage().temporary().get(&allowance_key);
+        match allowance {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            Some(allowance) => WrappedGovTokenAllowance {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            },
+            None => WrappedGovTokenAllowance {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+
+    // Saves an allowance in temporary storage until its expiration ledger
+    fn write_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            return Err(WrappedGovTokenContractErrors::InvalidExpiration);
+        }
+
+        let allowance_key =
+            WrappedGovTokenContractDataKey::Allowance(from.clone(), spender.clone());
+        let allowance = WrappedGovTokenAllowance {
+            amount,
+            expiration_ledger,
+        };
+        env.storage().temporary().set(&allowance_key, &allowance);
+        if amount > 0 {
+            let live_for = expiration_ledger - env.ledger().sequence();
+            env.storage()
+                .temporary()
+                .extend_ttl(&allowance_key, live_for, live_for);
+        }
+        Ok(())
+    }
+
+    // Consumes part of an allowance, failing if it is too low or expired
+    fn spend_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        let allowance = Self::read_allowance(env, from, spender);
+        if allowance.amount < amount {
+            return Err(WrappedGovTokenContractErrors::InsufficientAllowance);
+        }
+        if amount > 0 {
+            Self::write_allowance(
+                env,
+                from,
+                spender,
+                allowance.amount - amount,
+                allowance.expiration_ledger,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Adjusts the total supply by a signed delta and checkpoints the new value
+    fn adjust_supply(env: &Env, delta: i128) {
+        let total_supply = Self::total_supply(env.clone()).saturating_add(delta);
+        env.storage()
+            .instance()
+            .set(&WrappedGovTokenContractDataKey::TotalSupply, &total_supply);
+        checkpoints::push(env, &None, total_supply);
+    }
+
+    // --- Write Functions ---
+
+    // Initializes contract with the wrapped Stellar asset and token metadata; decimals are
+    // copied from the underlying asset so amounts wrap 1:1
+    pub fn __constructor(
+        env: Env,
+        underlying: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        if env
+            .storage()
+            .instance()
+            .has(&WrappedGovTokenContractDataKey::Underlying)
+        {
+            return Err(WrappedGovTokenContractErrors::ContractAlreadyInitialized);
+        }
+
+        let decimals = TokenClient::new(&env, &underlying).decimals();
+        env.storage()
+            .instance()
+            .set(&WrappedGovTokenContractDataKey::Underlying, &underlying);
+        env.storage()
+            .instance()
+            .set(&WrappedGovTokenContractDataKey::Decimals, &decimals);
+        env.storage()
+            .instance()
+            .set(&WrappedGovTokenContractDataKey::Name, &name);
+        env.storage()
+            .instance()
+            .set(&WrappedGovTokenContractDataKey::Symbol, &symbol);
+        Ok(())
+    }
+
+    // Locks underlying tokens in the contract and mints the same amount of wrapped tokens,
+    // adding the votes to the depositor's delegatee
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_positive(amount)?;
+
+        let underlying = Self::read_underlying(&env)?;
+        TokenClient::new(&env, &underlying).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+        Self::receive_balance(&env, &from, amount);
+        Self::adjust_supply(&env, amount);
+        checkpoints::move_votes(
+            &env,
+            None,
+            Some(Self::delegates(env.clone(), from.clone())),
+            amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("deposit"), from), amount);
+        Ok(())
+    }
+
+    // Burns wrapped tokens and returns the same amount of underlying tokens, removing the votes
+    // from the holder's delegatee
+    pub fn withdraw(
+        env: Env,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_positive(amount)?;
+
+        let underlying = Self::read_underlying(&env)?;
+        Self::spend_balance(&env, &from, amount)?;
+        Self::adjust_supply(&env, -amount);
+        checkpoints::move_votes(
+            &env,
+            Some(Self::delegates(env.clone(), from.clone())),
+            None,
+            amount,
+        );
+        TokenClient::new(&env, &underlying).transfer(
+            &env.current_contract_address(),
+            &from,
+            &amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("withdraw"), from), amount);
+        Ok(())
+    }
+
+    // Assigns the votes of the holder's whole balance to a delegatee, including future
+    // deposits and transfers; delegating to oneself restores the default
+    pub fn delegate(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        delegator.require_auth();
+        Self::read_underlying(&env)?;
+
+        let previous = Self::delegates(env.clone(), delegator.clone());
+        let delegate_key = WrappedGovTokenContractDataKey::Delegate(delegator.clone());
+        if delegatee == delegator {
+            env.storage().persistent().remove(&delegate_key);
+        } else {
+            env.storage().persistent().set(&delegate_key, &delegatee);
+            env.storage().persistent().extend_ttl(
+                &delegate_key,
+                BALANCE_TTL_EXTENSION,
+                BALANCE_TTL_EXTENSION,
+            );
+        }
+        checkpoints::move_votes(
+            &env,
+            Some(previous.clone()),
+            Some(delegatee.clone()),
+            Self::balance(env.clone(), delegator.clone()),
+        );
+
+        env.events().publish(
+            (symbol_short!("delegate"), delegator),
+            (previous, delegatee),
+        );
+        Ok(())
+    }
+
+    // Sets the allowance of a spender over the caller's tokens
+    pub fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
+
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+        Ok(())
+    }
+
+    // Transfers tokens from the caller to another address
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        from.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::move_balance(&env, &from, &to, amount)?;
+
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
+    }
+
+    // Transfers tokens on behalf of an owner using the spender's allowance
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), WrappedGovTokenContractErrors> {
+        governance_core::extend_instance_ttl(&env);
+
+        spender.require_auth();
+        Self::check_amount(amount)?;
+
+        Self::spend_allowance(&env, &from, &spender, amount)?;
+        Self::move_balance(&env, &from, &to, amount)?;
+
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
+    }
+
+    // Extends the instance storage TTL so a long-idle deployment keeps its configuration
+    pub fn bump_instance(env: Env) {
+        governance_core::extend_instance_ttl(&env);
+    }
+
+    // --- Read-Only Functions ---
+
+    // Returns the remaining allowance of a spender over an owner's tokens
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Self::read_allowance(&env, &from, &spender).amount
+    }
+
+    // Returns the wrapped token balance of an address
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&WrappedGovTokenContractDataKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    // Returns the wrapped tokens in circulation, always equal to the underlying tokens held
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&WrappedGovTokenContractDataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    // Returns the number of decimal places
+    pub fn decimals(env: Env) -> Result<u32, WrappedGovTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&WrappedGovTokenContractDataKey::Decimals)
+            .ok_or(WrappedGovTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token name
+    pub fn name(env: Env) -> Result<String, WrappedGovTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&WrappedGovTokenContractDataKey::Name)
+            .ok_or(WrappedGovTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the token ticker symbol
+    pub fn symbol(env: Env) -> Result<String, WrappedGovTokenContractErrors> {
+        env.storage()
+            .instance()
+            .get(&WrappedGovTokenContractDataKey::Symbol)
+            .ok_or(WrappedGovTokenContractErrors::ContractNotInitialized)
+    }
+
+    // Returns the wrapped Stellar asset address
+    pub fn underlying(env: Env) -> Result<Address, WrappedGovTokenContractErrors> {
+        Self::read_underlying(&env)
+    }
+
+    // Returns the delegatee of a holder, the holder itself unless it delegated elsewhere
+    pub fn delegates(env: Env, account: Address) -> Address {
+        env.storage()
+            .persistent()
+            .get(&WrappedGovTokenContractDataKey::Delegate(account.clone()))
+            .unwrap_or(account)
+    }
+
+    // Returns the current votes of an account
+    pub fn get_votes(env: Env, account: Address) -> i128 {
+        checkpoints::latest(&env, &Some(account))
+    }
+
+    // Returns the votes an account held at the start of a timestamp
+    pub fn get_past_votes(env: Env, account: Address, timestamp: u64) -> i128 {
+        checkpoints::lookup(&env, &Some(account), timestamp)
+    }
+
+    // Returns the total supply at the start of a timestamp
+    pub fn get_past_total_supply(env: Env, timestamp: u64) -> i128 {
+        checkpoints::lookup(&env, &None, timestamp)
+    }
+
+    // Returns the number of vote checkpoints written for an account
+    pub fn num_checkpoints(env: Env, account: Address) -> u32 {
+        checkpoints::count(&env, &Some(account))
+    }
+
+    // Returns one vote checkpoint of an account by position, oldest first
+    pub fn get_checkpoint(
+        env: Env,
+        account: Address,
+        index: u32,
+    ) -> Option<WrappedGovTokenCheckpoint> {
+        checkpoints::read(&env, &Some(account), index)
+    }
+
+    // Returns the votes of a user at a snapshot timestamp, implementing the WeightCalculator
+    // interface so the token can serve as a weighting strategy directly
+    pub fn calculate_weight(env: Env, user: Address, snapshot_time: u64) -> i128 {
+        Self::get_past_votes(env, user, snapshot_time)
+    }
+
+    // Returns the contract version as (major, minor, patch)
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        governance_core::CONTRACT_VERSION
+    }
+
+    // Returns whether the contract offers a discovery feature
+    pub fn supports(_env: Env, feature: Symbol) -> bool {
+        governance_core::supports(&FEATURES, &feature)
+    }
+}
+
+// --- Test Module ---
+mod test;
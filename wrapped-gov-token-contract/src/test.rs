@@ -0,0 +1,334 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use governance_errors::{in_range, GovernanceError, WRAPPED_GOV_TOKEN_CODES};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+fn create_underlying_token<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
+    let token_address = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    TokenClient::new(e, &token_address)
+}
+
+fn create_wrapped_token<'a>(e: &Env, underlying: &Address) -> WrappedGovTokenContractClient<'a> {
+    let contract_address = e.register(
+        WrappedGovTokenContract,
+        (
+            underlying.clone(),
+            String::from_str(e, "Wrapped Play Governance"),
+            String::from_str(e, "wPLAY"),
+        ),
+    );
+    WrappedGovTokenContractClient::new(e, &contract_address)
+}
+
+// Creates an underlying asset with 1000 tokens minted to a holder, and its wrapper
+fn setup_wrapper<'a>(e: &Env) -> (WrappedGovTokenContractClient<'a>, TokenClient<'a>, Address) {
+    let admin = Address::generate(e);
+    let holder = Address::generate(e);
+    let underlying = create_underlying_token(e, &admin);
+    StellarAssetClient::new(e, &underlying.address).mint(&holder, &1000);
+    let client = create_wrapped_token(e, &underlying.address);
+    (client, underlying, holder)
+}
+
+fn setup_test_env() -> Env {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|ledger| {
+        ledger.timestamp = 1000000;
+        ledger.sequence_number = 100;
+    });
+    e
+}
+
+// Tests successful initialization with the underlying asset and token metadata.
+// Expects: Metadata match the constructor arguments, decimals match the underlying asset, and no
+// supply exists yet.
+#[test]
+fn test_initialization() {
+    let e = setup_test_env();
+    let (client, underlying, _) = setup_wrapper(&e);
+
+    assert_eq!(client.underlying(), underlying.address);
+    assert_eq!(client.decimals(), underlying.decimals());
+    assert_eq!(
+        client.name(),
+        String::from_str(&e, "Wrapped Play Governance")
+    );
+    assert_eq!(client.symbol(), String::from_str(&e, "wPLAY"));
+    assert_eq!(client.total_supply(), 0);
+}
+
+// Tests contract re-initialization failure on already initialized contract.
+// Expects: ContractAlreadyInitialized error (Error #2) to prevent state reset.
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_reinitialization() {
+    let e = setup_test_env();
+    let (client, underlying, _) = setup_wrapper(&e);
+
+    e.register_at(
+        &client.address,
+        WrappedGovTokenContract,
+        (
+            underlying.address.clone(),
+            String::from_str(&e, "Wrapped Play Governance"),
+            String::from_str(&e, "wPLAY"),
+        ),
+    );
+}
+
+// Tests depositing underlying tokens.
+// Expects: Underlying tokens move into the wrapper, the same amount is minted 1:1, and the
+// self-delegated holder receives the votes.
+#[test]
+fn test_deposit() {
+    let e = setup_test_env();
+    let (client, underlying, holder) = setup_wrapper(&e);
+
+    client.deposit(&holder, &600);
+
+    assert_eq!(underlying.balance(&holder), 400);
+    assert_eq!(underlying.balance(&client.address), 600);
+    assert_eq!(client.balance(&holder), 600);
+    assert_eq!(client.total_supply(), 600);
+    assert_eq!(client.delegates(&holder), holder);
+    assert_eq!(client.get_votes(&holder), 600);
+}
+
+// Tests depositing nothing.
+// Expects: InvalidAmount error (Error #13).
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_deposit_zero_amount() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+
+    client.deposit(&holder, &0);
+}
+
+// Tests withdrawing part of a deposit.
+// Expects: Wrapped tokens are burned, underlying tokens return 1:1, and votes and supply drop.
+#[test]
+fn test_withdraw() {
+    let e = setup_test_env();
+    let (client, underlying, holder) = setup_wrapper(&e);
+
+    client.deposit(&holder, &600);
+    client.withdraw(&holder, &200);
+
+    assert_eq!(underlying.balance(&holder), 600);
+    assert_eq!(underlying.balance(&client.address), 400);
+    assert_eq!(client.balance(&holder), 400);
+    assert_eq!(client.total_supply(), 400);
+    assert_eq!(client.get_votes(&holder), 400);
+}
+
+// Tests withdrawing more wrapped tokens than held.
+// Expects: InsufficientBalance error (Error #1401).
+#[test]
+#[should_panic(expected = "Error(Contract, #1401)")]
+fn test_withdraw_insufficient_balance() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+
+    client.deposit(&holder, &600);
+    client.withdraw(&holder, &601);
+}
+
+// Tests transferring wrapped tokens between self-delegated holders.
+// Expects: Balances and votes move from sender to recipient without changing supply.
+#[test]
+fn test_transfer_moves_votes() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+    let recipient = Address::generate(&e);
+
+    client.deposit(&holder, &600);
+    client.transfer(&holder, &recipient, &250);
+
+    assert_eq!(client.balance(&holder), 350);
+    assert_eq!(client.balance(&recipient), 250);
+    assert_eq!(client.get_votes(&holder), 350);
+    assert_eq!(client.get_votes(&recipient), 250);
+    assert_eq!(client.total_supply(), 600);
+}
+
+// Tests delegating votes to another address and back.
+// Expects: The delegatee receives the holder's votes, including later incoming transfers, and
+// self-delegation returns them.
+#[test]
+fn test_delegate() {
+    let e = setup_test_env();
+    let (client, underlying, holder) = setup_wrapper(&e);
+    let delegatee = Address::generate(&e);
+    let sender = Address::generate(&e);
+    StellarAssetClient::new(&e, &underlying.address).mint(&sender, &100);
+
+    client.deposit(&holder, &600);
+    client.delegate(&holder, &delegatee);
+    assert_eq!(client.delegates(&holder), delegatee);
+    assert_eq!(client.get_votes(&holder), 0);
+    assert_eq!(client.get_votes(&delegatee), 600);
+
+    client.deposit(&sender, &100);
+    client.transfer(&sender, &holder, &100);
+    assert_eq!(client.get_votes(&delegatee), 700);
+    assert_eq!(client.get_votes(&sender), 0);
+
+    client.delegate(&holder, &holder);
+    assert_eq!(client.delegates(&holder), holder);
+    assert_eq!(client.get_votes(&holder), 700);
+    assert_eq!(client.get_votes(&delegatee), 0);
+}
+
+// Tests historical vote and supply lookups across several timestamps.
+// Expects: Lookups return the value held at the start of each timestamp, so changes made at a
+// timestamp only count from the next second on.
+#[test]
+fn test_past_votes() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+    let recipient = Address::generate(&e);
+    let deposited_at = e.ledger().timestamp();
+
+    client.deposit(&holder, &600);
+    e.ledger()
+        .with_mut(|ledger| ledger.timestamp = deposited_at + 100);
+    client.transfer(&holder, &recipient, &200);
+    client.withdraw(&recipient, &50);
+
+    assert_eq!(client.get_past_votes(&holder, &deposited_at), 0);
+    assert_eq!(client.get_past_votes(&holder, &(deposited_at + 1)), 600);
+    assert_eq!(client.get_past_votes(&holder, &(deposited_at + 100)), 600);
+    assert_eq!(client.get_past_votes(&holder, &(deposited_at + 101)), 400);
+    assert_eq!(
+        client.get_past_votes(&recipient, &(deposited_at + 101)),
+        150
+    );
+    assert_eq!(client.get_past_total_supply(&(deposited_at + 1)), 600);
+    assert_eq!(client.get_past_total_supply(&(deposited_at + 101)), 550);
+    assert_eq!(
+        client.calculate_weight(&holder, &(deposited_at + 101)),
+        client.get_past_votes(&holder, &(deposited_at + 101))
+    );
+}
+
+// Tests several changes to the same account within one second.
+// Expects: A single checkpoint holds the final value for that timestamp.
+#[test]
+fn test_checkpoint_same_timestamp() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+    let recipient = Address::generate(&e);
+
+    client.deposit(&holder, &600);
+    client.transfer(&holder, &recipient, &100);
+    client.deposit(&holder, &100);
+
+    assert_eq!(client.num_checkpoints(&holder), 1);
+    assert_eq!(
+        client.get_checkpoint(&holder, &0),
+        Some(WrappedGovTokenCheckpoint {
+            timestamp: e.ledger().timestamp(),
+            value: 600,
+        })
+    );
+    assert_eq!(client.get_checkpoint(&holder, &1), None);
+}
+
+// Tests spending an approved allowance with transfer_from.
+// Expects: Tokens and votes move and the allowance decreases by the spent amount.
+#[test]
+fn test_approve_and_transfer_from() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.deposit(&holder, &1000);
+    client.approve(&holder, &spender, &500, &200);
+    client.transfer_from(&spender, &holder, &recipient, &300);
+
+    assert_eq!(client.allowance(&holder, &spender), 200);
+    assert_eq!(client.balance(&recipient), 300);
+    assert_eq!(client.get_votes(&holder), 700);
+    assert_eq!(client.get_votes(&recipient), 300);
+}
+
+// Tests spending an allowance after its expiration ledger.
+// Expects: InsufficientAllowance error (Error #1402).
+#[test]
+#[should_panic(expected = "Error(Contract, #1402)")]
+fn test_transfer_from_expired_allowance() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+    let spender = Address::generate(&e);
+
+    client.deposit(&holder, &1000);
+    client.approve(&holder, &spender, &500, &200);
+    e.ledger().with_mut(|ledger| ledger.sequence_number = 201);
+
+    assert_eq!(client.allowance(&holder, &spender), 0);
+    client.transfer_from(&spender, &holder, &Address::generate(&e), &100);
+}
+
+// Tests approving a non-zero allowance that has already expired.
+// Expects: InvalidExpiration error (Error #1403).
+#[test]
+#[should_panic(expected = "Error(Contract, #1403)")]
+fn test_approve_past_expiration() {
+    let e = setup_test_env();
+    let (client, _, holder) = setup_wrapper(&e);
+
+    client.approve(&holder, &Address::generate(&e), &500, &99);
+}
+
+// Tests the version and feature discovery functions.
+// Expects: The shared workspace version, with token, delegation, snapshots and weight supported and
+// unrelated features not.
+#[test]
+fn test_interface_discovery() {
+    let e = setup_test_env();
+    let (client, _, _) = setup_wrapper(&e);
+
+    assert_eq!(client.get_version(), governance_core::CONTRACT_VERSION);
+    assert!(client.supports(&governance_core::FEATURE_TOKEN));
+    assert!(client.supports(&governance_core::FEATURE_DELEGATION));
+    assert!(client.supports(&governance_core::FEATURE_SNAPSHOTS));
+    assert!(client.supports(&governance_core::FEATURE_WEIGHT));
+    assert!(!client.supports(&governance_core::FEATURE_GOVERNANCE));
+    assert!(!client.supports(&governance_core::FEATURE_EXECUTION));
+}
+
+// Tests the contract error codes against the shared governance registry.
+// Expects: Shared conditions reuse GovernanceError codes and the rest stay in the 1400 block.
+#[test]
+fn test_error_codes() {
+    assert_eq!(
+        WrappedGovTokenContractErrors::ContractNotInitialized as u32,
+        GovernanceError::ContractNotInitialized as u32
+    );
+    assert_eq!(
+        WrappedGovTokenContractErrors::ContractAlreadyInitialized as u32,
+        GovernanceError::ContractAlreadyInitialized as u32
+    );
+    assert_eq!(
+        WrappedGovTokenContractErrors::InvalidAmount as u32,
+        GovernanceError::InvalidAmount as u32
+    );
+    for error in [
+        WrappedGovTokenContractErrors::InsufficientBalance,
+        WrappedGovTokenContractErrors::InsufficientAllowance,
+        WrappedGovTokenContractErrors::InvalidExpiration,
+    ] {
+        assert!(in_range(error as u32, WRAPPED_GOV_TOKEN_CODES));
+    }
+}